@@ -1,11 +1,39 @@
-use crate::Color;
+use crate::image;
+use crate::{Color, ContentFit};
 
 /// The background of some element.
+///
+/// The image of an [`Background::Image`] or a [`Background::Tiled`] is drawn
+/// while a [`Handle`] to it is alive. The `glow` renderer does not draw
+/// images yet, so it only draws their border.
+///
+/// [`Handle`]: image::Handle
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Background {
     /// A solid color
     Color(Color),
-    // TODO: Add gradient and image variants
+
+    /// An image filling the element
+    Image {
+        /// The image, from [`image::Handle::reference`].
+        handle: image::Reference,
+
+        /// How the image is fitted into the bounds of the element.
+        fit: ContentFit,
+
+        /// The opacity of the image, from `0.0` to `1.0`.
+        opacity: f32,
+    },
+
+    /// An image repeated over the element, like a pattern
+    Tiled {
+        /// The image, from [`image::Handle::reference`].
+        handle: image::Reference,
+
+        /// The scale of every tile, relative to the size of the image.
+        scale: f32,
+    },
+    // TODO: Add gradient variant
 }
 
 impl From<Color> for Background {
@@ -0,0 +1,31 @@
+/// The placement of the border of some element relative to its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderAlignment {
+    /// The border is drawn inside the bounds, which keeps the size of the
+    /// element.
+    Inside,
+
+    /// The border is centered on the edges of the bounds.
+    Center,
+
+    /// The border is drawn outside the bounds, around the whole element.
+    Outside,
+}
+
+impl BorderAlignment {
+    /// Returns how far a border of the given width reaches outside the bounds
+    /// of its element.
+    pub fn outset(self, width: f32) -> f32 {
+        match self {
+            BorderAlignment::Inside => 0.0,
+            BorderAlignment::Center => width / 2.0,
+            BorderAlignment::Outside => width,
+        }
+    }
+}
+
+impl Default for BorderAlignment {
+    fn default() -> Self {
+        BorderAlignment::Inside
+    }
+}
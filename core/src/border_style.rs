@@ -0,0 +1,28 @@
+/// The style of the border of some element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderStyle {
+    /// A continuous line.
+    Solid,
+
+    /// A line made of dashes of length `dash` separated by gaps of length
+    /// `gap`.
+    ///
+    /// Both lengths are adjusted slightly so that a whole number of dashes
+    /// fits the perimeter of the element.
+    Dashed {
+        /// The length of a dash.
+        dash: f32,
+
+        /// The length of the gap between two dashes.
+        gap: f32,
+    },
+
+    /// A line made of round dots with a diameter equal to the border width.
+    Dotted,
+}
+
+impl Default for BorderStyle {
+    fn default() -> Self {
+        BorderStyle::Solid
+    }
+}
@@ -0,0 +1,88 @@
+/// The width of each edge of a border.
+///
+/// You can leverage the `From` trait to build a [`BorderWidth`] conveniently:
+///
+/// ```
+/// # use iced_core::BorderWidth;
+/// #
+/// let width = BorderWidth::from(2.0);                  // 2px on all edges
+/// let width = BorderWidth::from([1.0, 0.0, 1.0, 0.0]); // top, right, bottom, left
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BorderWidth {
+    /// Width of the top edge
+    pub top: f32,
+    /// Width of the right edge
+    pub right: f32,
+    /// Width of the bottom edge
+    pub bottom: f32,
+    /// Width of the left edge
+    pub left: f32,
+}
+
+impl BorderWidth {
+    /// A [`BorderWidth`] of zero, i.e. no border.
+    pub const ZERO: BorderWidth = BorderWidth::new(0.0);
+
+    /// Creates a [`BorderWidth`] that is equal on all edges.
+    pub const fn new(width: f32) -> BorderWidth {
+        BorderWidth {
+            top: width,
+            right: width,
+            bottom: width,
+            left: width,
+        }
+    }
+
+    /// Returns the width of the widest edge of the [`BorderWidth`].
+    pub fn max(self) -> f32 {
+        self.top.max(self.right).max(self.bottom).max(self.left)
+    }
+
+    /// Returns whether all the edges of the [`BorderWidth`] are as wide.
+    pub fn is_uniform(self) -> bool {
+        self.top == self.right
+            && self.top == self.bottom
+            && self.top == self.left
+    }
+}
+
+impl From<f32> for BorderWidth {
+    fn from(width: f32) -> Self {
+        BorderWidth::new(width)
+    }
+}
+
+impl From<[f32; 4]> for BorderWidth {
+    fn from(width: [f32; 4]) -> Self {
+        BorderWidth {
+            top: width[0],
+            right: width[1],
+            bottom: width[2],
+            left: width[3],
+        }
+    }
+}
+
+impl From<BorderWidth> for [f32; 4] {
+    fn from(width: BorderWidth) -> Self {
+        [width.top, width.right, width.bottom, width.left]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_from_and_into_arrays_of_edges() {
+        assert_eq!(BorderWidth::from(2.0), BorderWidth::from([2.0; 4]));
+        assert!(BorderWidth::from(2.0).is_uniform());
+
+        let width = BorderWidth::from([1.0, 2.0, 3.0, 4.0]);
+
+        assert_eq!(width.left, 4.0);
+        assert_eq!(width.max(), 4.0);
+        assert_eq!(<[f32; 4]>::from(width), [1.0, 2.0, 3.0, 4.0]);
+    }
+}
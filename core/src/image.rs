@@ -0,0 +1,289 @@
+//! Reference images to display.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, Weak};
+
+/// The images with a [`Reference`], by the id of their [`Handle`].
+static REFERENCED: Mutex<BTreeMap<u64, Weak<Data>>> =
+    Mutex::new(BTreeMap::new());
+
+/// A handle to some image data.
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    data: Arc<Data>,
+}
+
+impl Handle {
+    /// Creates an image [`Handle`] pointing to the image of the given path.
+    ///
+    /// Makes an educated guess about the image format by examining the data in the file.
+    pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
+        Self::from_data(Data::Path(path.into()))
+    }
+
+    /// Creates an image [`Handle`] containing the image pixels directly. This
+    /// function expects the input data to be provided as a `Vec<u8>` of BGRA
+    /// pixels.
+    ///
+    /// This is useful if you have already decoded your image.
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
+        Self::from_data(Data::Pixels {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    /// Creates an image [`Handle`] containing the image pixels as indices into
+    /// a palette of BGRA colors.
+    ///
+    /// An indexed image uses a quarter of the memory of its BGRA pixels, which
+    /// is useful for large pixel art with a few colors.
+    pub fn from_indexed(
+        width: u32,
+        height: u32,
+        indices: Vec<u8>,
+        palette: Vec<[u8; 4]>,
+    ) -> Handle {
+        Self::from_data(Data::Indexed {
+            width,
+            height,
+            indices,
+            palette,
+        })
+    }
+
+    /// Creates an image [`Handle`] containing the image data directly.
+    ///
+    /// Makes an educated guess about the image format by examining the given data.
+    ///
+    /// This is useful if you already have your image loaded in-memory, maybe
+    /// because you downloaded or generated it procedurally.
+    pub fn from_memory(bytes: Vec<u8>) -> Handle {
+        Self::from_data(Data::Bytes(bytes))
+    }
+
+    fn from_data(data: Data) -> Handle {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Arc::new(data),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Handle`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns a reference to the image [`Data`].
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns a [`Reference`] to the image of the [`Handle`], which can be
+    /// copied around, like in a [`Background`].
+    ///
+    /// [`Background`]: crate::Background
+    pub fn reference(&self) -> Reference {
+        let mut referenced = REFERENCED.lock().expect("Lock image references");
+
+        let is_referenced = referenced
+            .get(&self.id)
+            .map_or(false, |data| data.strong_count() > 0);
+
+        if !is_referenced {
+            referenced.retain(|_, data| data.strong_count() > 0);
+            let _ = referenced.insert(self.id, Arc::downgrade(&self.data));
+        }
+
+        Reference { id: self.id }
+    }
+}
+
+/// A copyable reference to the image of a [`Handle`].
+///
+/// A [`Reference`] does not keep its image alive: it only resolves to the
+/// image while some [`Handle`] to it exists. Keep the [`Handle`] in your
+/// application state, or in the style sheet using the [`Reference`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reference {
+    id: u64,
+}
+
+impl Reference {
+    /// Returns the unique identifier of the referenced image, which is the one
+    /// of its [`Handle`].
+    pub fn id(self) -> u64 {
+        self.id
+    }
+
+    /// Returns a [`Handle`] to the referenced image, unless every [`Handle`]
+    /// to it has been dropped.
+    pub fn handle(self) -> Option<Handle> {
+        let referenced = REFERENCED.lock().expect("Lock image references");
+
+        referenced
+            .get(&self.id)
+            .and_then(Weak::upgrade)
+            .map(|data| Handle { id: self.id, data })
+    }
+}
+
+impl<T> From<T> for Handle
+where
+    T: Into<PathBuf>,
+{
+    fn from(path: T) -> Handle {
+        Handle::from_path(path.into())
+    }
+}
+
+impl Hash for Handle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl PartialEq for Handle {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Handle {}
+
+/// The data of an image.
+#[derive(Clone, Hash)]
+pub enum Data {
+    /// File data
+    Path(PathBuf),
+
+    /// In-memory data
+    Bytes(Vec<u8>),
+
+    /// Decoded image pixels in BGRA format.
+    Pixels {
+        /// The width of the image.
+        width: u32,
+        /// The height of the image.
+        height: u32,
+        /// The pixels.
+        pixels: Vec<u8>,
+    },
+
+    /// Decoded image pixels as indices into a palette of BGRA colors.
+    Indexed {
+        /// The width of the image.
+        width: u32,
+        /// The height of the image.
+        height: u32,
+        /// The index in the palette of every pixel.
+        indices: Vec<u8>,
+        /// The colors of the palette, in BGRA format.
+        palette: Vec<[u8; 4]>,
+    },
+}
+
+impl Data {
+    /// Expands the pixels of [`Data::Indexed`] image data to BGRA.
+    ///
+    /// It returns `None` for any other data, and for indexed data with a wrong
+    /// amount of pixels or with indices missing from its palette.
+    pub fn expand_palette(&self) -> Option<Vec<u8>> {
+        let (width, height, indices, palette) = match self {
+            Data::Indexed {
+                width,
+                height,
+                indices,
+                palette,
+            } => (width, height, indices, palette),
+            _ => return None,
+        };
+
+        if indices.len() != *width as usize * *height as usize {
+            return None;
+        }
+
+        let mut pixels = Vec::with_capacity(indices.len() * 4);
+
+        for index in indices {
+            pixels.extend_from_slice(palette.get(usize::from(*index))?);
+        }
+
+        Some(pixels)
+    }
+}
+
+impl std::fmt::Debug for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::Path(path) => write!(f, "Path({:?})", path),
+            Data::Bytes(_) => write!(f, "Bytes(...)"),
+            Data::Pixels { width, height, .. } => {
+                write!(f, "Pixels({} * {})", width, height)
+            }
+            Data::Indexed {
+                width,
+                height,
+                palette,
+                ..
+            } => write!(
+                f,
+                "Indexed({} * {}, {} colors)",
+                width,
+                height,
+                palette.len()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexed_pixels_expand_to_their_palette_colors() {
+        let palette = vec![[0, 0, 0, 255], [255, 128, 0, 255]];
+
+        let handle = Handle::from_indexed(3, 1, vec![1, 0, 1], palette.clone());
+
+        assert_eq!(
+            handle.data().expand_palette(),
+            Some(vec![255, 128, 0, 255, 0, 0, 0, 255, 255, 128, 0, 255])
+        );
+
+        // Missing colors and pixels make the image invalid
+        assert_eq!(
+            Handle::from_indexed(3, 1, vec![1, 0, 2], palette.clone())
+                .data()
+                .expand_palette(),
+            None
+        );
+        assert_eq!(
+            Handle::from_indexed(2, 2, vec![1, 0, 1], palette)
+                .data()
+                .expand_palette(),
+            None
+        );
+    }
+
+    #[test]
+    fn references_resolve_while_a_handle_is_alive() {
+        let handle = Handle::from_pixels(1, 1, vec![0, 0, 255, 255]);
+        let reference = handle.reference();
+
+        assert_eq!(reference.handle(), Some(handle.clone()));
+        assert_eq!(reference.id(), handle.id());
+
+        drop(handle);
+
+        assert_eq!(reference.handle(), None);
+    }
+}
@@ -1,8 +1,19 @@
 //! Listen to keyboard events.
 mod event;
+mod hotkey;
 mod key_code;
+mod key_location;
 mod modifiers;
+mod scan_code;
+mod shortcut;
+mod shortcuts;
 
 pub use event::Event;
+pub use hotkey::Hotkey;
 pub use key_code::KeyCode;
+pub use key_location::KeyLocation;
 pub use modifiers::Modifiers;
+pub use scan_code::ScanCode;
+pub use shortcut::Shortcut;
+pub use shortcuts::Shortcuts;
+pub use smol_str::SmolStr;
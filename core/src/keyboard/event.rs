@@ -1,4 +1,4 @@
-use super::{KeyCode, Modifiers};
+use super::{KeyCode, KeyLocation, Modifiers, ScanCode, SmolStr};
 
 /// A keyboard event.
 ///
@@ -6,15 +6,24 @@ use super::{KeyCode, Modifiers};
 /// additional events, feel free to [open an issue] and share your use case!_
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
         /// The key identifier
         key_code: KeyCode,
 
+        /// The physical key, independent of the keyboard layout
+        physical_key: ScanCode,
+
+        /// The location of the key
+        location: KeyLocation,
+
         /// The state of the modifier keys
         modifiers: Modifiers,
+
+        /// Whether the key is held down and the press is repeated
+        repeat: bool,
     },
 
     /// A keyboard key was released.
@@ -22,13 +31,65 @@ pub enum Event {
         /// The key identifier
         key_code: KeyCode,
 
+        /// The physical key, independent of the keyboard layout
+        physical_key: ScanCode,
+
+        /// The location of the key
+        location: KeyLocation,
+
         /// The state of the modifier keys
         modifiers: Modifiers,
     },
 
+    /// A keyboard key was pressed, along with the text it produced.
+    ///
+    /// It follows the [`Event::KeyPressed`] and the
+    /// [`Event::CharacterReceived`] events of the same key press, so widgets
+    /// handling text should only listen to one of them.
+    ///
+    /// Text received without a key press, like the result of a compose
+    /// sequence, produces an [`Event::Input`] with an unlabeled key.
+    Input {
+        /// The key identifier
+        key: KeyCode,
+
+        /// The physical key, independent of the keyboard layout
+        physical_key: ScanCode,
+
+        /// The location of the key
+        location: KeyLocation,
+
+        /// The text produced by the key, if any
+        text: Option<SmolStr>,
+
+        /// The state of the modifier keys
+        modifiers: Modifiers,
+
+        /// Whether the key is held down and the press is repeated
+        repeat: bool,
+    },
+
     /// A unicode character was received.
+    ///
+    /// _**Note:** Prefer the `text` of an [`Event::Input`], which tells
+    /// which key produced it. This event is still produced for compatibility,
+    /// but it will be removed in a future release._
     CharacterReceived(char),
 
+    /// An input method is composing some text, which is not committed yet.
+    ///
+    /// The composing text replaces the one of any previous
+    /// [`Event::ImeComposing`]. An empty string ends the composition.
+    ///
+    /// _**Note:** The shells do not produce this event yet, since `winit`
+    /// reports input methods only from its 0.27 release._
+    #[cfg(feature = "ime")]
+    ImeComposing(String),
+
+    /// An input method committed some text, ending the composition.
+    #[cfg(feature = "ime")]
+    ImeCommit(String),
+
     /// The keyboard modifiers have changed.
     ModifiersChanged(Modifiers),
 }
@@ -0,0 +1,106 @@
+use super::{Event, KeyCode, Modifiers};
+
+use std::fmt;
+
+/// A combination of [`Modifiers`] and a [`KeyCode`], like `Ctrl+S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    /// The [`Modifiers`] that must be held.
+    pub modifiers: Modifiers,
+
+    /// The [`KeyCode`] that must be pressed.
+    pub key_code: KeyCode,
+}
+
+impl Hotkey {
+    /// Creates a new [`Hotkey`] from some [`Modifiers`] and a [`KeyCode`].
+    pub const fn new(modifiers: Modifiers, key_code: KeyCode) -> Self {
+        Self {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns whether the given keyboard [`Event`] presses the [`Hotkey`].
+    ///
+    /// The [`Modifiers`] of the [`Event`] must match exactly. For instance,
+    /// `Ctrl+Shift+S` does not press `Ctrl+S`.
+    pub fn is_pressed(&self, event: &Event) -> bool {
+        match event {
+            Event::KeyPressed {
+                key_code,
+                modifiers,
+                ..
+            } => *key_code == self.key_code && *modifiers == self.modifiers,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Hotkey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (
+                Modifiers::LOGO,
+                if cfg!(target_os = "macos") {
+                    "Cmd"
+                } else {
+                    "Super"
+                },
+            ),
+        ];
+
+        for (modifier, name) in modifiers.iter() {
+            if self.modifiers.contains(*modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+
+        let key = format!("{:?}", self.key_code);
+
+        // Digit keys are named `Key1`, `Key2`, and so on
+        match key.strip_prefix("Key") {
+            Some(digit) if !digit.is_empty() => write!(f, "{}", digit),
+            _ => write!(f, "{}", key),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::{KeyLocation, ScanCode};
+
+    #[test]
+    fn modifiers_must_match_exactly() {
+        let save = Hotkey::new(Modifiers::CTRL, KeyCode::S);
+
+        let press = |modifiers| Event::KeyPressed {
+            key_code: KeyCode::S,
+            physical_key: ScanCode(0),
+            location: KeyLocation::Standard,
+            modifiers,
+            repeat: false,
+        };
+
+        assert!(save.is_pressed(&press(Modifiers::CTRL)));
+        assert!(!save.is_pressed(&press(Modifiers::CTRL | Modifiers::SHIFT)));
+        assert!(!save.is_pressed(&press(Modifiers::empty())));
+    }
+
+    #[test]
+    fn display_lists_modifiers_before_the_key() {
+        assert_eq!(
+            Hotkey::new(Modifiers::CTRL | Modifiers::SHIFT, KeyCode::Z)
+                .to_string(),
+            "Ctrl+Shift+Z"
+        );
+        assert_eq!(
+            Hotkey::new(Modifiers::ALT, KeyCode::Key1).to_string(),
+            "Alt+1"
+        );
+    }
+}
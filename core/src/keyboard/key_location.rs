@@ -0,0 +1,75 @@
+use super::KeyCode;
+
+/// The location of a key, for the keys that appear more than once on a
+/// keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyLocation {
+    /// The key only appears once, or its location is unknown.
+    Standard,
+
+    /// The key on the left side of the keyboard, like the left Shift.
+    Left,
+
+    /// The key on the right side of the keyboard, like the right Shift.
+    Right,
+
+    /// The key on the numeric keypad, like the numpad Enter.
+    Numpad,
+}
+
+impl Default for KeyLocation {
+    fn default() -> Self {
+        KeyLocation::Standard
+    }
+}
+
+impl From<KeyCode> for KeyLocation {
+    fn from(key_code: KeyCode) -> Self {
+        match key_code {
+            KeyCode::LAlt
+            | KeyCode::LControl
+            | KeyCode::LShift
+            | KeyCode::LWin => KeyLocation::Left,
+            KeyCode::RAlt
+            | KeyCode::RControl
+            | KeyCode::RShift
+            | KeyCode::RWin => KeyLocation::Right,
+            KeyCode::Numlock
+            | KeyCode::Numpad0
+            | KeyCode::Numpad1
+            | KeyCode::Numpad2
+            | KeyCode::Numpad3
+            | KeyCode::Numpad4
+            | KeyCode::Numpad5
+            | KeyCode::Numpad6
+            | KeyCode::Numpad7
+            | KeyCode::Numpad8
+            | KeyCode::Numpad9
+            | KeyCode::NumpadAdd
+            | KeyCode::NumpadDivide
+            | KeyCode::NumpadDecimal
+            | KeyCode::NumpadComma
+            | KeyCode::NumpadEnter
+            | KeyCode::NumpadEquals
+            | KeyCode::NumpadMultiply
+            | KeyCode::NumpadSubtract => KeyLocation::Numpad,
+            _ => KeyLocation::Standard,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicated_keys_are_told_apart() {
+        assert_eq!(KeyLocation::from(KeyCode::LShift), KeyLocation::Left);
+        assert_eq!(KeyLocation::from(KeyCode::RShift), KeyLocation::Right);
+        assert_eq!(
+            KeyLocation::from(KeyCode::NumpadEnter),
+            KeyLocation::Numpad
+        );
+        assert_eq!(KeyLocation::from(KeyCode::Enter), KeyLocation::Standard);
+    }
+}
@@ -0,0 +1,10 @@
+/// The code of a physical key, independent of the keyboard layout.
+///
+/// A [`ScanCode`] identifies the position of a key. For instance, the key
+/// labeled `W` on a QWERTY layout has the same [`ScanCode`] as the one
+/// labeled `Z` on an AZERTY layout.
+///
+/// The values depend on the platform, so they are meant to be recorded from
+/// the keyboard events instead of being hardcoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScanCode(pub u32);
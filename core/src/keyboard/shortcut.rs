@@ -0,0 +1,120 @@
+use super::{Event, KeyCode, Modifiers};
+
+/// A keyboard shortcut of a common command, like copying or undoing.
+///
+/// Widgets match the [`Event`] they receive against a [`Shortcut`] instead
+/// of checking the [`KeyCode`] and the [`Modifiers`] by hand. The constants
+/// use [`Modifiers::COMMAND`], so they follow the conventions of the
+/// current platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shortcut {
+    /// The [`Modifiers`] that must be held.
+    pub modifiers: Modifiers,
+
+    /// The [`KeyCode`] that must be pressed.
+    pub key: KeyCode,
+}
+
+impl Shortcut {
+    /// Copies the selection.
+    pub const COPY: Self = Self::new(Modifiers::COMMAND, KeyCode::C);
+
+    /// Pastes the contents of the clipboard.
+    pub const PASTE: Self = Self::new(Modifiers::COMMAND, KeyCode::V);
+
+    /// Cuts the selection.
+    pub const CUT: Self = Self::new(Modifiers::COMMAND, KeyCode::X);
+
+    /// Selects everything.
+    pub const SELECT_ALL: Self = Self::new(Modifiers::COMMAND, KeyCode::A);
+
+    /// Undoes the last change.
+    pub const UNDO: Self = Self::new(Modifiers::COMMAND, KeyCode::Z);
+
+    /// Redoes the last undone change.
+    pub const REDO: Self = Self::new(
+        Modifiers::from_bits_truncate(
+            Modifiers::COMMAND.bits() | Modifiers::SHIFT.bits(),
+        ),
+        KeyCode::Z,
+    );
+
+    /// Creates a new [`Shortcut`] from some [`Modifiers`] and a [`KeyCode`].
+    pub const fn new(modifiers: Modifiers, key: KeyCode) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Returns whether the given keyboard [`Event`] presses the [`Shortcut`].
+    ///
+    /// Only an [`Event::KeyPressed`] can press it, so a key press followed by
+    /// its [`Event::Input`] matches once. The [`Modifiers`] of the [`Event`]
+    /// must match exactly. For instance, [`Shortcut::REDO`] does not press
+    /// [`Shortcut::UNDO`].
+    pub fn matches(&self, event: &Event) -> bool {
+        match event {
+            Event::KeyPressed {
+                key_code: key,
+                modifiers,
+                ..
+            } => *key == self.key && *modifiers == self.modifiers,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::{KeyLocation, ScanCode};
+
+    #[test]
+    fn redo_is_not_undo() {
+        let press = |modifiers| Event::KeyPressed {
+            key_code: KeyCode::Z,
+            physical_key: ScanCode(0),
+            location: KeyLocation::Standard,
+            modifiers,
+            repeat: false,
+        };
+
+        let undo = press(Modifiers::COMMAND);
+        let redo = press(Modifiers::COMMAND | Modifiers::SHIFT);
+
+        assert!(Shortcut::UNDO.matches(&undo));
+        assert!(!Shortcut::UNDO.matches(&redo));
+        assert!(Shortcut::REDO.matches(&redo));
+        assert!(!Shortcut::REDO.matches(&undo));
+        assert!(!Shortcut::COPY.matches(&undo));
+    }
+
+    #[test]
+    fn a_key_press_and_its_input_match_once() {
+        let events = [
+            Event::KeyPressed {
+                key_code: KeyCode::C,
+                physical_key: ScanCode(0),
+                location: KeyLocation::Standard,
+                modifiers: Modifiers::COMMAND,
+                repeat: false,
+            },
+            Event::Input {
+                key: KeyCode::C,
+                physical_key: ScanCode(0),
+                location: KeyLocation::Standard,
+                text: None,
+                modifiers: Modifiers::COMMAND,
+                repeat: false,
+            },
+        ];
+
+        let matches = |shortcut: Shortcut| {
+            events
+                .iter()
+                .filter(|event| shortcut.matches(event))
+                .count()
+        };
+
+        assert_eq!(matches(Shortcut::COPY), 1);
+        assert_eq!(matches(Shortcut::PASTE), 0);
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Event, Hotkey};
+
+use std::fmt;
+use std::rc::Rc;
+
+/// A set of application-wide shortcuts, binding each [`Hotkey`] to a message.
+///
+/// The shortcuts of a user interface are consulted before any widget
+/// processes an event, so they work without a focused widget. An event
+/// triggering a shortcut is captured, and never reaches the widgets.
+///
+/// # Example
+/// ```
+/// use iced_core::keyboard::{Hotkey, KeyCode, Modifiers, Shortcuts};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     Quit,
+/// }
+///
+/// let shortcuts = Shortcuts::new()
+///     .bind(Hotkey::new(Modifiers::CTRL, KeyCode::S), Message::Save)
+///     .bind(Hotkey::new(Modifiers::CTRL, KeyCode::Q), Message::Quit);
+/// ```
+pub struct Shortcuts<Message> {
+    bindings: Vec<(Hotkey, Box<dyn Fn() -> Message>)>,
+}
+
+impl<Message> Shortcuts<Message> {
+    /// Creates an empty set of [`Shortcuts`].
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds a [`Hotkey`] to the message it produces.
+    ///
+    /// If a [`Hotkey`] is bound more than once, the first binding wins.
+    pub fn bind(self, hotkey: Hotkey, message: Message) -> Self
+    where
+        Message: Clone + 'static,
+    {
+        self.bind_with(hotkey, move || message.clone())
+    }
+
+    /// Binds a [`Hotkey`] to a function producing its message.
+    ///
+    /// This is useful when the message cannot be cloned.
+    pub fn bind_with(
+        mut self,
+        hotkey: Hotkey,
+        f: impl Fn() -> Message + 'static,
+    ) -> Self {
+        self.bindings.push((hotkey, Box::new(f)));
+        self
+    }
+
+    /// Returns whether there are no bindings in the [`Shortcuts`].
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    /// Returns the message bound to the [`Hotkey`] triggered by the given
+    /// keyboard [`Event`], if any.
+    pub fn handle(&self, event: &Event) -> Option<Message> {
+        self.bindings
+            .iter()
+            .find(|(hotkey, _)| hotkey.is_pressed(event))
+            .map(|(_, message)| message())
+    }
+
+    /// Applies a transformation to the messages of the [`Shortcuts`].
+    pub fn map<B>(self, f: impl Fn(Message) -> B + 'static) -> Shortcuts<B>
+    where
+        Message: 'static,
+    {
+        let f = Rc::new(f);
+
+        Shortcuts {
+            bindings: self
+                .bindings
+                .into_iter()
+                .map(|(hotkey, message)| {
+                    let f = f.clone();
+                    let message: Box<dyn Fn() -> B> =
+                        Box::new(move || f(message()));
+
+                    (hotkey, message)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<Message> Default for Shortcuts<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message> fmt::Debug for Shortcuts<Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.bindings.iter().map(|(hotkey, _)| hotkey))
+            .finish()
+    }
+}
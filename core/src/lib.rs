@@ -15,27 +15,39 @@
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
 pub mod alignment;
+pub mod image;
 pub mod keyboard;
 pub mod mouse;
 pub mod text;
 
 mod background;
+mod border_alignment;
+mod border_style;
+mod border_width;
 mod color;
+mod content_fit;
 mod font;
 mod length;
 mod padding;
 mod point;
 mod rectangle;
+mod shadow;
 mod size;
 mod vector;
 
 pub use alignment::Alignment;
 pub use background::Background;
+pub use border_alignment::BorderAlignment;
+pub use border_style::BorderStyle;
+pub use border_width::BorderWidth;
 pub use color::Color;
+pub use content_fit::ContentFit;
 pub use font::Font;
 pub use length::Length;
 pub use padding::Padding;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use shadow::Shadow;
 pub use size::Size;
+pub use text::{TextShadow, WritingMode};
 pub use vector::Vector;
@@ -1,8 +1,11 @@
 //! Handle mouse events.
+pub mod cursor;
+
 mod button;
 mod event;
 mod interaction;
 
 pub use button::Button;
+pub use cursor::Cursor;
 pub use event::{Event, ScrollDelta};
 pub use interaction::Interaction;
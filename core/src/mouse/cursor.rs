@@ -0,0 +1,220 @@
+//! Track the mouse cursor and customize its icon.
+use crate::{Point, Rectangle};
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// The state of the mouse cursor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Cursor {
+    /// The cursor has a defined position.
+    Available(Point),
+
+    /// The cursor is currently unavailable (i.e. out of the window, or only
+    /// touch input is being used).
+    Unavailable,
+}
+
+impl Cursor {
+    /// Returns the absolute position of the [`Cursor`], if available.
+    pub fn position(self) -> Option<Point> {
+        match self {
+            Cursor::Available(position) => Some(position),
+            Cursor::Unavailable => None,
+        }
+    }
+
+    /// Returns the absolute position of the [`Cursor`], if it is over the
+    /// given bounds.
+    pub fn position_over(self, bounds: Rectangle) -> Option<Point> {
+        self.position()
+            .filter(|position| bounds.contains(*position))
+    }
+
+    /// Returns the position of the [`Cursor`] relative to the given bounds,
+    /// if it is over them.
+    pub fn position_in(self, bounds: Rectangle) -> Option<Point> {
+        self.position_over(bounds).map(|position| {
+            Point::new(position.x - bounds.x, position.y - bounds.y)
+        })
+    }
+
+    /// Returns whether the [`Cursor`] is currently over the given bounds.
+    pub fn is_over(self, bounds: Rectangle) -> bool {
+        self.position_over(bounds).is_some()
+    }
+}
+
+impl Default for Cursor {
+    fn default() -> Cursor {
+        Cursor::Unavailable
+    }
+}
+
+/// A handle to a custom cursor icon made of RGBA pixels.
+///
+/// Handles are cheap to clone and compare, since cursors are usually
+/// returned from every draw. Clones share the same pixels, so creating a
+/// [`Handle`] once and reusing it avoids copying them.
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    pixels: Arc<[u8]>,
+    width: u32,
+    height: u32,
+    hotspot: (u32, u32),
+}
+
+impl Handle {
+    /// Creates a custom cursor [`Handle`] from its RGBA pixels, its
+    /// dimensions and its hotspot; the pixel of the icon that points at the
+    /// position of the cursor.
+    ///
+    /// # Panics
+    /// Panics if the amount of pixels does not match the dimensions or if the
+    /// hotspot is out of the icon.
+    pub fn from_rgba(
+        pixels: impl Into<Vec<u8>>,
+        width: u32,
+        height: u32,
+        hotspot: (u32, u32),
+    ) -> Handle {
+        let pixels = pixels.into();
+
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize * 4,
+            "the pixels of a cursor must be {}x{} RGBA values",
+            width,
+            height,
+        );
+        assert!(
+            hotspot.0 < width && hotspot.1 < height,
+            "the hotspot of a cursor must be inside of it",
+        );
+
+        let mut hasher = DefaultHasher::new();
+        (&pixels, width, height, hotspot).hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            pixels: Arc::from(pixels),
+            width,
+            height,
+            hotspot,
+        }
+    }
+
+    /// Returns the identifier of the [`Handle`].
+    ///
+    /// Handles created from the same icon share it. Different icons may
+    /// share it too, so it does not replace comparing the handles.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the RGBA pixels of the cursor icon.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Returns the width of the cursor icon.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the cursor icon.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the hotspot of the cursor icon.
+    pub fn hotspot(&self) -> (u32, u32) {
+        self.hotspot
+    }
+}
+
+impl PartialEq for Handle {
+    fn eq(&self, other: &Handle) -> bool {
+        // Clones share their pixels, so comparing them is usually cheap
+        self.id == other.id
+            && self.width == other.width
+            && self.height == other.height
+            && self.hotspot == other.hotspot
+            && (Arc::ptr_eq(&self.pixels, &other.pixels)
+                || self.pixels == other.pixels)
+    }
+}
+
+impl Eq for Handle {}
+
+impl PartialOrd for Handle {
+    fn partial_cmp(&self, other: &Handle) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Handle {
+    fn cmp(&self, other: &Handle) -> Ordering {
+        self.id
+            .cmp(&other.id)
+            .then_with(|| self.width.cmp(&other.width))
+            .then_with(|| self.height.cmp(&other.height))
+            .then_with(|| self.hotspot.cmp(&other.hotspot))
+            .then_with(|| self.pixels.cmp(&other.pixels))
+    }
+}
+
+impl Hash for Handle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Size;
+
+    #[test]
+    fn unavailable_cursor_is_never_over_anything() {
+        let bounds =
+            Rectangle::new(Point::new(-20.0, -20.0), Size::new(40.0, 40.0));
+
+        assert!(!Cursor::Unavailable.is_over(bounds));
+        assert_eq!(Cursor::Unavailable.position_over(bounds), None);
+
+        let cursor = Cursor::Available(Point::new(-10.0, 5.0));
+
+        assert!(cursor.is_over(bounds));
+        assert_eq!(cursor.position_in(bounds), Some(Point::new(10.0, 25.0)));
+    }
+
+    #[test]
+    fn handles_of_the_same_icon_are_equal() {
+        let pixels = vec![255; 2 * 2 * 4];
+
+        let a = Handle::from_rgba(pixels.clone(), 2, 2, (0, 0));
+        let b = Handle::from_rgba(pixels.clone(), 2, 2, (0, 0));
+        let c = Handle::from_rgba(pixels, 2, 2, (1, 1));
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.clone(), a);
+    }
+
+    #[test]
+    fn handles_with_the_same_id_but_different_icons_are_different() {
+        let a = Handle::from_rgba(vec![255; 4], 1, 1, (0, 0));
+        let b = Handle {
+            pixels: Arc::from(vec![0; 4]),
+            ..a.clone()
+        };
+
+        assert_eq!(a.id(), b.id());
+        assert_ne!(a, b);
+        assert_ne!(a.cmp(&b), Ordering::Equal);
+    }
+}
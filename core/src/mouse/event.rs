@@ -28,11 +28,28 @@ pub enum Event {
     /// A mouse button was released.
     ButtonReleased(Button),
 
+    /// A mouse button was pressed twice in quick succession, at the given
+    /// position.
+    ///
+    /// It replaces the [`Event::ButtonPressed`] of the second click. A
+    /// `UserInterface` delivers that [`Event::ButtonPressed`] to its widgets
+    /// anyway when none of them handles the double click.
+    DoubleClicked(Button, Point),
+
     /// The mouse wheel was scrolled.
     WheelScrolled {
         /// The scroll movement.
         delta: ScrollDelta,
     },
+
+    /// The mouse wheel, or a trackpad, was scrolled horizontally.
+    ///
+    /// It contains the number of horizontal pixels scrolled, with the same
+    /// sign as the `x` of a [`ScrollDelta`], a line being
+    /// [`ScrollDelta::PIXELS_PER_LINE`] pixels. It is produced right after
+    /// every [`Event::WheelScrolled`] with horizontal movement, letting
+    /// widgets handle that movement apart from the vertical one.
+    HorizontalScrolled(f32),
 }
 
 /// A scroll movement.
@@ -54,3 +71,9 @@ pub enum ScrollDelta {
         y: f32,
     },
 }
+
+impl ScrollDelta {
+    /// The number of pixels scrolled by a line of a [`ScrollDelta::Lines`]
+    /// in the built-in widgets.
+    pub const PIXELS_PER_LINE: f32 = 60.0;
+}
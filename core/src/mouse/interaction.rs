@@ -1,5 +1,7 @@
+use crate::mouse::cursor;
+
 /// The interaction of a mouse cursor.
-#[derive(Debug, Eq, PartialEq, Clone, Copy, PartialOrd, Ord)]
+#[derive(Debug, Eq, PartialEq, Clone, PartialOrd, Ord)]
 #[allow(missing_docs)]
 pub enum Interaction {
     Idle,
@@ -11,6 +13,17 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    ZoomIn,
+    ZoomOut,
+    Cell,
+    /// The cursor is hidden, i.e. while drawing with a pen.
+    Hidden,
+    /// A custom cursor icon.
+    ///
+    /// Shells that cannot display custom icons fall back to the default
+    /// cursor. For now, this is the case of every native shell, since their
+    /// version of `winit` does not support custom cursors.
+    Custom(cursor::Handle),
 }
 
 impl Default for Interaction {
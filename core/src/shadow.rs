@@ -0,0 +1,18 @@
+use crate::{Color, Vector};
+
+/// A drop shadow cast by the box of some element, like a card or a button.
+///
+/// Shadows are drawn beneath the element and never affect its layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// The [`Color`] of the shadow.
+    pub color: Color,
+
+    /// The offset of the shadow from the element.
+    pub offset: Vector,
+
+    /// The blur radius of the shadow.
+    ///
+    /// A blur radius of zero produces a crisp shadow.
+    pub blur_radius: f32,
+}
@@ -1,5 +1,39 @@
 //! Draw and interact with text.
-use crate::Vector;
+use crate::{Color, Vector};
+
+/// A shadow drawn behind some text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextShadow {
+    /// The offset of the shadow from the text.
+    pub offset: Vector,
+
+    /// The blur radius of the shadow.
+    ///
+    /// A shadow with no blur is a sharp copy of the text.
+    pub blur: f32,
+
+    /// The [`Color`] of the shadow.
+    pub color: Color,
+}
+
+/// The direction in which the lines of some text flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WritingMode {
+    /// Lines flow from left to right, and stack from top to bottom.
+    Horizontal,
+
+    /// Lines flow from top to bottom, and stack from right to left.
+    ///
+    /// It is the usual vertical layout of Chinese, Japanese, and Korean
+    /// text.
+    VerticalRL,
+}
+
+impl Default for WritingMode {
+    fn default() -> Self {
+        WritingMode::Horizontal
+    }
+}
 
 /// The result of hit testing on text.
 #[derive(Debug, Clone, Copy, PartialEq)]
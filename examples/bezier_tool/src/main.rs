@@ -1,6 +1,12 @@
 //! This example showcases an interactive `Canvas` for drawing Bézier curves.
+//!
+//! The points of the curves can be dragged around, even out of the canvas.
+//! While the eraser cursor is toggled, the canvas asks for a custom cursor
+//! icon. The version of `winit` used by the native shells cannot display
+//! custom icons yet, so they fall back to the default cursor for now.
 use iced::{
-    button, Alignment, Button, Column, Element, Length, Sandbox, Settings, Text,
+    button, mouse, Alignment, Button, Checkbox, Column, Element, Length, Row,
+    Sandbox, Settings, Text,
 };
 
 pub fn main() -> iced::Result {
@@ -10,16 +16,18 @@ pub fn main() -> iced::Result {
     })
 }
 
-#[derive(Default)]
 struct Example {
     bezier: bezier::State,
     curves: Vec<bezier::Curve>,
     button_state: button::State,
+    eraser: mouse::cursor::Handle,
+    is_eraser_shown: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
 enum Message {
-    AddCurve(bezier::Curve),
+    Bezier(bezier::Message),
+    EraserToggled(bool),
     Clear,
 }
 
@@ -27,7 +35,13 @@ impl Sandbox for Example {
     type Message = Message;
 
     fn new() -> Self {
-        Example::default()
+        Example {
+            bezier: bezier::State::default(),
+            curves: Vec::new(),
+            button_state: button::State::new(),
+            eraser: eraser_cursor(),
+            is_eraser_shown: false,
+        }
     }
 
     fn title(&self) -> String {
@@ -36,10 +50,17 @@ impl Sandbox for Example {
 
     fn update(&mut self, message: Message) {
         match message {
-            Message::AddCurve(curve) => {
+            Message::Bezier(bezier::Message::Added(curve)) => {
                 self.curves.push(curve);
                 self.bezier.request_redraw();
             }
+            Message::Bezier(bezier::Message::Moved { index, curve }) => {
+                self.curves[index] = curve;
+                self.bezier.request_redraw();
+            }
+            Message::EraserToggled(is_eraser_shown) => {
+                self.is_eraser_shown = is_eraser_shown;
+            }
             Message::Clear => {
                 self.bezier = bezier::State::default();
                 self.curves.clear();
@@ -57,26 +78,73 @@ impl Sandbox for Example {
                     .width(Length::Shrink)
                     .size(50),
             )
-            .push(self.bezier.view(&self.curves).map(Message::AddCurve))
             .push(
-                Button::new(&mut self.button_state, Text::new("Clear"))
-                    .padding(8)
-                    .on_press(Message::Clear),
+                self.bezier
+                    .view(
+                        &self.curves,
+                        Some(self.eraser.clone())
+                            .filter(|_| self.is_eraser_shown),
+                    )
+                    .map(Message::Bezier),
+            )
+            .push(
+                Row::new()
+                    .spacing(20)
+                    .align_items(Alignment::Center)
+                    .push(Checkbox::new(
+                        self.is_eraser_shown,
+                        "Eraser cursor",
+                        Message::EraserToggled,
+                    ))
+                    .push(
+                        Button::new(&mut self.button_state, Text::new("Clear"))
+                            .padding(8)
+                            .on_press(Message::Clear),
+                    ),
             )
             .into()
     }
 }
 
+/// Creates a 16x16 cursor icon of a hollow square, pointing at its center.
+fn eraser_cursor() -> mouse::cursor::Handle {
+    const SIZE: u32 = 16;
+
+    let pixels = (0..SIZE * SIZE)
+        .flat_map(|i| {
+            let (x, y) = (i % SIZE, i / SIZE);
+            let is_edge = x == 0 || y == 0 || x == SIZE - 1 || y == SIZE - 1;
+
+            if is_edge {
+                [0, 0, 0, 255]
+            } else {
+                [255, 255, 255, 128]
+            }
+        })
+        .collect::<Vec<u8>>();
+
+    mouse::cursor::Handle::from_rgba(pixels, SIZE, SIZE, (SIZE / 2, SIZE / 2))
+}
+
 mod bezier {
     use iced::{
         canvas::event::{self, Event},
         canvas::{self, Canvas, Cursor, Frame, Geometry, Path, Stroke},
-        mouse, Element, Length, Point, Rectangle,
+        mouse, Color, Element, Length, Point, Rectangle,
     };
 
+    /// The distance from a point of a curve where it can be grabbed.
+    const GRAB_DISTANCE: f32 = 8.0;
+
+    #[derive(Debug, Clone, Copy)]
+    pub enum Message {
+        Added(Curve),
+        Moved { index: usize, curve: Curve },
+    }
+
     #[derive(Default)]
     pub struct State {
-        pending: Option<Pending>,
+        canvas: canvas::State<Interaction>,
         cache: canvas::Cache,
     }
 
@@ -84,11 +152,14 @@ mod bezier {
         pub fn view<'a>(
             &'a mut self,
             curves: &'a [Curve],
-        ) -> Element<'a, Curve> {
+            cursor: Option<mouse::cursor::Handle>,
+        ) -> Element<'a, Message> {
             Canvas::new(Bezier {
-                state: self,
+                cache: &self.cache,
                 curves,
+                cursor,
             })
+            .state(&mut self.canvas)
             .width(Length::Fill)
             .height(Length::Fill)
             .into()
@@ -100,17 +171,55 @@ mod bezier {
     }
 
     struct Bezier<'a> {
-        state: &'a mut State,
+        cache: &'a canvas::Cache,
         curves: &'a [Curve],
+        cursor: Option<mouse::cursor::Handle>,
+    }
+
+    /// The interaction of the user with a [`Bezier`] canvas.
+    #[derive(Debug, Clone, Copy)]
+    pub enum Interaction {
+        None,
+        Drawing(Pending),
+        Dragging { index: usize, point: Handle },
+    }
+
+    impl Default for Interaction {
+        fn default() -> Self {
+            Interaction::None
+        }
     }
 
-    impl<'a> canvas::Program<Curve> for Bezier<'a> {
+    impl<'a> Bezier<'a> {
+        fn grab(&self, position: Point) -> Option<(usize, Handle)> {
+            self.curves
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(index, curve)| {
+                    Handle::ALL
+                        .iter()
+                        .find(|handle| {
+                            handle.position(curve).distance(position)
+                                <= GRAB_DISTANCE
+                        })
+                        .map(|handle| (index, *handle))
+                })
+        }
+    }
+
+    impl<'a> canvas::Program<Message> for Bezier<'a> {
+        type State = Interaction;
+
         fn update(
             &mut self,
+            interaction: &mut Interaction,
             event: Event,
             bounds: Rectangle,
             cursor: Cursor,
-        ) -> (event::Status, Option<Curve>) {
+        ) -> (event::Status, Option<Message>) {
+            // The cursor stays available out of bounds while dragging, since
+            // the mouse is captured
             let cursor_position =
                 if let Some(position) = cursor.position_in(&bounds) {
                     position
@@ -118,49 +227,79 @@ mod bezier {
                     return (event::Status::Ignored, None);
                 };
 
-            match event {
-                Event::Mouse(mouse_event) => {
-                    let message = match mouse_event {
-                        mouse::Event::ButtonPressed(mouse::Button::Left) => {
-                            match self.state.pending {
-                                None => {
-                                    self.state.pending = Some(Pending::One {
-                                        from: cursor_position,
-                                    });
-
-                                    None
-                                }
-                                Some(Pending::One { from }) => {
-                                    self.state.pending = Some(Pending::Two {
-                                        from,
-                                        to: cursor_position,
-                                    });
-
-                                    None
-                                }
-                                Some(Pending::Two { from, to }) => {
-                                    self.state.pending = None;
-
-                                    Some(Curve {
-                                        from,
-                                        to,
-                                        control: cursor_position,
-                                    })
-                                }
-                            }
+            let mouse_event = match event {
+                Event::Mouse(mouse_event) => mouse_event,
+                _ => return (event::Status::Ignored, None),
+            };
+
+            let message = match (mouse_event, *interaction) {
+                (
+                    mouse::Event::ButtonPressed(mouse::Button::Left),
+                    Interaction::None,
+                ) => {
+                    *interaction = match self.grab(cursor_position) {
+                        Some((index, point)) => {
+                            Interaction::Dragging { index, point }
                         }
-                        _ => None,
+                        None => Interaction::Drawing(Pending::One {
+                            from: cursor_position,
+                        }),
                     };
 
-                    (event::Status::Captured, message)
+                    None
                 }
-                _ => (event::Status::Ignored, None),
-            }
+                (
+                    mouse::Event::ButtonPressed(mouse::Button::Left),
+                    Interaction::Drawing(Pending::One { from }),
+                ) => {
+                    *interaction = Interaction::Drawing(Pending::Two {
+                        from,
+                        to: cursor_position,
+                    });
+
+                    None
+                }
+                (
+                    mouse::Event::ButtonPressed(mouse::Button::Left),
+                    Interaction::Drawing(Pending::Two { from, to }),
+                ) => {
+                    *interaction = Interaction::None;
+
+                    Some(Message::Added(Curve {
+                        from,
+                        to,
+                        control: cursor_position,
+                    }))
+                }
+                (
+                    mouse::Event::CursorMoved { .. },
+                    Interaction::Dragging { index, point },
+                ) => Some(Message::Moved {
+                    index,
+                    curve: point.move_to(self.curves[index], cursor_position),
+                }),
+                (
+                    mouse::Event::ButtonReleased(mouse::Button::Left),
+                    Interaction::Dragging { .. },
+                ) => {
+                    *interaction = Interaction::None;
+
+                    None
+                }
+                _ => None,
+            };
+
+            (event::Status::Captured, message)
         }
 
-        fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry> {
+        fn draw(
+            &self,
+            interaction: &Interaction,
+            bounds: Rectangle,
+            cursor: Cursor,
+        ) -> Vec<Geometry> {
             let content =
-                self.state.cache.draw(bounds.size(), |frame: &mut Frame| {
+                self.cache.draw(bounds.size(), |frame: &mut Frame| {
                     Curve::draw_all(self.curves, frame);
 
                     frame.stroke(
@@ -169,7 +308,7 @@ mod bezier {
                     );
                 });
 
-            if let Some(pending) = &self.state.pending {
+            if let Interaction::Drawing(pending) = interaction {
                 let pending_curve = pending.draw(bounds, cursor);
 
                 vec![content, pending_curve]
@@ -180,13 +319,23 @@ mod bezier {
 
         fn mouse_interaction(
             &self,
+            interaction: &Interaction,
             bounds: Rectangle,
             cursor: Cursor,
         ) -> mouse::Interaction {
-            if cursor.is_over(&bounds) {
-                mouse::Interaction::Crosshair
-            } else {
-                mouse::Interaction::default()
+            if let Interaction::Dragging { .. } = interaction {
+                return mouse::Interaction::Grabbing;
+            }
+
+            match cursor.position_in(&bounds) {
+                Some(position) if self.grab(position).is_some() => {
+                    mouse::Interaction::Grab
+                }
+                Some(_) => match &self.cursor {
+                    Some(cursor) => mouse::Interaction::Custom(cursor.clone()),
+                    None => mouse::Interaction::Crosshair,
+                },
+                None => mouse::Interaction::default(),
             }
         }
     }
@@ -208,11 +357,58 @@ mod bezier {
             });
 
             frame.stroke(&curves, Stroke::default().with_width(2.0));
+
+            let handles = Path::new(|p| {
+                for curve in curves {
+                    for handle in Handle::ALL.iter() {
+                        p.circle(handle.position(curve), 3.0);
+                    }
+                }
+            });
+
+            frame.fill(&handles, Color::from_rgb(0.3, 0.4, 0.8));
+        }
+    }
+
+    /// One of the points defining a [`Curve`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Handle {
+        From,
+        To,
+        Control,
+    }
+
+    impl Handle {
+        const ALL: [Handle; 3] = [Handle::From, Handle::To, Handle::Control];
+
+        fn position(self, curve: &Curve) -> Point {
+            match self {
+                Handle::From => curve.from,
+                Handle::To => curve.to,
+                Handle::Control => curve.control,
+            }
+        }
+
+        fn move_to(self, curve: Curve, position: Point) -> Curve {
+            match self {
+                Handle::From => Curve {
+                    from: position,
+                    ..curve
+                },
+                Handle::To => Curve {
+                    to: position,
+                    ..curve
+                },
+                Handle::Control => Curve {
+                    control: position,
+                    ..curve
+                },
+            }
         }
     }
 
     #[derive(Debug, Clone, Copy)]
-    enum Pending {
+    pub enum Pending {
         One { from: Point },
         Two { from: Point, to: Point },
     }
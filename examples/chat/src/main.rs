@@ -0,0 +1,82 @@
+use iced::scrollable::{self, Anchor};
+use iced::time::{self, Instant};
+use iced::{
+    executor, Application, Command, Container, Element, Length, Scrollable,
+    Settings, Subscription, Text,
+};
+use std::time::Duration;
+
+pub fn main() -> iced::Result {
+    Chat::run(Settings::default())
+}
+
+const AUTHORS: [&str; 3] = ["Alice", "Bob", "Carol"];
+
+struct Chat {
+    scroll: scrollable::State,
+    messages: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Received(Instant),
+}
+
+impl Application for Chat {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Chat, Command<Message>) {
+        (
+            Chat {
+                scroll: scrollable::State::new(),
+                messages: Vec::new(),
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Chat - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Received(_) => {
+                let i = self.messages.len();
+
+                self.messages.push(format!(
+                    "{}: message #{}",
+                    AUTHORS[i % AUTHORS.len()],
+                    i + 1
+                ));
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        time::every(Duration::from_millis(500)).map(Message::Received)
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        // The newest messages stay in view while they stream in, unless the
+        // history is being read
+        let messages = self.messages.iter().fold(
+            Scrollable::new(&mut self.scroll)
+                .anchor(Anchor::End)
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .padding(20)
+                .spacing(10),
+            |messages, message| messages.push(Text::new(message.as_str())),
+        );
+
+        Container::new(messages)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
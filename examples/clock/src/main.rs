@@ -56,7 +56,7 @@ impl Application for Clock {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        time::every(std::time::Duration::from_millis(500))
+        time::every_aligned(std::time::Duration::from_secs(1))
             .map(|_| Message::Tick(chrono::Local::now()))
     }
 
@@ -76,7 +76,14 @@ impl Application for Clock {
 }
 
 impl canvas::Program<Message> for Clock {
-    fn draw(&self, bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry> {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &(),
+        bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Vec<Geometry> {
         use chrono::Timelike;
 
         let clock = self.clock.draw(bounds.size(), |frame| {
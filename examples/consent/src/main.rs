@@ -0,0 +1,131 @@
+use iced::{
+    button, checkbox, Button, Checkbox, Color, Column, Container, Element,
+    Length, Sandbox, Settings, Text,
+};
+
+pub fn main() -> iced::Result {
+    Consent::run(Settings::default())
+}
+
+const BOX_SIZE: u16 = 20;
+const SPACING: u16 = 12;
+
+#[derive(Default)]
+struct Consent {
+    accepted: bool,
+    show_terms: bool,
+    terms: button::State,
+    submit: button::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Accept(bool),
+    ToggleTerms,
+    Submit,
+}
+
+impl Sandbox for Consent {
+    type Message = Message;
+
+    fn new() -> Self {
+        Consent::default()
+    }
+
+    fn title(&self) -> String {
+        String::from("Consent - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Accept(accepted) => {
+                self.accepted = accepted;
+            }
+            Message::ToggleTerms => {
+                self.show_terms = !self.show_terms;
+            }
+            Message::Submit => {
+                self.accepted = false;
+                self.show_terms = false;
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let checkbox = Checkbox::new(
+            self.accepted,
+            "I have read the terms of service and I agree to the processing \
+            of my personal data for the purposes described in them.",
+            Message::Accept,
+        )
+        .size(BOX_SIZE)
+        .spacing(SPACING)
+        .width(Length::Fill)
+        .label_position(checkbox::LabelPosition::After)
+        .toggle_on_label(false);
+
+        // The link sits right below the label, where clicks do not toggle
+        // the checkbox
+        let link = Button::new(
+            &mut self.terms,
+            Text::new(if self.show_terms {
+                "Hide the terms of service"
+            } else {
+                "Read the terms of service"
+            }),
+        )
+        .padding(0)
+        .style(Link)
+        .on_press(Message::ToggleTerms);
+
+        let mut content = Column::new()
+            .spacing(20)
+            .max_width(400)
+            .push(Text::new("Create your account").size(30))
+            .push(Column::new().spacing(5).push(checkbox).push(
+                Container::new(link).padding([0, 0, 0, BOX_SIZE + SPACING]),
+            ));
+
+        if self.show_terms {
+            content = content.push(
+                Text::new(
+                    "Your data is only used to provide the service, and it is \
+                    never shared with third parties.",
+                )
+                .size(16),
+            );
+        }
+
+        let mut submit = Button::new(&mut self.submit, Text::new("Sign up"))
+            .padding([10, 20]);
+
+        if self.accepted {
+            submit = submit.on_press(Message::Submit);
+        }
+
+        Container::new(content.push(submit))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+struct Link;
+
+impl button::StyleSheet for Link {
+    fn active(&self) -> button::Style {
+        button::Style {
+            text_color: Color::from_rgb(0.2, 0.4, 0.9),
+            ..button::Style::default()
+        }
+    }
+
+    fn hovered(&self) -> button::Style {
+        button::Style {
+            text_color: Color::from_rgb(0.1, 0.2, 0.6),
+            ..self.active()
+        }
+    }
+}
@@ -11,8 +11,8 @@ mod circle {
     // implemented by `iced_wgpu` and other renderers.
     use iced_graphics::{Backend, Defaults, Primitive, Renderer};
     use iced_native::{
-        layout, mouse, Background, Color, Element, Hasher, Layout, Length,
-        Point, Rectangle, Size, Widget,
+        layout, mouse, Background, BorderAlignment, BorderStyle, BorderWidth,
+        Color, Element, Hasher, Layout, Length, Rectangle, Size, Widget,
     };
 
     pub struct Circle {
@@ -56,7 +56,7 @@ mod circle {
             _renderer: &mut Renderer<B>,
             _defaults: &Defaults,
             layout: Layout<'_>,
-            _cursor_position: Point,
+            _cursor: mouse::Cursor,
             _viewport: &Rectangle,
         ) -> (Primitive<B>, mouse::Interaction) {
             (
@@ -64,8 +64,12 @@ mod circle {
                     bounds: layout.bounds(),
                     background: Background::Color(Color::BLACK),
                     border_radius: self.radius,
-                    border_width: 0.0,
+                    border_width: BorderWidth::ZERO,
                     border_color: Color::TRANSPARENT,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
                 },
                 mouse::Interaction::default(),
             )
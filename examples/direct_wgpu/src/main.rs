@@ -1,6 +1,6 @@
 //! This example showcases a simple native custom widget that draws a circle.
 mod triangle {
-    use std::{cell::RefCell, sync::Arc};
+    use std::{cell::RefCell, rc::Rc, sync::Arc};
 
     // For now, to implement a custom native widget you will need to add
     // `iced_native` and `iced_wgpu` to your dependencies.
@@ -13,8 +13,7 @@ mod triangle {
     // implemented by `iced_wgpu` and other renderers.
     use iced_graphics::Primitive;
     use iced_native::{
-        layout, mouse, Element, Hasher, Layout, Length, Point, Rectangle, Size,
-        Widget,
+        layout, mouse, Element, Hasher, Layout, Length, Rectangle, Size, Widget,
     };
     use iced_wgpu::{
         wgpu::{self, util::DeviceExt},
@@ -43,6 +42,27 @@ mod triangle {
         pipeline: wgpu::RenderPipeline,
         bind_group: wgpu::BindGroup,
         uniform_buffer: wgpu::Buffer,
+        animated_pipeline: wgpu::RenderPipeline,
+        animated_bind_group: wgpu::BindGroup,
+        simulation: Rc<Simulation>,
+    }
+
+    // The compute work animating the vertices of the triangle.
+    //
+    // It runs right before the render bundle of the triangle, so the vertices
+    // it writes to the storage buffer are the ones drawn in the same frame.
+    #[derive(Debug)]
+    struct Simulation {
+        pipeline: wgpu::ComputePipeline,
+        bind_group: wgpu::BindGroup,
+    }
+
+    impl iced_wgpu::ComputeStage for Simulation {
+        fn dispatch<'a>(&'a self, pass: &mut wgpu::ComputePass<'a>) {
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &self.bind_group, &[]);
+            pass.dispatch(1, 1, 1);
+        }
     }
 
     impl State {
@@ -103,61 +123,193 @@ mod triangle {
                     label: Some("Uniform Buffer Bind Group"),
                 });
 
-            let pipeline_layout = device.create_pipeline_layout(
+            let pipeline =
+                create_render_pipeline(&device, &shader, &bind_group_layout);
+
+            // The vertices animated by the compute shader live in a storage
+            // buffer, which the animated render pipeline reads them from.
+            let vertex_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Vertex Storage Buffer"),
+                size: 3 * 4 * std::mem::size_of::<f32>() as u64,
+                usage: wgpu::BufferUsages::STORAGE,
+                mapped_at_creation: false,
+            });
+
+            let compute_shader =
+                device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+                    label: Some("compute shader"),
+                    source: wgpu::ShaderSource::Wgsl(
+                        include_str!("shader/compute.wgsl").into(),
+                    ),
+                });
+
+            let compute_bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStages::COMPUTE,
+                            ty: wgpu::BindingType::Buffer {
+                                ty: wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStages::COMPUTE,
+                            ty: wgpu::BindingType::Buffer {
+                                ty: wgpu::BufferBindingType::Storage {
+                                    read_only: false,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                    label: Some("Compute Bind Group Layout"),
+                },
+            );
+            let compute_bind_group =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    layout: &compute_bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: uniform_buffer.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: vertex_buffer.as_entire_binding(),
+                        },
+                    ],
+                    label: Some("Compute Bind Group"),
+                });
+
+            let compute_pipeline_layout = device.create_pipeline_layout(
                 &wgpu::PipelineLayoutDescriptor {
                     label: None,
                     push_constant_ranges: &[],
-                    bind_group_layouts: &[&bind_group_layout],
+                    bind_group_layouts: &[&compute_bind_group_layout],
                 },
             );
-
-            let pipeline = device.create_render_pipeline(
-                &wgpu::RenderPipelineDescriptor {
+            let compute_pipeline = device.create_compute_pipeline(
+                &wgpu::ComputePipelineDescriptor {
                     label: None,
-                    layout: Some(&pipeline_layout),
-                    vertex: wgpu::VertexState {
-                        module: &shader,
-                        entry_point: "main",
-                        buffers: &[],
-                    },
-                    fragment: Some(wgpu::FragmentState {
-                        module: &shader,
-                        entry_point: "main",
-                        targets: &[wgpu::ColorTargetState {
-                            format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                            blend: Some(wgpu::BlendState {
-                                color: wgpu::BlendComponent::REPLACE,
-                                alpha: wgpu::BlendComponent::REPLACE,
-                            }),
-                            write_mask: wgpu::ColorWrites::ALL,
-                        }],
-                    }),
-                    primitive: wgpu::PrimitiveState {
-                        topology: wgpu::PrimitiveTopology::TriangleList,
-                        front_face: wgpu::FrontFace::Ccw,
-                        ..Default::default()
-                    },
-                    depth_stencil: None,
-                    multisample: wgpu::MultisampleState {
-                        count: 1,
-                        mask: !0,
-                        alpha_to_coverage_enabled: false,
-                    },
+                    layout: Some(&compute_pipeline_layout),
+                    module: &compute_shader,
+                    entry_point: "main",
                 },
             );
 
+            let animated_shader =
+                device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+                    label: Some("animated shader"),
+                    source: wgpu::ShaderSource::Wgsl(
+                        include_str!("shader/animated.wgsl").into(),
+                    ),
+                });
+
+            let animated_bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    entries: &[wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage {
+                                read_only: true,
+                            },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    }],
+                    label: Some("Vertex Storage Bind Group Layout"),
+                },
+            );
+            let animated_bind_group =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    layout: &animated_bind_group_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: vertex_buffer.as_entire_binding(),
+                    }],
+                    label: Some("Vertex Storage Bind Group"),
+                });
+
+            let animated_pipeline = create_render_pipeline(
+                &device,
+                &animated_shader,
+                &animated_bind_group_layout,
+            );
+
             Self {
                 _device: device,
                 queue,
                 pipeline,
                 bind_group,
                 uniform_buffer,
+                animated_pipeline,
+                animated_bind_group,
+                simulation: Rc::new(Simulation {
+                    pipeline: compute_pipeline,
+                    bind_group: compute_bind_group,
+                }),
             }
         }
     }
 
+    fn create_render_pipeline(
+        device: &wgpu::Device,
+        shader: &wgpu::ShaderModule,
+        bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> wgpu::RenderPipeline {
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: None,
+                push_constant_ranges: &[],
+                bind_group_layouts: &[bind_group_layout],
+            });
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "main",
+                targets: &[wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent::REPLACE,
+                        alpha: wgpu::BlendComponent::REPLACE,
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                }],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                front_face: wgpu::FrontFace::Ccw,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+        })
+    }
+
     pub struct Triangle<'a> {
         state: &'a State,
+        is_animated: bool,
     }
 
     impl<'a> Triangle<'a> {
@@ -177,7 +329,17 @@ mod triangle {
                     );
                 }
             }
-            Self { state }
+            Self {
+                state,
+                is_animated: false,
+            }
+        }
+
+        // Animates the vertices of the triangle with a compute shader,
+        // instead of computing them in the vertex shader.
+        pub fn animated(mut self, is_animated: bool) -> Self {
+            self.is_animated = is_animated;
+            self
         }
     }
 
@@ -212,7 +374,7 @@ mod triangle {
             renderer: &mut Renderer,
             _defaults: &<Renderer as iced_native::Renderer>::Defaults,
             layout: Layout<'_>,
-            _cursor_position: Point,
+            _cursor: mouse::Cursor,
             _viewport: &Rectangle,
         ) -> (Primitive<iced_wgpu::Backend>, mouse::Interaction) {
             let backend = renderer.backend();
@@ -234,7 +396,11 @@ mod triangle {
                 println!("Pipeline ready!");
                 r
             });
-            let pipeline = &inner.pipeline;
+            let (pipeline, bind_group) = if self.is_animated {
+                (&inner.animated_pipeline, &inner.animated_bind_group)
+            } else {
+                (&inner.pipeline, &inner.bind_group)
+            };
             let bounds = layout.bounds();
             let mut encoder = device.create_render_bundle_encoder(
                 &wgpu::RenderBundleEncoderDescriptor {
@@ -245,18 +411,21 @@ mod triangle {
                 },
             );
             encoder.set_pipeline(&*pipeline);
-            encoder.set_bind_group(0, &inner.bind_group, &[]);
+            encoder.set_bind_group(0, bind_group, &[]);
             encoder.draw(0..3, 0..1);
             let bundle = encoder.finish(&wgpu::RenderBundleDescriptor {
                 label: Some("Bundle"),
             });
-            (
-                // The Custom primitive takes a bundle and a rectangle bounds.
-                Primitive::Custom(iced_wgpu::DirectWgpuJob::new(
-                    bundle, bounds,
-                )),
-                mouse::Interaction::default(),
-            )
+            // The Custom primitive takes a bundle and a rectangle bounds.
+            let mut job = iced_wgpu::DirectWgpuJob::new(bundle, bounds);
+
+            if self.is_animated {
+                // The compute stage writes the vertices the bundle reads,
+                // right before the bundle is executed.
+                job = job.with_compute_rc(inner.simulation.clone());
+            }
+
+            (Primitive::Custom(job), mouse::Interaction::default())
         }
     }
 
@@ -269,7 +438,7 @@ mod triangle {
 
 use iced::{executor, time, Application, Command, Settings};
 use iced_native::{Alignment, Element, Length};
-use iced_wgpu::{slider, Column, Container, Slider, Text};
+use iced_wgpu::{slider, Checkbox, Column, Container, Slider, Text};
 use triangle::Triangle;
 
 pub fn main() -> iced::Result {
@@ -281,6 +450,7 @@ struct Example {
     size: f32,
     speed: f32,
     angle: f32,
+    is_animated: bool,
     size_slider: slider::State,
     speed_slider: slider::State,
     triangle_state: triangle::State,
@@ -290,6 +460,7 @@ struct Example {
 enum Message {
     RadiusChanged(f32),
     SpeedChanged(f32),
+    AnimatedToggled(bool),
     TimeTick,
 }
 
@@ -304,6 +475,7 @@ impl Application for Example {
                 size: 50.0,
                 speed: 5.0,
                 angle: 0.0,
+                is_animated: false,
                 size_slider: slider::State::new(),
                 speed_slider: slider::State::new(),
                 triangle_state: triangle::State::new(),
@@ -324,6 +496,9 @@ impl Application for Example {
             Message::SpeedChanged(speed) => {
                 self.speed = speed;
             }
+            Message::AnimatedToggled(is_animated) => {
+                self.is_animated = is_animated;
+            }
             Message::TimeTick => {
                 self.angle += self.speed / 100.0;
             }
@@ -343,11 +518,10 @@ impl Application for Example {
             .spacing(20)
             .max_width(500)
             .align_items(Alignment::Center)
-            .push(Triangle::new(
-                self.size,
-                self.angle,
-                &mut self.triangle_state,
-            ))
+            .push(
+                Triangle::new(self.size, self.angle, &mut self.triangle_state)
+                    .animated(self.is_animated),
+            )
             .push(Text::new(format!("Size: {:.2}", self.size)))
             .push(
                 Slider::new(
@@ -367,7 +541,12 @@ impl Application for Example {
                     Message::SpeedChanged,
                 )
                 .step(0.01),
-            );
+            )
+            .push(Checkbox::new(
+                self.is_animated,
+                "Animate vertices with a compute shader",
+                Message::AnimatedToggled,
+            ));
 
         Container::new(content)
             .width(Length::Fill)
@@ -0,0 +1,35 @@
+// Vertex shader
+
+[[block]]
+struct Vertices {
+    positions: array<vec4<f32>, 3>;
+};
+[[group(0), binding(0)]]
+var<storage, read> vertices: Vertices;
+
+struct VertexOutput {
+    [[location(0)]] color: vec3<f32>;
+    [[builtin(position)]] clip_pos: vec4<f32>;
+};
+
+[[stage(vertex)]]
+fn main(
+    [[builtin(vertex_index)]] in_vertex_index: u32,
+) -> VertexOutput {
+    var colors = array<vec3<f32>, 3>(
+        vec3<f32>(1.0, 0.0, 0.0),
+        vec3<f32>(0.0, 1.0, 0.0),
+        vec3<f32>(0.0, 0.0, 1.0)
+    );
+    var out: VertexOutput;
+    out.clip_pos = vertices.positions[in_vertex_index];
+    out.color = colors[in_vertex_index];
+    return out;
+}
+
+// Fragment shader
+
+[[stage(fragment)]]
+fn main(in: VertexOutput) -> [[location(0)]] vec4<f32> {
+    return vec4<f32>(in.color, 1.0);
+}
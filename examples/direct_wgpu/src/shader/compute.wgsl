@@ -0,0 +1,31 @@
+// Compute shader
+
+[[block]]
+struct Uniform {
+    rotation: f32;
+};
+[[group(0), binding(0)]]
+var<uniform> uniform: Uniform;
+
+[[block]]
+struct Vertices {
+    positions: array<vec4<f32>, 3>;
+};
+[[group(0), binding(1)]]
+var<storage, read_write> vertices: Vertices;
+
+[[stage(compute), workgroup_size(3)]]
+fn main(
+    [[builtin(global_invocation_id)]] id: vec3<u32>,
+) {
+    var index = f32(id.x);
+    var angle = uniform.rotation + index * 2.094395102;
+    // Every vertex moves in and out at its own pace
+    var radius = 0.75 + 0.25 * sin(uniform.rotation * 3.0 + index * 2.0);
+    vertices.positions[id.x] = vec4<f32>(
+        radius * cos(angle),
+        radius * sin(angle),
+        0.0,
+        1.0,
+    );
+}
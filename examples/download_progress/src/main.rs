@@ -1,7 +1,8 @@
 use iced::{
     button, executor, Alignment, Application, Button, Column, Command,
-    Container, Element, Length, ProgressBar, Settings, Subscription, Text,
+    Container, Element, Length, ProgressBar, Settings, Text,
 };
+use iced_native::command;
 
 mod download;
 
@@ -20,7 +21,9 @@ struct Example {
 pub enum Message {
     Add,
     Download(usize),
-    DownloadProgressed((usize, download::Progress)),
+    Cancel(usize),
+    DownloadProgressed(usize, f32),
+    DownloadFinished(usize, Result<(), download::Error>),
 }
 
 impl Application for Example {
@@ -52,25 +55,33 @@ impl Application for Example {
             }
             Message::Download(index) => {
                 if let Some(download) = self.downloads.get_mut(index) {
-                    download.start();
+                    return download.start();
                 }
             }
-            Message::DownloadProgressed((id, progress)) => {
+            Message::Cancel(index) => {
+                if let Some(download) = self.downloads.get_mut(index) {
+                    download.cancel();
+                }
+            }
+            Message::DownloadProgressed(id, progress) => {
                 if let Some(download) =
                     self.downloads.iter_mut().find(|download| download.id == id)
                 {
                     download.progress(progress);
                 }
             }
+            Message::DownloadFinished(id, result) => {
+                if let Some(download) =
+                    self.downloads.iter_mut().find(|download| download.id == id)
+                {
+                    download.finish(result);
+                }
+            }
         };
 
         Command::none()
     }
 
-    fn subscription(&self) -> Subscription<Message> {
-        Subscription::batch(self.downloads.iter().map(Download::subscription))
-    }
-
     fn view(&mut self) -> Element<Message> {
         let downloads = self
             .downloads
@@ -103,10 +114,21 @@ struct Download {
 
 #[derive(Debug)]
 enum State {
-    Idle { button: button::State },
-    Downloading { progress: f32 },
-    Finished { button: button::State },
-    Errored { button: button::State },
+    Idle {
+        button: button::State,
+    },
+    Downloading {
+        progress: f32,
+        // Dropping the handle stops the download
+        handle: command::Handle,
+        cancel: button::State,
+    },
+    Finished {
+        button: button::State,
+    },
+    Errored {
+        button: button::State,
+    },
 }
 
 impl Download {
@@ -119,55 +141,68 @@ impl Download {
         }
     }
 
-    pub fn start(&mut self) {
+    pub fn start(&mut self) -> Command<Message> {
         match self.state {
             State::Idle { .. }
             | State::Finished { .. }
             | State::Errored { .. } => {
-                self.state = State::Downloading { progress: 0.0 };
+                let id = self.id;
+
+                let (command, handle) = Command::run_with_progress(
+                    |progress| {
+                        download::file(
+                            "https://speed.hetzner.de/100MB.bin?",
+                            progress,
+                        )
+                    },
+                    move |progress| Message::DownloadProgressed(id, progress),
+                    move |result| Message::DownloadFinished(id, result),
+                )
+                .cancellable();
+
+                self.state = State::Downloading {
+                    progress: 0.0,
+                    handle,
+                    cancel: button::State::new(),
+                };
+
+                command
             }
-            _ => {}
+            _ => Command::none(),
         }
     }
 
-    pub fn progress(&mut self, new_progress: download::Progress) {
-        match &mut self.state {
-            State::Downloading { progress } => match new_progress {
-                download::Progress::Started => {
-                    *progress = 0.0;
-                }
-                download::Progress::Advanced(percentage) => {
-                    *progress = percentage;
-                }
-                download::Progress::Finished => {
-                    self.state = State::Finished {
-                        button: button::State::new(),
-                    }
-                }
-                download::Progress::Errored => {
-                    self.state = State::Errored {
-                        button: button::State::new(),
-                    };
-                }
-            },
-            _ => {}
+    pub fn cancel(&mut self) {
+        if let State::Downloading { .. } = self.state {
+            self.state = State::Idle {
+                button: button::State::new(),
+            };
         }
     }
 
-    pub fn subscription(&self) -> Subscription<Message> {
-        match self.state {
-            State::Downloading { .. } => {
-                download::file(self.id, "https://speed.hetzner.de/100MB.bin?")
-                    .map(Message::DownloadProgressed)
-            }
-            _ => Subscription::none(),
+    pub fn progress(&mut self, new_progress: f32) {
+        if let State::Downloading { progress, .. } = &mut self.state {
+            *progress = new_progress;
+        }
+    }
+
+    pub fn finish(&mut self, result: Result<(), download::Error>) {
+        if let State::Downloading { .. } = self.state {
+            self.state = match result {
+                Ok(()) => State::Finished {
+                    button: button::State::new(),
+                },
+                Err(_) => State::Errored {
+                    button: button::State::new(),
+                },
+            };
         }
     }
 
     pub fn view(&mut self) -> Element<Message> {
         let current_progress = match &self.state {
             State::Idle { .. } => 0.0,
-            State::Downloading { progress } => *progress,
+            State::Downloading { progress, .. } => *progress,
             State::Finished { .. } => 100.0,
             State::Errored { .. } => 0.0,
         };
@@ -189,10 +224,18 @@ impl Download {
                         .on_press(Message::Download(self.id)),
                 )
                 .into(),
-            State::Downloading { .. } => {
-                Text::new(format!("Downloading... {:.2}%", current_progress))
-                    .into()
-            }
+            State::Downloading { cancel, .. } => Column::new()
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .push(Text::new(format!(
+                    "Downloading... {:.2}%",
+                    current_progress
+                )))
+                .push(
+                    Button::new(cancel, Text::new("Cancel"))
+                        .on_press(Message::Cancel(self.id)),
+                )
+                .into(),
             State::Errored { button } => Column::new()
                 .spacing(10)
                 .align_items(Alignment::Center)
@@ -0,0 +1,276 @@
+use iced::{
+    button, executor, keyboard, Alignment, Application, Border, BorderStyle,
+    Button, Color, Column, Command, Container, Element, Length, Row, Settings,
+    Subscription, Text,
+};
+use iced_native::{event, subscription, Event};
+
+pub fn main() -> iced::Result {
+    DragAndDrop::run(Settings::default())
+}
+
+struct DragAndDrop {
+    items: Vec<Item>,
+    dropped: Vec<String>,
+    carried: Option<usize>,
+    focus: Option<usize>,
+    bin: button::State,
+}
+
+struct Item {
+    name: String,
+    state: button::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    PickUp(usize),
+    Drop,
+    FocusNext,
+    FocusPrevious,
+    PressFocused,
+}
+
+impl Application for DragAndDrop {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (DragAndDrop, Command<Message>) {
+        let items = ["Apple", "Banana", "Cherry", "Durian", "Elderberry"]
+            .iter()
+            .map(|name| Item {
+                name: name.to_string(),
+                state: button::State::new(),
+            })
+            .collect();
+
+        (
+            DragAndDrop {
+                items,
+                dropped: Vec::new(),
+                carried: None,
+                focus: None,
+                bin: button::State::new(),
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Drag and drop - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        // The items come first in the focus order, followed by the bin
+        let focusable = self.items.len() + 1;
+
+        match message {
+            Message::PickUp(index) => {
+                self.carried = if self.carried == Some(index) {
+                    None
+                } else {
+                    Some(index)
+                };
+            }
+            Message::Drop => {
+                if let Some(index) = self.carried.take() {
+                    let item = self.items.remove(index);
+
+                    self.dropped.push(item.name);
+                    self.focus =
+                        self.focus.map(|focus| focus.min(self.items.len()));
+                }
+            }
+            Message::FocusNext => {
+                self.focus = Some(match self.focus {
+                    Some(focus) => (focus + 1) % focusable,
+                    None => 0,
+                });
+            }
+            Message::FocusPrevious => {
+                self.focus = Some(match self.focus {
+                    Some(focus) => (focus + focusable - 1) % focusable,
+                    None => focusable - 1,
+                });
+            }
+            Message::PressFocused => match self.focus {
+                Some(focus) if focus < self.items.len() => {
+                    return self.update(Message::PickUp(focus));
+                }
+                Some(_) => {
+                    return self.update(Message::Drop);
+                }
+                None => {}
+            },
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, status| match (event, status) {
+            (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Tab,
+                    modifiers,
+                    ..
+                }),
+                event::Status::Ignored,
+            ) => Some(if modifiers.shift() {
+                Message::FocusPrevious
+            } else {
+                Message::FocusNext
+            }),
+            (
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: keyboard::KeyCode::Enter,
+                    ..
+                }),
+                event::Status::Ignored,
+            ) => Some(Message::PressFocused),
+            _ => None,
+        })
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let DragAndDrop {
+            items,
+            dropped,
+            carried,
+            focus,
+            bin,
+        } = self;
+
+        let bin_index = items.len();
+
+        let items = items.iter_mut().enumerate().fold(
+            Column::new().spacing(10).width(Length::Units(200)),
+            |column, (index, item)| {
+                let button =
+                    Button::new(&mut item.state, Text::new(&item.name))
+                        .width(Length::Fill)
+                        .on_press(Message::PickUp(index))
+                        .style(style::Item {
+                            is_carried: *carried == Some(index),
+                        });
+
+                column.push(focus_ring(button, *focus == Some(index)))
+            },
+        );
+
+        let label = if carried.is_some() {
+            String::from("Drop it here!")
+        } else {
+            format!("{} items in the bin", dropped.len())
+        };
+
+        let bin = Button::new(
+            bin,
+            Container::new(Text::new(label))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y(),
+        )
+        .width(Length::Units(200))
+        .height(Length::Units(200))
+        .on_press(Message::Drop)
+        .style(style::Bin {
+            is_target: carried.is_some(),
+        });
+
+        let content = Row::new()
+            .spacing(40)
+            .align_items(Alignment::Center)
+            .push(items)
+            .push(focus_ring(bin, *focus == Some(bin_index)));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
+
+fn focus_ring<'a>(
+    content: impl Into<Element<'a, Message>>,
+    is_focused: bool,
+) -> Element<'a, Message> {
+    Border::new(content)
+        .padding(4)
+        .thickness(if is_focused { 2.0 } else { 0.0 })
+        .radius(8.0)
+        .color(Color::from_rgb(0.2, 0.5, 1.0))
+        .style(BorderStyle::Dotted)
+        .into()
+}
+
+mod style {
+    use iced::{button, Background, BorderStyle, Color, Vector};
+
+    pub struct Item {
+        pub is_carried: bool,
+    }
+
+    impl button::StyleSheet for Item {
+        fn active(&self) -> button::Style {
+            button::Style {
+                background: Some(Background::Color(if self.is_carried {
+                    Color::from_rgb(0.85, 0.9, 1.0)
+                } else {
+                    Color::from_rgb(0.95, 0.95, 0.95)
+                })),
+                border_radius: 4.0,
+                border_width: 1.0,
+                border_color: Color::from_rgb(0.7, 0.7, 0.7),
+                ..button::Style::default()
+            }
+        }
+
+        fn hovered(&self) -> button::Style {
+            button::Style {
+                shadow_offset: Vector::new(0.0, 1.0),
+                ..self.active()
+            }
+        }
+    }
+
+    pub struct Bin {
+        pub is_target: bool,
+    }
+
+    impl button::StyleSheet for Bin {
+        fn active(&self) -> button::Style {
+            if self.is_target {
+                button::Style {
+                    border_radius: 12.0,
+                    border_width: 3.0,
+                    border_color: Color::from_rgb(0.2, 0.5, 1.0),
+                    border_style: BorderStyle::Dashed {
+                        dash: 12.0,
+                        gap: 8.0,
+                    },
+                    ..button::Style::default()
+                }
+            } else {
+                button::Style {
+                    border_radius: 12.0,
+                    border_width: 1.0,
+                    border_color: Color::from_rgb(0.7, 0.7, 0.7),
+                    ..button::Style::default()
+                }
+            }
+        }
+
+        fn hovered(&self) -> button::Style {
+            self.active()
+        }
+
+        fn pressed(&self) -> button::Style {
+            self.active()
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use iced::keyboard::{Hotkey, KeyCode, Modifiers, Shortcuts};
+use iced::menu_bar::{self, Menu, MenuItem};
+use iced::{
+    executor, text_input, Application, Column, Command, Container, Element,
+    Length, MenuBar, Settings, Text, TextInput,
+};
+
+pub fn main() -> iced::Result {
+    Editor::run(Settings::default())
+}
+
+const SAVE: Hotkey = Hotkey::new(Modifiers::COMMAND, KeyCode::S);
+const UNDO: Hotkey = Hotkey::new(Modifiers::COMMAND, KeyCode::Z);
+const NEW: Hotkey = Hotkey::new(Modifiers::COMMAND, KeyCode::N);
+
+#[derive(Default)]
+struct Editor {
+    content: String,
+    saved: String,
+    history: Vec<String>,
+    should_exit: bool,
+    menu_bar: menu_bar::State,
+    input: text_input::State,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    New,
+    Save,
+    Insert(&'static str),
+    Quit,
+    Undo,
+    Uppercase,
+    Clear,
+    Edited(String),
+}
+
+impl Application for Editor {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Editor, Command<Message>) {
+        (
+            Editor {
+                input: text_input::State::focused(),
+                ..Editor::default()
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        let modified = if self.content != self.saved { "*" } else { "" };
+
+        format!("Editor{} - Iced", modified)
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::New => self.edit(String::new()),
+            Message::Save => {
+                self.saved = self.content.clone();
+            }
+            Message::Insert(snippet) => {
+                self.edit(format!("{}{}", self.content, snippet))
+            }
+            Message::Quit => {
+                self.should_exit = true;
+            }
+            Message::Undo => {
+                if let Some(previous) = self.history.pop() {
+                    self.content = previous;
+                }
+            }
+            Message::Uppercase => self.edit(self.content.to_uppercase()),
+            Message::Clear => self.edit(String::new()),
+            Message::Edited(content) => self.edit(content),
+        }
+
+        Command::none()
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+
+    fn keyboard_accelerators(&self) -> Shortcuts<Message> {
+        Shortcuts::new()
+            .bind(SAVE, Message::Save)
+            .bind(UNDO, Message::Undo)
+            .bind(NEW, Message::New)
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let file = Menu::new("File")
+            .push(MenuItem::new("New", Message::New).shortcut(NEW.to_string()))
+            .push(
+                MenuItem::new("Save", Message::Save)
+                    .shortcut(SAVE.to_string())
+                    .enabled(self.content != self.saved),
+            )
+            .push(MenuItem::separator())
+            .push(MenuItem::submenu(
+                Menu::new("Insert")
+                    .push(MenuItem::new("Greeting", Message::Insert("Hello! ")))
+                    .push(MenuItem::new(
+                        "Signature",
+                        Message::Insert(" -- Iced"),
+                    )),
+            ))
+            .push(MenuItem::separator())
+            .push(MenuItem::new("Quit", Message::Quit));
+
+        let edit = Menu::new("Edit")
+            .push(
+                MenuItem::new("Undo", Message::Undo)
+                    .shortcut(UNDO.to_string())
+                    .enabled(!self.history.is_empty()),
+            )
+            .push(MenuItem::separator())
+            .push(MenuItem::new("Uppercase", Message::Uppercase))
+            .push(MenuItem::new("Clear", Message::Clear));
+
+        let menu_bar = MenuBar::new(&mut self.menu_bar, vec![file, edit]);
+
+        let input = TextInput::new(
+            &mut self.input,
+            "Type something...",
+            &self.content,
+            Message::Edited,
+        )
+        .padding(10)
+        .size(20);
+
+        let status = if self.content == self.saved {
+            "Saved"
+        } else {
+            "Modified, press Ctrl+S to save"
+        };
+
+        let content = Column::new()
+            .spacing(10)
+            .padding(20)
+            .push(input)
+            .push(Text::new(status).size(16));
+
+        Column::new()
+            .push(menu_bar)
+            .push(Container::new(content).width(Length::Fill))
+            .into()
+    }
+}
+
+impl Editor {
+    fn edit(&mut self, content: String) {
+        if content != self.content {
+            let previous = std::mem::replace(&mut self.content, content);
+
+            self.history.push(previous);
+        }
+    }
+}
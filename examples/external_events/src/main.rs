@@ -0,0 +1,71 @@
+use iced::event;
+use iced::{
+    executor, Alignment, Application, Column, Command, Container, Element,
+    Length, Settings, Text,
+};
+use std::thread;
+use std::time::Duration;
+
+pub fn main() -> iced::Result {
+    ExternalEvents::run(Settings::default())
+}
+
+#[derive(Default)]
+struct ExternalEvents {
+    ticks: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    ProxyReady(event::Proxy<Message>),
+    Tick,
+}
+
+impl Application for ExternalEvents {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (ExternalEvents, Command<Message>) {
+        (ExternalEvents::default(), event::proxy(Message::ProxyReady))
+    }
+
+    fn title(&self) -> String {
+        String::from("External events - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::ProxyReady(proxy) => {
+                // A plain thread, without any executor or subscription
+                let _ = thread::spawn(move || loop {
+                    thread::sleep(Duration::from_millis(500));
+
+                    if !proxy.send(Message::Tick) {
+                        break;
+                    }
+                });
+            }
+            Message::Tick => {
+                self.ticks += 1;
+            }
+        }
+
+        Command::none()
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(Text::new(format!("{} ticks", self.ticks)).size(40))
+            .push(Text::new("Sent every 500ms from a background thread"));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
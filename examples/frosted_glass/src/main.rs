@@ -0,0 +1,271 @@
+//! This example showcases a header that blurs the content scrolling behind it.
+mod stack {
+    // A widget drawing a header on top of some content, so the content can
+    // scroll behind it.
+    use iced_graphics::{Backend, Defaults, Primitive, Renderer};
+    use iced_native::{
+        event, layout, mouse, overlay, Clipboard, Element, Event, Hasher,
+        Layout, Length, Rectangle, Size, Widget,
+    };
+
+    pub struct Stack<'a, Message, B: Backend> {
+        content: Element<'a, Message, Renderer<B>>,
+        header: Element<'a, Message, Renderer<B>>,
+    }
+
+    impl<'a, Message, B: Backend> Stack<'a, Message, B> {
+        pub fn new(
+            content: impl Into<Element<'a, Message, Renderer<B>>>,
+            header: impl Into<Element<'a, Message, Renderer<B>>>,
+        ) -> Self {
+            Self {
+                content: content.into(),
+                header: header.into(),
+            }
+        }
+    }
+
+    impl<'a, Message, B> Widget<Message, Renderer<B>> for Stack<'a, Message, B>
+    where
+        B: Backend,
+    {
+        fn width(&self) -> Length {
+            self.content.width()
+        }
+
+        fn height(&self) -> Length {
+            self.content.height()
+        }
+
+        fn layout(
+            &self,
+            renderer: &Renderer<B>,
+            limits: &layout::Limits,
+        ) -> layout::Node {
+            let content = self.content.layout(renderer, limits);
+            let size = content.size();
+
+            let header = self
+                .header
+                .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+            layout::Node::with_children(size, vec![content, header])
+        }
+
+        fn on_event(
+            &mut self,
+            event: Event,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            renderer: &Renderer<B>,
+            clipboard: &mut dyn Clipboard,
+            messages: &mut Vec<Message>,
+        ) -> event::Status<Message> {
+            let mut children = layout.children();
+            let content_layout = children.next().unwrap();
+            let header_layout = children.next().unwrap();
+
+            let header_status = self.header.on_event(
+                event.clone(),
+                header_layout,
+                cursor,
+                renderer,
+                clipboard,
+                messages,
+            );
+
+            if header_status.is_captured() {
+                return header_status;
+            }
+
+            self.content.on_event(
+                event,
+                content_layout,
+                cursor,
+                renderer,
+                clipboard,
+                messages,
+            )
+        }
+
+        fn draw(
+            &self,
+            renderer: &mut Renderer<B>,
+            defaults: &Defaults,
+            layout: Layout<'_>,
+            cursor: mouse::Cursor,
+            viewport: &Rectangle,
+        ) -> (Primitive<B>, mouse::Interaction) {
+            let mut children = layout.children();
+            let content_layout = children.next().unwrap();
+            let header_layout = children.next().unwrap();
+
+            let (content, content_interaction) = self.content.draw(
+                renderer,
+                defaults,
+                content_layout,
+                cursor,
+                viewport,
+            );
+
+            let (header, header_interaction) = self.header.draw(
+                renderer,
+                defaults,
+                header_layout,
+                cursor,
+                viewport,
+            );
+
+            (
+                Primitive::Group {
+                    primitives: vec![content, header],
+                },
+                if cursor.is_over(header_layout.bounds()) {
+                    header_interaction
+                } else {
+                    content_interaction
+                },
+            )
+        }
+
+        fn hash_layout(&self, state: &mut Hasher) {
+            self.content.hash_layout(state);
+            self.header.hash_layout(state);
+        }
+
+        fn overlay(
+            &mut self,
+            layout: Layout<'_>,
+        ) -> Option<overlay::Element<'_, Message, Renderer<B>>> {
+            self.content.overlay(layout.children().next().unwrap())
+        }
+    }
+
+    impl<'a, Message, B> Into<Element<'a, Message, Renderer<B>>>
+        for Stack<'a, Message, B>
+    where
+        Message: 'a,
+        B: Backend + 'a,
+    {
+        fn into(self) -> Element<'a, Message, Renderer<B>> {
+            Element::new(self)
+        }
+    }
+}
+
+use iced::{
+    scrollable, Color, Column, Container, Element, Length, Sandbox, Scrollable,
+    Settings, Text,
+};
+use stack::Stack;
+
+pub fn main() -> iced::Result {
+    FrostedGlass::run(Settings::default())
+}
+
+/// The height of the header, in logical pixels.
+const HEADER_HEIGHT: u16 = 64;
+
+struct FrostedGlass {
+    scroll: scrollable::State,
+}
+
+impl Sandbox for FrostedGlass {
+    type Message = ();
+
+    fn new() -> Self {
+        FrostedGlass {
+            scroll: scrollable::State::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Frosted glass - Iced")
+    }
+
+    fn update(&mut self, _message: ()) {}
+
+    fn view(&mut self) -> Element<()> {
+        let cards = (0..30).fold(
+            Column::new()
+                .spacing(20)
+                .padding([HEADER_HEIGHT + 20, 20, 20, 20])
+                .width(Length::Fill),
+            |column, i| {
+                column.push(
+                    Container::new(
+                        Text::new(format!("Card #{}", i + 1)).size(30),
+                    )
+                    .width(Length::Fill)
+                    .height(Length::Units(120))
+                    .padding(20)
+                    .style(style::Card {
+                        hue: i as f32 / 30.0,
+                    }),
+                )
+            },
+        );
+
+        let content = Scrollable::new(&mut self.scroll)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .push(cards);
+
+        let header = Container::new(Text::new("Frosted glass").size(30))
+            .width(Length::Fill)
+            .height(Length::Units(HEADER_HEIGHT))
+            .padding([0, 20])
+            .center_y()
+            .backdrop_blur(12.0)
+            .backdrop_tint(Color {
+                a: 0.4,
+                ..Color::WHITE
+            })
+            .style(style::Header);
+
+        Stack::new(content, header).into()
+    }
+}
+
+mod style {
+    use iced::{container, Background, Color};
+
+    pub struct Header;
+
+    impl container::StyleSheet for Header {
+        fn style(&self) -> container::Style {
+            // The backdrop of the header is tinted instead of filled, so
+            // renderers unable to blur still draw it translucent
+            container::Style {
+                text_color: Some(Color::BLACK),
+                ..container::Style::default()
+            }
+        }
+    }
+
+    pub struct Card {
+        pub hue: f32,
+    }
+
+    impl container::StyleSheet for Card {
+        fn style(&self) -> container::Style {
+            // A rough hue wheel, good enough for some colorful cards
+            let channel = |offset: f32| {
+                let angle = (self.hue + offset) * std::f32::consts::PI * 2.0;
+
+                0.5 + 0.4 * angle.cos()
+            };
+
+            container::Style {
+                background: Some(Background::Color(Color::from_rgb(
+                    channel(0.0),
+                    channel(1.0 / 3.0),
+                    channel(2.0 / 3.0),
+                ))),
+                text_color: Some(Color::WHITE),
+                border_radius: 12.0,
+                ..container::Style::default()
+            }
+        }
+    }
+}
@@ -327,8 +327,11 @@ mod grid {
     }
 
     impl<'a> canvas::Program<Message> for Grid {
+        type State = ();
+
         fn update(
             &mut self,
+            _state: &mut (),
             event: Event,
             bounds: Rectangle,
             cursor: Cursor,
@@ -443,7 +446,12 @@ mod grid {
             }
         }
 
-        fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry> {
+        fn draw(
+            &self,
+            _state: &(),
+            bounds: Rectangle,
+            cursor: Cursor,
+        ) -> Vec<Geometry> {
             let center = Vector::new(bounds.width / 2.0, bounds.height / 2.0);
 
             let life = self.life_cache.draw(bounds.size(), |frame| {
@@ -569,6 +577,7 @@ mod grid {
 
         fn mouse_interaction(
             &self,
+            _state: &(),
             bounds: Rectangle,
             cursor: Cursor,
         ) -> mouse::Interaction {
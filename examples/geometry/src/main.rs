@@ -15,7 +15,7 @@ mod rainbow {
         Backend, Defaults, Primitive, Renderer,
     };
     use iced_native::{
-        layout, mouse, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+        layout, mouse, Element, Hasher, Layout, Length, Rectangle, Size,
         Vector, Widget,
     };
 
@@ -56,7 +56,7 @@ mod rainbow {
             _renderer: &mut Renderer<B>,
             _defaults: &Defaults,
             layout: Layout<'_>,
-            cursor_position: Point,
+            cursor: mouse::Cursor,
             _viewport: &Rectangle,
         ) -> (Primitive<B>, mouse::Interaction) {
             let b = layout.bounds();
@@ -72,8 +72,8 @@ mod rainbow {
             let color_v = [0.75, 0.0, 0.5, 1.0];
 
             let posn_center = {
-                if b.contains(cursor_position) {
-                    [cursor_position.x - b.x, cursor_position.y - b.y]
+                if let Some(cursor_position) = cursor.position_in(b) {
+                    [cursor_position.x, cursor_position.y]
                 } else {
                     [b.width / 2.0, b.height / 2.0]
                 }
@@ -0,0 +1,149 @@
+use iced::scrollable::{self, ScrollbarVisibility};
+use iced::{
+    Column, Container, Element, Length, Sandbox, Scrollable, Settings, Text,
+};
+
+pub fn main() -> iced::Result {
+    Feed::run(Settings::default())
+}
+
+const PAGE_SIZE: usize = 20;
+const MAX_PAGES: usize = 10;
+
+struct Feed {
+    scroll: scrollable::State,
+    posts: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    Scrolled(f32),
+}
+
+impl Feed {
+    fn load_next_page(&mut self) {
+        let start = self.posts.len();
+
+        self.posts.extend(
+            (start..start + PAGE_SIZE).map(|i| format!("Post #{}", i + 1)),
+        );
+    }
+}
+
+impl Sandbox for Feed {
+    type Message = Message;
+
+    fn new() -> Self {
+        let mut feed = Feed {
+            scroll: scrollable::State::new(),
+            posts: Vec::new(),
+        };
+
+        feed.load_next_page();
+        feed
+    }
+
+    fn title(&self) -> String {
+        String::from("Infinite feed - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::Scrolled(offset) => {
+                if offset > 0.9 && self.posts.len() < PAGE_SIZE * MAX_PAGES {
+                    self.load_next_page();
+                }
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let is_complete = self.posts.len() >= PAGE_SIZE * MAX_PAGES;
+
+        let feed = self
+            .posts
+            .iter()
+            .fold(
+                Scrollable::new(&mut self.scroll)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .padding(20)
+                    .spacing(10)
+                    .scrollbar_width(8)
+                    .scroller_width(8)
+                    .scrollbar_margin(2)
+                    .scrollbar_visibility(ScrollbarVisibility::Always)
+                    .style(style::Scrollbar)
+                    .on_scroll(Message::Scrolled),
+                |feed, post| feed.push(Text::new(post.as_str()).size(24)),
+            )
+            .push(Text::new(if is_complete {
+                "You are all caught up!"
+            } else {
+                "Loading more..."
+            }));
+
+        let content = Column::new()
+            .max_width(500)
+            .push(Text::new(format!("{} posts", self.posts.len())).size(16))
+            .push(feed);
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .into()
+    }
+}
+
+mod style {
+    use iced::{scrollable, Color};
+
+    pub struct Scrollbar;
+
+    impl scrollable::StyleSheet for Scrollbar {
+        fn active(&self) -> scrollable::Scrollbar {
+            scrollable::Scrollbar {
+                background: Some(Color::from_rgb(0.92, 0.92, 0.92).into()),
+                border_radius: 4.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+                margin: 0.0,
+                scroller: scrollable::Scroller {
+                    color: Color::from_rgb(0.6, 0.6, 0.6),
+                    border_radius: 4.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                    margin: 1.0,
+                    min_length: 30.0,
+                },
+            }
+        }
+
+        fn hovered(&self) -> scrollable::Scrollbar {
+            let active = self.active();
+
+            scrollable::Scrollbar {
+                scroller: scrollable::Scroller {
+                    color: Color::from_rgb(0.45, 0.45, 0.45),
+                    margin: 0.0,
+                    ..active.scroller
+                },
+                ..active
+            }
+        }
+
+        fn dragging(&self) -> scrollable::Scrollbar {
+            let hovered = self.hovered();
+
+            scrollable::Scrollbar {
+                scroller: scrollable::Scroller {
+                    color: Color::from_rgb(0.2, 0.5, 0.9),
+                    ..hovered.scroller
+                },
+                ..hovered
+            }
+        }
+    }
+}
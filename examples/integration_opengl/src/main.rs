@@ -10,10 +10,10 @@ use iced_glow::{Backend, Renderer, Settings, Viewport};
 use iced_glutin::glutin;
 use iced_glutin::glutin::event::{Event, WindowEvent};
 use iced_glutin::glutin::event_loop::ControlFlow;
-use iced_glutin::{program, Clipboard, Debug, Size};
+use iced_glutin::{mouse, program, Clipboard, Debug, Size};
 use iced_winit::conversion;
 use iced_winit::winit;
-use winit::{dpi::PhysicalPosition, event::ModifiersState};
+use winit::event::ModifiersState;
 
 pub fn main() {
     env_logger::init();
@@ -56,7 +56,7 @@ pub fn main() {
         windowed_context.window().scale_factor(),
     );
 
-    let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
+    let mut cursor_position = None;
     let mut modifiers = ModifiersState::default();
     let mut clipboard = Clipboard::connect(&windowed_context.window());
 
@@ -68,7 +68,7 @@ pub fn main() {
     let mut state = program::State::new(
         controls,
         viewport.logical_size(),
-        conversion::cursor_position(cursor_position, viewport.scale_factor()),
+        mouse::Cursor::Unavailable,
         &mut renderer,
         &mut debug,
     );
@@ -83,7 +83,7 @@ pub fn main() {
             Event::WindowEvent { event, .. } => {
                 match event {
                     WindowEvent::CursorMoved { position, .. } => {
-                        cursor_position = position;
+                        cursor_position = Some(position);
                     }
                     WindowEvent::ModifiersChanged(new_modifiers) => {
                         modifiers = new_modifiers;
@@ -112,7 +112,14 @@ pub fn main() {
                     windowed_context.window().scale_factor(),
                     modifiers,
                 ) {
+                    let horizontal_scroll =
+                        iced_winit::conversion::horizontal_scroll(&event);
+
                     state.queue_event(event);
+
+                    if let Some(event) = horizontal_scroll {
+                        state.queue_event(event);
+                    }
                 }
             }
             Event::MainEventsCleared => {
@@ -121,10 +128,15 @@ pub fn main() {
                     // We update iced
                     let _ = state.update(
                         viewport.logical_size(),
-                        conversion::cursor_position(
-                            cursor_position,
-                            viewport.scale_factor(),
-                        ),
+                        cursor_position
+                            .map(|position| {
+                                conversion::cursor_position(
+                                    position,
+                                    viewport.scale_factor(),
+                                )
+                            })
+                            .map(mouse::Cursor::Available)
+                            .unwrap_or(mouse::Cursor::Unavailable),
                         &mut renderer,
                         &mut clipboard,
                         &mut debug,
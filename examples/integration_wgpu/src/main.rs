@@ -7,11 +7,12 @@ use controls::Controls;
 use scene::Scene;
 
 use iced_wgpu::{wgpu, Backend, Renderer, Settings, Viewport};
-use iced_winit::{conversion, futures, program, winit, Clipboard, Debug, Size};
+use iced_winit::{
+    conversion, futures, mouse, program, winit, Clipboard, Debug, Size,
+};
 
 use futures::task::SpawnExt;
 use winit::{
-    dpi::PhysicalPosition,
     event::{Event, ModifiersState, WindowEvent},
     event_loop::{ControlFlow, EventLoop},
 };
@@ -28,7 +29,7 @@ pub fn main() {
         Size::new(physical_size.width, physical_size.height),
         window.scale_factor(),
     );
-    let mut cursor_position = PhysicalPosition::new(-1.0, -1.0);
+    let mut cursor_position = None;
     let mut modifiers = ModifiersState::default();
     let mut clipboard = Clipboard::connect(&window);
 
@@ -102,7 +103,7 @@ pub fn main() {
     let mut state = program::State::new(
         controls,
         viewport.logical_size(),
-        conversion::cursor_position(cursor_position, viewport.scale_factor()),
+        mouse::Cursor::Unavailable,
         &mut renderer,
         &mut debug,
     );
@@ -116,7 +117,7 @@ pub fn main() {
             Event::WindowEvent { event, .. } => {
                 match event {
                     WindowEvent::CursorMoved { position, .. } => {
-                        cursor_position = position;
+                        cursor_position = Some(position);
                     }
                     WindowEvent::ModifiersChanged(new_modifiers) => {
                         modifiers = new_modifiers;
@@ -141,7 +142,14 @@ pub fn main() {
                     window.scale_factor(),
                     modifiers,
                 ) {
+                    let horizontal_scroll =
+                        iced_winit::conversion::horizontal_scroll(&event);
+
                     state.queue_event(event);
+
+                    if let Some(event) = horizontal_scroll {
+                        state.queue_event(event);
+                    }
                 }
             }
             Event::MainEventsCleared => {
@@ -150,10 +158,15 @@ pub fn main() {
                     // We update iced
                     let _ = state.update(
                         viewport.logical_size(),
-                        conversion::cursor_position(
-                            cursor_position,
-                            viewport.scale_factor(),
-                        ),
+                        cursor_position
+                            .map(|position| {
+                                conversion::cursor_position(
+                                    position,
+                                    viewport.scale_factor(),
+                                )
+                            })
+                            .map(mouse::Cursor::Available)
+                            .unwrap_or(mouse::Cursor::Unavailable),
                         &mut renderer,
                         &mut clipboard,
                         &mut debug,
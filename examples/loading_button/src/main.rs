@@ -0,0 +1,92 @@
+use iced::{
+    button, executor, time, Alignment, Application, Button, Column, Command,
+    Container, Element, Length, Settings, Subscription, Text,
+};
+use std::time::{Duration, Instant};
+
+pub fn main() -> iced::Result {
+    LoadingButton::run(Settings::default())
+}
+
+/// How long the button stays loading after being pressed.
+const LOADING_TIME: Duration = Duration::from_secs(2);
+
+struct LoadingButton {
+    submissions: usize,
+    loading_since: Option<Instant>,
+    submit: button::State,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Submit,
+    Tick(Instant),
+}
+
+impl Application for LoadingButton {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (LoadingButton, Command<Message>) {
+        (
+            LoadingButton {
+                submissions: 0,
+                loading_since: None,
+                submit: button::State::new(),
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Loading button - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Submit => {
+                self.submissions += 1;
+                self.loading_since = Some(Instant::now());
+            }
+            Message::Tick(now) => {
+                if let Some(loading_since) = self.loading_since {
+                    if now.duration_since(loading_since) >= LOADING_TIME {
+                        self.loading_since = None;
+                    }
+                }
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        // The spinner turns on its own, so we only need to stop loading
+        if self.loading_since.is_some() {
+            time::every(LOADING_TIME).map(Message::Tick)
+        } else {
+            Subscription::none()
+        }
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let submit = Button::new(&mut self.submit, Text::new("Submit order"))
+            .padding([10, 20])
+            .on_press(Message::Submit)
+            .loading(self.loading_since.is_some());
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(submit)
+            .push(Text::new(format!("Submitted {} times", self.submissions)));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
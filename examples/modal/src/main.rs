@@ -0,0 +1,156 @@
+use iced::{
+    button, container, scrollable, text_input, Alignment, Background, Button,
+    Color, Column, Container, Element, Length, Modal, Row, Sandbox, Scrollable,
+    Settings, Text, TextInput,
+};
+
+pub fn main() -> iced::Result {
+    Form::run(Settings::default())
+}
+
+const FIELDS: [&str; 12] = [
+    "First name",
+    "Last name",
+    "Email",
+    "Phone",
+    "Company",
+    "Street",
+    "City",
+    "Postal code",
+    "Country",
+    "Website",
+    "Referral code",
+    "Notes",
+];
+
+#[derive(Default)]
+struct Form {
+    fields: Vec<Field>,
+    scroll: scrollable::State,
+    submit: button::State,
+    cancel: button::State,
+    confirm: button::State,
+    is_confirming: bool,
+    submissions: usize,
+}
+
+#[derive(Default)]
+struct Field {
+    state: text_input::State,
+    value: String,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    FieldChanged(usize, String),
+    Submit,
+    Cancel,
+    Confirm,
+}
+
+impl Sandbox for Form {
+    type Message = Message;
+
+    fn new() -> Self {
+        Form {
+            fields: FIELDS.iter().map(|_| Field::default()).collect(),
+            ..Form::default()
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Modal - Iced")
+    }
+
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::FieldChanged(index, value) => {
+                self.fields[index].value = value;
+            }
+            Message::Submit => {
+                self.is_confirming = true;
+            }
+            Message::Cancel => {
+                self.is_confirming = false;
+            }
+            Message::Confirm => {
+                self.is_confirming = false;
+                self.submissions += 1;
+
+                for field in &mut self.fields {
+                    field.value.clear();
+                }
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let fields =
+            self.fields.iter_mut().zip(FIELDS.iter()).enumerate().fold(
+                Column::new().spacing(10),
+                |column, (index, (field, label))| {
+                    column.push(
+                        TextInput::new(
+                            &mut field.state,
+                            label,
+                            &field.value,
+                            move |value| Message::FieldChanged(index, value),
+                        )
+                        .padding(10),
+                    )
+                },
+            );
+
+        let form = Scrollable::new(&mut self.scroll)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .spacing(20)
+            .push(Text::new(format!("Submitted {} times", self.submissions)))
+            .push(fields)
+            .push(
+                Button::new(&mut self.submit, Text::new("Submit"))
+                    .padding([10, 20])
+                    .on_press(Message::Submit),
+            );
+
+        let dialog = if self.is_confirming {
+            let buttons = Row::new()
+                .spacing(10)
+                .push(
+                    Button::new(&mut self.cancel, Text::new("Cancel"))
+                        .padding([10, 20])
+                        .on_press(Message::Cancel),
+                )
+                .push(
+                    Button::new(&mut self.confirm, Text::new("Submit"))
+                        .padding([10, 20])
+                        .on_press(Message::Confirm),
+                );
+
+            let content = Column::new()
+                .spacing(20)
+                .align_items(Alignment::Center)
+                .push(Text::new("Submit the form?").size(24))
+                .push(buttons);
+
+            Some(Container::new(content).padding(30).style(Dialog).into())
+        } else {
+            None
+        };
+
+        Modal::new(form, dialog).on_blur(Message::Cancel).into()
+    }
+}
+
+struct Dialog;
+
+impl container::StyleSheet for Dialog {
+    fn style(&self) -> container::Style {
+        container::Style {
+            background: Some(Background::Color(Color::WHITE)),
+            border_radius: 10.0,
+            ..container::Style::default()
+        }
+    }
+}
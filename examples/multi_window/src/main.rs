@@ -0,0 +1,228 @@
+use iced::button::{self, Button};
+use iced::multi_window::{Event, Status};
+use iced::slider::{self, Slider};
+use iced::window;
+use iced::{
+    executor, Alignment, Color, Column, Command, Container, Element, Length,
+    MultiWindowApplication, Row, Settings, Text,
+};
+
+pub fn main() -> iced::Result {
+    Editor::run(Settings {
+        window: window::Settings {
+            size: (600, 400),
+            ..window::Settings::default()
+        },
+        ..Settings::default()
+    })
+}
+
+const COLORS: [(&str, Color); 4] = [
+    ("Red", Color::from_rgb(0.8, 0.2, 0.2)),
+    ("Green", Color::from_rgb(0.2, 0.7, 0.3)),
+    ("Blue", Color::from_rgb(0.2, 0.4, 0.8)),
+    ("Black", Color::BLACK),
+];
+
+struct Editor {
+    color: Color,
+    size: f32,
+    palette: Option<window::Id>,
+    should_exit: bool,
+    toggle_palette: button::State,
+    color_buttons: [button::State; 4],
+    size_slider: slider::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    TogglePalette,
+    PaletteClosed,
+    ColorPicked(Color),
+    SizeChanged(f32),
+    Exit,
+}
+
+impl MultiWindowApplication for Editor {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        (
+            Editor {
+                color: COLORS[2].1,
+                size: 100.0,
+                palette: None,
+                should_exit: false,
+                toggle_palette: button::State::new(),
+                color_buttons: Default::default(),
+                size_slider: slider::State::new(),
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        if Some(window) == self.palette {
+            String::from("Palette")
+        } else {
+            String::from("Multi-window - Iced")
+        }
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::TogglePalette => match self.palette.take() {
+                Some(palette) => window::close(palette),
+                None => {
+                    let (palette, command) = window::spawn(window::Settings {
+                        size: (240, 320),
+                        resizable: false,
+                        ..window::Settings::default()
+                    });
+
+                    self.palette = Some(palette);
+
+                    command
+                }
+            },
+            Message::PaletteClosed => {
+                self.palette = None;
+
+                Command::none()
+            }
+            Message::ColorPicked(color) => {
+                self.color = color;
+
+                Command::none()
+            }
+            Message::SizeChanged(size) => {
+                self.size = size;
+
+                Command::none()
+            }
+            Message::Exit => {
+                self.should_exit = true;
+
+                Command::none()
+            }
+        }
+    }
+
+    fn view(&mut self, window: window::Id) -> Element<Message> {
+        if Some(window) == self.palette {
+            self.palette_view()
+        } else {
+            self.main_view()
+        }
+    }
+
+    fn event(
+        &self,
+        window: window::Id,
+        event: Event,
+        _status: Status,
+    ) -> Option<Message> {
+        match event {
+            Event::Window(window::Event::CloseRequested) => {
+                if window == window::Id::MAIN {
+                    Some(Message::Exit)
+                } else {
+                    Some(Message::PaletteClosed)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+}
+
+impl Editor {
+    fn main_view(&mut self) -> Element<Message> {
+        let toggle = Button::new(
+            &mut self.toggle_palette,
+            Text::new(if self.palette.is_some() {
+                "Close palette"
+            } else {
+                "Open palette"
+            }),
+        )
+        .padding(10)
+        .on_press(Message::TogglePalette);
+
+        let shape = Container::new(Text::new(""))
+            .width(Length::Units(self.size as u16))
+            .height(Length::Units(self.size as u16))
+            .style(style::Shape(self.color));
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(toggle)
+            .push(shape);
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+
+    fn palette_view(&mut self) -> Element<Message> {
+        let colors = self.color_buttons.iter_mut().zip(COLORS.iter()).fold(
+            Column::new().spacing(10),
+            |colors, (state, (name, color))| {
+                colors.push(
+                    Button::new(state, Text::new(*name))
+                        .width(Length::Fill)
+                        .on_press(Message::ColorPicked(*color)),
+                )
+            },
+        );
+
+        let size = Row::new()
+            .spacing(10)
+            .align_items(Alignment::Center)
+            .push(Text::new(format!("{:>3}", self.size as u16)).size(16))
+            .push(Slider::new(
+                &mut self.size_slider,
+                20.0..=200.0,
+                self.size,
+                Message::SizeChanged,
+            ));
+
+        Container::new(
+            Column::new()
+                .spacing(20)
+                .push(Text::new("Color").size(24))
+                .push(colors)
+                .push(Text::new("Size").size(24))
+                .push(size),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .into()
+    }
+}
+
+mod style {
+    use iced::{container, Background, Color};
+
+    pub struct Shape(pub Color);
+
+    impl container::StyleSheet for Shape {
+        fn style(&self) -> container::Style {
+            container::Style {
+                background: Some(Background::Color(self.0)),
+                border_radius: 8.0,
+                ..container::Style::default()
+            }
+        }
+    }
+}
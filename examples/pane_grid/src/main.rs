@@ -1,9 +1,11 @@
 use iced::{
-    alignment, button, executor, keyboard, pane_grid, scrollable, Alignment,
-    Application, Button, Color, Column, Command, Container, Element, Length,
-    PaneGrid, Row, Scrollable, Settings, Subscription, Text,
+    alignment, button, executor, keyboard, pane_grid, scrollable, time,
+    Alignment, Application, Button, Color, Column, Command, Container, Element,
+    Length, PaneGrid, Row, Scrollable, Settings, Size, Subscription, Text,
 };
 use iced_native::{event, subscription, Event};
+use std::collections::HashSet;
+use std::time::Duration;
 
 pub fn main() -> iced::Result {
     Example::run(Settings::default())
@@ -24,6 +26,8 @@ enum Message {
     Dragged(pane_grid::DragEvent),
     Resized(pane_grid::ResizeEvent),
     TogglePin(pane_grid::Pane),
+    ToggleCollapse(pane_grid::Pane),
+    Animate,
     Close(pane_grid::Pane),
     CloseFocused,
 }
@@ -34,7 +38,8 @@ impl Application for Example {
     type Flags = ();
 
     fn new(_flags: ()) -> (Self, Command<Message>) {
-        let (panes, _) = pane_grid::State::new(Pane::new(0));
+        let (mut panes, _) = pane_grid::State::new(Pane::new(0));
+        panes.set_transition(Duration::from_millis(250));
 
         (
             Example {
@@ -108,6 +113,14 @@ impl Application for Example {
                     *is_pinned = !*is_pinned;
                 }
             }
+            Message::ToggleCollapse(pane) => {
+                if self.panes.is_collapsed(&pane) {
+                    self.panes.restore(&pane);
+                } else {
+                    self.panes.collapse(&pane);
+                }
+            }
+            Message::Animate => {}
             Message::Close(pane) => {
                 if let Some((_, sibling)) = self.panes.close(&pane) {
                     self.focus = Some(sibling);
@@ -132,7 +145,7 @@ impl Application for Example {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        subscription::events_with(|event, status| {
+        let hotkeys = subscription::events_with(|event, status| {
             if let event::Status::Captured = status {
                 return None;
             }
@@ -141,15 +154,33 @@ impl Application for Example {
                 Event::Keyboard(keyboard::Event::KeyPressed {
                     modifiers,
                     key_code,
+                    ..
                 }) if modifiers.command() => handle_hotkey(key_code),
                 _ => None,
             }
-        })
+        });
+
+        // Panes collapse smoothly as long as we keep redrawing
+        if self.panes.is_animating() {
+            Subscription::batch(vec![
+                hotkeys,
+                time::every(Duration::from_millis(16))
+                    .map(|_| Message::Animate),
+            ])
+        } else {
+            hotkeys
+        }
     }
 
     fn view(&mut self) -> Element<Message> {
         let focus = self.focus;
         let total_panes = self.panes.len();
+        let collapsed: HashSet<_> = self
+            .panes
+            .iter()
+            .map(|(pane, _)| *pane)
+            .filter(|pane| self.panes.is_collapsed(pane))
+            .collect();
 
         let pane_grid = PaneGrid::new(&mut self.panes, |id, pane| {
             let is_focused = focus == Some(id);
@@ -175,7 +206,12 @@ impl Application for Example {
             .spacing(5);
 
             let title_bar = pane_grid::TitleBar::new(title)
-                .controls(pane.controls.view(id, total_panes, pane.is_pinned))
+                .controls(pane.controls.view(
+                    id,
+                    total_panes,
+                    pane.is_pinned,
+                    collapsed.contains(&id),
+                ))
                 .padding(10)
                 .style(style::TitleBar { is_focused });
 
@@ -186,10 +222,12 @@ impl Application for Example {
             ))
             .title_bar(title_bar)
             .style(style::Pane { is_focused })
+            .min_size(Size::new(150.0, 150.0))
         })
         .width(Length::Fill)
         .height(Length::Fill)
         .spacing(10)
+        .collapsed_thickness(40)
         .on_click(Message::Clicked)
         .on_drag(Message::Dragged)
         .on_resize(10, Message::Resized);
@@ -249,6 +287,7 @@ struct Content {
 }
 
 struct Controls {
+    collapse: button::State,
     close: button::State,
 }
 
@@ -344,6 +383,7 @@ impl Content {
 impl Controls {
     fn new() -> Self {
         Self {
+            collapse: button::State::new(),
             close: button::State::new(),
         }
     }
@@ -353,15 +393,27 @@ impl Controls {
         pane: pane_grid::Pane,
         total_panes: usize,
         is_pinned: bool,
+        is_collapsed: bool,
     ) -> Element<Message> {
-        let mut button =
+        let label = if is_collapsed { "Restore" } else { "Collapse" };
+
+        let mut collapse =
+            Button::new(&mut self.collapse, Text::new(label).size(14))
+                .style(style::Button::Control)
+                .padding(3);
+        if total_panes > 1 {
+            collapse = collapse.on_press(Message::ToggleCollapse(pane));
+        }
+
+        let mut close =
             Button::new(&mut self.close, Text::new("Close").size(14))
                 .style(style::Button::Control)
                 .padding(3);
         if total_panes > 1 && !is_pinned {
-            button = button.on_press(Message::Close(pane));
+            close = close.on_press(Message::Close(pane));
         }
-        button.into()
+
+        Row::new().spacing(5).push(collapse).push(close).into()
     }
 }
 
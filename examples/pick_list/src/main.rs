@@ -12,18 +12,25 @@ struct Example {
     scroll: scrollable::State,
     pick_list: pick_list::State<Language>,
     selected_language: Option<Language>,
+    servers: Vec<Server>,
+    server_list: pick_list::State<ServerId>,
+    selected_server: Option<ServerId>,
 }
 
 #[derive(Debug, Clone, Copy)]
 enum Message {
     LanguageSelected(Language),
+    ServerSelected(ServerId),
 }
 
 impl Sandbox for Example {
     type Message = Message;
 
     fn new() -> Self {
-        Self::default()
+        Self {
+            servers: Server::all(),
+            ..Self::default()
+        }
     }
 
     fn title(&self) -> String {
@@ -35,6 +42,9 @@ impl Sandbox for Example {
             Message::LanguageSelected(language) => {
                 self.selected_language = Some(language);
             }
+            Message::ServerSelected(id) => {
+                self.selected_server = Some(id);
+            }
         }
     }
 
@@ -47,13 +57,34 @@ impl Sandbox for Example {
         )
         .placeholder("Choose a language...");
 
+        // The servers are picked by id, with their names as labels
+        let server_list = PickList::with_labels(
+            &mut self.server_list,
+            self.servers
+                .iter()
+                .map(|server| (server.id, server.name.clone())),
+            self.selected_server,
+            Message::ServerSelected,
+        )
+        .placeholder("Choose a server...");
+
+        let server = self
+            .selected_server
+            .and_then(|id| self.servers.iter().find(|server| server.id == id))
+            .map(|server| format!("Connecting to {}...", server.address))
+            .unwrap_or_default();
+
         let mut content = Scrollable::new(&mut self.scroll)
             .width(Length::Fill)
             .align_items(Alignment::Center)
             .spacing(10)
             .push(Space::with_height(Length::Units(600)))
             .push(Text::new("Which is your favorite language?"))
-            .push(pick_list);
+            .push(pick_list)
+            .push(Space::with_height(Length::Units(20)))
+            .push(Text::new("Where do you want to play?"))
+            .push(server_list)
+            .push(Text::new(server));
 
         content = content.push(Space::with_height(Length::Units(600)));
 
@@ -112,3 +143,30 @@ impl std::fmt::Display for Language {
         )
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ServerId(u64);
+
+#[derive(Debug)]
+pub struct Server {
+    id: ServerId,
+    name: String,
+    address: String,
+}
+
+impl Server {
+    fn all() -> Vec<Server> {
+        [
+            (1, "Europe", "eu.example.com"),
+            (2, "North America", "na.example.com"),
+            (3, "Asia", "asia.example.com"),
+        ]
+        .iter()
+        .map(|&(id, name, address)| Server {
+            id: ServerId(id),
+            name: String::from(name),
+            address: String::from(address),
+        })
+        .collect()
+    }
+}
@@ -0,0 +1,84 @@
+use iced::{
+    executor, time, Alignment, Application, Column, Command, Container,
+    Element, Length, Settings, Subscription, Text,
+};
+use std::time::{Duration, Instant};
+
+pub fn main() -> iced::Result {
+    Polling::run(Settings::default())
+}
+
+struct Polling {
+    polls: usize,
+    started_at: Instant,
+    last_poll: Option<Instant>,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Poll(Instant),
+}
+
+impl Application for Polling {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Polling, Command<Message>) {
+        (
+            Polling {
+                polls: 0,
+                started_at: Instant::now(),
+                last_poll: None,
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Polling - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Poll(now) => {
+                self.polls += 1;
+                self.last_poll = Some(now);
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        // Polling stops while the window is unfocused or minimized
+        time::every_while_active(Duration::from_secs(1)).map(Message::Poll)
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let last_poll = match self.last_poll {
+            Some(last_poll) => format!(
+                "Last poll {:.0}s after start",
+                last_poll.duration_since(self.started_at).as_secs_f32()
+            ),
+            None => String::from("Not polled yet"),
+        };
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(Text::new(format!("Polled {} times", self.polls)).size(40))
+            .push(Text::new(last_poll))
+            .push(
+                Text::new("Unfocus or minimize the window to pause polling")
+                    .size(16),
+            );
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
@@ -1,4 +1,8 @@
-use iced::{slider, Column, Element, ProgressBar, Sandbox, Settings, Slider};
+use iced::{
+    slider, Column, Element, ProgressBar, Sandbox, Settings, Slider, Text,
+};
+
+const TOTAL_BYTES: u64 = 10_000_000_000;
 
 pub fn main() -> iced::Result {
     Progress::run(Settings::default())
@@ -8,11 +12,14 @@ pub fn main() -> iced::Result {
 struct Progress {
     value: f32,
     progress_bar_slider: slider::State,
+    bytes: u64,
+    bytes_slider: slider::State,
 }
 
 #[derive(Debug, Clone, Copy)]
 enum Message {
     SliderChanged(f32),
+    BytesChanged(u64),
 }
 
 impl Sandbox for Progress {
@@ -29,12 +36,14 @@ impl Sandbox for Progress {
     fn update(&mut self, message: Message) {
         match message {
             Message::SliderChanged(x) => self.value = x,
+            Message::BytesChanged(bytes) => self.bytes = bytes,
         }
     }
 
     fn view(&mut self) -> Element<Message> {
         Column::new()
             .padding(20)
+            .spacing(10)
             .push(ProgressBar::new(0.0..=100.0, self.value))
             .push(
                 Slider::new(
@@ -45,6 +54,14 @@ impl Sandbox for Progress {
                 )
                 .step(0.01),
             )
+            .push(ProgressBar::<u64>::new(0..=TOTAL_BYTES, self.bytes))
+            .push(Slider::new(
+                &mut self.bytes_slider,
+                0..=TOTAL_BYTES,
+                self.bytes,
+                Message::BytesChanged,
+            ))
+            .push(Text::new(format!("{} / {} bytes", self.bytes, TOTAL_BYTES)))
             .into()
     }
 }
@@ -140,11 +140,14 @@ mod dark {
                 border_radius: 2.0,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                margin: 0.0,
                 scroller: scrollable::Scroller {
                     color: Color { a: 0.7, ..SCROLLER },
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    margin: 0.0,
+                    min_length: 0.0,
                 },
             }
         }
@@ -184,6 +187,7 @@ mod dark {
                 width: 2,
                 radius: 1.0,
                 fill_mode: rule::FillMode::Percent(30.0),
+                cap: rule::CapStyle::Square,
             }
         }
     }
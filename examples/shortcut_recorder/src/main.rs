@@ -0,0 +1,215 @@
+use iced::keyboard::{self, KeyCode, KeyLocation, ScanCode};
+use iced::{
+    button, executor, Alignment, Application, Button, Column, Command,
+    Container, Element, Length, Row, Settings, Subscription, Text,
+};
+use iced_native::{subscription, Event};
+
+pub fn main() -> iced::Result {
+    ShortcutRecorder::run(Settings::default())
+}
+
+/// The physical keys labeled W, A, S, and D on a QWERTY layout.
+#[cfg(target_os = "macos")]
+const WASD: [u32; 4] = [13, 0, 1, 2];
+
+#[cfg(not(target_os = "macos"))]
+const WASD: [u32; 4] = [17, 30, 31, 32];
+
+struct ShortcutRecorder {
+    presses: Vec<Press>,
+    bindings: Vec<Binding>,
+    rebinding: Option<Direction>,
+    position: (i32, i32),
+}
+
+struct Binding {
+    direction: Direction,
+    physical_key: ScanCode,
+    state: button::State,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Left,
+    Down,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct Press {
+    key: KeyCode,
+    physical_key: ScanCode,
+    location: KeyLocation,
+    text: Option<String>,
+    repeat: bool,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    KeyPressed(Press),
+    Rebind(Direction),
+}
+
+impl Application for ShortcutRecorder {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        let directions = [
+            Direction::Up,
+            Direction::Left,
+            Direction::Down,
+            Direction::Right,
+        ];
+
+        let bindings = directions
+            .iter()
+            .zip(WASD.iter())
+            .map(|(direction, scan_code)| Binding {
+                direction: *direction,
+                physical_key: ScanCode(*scan_code),
+                state: button::State::new(),
+            })
+            .collect();
+
+        (
+            ShortcutRecorder {
+                presses: Vec::new(),
+                bindings,
+                rebinding: None,
+                position: (0, 0),
+            },
+            Command::none(),
+        )
+    }
+
+    fn title(&self) -> String {
+        String::from("Shortcut recorder - Iced")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::KeyPressed(press) => {
+                if let Some(direction) = self.rebinding.take() {
+                    if let Some(binding) = self
+                        .bindings
+                        .iter_mut()
+                        .find(|binding| binding.direction == direction)
+                    {
+                        binding.physical_key = press.physical_key;
+                    }
+                } else if let Some(binding) = self
+                    .bindings
+                    .iter()
+                    .find(|binding| binding.physical_key == press.physical_key)
+                {
+                    let (x, y) = self.position;
+
+                    self.position = match binding.direction {
+                        Direction::Up => (x, y - 1),
+                        Direction::Left => (x - 1, y),
+                        Direction::Down => (x, y + 1),
+                        Direction::Right => (x + 1, y),
+                    };
+                }
+
+                if !press.repeat {
+                    self.presses.push(press);
+
+                    if self.presses.len() > 8 {
+                        let _ = self.presses.remove(0);
+                    }
+                }
+            }
+            Message::Rebind(direction) => {
+                self.rebinding = Some(direction);
+            }
+        }
+
+        Command::none()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, _status| match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key,
+                physical_key,
+                location,
+                text,
+                repeat,
+                ..
+            }) => Some(Message::KeyPressed(Press {
+                key,
+                physical_key,
+                location,
+                text: text.map(|text| text.to_string()),
+                repeat,
+            })),
+            _ => None,
+        })
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let presses = self.presses.iter().rev().fold(
+            Column::new().spacing(5),
+            |column, press| {
+                column.push(Text::new(format!(
+                    "{:?} ({:?}), scan code {}{}",
+                    press.key,
+                    press.location,
+                    press.physical_key.0,
+                    press
+                        .text
+                        .as_ref()
+                        .filter(|text| !text.chars().any(char::is_control))
+                        .map(|text| format!(", typing \"{}\"", text))
+                        .unwrap_or_default(),
+                )))
+            },
+        );
+
+        let rebinding = self.rebinding;
+
+        let bindings = self.bindings.iter_mut().fold(
+            Row::new().spacing(10),
+            |row, binding| {
+                let label = if rebinding == Some(binding.direction) {
+                    format!("{:?}: press a key", binding.direction)
+                } else {
+                    format!(
+                        "{:?}: scan code {}",
+                        binding.direction, binding.physical_key.0
+                    )
+                };
+
+                row.push(
+                    Button::new(&mut binding.state, Text::new(label).size(16))
+                        .padding(8)
+                        .on_press(Message::Rebind(binding.direction)),
+                )
+            },
+        );
+
+        let content = Column::new()
+            .spacing(20)
+            .align_items(Alignment::Center)
+            .push(Text::new("Press any key").size(30))
+            .push(presses)
+            .push(bindings)
+            .push(Text::new(format!(
+                "Position: ({}, {})",
+                self.position.0, self.position.1
+            )));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
+            .center_y()
+            .padding(20)
+            .into()
+    }
+}
@@ -131,8 +131,11 @@ impl State {
 }
 
 impl<Message> canvas::Program<Message> for State {
+    type State = ();
+
     fn draw(
         &self,
+        _state: &(),
         bounds: Rectangle,
         _cursor: Cursor,
     ) -> Vec<canvas::Geometry> {
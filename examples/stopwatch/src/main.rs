@@ -1,8 +1,9 @@
+use iced::time::{self, Instant};
 use iced::{
-    alignment, button, executor, time, Alignment, Application, Button, Column,
+    alignment, button, executor, Alignment, Application, Button, Column,
     Command, Container, Element, Length, Row, Settings, Subscription, Text,
 };
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 pub fn main() -> iced::Result {
     Stopwatch::run(Settings::default())
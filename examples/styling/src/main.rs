@@ -1,5 +1,5 @@
 use iced::{
-    button, scrollable, slider, text_input, Alignment, Button, Checkbox,
+    button, image, scrollable, slider, text_input, Alignment, Button, Checkbox,
     Column, Container, Element, Length, ProgressBar, Radio, Row, Rule, Sandbox,
     Scrollable, Settings, Slider, Space, Text, TextInput, Toggler,
 };
@@ -8,13 +8,15 @@ pub fn main() -> iced::Result {
     Styling::run(Settings::default())
 }
 
-#[derive(Default)]
 struct Styling {
     theme: style::Theme,
+    hero: image::Handle,
+    texture: image::Handle,
     scroll: scrollable::State,
     input: text_input::State,
     input_value: String,
     button: button::State,
+    textured_button: button::State,
     slider: slider::State,
     slider_value: f32,
     checkbox_value: bool,
@@ -35,7 +37,20 @@ impl Sandbox for Styling {
     type Message = Message;
 
     fn new() -> Self {
-        Styling::default()
+        Styling {
+            theme: style::Theme::default(),
+            hero: style::hero(),
+            texture: style::texture(),
+            scroll: scrollable::State::new(),
+            input: text_input::State::new(),
+            input_value: String::new(),
+            button: button::State::new(),
+            textured_button: button::State::new(),
+            slider: slider::State::new(),
+            slider_value: 0.0,
+            checkbox_value: false,
+            toggler_value: false,
+        }
     }
 
     fn title(&self) -> String {
@@ -54,6 +69,13 @@ impl Sandbox for Styling {
     }
 
     fn view(&mut self) -> Element<Message> {
+        let hero = Container::new(Text::new("Styling").size(40))
+            .width(Length::Fill)
+            .height(Length::Units(120))
+            .padding(20)
+            .center_y()
+            .style(style::Hero(self.hero.clone()));
+
         let choose_theme = style::Theme::ALL.iter().fold(
             Column::new().spacing(10).push(Text::new("Choose a theme:")),
             |column, theme| {
@@ -84,6 +106,12 @@ impl Sandbox for Styling {
             .on_press(Message::ButtonPressed)
             .style(self.theme);
 
+        let textured_button =
+            Button::new(&mut self.textured_button, Text::new("Textured"))
+                .padding(10)
+                .on_press(Message::ButtonPressed)
+                .style(style::Textured(self.texture.clone()));
+
         let slider = Slider::new(
             &mut self.slider,
             0.0..=100.0,
@@ -123,9 +151,16 @@ impl Sandbox for Styling {
             .spacing(20)
             .padding(20)
             .max_width(600)
+            .push(hero)
             .push(choose_theme)
             .push(Rule::horizontal(38).style(self.theme))
-            .push(Row::new().spacing(10).push(text_input).push(button))
+            .push(
+                Row::new()
+                    .spacing(10)
+                    .push(text_input)
+                    .push(button)
+                    .push(textured_button),
+            )
             .push(slider)
             .push(progress_bar)
             .push(
@@ -144,7 +179,12 @@ impl Sandbox for Styling {
                     ),
             );
 
-        Container::new(content)
+        let card = Container::new(content)
+            .padding(20)
+            .max_width(640)
+            .style(style::Card(self.theme));
+
+        Container::new(card)
             .width(Length::Fill)
             .height(Length::Fill)
             .center_x()
@@ -156,8 +196,9 @@ impl Sandbox for Styling {
 
 mod style {
     use iced::{
-        button, checkbox, container, progress_bar, radio, rule, scrollable,
-        slider, text_input, toggler,
+        button, checkbox, container, image, progress_bar, radio, rule,
+        scrollable, slider, text_input, toggler, Background, Color, ContentFit,
+        Shadow, Vector,
     };
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -185,6 +226,118 @@ mod style {
         }
     }
 
+    /// A raised card, casting a soft shadow over the background.
+    pub struct Card(pub Theme);
+
+    impl container::StyleSheet for Card {
+        fn style(&self) -> container::Style {
+            match self.0 {
+                Theme::Light => light::Card.style(),
+                Theme::Dark => dark::Card.style(),
+            }
+        }
+    }
+
+    /// A header covered by an image, clipped by its rounded corners.
+    pub struct Hero(pub image::Handle);
+
+    impl container::StyleSheet for Hero {
+        fn style(&self) -> container::Style {
+            container::Style {
+                background: Some(Background::Image {
+                    handle: self.0.reference(),
+                    fit: ContentFit::Cover,
+                    opacity: 1.0,
+                }),
+                text_color: Some(Color::WHITE),
+                border_radius: 8.0,
+                ..container::Style::default()
+            }
+        }
+    }
+
+    /// A button filled with a repeated texture.
+    pub struct Textured(pub image::Handle);
+
+    impl button::StyleSheet for Textured {
+        fn active(&self) -> button::Style {
+            button::Style {
+                background: Some(Background::Tiled {
+                    handle: self.0.reference(),
+                    scale: 1.0,
+                }),
+                border_radius: 12.0,
+                text_color: Color::WHITE,
+                shadow: Some(Shadow {
+                    color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                    offset: Vector::new(0.0, 1.0),
+                    blur_radius: 3.0,
+                }),
+                ..button::Style::default()
+            }
+        }
+
+        fn hovered(&self) -> button::Style {
+            button::Style {
+                border_width: 2.0,
+                border_color: Color::from_rgba(1.0, 1.0, 1.0, 0.8),
+                shadow: Some(Shadow {
+                    color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                    offset: Vector::new(0.0, 3.0),
+                    blur_radius: 6.0,
+                }),
+                ..self.active()
+            }
+        }
+    }
+
+    /// Generates a sunset over some hills, in BGRA.
+    pub fn hero() -> image::Handle {
+        let (width, height) = (480, 160);
+        let mut pixels = Vec::with_capacity(width * height * 4);
+
+        for y in 0..height {
+            for x in 0..width {
+                let t = y as f32 / height as f32;
+                let hill = height as f32
+                    * (0.75 + 0.1 * (x as f32 / width as f32 * 9.0).sin());
+
+                let [r, g, b] = if (y as f32) > hill {
+                    [0.16, 0.12, 0.25]
+                } else {
+                    [0.95 - 0.3 * t, 0.45 + 0.2 * t, 0.35 + 0.3 * t]
+                };
+
+                pixels.extend_from_slice(&[
+                    (b * 255.0) as u8,
+                    (g * 255.0) as u8,
+                    (r * 255.0) as u8,
+                    255,
+                ]);
+            }
+        }
+
+        image::Handle::from_pixels(width as u32, height as u32, pixels)
+    }
+
+    /// Generates a tile of diagonal stripes, in BGRA.
+    pub fn texture() -> image::Handle {
+        let size = 8;
+        let mut pixels = Vec::with_capacity(size * size * 4);
+
+        for y in 0..size {
+            for x in 0..size {
+                if (x + y) % size < 2 {
+                    pixels.extend_from_slice(&[0xF0, 0x7A, 0x2A, 255]);
+                } else {
+                    pixels.extend_from_slice(&[0xDE, 0x6B, 0x1C, 255]);
+                }
+            }
+        }
+
+        image::Handle::from_pixels(size as u32, size as u32, pixels)
+    }
+
     impl From<Theme> for Box<dyn radio::StyleSheet> {
         fn from(theme: Theme) -> Self {
             match theme {
@@ -267,7 +420,24 @@ mod style {
     }
 
     mod light {
-        use iced::{button, Color, Vector};
+        use iced::{button, container, Color, Shadow, Vector};
+
+        pub struct Card;
+
+        impl container::StyleSheet for Card {
+            fn style(&self) -> container::Style {
+                container::Style {
+                    background: Color::WHITE.into(),
+                    border_radius: 8.0,
+                    shadow: Some(Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.2),
+                        offset: Vector::new(0.0, 4.0),
+                        blur_radius: 16.0,
+                    }),
+                    ..container::Style::default()
+                }
+            }
+        }
 
         pub struct Button;
 
@@ -276,17 +446,28 @@ mod style {
                 button::Style {
                     background: Color::from_rgb(0.11, 0.42, 0.87).into(),
                     border_radius: 12.0,
-                    shadow_offset: Vector::new(1.0, 1.0),
                     text_color: Color::from_rgb8(0xEE, 0xEE, 0xEE),
+                    shadow: Some(Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.3),
+                        offset: Vector::new(0.0, 1.0),
+                        blur_radius: 3.0,
+                    }),
                     ..button::Style::default()
                 }
             }
 
             fn hovered(&self) -> button::Style {
+                let active = self.active();
+
+                // Raise the button further than the default does
                 button::Style {
                     text_color: Color::WHITE,
-                    shadow_offset: Vector::new(1.0, 2.0),
-                    ..self.active()
+                    shadow: active.shadow.map(|shadow| Shadow {
+                        offset: Vector::new(0.0, 3.0),
+                        blur_radius: 6.0,
+                        ..shadow
+                    }),
+                    ..active
                 }
             }
         }
@@ -295,7 +476,7 @@ mod style {
     mod dark {
         use iced::{
             button, checkbox, container, progress_bar, radio, rule, scrollable,
-            slider, text_input, toggler, Color,
+            slider, text_input, toggler, Color, Shadow, Vector,
         };
 
         const SURFACE: Color = Color::from_rgb(
@@ -334,6 +515,23 @@ mod style {
             }
         }
 
+        pub struct Card;
+
+        impl container::StyleSheet for Card {
+            fn style(&self) -> container::Style {
+                container::Style {
+                    background: Color::from_rgb8(0x40, 0x44, 0x4B).into(),
+                    border_radius: 8.0,
+                    shadow: Some(Shadow {
+                        color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+                        offset: Vector::new(0.0, 4.0),
+                        blur_radius: 16.0,
+                    }),
+                    ..container::Style::default()
+                }
+            }
+        }
+
         pub struct Radio;
 
         impl radio::StyleSheet for Radio {
@@ -433,11 +631,14 @@ mod style {
                     border_radius: 2.0,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
+                    margin: 0.0,
                     scroller: scrollable::Scroller {
                         color: ACTIVE,
                         border_radius: 2.0,
                         border_width: 0.0,
                         border_color: Color::TRANSPARENT,
+                        margin: 0.0,
+                        min_length: 0.0,
                     },
                 }
             }
@@ -555,6 +756,8 @@ mod style {
                     background_border: None,
                     foreground: if is_active { Color::WHITE } else { ACTIVE },
                     foreground_border: None,
+                    track_border_radius: f32::INFINITY,
+                    thumb_border_radius: f32::INFINITY,
                 }
             }
 
@@ -571,6 +774,8 @@ mod style {
                         Color { a: 0.5, ..ACTIVE }
                     },
                     foreground_border: None,
+                    track_border_radius: f32::INFINITY,
+                    thumb_border_radius: f32::INFINITY,
                 }
             }
         }
@@ -584,6 +789,7 @@ mod style {
                     width: 2,
                     radius: 1.0,
                     fill_mode: rule::FillMode::Padded(15),
+                    cap: rule::CapStyle::Square,
                 }
             }
         }
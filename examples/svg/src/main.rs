@@ -1,38 +1,78 @@
-use iced::{Container, Element, Length, Sandbox, Settings, Svg};
+use iced::{
+    slider, svg, Column, Container, Element, Length, Sandbox, Settings, Slider,
+    Svg, Text,
+};
 
 pub fn main() -> iced::Result {
     Tiger::run(Settings::default())
 }
 
-struct Tiger;
+struct Tiger {
+    handle: svg::Handle,
+    zoom: f32,
+    slider: slider::State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    ZoomChanged(f32),
+}
 
 impl Sandbox for Tiger {
-    type Message = ();
+    type Message = Message;
 
     fn new() -> Self {
-        Tiger
+        Tiger {
+            handle: svg::Handle::from_path(format!(
+                "{}/resources/tiger.svg",
+                env!("CARGO_MANIFEST_DIR")
+            )),
+            zoom: 1.0,
+            slider: slider::State::new(),
+        }
     }
 
     fn title(&self) -> String {
         String::from("SVG - Iced")
     }
 
-    fn update(&mut self, _message: ()) {}
+    fn update(&mut self, message: Message) {
+        match message {
+            Message::ZoomChanged(zoom) => self.zoom = zoom,
+        }
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        // While zooming, the tiger is scaled from the closest rasterization
+        // until a sharp one is ready
+        let svg = Svg::new(self.handle.clone())
+            .width(Length::Units((500.0 * self.zoom) as u16))
+            .height(Length::Units((500.0 * self.zoom) as u16));
+
+        let zoom = Slider::new(
+            &mut self.slider,
+            0.25..=4.0,
+            self.zoom,
+            Message::ZoomChanged,
+        )
+        .step(0.01);
 
-    fn view(&mut self) -> Element<()> {
-        let svg = Svg::from_path(format!(
-            "{}/resources/tiger.svg",
-            env!("CARGO_MANIFEST_DIR")
-        ))
-        .width(Length::Fill)
-        .height(Length::Fill);
+        let content = Column::new()
+            .spacing(20)
+            .push(Text::new(format!("Zoom: {:.0}%", self.zoom * 100.0)))
+            .push(zoom)
+            .push(
+                Container::new(svg)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center_x()
+                    .center_y(),
+            );
 
-        Container::new(svg)
+        Container::new(content)
             .width(Length::Fill)
             .height(Length::Fill)
             .padding(20)
-            .center_x()
-            .center_y()
             .into()
     }
 }
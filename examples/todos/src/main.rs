@@ -25,6 +25,7 @@ struct State {
     input_value: String,
     filter: Filter,
     tasks: Vec<Task>,
+    next_id: usize,
     controls: Controls,
     dirty: bool,
     saving: bool,
@@ -37,6 +38,7 @@ enum Message {
     InputChanged(String),
     CreateTask,
     FilterChanged(Filter),
+    SortTasks,
     TaskMessage(usize, TaskMessage),
 }
 
@@ -66,10 +68,18 @@ impl Application for Todos {
             Todos::Loading => {
                 match message {
                     Message::Loaded(Ok(state)) => {
+                        let tasks: Vec<Task> = state
+                            .tasks
+                            .into_iter()
+                            .enumerate()
+                            .map(|(id, task)| Task { id, ..task })
+                            .collect();
+
                         *self = Todos::Loaded(State {
                             input_value: state.input_value,
                             filter: state.filter,
-                            tasks: state.tasks,
+                            next_id: tasks.len(),
+                            tasks,
                             ..State::default()
                         });
                     }
@@ -90,15 +100,24 @@ impl Application for Todos {
                     }
                     Message::CreateTask => {
                         if !state.input_value.is_empty() {
-                            state
-                                .tasks
-                                .push(Task::new(state.input_value.clone()));
+                            state.tasks.push(Task::new(
+                                state.next_id,
+                                state.input_value.clone(),
+                            ));
+                            state.next_id += 1;
                             state.input_value.clear();
                         }
                     }
                     Message::FilterChanged(filter) => {
                         state.filter = filter;
                     }
+                    Message::SortTasks => {
+                        // Tasks keep their state, so the ones being edited
+                        // move along with their unfinished descriptions
+                        state.tasks.sort_by_cached_key(|task| {
+                            (task.completed, task.description.to_lowercase())
+                        });
+                    }
                     Message::TaskMessage(i, TaskMessage::Delete) => {
                         state.tasks.remove(i);
                     }
@@ -171,16 +190,24 @@ impl Application for Todos {
                     tasks.iter().filter(|task| filter.matches(task));
 
                 let tasks: Element<_> = if filtered_tasks.count() > 0 {
-                    tasks
-                        .iter_mut()
-                        .enumerate()
-                        .filter(|(_, task)| filter.matches(task))
-                        .fold(Column::new().spacing(20), |column, (i, task)| {
-                            column.push(task.view().map(move |message| {
-                                Message::TaskMessage(i, message)
-                            }))
-                        })
-                        .into()
+                    Column::with_children_keyed(
+                        tasks
+                            .iter_mut()
+                            .enumerate()
+                            .filter(|(_, task)| filter.matches(task))
+                            .map(|(i, task)| {
+                                let id = task.id;
+
+                                (
+                                    id,
+                                    task.view().map(move |message| {
+                                        Message::TaskMessage(i, message)
+                                    }),
+                                )
+                            }),
+                    )
+                    .spacing(20)
+                    .into()
                 } else {
                     empty_message(match filter {
                         Filter::All => "You have not created a task yet...",
@@ -212,6 +239,8 @@ impl Application for Todos {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Task {
+    #[serde(skip)]
+    id: usize,
     description: String,
     completed: bool,
 
@@ -248,8 +277,9 @@ pub enum TaskMessage {
 }
 
 impl Task {
-    fn new(description: String) -> Self {
+    fn new(id: usize, description: String) -> Self {
         Task {
+            id,
             description,
             completed: false,
             state: TaskState::Idle {
@@ -345,6 +375,7 @@ impl Task {
 
 #[derive(Debug, Default, Clone)]
 pub struct Controls {
+    sort_button: button::State,
     all_button: button::State,
     active_button: button::State,
     completed_button: button::State,
@@ -353,6 +384,7 @@ pub struct Controls {
 impl Controls {
     fn view(&mut self, tasks: &[Task], current_filter: Filter) -> Row<Message> {
         let Controls {
+            sort_button,
             all_button,
             active_button,
             completed_button,
@@ -382,6 +414,12 @@ impl Controls {
                 .width(Length::Fill)
                 .size(16),
             )
+            .push(
+                Button::new(sort_button, Text::new("Sort").size(16))
+                    .on_press(Message::SortTasks)
+                    .padding(8)
+                    .style(style::Button::Filter { selected: false }),
+            )
             .push(
                 Row::new()
                     .width(Length::Shrink)
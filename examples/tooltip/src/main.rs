@@ -115,6 +115,7 @@ fn tooltip<'a>(
     )
     .gap(5)
     .padding(10)
+    .arrow(5)
     .style(style::Tooltip)
     .into()
 }
@@ -528,6 +528,13 @@ impl<'a> Step {
             ))
             .push(size_section)
             .push(color_section)
+            .push(
+                Text::new(
+                    "With the `color-emoji` feature, emoji fall back to a \
+                     color emoji font of your system: Deploy \u{1F680} done \u{2705}",
+                )
+                .size(20),
+            )
     }
 
     fn radio(selection: Option<Language>) -> Column<'a, StepMessage> {
@@ -0,0 +1,117 @@
+use iced::{
+    executor, keyboard, window, Application, Column, Command, Container,
+    Element, Length, Settings, Subscription, Text,
+};
+use iced_native::{event, subscription, Event};
+
+pub fn main() -> iced::Result {
+    Zoom::run(Settings::default())
+}
+
+const ZOOM_STEP: f32 = 0.1;
+
+#[derive(Debug)]
+struct Zoom {
+    level: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Message {
+    ZoomIn,
+    ZoomOut,
+    Reset,
+    ZoomChanged(f32),
+}
+
+impl Application for Zoom {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Zoom, Command<Message>) {
+        (
+            Zoom { level: 1.0 },
+            window::scale_factor_override(Message::ZoomChanged),
+        )
+    }
+
+    fn title(&self) -> String {
+        format!("Zoom {:.0}% - Iced", self.level * 100.0)
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        let level = match message {
+            Message::ZoomIn => self.level + ZOOM_STEP,
+            Message::ZoomOut => self.level - ZOOM_STEP,
+            Message::Reset => 1.0,
+            Message::ZoomChanged(level) => {
+                self.level = level;
+
+                return Command::none();
+            }
+        };
+
+        // The runtime clamps the override, so we fetch it back to display
+        // the zoom level that is actually in use
+        Command::batch(vec![
+            window::set_scale_factor_override(level),
+            window::scale_factor_override(Message::ZoomChanged),
+        ])
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        subscription::events_with(|event, status| {
+            if let event::Status::Captured = status {
+                return None;
+            }
+
+            match event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers,
+                    ..
+                }) if modifiers.command() => match key_code {
+                    keyboard::KeyCode::Plus
+                    | keyboard::KeyCode::Equals
+                    | keyboard::KeyCode::NumpadAdd => Some(Message::ZoomIn),
+                    keyboard::KeyCode::Minus
+                    | keyboard::KeyCode::NumpadSubtract => {
+                        Some(Message::ZoomOut)
+                    }
+                    keyboard::KeyCode::Key0 | keyboard::KeyCode::Numpad0 => {
+                        Some(Message::Reset)
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        })
+    }
+
+    fn view(&mut self) -> Element<Message> {
+        let content = Column::new()
+            .spacing(20)
+            .max_width(600)
+            .push(
+                Text::new(format!("Zoom: {:.0}%", self.level * 100.0)).size(50),
+            )
+            .push(Text::new(
+                "Press Ctrl and + to zoom in, Ctrl and - to zoom out, and \
+                 Ctrl and 0 to reset the zoom level.",
+            ))
+            .push(Text::new(
+                "The zoom level is multiplied with the scale factor of your \
+                 display. Text stays crisp and paragraphs like this one \
+                 reflow to fit the window, since the whole user interface \
+                 is laid out again with the new logical size of the window.",
+            ));
+
+        Container::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .padding(20)
+            .center_x()
+            .center_y()
+            .into()
+    }
+}
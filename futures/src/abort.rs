@@ -0,0 +1,44 @@
+//! Abort running futures and streams.
+use futures::future::{AbortHandle, Abortable};
+use futures::Stream;
+
+/// A handle to some running work that aborts it when dropped.
+///
+/// You can keep a [`Handle`] around for as long as the work is relevant, and
+/// simply drop it (or call [`Handle::cancel`]) once it is not needed anymore.
+#[derive(Debug)]
+pub struct Handle {
+    raw: Option<AbortHandle>,
+}
+
+impl Handle {
+    /// Cancels the work of the [`Handle`].
+    ///
+    /// This is the same as dropping the [`Handle`].
+    pub fn cancel(self) {}
+
+    /// Detaches the work of the [`Handle`], letting it run to completion.
+    pub fn detach(mut self) {
+        let _ = self.raw.take();
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        if let Some(raw) = self.raw.take() {
+            raw.abort();
+        }
+    }
+}
+
+/// Wraps the given stream so it can be aborted with the returned [`Handle`].
+///
+/// Once aborted, the stream will end the next time it is polled.
+pub fn abortable<S: Stream>(stream: S) -> (Abortable<S>, Handle) {
+    let (raw, registration) = AbortHandle::new_pair();
+
+    (
+        Abortable::new(stream, registration),
+        Handle { raw: Some(raw) },
+    )
+}
@@ -13,24 +13,24 @@ pub use futures;
 mod command;
 mod runtime;
 
+pub mod abort;
 pub mod executor;
 pub mod subscription;
 
-#[cfg(all(
-    any(
-        feature = "tokio",
-        feature = "tokio_old",
-        feature = "async-std",
-        feature = "smol"
-    ),
-    not(target_arch = "wasm32")
+#[cfg(any(
+    feature = "tokio",
+    feature = "tokio_old",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
 ))]
 #[cfg_attr(
     docsrs,
     doc(cfg(any(
         feature = "tokio",
         feature = "async-std",
-        feature = "smol"
+        feature = "smol",
+        target_arch = "wasm32"
     )))
 )]
 pub mod time;
@@ -91,7 +91,7 @@ where
             let (event_sender, event_receiver) =
                 futures::channel::mpsc::channel(100);
 
-            let stream = recipe.stream(event_receiver.boxed());
+            let stream = recipe.stream(Box::pin(event_receiver));
 
             let future = futures::future::select(
                 cancelled,
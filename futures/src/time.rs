@@ -1,5 +1,22 @@
 //! Listen and react to time.
 use crate::subscription::{self, Subscription};
+use crate::BoxStream;
+
+/// A measurement of a monotonically nondecreasing clock.
+///
+/// On native platforms, it is a [`std::time::Instant`]. On the Web, where
+/// [`std::time::Instant::now`] is not available, it is backed by the
+/// `performance.now()` clock of the browser.
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+
+/// A measurement of a monotonically nondecreasing clock.
+///
+/// On native platforms, it is a [`std::time::Instant`]. On the Web, where
+/// [`std::time::Instant::now`] is not available, it is backed by the
+/// `performance.now()` clock of the browser.
+#[cfg(target_arch = "wasm32")]
+pub use instant::Instant;
 
 /// Returns a [`Subscription`] that produces messages at a set interval.
 ///
@@ -7,7 +24,7 @@ use crate::subscription::{self, Subscription};
 /// produce more messages every `duration` after that.
 pub fn every<H: std::hash::Hasher, E>(
     duration: std::time::Duration,
-) -> Subscription<H, E, std::time::Instant> {
+) -> Subscription<H, E, Instant> {
     Subscription::from_recipe(Every(duration))
 }
 
@@ -15,13 +32,14 @@ struct Every(std::time::Duration);
 
 #[cfg(all(
     not(any(feature = "tokio_old", feature = "tokio", feature = "async-std")),
-    feature = "smol"
+    feature = "smol",
+    not(target_arch = "wasm32")
 ))]
 impl<H, E> subscription::Recipe<H, E> for Every
 where
     H: std::hash::Hasher,
 {
-    type Output = std::time::Instant;
+    type Output = Instant;
 
     fn hash(&self, state: &mut H) {
         use std::hash::Hash;
@@ -32,20 +50,20 @@ where
 
     fn stream(
         self: Box<Self>,
-        _input: futures::stream::BoxStream<'static, E>,
-    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
         use futures::stream::StreamExt;
 
         smol::Timer::interval(self.0).boxed()
     }
 }
 
-#[cfg(feature = "async-std")]
+#[cfg(all(feature = "async-std", not(target_arch = "wasm32")))]
 impl<H, E> subscription::Recipe<H, E> for Every
 where
     H: std::hash::Hasher,
 {
-    type Output = std::time::Instant;
+    type Output = Instant;
 
     fn hash(&self, state: &mut H) {
         use std::hash::Hash;
@@ -56,25 +74,26 @@ where
 
     fn stream(
         self: Box<Self>,
-        _input: futures::stream::BoxStream<'static, E>,
-    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
         use futures::stream::StreamExt;
 
         async_std::stream::interval(self.0)
-            .map(|_| std::time::Instant::now())
+            .map(|_| Instant::now())
             .boxed()
     }
 }
 
 #[cfg(all(
     any(feature = "tokio", feature = "tokio_old"),
-    not(any(feature = "async-std", feature = "smol"))
+    not(any(feature = "async-std", feature = "smol")),
+    not(target_arch = "wasm32")
 ))]
 impl<H, E> subscription::Recipe<H, E> for Every
 where
     H: std::hash::Hasher,
 {
-    type Output = std::time::Instant;
+    type Output = Instant;
 
     fn hash(&self, state: &mut H) {
         use std::hash::Hash;
@@ -85,8 +104,8 @@ where
 
     fn stream(
         self: Box<Self>,
-        _input: futures::stream::BoxStream<'static, E>,
-    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
         use futures::stream::StreamExt;
 
         #[cfg(feature = "tokio_old")]
@@ -111,3 +130,334 @@ where
         stream.map(tokio::time::Instant::into_std).boxed()
     }
 }
+
+#[cfg(target_arch = "wasm32")]
+impl<H, E> subscription::Recipe<H, E> for Every
+where
+    H: std::hash::Hasher,
+{
+    type Output = Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
+        use futures::stream::StreamExt;
+
+        Box::pin(
+            gloo_timers::future::IntervalStream::new(millis(self.0))
+                .map(|_| Instant::now()),
+        )
+    }
+}
+
+/// Returns a [`Subscription`] that produces messages at every multiple of the
+/// given `duration` since the Unix epoch.
+///
+/// Unlike [`every`], the ticks stay aligned to the wall clock and do not drift
+/// over time. For instance, a `duration` of one second produces a message at
+/// the start of every second.
+///
+/// If the process is suspended and some ticks are missed, they are skipped
+/// instead of being produced all at once.
+pub fn every_aligned<H: std::hash::Hasher, E>(
+    duration: std::time::Duration,
+) -> Subscription<H, E, Instant> {
+    Subscription::from_recipe(EveryAligned(duration))
+}
+
+/// Returns a [`Subscription`] that produces a single message at the given
+/// [`Instant`].
+///
+/// If the [`Instant`] is already in the past, the message is produced right
+/// away.
+///
+pub fn at<H: std::hash::Hasher, E>(
+    instant: Instant,
+) -> Subscription<H, E, Instant> {
+    schedule(std::iter::once(instant))
+}
+
+/// Returns a [`Subscription`] that produces a message at every [`Instant`] of
+/// the given schedule.
+///
+/// The schedule is expected to be sorted. Any [`Instant`] that is already in
+/// the past when it is reached produces its message right away. The
+/// subscription is identified by the type of the schedule and its first
+/// [`Instant`].
+///
+pub fn schedule<H: std::hash::Hasher, E, I>(
+    instants: I,
+) -> Subscription<H, E, Instant>
+where
+    I: IntoIterator<Item = Instant>,
+    I::IntoIter: Send + 'static,
+{
+    let mut instants = instants.into_iter();
+
+    Subscription::from_recipe(Schedule {
+        first: instants.next(),
+        rest: instants,
+    })
+}
+
+/// Returns a [`Subscription`] that produces messages at a set interval, but
+/// only while it is active.
+///
+/// The `activity` function is called with every event of the runtime and
+/// returns whether the event activates (`Some(true)`) or suspends
+/// (`Some(false)`) the interval. Events that do not change the activity
+/// should return `None`.
+///
+/// The interval starts active. While suspended, no messages are produced and
+/// the interval starts over once it is activated again.
+pub fn every_while<H: std::hash::Hasher, E>(
+    duration: std::time::Duration,
+    activity: fn(&E) -> Option<bool>,
+) -> Subscription<H, E, Instant>
+where
+    E: 'static + Send,
+{
+    Subscription::from_recipe(EveryWhile { duration, activity })
+}
+
+struct EveryAligned(std::time::Duration);
+
+impl<H, E> subscription::Recipe<H, E> for EveryAligned
+where
+    H: std::hash::Hasher,
+{
+    type Output = Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
+        let period = self.0.as_nanos().max(1);
+
+        Box::pin(futures::stream::unfold(0, move |last| async move {
+            // The index of the next boundary since the epoch. It always
+            // increases, so a timer waking up early never ticks twice.
+            let next = (since_epoch() / period + 1).max(last + 1);
+
+            loop {
+                let now = since_epoch();
+                let target = next * period;
+
+                if now >= target {
+                    break;
+                }
+
+                sleep(std::time::Duration::from_nanos((target - now) as u64))
+                    .await;
+            }
+
+            Some((Instant::now(), next))
+        }))
+    }
+}
+
+struct Schedule<I> {
+    first: Option<Instant>,
+    rest: I,
+}
+
+impl<H, E, I> subscription::Recipe<H, E> for Schedule<I>
+where
+    H: std::hash::Hasher,
+    I: Iterator<Item = Instant> + Send + 'static,
+{
+    type Output = Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.first.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: BoxStream<E>,
+    ) -> BoxStream<Self::Output> {
+        let Schedule { first, rest } = *self;
+
+        Box::pin(futures::stream::unfold(
+            (first, rest),
+            |(mut first, mut rest)| async move {
+                let instant = first.take().or_else(|| rest.next())?;
+
+                sleep(instant.saturating_duration_since(Instant::now())).await;
+
+                Some((instant, (first, rest)))
+            },
+        ))
+    }
+}
+
+struct EveryWhile<E> {
+    duration: std::time::Duration,
+    activity: fn(&E) -> Option<bool>,
+}
+
+impl<H, E> subscription::Recipe<H, E> for EveryWhile<E>
+where
+    H: std::hash::Hasher,
+    E: 'static + Send,
+{
+    type Output = Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.duration.hash(state);
+        (self.activity as usize).hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<Self::Output> {
+        use futures::future::{self, Either};
+        use futures::stream::StreamExt;
+
+        let EveryWhile { duration, activity } = *self;
+
+        let changes = Box::pin(
+            input.filter_map(move |event| future::ready(activity(&event))),
+        );
+
+        Box::pin(futures::stream::unfold(
+            (changes, true),
+            move |(mut changes, mut is_active)| async move {
+                loop {
+                    if !is_active {
+                        is_active = changes.next().await?;
+                        continue;
+                    }
+
+                    let mut tick = Box::pin(sleep(duration));
+
+                    while is_active {
+                        let change =
+                            match future::select(&mut tick, changes.next())
+                                .await
+                            {
+                                Either::Left(_) => None,
+                                Either::Right((change, _)) => Some(change?),
+                            };
+
+                        match change {
+                            None => {
+                                return Some((
+                                    Instant::now(),
+                                    (changes, is_active),
+                                ));
+                            }
+                            Some(change) => {
+                                is_active = change;
+                            }
+                        }
+                    }
+                }
+            },
+        ))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn since_epoch() -> u128 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn since_epoch() -> u128 {
+    (js_sys::Date::now() * 1_000_000.0) as u128
+}
+
+#[cfg(all(
+    not(any(feature = "tokio_old", feature = "tokio", feature = "async-std")),
+    feature = "smol",
+    not(target_arch = "wasm32")
+))]
+async fn sleep(duration: std::time::Duration) {
+    let _ = smol::Timer::after(duration).await;
+}
+
+#[cfg(all(feature = "async-std", not(target_arch = "wasm32")))]
+async fn sleep(duration: std::time::Duration) {
+    async_std::task::sleep(duration).await;
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old"),
+    not(any(feature = "async-std", feature = "smol")),
+    not(target_arch = "wasm32")
+))]
+async fn sleep(duration: std::time::Duration) {
+    #[cfg(feature = "tokio")]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(feature = "tokio_old")]
+    tokio_old::time::delay_for(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+async fn sleep(duration: std::time::Duration) {
+    gloo_timers::future::TimeoutFuture::new(millis(duration)).await;
+}
+
+/// Rounds up a duration to the whole milliseconds the browser timers expect.
+#[cfg(target_arch = "wasm32")]
+fn millis(duration: std::time::Duration) -> u32 {
+    let millis = (duration.as_nanos() + 999_999) / 1_000_000;
+
+    millis.min(u128::from(u32::MAX)) as u32
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+
+    use futures::stream::StreamExt;
+    use std::collections::hash_map::DefaultHasher;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn every_produces_multiple_ticks() {
+        let subscription: Subscription<DefaultHasher, (), Instant> =
+            every(std::time::Duration::from_millis(10));
+
+        let recipe = subscription
+            .recipes()
+            .into_iter()
+            .next()
+            .expect("Every recipe");
+
+        let ticks: Vec<Instant> = recipe
+            .stream(Box::pin(futures::stream::empty()))
+            .take(3)
+            .collect()
+            .await;
+
+        assert_eq!(ticks.len(), 3);
+        assert!(ticks.windows(2).all(|ticks| ticks[0] <= ticks[1]));
+    }
+}
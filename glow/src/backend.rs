@@ -8,8 +8,9 @@ use iced_graphics::font;
 use iced_graphics::Layer;
 use iced_graphics::Primitive;
 use iced_native::alignment;
+use iced_native::futures::channel::oneshot;
 use iced_native::mouse;
-use iced_native::{Font, Size};
+use iced_native::{Font, Rectangle, Size, WritingMode};
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -21,6 +22,7 @@ pub struct Backend {
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     default_text_size: u16,
+    frame_captures: Vec<oneshot::Sender<Vec<u8>>>,
 }
 
 impl Backend {
@@ -30,6 +32,8 @@ impl Backend {
             gl,
             settings.default_font,
             settings.text_multithreading,
+            settings.hinting,
+            settings.subpixel_positioning,
         );
 
         let quad_pipeline = quad::Pipeline::new(gl);
@@ -40,9 +44,24 @@ impl Backend {
             text_pipeline,
             triangle_pipeline,
             default_text_size: settings.default_text_size,
+            frame_captures: Vec::new(),
         }
     }
 
+    /// Requests the pixels of the next frame drawn by the [`Backend`] to be
+    /// sent through the given `sender`, as RGBA bytes.
+    ///
+    /// Every request made before the next frame is fulfilled with it.
+    pub fn capture_next_frame(&mut self, sender: oneshot::Sender<Vec<u8>>) {
+        self.frame_captures.push(sender);
+    }
+
+    pub(crate) fn take_frame_captures(
+        &mut self,
+    ) -> Vec<oneshot::Sender<Vec<u8>>> {
+        std::mem::take(&mut self.frame_captures)
+    }
+
     /// Draws the provided primitives in the default framebuffer.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -74,7 +93,7 @@ impl Backend {
             );
         }
 
-        *mouse_interaction
+        mouse_interaction.clone()
     }
 
     fn flush(
@@ -88,6 +107,19 @@ impl Backend {
         let mut bounds = (layer.bounds * scale_factor).snap();
         bounds.height = bounds.height.min(target_height);
 
+        // Reading back the target is not supported, so backdrops are only
+        // tinted
+        if let Some(backdrop) = &layer.backdrop {
+            self.quad_pipeline.draw(
+                gl,
+                target_height,
+                &[backdrop.tint_quad()],
+                transformation,
+                scale_factor,
+                bounds,
+            );
+        }
+
         if !layer.quads.is_empty() {
             self.quad_pipeline.draw(
                 gl,
@@ -112,17 +144,44 @@ impl Backend {
             );
         }
 
+        if !layer.textured_meshes.is_empty() {
+            log::warn!("Textured meshes are not supported by the glow backend");
+        }
+
+        if !layer.images.is_empty() {
+            log::warn!("Images are not supported by the glow backend");
+        }
+
+        if !layer.videos.is_empty() {
+            log::warn!("Videos are not supported by the glow backend");
+        }
+
         if !layer.text.is_empty() {
             for text in layer.text.iter() {
+                let (_, sections) = self.text_pipeline.sections(
+                    text.content,
+                    self.text_pipeline.find_font(text.font),
+                    glow_glyph::ab_glyph::PxScale {
+                        x: text.size * scale_factor,
+                        y: text.size * scale_factor,
+                    },
+                    glow_glyph::Extra {
+                        color: text.color,
+                        z: 0.0,
+                    },
+                );
+
+                let letter_spacing = text.letter_spacing * scale_factor;
+                let writing_mode = text.writing_mode;
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = glow_glyph::Section {
-                    // TODO: We `round` here to avoid rerasterizing text when
-                    // its position changes slightly. This can make text feel a
-                    // bit "jumpy". We may be able to do better once we improve
-                    // our text rendering/caching pipeline.
-                    screen_position: (
-                        (text.bounds.x * scale_factor).round(),
-                        (text.bounds.y * scale_factor).round(),
+                    // We snap the position to avoid rerasterizing text when
+                    // it changes slightly. Unless subpixel positioning is
+                    // enabled, this can make text feel a bit "jumpy".
+                    screen_position: self.text_pipeline.snap_origin(
+                        text.bounds.x * scale_factor,
+                        text.bounds.y * scale_factor,
                     ),
                     // TODO: Fix precision issues with some scale factors.
                     //
@@ -137,18 +196,7 @@ impl Backend {
                         (text.bounds.width * scale_factor).ceil(),
                         (text.bounds.height * scale_factor).ceil(),
                     ),
-                    text: vec![glow_glyph::Text {
-                        text: text.content,
-                        scale: glow_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: glow_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
+                    text: sections,
                     layout: glow_glyph::Layout::default()
                         .h_align(match text.horizontal_alignment {
                             alignment::Horizontal::Left => {
@@ -175,7 +223,7 @@ impl Backend {
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                self.text_pipeline.queue(text, letter_spacing, writing_mode);
             }
 
             self.text_pipeline.draw_queued(
@@ -215,7 +263,51 @@ impl backend::Text for Backend {
         font: Font,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            bounds,
+            0.0,
+            WritingMode::Horizontal,
+        )
+    }
+
+    fn measure_with_letter_spacing(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            bounds,
+            letter_spacing,
+            WritingMode::Horizontal,
+        )
+    }
+
+    fn measure_with_writing_mode(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            bounds,
+            letter_spacing,
+            writing_mode,
+        )
     }
 
     fn hit_test(
@@ -236,6 +328,17 @@ impl backend::Text for Backend {
             nearest_only,
         )
     }
+
+    fn glyph_positions(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<Rectangle> {
+        self.text_pipeline
+            .glyph_positions(contents, size, font, bounds)
+    }
 }
 
 #[cfg(feature = "image")]
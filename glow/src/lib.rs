@@ -28,7 +28,7 @@ pub(crate) use iced_graphics::Transformation;
 #[doc(no_inline)]
 pub use widget::*;
 
-pub use iced_graphics::{Error, Viewport};
+pub use iced_graphics::{Error, Hinting, Styleable, Theme, Viewport};
 
 pub use iced_native::alignment;
 pub use iced_native::{Alignment, Background, Color, Command, Length, Vector};
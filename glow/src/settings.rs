@@ -1,5 +1,5 @@
 //! Configure a renderer.
-pub use iced_graphics::Antialiasing;
+pub use iced_graphics::{Antialiasing, Hinting};
 
 /// The settings of a [`Backend`].
 ///
@@ -26,6 +26,21 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The hinting strategy that will be used for the glyphs of text.
+    ///
+    /// By default, it is `None`, placing glyphs at their exact positions.
+    pub hinting: Hinting,
+
+    /// If enabled, text is placed horizontally at the closest quarter of a
+    /// pixel, instead of being snapped to whole pixels, spacing its glyphs
+    /// more evenly.
+    ///
+    /// This overrides the horizontal snapping of [`Hinting::Full`], at the
+    /// cost of rasterizing up to four versions of every glyph.
+    ///
+    /// By default, it is disabled.
+    pub subpixel_positioning: bool,
 }
 
 impl Default for Settings {
@@ -35,6 +50,8 @@ impl Default for Settings {
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: None,
+            hinting: Hinting::None,
+            subpixel_positioning: false,
         }
     }
 }
@@ -7,7 +7,15 @@ in vec4 v_BorderColor;
 in vec2 v_Pos;
 in vec2 v_Scale;
 in float v_BorderRadius;
-in float v_BorderWidth;
+// The widths of the top, right, bottom and left edges of the border
+in vec4 v_BorderWidth;
+in float v_BorderStyle;
+in vec2 v_BorderDash;
+in float v_BorderOffset;
+in vec4 v_ShadowColor;
+in vec2 v_ShadowOffset;
+in float v_ShadowBlur;
+in float v_ShadowKind;
 
 out vec4 o_Color;
 
@@ -29,19 +37,216 @@ float distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
     return sqrt(distance.x * distance.x + distance.y * distance.y);
 }
 
+float rounded_box_distance(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
+{
+    vec2 half_size = size / 2.0;
+    vec2 q = abs(frag_coord - position - half_size) - half_size + vec2(radius);
+
+    return length(max(q, 0.0)) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+// Distance travelled along the perimeter of a rounded box, clockwise from the
+// start of its top edge, to reach the point closest to `frag_coord`.
+float perimeter_position(in vec2 frag_coord, in vec2 position, in vec2 size, float radius)
+{
+    vec2 top_left = position + vec2(radius);
+    vec2 bottom_right = position + size - vec2(radius);
+    vec2 inner = bottom_right - top_left;
+    float arc = 1.5707964 * radius;
+
+    if (frag_coord.x > bottom_right.x && frag_coord.y < top_left.y) {
+        vec2 d = frag_coord - vec2(bottom_right.x, top_left.y);
+
+        return inner.x + atan(d.x, -d.y) * radius;
+    }
+
+    if (frag_coord.x > bottom_right.x && frag_coord.y > bottom_right.y) {
+        vec2 d = frag_coord - bottom_right;
+
+        return inner.x + inner.y + arc + atan(d.y, d.x) * radius;
+    }
+
+    if (frag_coord.x < top_left.x && frag_coord.y > bottom_right.y) {
+        vec2 d = frag_coord - vec2(top_left.x, bottom_right.y);
+
+        return 2.0 * inner.x + inner.y + 2.0 * arc + atan(-d.x, d.y) * radius;
+    }
+
+    if (frag_coord.x < top_left.x && frag_coord.y < top_left.y) {
+        vec2 d = frag_coord - top_left;
+
+        return 2.0 * inner.x + 2.0 * inner.y + 3.0 * arc + atan(-d.y, -d.x) * radius;
+    }
+
+    float to_top = frag_coord.y - position.y;
+    float to_right = position.x + size.x - frag_coord.x;
+    float to_bottom = position.y + size.y - frag_coord.y;
+    float to_left = frag_coord.x - position.x;
+    float nearest = min(min(to_top, to_right), min(to_bottom, to_left));
+
+    if (nearest == to_top) {
+        return frag_coord.x - top_left.x;
+    }
+
+    if (nearest == to_right) {
+        return inner.x + arc + frag_coord.y - top_left.y;
+    }
+
+    if (nearest == to_bottom) {
+        return inner.x + inner.y + 2.0 * arc + bottom_right.x - frag_coord.x;
+    }
+
+    return 2.0 * inner.x + inner.y + 3.0 * arc + bottom_right.y - frag_coord.y;
+}
+
+float max_border_width()
+{
+    return max(
+        max(v_BorderWidth.x, v_BorderWidth.y),
+        max(v_BorderWidth.z, v_BorderWidth.w)
+    );
+}
+
+// Coverage of the dash pattern of the border at `frag_coord`.
+//
+// The pattern is measured along the center line of the border and stretched
+// so a whole number of periods fits its perimeter. This keeps the pattern
+// still unless `v_BorderOffset` changes.
+float dash_alpha(in vec2 frag_coord)
+{
+    float period = v_BorderDash.x + v_BorderDash.y;
+
+    if (period <= 0.0) {
+        return 1.0;
+    }
+
+    float border_width = max_border_width();
+    float half_width = border_width / 2.0;
+    vec2 center_position = v_Pos + vec2(half_width);
+    vec2 center_size = v_Scale - vec2(border_width);
+    float center_radius = max(v_BorderRadius - half_width, 0.0);
+
+    float perimeter = 2.0 * (center_size.x + center_size.y)
+        + (6.2831855 - 8.0) * center_radius;
+
+    float stretch = perimeter / (max(round(perimeter / period), 1.0) * period);
+    float stretched_period = period * stretch;
+    float stretched_dash = v_BorderDash.x * stretch;
+
+    float along = perimeter_position(
+        frag_coord,
+        center_position,
+        center_size,
+        center_radius
+    ) + v_BorderOffset;
+
+    float phase = along - floor(along / stretched_period) * stretched_period;
+
+    if (v_BorderStyle > 1.5) {
+        float across = rounded_box_distance(
+            frag_coord,
+            center_position,
+            center_size,
+            center_radius
+        );
+
+        float dot_distance = length(vec2(phase - stretched_dash / 2.0, across));
+
+        return 1.0 - smoothstep(half_width - 0.5, half_width + 0.5, dot_distance);
+    }
+
+    return clamp(
+        0.5 + max(min(phase, stretched_dash - phase), phase - stretched_period),
+        0.0,
+        1.0
+    );
+}
+
+// Opacity of the inner shadow of the quad at `frag_coord`.
+//
+// The shadow covers the region of the quad left uncovered when the quad is
+// shifted by its shadow offset. A blur of zero produces a crisp edge.
+float inner_shadow_alpha(in vec2 frag_coord)
+{
+    float shadow_distance = rounded_box_distance(
+        frag_coord - v_ShadowOffset,
+        v_Pos,
+        v_Scale,
+        v_BorderRadius
+    );
+
+    if (v_ShadowBlur > 0.0) {
+        return smoothstep(-v_ShadowBlur, v_ShadowBlur, shadow_distance);
+    }
+
+    return step(0.0, shadow_distance);
+}
+
+// Opacity of the drop shadow of the quad at `frag_coord`.
+//
+// The shadow is cast by the box left inside the quad when shrunk by its blur,
+// which already includes the shadow offset. A blur of zero produces a crisp
+// edge.
+float drop_shadow_alpha(in vec2 frag_coord)
+{
+    vec2 box_size = v_Scale - vec2(v_ShadowBlur) * 2.0;
+
+    float shadow_distance = rounded_box_distance(
+        frag_coord,
+        v_Pos + vec2(v_ShadowBlur),
+        box_size,
+        min(v_BorderRadius, min(box_size.x, box_size.y) / 2.0)
+    );
+
+    if (v_ShadowBlur > 0.0) {
+        return 1.0 - smoothstep(-v_ShadowBlur, v_ShadowBlur, shadow_distance);
+    }
+
+    return step(shadow_distance, 0.0);
+}
+
 void main() {
     vec4 mixed_color;
 
     vec2 fragCoord = vec2(gl_FragCoord.x, u_ScreenHeight - gl_FragCoord.y);
 
+    if (v_ShadowKind > 0.5) {
+        o_Color = vec4(
+            v_ShadowColor.xyz,
+            v_ShadowColor.w * drop_shadow_alpha(fragCoord)
+        );
+
+        return;
+    }
+
+    vec4 background = v_Color;
+
+    if (v_ShadowColor.w > 0.0) {
+        float shadow_alpha = v_ShadowColor.w * inner_shadow_alpha(fragCoord);
+        float alpha = shadow_alpha + background.w * (1.0 - shadow_alpha);
+
+        if (alpha > 0.0) {
+            background = vec4(
+                (v_ShadowColor.xyz * shadow_alpha
+                    + background.xyz * background.w * (1.0 - shadow_alpha)) / alpha,
+                alpha
+            );
+        }
+    }
+
     // TODO: Remove branching (?)
-    if(v_BorderWidth > 0) {
-        float internal_border = max(v_BorderRadius - v_BorderWidth, 0.0);
+    float border_width = max_border_width();
+
+    if(border_width > 0) {
+        float internal_border = max(v_BorderRadius - border_width, 0.0);
 
         float internal_distance = distance(
             fragCoord,
-            v_Pos + vec2(v_BorderWidth),
-            v_Scale - vec2(v_BorderWidth * 2.0),
+            v_Pos + vec2(v_BorderWidth.w, v_BorderWidth.x),
+            v_Scale - vec2(
+                v_BorderWidth.y + v_BorderWidth.w,
+                v_BorderWidth.x + v_BorderWidth.z
+            ),
             internal_border
         );
 
@@ -51,9 +256,13 @@ void main() {
             internal_distance
         );
 
-        mixed_color = mix(v_Color, v_BorderColor, border_mix);
+        if (v_BorderStyle > 0.5) {
+            border_mix *= dash_alpha(fragCoord);
+        }
+
+        mixed_color = mix(background, v_BorderColor, border_mix);
     } else {
-        mixed_color = v_Color;
+        mixed_color = background;
     }
 
     float d = distance(
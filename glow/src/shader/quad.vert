@@ -8,14 +8,28 @@ layout(location = 1) in vec2 i_Scale;
 layout(location = 2) in vec4 i_Color;
 layout(location = 3) in vec4 i_BorderColor;
 layout(location = 4) in float i_BorderRadius;
-layout(location = 5) in float i_BorderWidth;
+layout(location = 5) in vec4 i_BorderWidth;
+layout(location = 6) in uint i_BorderStyle;
+layout(location = 7) in vec2 i_BorderDash;
+layout(location = 8) in float i_BorderOffset;
+layout(location = 9) in vec4 i_ShadowColor;
+layout(location = 10) in vec2 i_ShadowOffset;
+layout(location = 11) in float i_ShadowBlur;
+layout(location = 12) in uint i_ShadowKind;
 
 out vec4 v_Color;
 out vec4 v_BorderColor;
 out vec2 v_Pos;
 out vec2 v_Scale;
 out float v_BorderRadius;
-out float v_BorderWidth;
+out vec4 v_BorderWidth;
+out float v_BorderStyle;
+out vec2 v_BorderDash;
+out float v_BorderOffset;
+out vec4 v_ShadowColor;
+out vec2 v_ShadowOffset;
+out float v_ShadowBlur;
+out float v_ShadowKind;
 
 const vec2 positions[4] = vec2[](
     vec2(0.0, 0.0),
@@ -47,6 +61,13 @@ void main() {
     v_Scale = p_Scale;
     v_BorderRadius = i_BorderRadius * u_Scale;
     v_BorderWidth = i_BorderWidth * u_Scale;
+    v_BorderStyle = float(i_BorderStyle);
+    v_BorderDash = i_BorderDash * u_Scale;
+    v_BorderOffset = i_BorderOffset * u_Scale;
+    v_ShadowColor = i_ShadowColor;
+    v_ShadowOffset = i_ShadowOffset * u_Scale;
+    v_ShadowBlur = i_ShadowBlur * u_Scale;
+    v_ShadowKind = float(i_ShadowKind);
 
     gl_Position = u_Transform * i_Transform * vec4(q_Pos, 0.0, 1.0);
 }
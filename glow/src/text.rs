@@ -1,6 +1,7 @@
-use crate::Transformation;
+use crate::{Hinting, Transformation};
 
-use iced_graphics::font;
+use iced_graphics::font::{self, Hinted};
+use iced_native::WritingMode;
 
 use glow_glyph::ab_glyph;
 use std::{cell::RefCell, collections::HashMap};
@@ -12,6 +13,9 @@ pub struct Pipeline {
     draw_brush: RefCell<glow_glyph::GlyphBrush>,
     draw_font_map: RefCell<HashMap<String, glow_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    emoji_font: Option<glow_glyph::FontId>,
+    hinting: Hinting,
+    subpixel_positioning: bool,
 }
 
 impl Pipeline {
@@ -19,6 +23,8 @@ impl Pipeline {
         gl: &glow::Context,
         default_font: Option<&[u8]>,
         multithreading: bool,
+        hinting: Hinting,
+        subpixel_positioning: bool,
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
 
@@ -46,24 +52,55 @@ impl Pipeline {
                     .expect("Load fallback font")
             });
 
-        let draw_brush =
+        let mut draw_brush =
             glow_glyph::GlyphBrushBuilder::using_font(font.clone())
                 .initial_cache_size((2048, 2048))
                 .draw_cache_multithread(multithreading)
                 .build(&gl);
 
-        let measure_brush =
+        let mut measure_brush =
             glyph_brush::GlyphBrushBuilder::using_font(font).build();
 
+        // Only the outlines of the emoji font are drawn, without colors
+        #[cfg(feature = "emoji")]
+        let emoji_font = font::emoji::load()
+            .and_then(|bytes| ab_glyph::FontArc::try_from_vec(bytes).ok())
+            .map(|font| {
+                let _ = measure_brush.add_font(font.clone());
+
+                draw_brush.add_font(font)
+            });
+
+        #[cfg(not(feature = "emoji"))]
+        let emoji_font = None;
+
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            emoji_font,
+            hinting,
+            subpixel_positioning,
         }
     }
 
-    pub fn queue(&mut self, section: glow_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue(
+        &mut self,
+        section: glow_glyph::Section<'_>,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) {
+        let layout = Hinted::new(
+            section.layout,
+            self.hinting,
+            self.subpixel_positioning,
+            letter_spacing,
+            writing_mode,
+        );
+
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, &layout);
     }
 
     pub fn draw_queued(
@@ -88,24 +125,36 @@ impl Pipeline {
         size: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
     ) -> (f32, f32) {
         use glow_glyph::GlyphCruncher;
 
-        let glow_glyph::FontId(font_id) = self.find_font(font);
+        let (_, text) = self.sections(
+            content,
+            self.find_font(font),
+            size.into(),
+            glow_glyph::Extra::default(),
+        );
 
         let section = glow_glyph::Section {
             bounds: (bounds.width, bounds.height),
-            text: vec![glow_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: glow_glyph::FontId(font_id),
-                extra: glow_glyph::Extra::default(),
-            }],
+            text,
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
+        let layout = Hinted::new(
+            section.layout,
+            Hinting::None,
+            false,
+            letter_spacing,
+            writing_mode,
+        );
+
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
         {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
@@ -122,47 +171,7 @@ impl Pipeline {
         point: iced_native::Point,
         nearest_only: bool,
     ) -> Option<Hit> {
-        use glow_glyph::GlyphCruncher;
-
-        let glow_glyph::FontId(font_id) = self.find_font(font);
-
-        let section = glow_glyph::Section {
-            bounds: (bounds.width, bounds.height),
-            text: vec![glow_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: glow_glyph::FontId(font_id),
-                extra: glow_glyph::Extra::default(),
-            }],
-            ..Default::default()
-        };
-
-        let mut mb = self.measure_brush.borrow_mut();
-
-        // The underlying type is FontArc, so clones are cheap.
-        use ab_glyph::{Font, ScaleFont};
-        let font = mb.fonts()[font_id].clone().into_scaled(size);
-
-        // Implements an iterator over the glyph bounding boxes.
-        let bounds = mb.glyphs(section).map(
-            |glow_glyph::SectionGlyph {
-                 byte_index, glyph, ..
-             }| {
-                (
-                    *byte_index,
-                    iced_native::Rectangle::new(
-                        iced_native::Point::new(
-                            glyph.position.x - font.h_side_bearing(glyph.id),
-                            glyph.position.y - font.ascent(),
-                        ),
-                        iced_native::Size::new(
-                            font.h_advance(glyph.id),
-                            font.ascent() - font.descent(),
-                        ),
-                    ),
-                )
-            },
-        );
+        let bounds = self.glyph_bounds(content, size, font, bounds);
 
         // Implements computation of the character index based on the byte index
         // within the input string.
@@ -180,14 +189,15 @@ impl Pipeline {
         };
 
         if !nearest_only {
-            for (idx, bounds) in bounds.clone() {
+            for (idx, bounds) in bounds.iter() {
                 if bounds.contains(point) {
-                    return Some(Hit::CharOffset(char_index(idx)));
+                    return Some(Hit::CharOffset(char_index(*idx)));
                 }
             }
         }
 
         let nearest = bounds
+            .into_iter()
             .map(|(index, bounds)| (index, bounds.center()))
             .min_by(|(_, center_a), (_, center_b)| {
                 center_a
@@ -201,6 +211,137 @@ impl Pipeline {
         })
     }
 
+    pub fn glyph_positions(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<iced_native::Rectangle> {
+        self.glyph_bounds(content, size, font, bounds)
+            .into_iter()
+            .map(|(_, bounds)| bounds)
+            .collect()
+    }
+
+    /// Returns the byte index and the bounding box of every glyph of some
+    /// laid out content, in order.
+    fn glyph_bounds(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<(usize, iced_native::Rectangle)> {
+        use glow_glyph::GlyphCruncher;
+
+        let (starts, text) = self.sections(
+            content,
+            self.find_font(font),
+            size.into(),
+            glow_glyph::Extra::default(),
+        );
+
+        let section = glow_glyph::Section {
+            bounds: (bounds.width, bounds.height),
+            text,
+            ..Default::default()
+        };
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        use ab_glyph::{Font, ScaleFont};
+        let fonts: Vec<_> = mb
+            .fonts()
+            .iter()
+            .map(|font| font.clone().into_scaled(size))
+            .collect();
+
+        mb.glyphs(section)
+            .map(
+                |glow_glyph::SectionGlyph {
+                     section_index,
+                     byte_index,
+                     glyph,
+                     font_id,
+                 }| {
+                    let font = &fonts[font_id.0];
+
+                    (
+                        starts[*section_index] + *byte_index,
+                        iced_native::Rectangle::new(
+                            iced_native::Point::new(
+                                glyph.position.x
+                                    - font.h_side_bearing(glyph.id),
+                                glyph.position.y - font.ascent(),
+                            ),
+                            iced_native::Size::new(
+                                font.h_advance(glyph.id),
+                                font.ascent() - font.descent(),
+                            ),
+                        ),
+                    )
+                },
+            )
+            .collect()
+    }
+
+    /// Splits some content into the sections of text laid out by the brushes,
+    /// alongside the byte index where each section starts.
+    ///
+    /// Emoji missing from the given font are laid out with the emoji font,
+    /// if one was found.
+    pub fn sections<'a>(
+        &self,
+        content: &'a str,
+        font_id: glow_glyph::FontId,
+        scale: ab_glyph::PxScale,
+        extra: glow_glyph::Extra,
+    ) -> (Vec<usize>, Vec<glow_glyph::Text<'a>>) {
+        let text = |text, font_id| glow_glyph::Text {
+            text,
+            scale,
+            font_id,
+            extra,
+        };
+
+        let emoji_font = match self.emoji_font {
+            Some(emoji_font) if font_id != emoji_font => emoji_font,
+            _ => return (vec![0], vec![text(content, font_id)]),
+        };
+
+        #[cfg(feature = "emoji")]
+        {
+            use ab_glyph::Font;
+
+            let brush = self.measure_brush.borrow();
+            let fonts = brush.fonts();
+
+            font::emoji::runs(
+                content,
+                |c| fonts[font_id.0].glyph_id(c).0 == 0,
+                |c| fonts[emoji_font.0].glyph_id(c).0 != 0,
+            )
+            .into_iter()
+            .flat_map(|run| {
+                let font_id = if run.is_emoji { emoji_font } else { font_id };
+
+                run.pieces()
+                    .into_iter()
+                    .map(move |(start, piece)| (start, text(piece, font_id)))
+            })
+            .unzip()
+        }
+
+        #[cfg(not(feature = "emoji"))]
+        {
+            let _ = emoji_font;
+
+            (vec![0], vec![text(content, font_id)])
+        }
+    }
+
     pub fn trim_measurement_cache(&mut self) {
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
@@ -225,6 +366,19 @@ impl Pipeline {
         }
     }
 
+    /// Returns the physical position where a section of text starting at the
+    /// given one is drawn.
+    ///
+    /// It is snapped to whole pixels, unless subpixel positioning is enabled
+    /// and it is only snapped to a quarter of a pixel horizontally.
+    pub fn snap_origin(&self, x: f32, y: f32) -> (f32, f32) {
+        if self.subpixel_positioning {
+            (font::quantize(x), y.round())
+        } else {
+            (x.round(), y.round())
+        }
+    }
+
     pub fn find_font(&self, font: iced_native::Font) -> glow_glyph::FontId {
         match font {
             iced_native::Font::Default => glow_glyph::FontId(0),
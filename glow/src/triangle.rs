@@ -5,7 +5,9 @@ use glow::HasContext;
 use iced_graphics::layer;
 use std::marker::PhantomData;
 
-pub use iced_graphics::triangle::{Mesh2D, Vertex2D};
+pub use iced_graphics::triangle::{
+    Mesh2D, TexturedMesh2D, Vertex2D, Vertex2DTextured,
+};
 
 const VERTEX_BUFFER_SIZE: usize = 10_000;
 const INDEX_BUFFER_SIZE: usize = 10_000;
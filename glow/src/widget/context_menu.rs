@@ -0,0 +1,14 @@
+//! Show a menu at the cursor position on right click.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::context_menu::State;
+
+/// An element that displays a menu at the cursor position when its content is
+/// right clicked.
+///
+/// This is an alias of an `iced_native` context menu with an
+/// `iced_glow::Renderer`.
+pub type ContextMenu<'a, Message> =
+    iced_native::ContextMenu<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Show a bar of dropdown menus, like File, Edit, and View.
+//!
+//! A [`MenuBar`] has some local [`State`].
+pub use iced_native::menu_bar::{Menu, MenuItem, State};
+
+pub use iced_graphics::menu_bar::{Style, StyleSheet};
+
+/// A horizontal bar of menus, each one opening a dropdown of menu items.
+///
+/// This is an alias of an `iced_native` menu bar with an
+/// `iced_glow::Renderer`.
+pub type MenuBar<'a, Message> =
+    iced_native::MenuBar<'a, Message, crate::Renderer>;
@@ -10,4 +10,4 @@ pub use iced_graphics::progress_bar::{Style, StyleSheet};
 ///
 /// This is an alias of an `iced_native` progress bar with an
 /// `iced_wgpu::Renderer`.
-pub type ProgressBar = iced_native::ProgressBar<Renderer>;
+pub type ProgressBar<T = f32> = iced_native::ProgressBar<Renderer, T>;
@@ -3,6 +3,7 @@ use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 use core::ffi::c_void;
 use glow::HasContext;
 use iced_graphics::{Antialiasing, Size};
+use iced_native::futures::channel::oneshot;
 use iced_native::mouse;
 
 /// A window graphics backend for iced powered by `glow`.
@@ -76,6 +77,54 @@ impl iced_graphics::window::GLCompositor for Compositor {
             gl.clear(glow::COLOR_BUFFER_BIT);
         }
 
-        renderer.backend_mut().draw(gl, viewport, output, overlay)
+        let mouse_interaction =
+            renderer.backend_mut().draw(gl, viewport, output, overlay);
+
+        let senders = renderer.backend_mut().take_frame_captures();
+
+        if !senders.is_empty() {
+            let pixels = read_frame(gl, viewport.physical_size());
+
+            for sender in senders {
+                let _ = sender.send(pixels.clone());
+            }
+        }
+
+        mouse_interaction
     }
+
+    fn capture_next_frame(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        sender: oneshot::Sender<Vec<u8>>,
+    ) {
+        renderer.backend_mut().capture_next_frame(sender);
+    }
+}
+
+/// Reads the RGBA pixels of the default framebuffer, row by row from the top.
+fn read_frame(gl: &glow::Context, size: Size<u32>) -> Vec<u8> {
+    let row_length = size.width as usize * 4;
+    let mut pixels = vec![0; row_length * size.height as usize];
+
+    unsafe {
+        gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+        gl.read_pixels(
+            0,
+            0,
+            size.width as i32,
+            size.height as i32,
+            glow::RGBA,
+            glow::UNSIGNED_BYTE,
+            &mut pixels,
+        );
+    }
+
+    // OpenGL reads rows from the bottom
+    pixels
+        .chunks_exact(row_length.max(1))
+        .rev()
+        .flatten()
+        .copied()
+        .collect()
 }
@@ -1,5 +1,5 @@
 //! Create interactive, native cross-platform applications.
-use crate::{mouse, Error, Executor, Runtime};
+use crate::{mouse, trace, Error, Executor, Runtime};
 
 pub use iced_winit::Application;
 
@@ -8,10 +8,15 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
-use iced_winit::{Cache, Clipboard, Debug, Proxy, Settings};
+use iced_winit::gesture;
+use iced_winit::keyboard;
+use iced_winit::{Cache, Clipboard, Debug, Proxy, Renderer as _, Settings};
 
 use glutin::window::Window;
+use std::cell::Cell;
 use std::mem::ManuallyDrop;
+use std::rc::Rc;
+use std::time::Instant;
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
 /// settings.
@@ -88,16 +93,24 @@ where
 
     let mut clipboard = Clipboard::connect(context.window());
 
+    let mut state = application::State::new(&application, context.window());
+    state.set_scale_factor_override(
+        context.window(),
+        settings.scale_factor_override,
+    );
+
     application::run_command(
         init_command,
         &mut runtime,
         &mut clipboard,
         &mut proxy,
+        &mut state,
         context.window(),
     );
     runtime.track(subscription);
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let wake_up_at = Rc::new(Cell::new(None));
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -108,8 +121,13 @@ where
         proxy,
         debug,
         receiver,
+        state,
         context,
         settings.exit_on_close_request,
+        window::FramePacer::new(settings.max_fps),
+        gesture::LongPress::new(settings.long_press_duration),
+        gesture::DoubleClick::new(settings.double_click_interval),
+        wake_up_at.clone(),
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -142,7 +160,10 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => match wake_up_at.get() {
+                    Some(instant) => ControlFlow::WaitUntil(instant),
+                    None => ControlFlow::Wait,
+                },
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -158,8 +179,13 @@ async fn run_instance<A, E, C>(
     mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    mut state: application::State<A>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     exit_on_close_request: bool,
+    mut frame_pacer: window::FramePacer,
+    mut long_press: gesture::LongPress,
+    mut double_click: gesture::DoubleClick,
+    wake_up_at: Rc<Cell<Option<Instant>>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -168,19 +194,21 @@ async fn run_instance<A, E, C>(
     use glutin::event;
     use iced_winit::futures::stream::StreamExt;
 
-    let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
+    let mut pinch = gesture::Pinch::new();
+    let mut keyboard = keyboard::Tracker::new();
     let mut user_interface =
         ManuallyDrop::new(application::build_user_interface(
             &mut application,
             Cache::default(),
             &mut renderer,
             state.logical_size(),
+            state.safe_area(),
             &mut debug,
         ));
 
-    let mut primitive =
-        user_interface.draw(&mut renderer, state.cursor_position());
+    let mut primitive = user_interface.draw(&mut renderer, state.cursor());
+    let mut is_animating = renderer.take_redraw_request();
     let mut mouse_interaction = mouse::Interaction::default();
 
     let mut events = Vec::new();
@@ -191,15 +219,39 @@ async fn run_instance<A, E, C>(
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                events.extend(
+                    long_press
+                        .recognize(Instant::now())
+                        .into_iter()
+                        .map(iced_winit::Event::Touch),
+                );
+
+                if frame_pacer.resume(Instant::now()) {
+                    context.window().request_redraw();
+                }
+
+                // Widgets that requested a redraw are animated to the time of
+                // this frame
+                if is_animating {
+                    let now = Instant::now();
+
+                    renderer.advance_clock(now);
+                    events.push(iced_winit::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    ));
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
 
                 debug.event_processing_started();
 
+                keyboard.complete(&mut events);
+
                 let statuses = user_interface.update(
                     &events,
-                    state.cursor_position(),
+                    state.cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
@@ -207,8 +259,12 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                {
+                    let _span = trace::subscriptions();
+
+                    for event in events.drain(..).zip(statuses.into_iter()) {
+                        runtime.broadcast(event);
+                    }
                 }
 
                 if !messages.is_empty() {
@@ -223,6 +279,7 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut state,
                         context.window(),
                     );
 
@@ -237,6 +294,7 @@ async fn run_instance<A, E, C>(
                             cache,
                             &mut renderer,
                             state.logical_size(),
+                            state.safe_area(),
                             &mut debug,
                         ));
 
@@ -246,8 +304,8 @@ async fn run_instance<A, E, C>(
                 }
 
                 debug.draw_started();
-                primitive =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                primitive = user_interface.draw(&mut renderer, state.cursor());
+                is_animating = renderer.take_redraw_request();
                 debug.draw_finished();
 
                 context.window().request_redraw();
@@ -265,7 +323,25 @@ async fn run_instance<A, E, C>(
             event::Event::UserEvent(message) => {
                 messages.push(message);
             }
+            event::Event::RedrawEventsCleared => {
+                // Fingers staying still do not produce any events, so the
+                // event loop needs to wake up to recognize long presses, as
+                // well as to present deferred frames
+                wake_up_at.set(
+                    long_press
+                        .deadline()
+                        .into_iter()
+                        .chain(frame_pacer.deadline())
+                        .min(),
+                );
+            }
             event::Event::RedrawRequested(_) => {
+                // Frames over the maximum rate are presented once the event
+                // loop wakes up at the deadline of the pacer
+                if !frame_pacer.ready() {
+                    continue;
+                }
+
                 debug.render_started();
 
                 #[allow(unsafe_code)]
@@ -286,13 +362,18 @@ async fn run_instance<A, E, C>(
                     debug.layout_started();
                     user_interface = ManuallyDrop::new(
                         ManuallyDrop::into_inner(user_interface)
-                            .relayout(logical_size, &mut renderer),
+                            .relayout_in_safe_area(
+                                logical_size,
+                                state.safe_area(),
+                                &mut renderer,
+                            ),
                     );
                     debug.layout_finished();
 
                     debug.draw_started();
-                    primitive = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    primitive =
+                        user_interface.draw(&mut renderer, state.cursor());
+                    is_animating = renderer.take_redraw_request();
                     debug.draw_finished();
 
                     context.resize(glutin::dpi::PhysicalSize::new(
@@ -305,6 +386,10 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
+                for sender in state.take_frame_captures() {
+                    compositor.capture_next_frame(&mut renderer, sender);
+                }
+
                 let new_mouse_interaction = compositor.draw(
                     &mut renderer,
                     state.viewport(),
@@ -318,15 +403,22 @@ async fn run_instance<A, E, C>(
                 debug.render_finished();
 
                 if new_mouse_interaction != mouse_interaction {
+                    context.window().set_cursor_visible(
+                        conversion::cursor_visible(&new_mouse_interaction),
+                    );
                     context.window().set_cursor_icon(
-                        conversion::mouse_interaction(new_mouse_interaction),
+                        conversion::mouse_interaction(
+                            new_mouse_interaction.clone(),
+                        ),
                     );
 
                     mouse_interaction = new_mouse_interaction;
                 }
 
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
+                // Keep drawing frames while widgets are animating
+                if is_animating {
+                    context.window().request_redraw();
+                }
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -345,7 +437,32 @@ async fn run_instance<A, E, C>(
                     state.scale_factor(),
                     state.modifiers(),
                 ) {
+                    let horizontal_scroll =
+                        conversion::horizontal_scroll(&event);
+
+                    let event = match event {
+                        iced_winit::Event::Mouse(mouse_event) => {
+                            iced_winit::Event::Mouse(
+                                double_click.track(mouse_event, Instant::now()),
+                            )
+                        }
+                        event => event,
+                    };
+
+                    let gestures =
+                        if let iced_winit::Event::Touch(touch_event) = &event {
+                            long_press.track(touch_event, Instant::now());
+
+                            pinch.track(touch_event)
+                        } else {
+                            Vec::new()
+                        };
+
                     events.push(event);
+                    events.extend(horizontal_scroll);
+                    events.extend(
+                        gestures.into_iter().map(iced_winit::Event::Touch),
+                    );
                 }
             }
             _ => {}
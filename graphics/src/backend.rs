@@ -2,7 +2,7 @@
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
-use iced_native::{Font, Point, Size};
+use iced_native::{Font, Point, Rectangle, Size, WritingMode};
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -48,6 +48,47 @@ pub trait Text {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Measures the text contents like [`measure`], adding the given spacing
+    /// between every two consecutive glyphs of a line.
+    ///
+    /// By default, the letter spacing is ignored.
+    ///
+    /// [`measure`]: Self::measure
+    fn measure_with_letter_spacing(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        _letter_spacing: f32,
+    ) -> (f32, f32) {
+        self.measure(contents, size, font, bounds)
+    }
+
+    /// Measures the text contents like [`measure_with_letter_spacing`],
+    /// laying out its lines with the given [`WritingMode`].
+    ///
+    /// By default, the writing mode is ignored.
+    ///
+    /// [`measure_with_letter_spacing`]: Self::measure_with_letter_spacing
+    fn measure_with_writing_mode(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+        _writing_mode: WritingMode,
+    ) -> (f32, f32) {
+        self.measure_with_letter_spacing(
+            contents,
+            size,
+            font,
+            bounds,
+            letter_spacing,
+        )
+    }
+
     /// Tests whether the provided point is within the boundaries of [`Text`]
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -64,6 +105,20 @@ pub trait Text {
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit>;
+
+    /// Returns the bounding rectangle of every glyph of the text contents laid
+    /// out with the given size, font, and bounds, in order.
+    ///
+    /// The rectangles are relative to the top-left corner of the text. They
+    /// can be used to paint the highlight of a selection spanning multiple
+    /// characters.
+    fn glyph_positions(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<Rectangle>;
 }
 
 /// A graphics backend that supports image rendering.
@@ -2,7 +2,7 @@
 use iced_native::Color;
 
 /// Some default styling attributes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Defaults {
     /// Text styling
     pub text: Text,
@@ -17,7 +17,7 @@ impl Default for Defaults {
 }
 
 /// Some default text styling attributes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Text {
     /// The default color of text
     pub color: Color,
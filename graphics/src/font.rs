@@ -2,10 +2,21 @@
 #[cfg(feature = "font-source")]
 mod source;
 
+#[cfg(feature = "glyph-brush")]
+mod hinted;
+
+#[cfg(feature = "color-emoji")]
+#[cfg_attr(docsrs, doc(cfg(feature = "color-emoji")))]
+pub mod emoji;
+
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
 pub use source::Source;
 
+#[cfg(feature = "glyph-brush")]
+#[cfg_attr(docsrs, doc(cfg(feature = "glyph-brush")))]
+pub use hinted::{quantize, Hinted, SUBPIXEL_STEPS};
+
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
 pub use font_kit::{
@@ -0,0 +1,213 @@
+//! Fall back to a color emoji font for the emoji of some text.
+use crate::font::{Family, Source};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The families of the color emoji fonts looked up in the system, by
+/// priority.
+const FAMILIES: [&str; 4] = [
+    "Noto Color Emoji",
+    "Apple Color Emoji",
+    "Segoe UI Emoji",
+    "Twemoji Mozilla",
+];
+
+/// Finds and loads a color emoji font installed in the system.
+pub fn load() -> Option<Vec<u8>> {
+    let families: Vec<_> = FAMILIES
+        .iter()
+        .map(|family| Family::Title(String::from(*family)))
+        .collect();
+
+    Source::new().load(&families).ok()
+}
+
+/// A run of some text drawn with the same font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run<'a> {
+    /// The byte index of the [`Run`] in the text.
+    pub start: usize,
+
+    /// The contents of the [`Run`].
+    pub content: &'a str,
+
+    /// Whether the [`Run`] is an emoji cluster.
+    pub is_emoji: bool,
+}
+
+impl<'a> Run<'a> {
+    /// Splits the [`Run`] into the pieces laid out as separate sections, with
+    /// the byte index where each of them starts in the text.
+    ///
+    /// The glyphs of an emoji cluster are not composed without text shaping,
+    /// so every emoji of the cluster is laid out on its own, side by side.
+    /// Joiners and variation selectors are left out.
+    pub fn pieces(self) -> Vec<(usize, &'a str)> {
+        if !self.is_emoji {
+            return vec![(self.start, self.content)];
+        }
+
+        self.content
+            .char_indices()
+            .filter(|(_, c)| !is_joiner_or_selector(*c))
+            .map(|(i, c)| (self.start + i, &self.content[i..i + c.len_utf8()]))
+            .collect()
+    }
+}
+
+/// Splits some text into runs, isolating every emoji cluster in its own
+/// [`Run`].
+///
+/// A grapheme cluster is an emoji if it requests the emoji presentation, if it
+/// is a ZWJ sequence, if it starts with a pictograph, or if it starts with a
+/// `char` missing from the primary font that the emoji font has.
+pub fn runs<'a>(
+    content: &'a str,
+    is_missing: impl Fn(char) -> bool,
+    is_emoji_available: impl Fn(char) -> bool,
+) -> Vec<Run<'a>> {
+    let mut runs: Vec<Run<'a>> = Vec::new();
+    let mut text_start = None;
+
+    for (start, cluster) in content.grapheme_indices(true) {
+        let first = match cluster.chars().next() {
+            Some(first) => first,
+            None => continue,
+        };
+
+        let is_emoji = is_emoji_available(first)
+            && (is_pictograph(first)
+                || cluster.contains(|c| c == '\u{FE0F}' || c == '\u{200D}')
+                || is_missing(first));
+
+        if is_emoji {
+            if let Some(text_start) = text_start.take() {
+                runs.push(Run {
+                    start: text_start,
+                    content: &content[text_start..start],
+                    is_emoji: false,
+                });
+            }
+
+            runs.push(Run {
+                start,
+                content: cluster,
+                is_emoji: true,
+            });
+        } else if text_start.is_none() {
+            text_start = Some(start);
+        }
+    }
+
+    if let Some(text_start) = text_start {
+        runs.push(Run {
+            start: text_start,
+            content: &content[text_start..],
+            is_emoji: false,
+        });
+    }
+
+    runs
+}
+
+/// Returns whether a `char` belongs to the pictograph blocks of Unicode,
+/// which are presented as emoji by default.
+fn is_pictograph(c: char) -> bool {
+    matches!(c as u32, 0x1F000..=0x1FAFF)
+}
+
+/// Returns whether a `char` joins the emoji of a ZWJ sequence or selects the
+/// presentation of an emoji.
+fn is_joiner_or_selector(c: char) -> bool {
+    matches!(c, '\u{200D}' | '\u{FE0E}' | '\u{FE0F}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emoji_runs(content: &str) -> Vec<Run<'_>> {
+        runs(content, |c| c == '\u{2705}', |_| true)
+    }
+
+    #[test]
+    fn emoji_are_split_from_the_text() {
+        assert_eq!(
+            emoji_runs("Deploy \u{1F680} done \u{2705}"),
+            vec![
+                Run {
+                    start: 0,
+                    content: "Deploy ",
+                    is_emoji: false,
+                },
+                Run {
+                    start: 7,
+                    content: "\u{1F680}",
+                    is_emoji: true,
+                },
+                Run {
+                    start: 11,
+                    content: " done ",
+                    is_emoji: false,
+                },
+                Run {
+                    start: 17,
+                    content: "\u{2705}",
+                    is_emoji: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn zwj_sequences_are_a_single_emoji() {
+        // Family: man, woman, girl
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let content = format!("a{}b", family);
+
+        let runs = emoji_runs(&content);
+
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[1].start, 1);
+        assert_eq!(runs[1].content, family);
+        assert!(runs[1].is_emoji);
+        assert_eq!(runs[2].start, 1 + family.len());
+    }
+
+    #[test]
+    fn every_emoji_of_a_cluster_is_a_piece() {
+        // Family: man, woman, girl
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let content = format!("a{}\u{2764}\u{FE0F}", family);
+
+        let pieces: Vec<_> = emoji_runs(&content)
+            .into_iter()
+            .flat_map(Run::pieces)
+            .collect();
+
+        assert_eq!(
+            pieces,
+            vec![
+                (0, "a"),
+                (1, "\u{1F468}"),
+                (8, "\u{1F469}"),
+                (15, "\u{1F467}"),
+                (19, "\u{2764}"),
+            ]
+        );
+    }
+
+    #[test]
+    fn text_is_kept_without_an_emoji_font() {
+        let runs = runs("Deploy \u{1F680}", |_| true, |_| false);
+
+        assert_eq!(
+            runs,
+            vec![Run {
+                start: 0,
+                content: "Deploy \u{1F680}",
+                is_emoji: false,
+            }]
+        );
+    }
+}
@@ -0,0 +1,568 @@
+use crate::{Hinting, WritingMode};
+
+use glyph_brush::ab_glyph;
+
+/// The amount of horizontal positions a glyph can take inside a pixel with
+/// subpixel positioning.
+pub const SUBPIXEL_STEPS: f32 = 4.0;
+
+/// Moves a horizontal physical position to the closest subpixel step.
+pub fn quantize(x: f32) -> f32 {
+    (x * SUBPIXEL_STEPS).round() / SUBPIXEL_STEPS
+}
+
+/// A [`glyph_brush::GlyphPositioner`] that adds some letter spacing between
+/// the glyphs placed by a [`glyph_brush::Layout`], and snaps them to the
+/// pixel grid following some [`Hinting`].
+///
+/// With subpixel positioning, glyphs are snapped horizontally to a quarter of
+/// a pixel instead, even with [`Hinting::Full`].
+///
+/// The spacing is part of the advance of every glyph, so lines are broken
+/// with it.
+///
+/// With [`WritingMode::VerticalRL`], the glyphs of every line are stacked
+/// from top to bottom instead, and lines are placed from right to left.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hinted {
+    layout: glyph_brush::Layout<glyph_brush::BuiltInLineBreaker>,
+    hinting: Hinting,
+    subpixel_positioning: bool,
+    letter_spacing: f32,
+    writing_mode: WritingMode,
+}
+
+impl Hinted {
+    /// Creates a new [`Hinted`] positioner wrapping the given layout.
+    pub fn new(
+        layout: glyph_brush::Layout<glyph_brush::BuiltInLineBreaker>,
+        hinting: Hinting,
+        subpixel_positioning: bool,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) -> Self {
+        Hinted {
+            layout,
+            hinting,
+            subpixel_positioning,
+            letter_spacing,
+            writing_mode,
+        }
+    }
+
+    /// Lays out the glyphs of some sections with the letter spacing added to
+    /// the advance of every glyph.
+    ///
+    /// The spacing after the last glyph of a line is not part of the line, so
+    /// it is discounted when breaking and aligning the lines.
+    fn spaced_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &glyph_brush::SectionGeometry,
+        sections: &[S],
+    ) -> Vec<glyph_brush::SectionGlyph>
+    where
+        F: ab_glyph::Font,
+        S: glyph_brush::ToSectionText,
+    {
+        use glyph_brush::GlyphPositioner;
+
+        // The sections of a text share the same scale
+        let scale = match sections.first() {
+            Some(section) => section.to_section_text().scale,
+            None => return Vec::new(),
+        };
+
+        let fonts: Vec<_> = fonts
+            .iter()
+            .map(|font| Spaced::new(font, self.letter_spacing, scale))
+            .collect();
+
+        let (width, height) = geometry.bounds;
+
+        let mut glyphs = self.layout.calculate_glyphs(
+            &fonts,
+            &glyph_brush::SectionGeometry {
+                bounds: (width + self.letter_spacing, height),
+                ..*geometry
+            },
+            sections,
+        );
+
+        let h_align = match self.layout {
+            glyph_brush::Layout::SingleLine { h_align, .. }
+            | glyph_brush::Layout::Wrap { h_align, .. } => h_align,
+        };
+
+        let shift = match h_align {
+            glyph_brush::HorizontalAlign::Left => 0.0,
+            glyph_brush::HorizontalAlign::Center => self.letter_spacing / 2.0,
+            glyph_brush::HorizontalAlign::Right => self.letter_spacing,
+        };
+
+        for section_glyph in glyphs.iter_mut() {
+            section_glyph.glyph.position.x += shift;
+        }
+
+        glyphs
+    }
+
+    /// Lays out the glyphs of some sections in columns, following
+    /// [`WritingMode::VerticalRL`].
+    ///
+    /// The glyphs are laid out horizontally first, so every line keeps its
+    /// hard breaks and becomes a column. Columns are broken when they exceed
+    /// the height of the bounds.
+    fn vertical_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &glyph_brush::SectionGeometry,
+        sections: &[S],
+    ) -> Vec<glyph_brush::SectionGlyph>
+    where
+        F: ab_glyph::Font,
+        S: glyph_brush::ToSectionText,
+    {
+        use ab_glyph::ScaleFont;
+        use glyph_brush::GlyphPositioner;
+
+        let (h_align, v_align) = match self.layout {
+            glyph_brush::Layout::SingleLine {
+                h_align, v_align, ..
+            }
+            | glyph_brush::Layout::Wrap {
+                h_align, v_align, ..
+            } => (h_align, v_align),
+        };
+
+        let mut glyphs = glyph_brush::Layout::default_wrap().calculate_glyphs(
+            fonts,
+            &glyph_brush::SectionGeometry {
+                screen_position: (0.0, 0.0),
+                bounds: (f32::INFINITY, f32::INFINITY),
+            },
+            sections,
+        );
+
+        let max_height = geometry.bounds.1;
+
+        let mut baseline = None;
+        let mut wrapped = 0.0;
+        let mut top = 0.0;
+        let mut width: f32 = 0.0;
+        let mut height: f32 = 0.0;
+
+        // Columns grow to the left of the origin and down from it
+        for section_glyph in glyphs.iter_mut() {
+            let font = fonts[section_glyph.font_id.0]
+                .as_scaled(section_glyph.glyph.scale);
+
+            let position = section_glyph.glyph.position;
+            let advance = font.height();
+
+            if baseline != Some(position.y) {
+                baseline = Some(position.y);
+                top = 0.0;
+            } else if top + advance > max_height {
+                wrapped += advance + font.line_gap();
+                top = 0.0;
+            }
+
+            let right = position.y - font.ascent() + wrapped;
+            let h_advance = font.h_advance(section_glyph.glyph.id);
+
+            section_glyph.glyph.position = ab_glyph::point(
+                -right - (advance + h_advance) / 2.0,
+                top + font.ascent(),
+            );
+
+            width = width.max(right + advance);
+            height = height.max(top + advance);
+            top += advance + self.letter_spacing;
+        }
+
+        let (x, y) = geometry.screen_position;
+
+        let x = match h_align {
+            glyph_brush::HorizontalAlign::Left => x + width,
+            glyph_brush::HorizontalAlign::Center => x + width / 2.0,
+            glyph_brush::HorizontalAlign::Right => x,
+        };
+
+        let y = match v_align {
+            glyph_brush::VerticalAlign::Top => y,
+            glyph_brush::VerticalAlign::Center => y - height / 2.0,
+            glyph_brush::VerticalAlign::Bottom => y - height,
+        };
+
+        for section_glyph in glyphs.iter_mut() {
+            section_glyph.glyph.position.x += x;
+            section_glyph.glyph.position.y += y;
+        }
+
+        glyphs
+    }
+}
+
+impl std::hash::Hash for Hinted {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.layout.hash(state);
+        self.hinting.hash(state);
+        self.subpixel_positioning.hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.writing_mode.hash(state);
+    }
+}
+
+impl glyph_brush::GlyphPositioner for Hinted {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &glyph_brush::SectionGeometry,
+        sections: &[S],
+    ) -> Vec<glyph_brush::SectionGlyph>
+    where
+        F: ab_glyph::Font,
+        S: glyph_brush::ToSectionText,
+    {
+        let mut glyphs = match self.writing_mode {
+            WritingMode::Horizontal if self.letter_spacing != 0.0 => {
+                self.spaced_glyphs(fonts, geometry, sections)
+            }
+            WritingMode::Horizontal => {
+                self.layout.calculate_glyphs(fonts, geometry, sections)
+            }
+            WritingMode::VerticalRL => {
+                self.vertical_glyphs(fonts, geometry, sections)
+            }
+        };
+
+        if self.hinting != Hinting::None || self.subpixel_positioning {
+            for section_glyph in glyphs.iter_mut() {
+                let position = &mut section_glyph.glyph.position;
+                let (x, y) = self.hinting.snap(position.x, position.y);
+
+                let x = if self.subpixel_positioning {
+                    quantize(position.x)
+                } else {
+                    x
+                };
+
+                *position = ab_glyph::point(x, y);
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(
+        &self,
+        geometry: &glyph_brush::SectionGeometry,
+    ) -> ab_glyph::Rect {
+        self.layout.bounds_rect(geometry)
+    }
+}
+
+/// An [`ab_glyph::Font`] adding some letter spacing to the horizontal advance
+/// of its glyphs, in pixels at the given scale.
+#[derive(Debug)]
+struct Spaced<'a, F> {
+    font: &'a F,
+    spacing: f32,
+}
+
+impl<'a, F: ab_glyph::Font> Spaced<'a, F> {
+    fn new(font: &'a F, spacing: f32, scale: ab_glyph::PxScale) -> Self {
+        Spaced {
+            font,
+            spacing: spacing * font.height_unscaled() / scale.x,
+        }
+    }
+}
+
+impl<F: ab_glyph::Font> ab_glyph::Font for Spaced<'_, F> {
+    fn units_per_em(&self) -> Option<f32> {
+        self.font.units_per_em()
+    }
+
+    fn ascent_unscaled(&self) -> f32 {
+        self.font.ascent_unscaled()
+    }
+
+    fn descent_unscaled(&self) -> f32 {
+        self.font.descent_unscaled()
+    }
+
+    fn line_gap_unscaled(&self) -> f32 {
+        self.font.line_gap_unscaled()
+    }
+
+    fn glyph_id(&self, c: char) -> ab_glyph::GlyphId {
+        self.font.glyph_id(c)
+    }
+
+    fn h_advance_unscaled(&self, id: ab_glyph::GlyphId) -> f32 {
+        self.font.h_advance_unscaled(id) + self.spacing
+    }
+
+    fn h_side_bearing_unscaled(&self, id: ab_glyph::GlyphId) -> f32 {
+        self.font.h_side_bearing_unscaled(id)
+    }
+
+    fn v_advance_unscaled(&self, id: ab_glyph::GlyphId) -> f32 {
+        self.font.v_advance_unscaled(id)
+    }
+
+    fn v_side_bearing_unscaled(&self, id: ab_glyph::GlyphId) -> f32 {
+        self.font.v_side_bearing_unscaled(id)
+    }
+
+    fn kern_unscaled(
+        &self,
+        first: ab_glyph::GlyphId,
+        second: ab_glyph::GlyphId,
+    ) -> f32 {
+        self.font.kern_unscaled(first, second)
+    }
+
+    fn outline(&self, id: ab_glyph::GlyphId) -> Option<ab_glyph::Outline> {
+        self.font.outline(id)
+    }
+
+    fn glyph_count(&self) -> usize {
+        self.font.glyph_count()
+    }
+
+    fn codepoint_ids(&self) -> ab_glyph::CodepointIdIter<'_> {
+        self.font.codepoint_ids()
+    }
+
+    fn glyph_raster_image2(
+        &self,
+        id: ab_glyph::GlyphId,
+        pixel_size: u16,
+    ) -> Option<ab_glyph::v2::GlyphImage<'_>> {
+        self.font.glyph_raster_image2(id, pixel_size)
+    }
+}
+
+#[cfg(all(test, feature = "font-fallback"))]
+mod tests {
+    use super::*;
+    use crate::font;
+
+    use glyph_brush::{GlyphCruncher, GlyphPositioner};
+
+    fn positions(
+        hinting: Hinting,
+        subpixel_positioning: bool,
+    ) -> Vec<(f32, f32)> {
+        let font = ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+            .expect("Load fallback font");
+
+        let geometry = glyph_brush::SectionGeometry {
+            screen_position: (10.3, 20.6),
+            bounds: (f32::INFINITY, f32::INFINITY),
+        };
+
+        let text = glyph_brush::SectionText {
+            text: "Hinted text",
+            scale: ab_glyph::PxScale::from(17.5),
+            font_id: glyph_brush::FontId(0),
+        };
+
+        Hinted::new(
+            glyph_brush::Layout::default(),
+            hinting,
+            subpixel_positioning,
+            0.0,
+            WritingMode::Horizontal,
+        )
+        .calculate_glyphs(&[font], &geometry, &[text])
+        .into_iter()
+        .map(|section_glyph| {
+            let position = section_glyph.glyph.position;
+
+            (position.x, position.y)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn hinting_mode_is_forwarded_to_the_glyph_positions() {
+        let unhinted = positions(Hinting::None, false);
+        let vertical = positions(Hinting::Vertical, false);
+        let full = positions(Hinting::Full, false);
+
+        assert!(unhinted.iter().any(|(_, y)| y.fract() != 0.0));
+
+        for ((unhinted, vertical), full) in
+            unhinted.iter().zip(&vertical).zip(&full)
+        {
+            assert_eq!(vertical.0, unhinted.0);
+            assert_eq!(vertical.1, unhinted.1.round());
+
+            assert_eq!(full.0, unhinted.0.round());
+            assert_eq!(full.1, unhinted.1.round());
+        }
+    }
+
+    #[test]
+    fn subpixel_positioning_yields_fractional_glyph_advances() {
+        fn advances(positions: Vec<(f32, f32)>) -> Vec<f32> {
+            positions
+                .windows(2)
+                .map(|pair| pair[1].0 - pair[0].0)
+                .collect()
+        }
+
+        let whole = advances(positions(Hinting::Full, false));
+        let subpixel = advances(positions(Hinting::Full, true));
+
+        assert!(whole.iter().all(|advance| advance.fract() == 0.0));
+        assert!(subpixel.iter().any(|advance| advance.fract() != 0.0));
+
+        // Glyphs are quantized to quarters of a pixel
+        assert!(subpixel
+            .iter()
+            .all(|advance| (advance * SUBPIXEL_STEPS).fract() == 0.0));
+    }
+
+    #[test]
+    fn letter_spacing_widens_measurements_between_glyphs() {
+        let font = ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+            .expect("Load fallback font");
+
+        let mut brush: glyph_brush::GlyphBrush<()> =
+            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        let mut width = |letter_spacing| {
+            let section = glyph_brush::Section::default()
+                .add_text(glyph_brush::Text::new("Tracking").with_scale(20.0));
+
+            let layout = Hinted::new(
+                section.layout,
+                Hinting::None,
+                false,
+                letter_spacing,
+                WritingMode::Horizontal,
+            );
+
+            brush
+                .glyph_bounds_custom_layout(section, &layout)
+                .map(|bounds| bounds.width())
+                .unwrap_or_default()
+        };
+
+        let tight = width(0.0);
+        let tracked = width(3.0);
+
+        // Eight glyphs have seven gaps between them
+        assert!((tracked - tight - 3.0 * 7.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn wrapped_letter_spacing_stays_inside_the_bounds() {
+        use ab_glyph::{Font, ScaleFont};
+
+        let font = ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+            .expect("Load fallback font");
+
+        let width = 120.0;
+
+        let text = glyph_brush::SectionText {
+            text: "Tracked words are wrapped with their spacing",
+            scale: ab_glyph::PxScale::from(20.0),
+            font_id: glyph_brush::FontId(0),
+        };
+
+        for (h_align, x) in &[
+            (glyph_brush::HorizontalAlign::Left, 0.0),
+            (glyph_brush::HorizontalAlign::Center, width / 2.0),
+            (glyph_brush::HorizontalAlign::Right, width),
+        ] {
+            let geometry = glyph_brush::SectionGeometry {
+                screen_position: (*x, 0.0),
+                bounds: (width, f32::INFINITY),
+            };
+
+            let glyphs = Hinted::new(
+                glyph_brush::Layout::default_wrap().h_align(*h_align),
+                Hinting::None,
+                false,
+                3.0,
+                WritingMode::Horizontal,
+            )
+            .calculate_glyphs(&[&font], &geometry, &[text]);
+
+            let mut baselines: Vec<f32> = glyphs
+                .iter()
+                .map(|section_glyph| section_glyph.glyph.position.y)
+                .collect();
+
+            baselines.dedup();
+
+            assert!(baselines.len() > 1);
+
+            let left = glyphs
+                .iter()
+                .map(|section_glyph| section_glyph.glyph.position.x)
+                .fold(f32::INFINITY, f32::min);
+
+            // Trailing spaces are not part of a line
+            let right = glyphs
+                .iter()
+                .filter(|section_glyph| {
+                    !text.text[section_glyph.byte_index..].starts_with(' ')
+                })
+                .map(|section_glyph| {
+                    let glyph = &section_glyph.glyph;
+
+                    glyph.position.x
+                        + font.as_scaled(glyph.scale).h_advance(glyph.id)
+                })
+                .fold(f32::NEG_INFINITY, f32::max);
+
+            assert!(left >= -0.01);
+            assert!(right <= width + 0.01);
+        }
+    }
+
+    #[test]
+    fn vertical_text_is_measured_tall_and_narrow() {
+        let font = ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+            .expect("Load fallback font");
+
+        let mut brush: glyph_brush::GlyphBrush<()> =
+            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        let mut size = |writing_mode| {
+            let section = glyph_brush::Section::default()
+                .add_text(glyph_brush::Text::new("Vertical").with_scale(20.0));
+
+            let layout = Hinted::new(
+                section.layout,
+                Hinting::None,
+                false,
+                0.0,
+                writing_mode,
+            );
+
+            brush
+                .glyph_bounds_custom_layout(section, &layout)
+                .map(|bounds| (bounds.width(), bounds.height()))
+                .unwrap_or_default()
+        };
+
+        let (width, height) = size(WritingMode::Horizontal);
+        let (vertical_width, vertical_height) = size(WritingMode::VerticalRL);
+
+        assert!(width > height);
+        assert!(vertical_height > vertical_width);
+
+        // A single column is as narrow as a line is short, and each glyph
+        // takes a whole line of height
+        assert!(vertical_width <= height);
+        assert!((vertical_height - height * 8.0).abs() < 0.01);
+    }
+}
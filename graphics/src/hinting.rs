@@ -0,0 +1,44 @@
+/// A hinting strategy for the glyphs of text.
+///
+/// The rasterizer does not hint the outlines of the glyphs themselves. Instead,
+/// the origins of the glyphs are snapped to the pixel grid, which keeps
+/// baselines and stems crisp on low-DPI displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hinting {
+    /// Glyphs are placed at their exact subpixel positions.
+    None,
+    /// Glyphs are snapped to whole pixels vertically.
+    Vertical,
+    /// Glyphs are snapped to whole pixels both vertically and horizontally.
+    Full,
+}
+
+impl Hinting {
+    /// Snaps the physical position of the origin of a glyph according to the
+    /// [`Hinting`].
+    pub fn snap(self, x: f32, y: f32) -> (f32, f32) {
+        match self {
+            Hinting::None => (x, y),
+            Hinting::Vertical => (x, y.round()),
+            Hinting::Full => (x.round(), y.round()),
+        }
+    }
+}
+
+impl Default for Hinting {
+    fn default() -> Self {
+        Hinting::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hinting_snaps_the_chosen_axes() {
+        assert_eq!(Hinting::None.snap(10.3, 20.6), (10.3, 20.6));
+        assert_eq!(Hinting::Vertical.snap(10.3, 20.6), (10.3, 21.0));
+        assert_eq!(Hinting::Full.snap(10.3, 20.6), (10.0, 21.0));
+    }
+}
@@ -3,11 +3,15 @@ use crate::alignment;
 use crate::image;
 use crate::svg;
 use crate::triangle;
+use crate::video;
 use crate::Backend;
 use crate::{
-    Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
+    Background, BorderAlignment, BorderStyle, BorderWidth, ContentFit, Font,
+    Point, Primitive, Rectangle, Size, Vector, Viewport, WritingMode,
 };
 
+use std::borrow::Cow;
+
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
 pub struct Layer<'a, B: Backend> {
@@ -20,14 +24,23 @@ pub struct Layer<'a, B: Backend> {
     /// The triangle meshes of the [`Layer`].
     pub meshes: Vec<Mesh<'a>>,
 
+    /// The textured triangle meshes of the [`Layer`].
+    pub textured_meshes: Vec<TexturedMesh<'a>>,
+
     /// The text of the [`Layer`].
     pub text: Vec<Text<'a>>,
 
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
 
+    /// The videos of the [`Layer`].
+    pub videos: Vec<Video>,
+
     /// The custom rendering primitives (e.g. wgpu render commands) of [`Layer`]
     pub customs: Vec<&'a B::CustomRenderPrimitive>,
+
+    /// The [`Backdrop`] of the [`Layer`], applied before drawing its contents.
+    pub backdrop: Option<Backdrop>,
 }
 
 impl<'a, B: Backend> Layer<'a, B> {
@@ -37,9 +50,12 @@ impl<'a, B: Backend> Layer<'a, B> {
             bounds,
             quads: Vec::new(),
             meshes: Vec::new(),
+            textured_meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            videos: Vec::new(),
             customs: Vec::new(),
+            backdrop: None,
         }
     }
 
@@ -62,6 +78,8 @@ impl<'a, B: Backend> Layer<'a, B> {
                 font: Font::Default,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
+                letter_spacing: 0.0,
+                writing_mode: WritingMode::Horizontal,
             };
 
             overlay.text.push(text);
@@ -82,14 +100,61 @@ impl<'a, B: Backend> Layer<'a, B> {
         primitive: &'a Primitive<B>,
         viewport: &Viewport,
     ) -> Vec<Self> {
-        let first_layer =
-            Layer::new(Rectangle::with_size(viewport.logical_size()));
+        Self::generate_at(primitive, viewport, Vector::new(0, 0))
+    }
+
+    /// Distributes the given [`Primitive`] and generates a list of layers
+    /// based on its contents, placing the [`Viewport`] at the given physical
+    /// `origin` of a larger target.
+    ///
+    /// The layers are positioned and clipped in the logical coordinates of the
+    /// [`Viewport`], so scaling them by its scale factor produces physical
+    /// coordinates in the target. This allows drawing several viewports with
+    /// different scale factors into the same frame.
+    pub fn generate_at(
+        primitive: &'a Primitive<B>,
+        viewport: &Viewport,
+        origin: Vector<u32>,
+    ) -> Vec<Self> {
+        Self::generate_in(
+            primitive,
+            viewport,
+            origin,
+            Vec::new(),
+            &mut Vec::new(),
+        )
+    }
+
+    /// Generates the layers of the given [`Primitive`] into `layers`, taking
+    /// new layers from the `spare` ones before creating them.
+    fn generate_in(
+        primitive: &'a Primitive<B>,
+        viewport: &Viewport,
+        origin: Vector<u32>,
+        mut layers: Vec<Self>,
+        spare: &mut Vec<Self>,
+    ) -> Vec<Self> {
+        let scale_factor = viewport.scale_factor() as f32;
+        let offset = Vector::new(
+            origin.x as f32 / scale_factor,
+            origin.y as f32 / scale_factor,
+        );
 
-        let mut layers = vec![first_layer];
+        let first_layer = Self::reuse(
+            spare,
+            Rectangle::new(
+                Point::new(offset.x, offset.y),
+                viewport.logical_size(),
+            ),
+        );
+
+        layers.push(first_layer);
 
-        Self::process_primitive(
+        let _ = Self::process_primitive(
             &mut layers,
-            Vector::new(0.0, 0.0),
+            spare,
+            offset,
+            1.0,
             primitive,
             0,
         );
@@ -97,24 +162,85 @@ impl<'a, B: Backend> Layer<'a, B> {
         layers
     }
 
+    /// Takes an empty layer from the `spare` ones, or creates a new one.
+    fn reuse(spare: &mut Vec<Self>, bounds: Rectangle) -> Self {
+        match spare.pop() {
+            Some(mut layer) => {
+                layer.bounds = bounds;
+                layer
+            }
+            None => Layer::new(bounds),
+        }
+    }
+
+    /// Turns the [`Layer`] into a layer of another [`Backend`], leaving its
+    /// custom primitives out.
+    ///
+    /// This allows a backend to draw the layers generated for another one.
+    pub fn without_customs<C: Backend>(self) -> Layer<'a, C> {
+        Layer {
+            bounds: self.bounds,
+            quads: self.quads,
+            meshes: self.meshes,
+            textured_meshes: self.textured_meshes,
+            text: self.text,
+            images: self.images,
+            videos: self.videos,
+            customs: Vec::new(),
+            backdrop: self.backdrop,
+        }
+    }
+
+    /// Empties the [`Layer`], keeping the allocations of its primitives.
+    ///
+    /// As it does not contain any primitive anymore, it can be used to
+    /// generate the layers of any other frame.
+    fn into_empty(self) -> Layer<'static, B>
+    where
+        B: 'static,
+    {
+        Layer {
+            bounds: self.bounds,
+            quads: empty(self.quads),
+            meshes: empty(self.meshes),
+            textured_meshes: empty(self.textured_meshes),
+            text: empty(self.text),
+            images: empty(self.images),
+            videos: empty(self.videos),
+            customs: empty(self.customs),
+            backdrop: None,
+        }
+    }
+
+    /// Processes a [`Primitive`] in the current layer and returns the layer
+    /// where the primitives that follow it should be placed.
+    ///
+    /// The alpha of its colors is multiplied by the given `opacity`.
     fn process_primitive(
         layers: &mut Vec<Self>,
+        spare: &mut Vec<Self>,
         translation: Vector,
+        opacity: f32,
         primitive: &'a Primitive<B>,
         current_layer: usize,
-    ) {
+    ) -> usize {
         match primitive {
             Primitive::None => {}
             Primitive::Group { primitives } => {
                 // TODO: Inspect a bit and regroup (?)
-                for primitive in primitives {
-                    Self::process_primitive(
-                        layers,
-                        translation,
-                        primitive,
-                        current_layer,
-                    )
-                }
+                return primitives.iter().fold(
+                    current_layer,
+                    |current_layer, primitive| {
+                        Self::process_primitive(
+                            layers,
+                            spare,
+                            translation,
+                            opacity,
+                            primitive,
+                            current_layer,
+                        )
+                    },
+                );
             }
             Primitive::Text {
                 content,
@@ -124,17 +250,46 @@ impl<'a, B: Backend> Layer<'a, B> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                letter_spacing,
+                writing_mode,
+                background,
             } => {
                 let layer = &mut layers[current_layer];
 
+                if let Some(Background::Color(color)) = background {
+                    let bounds = text_bounds(
+                        *bounds + translation,
+                        *horizontal_alignment,
+                        *vertical_alignment,
+                    );
+
+                    layer.quads.push(Quad {
+                        position: [bounds.x, bounds.y],
+                        size: [bounds.width, bounds.height],
+                        color: fade(color.into_linear(), opacity),
+                        border_radius: 0.0,
+                        border_width: [0.0; 4],
+                        border_color: [0.0, 0.0, 0.0, 0.0],
+                        border_style: Quad::SOLID,
+                        border_dash: [0.0, 0.0],
+                        border_offset: 0.0,
+                        shadow_color: [0.0, 0.0, 0.0, 0.0],
+                        shadow_offset: [0.0, 0.0],
+                        shadow_blur: 0.0,
+                        shadow_kind: Quad::INNER_SHADOW,
+                    });
+                }
+
                 layer.text.push(Text {
                     content,
                     bounds: *bounds + translation,
                     size: *size,
-                    color: color.into_linear(),
+                    color: fade(color.into_linear(), opacity),
                     font: *font,
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    letter_spacing: *letter_spacing,
+                    writing_mode: *writing_mode,
                 });
             }
             Primitive::Quad {
@@ -143,24 +298,207 @@ impl<'a, B: Backend> Layer<'a, B> {
                 border_radius,
                 border_width,
                 border_color,
+                border_style,
+                border_alignment,
+                border_offset,
+                fill,
             } => {
-                let layer = &mut layers[current_layer];
+                let (border_bounds, aligned_border_radius) = align_border(
+                    *bounds,
+                    *border_radius,
+                    *border_width,
+                    *border_alignment,
+                );
+
+                let (border_style, border_dash) = match border_style {
+                    BorderStyle::Solid => (Quad::SOLID, [0.0, 0.0]),
+                    BorderStyle::Dashed { dash, gap } => {
+                        (Quad::DASHED, [dash.max(0.0), gap.max(0.0)])
+                    }
+                    BorderStyle::Dotted => {
+                        let width = border_width.max();
+
+                        (Quad::DOTTED, [width, width])
+                    }
+                };
+
+                let bounds = *bounds + translation;
+                let border_bounds = border_bounds + translation;
+
+                let color = match background {
+                    Background::Color(color) if *fill => {
+                        fade(color.into_linear(), opacity)
+                    }
+                    _ => [0.0, 0.0, 0.0, 0.0],
+                };
+
+                // The image of a dropped handle is not drawn anymore
+                let image = match background {
+                    Background::Color(_) => None,
+                    Background::Image {
+                        handle,
+                        fit,
+                        opacity,
+                    } => handle.handle().map(|handle| {
+                        (handle, Pattern::Fitted(*fit), *opacity)
+                    }),
+                    Background::Tiled { handle, scale } => handle
+                        .handle()
+                        .map(|handle| (handle, Pattern::Tiled(*scale), 1.0)),
+                };
+
+                let current_layer = match image.filter(|_| *fill) {
+                    Some((handle, pattern, image_opacity)) => {
+                        let layer_bounds = layers[current_layer].bounds;
+
+                        layers[current_layer].images.push(Image::Background {
+                            handle,
+                            bounds,
+                            border_radius: *border_radius,
+                            pattern,
+                            opacity: image_opacity.max(0.0).min(1.0) * opacity,
+                        });
+
+                        // Images are drawn after the quads of a layer, so the
+                        // border and the primitives that follow the image go
+                        // in a new layer
+                        layers.push(Self::reuse(spare, layer_bounds));
+
+                        layers.len() - 1
+                    }
+                    None => current_layer,
+                };
 
                 // TODO: Move some of these computations to the GPU (?)
+                let quad = Quad {
+                    position: [border_bounds.x, border_bounds.y],
+                    size: [border_bounds.width, border_bounds.height],
+                    color,
+                    border_radius: aligned_border_radius,
+                    border_width: (*border_width).into(),
+                    border_color: fade(border_color.into_linear(), opacity),
+                    border_style,
+                    border_dash,
+                    border_offset: *border_offset,
+                    shadow_color: [0.0, 0.0, 0.0, 0.0],
+                    shadow_offset: [0.0, 0.0],
+                    shadow_blur: 0.0,
+                    shadow_kind: Quad::INNER_SHADOW,
+                };
+
+                let quads = &mut layers[current_layer].quads;
+
+                if border_bounds == bounds {
+                    quads.push(quad);
+                } else {
+                    // Only the border grows outside of the bounds, so the
+                    // fill is drawn on its own beneath it
+                    if color[3] > 0.0 {
+                        quads.push(Quad {
+                            position: [bounds.x, bounds.y],
+                            size: [bounds.width, bounds.height],
+                            border_radius: *border_radius,
+                            border_width: [0.0; 4],
+                            ..quad
+                        });
+                    }
+
+                    quads.push(Quad {
+                        color: [0.0, 0.0, 0.0, 0.0],
+                        ..quad
+                    });
+                }
+
+                return current_layer;
+            }
+            Primitive::InnerShadow {
+                bounds,
+                border_radius,
+                color,
+                offset,
+                blur_radius,
+            } => {
+                let layer = &mut layers[current_layer];
+
                 layer.quads.push(Quad {
                     position: [
                         bounds.x + translation.x,
                         bounds.y + translation.y,
                     ],
                     size: [bounds.width, bounds.height],
-                    color: match background {
-                        Background::Color(color) => color.into_linear(),
-                    },
+                    color: [0.0, 0.0, 0.0, 0.0],
                     border_radius: *border_radius,
-                    border_width: *border_width,
-                    border_color: border_color.into_linear(),
+                    border_width: [0.0; 4],
+                    border_color: [0.0, 0.0, 0.0, 0.0],
+                    border_style: Quad::SOLID,
+                    border_dash: [0.0, 0.0],
+                    border_offset: 0.0,
+                    shadow_color: fade(color.into_linear(), opacity),
+                    shadow_offset: [offset.x, offset.y],
+                    shadow_blur: blur_radius.max(0.0),
+                    shadow_kind: Quad::INNER_SHADOW,
                 });
             }
+            Primitive::Shadow {
+                bounds,
+                border_radius,
+                color,
+                offset,
+                blur_radius,
+            } => {
+                let layer = &mut layers[current_layer];
+                let blur_radius = blur_radius.max(0.0);
+
+                // The quad covers the whole blur around the shifted box
+                layer.quads.push(Quad {
+                    position: [
+                        bounds.x + translation.x + offset.x - blur_radius,
+                        bounds.y + translation.y + offset.y - blur_radius,
+                    ],
+                    size: [
+                        bounds.width + blur_radius * 2.0,
+                        bounds.height + blur_radius * 2.0,
+                    ],
+                    color: [0.0, 0.0, 0.0, 0.0],
+                    border_radius: *border_radius,
+                    border_width: [0.0; 4],
+                    border_color: [0.0, 0.0, 0.0, 0.0],
+                    border_style: Quad::SOLID,
+                    border_dash: [0.0, 0.0],
+                    border_offset: 0.0,
+                    shadow_color: fade(color.into_linear(), opacity),
+                    shadow_offset: [offset.x, offset.y],
+                    shadow_blur: blur_radius,
+                    shadow_kind: Quad::DROP_SHADOW,
+                });
+            }
+            Primitive::Backdrop {
+                bounds,
+                blur_radius,
+                tint,
+            } => {
+                let layer = &layers[current_layer];
+                let layer_bounds = layer.bounds;
+
+                // Only blur visible content
+                if let Some(bounds) =
+                    layer_bounds.intersection(&(*bounds + translation))
+                {
+                    // The backdrop needs everything drawn before it, so the
+                    // primitives that follow it go in a new layer
+                    let mut backdrop_layer = Self::reuse(spare, layer_bounds);
+
+                    backdrop_layer.backdrop = Some(Backdrop {
+                        bounds,
+                        blur_radius: blur_radius.max(0.0),
+                        tint: fade(tint.into_linear(), opacity),
+                    });
+
+                    layers.push(backdrop_layer);
+
+                    return layers.len() - 1;
+                }
+            }
             Primitive::Mesh2D { buffers, size } => {
                 let layer = &mut layers[current_layer];
 
@@ -173,11 +511,82 @@ impl<'a, B: Backend> Layer<'a, B> {
                 if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
                     layer.meshes.push(Mesh {
                         origin: Point::new(translation.x, translation.y),
-                        buffers,
+                        buffers: if opacity < 1.0 {
+                            Cow::Owned(triangle::Mesh2D {
+                                vertices: buffers
+                                    .vertices
+                                    .iter()
+                                    .map(|vertex| triangle::Vertex2D {
+                                        color: fade(vertex.color, opacity),
+                                        ..*vertex
+                                    })
+                                    .collect(),
+                                indices: buffers.indices.clone(),
+                            })
+                        } else {
+                            Cow::Borrowed(buffers)
+                        },
+                        clip_bounds,
+                    });
+                }
+            }
+            Primitive::TexturedMesh2D {
+                buffers,
+                size,
+                handle,
+            } => {
+                let layer = &mut layers[current_layer];
+
+                let bounds = Rectangle::new(
+                    Point::new(translation.x, translation.y),
+                    *size,
+                );
+
+                // Only draw visible content
+                if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
+                    layer.textured_meshes.push(TexturedMesh {
+                        origin: Point::new(translation.x, translation.y),
+                        buffers: if opacity < 1.0 {
+                            Cow::Owned(triangle::TexturedMesh2D {
+                                vertices: buffers
+                                    .vertices
+                                    .iter()
+                                    .map(|vertex| triangle::Vertex2DTextured {
+                                        color: fade(vertex.color, opacity),
+                                        ..*vertex
+                                    })
+                                    .collect(),
+                                indices: buffers.indices.clone(),
+                            })
+                        } else {
+                            Cow::Borrowed(buffers)
+                        },
+                        handle,
                         clip_bounds,
                     });
                 }
             }
+            #[cfg(feature = "canvas")]
+            Primitive::Path { path, fill, stroke } => {
+                let layer = &mut layers[current_layer];
+
+                let mut buffers = path.tessellate(*fill, *stroke);
+
+                // Paths have no size, so they are only clipped by their layer
+                if !buffers.indices.is_empty() {
+                    if opacity < 1.0 {
+                        for vertex in &mut buffers.vertices {
+                            vertex.color = fade(vertex.color, opacity);
+                        }
+                    }
+
+                    layer.meshes.push(Mesh {
+                        origin: Point::new(translation.x, translation.y),
+                        buffers: Cow::Owned(buffers),
+                        clip_bounds: layer.bounds,
+                    });
+                }
+            }
             Primitive::Clip {
                 bounds,
                 offset,
@@ -190,13 +599,15 @@ impl<'a, B: Backend> Layer<'a, B> {
                 if let Some(clip_bounds) =
                     layer.bounds.intersection(&translated_bounds)
                 {
-                    let clip_layer = Layer::new(clip_bounds);
+                    let clip_layer = Self::reuse(spare, clip_bounds);
                     layers.push(clip_layer);
 
-                    Self::process_primitive(
+                    let _ = Self::process_primitive(
                         layers,
+                        spare,
                         translation
                             - Vector::new(offset.x as f32, offset.y as f32),
+                        opacity,
                         content,
                         layers.len() - 1,
                     );
@@ -206,17 +617,34 @@ impl<'a, B: Backend> Layer<'a, B> {
                 translation: new_translation,
                 content,
             } => {
-                Self::process_primitive(
+                return Self::process_primitive(
                     layers,
+                    spare,
                     translation + *new_translation,
+                    opacity,
+                    &content,
+                    current_layer,
+                );
+            }
+            Primitive::Opacity {
+                opacity: new_opacity,
+                content,
+            } => {
+                return Self::process_primitive(
+                    layers,
+                    spare,
+                    translation,
+                    opacity * new_opacity.max(0.0).min(1.0),
                     &content,
                     current_layer,
                 );
             }
             Primitive::Cached { cache } => {
-                Self::process_primitive(
+                return Self::process_primitive(
                     layers,
+                    spare,
                     translation,
+                    opacity,
                     &cache,
                     current_layer,
                 );
@@ -237,12 +665,58 @@ impl<'a, B: Backend> Layer<'a, B> {
                     bounds: *bounds + translation,
                 });
             }
+            Primitive::Video { handle, bounds } => {
+                let layer = &mut layers[current_layer];
+
+                layer.videos.push(Video {
+                    handle: handle.clone(),
+                    bounds: *bounds + translation,
+                });
+            }
             Primitive::Custom(custom_job) => {
                 let layer = &mut layers[current_layer];
 
                 layer.customs.push(custom_job);
             }
         }
+
+        current_layer
+    }
+}
+
+/// A region of a [`Layer`] that blurs everything drawn behind it.
+#[derive(Debug, Clone, Copy)]
+pub struct Backdrop {
+    /// The bounds of the [`Backdrop`].
+    pub bounds: Rectangle,
+
+    /// The blur radius of the [`Backdrop`].
+    pub blur_radius: f32,
+
+    /// The tint of the [`Backdrop`], in __linear RGB__.
+    pub tint: [f32; 4],
+}
+
+impl Backdrop {
+    /// Returns a [`Quad`] filled with the tint of the [`Backdrop`].
+    ///
+    /// Backends that cannot blur their target can draw it instead.
+    pub fn tint_quad(&self) -> Quad {
+        Quad {
+            position: [self.bounds.x, self.bounds.y],
+            size: [self.bounds.width, self.bounds.height],
+            color: self.tint,
+            border_color: [0.0, 0.0, 0.0, 0.0],
+            border_radius: 0.0,
+            border_width: [0.0; 4],
+            border_style: Quad::SOLID,
+            border_dash: [0.0, 0.0],
+            border_offset: 0.0,
+            shadow_color: [0.0, 0.0, 0.0, 0.0],
+            shadow_offset: [0.0, 0.0],
+            shadow_blur: 0.0,
+            shadow_kind: Quad::INNER_SHADOW,
+        }
     }
 }
 
@@ -267,23 +741,146 @@ pub struct Quad {
     /// The border radius of the [`Quad`].
     pub border_radius: f32,
 
-    /// The border width of the [`Quad`].
-    pub border_width: f32,
+    /// The border width of each edge of the [`Quad`]: top, right, bottom
+    /// and left.
+    pub border_width: [f32; 4],
+
+    /// The border style of the [`Quad`].
+    ///
+    /// It is one of [`Quad::SOLID`], [`Quad::DASHED`], or [`Quad::DOTTED`].
+    pub border_style: u32,
+
+    /// The dash and gap lengths of the border of the [`Quad`].
+    pub border_dash: [f32; 2],
+
+    /// The offset of the dash pattern along the border of the [`Quad`].
+    pub border_offset: f32,
+
+    /// The color of the inner shadow of the [`Quad`], in __linear RGB__.
+    pub shadow_color: [f32; 4],
+
+    /// The offset of the inner shadow of the [`Quad`].
+    pub shadow_offset: [f32; 2],
+
+    /// The blur radius of the inner shadow of the [`Quad`].
+    pub shadow_blur: f32,
+
+    /// The kind of shadow of the [`Quad`].
+    ///
+    /// It is either [`Quad::INNER_SHADOW`] or [`Quad::DROP_SHADOW`]. A drop
+    /// shadow is cast by the box left inside the [`Quad`] when shrunk by
+    /// [`Quad::shadow_blur`], and it only draws the shadow.
+    pub shadow_kind: u32,
+}
+
+impl Quad {
+    /// The [`Quad::border_style`] of a continuous border.
+    pub const SOLID: u32 = 0;
+
+    /// The [`Quad::border_style`] of a dashed border.
+    pub const DASHED: u32 = 1;
+
+    /// The [`Quad::border_style`] of a dotted border.
+    pub const DOTTED: u32 = 2;
+
+    /// The [`Quad::shadow_kind`] of a shadow cast inside the [`Quad`].
+    pub const INNER_SHADOW: u32 = 0;
+
+    /// The [`Quad::shadow_kind`] of a shadow cast beneath another quad.
+    pub const DROP_SHADOW: u32 = 1;
+}
+
+/// Returns the bounds and the border radius of a quad whose border, drawn
+/// inside of them, is placed with the given [`BorderAlignment`] relative to
+/// the original bounds.
+pub(crate) fn align_border(
+    bounds: Rectangle,
+    border_radius: f32,
+    border_width: BorderWidth,
+    alignment: BorderAlignment,
+) -> (Rectangle, f32) {
+    let top = alignment.outset(border_width.top);
+    let right = alignment.outset(border_width.right);
+    let bottom = alignment.outset(border_width.bottom);
+    let left = alignment.outset(border_width.left);
+
+    let bounds = Rectangle {
+        x: bounds.x - left,
+        y: bounds.y - top,
+        width: bounds.width + left + right,
+        height: bounds.height + top + bottom,
+    };
+
+    // The outer corners stay concentric with the inner ones
+    let border_radius = if border_radius > 0.0 {
+        border_radius + alignment.outset(border_width.max())
+    } else {
+        border_radius
+    };
+
+    (bounds, border_radius)
+}
+
+/// Multiplies the alpha of a linear color by the given opacity.
+fn fade([r, g, b, a]: [f32; 4], opacity: f32) -> [f32; 4] {
+    [r, g, b, a * opacity]
+}
+
+/// Returns the box covered by a text with the given bounds, whose position is
+/// the anchor of its alignment.
+fn text_bounds(
+    bounds: Rectangle,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> Rectangle {
+    let x = match horizontal_alignment {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => bounds.x - bounds.width / 2.0,
+        alignment::Horizontal::Right => bounds.x - bounds.width,
+    };
+
+    let y = match vertical_alignment {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => bounds.y - bounds.height / 2.0,
+        alignment::Vertical::Bottom => bounds.y - bounds.height,
+    };
+
+    Rectangle { x, y, ..bounds }
 }
 
 /// A mesh of triangles.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Mesh<'a> {
     /// The origin of the vertices of the [`Mesh`].
     pub origin: Point,
 
     /// The vertex and index buffers of the [`Mesh`].
-    pub buffers: &'a triangle::Mesh2D,
+    ///
+    /// They are only copied when their colors need to be faded.
+    pub buffers: Cow<'a, triangle::Mesh2D>,
 
     /// The clipping bounds of the [`Mesh`].
     pub clip_bounds: Rectangle<f32>,
 }
 
+/// A mesh of triangles that samples an image.
+#[derive(Debug, Clone)]
+pub struct TexturedMesh<'a> {
+    /// The origin of the vertices of the [`TexturedMesh`].
+    pub origin: Point,
+
+    /// The vertex and index buffers of the [`TexturedMesh`].
+    ///
+    /// They are only copied when their colors need to be faded.
+    pub buffers: Cow<'a, triangle::TexturedMesh2D>,
+
+    /// The image sampled by the [`TexturedMesh`].
+    pub handle: &'a image::Handle,
+
+    /// The clipping bounds of the [`TexturedMesh`].
+    pub clip_bounds: Rectangle<f32>,
+}
+
 /// A paragraph of text.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a> {
@@ -307,6 +904,12 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The extra space between every two consecutive glyphs of the [`Text`].
+    pub letter_spacing: f32,
+
+    /// The direction in which the lines of the [`Text`] flow.
+    pub writing_mode: WritingMode,
 }
 
 /// A raster or vector image.
@@ -328,6 +931,174 @@ pub enum Image {
         /// The bounds of the image.
         bounds: Rectangle,
     },
+    /// A raster image filling the background of a quad.
+    Background {
+        /// The handle of a raster image.
+        handle: image::Handle,
+
+        /// The bounds of the quad.
+        bounds: Rectangle,
+
+        /// The border radius of the quad, masking the corners of the image.
+        border_radius: f32,
+
+        /// How the image is placed within the bounds of the quad.
+        pattern: Pattern,
+
+        /// The opacity of the image, from `0.0` to `1.0`.
+        opacity: f32,
+    },
+}
+
+/// The placement of the image of a [`Background`] within the bounds of a
+/// quad.
+///
+/// [`Background`]: crate::Background
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pattern {
+    /// A single image, fitted into the bounds and centered.
+    Fitted(ContentFit),
+
+    /// An image repeated from the top left corner of the bounds, scaled by
+    /// the given factor.
+    Tiled(f32),
+}
+
+impl Pattern {
+    /// Returns the bounds of every copy of an image with the given size that
+    /// the [`Pattern`] places over some bounds.
+    ///
+    /// The copies may overflow the bounds, which mask them.
+    pub fn tiles(self, bounds: Rectangle, image_size: Size) -> Vec<Rectangle> {
+        if image_size.width < 1.0 || image_size.height < 1.0 {
+            return Vec::new();
+        }
+
+        match self {
+            Pattern::Fitted(fit) => {
+                let size = fit.fit(image_size, bounds.size());
+
+                vec![Rectangle {
+                    x: bounds.center_x() - size.width / 2.0,
+                    y: bounds.center_y() - size.height / 2.0,
+                    width: size.width,
+                    height: size.height,
+                }]
+            }
+            Pattern::Tiled(scale) => {
+                let width = image_size.width * scale;
+                let height = image_size.height * scale;
+
+                // Tiles smaller than a pixel would be too many to draw
+                if width < 1.0 || height < 1.0 {
+                    return Vec::new();
+                }
+
+                let columns = (bounds.width / width).ceil() as usize;
+                let rows = (bounds.height / height).ceil() as usize;
+
+                (0..rows)
+                    .flat_map(|row| {
+                        (0..columns).map(move |column| Rectangle {
+                            x: bounds.x + column as f32 * width,
+                            y: bounds.y + row as f32 * height,
+                            width,
+                            height,
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The allocations of the layers of a frame, reused to generate the layers of
+/// the next ones.
+///
+/// Giving the layers back to the [`Pool`] once they are drawn keeps all of
+/// their buffers. Therefore, generating the layers of a frame similar to the
+/// previous one does not allocate.
+#[derive(Debug)]
+pub struct Pool<B: Backend + 'static> {
+    layers: Vec<Layer<'static, B>>,
+    spare: Vec<Layer<'static, B>>,
+}
+
+impl<B: Backend + 'static> Pool<B> {
+    /// Creates a new empty [`Pool`].
+    pub fn new() -> Self {
+        Self {
+            layers: Vec::new(),
+            spare: Vec::new(),
+        }
+    }
+
+    /// Generates the layers of the given [`Primitive`] like
+    /// [`Layer::generate`], reusing the allocations of the [`Pool`].
+    pub fn generate<'a>(
+        &mut self,
+        primitive: &'a Primitive<B>,
+        viewport: &Viewport,
+    ) -> Vec<Layer<'a, B>> {
+        self.generate_at(primitive, viewport, Vector::new(0, 0))
+    }
+
+    /// Generates the layers of the given [`Primitive`] like
+    /// [`Layer::generate_at`], reusing the allocations of the [`Pool`].
+    pub fn generate_at<'a>(
+        &mut self,
+        primitive: &'a Primitive<B>,
+        viewport: &Viewport,
+        origin: Vector<u32>,
+    ) -> Vec<Layer<'a, B>> {
+        let mut spare: Vec<Layer<'a, B>> = std::mem::take(&mut self.spare);
+
+        let layers = Layer::generate_in(
+            primitive,
+            viewport,
+            origin,
+            std::mem::take(&mut self.layers),
+            &mut spare,
+        );
+
+        self.spare = spare.into_iter().map(Layer::into_empty).collect();
+
+        layers
+    }
+
+    /// Gives some drawn layers back to the [`Pool`], keeping their
+    /// allocations for the next frame.
+    pub fn recycle(&mut self, mut layers: Vec<Layer<'_, B>>) {
+        // Spare layers are taken from the end, so the first layers of the next
+        // frame get the buffers of the first layers of this one
+        self.spare
+            .extend(layers.drain(..).rev().map(Layer::into_empty));
+
+        self.layers = empty(layers);
+    }
+}
+
+impl<B: Backend + 'static> Default for Pool<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Clears the given items, keeping their allocation for items of another
+/// type with the same size.
+fn empty<T, U>(mut items: Vec<T>) -> Vec<U> {
+    items.clear();
+    items.into_iter().filter_map(|_| None).collect()
+}
+
+/// A video.
+#[derive(Debug, Clone)]
+pub struct Video {
+    /// The handle of the video.
+    pub handle: video::Handle,
+
+    /// The bounds of the video.
+    pub bounds: Rectangle,
 }
 
 #[allow(unsafe_code)]
@@ -335,3 +1106,617 @@ unsafe impl bytemuck::Zeroable for Quad {}
 
 #[allow(unsafe_code)]
 unsafe impl bytemuck::Pod for Quad {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BorderWidth, Color};
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    fn inner_shadow(blur_radius: f32) -> Quad {
+        let primitive: Primitive<Null> = Primitive::InnerShadow {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            border_radius: 0.0,
+            color: Color::BLACK,
+            offset: Vector::new(4.0, 4.0),
+            blur_radius,
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        layers[0].quads[0]
+    }
+
+    #[cfg(feature = "canvas")]
+    #[test]
+    fn paths_are_tessellated_into_translated_meshes() {
+        use crate::canvas;
+
+        let primitive: Primitive<Null> = Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Translate {
+                translation: Vector::new(10.0, 20.0),
+                content: Box::new(Primitive::Path {
+                    path: canvas::Path::rectangle(
+                        Point::ORIGIN,
+                        Size::new(30.0, 30.0),
+                    ),
+                    fill: Some(canvas::Fill::from(Color::BLACK)),
+                    stroke: None,
+                }),
+            }),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+        let mesh = &layers[0].meshes[0];
+
+        assert_eq!(mesh.origin, Point::new(10.0, 20.0));
+        assert_eq!(mesh.clip_bounds, layers[0].bounds);
+        assert_eq!(mesh.buffers.indices.len(), 6);
+        assert!(mesh
+            .buffers
+            .vertices
+            .iter()
+            .all(|vertex| vertex.color == [0.0, 0.0, 0.0, 0.5]));
+    }
+
+    #[test]
+    fn only_the_top_edge_of_a_border_is_drawn_when_the_others_are_zero() {
+        let primitive: Primitive<Null> = Primitive::Quad {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0,
+            border_width: BorderWidth::from([2.0, 0.0, 0.0, 0.0]),
+            border_color: Color::BLACK,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+        let quad = layers[0].quads[0];
+
+        assert_eq!(quad.border_width, [2.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn inner_shadows_are_cast_inside_transparent_quads() {
+        let quad = inner_shadow(4.0);
+
+        assert_eq!(quad.shadow_kind, Quad::INNER_SHADOW);
+        assert_eq!(quad.position, [0.0, 0.0]);
+        assert_eq!(quad.size, [100.0, 100.0]);
+        assert_eq!(quad.color, [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(quad.shadow_color, [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(quad.shadow_offset, [4.0, 4.0]);
+        assert_eq!(quad.shadow_blur, 4.0);
+    }
+
+    #[test]
+    fn drop_shadows_cover_the_blur_around_the_offset_box() {
+        let primitive: Primitive<Null> = Primitive::Shadow {
+            bounds: Rectangle::new(
+                Point::new(10.0, 10.0),
+                Size::new(80.0, 40.0),
+            ),
+            border_radius: 4.0,
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 6.0,
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+        let quad = layers[0].quads[0];
+
+        assert_eq!(quad.shadow_kind, Quad::DROP_SHADOW);
+        assert_eq!(quad.position, [4.0, 6.0]);
+        assert_eq!(quad.size, [92.0, 52.0]);
+        assert_eq!(quad.color, [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(quad.shadow_blur, 6.0);
+    }
+
+    fn physical_quad(layer: &Layer<'_, Null>, scale_factor: f32) -> Rectangle {
+        let quad = layer.quads[0];
+
+        Rectangle {
+            x: quad.position[0] * scale_factor,
+            y: quad.position[1] * scale_factor,
+            width: quad.size[0] * scale_factor,
+            height: quad.size[1] * scale_factor,
+        }
+    }
+
+    #[test]
+    fn viewports_with_different_scales_share_a_target() {
+        let primitive: Primitive<Null> = Primitive::Quad {
+            bounds: Rectangle::new(
+                Point::new(10.0, 10.0),
+                Size::new(50.0, 20.0),
+            ),
+            background: Background::Color(Color::BLACK),
+            border_radius: 0.0,
+            border_width: BorderWidth::ZERO,
+            border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let left = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let right = Viewport::with_physical_size(Size::new(200, 200), 2.0);
+
+        let left_layers =
+            Layer::generate_at(&primitive, &left, Vector::new(0, 0));
+        let right_layers =
+            Layer::generate_at(&primitive, &right, Vector::new(200, 0));
+
+        assert_eq!(
+            physical_quad(&left_layers[0], 1.0),
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(50.0, 20.0))
+        );
+        assert_eq!(
+            physical_quad(&right_layers[0], 2.0),
+            Rectangle::new(Point::new(220.0, 20.0), Size::new(100.0, 40.0))
+        );
+
+        // Each viewport is clipped to its own region of the target
+        assert_eq!(
+            left_layers[0].bounds * 1.0,
+            Rectangle::new(Point::ORIGIN, Size::new(200.0, 200.0))
+        );
+        assert_eq!(
+            right_layers[0].bounds * 2.0,
+            Rectangle::new(Point::new(200.0, 0.0), Size::new(200.0, 200.0))
+        );
+    }
+
+    #[test]
+    fn backdrop_moves_the_following_primitives_to_a_new_layer() {
+        let quad = |x: f32| Primitive::Quad {
+            bounds: Rectangle::new(Point::new(x, 0.0), Size::new(10.0, 10.0)),
+            background: Background::Color(Color::BLACK),
+            border_radius: 0.0,
+            border_width: BorderWidth::ZERO,
+            border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let primitive: Primitive<Null> = Primitive::Group {
+            primitives: vec![
+                quad(0.0),
+                Primitive::Group {
+                    primitives: vec![
+                        Primitive::Backdrop {
+                            bounds: Rectangle::new(
+                                Point::new(20.0, 0.0),
+                                Size::new(50.0, 300.0),
+                            ),
+                            blur_radius: 8.0,
+                            tint: Color::WHITE,
+                        },
+                        quad(20.0),
+                    ],
+                },
+                quad(40.0),
+            ],
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        assert_eq!(layers.len(), 2);
+        assert!(layers[0].backdrop.is_none());
+        assert_eq!(layers[0].quads.len(), 1);
+        assert_eq!(layers[1].quads.len(), 2);
+
+        let backdrop = layers[1].backdrop.expect("Backdrop layer");
+
+        // The backdrop is clipped to the bounds of its layer
+        assert_eq!(
+            backdrop.bounds,
+            Rectangle::new(Point::new(20.0, 0.0), Size::new(50.0, 200.0))
+        );
+    }
+
+    #[test]
+    fn pooled_layers_reuse_the_buffers_of_the_previous_frame() {
+        let quad = |x: f32| Primitive::Quad {
+            bounds: Rectangle::new(Point::new(x, 0.0), Size::new(10.0, 10.0)),
+            background: Background::Color(Color::BLACK),
+            border_radius: 0.0,
+            border_width: BorderWidth::ZERO,
+            border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let primitive: Primitive<Null> = Primitive::Group {
+            primitives: vec![
+                quad(0.0),
+                Primitive::Clip {
+                    bounds: Rectangle::new(
+                        Point::new(20.0, 0.0),
+                        Size::new(50.0, 50.0),
+                    ),
+                    offset: Vector::new(0, 0),
+                    content: Box::new(quad(20.0)),
+                },
+                quad(40.0),
+            ],
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let mut pool = Pool::new();
+
+        let layers = pool.generate(&primitive, &viewport);
+        let expected = format!("{:?}", layers);
+        let buffers: Vec<_> =
+            layers.iter().map(|layer| layer.quads.as_ptr()).collect();
+
+        pool.recycle(layers);
+
+        let layers = pool.generate(&primitive, &viewport);
+
+        assert_eq!(format!("{:?}", layers), expected);
+        assert_eq!(
+            layers
+                .iter()
+                .map(|layer| layer.quads.as_ptr())
+                .collect::<Vec<_>>(),
+            buffers
+        );
+    }
+
+    #[test]
+    fn textured_meshes_are_translated_and_faded() {
+        let vertex =
+            |position: [f32; 2], uv: [f32; 2]| triangle::Vertex2DTextured {
+                position,
+                uv,
+                color: [1.0, 1.0, 1.0, 1.0],
+            };
+
+        let primitive: Primitive<Null> = Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Translate {
+                translation: Vector::new(10.0, 20.0),
+                content: Box::new(Primitive::TexturedMesh2D {
+                    buffers: triangle::TexturedMesh2D {
+                        vertices: vec![
+                            vertex([0.0, 0.0], [0.0, 0.0]),
+                            vertex([50.0, 0.0], [1.0, 0.0]),
+                            vertex([0.0, 50.0], [0.0, 1.0]),
+                        ],
+                        indices: vec![0, 1, 2],
+                    },
+                    size: Size::new(50.0, 50.0),
+                    handle: image::Handle::from_pixels(1, 1, vec![0; 4]),
+                }),
+            }),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        let mesh = &layers[0].textured_meshes[0];
+
+        assert_eq!(mesh.origin, Point::new(10.0, 20.0));
+        assert_eq!(
+            mesh.clip_bounds,
+            Rectangle::new(Point::new(10.0, 20.0), Size::new(50.0, 50.0))
+        );
+        assert_eq!(mesh.buffers.vertices[1].uv, [1.0, 0.0]);
+        assert_eq!(mesh.buffers.vertices[1].color[3], 0.5);
+    }
+
+    #[test]
+    fn text_backgrounds_fill_the_measured_text_bounds() {
+        // A text measured as 120x20, centered at (100, 50)
+        let measured = Size::new(120.0, 20.0);
+
+        let primitive: Primitive<Null> = Primitive::Text {
+            content: String::from("Highlighted"),
+            bounds: Rectangle::new(Point::new(100.0, 50.0), measured),
+            color: Color::BLACK,
+            size: 20.0,
+            font: Font::Default,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            letter_spacing: 0.0,
+            writing_mode: WritingMode::Horizontal,
+            background: Some(Background::Color(Color::WHITE)),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        assert_eq!(layers[0].text.len(), 1);
+        assert_eq!(layers[0].quads.len(), 1);
+
+        let quad = layers[0].quads[0];
+
+        assert_eq!(quad.position, [40.0, 40.0]);
+        assert_eq!(quad.size, [measured.width, measured.height]);
+    }
+
+    #[test]
+    fn border_styles_are_carried_to_the_quads() {
+        let quad = |border_style| Primitive::Quad {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 40.0)),
+            background: Background::Color(Color::WHITE),
+            border_radius: 4.0,
+            border_width: BorderWidth::new(2.0),
+            border_color: Color::BLACK,
+            border_style,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let primitive: Primitive<Null> = Primitive::Group {
+            primitives: vec![
+                quad(BorderStyle::Solid),
+                quad(BorderStyle::Dashed {
+                    dash: 6.0,
+                    gap: 3.0,
+                }),
+                quad(BorderStyle::Dotted),
+            ],
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+        let quads = &layers[0].quads;
+
+        assert_eq!(quads[0].border_style, Quad::SOLID);
+        assert_eq!(quads[1].border_style, Quad::DASHED);
+        assert_eq!(quads[1].border_dash, [6.0, 3.0]);
+
+        // Dots are as long as the border is wide, and as far apart
+        assert_eq!(quads[2].border_style, Quad::DOTTED);
+        assert_eq!(quads[2].border_dash, [2.0, 2.0]);
+    }
+
+    #[test]
+    fn inside_borders_keep_the_fill_within_the_bounds() {
+        let bounds =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(50.0, 30.0));
+
+        let quad = |border_alignment| Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0,
+            border_width: BorderWidth::new(2.0),
+            border_color: Color::BLACK,
+            border_style: BorderStyle::Solid,
+            border_alignment,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let primitive: Primitive<Null> = Primitive::Group {
+            primitives: vec![
+                quad(BorderAlignment::Inside),
+                quad(BorderAlignment::Center),
+                quad(BorderAlignment::Outside),
+            ],
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+        let quads = &layers[0].quads;
+
+        // The border covers the edges of the bounds, and the fill the rest
+        assert_eq!(quads[0].position, [10.0, 10.0]);
+        assert_eq!(quads[0].size, [50.0, 30.0]);
+        assert_eq!(quads[0].border_width, [2.0; 4]);
+
+        // Otherwise, the fill keeps the bounds and only the border grows
+        for (fill, border, outset) in [(1, 2, 1.0), (3, 4, 2.0)] {
+            assert_eq!(quads[fill].position, [10.0, 10.0]);
+            assert_eq!(quads[fill].size, [50.0, 30.0]);
+            assert_eq!(quads[fill].color, [1.0, 1.0, 1.0, 1.0]);
+            assert_eq!(quads[fill].border_width, [0.0; 4]);
+
+            assert_eq!(quads[border].position, [10.0 - outset, 10.0 - outset]);
+            assert_eq!(
+                quads[border].size,
+                [50.0 + 2.0 * outset, 30.0 + 2.0 * outset]
+            );
+            assert_eq!(quads[border].color, [0.0, 0.0, 0.0, 0.0]);
+        }
+    }
+
+    #[test]
+    fn opacity_fades_the_colors_of_its_content() {
+        let primitive: Primitive<Null> = Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Opacity {
+                opacity: 0.5,
+                content: Box::new(Primitive::Quad {
+                    bounds: Rectangle::new(
+                        Point::ORIGIN,
+                        Size::new(10.0, 10.0),
+                    ),
+                    background: Background::Color(Color::WHITE),
+                    border_radius: 0.0,
+                    border_width: BorderWidth::new(1.0),
+                    border_color: Color::BLACK,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
+                }),
+            }),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+        let quad = layers[0].quads[0];
+
+        // Nested opacities multiply
+        assert_eq!(quad.color, [1.0, 1.0, 1.0, 0.25]);
+        assert_eq!(quad.border_color, [0.0, 0.0, 0.0, 0.25]);
+    }
+
+    #[test]
+    fn image_backgrounds_are_drawn_beneath_their_border_and_content() {
+        let handle = image::Handle::from_pixels(2, 2, vec![255; 16]);
+
+        let primitive: Primitive<Null> = Primitive::Opacity {
+            opacity: 0.5,
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds: Rectangle::new(
+                            Point::new(10.0, 10.0),
+                            Size::new(50.0, 30.0),
+                        ),
+                        background: Background::Image {
+                            handle: handle.reference(),
+                            fit: ContentFit::Cover,
+                            opacity: 0.5,
+                        },
+                        border_radius: 4.0,
+                        border_width: BorderWidth::new(1.0),
+                        border_color: Color::BLACK,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
+                    },
+                    Primitive::Image {
+                        handle,
+                        bounds: Rectangle::new(
+                            Point::new(20.0, 20.0),
+                            Size::new(10.0, 10.0),
+                        ),
+                    },
+                ],
+            }),
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        assert_eq!(layers.len(), 2);
+        assert_eq!(layers[1].bounds, layers[0].bounds);
+
+        match &layers[0].images[..] {
+            [Image::Background {
+                bounds,
+                border_radius,
+                pattern,
+                opacity,
+                ..
+            }] => {
+                assert_eq!(
+                    *bounds,
+                    Rectangle::new(
+                        Point::new(10.0, 10.0),
+                        Size::new(50.0, 30.0)
+                    )
+                );
+                assert_eq!(*border_radius, 4.0);
+                assert_eq!(*pattern, Pattern::Fitted(ContentFit::Cover));
+                assert_eq!(*opacity, 0.25);
+            }
+            images => panic!("unexpected images: {:?}", images),
+        }
+
+        // The border is drawn without a fill, over the image
+        assert_eq!(layers[0].quads.len(), 0);
+        assert_eq!(layers[1].quads[0].color, [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(layers[1].quads[0].border_color, [0.0, 0.0, 0.0, 0.5]);
+        assert!(matches!(layers[1].images[..], [Image::Raster { .. }]));
+    }
+
+    #[test]
+    fn patterns_cover_their_bounds() {
+        let bounds =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(50.0, 30.0));
+        let image_size = Size::new(20.0, 20.0);
+
+        assert_eq!(
+            Pattern::Fitted(ContentFit::Cover).tiles(bounds, image_size),
+            vec![Rectangle::new(Point::new(10.0, 0.0), Size::new(50.0, 50.0))]
+        );
+
+        let tiles = Pattern::Tiled(0.5).tiles(bounds, image_size);
+
+        // Five columns and three rows of 10x10 tiles
+        assert_eq!(tiles.len(), 15);
+        assert_eq!(
+            tiles[0],
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(10.0, 10.0))
+        );
+        assert_eq!(
+            tiles[14],
+            Rectangle::new(Point::new(50.0, 30.0), Size::new(10.0, 10.0))
+        );
+
+        assert!(Pattern::Tiled(0.01).tiles(bounds, image_size).is_empty());
+        assert!(Pattern::Fitted(ContentFit::Fill)
+            .tiles(bounds, Size::ZERO)
+            .is_empty());
+    }
+
+    #[test]
+    fn layers_keep_their_primitives_without_customs() {
+        #[derive(Debug)]
+        struct Custom;
+
+        impl Backend for Custom {
+            type CustomRenderPrimitive = u32;
+        }
+
+        let primitive: Primitive<Custom> = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds: Rectangle::new(
+                        Point::ORIGIN,
+                        Size::new(10.0, 10.0),
+                    ),
+                    background: Background::Color(Color::BLACK),
+                    border_radius: 0.0,
+                    border_width: BorderWidth::from(0.0),
+                    border_color: Color::TRANSPARENT,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
+                },
+                Primitive::Custom(42),
+            ],
+        };
+
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        assert_eq!(layers[0].customs, vec![&42]);
+
+        let layers: Vec<Layer<'_, Null>> =
+            layers.into_iter().map(Layer::without_customs).collect();
+
+        assert_eq!(layers.len(), 1);
+        assert_eq!(layers[0].quads.len(), 1);
+        assert!(layers[0].customs.is_empty());
+    }
+}
@@ -12,8 +12,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 mod antialiasing;
 mod error;
+mod hinting;
 mod primitive;
 mod renderer;
+mod svg_renderer;
 mod transformation;
 mod viewport;
 
@@ -33,13 +35,18 @@ pub use antialiasing::Antialiasing;
 pub use backend::Backend;
 pub use defaults::Defaults;
 pub use error::Error;
+pub use hinting::Hinting;
 pub use layer::Layer;
 pub use primitive::Primitive;
 pub use renderer::Renderer;
+pub use svg_renderer::SvgRenderer;
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 
+pub use iced_style::{Styleable, Theme};
+
 pub use iced_native::alignment;
 pub use iced_native::{
-    Alignment, Background, Color, Font, Point, Rectangle, Size, Vector,
+    Alignment, Background, BorderAlignment, BorderStyle, BorderWidth, Color,
+    ContentFit, Font, Point, Rectangle, Shadow, Size, Vector, WritingMode,
 };
@@ -3,7 +3,10 @@ use crate::alignment;
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 
-use iced_native::{mouse, overlay, Color, Font, Padding, Point, Rectangle};
+use iced_native::{
+    mouse, overlay, BorderAlignment, BorderStyle, BorderWidth, Color, Font,
+    Padding, Rectangle, WritingMode,
+};
 
 pub use iced_style::menu::Style;
 
@@ -16,7 +19,7 @@ where
     fn decorate(
         &mut self,
         bounds: Rectangle,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         style: &Style,
         (primitives, mouse_cursor): Self::Output,
     ) -> Self::Output {
@@ -27,7 +30,11 @@ where
                         bounds,
                         background: style.background,
                         border_color: style.border_color,
-                        border_width: style.border_width,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
+                        border_width: style.border_width.into(),
                         border_radius: 0.0,
                     },
                     primitives,
@@ -40,7 +47,7 @@ where
     fn draw<T: ToString>(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         options: &[T],
         hovered_option: Option<usize>,
@@ -51,7 +58,7 @@ where
     ) -> Self::Output {
         use std::f32;
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
         let option_height = (text_size + padding.vertical()) as usize;
 
         let mut primitives = Vec::new();
@@ -79,7 +86,11 @@ where
                     bounds,
                     background: style.selected_background,
                     border_color: Color::TRANSPARENT,
-                    border_width: 0.0,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
+                    border_width: BorderWidth::ZERO,
                     border_radius: 0.0,
                 });
             }
@@ -101,6 +112,9 @@ where
                 },
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
+                letter_spacing: 0.0,
+                writing_mode: WritingMode::Horizontal,
+                background: None,
             });
         }
 
@@ -1,8 +1,11 @@
 use iced_native::{
-    image, svg, Background, Color, Font, Rectangle, Size, Vector,
+    image, svg, video, Background, BorderAlignment, BorderStyle, BorderWidth,
+    Color, Font, Rectangle, Size, Vector, WritingMode,
 };
 
 use crate::alignment;
+#[cfg(feature = "canvas")]
+use crate::canvas;
 use crate::triangle;
 use crate::Backend;
 
@@ -42,6 +45,15 @@ pub enum Primitive<B: PrimitiveBackend> {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
+        /// The extra space between every two consecutive glyphs of the text
+        letter_spacing: f32,
+        /// The direction in which the lines of the text flow
+        writing_mode: WritingMode,
+        /// The background filling the bounds of the text, if any
+        ///
+        /// It is drawn right before the text, in the same layer, so it is
+        /// always aligned with the glyphs.
+        background: Option<Background>,
     },
     /// A quad primitive
     Quad {
@@ -51,10 +63,69 @@ pub enum Primitive<B: PrimitiveBackend> {
         background: Background,
         /// The border radius of the quad
         border_radius: f32,
-        /// The border width of the quad
-        border_width: f32,
+        /// The border width of each edge of the quad
+        border_width: BorderWidth,
         /// The border color of the quad
         border_color: Color,
+        /// The border style of the quad
+        border_style: BorderStyle,
+        /// The placement of the border of the quad relative to its bounds
+        ///
+        /// Borders that are not drawn inside grow the quad past its bounds.
+        border_alignment: BorderAlignment,
+        /// The offset of the dash pattern along the border of the quad
+        ///
+        /// The pattern stays still unless this offset changes.
+        border_offset: f32,
+        /// Whether the background of the quad is filled
+        fill: bool,
+    },
+    /// A shadow cast inside the bounds of a quad, like the one of an inset
+    /// field or a pressed button
+    InnerShadow {
+        /// The bounds of the quad casting the shadow
+        bounds: Rectangle,
+        /// The border radius of the quad
+        border_radius: f32,
+        /// The color of the shadow
+        color: Color,
+        /// The offset of the shadow
+        offset: Vector,
+        /// The blur radius of the shadow
+        ///
+        /// A blur radius of zero produces a crisp shadow.
+        blur_radius: f32,
+    },
+    /// A shadow cast outside the bounds of a quad, like the one of a raised
+    /// card or button
+    ///
+    /// It should be drawn before the quad casting it.
+    Shadow {
+        /// The bounds of the quad casting the shadow
+        bounds: Rectangle,
+        /// The border radius of the quad
+        border_radius: f32,
+        /// The color of the shadow
+        color: Color,
+        /// The offset of the shadow
+        offset: Vector,
+        /// The blur radius of the shadow
+        ///
+        /// A blur radius of zero produces a crisp shadow.
+        blur_radius: f32,
+    },
+    /// A blur of everything drawn behind some bounds, like a frosted glass
+    ///
+    /// The primitives drawn after it are composited on top of the blurred
+    /// region. Backends that cannot read back their target fall back to a
+    /// quad filled with the tint.
+    Backdrop {
+        /// The bounds of the blurred region
+        bounds: Rectangle,
+        /// The blur radius of the backdrop
+        blur_radius: f32,
+        /// The color blended over the blurred region
+        tint: Color,
     },
     /// An image primitive
     Image {
@@ -71,6 +142,13 @@ pub enum Primitive<B: PrimitiveBackend> {
         /// The bounds of the viewport
         bounds: Rectangle,
     },
+    /// The current frame of a video
+    Video {
+        /// The handle of the video
+        handle: video::Handle,
+        /// The bounds of the video
+        bounds: Rectangle,
+    },
     /// A clip primitive
     Clip {
         /// The bounds of the clip
@@ -88,6 +166,18 @@ pub enum Primitive<B: PrimitiveBackend> {
         /// The primitive to translate
         content: Box<Primitive<B>>,
     },
+    /// A primitive that fades its content
+    ///
+    /// The opacity multiplies the alpha of every primitive of the content on
+    /// its own, so overlapping primitives show through each other. Images,
+    /// SVGs, videos and custom primitives are drawn fully opaque.
+    Opacity {
+        /// The opacity of the content, from `0.0` to `1.0`
+        opacity: f32,
+
+        /// The primitive to fade
+        content: Box<Primitive<B>>,
+    },
     /// A low-level primitive to render a mesh of triangles.
     ///
     /// It can be used to render many kinds of geometry freely.
@@ -100,6 +190,39 @@ pub enum Primitive<B: PrimitiveBackend> {
         /// Any geometry that falls out of this region will be clipped.
         size: Size,
     },
+    /// A low-level primitive to render a mesh of triangles that samples an
+    /// image, like a sprite.
+    ///
+    /// The color of each vertex multiplies the sampled texels.
+    TexturedMesh2D {
+        /// The vertex and index buffers of the mesh
+        buffers: triangle::TexturedMesh2D,
+
+        /// The size of the drawable region of the mesh.
+        ///
+        /// Any geometry that falls out of this region will be clipped.
+        size: Size,
+
+        /// The image sampled by the mesh
+        handle: image::Handle,
+    },
+    /// A vector path, filled and/or stroked.
+    ///
+    /// It is tessellated into a triangle mesh on the CPU every time it is
+    /// laid out, so prefer a [`canvas::Cache`] for complex paths that rarely
+    /// change.
+    #[cfg(feature = "canvas")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+    Path {
+        /// The path to draw
+        path: canvas::Path,
+
+        /// The style used to fill the path, if any
+        fill: Option<canvas::Fill>,
+
+        /// The style used to stroke the path, if any
+        stroke: Option<canvas::Stroke>,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
@@ -118,6 +241,151 @@ impl<B: PrimitiveBackend> Default for Primitive<B> {
     }
 }
 
+impl<B: PrimitiveBackend> Primitive<B> {
+    /// Flattens the [`Primitive`] into a single group of leaves.
+    ///
+    /// Nested groups are collapsed and translations are folded into the
+    /// bounds of the leaves, so the backend walks a shallow tree. Meshes and
+    /// cached primitives keep their translation, since their contents cannot
+    /// be offset without rebuilding them. The contents of a clip are flattened
+    /// separately.
+    pub fn flatten(self) -> Self {
+        self.flatten_at(Vector::new(0.0, 0.0))
+    }
+
+    /// Returns the amount of drawable primitives in the [`Primitive`] tree.
+    ///
+    /// Groups, clips, translations and cached primitives only contain other
+    /// primitives, so they are not counted themselves.
+    pub fn primitive_count(&self) -> usize {
+        match self {
+            Primitive::None => 0,
+            Primitive::Group { primitives } => {
+                primitives.iter().map(Self::primitive_count).sum()
+            }
+            Primitive::Clip { content, .. }
+            | Primitive::Translate { content, .. }
+            | Primitive::Opacity { content, .. } => content.primitive_count(),
+            Primitive::Cached { cache } => cache.primitive_count(),
+            _ => 1,
+        }
+    }
+
+    /// Returns the amount of layers needed to draw the [`Primitive`] tree:
+    /// one, plus one per clip and backdrop.
+    ///
+    /// It is an upper bound of the layers produced by [`Layer::generate`],
+    /// which skips the clips and backdrops out of the viewport.
+    ///
+    /// [`Layer::generate`]: crate::Layer::generate
+    pub fn layer_count(&self) -> usize {
+        1 + self.nested_layer_count()
+    }
+
+    fn nested_layer_count(&self) -> usize {
+        match self {
+            Primitive::Group { primitives } => {
+                primitives.iter().map(Self::nested_layer_count).sum()
+            }
+            Primitive::Clip { content, .. } => 1 + content.nested_layer_count(),
+            Primitive::Translate { content, .. }
+            | Primitive::Opacity { content, .. } => {
+                content.nested_layer_count()
+            }
+            Primitive::Cached { cache } => cache.nested_layer_count(),
+            Primitive::Backdrop { .. } => 1,
+            _ => 0,
+        }
+    }
+
+    fn flatten_at(self, translation: Vector) -> Self {
+        let mut primitives = Vec::new();
+
+        self.flatten_into(translation, &mut primitives);
+
+        match primitives.len() {
+            0 => Primitive::None,
+            1 => primitives.pop().unwrap_or_default(),
+            _ => Primitive::Group { primitives },
+        }
+    }
+
+    fn flatten_into(self, translation: Vector, primitives: &mut Vec<Self>) {
+        match self {
+            Primitive::None => {}
+            Primitive::Group {
+                primitives: children,
+            } => {
+                for child in children {
+                    child.flatten_into(translation, primitives);
+                }
+            }
+            Primitive::Translate {
+                translation: new_translation,
+                content,
+            } => {
+                content.flatten_into(translation + new_translation, primitives);
+            }
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => primitives.push(Primitive::Clip {
+                bounds: bounds + translation,
+                offset,
+                content: Box::new(content.flatten_at(translation)),
+            }),
+            Primitive::Opacity { opacity, content } => {
+                primitives.push(Primitive::Opacity {
+                    opacity,
+                    content: Box::new(content.flatten_at(translation)),
+                })
+            }
+            primitive @ Primitive::Mesh2D { .. }
+            | primitive @ Primitive::TexturedMesh2D { .. }
+            | primitive @ Primitive::Cached { .. } => {
+                primitives.push(if translation == Vector::new(0.0, 0.0) {
+                    primitive
+                } else {
+                    Primitive::Translate {
+                        translation,
+                        content: Box::new(primitive),
+                    }
+                })
+            }
+            #[cfg(feature = "canvas")]
+            Primitive::Path { path, fill, stroke } => {
+                primitives.push(Primitive::Path {
+                    path: path.translated(translation),
+                    fill,
+                    stroke,
+                })
+            }
+            mut primitive => {
+                if let Some(bounds) = primitive.bounds_mut() {
+                    *bounds = *bounds + translation;
+                }
+
+                primitives.push(primitive);
+            }
+        }
+    }
+
+    fn bounds_mut(&mut self) -> Option<&mut Rectangle> {
+        match self {
+            Primitive::Text { bounds, .. }
+            | Primitive::Quad { bounds, .. }
+            | Primitive::InnerShadow { bounds, .. }
+            | Primitive::Shadow { bounds, .. }
+            | Primitive::Backdrop { bounds, .. }
+            | Primitive::Image { bounds, .. }
+            | Primitive::Svg { bounds, .. }
+            | Primitive::Video { bounds, .. } => Some(bounds),
+            _ => None,
+        }
+    }
+}
+
 impl PrimitiveBackend for () {
     type CustomRenderPrimitive = ();
 }
@@ -137,6 +405,9 @@ impl<B: Backend> From<Primitive<()>> for Primitive<B> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                letter_spacing,
+                writing_mode,
+                background,
             } => Primitive::Text {
                 content,
                 bounds,
@@ -145,6 +416,9 @@ impl<B: Backend> From<Primitive<()>> for Primitive<B> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                letter_spacing,
+                writing_mode,
+                background,
             },
             Primitive::Quad {
                 bounds,
@@ -152,12 +426,55 @@ impl<B: Backend> From<Primitive<()>> for Primitive<B> {
                 border_radius,
                 border_width,
                 border_color,
+                border_style,
+                border_alignment,
+                border_offset,
+                fill,
             } => Primitive::Quad {
                 bounds,
                 background,
                 border_radius,
                 border_width,
                 border_color,
+                border_style,
+                border_alignment,
+                border_offset,
+                fill,
+            },
+            Primitive::InnerShadow {
+                bounds,
+                border_radius,
+                color,
+                offset,
+                blur_radius,
+            } => Primitive::InnerShadow {
+                bounds,
+                border_radius,
+                color,
+                offset,
+                blur_radius,
+            },
+            Primitive::Shadow {
+                bounds,
+                border_radius,
+                color,
+                offset,
+                blur_radius,
+            } => Primitive::Shadow {
+                bounds,
+                border_radius,
+                color,
+                offset,
+                blur_radius,
+            },
+            Primitive::Backdrop {
+                bounds,
+                blur_radius,
+                tint,
+            } => Primitive::Backdrop {
+                bounds,
+                blur_radius,
+                tint,
             },
             Primitive::Image { handle, bounds } => {
                 Primitive::Image { handle, bounds }
@@ -165,6 +482,9 @@ impl<B: Backend> From<Primitive<()>> for Primitive<B> {
             Primitive::Svg { handle, bounds } => {
                 Primitive::Svg { handle, bounds }
             }
+            Primitive::Video { handle, bounds } => {
+                Primitive::Video { handle, bounds }
+            }
             Primitive::Clip {
                 bounds,
                 offset,
@@ -181,9 +501,26 @@ impl<B: Backend> From<Primitive<()>> for Primitive<B> {
                 translation,
                 content: Box::new(From::from(*content)),
             },
+            Primitive::Opacity { opacity, content } => Primitive::Opacity {
+                opacity,
+                content: Box::new(From::from(*content)),
+            },
             Primitive::Mesh2D { buffers, size } => {
                 Primitive::Mesh2D { buffers, size }
             }
+            Primitive::TexturedMesh2D {
+                buffers,
+                size,
+                handle,
+            } => Primitive::TexturedMesh2D {
+                buffers,
+                size,
+                handle,
+            },
+            #[cfg(feature = "canvas")]
+            Primitive::Path { path, fill, stroke } => {
+                Primitive::Path { path, fill, stroke }
+            }
             Primitive::Cached { cache } => Primitive::Cached {
                 cache: Arc::new(From::from((*cache).clone())),
             },
@@ -193,3 +530,93 @@ impl<B: Backend> From<Primitive<()>> for Primitive<B> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quad(bounds: Rectangle) -> Primitive<()> {
+        Primitive::Quad {
+            bounds,
+            background: Background::Color(Color::BLACK),
+            border_radius: 0.0,
+            border_width: BorderWidth::ZERO,
+            border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        }
+    }
+
+    fn bounds(x: f32, y: f32) -> Rectangle {
+        Rectangle {
+            x,
+            y,
+            width: 10.0,
+            height: 10.0,
+        }
+    }
+
+    #[test]
+    fn nested_translate_groups_flatten_to_offset_leaves() {
+        let primitive = Primitive::Translate {
+            translation: Vector::new(100.0, 0.0),
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    quad(bounds(0.0, 0.0)),
+                    Primitive::None,
+                    Primitive::Group {
+                        primitives: vec![Primitive::Translate {
+                            translation: Vector::new(0.0, 50.0),
+                            content: Box::new(Primitive::Group {
+                                primitives: vec![quad(bounds(5.0, 5.0))],
+                            }),
+                        }],
+                    },
+                ],
+            }),
+        };
+
+        let leaves = match primitive.flatten() {
+            Primitive::Group { primitives } => primitives,
+            primitive => panic!("Expected a group, found {:?}", primitive),
+        };
+
+        let leaf_bounds: Vec<_> = leaves
+            .iter()
+            .map(|leaf| match leaf {
+                Primitive::Quad { bounds, .. } => *bounds,
+                leaf => panic!("Expected a quad, found {:?}", leaf),
+            })
+            .collect();
+
+        assert_eq!(leaf_bounds, vec![bounds(100.0, 0.0), bounds(105.0, 55.0)]);
+    }
+
+    #[test]
+    fn only_drawable_primitives_and_layers_are_counted() {
+        let primitive = Primitive::Group {
+            primitives: vec![
+                quad(bounds(0.0, 0.0)),
+                Primitive::None,
+                Primitive::Clip {
+                    bounds: bounds(0.0, 0.0),
+                    offset: Vector::new(0, 0),
+                    content: Box::new(Primitive::Translate {
+                        translation: Vector::new(5.0, 5.0),
+                        content: Box::new(Primitive::Group {
+                            primitives: vec![
+                                quad(bounds(0.0, 0.0)),
+                                quad(bounds(5.0, 5.0)),
+                            ],
+                        }),
+                    }),
+                },
+            ],
+        };
+
+        assert_eq!(primitive.primitive_count(), 3);
+        assert_eq!(primitive.layer_count(), 2);
+    }
+}
@@ -1,20 +1,36 @@
-use crate::{Backend, Defaults, Primitive};
+use crate::defaults::{self, Defaults};
+use crate::{Backend, Primitive, SvgRenderer, Theme};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::{
-    Background, Color, Element, Point, Rectangle, Vector, Widget,
+    Background, BorderAlignment, BorderStyle, BorderWidth, Color, Element,
+    Rectangle, Vector, Widget,
 };
 
+use std::time::{Duration, Instant};
+
 /// A backend-agnostic renderer that supports all the built-in widgets.
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    theme: Theme,
+    started_at: Instant,
+    now: Instant,
+    redraw_requested: bool,
 }
 
 impl<B: Backend> Renderer<B> {
     /// Creates a new [`Renderer`] from the given [`Backend`].
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        let now = Instant::now();
+
+        Self {
+            backend,
+            theme: Theme::default(),
+            started_at: now,
+            now,
+            redraw_requested: false,
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -26,6 +42,24 @@ impl<B: Backend> Renderer<B> {
     pub fn backend_mut(&mut self) -> &mut B {
         &mut self.backend
     }
+
+    /// Returns the active [`Theme`] of the [`Renderer`].
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Sets the active [`Theme`] of the [`Renderer`].
+    ///
+    /// Widgets without an explicit style will resolve it from this [`Theme`].
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Returns the time on the animation clock of the [`Renderer`], measured
+    /// since its creation.
+    pub(crate) fn animation_time(&self) -> Duration {
+        self.now.duration_since(self.started_at)
+    }
 }
 
 impl<B> iced_native::Renderer for Renderer<B>
@@ -35,6 +69,14 @@ where
     type Output = (Primitive<B>, mouse::Interaction);
     type Defaults = Defaults;
 
+    fn defaults(&self) -> Defaults {
+        Defaults {
+            text: defaults::Text {
+                color: self.theme.text,
+            },
+        }
+    }
+
     fn layout<'a, Message>(
         &mut self,
         element: &Element<'a, Message, Self>,
@@ -47,6 +89,35 @@ where
         layout
     }
 
+    fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    fn take_redraw_request(&mut self) -> bool {
+        std::mem::take(&mut self.redraw_requested)
+    }
+
+    fn advance_clock(&mut self, now: Instant) {
+        self.now = self.now.max(now);
+    }
+
+    fn primitive_count(&self, (primitive, _): &Self::Output) -> usize {
+        primitive.primitive_count()
+    }
+
+    fn layer_count(&self, (primitive, _): &Self::Output) -> usize {
+        primitive.layer_count()
+    }
+
+    fn to_svg(
+        &self,
+        (primitive, _): &Self::Output,
+        width: u32,
+        height: u32,
+    ) -> String {
+        SvgRenderer::new(width, height).render(primitive)
+    }
+
     fn overlay(
         &mut self,
         (base_primitive, base_cursor): (Primitive<B>, mouse::Interaction),
@@ -89,19 +160,19 @@ where
         defaults: &Defaults,
         widget: &dyn Widget<Message, Self>,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         color: Color,
     ) -> Self::Output {
-        let (primitive, cursor) =
-            widget.draw(self, defaults, layout, cursor_position, viewport);
+        let (primitive, mouse_interaction) =
+            widget.draw(self, defaults, layout, cursor, viewport);
 
         let mut primitives = Vec::new();
 
         explain_layout(layout, color, &mut primitives);
         primitives.push(primitive);
 
-        (Primitive::Group { primitives }, cursor)
+        (Primitive::Group { primitives }, mouse_interaction)
     }
 }
 
@@ -114,11 +185,72 @@ fn explain_layout<B: Backend>(
         bounds: layout.bounds(),
         background: Background::Color(Color::TRANSPARENT),
         border_radius: 0.0,
-        border_width: 1.0,
-        border_color: [0.6, 0.6, 0.6, 0.5].into(),
+        border_width: BorderWidth::new(1.0),
+        border_color: color,
+        border_style: BorderStyle::Solid,
+        border_alignment: BorderAlignment::Inside,
+        border_offset: 0.0,
+        fill: true,
     });
 
     for child in layout.children() {
         explain_layout(child, color, primitives);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{Column, Point, Size};
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    #[test]
+    fn explain_outlines_every_node_with_the_given_color() {
+        let mut renderer = Renderer::new(Null);
+        let color = Color::from_rgb(1.0, 0.0, 0.0);
+
+        let node = layout::Node::with_children(
+            Size::new(100.0, 100.0),
+            vec![
+                layout::Node::new(Size::new(100.0, 50.0)),
+                layout::Node::with_children(
+                    Size::new(100.0, 50.0),
+                    vec![layout::Node::new(Size::new(50.0, 50.0))],
+                ),
+            ],
+        );
+
+        let column: Column<'_, (), Renderer<Null>> = Column::new();
+
+        let (primitive, _) = layout::Debugger::explain(
+            &mut renderer,
+            &Defaults::default(),
+            &column,
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            color,
+        );
+
+        let primitives = match primitive {
+            Primitive::Group { primitives } => primitives,
+            primitive => panic!("Expected a group, found {:?}", primitive),
+        };
+
+        let outlines: Vec<_> = primitives
+            .iter()
+            .filter_map(|primitive| match primitive {
+                Primitive::Quad { border_color, .. } => Some(*border_color),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(outlines, vec![color; 4]);
+    }
+}
@@ -0,0 +1,545 @@
+//! Convert primitives into static SVG documents.
+use crate::alignment;
+use crate::layer;
+use crate::primitive::PrimitiveBackend;
+use crate::triangle;
+use crate::{
+    Background, BorderStyle, BorderWidth, Color, Font, Primitive, Rectangle,
+    WritingMode,
+};
+
+use std::fmt::Write;
+
+/// Converts a [`Primitive`] tree into an SVG document.
+///
+/// Quads become `<rect>` elements, text becomes `<text>` elements and meshes
+/// become `<path>` elements, filled with the average color of each triangle.
+/// Clips become groups with a `clip-path`. Backdrops are only tinted, inner
+/// shadows are skipped, and images, SVGs, videos and custom primitives are
+/// left out. So are image backgrounds, leaving their quads unfilled.
+#[derive(Debug)]
+pub struct SvgRenderer {
+    width: u32,
+    height: u32,
+    definitions: String,
+    content: String,
+    clip_count: usize,
+}
+
+impl SvgRenderer {
+    /// Creates a new [`SvgRenderer`] producing documents of the given size,
+    /// in logical pixels.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            definitions: String::new(),
+            content: String::new(),
+            clip_count: 0,
+        }
+    }
+
+    /// Renders the given [`Primitive`] into an SVG document.
+    pub fn render<B: PrimitiveBackend>(
+        mut self,
+        primitive: &Primitive<B>,
+    ) -> String {
+        self.primitive(primitive);
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" \
+            height=\"{1}\" viewBox=\"0 0 {0} {1}\"><defs>{2}</defs>{3}</svg>",
+            self.width, self.height, self.definitions, self.content
+        )
+    }
+
+    fn primitive<B: PrimitiveBackend>(&mut self, primitive: &Primitive<B>) {
+        match primitive {
+            Primitive::None
+            | Primitive::InnerShadow { .. }
+            | Primitive::Shadow { .. }
+            | Primitive::Image { .. }
+            | Primitive::Svg { .. }
+            | Primitive::Video { .. }
+            | Primitive::TexturedMesh2D { .. }
+            | Primitive::Custom(_) => {}
+            Primitive::Group { primitives } => {
+                for primitive in primitives {
+                    self.primitive(primitive);
+                }
+            }
+            Primitive::Text {
+                content,
+                bounds,
+                color,
+                size,
+                font,
+                horizontal_alignment,
+                vertical_alignment,
+                letter_spacing,
+                writing_mode,
+                background,
+            } => {
+                if let Some(background) = background {
+                    self.rect(
+                        *bounds,
+                        0.0,
+                        Some(*background),
+                        BorderWidth::ZERO,
+                        None,
+                    );
+                }
+
+                self.text(
+                    content,
+                    *bounds,
+                    *color,
+                    *size,
+                    *font,
+                    *horizontal_alignment,
+                    *vertical_alignment,
+                    *letter_spacing,
+                    *writing_mode,
+                );
+            }
+            Primitive::Quad {
+                bounds,
+                background,
+                border_radius,
+                border_width,
+                border_color,
+                border_style,
+                border_alignment,
+                fill,
+                ..
+            } => {
+                let (bounds, border_radius) = layer::align_border(
+                    *bounds,
+                    *border_radius,
+                    *border_width,
+                    *border_alignment,
+                );
+
+                self.rect(
+                    bounds,
+                    border_radius,
+                    if *fill { Some(*background) } else { None },
+                    *border_width,
+                    Some((*border_color, *border_style)),
+                );
+            }
+            Primitive::Backdrop { bounds, tint, .. } => {
+                self.rect(
+                    *bounds,
+                    0.0,
+                    Some(Background::Color(*tint)),
+                    BorderWidth::ZERO,
+                    None,
+                );
+            }
+            Primitive::Clip {
+                bounds,
+                offset,
+                content,
+            } => {
+                let id = self.clip(*bounds);
+
+                let _ = write!(
+                    self.content,
+                    "<g clip-path=\"url(#{})\"><g transform=\"translate({} {})\">",
+                    id,
+                    -i64::from(offset.x),
+                    -i64::from(offset.y)
+                );
+
+                self.primitive(content);
+                self.content.push_str("</g></g>");
+            }
+            Primitive::Translate {
+                translation,
+                content,
+            } => {
+                let _ = write!(
+                    self.content,
+                    "<g transform=\"translate({} {})\">",
+                    translation.x, translation.y
+                );
+
+                self.primitive(content);
+                self.content.push_str("</g>");
+            }
+            Primitive::Opacity { opacity, content } => {
+                let _ = write!(self.content, "<g opacity=\"{}\">", opacity);
+
+                self.primitive(content);
+                self.content.push_str("</g>");
+            }
+            Primitive::Mesh2D { buffers, size } => {
+                let id = self.clip(Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: size.width,
+                    height: size.height,
+                });
+
+                let _ = write!(self.content, "<g clip-path=\"url(#{})\">", id);
+                self.mesh(buffers);
+                self.content.push_str("</g>");
+            }
+            #[cfg(feature = "canvas")]
+            Primitive::Path { path, fill, stroke } => {
+                self.mesh(&path.tessellate(*fill, *stroke));
+            }
+            Primitive::Cached { cache } => {
+                self.primitive(cache);
+            }
+        }
+    }
+
+    fn rect(
+        &mut self,
+        bounds: Rectangle,
+        border_radius: f32,
+        background: Option<Background>,
+        border_width: BorderWidth,
+        border: Option<(Color, BorderStyle)>,
+    ) {
+        let _ = write!(
+            self.content,
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\"",
+            bounds.x, bounds.y, bounds.width, bounds.height, border_radius
+        );
+
+        match background {
+            Some(Background::Color(color)) => {
+                self.content.push_str(&paint("fill", color));
+            }
+            Some(Background::Image { .. })
+            | Some(Background::Tiled { .. })
+            | None => {
+                self.content.push_str(" fill=\"none\"");
+            }
+        }
+
+        self.content.push_str("/>");
+
+        // Borders are drawn inside the bounds, while SVG strokes are
+        // centered on the outline
+        if let Some((color, style)) = border {
+            if color.a <= 0.0 || border_width.max() <= 0.0 {
+                return;
+            }
+
+            // Borders with uneven edges are drawn as a solid rectangle per
+            // edge, ignoring their radius and style
+            if !border_width.is_uniform() {
+                let BorderWidth {
+                    top,
+                    right,
+                    bottom,
+                    left,
+                } = border_width;
+
+                let edges = [
+                    (bounds.x, bounds.y, bounds.width, top),
+                    (
+                        bounds.x + bounds.width - right,
+                        bounds.y,
+                        right,
+                        bounds.height,
+                    ),
+                    (
+                        bounds.x,
+                        bounds.y + bounds.height - bottom,
+                        bounds.width,
+                        bottom,
+                    ),
+                    (bounds.x, bounds.y, left, bounds.height),
+                ];
+
+                for (x, y, width, height) in edges.iter().copied() {
+                    if width > 0.0 && height > 0.0 {
+                        let _ = write!(
+                            self.content,
+                            "<rect x=\"{}\" y=\"{}\" width=\"{}\" \
+                            height=\"{}\"{}/>",
+                            x,
+                            y,
+                            width,
+                            height,
+                            paint("fill", color),
+                        );
+                    }
+                }
+
+                return;
+            }
+
+            let border_width = border_width.top;
+            let half = border_width / 2.0;
+
+            let _ = write!(
+                self.content,
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" \
+                rx=\"{}\" fill=\"none\" stroke-width=\"{}\"{}",
+                bounds.x + half,
+                bounds.y + half,
+                (bounds.width - border_width).max(0.0),
+                (bounds.height - border_width).max(0.0),
+                (border_radius - half).max(0.0),
+                border_width,
+                paint("stroke", color),
+            );
+
+            match style {
+                BorderStyle::Solid => {}
+                BorderStyle::Dashed { dash, gap } => {
+                    let _ = write!(
+                        self.content,
+                        " stroke-dasharray=\"{} {}\"",
+                        dash, gap
+                    );
+                }
+                BorderStyle::Dotted => {
+                    let _ = write!(
+                        self.content,
+                        " stroke-dasharray=\"0 {}\" \
+                        stroke-linecap=\"round\"",
+                        border_width * 2.0
+                    );
+                }
+            }
+
+            self.content.push_str("/>");
+        }
+    }
+
+    fn text(
+        &mut self,
+        content: &str,
+        bounds: Rectangle,
+        color: Color,
+        size: f32,
+        font: Font,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) {
+        let anchor = match horizontal_alignment {
+            alignment::Horizontal::Left => "start",
+            alignment::Horizontal::Center => "middle",
+            alignment::Horizontal::Right => "end",
+        };
+
+        let baseline = match vertical_alignment {
+            alignment::Vertical::Top => "hanging",
+            alignment::Vertical::Center => "middle",
+            alignment::Vertical::Bottom => "text-after-edge",
+        };
+
+        let family = match font {
+            Font::Default => "sans-serif",
+            Font::External { name, .. } => name,
+        };
+
+        let _ = write!(
+            self.content,
+            "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-family=\"{}\" \
+            text-anchor=\"{}\" dominant-baseline=\"{}\"{}{}{}>{}</text>",
+            bounds.x,
+            bounds.y,
+            size,
+            escape(family),
+            anchor,
+            baseline,
+            if letter_spacing != 0.0 {
+                format!(" letter-spacing=\"{}\"", letter_spacing)
+            } else {
+                String::new()
+            },
+            match writing_mode {
+                WritingMode::Horizontal => "",
+                WritingMode::VerticalRL => " writing-mode=\"vertical-rl\"",
+            },
+            paint("fill", color),
+            escape(content),
+        );
+    }
+
+    fn mesh(&mut self, mesh: &triangle::Mesh2D) {
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertices = [
+                mesh.vertices[triangle[0] as usize],
+                mesh.vertices[triangle[1] as usize],
+                mesh.vertices[triangle[2] as usize],
+            ];
+
+            let mut linear = [0.0; 4];
+
+            for vertex in &vertices {
+                for (channel, value) in linear.iter_mut().zip(&vertex.color) {
+                    *channel += value / 3.0;
+                }
+            }
+
+            let color = Color::new(
+                from_linear(linear[0]),
+                from_linear(linear[1]),
+                from_linear(linear[2]),
+                linear[3],
+            );
+
+            let [a, b, c] = [
+                vertices[0].position,
+                vertices[1].position,
+                vertices[2].position,
+            ];
+
+            let _ = write!(
+                self.content,
+                "<path d=\"M {} {} L {} {} L {} {} Z\"{}/>",
+                a[0],
+                a[1],
+                b[0],
+                b[1],
+                c[0],
+                c[1],
+                paint("fill", color),
+            );
+        }
+    }
+
+    /// Defines a new clip path with the given bounds, returning its id.
+    fn clip(&mut self, bounds: Rectangle) -> String {
+        let id = format!("clip{}", self.clip_count);
+        self.clip_count += 1;
+
+        let _ = write!(
+            self.definitions,
+            "<clipPath id=\"{}\"><rect x=\"{}\" y=\"{}\" width=\"{}\" \
+            height=\"{}\"/></clipPath>",
+            id, bounds.x, bounds.y, bounds.width, bounds.height
+        );
+
+        id
+    }
+}
+
+/// Returns the attributes painting the given property with a [`Color`].
+fn paint(property: &str, color: Color) -> String {
+    let channel = |value: f32| (value.max(0.0).min(1.0) * 255.0).round() as u8;
+    let [r, g, b] = [channel(color.r), channel(color.g), channel(color.b)];
+
+    if color.a < 1.0 {
+        format!(
+            " {0}=\"rgb({1},{2},{3})\" {0}-opacity=\"{4}\"",
+            property, r, g, b, color.a
+        )
+    } else {
+        format!(" {}=\"rgb({},{},{})\"", property, r, g, b)
+    }
+}
+
+fn from_linear(linear: f32) -> f32 {
+    let linear = linear.max(0.0).min(1.0);
+
+    if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BorderAlignment, Vector};
+
+    #[test]
+    fn quads_text_and_clips_become_svg_elements() {
+        let primitive: Primitive<()> = Primitive::Clip {
+            bounds: Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 100.0,
+                height: 50.0,
+            },
+            offset: Vector::new(0, 10),
+            content: Box::new(Primitive::Group {
+                primitives: vec![
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: 10.0,
+                            y: 10.0,
+                            width: 80.0,
+                            height: 30.0,
+                        },
+                        background: Background::Color(Color::from_rgb(
+                            1.0, 0.0, 0.0,
+                        )),
+                        border_radius: 4.0,
+                        border_width: BorderWidth::ZERO,
+                        border_color: Color::TRANSPARENT,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
+                    },
+                    Primitive::Text {
+                        content: String::from("a < b"),
+                        bounds: Rectangle {
+                            x: 50.0,
+                            y: 25.0,
+                            width: 80.0,
+                            height: 30.0,
+                        },
+                        color: Color::BLACK,
+                        size: 20.0,
+                        font: Font::Default,
+                        horizontal_alignment: alignment::Horizontal::Center,
+                        vertical_alignment: alignment::Vertical::Center,
+                        letter_spacing: 0.0,
+                        writing_mode: WritingMode::Horizontal,
+                        background: None,
+                    },
+                ],
+            }),
+        };
+
+        let svg = SvgRenderer::new(100, 50).render(&primitive);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"100\" height=\"50\""));
+        assert!(svg.contains(
+            "<clipPath id=\"clip0\"><rect x=\"0\" y=\"0\" width=\"100\" \
+            height=\"50\"/></clipPath>"
+        ));
+        assert!(svg.contains(
+            "<g clip-path=\"url(#clip0)\"><g transform=\"translate(0 -10)\">"
+        ));
+        assert!(svg.contains(
+            "<rect x=\"10\" y=\"10\" width=\"80\" height=\"30\" rx=\"4\" \
+            fill=\"rgb(255,0,0)\"/>"
+        ));
+        assert!(svg.contains("text-anchor=\"middle\""));
+        assert!(svg.contains(">a &lt; b</text>"));
+        assert!(svg.ends_with("</g></g></svg>"));
+    }
+}
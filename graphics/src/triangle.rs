@@ -23,3 +23,32 @@ pub struct Vertex2D {
     /// The vertex color in __linear__ RGBA.
     pub color: [f32; 4],
 }
+
+/// A set of [`Vertex2DTextured`] and indices representing a list of triangles
+/// that sample an image.
+#[derive(Clone, Debug)]
+pub struct TexturedMesh2D {
+    /// The vertices of the mesh
+    pub vertices: Vec<Vertex2DTextured>,
+
+    /// The list of vertex indices that defines the triangles of the mesh.
+    ///
+    /// Therefore, this list should always have a length that is a multiple of
+    /// 3.
+    pub indices: Vec<u32>,
+}
+
+/// A two-dimensional vertex with texture coordinates and some color in
+/// __linear__ RGBA.
+#[derive(Copy, Clone, Debug, Zeroable, Pod)]
+#[repr(C)]
+pub struct Vertex2DTextured {
+    /// The vertex position
+    pub position: [f32; 2],
+    /// The texture coordinates of the vertex, from `[0.0, 0.0]` at the
+    /// top-left corner of the image to `[1.0, 1.0]` at its bottom-right
+    /// corner.
+    pub uv: [f32; 2],
+    /// The color multiplied with the sampled texel, in __linear__ RGBA.
+    pub color: [f32; 4],
+}
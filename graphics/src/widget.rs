@@ -7,34 +7,60 @@
 //! ```
 //! use iced_graphics::{button, Button};
 //! ```
+pub mod border;
 pub mod button;
+pub mod chart;
 pub mod checkbox;
 pub mod container;
 pub mod image;
+pub mod lazy;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
+pub mod opacity;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
+pub mod spinner;
 pub mod svg;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod video;
 
 mod column;
+mod grid;
 mod row;
 mod space;
 mod text;
+mod wrap;
 
+#[doc(no_inline)]
+pub use border::Border;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use chart::{BarChart, LineChart};
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use lazy::Lazy;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -47,8 +73,12 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
 pub use toggler::Toggler;
@@ -56,11 +86,14 @@ pub use toggler::Toggler;
 pub use tooltip::Tooltip;
 
 pub use column::Column;
+pub use grid::Grid;
 pub use image::Image;
 pub use row::Row;
 pub use space::Space;
 pub use svg::Svg;
 pub use text::Text;
+pub use video::Video;
+pub use wrap::Wrap;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -76,4 +109,4 @@ pub mod qr_code;
 
 #[cfg(feature = "qr_code")]
 #[doc(no_inline)]
-pub use qr_code::QRCode;
+pub use qr_code::{QRCode, QrCode};
@@ -0,0 +1,58 @@
+//! Draw an outline around some content.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{
+    mouse, Background, BorderAlignment, BorderStyle, Color, Element, Layout,
+    Rectangle,
+};
+
+/// An element drawing a border around some content.
+///
+/// This is an alias of an `iced_native` border with a default `Renderer`.
+pub type Border<'a, Message, Backend> =
+    iced_native::Border<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::border::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        thickness: f32,
+        radius: f32,
+        color: Color,
+        style: BorderStyle,
+        offset: f32,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let (content, mouse_interaction) =
+            content.draw(self, defaults, content_layout, cursor, viewport);
+
+        if thickness > 0.0 {
+            let border = Primitive::Quad {
+                bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: radius,
+                border_width: thickness.into(),
+                border_color: color,
+                border_style: style,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: offset,
+                fill: false,
+            };
+
+            (
+                Primitive::Group {
+                    primitives: vec![content, border],
+                },
+                mouse_interaction,
+            )
+        } else {
+            (content, mouse_interaction)
+        }
+    }
+}
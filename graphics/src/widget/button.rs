@@ -2,12 +2,16 @@
 //!
 //! A [`Button`] has some local [`State`].
 use crate::defaults::{self, Defaults};
+use crate::widget::spinner;
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::{
-    Background, Color, Element, Layout, Padding, Point, Rectangle, Vector,
+    Background, BorderAlignment, Color, Element, Layout, Padding, Rectangle,
+    Shadow, Vector,
 };
 
+use std::time::Duration;
+
 pub use iced_native::button::State;
 pub use iced_style::button::{Style, StyleSheet};
 
@@ -22,6 +26,7 @@ where
     B: Backend,
 {
     const DEFAULT_PADDING: Padding = Padding::new(5);
+    const SPINNER_SIZE: u16 = 16;
 
     type Style = Box<dyn StyleSheet>;
 
@@ -29,16 +34,19 @@ where
         &mut self,
         _defaults: &Defaults,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         is_disabled: bool,
         is_pressed: bool,
+        is_loading: bool,
         style: &Box<dyn StyleSheet>,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
-        let styling = if is_disabled {
+        let styling = if is_loading {
+            style.loading()
+        } else if is_disabled {
             style.disabled()
         } else if is_mouse_over {
             if is_pressed {
@@ -50,17 +58,29 @@ where
             style.active()
         };
 
-        let (content, _) = content.draw(
-            self,
-            &Defaults {
-                text: defaults::Text {
-                    color: styling.text_color,
+        // The spinner takes the place of the content while loading
+        let content = if is_loading {
+            spinner::arc(
+                content_layout.bounds().center(),
+                f32::from(Self::SPINNER_SIZE) / 2.0,
+                spinner::rotation(self.animation_time(), SPINNER_PERIOD),
+                styling.text_color,
+            )
+        } else {
+            let (content, _) = content.draw(
+                self,
+                &Defaults {
+                    text: defaults::Text {
+                        color: styling.text_color,
+                    },
                 },
-            },
-            content_layout,
-            cursor_position,
-            &bounds,
-        );
+                content_layout,
+                cursor,
+                &bounds,
+            );
+
+            content
+        };
 
         (
             if styling.background.is_some() || styling.border_width > 0.0 {
@@ -70,38 +90,44 @@ where
                         .background
                         .unwrap_or(Background::Color(Color::TRANSPARENT)),
                     border_radius: styling.border_radius,
-                    border_width: styling.border_width,
+                    border_width: styling.border_width.into(),
                     border_color: styling.border_color,
+                    border_style: styling.border_style,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: styling.background.is_some(),
                 };
 
-                if styling.shadow_offset == Vector::default() {
-                    Primitive::Group {
-                        primitives: vec![background, content],
-                    }
-                } else {
-                    // TODO: Implement proper shadow support
-                    let shadow = Primitive::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + styling.shadow_offset.x,
-                            y: bounds.y + styling.shadow_offset.y,
-                            ..bounds
-                        },
-                        background: Background::Color(
-                            [0.0, 0.0, 0.0, 0.5].into(),
-                        ),
+                // The offset alone casts the crisp shadow of older styles
+                let shadow = styling
+                    .shadow
+                    .or_else(|| {
+                        (styling.shadow_offset != Vector::default()).then(
+                            || Shadow {
+                                color: [0.0, 0.0, 0.0, 0.5].into(),
+                                offset: styling.shadow_offset,
+                                blur_radius: 0.0,
+                            },
+                        )
+                    })
+                    .map(|shadow| Primitive::Shadow {
+                        bounds,
                         border_radius: styling.border_radius,
-                        border_width: 0.0,
-                        border_color: Color::TRANSPARENT,
-                    };
+                        color: shadow.color,
+                        offset: shadow.offset,
+                        blur_radius: shadow.blur_radius,
+                    });
 
-                    Primitive::Group {
-                        primitives: vec![shadow, background, content],
-                    }
+                Primitive::Group {
+                    primitives: shadow
+                        .into_iter()
+                        .chain(vec![background, content])
+                        .collect(),
                 }
             } else {
                 content
             },
-            if is_mouse_over && !is_disabled {
+            if is_mouse_over && !is_disabled && !is_loading {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
@@ -109,3 +135,54 @@ where
         )
     }
 }
+
+/// The time it takes the spinner of a loading [`Button`] to complete a turn.
+const SPINNER_PERIOD: Duration = Duration::from_millis(1000);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Renderer as _;
+    use iced_native::{layout, Length, Size, Space};
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    fn draw(renderer: &mut Renderer<Null>, is_loading: bool) {
+        let mut state = State::new();
+        let button: Element<'_, (), Renderer<Null>> = Button::new(
+            &mut state,
+            Space::new(Length::Units(40), Length::Units(20)),
+        )
+        .on_press(())
+        .loading(is_loading)
+        .into();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0));
+        let node = button.layout(renderer, &limits);
+        let viewport = node.bounds();
+
+        let _ = button.draw(
+            renderer,
+            &Default::default(),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &viewport,
+        );
+    }
+
+    #[test]
+    fn loading_buttons_request_redraws() {
+        let mut renderer = Renderer::new(Null);
+
+        draw(&mut renderer, false);
+        assert!(!renderer.take_redraw_request());
+
+        draw(&mut renderer, true);
+        assert!(renderer.take_redraw_request());
+    }
+}
@@ -6,12 +6,13 @@
 use crate::{Backend, Defaults, Primitive, Renderer};
 use iced_native::layout;
 use iced_native::mouse;
+use iced_native::trace;
 use iced_native::{
-    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
-    Widget,
+    Clipboard, Element, Hasher, Layout, Length, Rectangle, Size, Vector, Widget,
 };
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 pub mod event;
 pub mod path;
@@ -22,6 +23,7 @@ mod fill;
 mod frame;
 mod geometry;
 mod program;
+mod state;
 mod stroke;
 mod text;
 
@@ -33,6 +35,7 @@ pub use frame::Frame;
 pub use geometry::Geometry;
 pub use path::Path;
 pub use program::Program;
+pub use state::State;
 pub use stroke::{LineCap, LineJoin, Stroke};
 pub use text::Text;
 
@@ -73,7 +76,9 @@ pub use text::Text;
 ///
 /// // Then, we implement the `Program` trait
 /// impl Program<()> for Circle {
-///     fn draw(&self, bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry>{
+///     type State = ();
+///
+///     fn draw(&self, _state: &(), bounds: Rectangle, _cursor: Cursor) -> Vec<Geometry>{
 ///         // We prepare a new `Frame`
 ///         let mut frame = Frame::new(bounds.size());
 ///
@@ -91,15 +96,22 @@ pub use text::Text;
 /// // Finally, we simply use our `Circle` to create the `Canvas`!
 /// let canvas = Canvas::new(Circle { radius: 50.0 });
 /// ```
+///
+/// ## Keeping interaction state
+/// A [`Canvas`] created with [`Canvas::new`] starts with the default
+/// [`Program::State`] every time it is built. In order to keep it across the
+/// views of your application, like during a drag, store a [`State`] in your
+/// application and provide it with [`Canvas::state`].
 #[derive(Debug)]
-pub struct Canvas<Message, P: Program<Message>> {
+pub struct Canvas<'a, Message, P: Program<Message>> {
     width: Length,
     height: Length,
     program: P,
+    state: state::Storage<'a, P::State>,
     phantom: PhantomData<Message>,
 }
 
-impl<Message, P: Program<Message>> Canvas<Message, P> {
+impl<'a, Message, P: Program<Message>> Canvas<'a, Message, P> {
     const DEFAULT_SIZE: u16 = 100;
 
     /// Creates a new [`Canvas`].
@@ -108,10 +120,18 @@ impl<Message, P: Program<Message>> Canvas<Message, P> {
             width: Length::Units(Self::DEFAULT_SIZE),
             height: Length::Units(Self::DEFAULT_SIZE),
             program,
+            state: state::Storage::Owned(State::default()),
             phantom: PhantomData,
         }
     }
 
+    /// Sets the [`State`] of the [`Canvas`], kept by your application across
+    /// views.
+    pub fn state(mut self, state: &'a mut State<P::State>) -> Self {
+        self.state = state::Storage::Borrowed(state);
+        self
+    }
+
     /// Sets the width of the [`Canvas`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -125,7 +145,7 @@ impl<Message, P: Program<Message>> Canvas<Message, P> {
     }
 }
 
-impl<Message, P, B> Widget<Message, Renderer<B>> for Canvas<Message, P>
+impl<'a, Message, P, B> Widget<Message, Renderer<B>> for Canvas<'a, Message, P>
 where
     P: Program<Message>,
     B: Backend,
@@ -153,11 +173,11 @@ where
         &mut self,
         event: iced_native::Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer<B>,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         let bounds = layout.bounds();
 
         let canvas_event = match event {
@@ -170,17 +190,35 @@ where
             _ => None,
         };
 
-        let cursor = Cursor::from_window_position(cursor_position);
+        let state = self.state.get_mut();
+        let cursor = canvas_cursor(state, cursor);
 
         if let Some(canvas_event) = canvas_event {
-            let (event_status, message) =
-                self.program.update(canvas_event, bounds, cursor);
+            let (event_status, message) = self.program.update(
+                &mut state.program,
+                canvas_event.clone(),
+                bounds,
+                cursor,
+            );
+
+            match canvas_event {
+                Event::Mouse(mouse::Event::ButtonPressed(_))
+                | Event::Mouse(mouse::Event::DoubleClicked(..))
+                    if event_status == event::Status::Captured =>
+                {
+                    state.is_capturing = true;
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(_)) => {
+                    state.is_capturing = false;
+                }
+                _ => {}
+            }
 
             if let Some(message) = message {
                 messages.push(message);
             }
 
-            return event_status;
+            return event_status.cast();
         }
 
         event::Status::Ignored
@@ -191,27 +229,71 @@ where
         _renderer: &mut Renderer<B>,
         _defaults: &Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> (Primitive<B>, mouse::Interaction) {
         let bounds = layout.bounds();
         let translation = Vector::new(bounds.x, bounds.y);
-        let cursor = Cursor::from_window_position(cursor_position);
+        let state = self.state.get();
+        let cursor = canvas_cursor(state, cursor);
 
-        (
-            Primitive::Translate {
-                translation,
-                content: Box::new(Primitive::Group {
+        let content =
+            match self.program.cache_key(&state.program, bounds, cursor) {
+                Some(key) => {
+                    let mut cache = state.cache.borrow_mut();
+
+                    let primitive = match cache.as_ref() {
+                        Some(cached)
+                            if cached.key == key
+                                && cached.bounds == bounds.size() =>
+                        {
+                            cached.primitive.clone()
+                        }
+                        _ => {
+                            trace::cache_rebuilt("canvas program");
+
+                            let primitive = Arc::new(
+                                Primitive::Group {
+                                    primitives: self
+                                        .program
+                                        .draw(&state.program, bounds, cursor)
+                                        .into_iter()
+                                        .map(Geometry::into_primitive)
+                                        .collect(),
+                                }
+                                .flatten(),
+                            );
+
+                            *cache = Some(state::Cached {
+                                key,
+                                bounds: bounds.size(),
+                                primitive: primitive.clone(),
+                            });
+
+                            primitive
+                        }
+                    };
+
+                    Primitive::Cached { cache: primitive }.into()
+                }
+                None => Primitive::Group {
                     primitives: self
                         .program
-                        .draw(bounds, cursor)
+                        .draw(&state.program, bounds, cursor)
                         .into_iter()
                         .map(Geometry::into_primitive)
                         .map(Into::into)
                         .collect(),
-                }),
+                },
+            };
+
+        (
+            Primitive::Translate {
+                translation,
+                content: Box::new(content),
             },
-            self.program.mouse_interaction(bounds, cursor),
+            self.program
+                .mouse_interaction(&state.program, bounds, cursor),
         )
     }
 
@@ -224,14 +306,183 @@ where
     }
 }
 
-impl<'a, Message, P, B> From<Canvas<Message, P>>
+impl<'a, Message, P, B> From<Canvas<'a, Message, P>>
     for Element<'a, Message, Renderer<B>>
 where
     Message: 'static,
     P: Program<Message> + 'a,
     B: Backend,
 {
-    fn from(canvas: Canvas<Message, P>) -> Element<'a, Message, Renderer<B>> {
+    fn from(
+        canvas: Canvas<'a, Message, P>,
+    ) -> Element<'a, Message, Renderer<B>> {
         Element::new(canvas)
     }
 }
+
+/// Returns the [`Cursor`] of a [`Canvas`] with the given [`State`].
+fn canvas_cursor<S>(state: &State<S>, cursor: mouse::Cursor) -> Cursor {
+    match cursor.position() {
+        Some(position) if state.is_capturing => Cursor::Captured(position),
+        _ => Cursor::from(cursor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::{Color, Point};
+    use std::cell::Cell;
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    struct Square<'a> {
+        color: Color,
+        key: u64,
+        draws: &'a Cell<usize>,
+    }
+
+    impl<'a> Program<()> for Square<'a> {
+        type State = ();
+
+        fn draw(
+            &self,
+            _state: &(),
+            bounds: Rectangle,
+            _cursor: Cursor,
+        ) -> Vec<Geometry> {
+            self.draws.set(self.draws.get() + 1);
+
+            let mut frame = Frame::new(bounds.size());
+            frame.fill_rectangle(Point::ORIGIN, bounds.size(), self.color);
+
+            vec![frame.into_geometry()]
+        }
+
+        fn cache_key(
+            &self,
+            _state: &(),
+            _bounds: Rectangle,
+            _cursor: Cursor,
+        ) -> Option<u64> {
+            Some(self.key)
+        }
+    }
+
+    struct Hover<'a> {
+        draws: &'a Cell<usize>,
+    }
+
+    impl<'a> Program<()> for Hover<'a> {
+        type State = ();
+
+        fn draw(
+            &self,
+            _state: &(),
+            bounds: Rectangle,
+            cursor: Cursor,
+        ) -> Vec<Geometry> {
+            self.draws.set(self.draws.get() + 1);
+
+            let mut frame = Frame::new(bounds.size());
+
+            if let Some(position) = cursor.position_in(&bounds) {
+                frame.fill_rectangle(
+                    position,
+                    Size::new(1.0, 1.0),
+                    Color::BLACK,
+                );
+            }
+
+            vec![frame.into_geometry()]
+        }
+
+        fn cache_key(
+            &self,
+            _state: &(),
+            bounds: Rectangle,
+            cursor: Cursor,
+        ) -> Option<u64> {
+            let position = cursor.position_in(&bounds)?;
+
+            Some(
+                u64::from(position.x.to_bits()) << 32
+                    | u64::from(position.y.to_bits()),
+            )
+        }
+    }
+
+    fn draw<P: Program<()>>(
+        state: &mut State<P::State>,
+        program: P,
+        cursor: mouse::Cursor,
+    ) {
+        let mut renderer = Renderer::new(Null);
+        let canvas = Canvas::new(program).state(state);
+        let bounds = Size::new(100.0, 100.0);
+
+        let node = Widget::<(), _>::layout(
+            &canvas,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+
+        let defaults = iced_native::Renderer::defaults(&renderer);
+
+        let _ = Widget::<(), _>::draw(
+            &canvas,
+            &mut renderer,
+            &defaults,
+            Layout::new(&node),
+            cursor,
+            &Rectangle::with_size(bounds),
+        );
+    }
+
+    #[test]
+    fn program_is_only_drawn_when_its_cache_key_changes() {
+        let draws = Cell::new(0);
+        let mut state = State::new();
+
+        let red = || Square {
+            color: Color::from_rgb(1.0, 0.0, 0.0),
+            key: 1,
+            draws: &draws,
+        };
+
+        draw(&mut state, red(), mouse::Cursor::Unavailable);
+        draw(&mut state, red(), mouse::Cursor::Unavailable);
+        assert_eq!(draws.get(), 1);
+
+        draw(
+            &mut state,
+            Square {
+                color: Color::BLACK,
+                key: 2,
+                draws: &draws,
+            },
+            mouse::Cursor::Unavailable,
+        );
+        assert_eq!(draws.get(), 2);
+    }
+
+    #[test]
+    fn program_is_drawn_again_when_the_cursor_changes_its_cache_key() {
+        let draws = Cell::new(0);
+        let mut state = State::new();
+
+        let at = |x, y| mouse::Cursor::Available(Point::new(x, y));
+
+        draw(&mut state, Hover { draws: &draws }, at(10.0, 10.0));
+        draw(&mut state, Hover { draws: &draws }, at(10.0, 10.0));
+        assert_eq!(draws.get(), 1);
+
+        draw(&mut state, Hover { draws: &draws }, at(20.0, 10.0));
+        assert_eq!(draws.get(), 2);
+    }
+}
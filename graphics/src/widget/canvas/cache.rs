@@ -3,7 +3,7 @@ use crate::{
     Primitive,
 };
 
-use iced_native::Size;
+use iced_native::{trace, Size};
 use std::{cell::RefCell, sync::Arc};
 
 enum State {
@@ -66,13 +66,15 @@ impl Cache {
             }
         }
 
+        trace::cache_rebuilt("canvas");
+
         let mut frame = Frame::new(bounds);
         draw_fn(&mut frame);
 
         let primitive = {
             let geometry = frame.into_geometry();
 
-            Arc::new(geometry.into_primitive())
+            Arc::new(geometry.into_primitive().flatten())
         };
 
         *self.state.borrow_mut() = State::Filled {
@@ -1,3 +1,4 @@
+use iced_native::mouse;
 use iced_native::{Point, Rectangle};
 
 /// The mouse cursor state.
@@ -8,23 +9,21 @@ pub enum Cursor {
 
     /// The cursor is currently unavailable (i.e. out of bounds or busy).
     Unavailable,
+
+    /// The mouse is captured by the [`Canvas`], so the cursor is available
+    /// even out of its bounds.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    Captured(Point),
 }
 
 impl Cursor {
-    // TODO: Remove this once this type is used in `iced_native` to encode
-    // proper cursor availability
-    pub(crate) fn from_window_position(position: Point) -> Self {
-        if position.x < 0.0 || position.y < 0.0 {
-            Cursor::Unavailable
-        } else {
-            Cursor::Available(position)
-        }
-    }
-
     /// Returns the absolute position of the [`Cursor`], if available.
     pub fn position(&self) -> Option<Point> {
         match self {
-            Cursor::Available(position) => Some(*position),
+            Cursor::Available(position) | Cursor::Captured(position) => {
+                Some(*position)
+            }
             Cursor::Unavailable => None,
         }
     }
@@ -33,9 +32,10 @@ impl Cursor {
     /// if available.
     ///
     /// If the [`Cursor`] is not over the provided bounds, this method will
-    /// return `None`.
+    /// return `None`, unless the mouse is [`Cursor::Captured`]. Then, the
+    /// relative position may be negative.
     pub fn position_in(&self, bounds: &Rectangle) -> Option<Point> {
-        if self.is_over(bounds) {
+        if self.is_over(bounds) || self.is_captured() {
             self.position_from(bounds.position())
         } else {
             None
@@ -46,7 +46,7 @@ impl Cursor {
     /// if available.
     pub fn position_from(&self, origin: Point) -> Option<Point> {
         match self {
-            Cursor::Available(position) => {
+            Cursor::Available(position) | Cursor::Captured(position) => {
                 Some(Point::new(position.x - origin.x, position.y - origin.y))
             }
             Cursor::Unavailable => None,
@@ -57,8 +57,46 @@ impl Cursor {
     /// or not.
     pub fn is_over(&self, bounds: &Rectangle) -> bool {
         match self {
-            Cursor::Available(position) => bounds.contains(*position),
+            Cursor::Available(position) | Cursor::Captured(position) => {
+                bounds.contains(*position)
+            }
             Cursor::Unavailable => false,
         }
     }
+
+    /// Returns whether the mouse is captured by the [`Canvas`].
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    pub fn is_captured(&self) -> bool {
+        matches!(self, Cursor::Captured(_))
+    }
+}
+
+impl From<mouse::Cursor> for Cursor {
+    fn from(cursor: mouse::Cursor) -> Self {
+        match cursor {
+            mouse::Cursor::Available(position) => Cursor::Available(position),
+            mouse::Cursor::Unavailable => Cursor::Unavailable,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Size;
+
+    #[test]
+    fn captured_cursor_is_available_out_of_bounds() {
+        let bounds =
+            Rectangle::new(Point::new(10.0, 10.0), Size::new(50.0, 50.0));
+        let position = Point::new(5.0, 70.0);
+
+        assert_eq!(Cursor::Available(position).position_in(&bounds), None);
+        assert_eq!(
+            Cursor::Captured(position).position_in(&bounds),
+            Some(Point::new(-5.0, 60.0))
+        );
+        assert!(!Cursor::Captured(position).is_over(&bounds));
+    }
 }
@@ -7,7 +7,7 @@ pub use iced_native::event::Status;
 /// A [`Canvas`] event.
 ///
 /// [`Canvas`]: crate::widget::Canvas
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Event {
     /// A mouse event.
     Mouse(mouse::Event),
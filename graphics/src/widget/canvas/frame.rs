@@ -1,4 +1,4 @@
-use iced_native::{Point, Rectangle, Size, Vector};
+use iced_native::{Point, Rectangle, Size, Vector, WritingMode};
 
 use crate::{
     canvas::{Fill, Geometry, Path, Stroke, Text},
@@ -227,6 +227,9 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            letter_spacing: 0.0,
+            writing_mode: WritingMode::Horizontal,
+            background: None,
         });
     }
 
@@ -293,6 +296,14 @@ impl Frame {
             primitives: self.primitives,
         })
     }
+
+    /// Produces the triangle mesh of the [`Frame`], leaving out its text.
+    pub(crate) fn into_mesh(self) -> triangle::Mesh2D {
+        triangle::Mesh2D {
+            vertices: self.buffers.vertices,
+            indices: self.buffers.indices,
+        }
+    }
 }
 
 struct FillVertex([f32; 4]);
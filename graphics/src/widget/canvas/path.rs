@@ -7,7 +7,10 @@ mod builder;
 pub use arc::Arc;
 pub use builder::Builder;
 
-use iced_native::{Point, Size};
+use crate::canvas::{Fill, Frame, Stroke};
+use crate::triangle;
+
+use iced_native::{Point, Size, Vector};
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -51,11 +54,45 @@ impl Path {
         Self::new(|p| p.circle(center, radius))
     }
 
+    /// Tessellates the [`Path`] into a triangle mesh, filling and stroking it
+    /// with the given styles.
+    ///
+    /// The stroke is drawn on top of the fill.
+    pub fn tessellate(
+        &self,
+        fill: Option<Fill>,
+        stroke: Option<Stroke>,
+    ) -> triangle::Mesh2D {
+        let mut frame = Frame::new(Size::ZERO);
+
+        if let Some(fill) = fill {
+            frame.fill(self, fill);
+        }
+
+        if let Some(stroke) = stroke {
+            frame.stroke(self, stroke);
+        }
+
+        frame.into_mesh()
+    }
+
     #[inline]
     pub(crate) fn raw(&self) -> &lyon::path::Path {
         &self.raw
     }
 
+    #[inline]
+    pub(crate) fn translated(&self, translation: Vector) -> Path {
+        if translation == Vector::new(0.0, 0.0) {
+            return self.clone();
+        }
+
+        self.transformed(&lyon::math::Transform::translation(
+            translation.x,
+            translation.y,
+        ))
+    }
+
     #[inline]
     pub(crate) fn transformed(
         &self,
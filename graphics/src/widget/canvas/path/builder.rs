@@ -39,25 +39,59 @@ impl Builder {
         self.ellipse(arc.into());
     }
 
-    /// Adds a circular arc to the [`Path`] with the given control points and
-    /// radius.
+    /// Adds a circular arc to the [`Path`] with the given control point, end
+    /// point, and radius.
+    ///
+    /// The arc is tangent to the line from the last point in the [`Path`] to
+    /// the `control` point, and to the line from the `control` point to the
+    /// `end` point. The last point is connected to the start of the arc by a
+    /// straight line, if necessary.
     ///
-    /// The arc is connected to the previous point by a straight line, if
-    /// necessary.
-    pub fn arc_to(&mut self, a: Point, b: Point, radius: f32) {
+    /// If the points are collinear or the radius is zero, a straight line to
+    /// the `control` point is added instead.
+    pub fn arc_to(&mut self, control: Point, end: Point, radius: f32) {
         use lyon::{math, path};
 
-        let a = math::Point::new(a.x, a.y);
+        let from = self.raw.current_position();
+        let control = math::Point::new(control.x, control.y);
+        let end = math::Point::new(end.x, end.y);
+
+        let to_from = from - control;
+        let to_end = end - control;
+        let cross = to_from.cross(to_end);
+
+        if radius <= 0.0
+            || to_from.square_length() == 0.0
+            || to_end.square_length() == 0.0
+            || cross.abs() <= f32::EPSILON
+        {
+            let _ = self.raw.line_to(control);
+            return;
+        }
+
+        let to_from = to_from.normalize();
+        let to_end = to_end.normalize();
+
+        // The tangent points are as far from the control point as the radius
+        // divided by the tangent of half the angle between both lines
+        let half_angle = to_from.angle_to(to_end).radians.abs() / 2.0;
+        let distance = radius / half_angle.tan();
 
-        if self.raw.current_position() != a {
-            let _ = self.raw.line_to(a);
+        let arc_start = control + to_from * distance;
+        let arc_end = control + to_end * distance;
+
+        if from != arc_start {
+            let _ = self.raw.line_to(arc_start);
         }
 
         let _ = self.raw.arc_to(
             math::Vector::new(radius, radius),
             math::Angle::radians(0.0),
-            path::ArcFlags::default(),
-            math::Point::new(b.x, b.y),
+            path::ArcFlags {
+                large_arc: false,
+                sweep: cross < 0.0,
+            },
+            arc_end,
         );
     }
 
@@ -151,3 +185,39 @@ impl Builder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_to_is_tangent_to_both_lines() {
+        let mut builder = Builder::new();
+
+        builder.move_to(Point::new(0.0, 0.0));
+        builder.arc_to(Point::new(10.0, 0.0), Point::new(10.0, 10.0), 5.0);
+
+        let path = builder.build();
+        let mut events = path.raw().iter();
+
+        let _ = events.next();
+
+        match events.next() {
+            Some(lyon::path::Event::Line { to, .. }) => {
+                assert_eq!(to, lyon::math::Point::new(5.0, 0.0));
+            }
+            event => panic!("Expected a line, found {:?}", event),
+        }
+
+        let last = events
+            .filter_map(|event| match event {
+                lyon::path::Event::End { last, .. } => Some(last),
+                _ => None,
+            })
+            .next()
+            .expect("Path should end");
+
+        assert!((last.x - 10.0).abs() < 1e-4);
+        assert!((last.y - 5.0).abs() < 1e-4);
+    }
+}
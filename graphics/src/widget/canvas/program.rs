@@ -9,6 +9,14 @@ use iced_native::{mouse, Rectangle};
 ///
 /// [`Canvas`]: crate::widget::Canvas
 pub trait Program<Message> {
+    /// The interaction state of the [`Program`], like an ongoing drag.
+    ///
+    /// It is kept by the [`Canvas`] across events and redraws, instead of in
+    /// your application. Use `()` if the [`Program`] does not need any.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    type State: Default;
+
     /// Updates the state of the [`Program`].
     ///
     /// When a [`Program`] is used in a [`Canvas`], the runtime will call this
@@ -17,11 +25,16 @@ pub trait Program<Message> {
     /// This method can optionally return a `Message` to notify an application
     /// of any meaningful interactions.
     ///
+    /// Capturing an [`Event`] where a mouse button is pressed captures the
+    /// mouse until the button is released: the [`Cursor`] stays available
+    /// even out of the bounds of the [`Canvas`].
+    ///
     /// By default, this method does and returns nothing.
     ///
     /// [`Canvas`]: crate::widget::Canvas
     fn update(
         &mut self,
+        _state: &mut Self::State,
         _event: Event,
         _bounds: Rectangle,
         _cursor: Cursor,
@@ -36,7 +49,12 @@ pub trait Program<Message> {
     ///
     /// [`Frame`]: crate::widget::canvas::Frame
     /// [`Cache`]: crate::widget::canvas::Cache
-    fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry>;
+    fn draw(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry>;
 
     /// Returns the current mouse interaction of the [`Program`].
     ///
@@ -46,35 +64,78 @@ pub trait Program<Message> {
     /// [`Canvas`]: crate::widget::Canvas
     fn mouse_interaction(
         &self,
+        _state: &Self::State,
         _bounds: Rectangle,
         _cursor: Cursor,
     ) -> mouse::Interaction {
         mouse::Interaction::default()
     }
+
+    /// Returns a hash of everything the drawing of the [`Program`] depends
+    /// on, if it can be computed.
+    ///
+    /// The key should include any part of the [`State`] and the [`Cursor`]
+    /// that [`draw`] uses, like the hovered item of a chart. When the key and
+    /// the bounds of the [`Canvas`] match the ones of the previous frame,
+    /// [`draw`] is skipped and the previous [`Geometry`] is reused. Keep the [`State`] of the [`Canvas`] in your application with
+    /// [`Canvas::state`] for the geometry to survive your views.
+    ///
+    /// By default, it returns `None` and the [`Program`] is always drawn.
+    ///
+    /// [`Canvas`]: crate::widget::Canvas
+    /// [`Canvas::state`]: crate::widget::Canvas::state
+    /// [`State`]: crate::widget::canvas::State
+    /// [`draw`]: Self::draw
+    fn cache_key(
+        &self,
+        _state: &Self::State,
+        _bounds: Rectangle,
+        _cursor: Cursor,
+    ) -> Option<u64> {
+        None
+    }
 }
 
 impl<T, Message> Program<Message> for &mut T
 where
     T: Program<Message>,
 {
+    type State = T::State;
+
     fn update(
         &mut self,
+        state: &mut Self::State,
         event: Event,
         bounds: Rectangle,
         cursor: Cursor,
     ) -> (event::Status, Option<Message>) {
-        T::update(self, event, bounds, cursor)
+        T::update(self, state, event, bounds, cursor)
     }
 
-    fn draw(&self, bounds: Rectangle, cursor: Cursor) -> Vec<Geometry> {
-        T::draw(self, bounds, cursor)
+    fn draw(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Vec<Geometry> {
+        T::draw(self, state, bounds, cursor)
     }
 
     fn mouse_interaction(
         &self,
+        state: &Self::State,
         bounds: Rectangle,
         cursor: Cursor,
     ) -> mouse::Interaction {
-        T::mouse_interaction(self, bounds, cursor)
+        T::mouse_interaction(self, state, bounds, cursor)
+    }
+
+    fn cache_key(
+        &self,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: Cursor,
+    ) -> Option<u64> {
+        T::cache_key(self, state, bounds, cursor)
     }
 }
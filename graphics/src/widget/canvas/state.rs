@@ -0,0 +1,93 @@
+use crate::Primitive;
+
+use iced_native::Size;
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::Arc;
+
+/// The state of a [`Canvas`], kept across the views of your application.
+///
+/// It stores the [`Program::State`] of its [`Program`], whether the mouse
+/// is captured and the last drawn geometry of a program with a
+/// [`Program::cache_key`].
+///
+/// [`Canvas`]: crate::widget::Canvas
+/// [`Program`]: crate::widget::canvas::Program
+/// [`Program::State`]: crate::widget::canvas::Program::State
+/// [`Program::cache_key`]: crate::widget::canvas::Program::cache_key
+#[derive(Default)]
+pub struct State<S> {
+    pub(super) program: S,
+    pub(super) is_capturing: bool,
+    pub(super) cache: RefCell<Option<Cached>>,
+}
+
+/// The geometry drawn by a program for some cache key and bounds.
+#[derive(Debug)]
+pub(super) struct Cached {
+    pub key: u64,
+    pub bounds: Size,
+    pub primitive: Arc<Primitive<()>>,
+}
+
+impl<S: Default> State<S> {
+    /// Creates a new [`State`] with the default state of a program.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<S> State<S> {
+    /// Returns the state of the program.
+    pub fn program(&self) -> &S {
+        &self.program
+    }
+
+    /// Returns a mutable reference to the state of the program.
+    pub fn program_mut(&mut self) -> &mut S {
+        &mut self.program
+    }
+
+    /// Returns whether the mouse is captured, until its buttons are released.
+    pub fn is_capturing(&self) -> bool {
+        self.is_capturing
+    }
+}
+
+impl<S> fmt::Debug for State<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("State")
+            .field("is_capturing", &self.is_capturing)
+            .finish()
+    }
+}
+
+/// The [`State`] of a [`Canvas`], either owned or kept by the application.
+///
+/// [`Canvas`]: crate::widget::Canvas
+pub(super) enum Storage<'a, S> {
+    Owned(State<S>),
+    Borrowed(&'a mut State<S>),
+}
+
+impl<'a, S> Storage<'a, S> {
+    pub fn get(&self) -> &State<S> {
+        match self {
+            Storage::Owned(state) => state,
+            Storage::Borrowed(state) => state,
+        }
+    }
+
+    pub fn get_mut(&mut self) -> &mut State<S> {
+        match self {
+            Storage::Owned(state) => state,
+            Storage::Borrowed(state) => state,
+        }
+    }
+}
+
+impl<'a, S> fmt::Debug for Storage<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
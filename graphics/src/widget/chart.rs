@@ -0,0 +1,372 @@
+//! Plot data points as lines or bars.
+use crate::backend::{self, Backend};
+use crate::triangle::{Mesh2D, Vertex2D};
+use crate::{Primitive, Renderer};
+use iced_native::chart;
+use iced_native::text;
+use iced_native::{
+    alignment, mouse, Background, BorderAlignment, BorderStyle, BorderWidth,
+    Color, Font, Point, Rectangle, Size, Vector, WritingMode,
+};
+
+pub use iced_native::chart::{Axis, Bar, Series};
+pub use iced_style::chart::{Style, StyleSheet};
+
+/// A chart plotting some series of data points as lines.
+///
+/// This is an alias of an `iced_native` line chart with an
+/// `iced_wgpu::Renderer`.
+pub type LineChart<Backend> = iced_native::LineChart<Renderer<Backend>>;
+
+/// A chart displaying discrete values as vertical bars.
+///
+/// This is an alias of an `iced_native` bar chart with an
+/// `iced_wgpu::Renderer`.
+pub type BarChart<Message, Backend> =
+    iced_native::BarChart<Message, Renderer<Backend>>;
+
+const LABEL_SPACING: f32 = 4.0;
+const TOOLTIP_PADDING: f32 = 4.0;
+
+impl<B> chart::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        plot: Rectangle,
+        series: &[Series],
+        x_axis: &Axis,
+        y_axis: &Axis,
+        label_size: u16,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let to_x = |x| plot.x + x_axis.normalize(x) * plot.width;
+        let to_y = |y| plot.y + (1.0 - y_axis.normalize(y)) * plot.height;
+
+        let mut primitives = Vec::new();
+
+        if let Some(background) = style.background {
+            primitives.push(quad(bounds, background));
+        }
+
+        let label =
+            |content, x, y, horizontal_alignment, vertical_alignment| {
+                Primitive::Text {
+                    content,
+                    bounds: Rectangle {
+                        x,
+                        y,
+                        width: bounds.width,
+                        height: f32::from(label_size),
+                    },
+                    color: style.label_color,
+                    size: f32::from(label_size),
+                    font: Font::Default,
+                    horizontal_alignment,
+                    vertical_alignment,
+                    letter_spacing: 0.0,
+                    writing_mode: WritingMode::Horizontal,
+                    background: None,
+                }
+            };
+
+        // Grid lines are one pixel wide stripes, with their labels
+        for tick in &x_axis.ticks {
+            let x = to_x(*tick);
+
+            primitives.push(quad(
+                Rectangle {
+                    x: x.round(),
+                    y: plot.y,
+                    width: 1.0,
+                    height: plot.height,
+                },
+                Background::Color(style.grid_color),
+            ));
+
+            primitives.push(label(
+                x_axis.label(*tick),
+                x,
+                plot.y + plot.height + LABEL_SPACING,
+                alignment::Horizontal::Center,
+                alignment::Vertical::Top,
+            ));
+        }
+
+        for tick in &y_axis.ticks {
+            let y = to_y(*tick);
+
+            primitives.push(quad(
+                Rectangle {
+                    x: plot.x,
+                    y: y.round(),
+                    width: plot.width,
+                    height: 1.0,
+                },
+                Background::Color(style.grid_color),
+            ));
+
+            primitives.push(label(
+                y_axis.label(*tick),
+                plot.x - LABEL_SPACING,
+                y,
+                alignment::Horizontal::Right,
+                alignment::Vertical::Center,
+            ));
+        }
+
+        // The lines are tessellated relative to the plot, which clips them
+        for series in series {
+            let points: Vec<_> = series
+                .points
+                .iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(|(x, y)| Point::new(to_x(*x) - plot.x, to_y(*y) - plot.y))
+                .collect();
+
+            let buffers =
+                tessellate(&points, series.stroke_width, series.color);
+
+            if buffers.indices.is_empty() {
+                continue;
+            }
+
+            primitives.push(Primitive::Translate {
+                translation: Vector::new(plot.x, plot.y),
+                content: Box::new(Primitive::Mesh2D {
+                    buffers,
+                    size: plot.size(),
+                }),
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
+    }
+
+    fn draw_bars(
+        &mut self,
+        bounds: Rectangle,
+        plot: Rectangle,
+        bars: &[Bar],
+        axis: &Axis,
+        hovered: Option<usize>,
+        is_clickable: bool,
+        color: Option<Color>,
+        label_size: u16,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+        let size = f32::from(label_size);
+
+        let label =
+            |content, x, y, horizontal_alignment, vertical_alignment| {
+                Primitive::Text {
+                    content,
+                    bounds: Rectangle {
+                        x,
+                        y,
+                        width: bounds.width,
+                        height: size,
+                    },
+                    color: style.label_color,
+                    size,
+                    font: Font::Default,
+                    horizontal_alignment,
+                    vertical_alignment,
+                    letter_spacing: 0.0,
+                    writing_mode: WritingMode::Horizontal,
+                    background: None,
+                }
+            };
+
+        let mut primitives = Vec::new();
+
+        if let Some(background) = style.background {
+            primitives.push(quad(bounds, background));
+        }
+
+        for tick in &axis.ticks {
+            let y = plot.y + (1.0 - axis.normalize(*tick)) * plot.height;
+
+            primitives.push(quad(
+                Rectangle {
+                    x: plot.x,
+                    y: y.round(),
+                    width: plot.width,
+                    height: 1.0,
+                },
+                Background::Color(style.grid_color),
+            ));
+
+            primitives.push(label(
+                axis.label(*tick),
+                plot.x - LABEL_SPACING,
+                y,
+                alignment::Horizontal::Right,
+                alignment::Vertical::Center,
+            ));
+        }
+
+        for (index, bar) in bars.iter().enumerate() {
+            let bar_color =
+                color.unwrap_or_else(|| style_sheet.bar_color(index));
+
+            primitives.push(quad(bar.bounds, Background::Color(bar_color)));
+
+            primitives.push(label(
+                bar.label.clone(),
+                bar.bounds.center_x(),
+                plot.y + plot.height + LABEL_SPACING,
+                alignment::Horizontal::Center,
+                alignment::Vertical::Top,
+            ));
+        }
+
+        // The value of the hovered bar is displayed right above it
+        if let Some(bar) = hovered.and_then(|index| bars.get(index)) {
+            let content = bar.value.to_string();
+
+            let (width, height) = text::Renderer::measure(
+                self,
+                &content,
+                label_size,
+                Font::Default,
+                Size::INFINITY,
+            );
+
+            let tooltip = Rectangle {
+                x: bar.bounds.center_x() - width / 2.0 - TOOLTIP_PADDING,
+                y: bar.bounds.y
+                    - height
+                    - TOOLTIP_PADDING * 2.0
+                    - LABEL_SPACING,
+                width: width + TOOLTIP_PADDING * 2.0,
+                height: height + TOOLTIP_PADDING * 2.0,
+            };
+
+            primitives.push(quad(tooltip, style.tooltip_background));
+            primitives.push(label(
+                content,
+                tooltip.center_x(),
+                tooltip.center_y(),
+                alignment::Horizontal::Center,
+                alignment::Vertical::Center,
+            ));
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_clickable && hovered.is_some() {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
+
+fn quad<B: Backend>(bounds: Rectangle, background: Background) -> Primitive<B> {
+    Primitive::Quad {
+        bounds,
+        background,
+        border_radius: 0.0,
+        border_width: BorderWidth::ZERO,
+        border_color: Color::TRANSPARENT,
+        border_style: BorderStyle::Solid,
+        border_alignment: BorderAlignment::Inside,
+        border_offset: 0.0,
+        fill: true,
+    }
+}
+
+/// Tessellates a polyline into a quad of two triangles per segment.
+///
+/// Segments overlap at their joints, which hides the gaps between them for
+/// the usual stroke widths.
+fn tessellate(points: &[Point], width: f32, color: Color) -> Mesh2D {
+    let color = color.into_linear();
+    let half_width = width / 2.0;
+
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    for segment in points.windows(2) {
+        let (from, to) = (segment[0], segment[1]);
+        let direction = to - from;
+        let length =
+            (direction.x * direction.x + direction.y * direction.y).sqrt();
+
+        if length == 0.0 {
+            continue;
+        }
+
+        // Extending both ends by half the width covers the joints
+        let along = direction * (half_width / length);
+        let normal = Vector::new(-along.y, along.x);
+
+        let start = vertices.len() as u32;
+
+        for position in [
+            from - along + normal,
+            from - along - normal,
+            to + along + normal,
+            to + along - normal,
+        ]
+        .iter()
+        {
+            vertices.push(Vertex2D {
+                position: [position.x, position.y],
+                color,
+            });
+        }
+
+        indices.extend_from_slice(&[
+            start,
+            start + 1,
+            start + 2,
+            start + 1,
+            start + 3,
+            start + 2,
+        ]);
+    }
+
+    Mesh2D { vertices, indices }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_segment_is_tessellated_into_a_quad() {
+        let points = [
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+        ];
+
+        let mesh = tessellate(&points, 2.0, Color::BLACK);
+
+        // The degenerate segment in the middle is skipped
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.indices.len(), 12);
+
+        let positions: Vec<_> =
+            mesh.vertices[..4].iter().map(|v| v.position).collect();
+
+        assert_eq!(
+            positions,
+            vec![[-1.0, 1.0], [-1.0, -1.0], [11.0, 1.0], [11.0, -1.0]]
+        );
+    }
+}
@@ -1,11 +1,15 @@
 //! Show toggle controls using checkboxes.
 use crate::alignment;
 use crate::backend::{self, Backend};
-use crate::{Primitive, Rectangle, Renderer};
+use crate::{
+    Background, BorderAlignment, BorderStyle, BorderWidth, Font, Primitive,
+    Rectangle, Renderer, WritingMode,
+};
 
 use iced_native::checkbox;
 use iced_native::mouse;
 
+pub use iced_native::checkbox::{Icon, LabelPosition};
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
@@ -27,30 +31,62 @@ where
         &mut self,
         bounds: Rectangle,
         is_checked: bool,
+        is_indeterminate: bool,
         is_mouse_over: bool,
+        icon: Option<&Icon<Font>>,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
+        let is_filled = is_checked || is_indeterminate;
+
         let style = if is_mouse_over {
-            style_sheet.hovered(is_checked)
+            style_sheet.hovered(is_filled)
         } else {
-            style_sheet.active(is_checked)
+            style_sheet.active(is_filled)
         };
 
         let checkbox = Primitive::Quad {
             bounds,
             background: style.background,
             border_radius: style.border_radius,
-            border_width: style.border_width,
+            border_width: style.border_width.into(),
             border_color: style.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
         };
 
         (
             Primitive::Group {
-                primitives: if is_checked {
+                primitives: if is_indeterminate {
+                    let dash = Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + bounds.width * 0.25,
+                            y: bounds.center_y() - bounds.height * 0.06,
+                            width: bounds.width * 0.5,
+                            height: bounds.height * 0.12,
+                        },
+                        background: Background::Color(style.checkmark_color),
+                        border_radius: bounds.height * 0.06,
+                        border_width: BorderWidth::ZERO,
+                        border_color: style.checkmark_color,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
+                    };
+
+                    vec![checkbox, dash, label]
+                } else if is_checked {
+                    let (font, code_point) = match icon {
+                        Some(icon) => (icon.font, icon.code_point),
+                        None => (B::ICON_FONT, B::CHECKMARK_ICON),
+                    };
+
                     let check = Primitive::Text {
-                        content: B::CHECKMARK_ICON.to_string(),
-                        font: B::ICON_FONT,
+                        content: code_point.to_string(),
+                        font,
                         size: bounds.height * 0.7,
                         bounds: Rectangle {
                             x: bounds.center_x(),
@@ -60,6 +96,9 @@ where
                         color: style.checkmark_color,
                         horizontal_alignment: alignment::Horizontal::Center,
                         vertical_alignment: alignment::Vertical::Center,
+                        letter_spacing: 0.0,
+                        writing_mode: WritingMode::Horizontal,
+                        background: None,
                     };
 
                     vec![checkbox, check, label]
@@ -1,7 +1,7 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::column;
 use iced_native::mouse;
-use iced_native::{Element, Layout, Point, Rectangle};
+use iced_native::{Element, Layout, Rectangle};
 
 /// A container that distributes its contents vertically.
 pub type Column<'a, Message, Backend> =
@@ -16,7 +16,7 @@ where
         defaults: &Self::Defaults,
         content: &[Element<'_, Message, Self>],
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output {
         let mut mouse_interaction = mouse::Interaction::default();
@@ -27,13 +27,8 @@ where
                     .iter()
                     .zip(layout.children())
                     .map(|(child, layout)| {
-                        let (primitive, new_mouse_interaction) = child.draw(
-                            self,
-                            defaults,
-                            layout,
-                            cursor_position,
-                            viewport,
-                        );
+                        let (primitive, new_mouse_interaction) = child
+                            .draw(self, defaults, layout, cursor, viewport);
 
                         if new_mouse_interaction > mouse_interaction {
                             mouse_interaction = new_mouse_interaction;
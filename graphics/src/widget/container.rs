@@ -2,7 +2,9 @@
 use crate::container;
 use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
-use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
+use iced_native::{
+    mouse, Background, BorderAlignment, Color, Element, Layout, Rectangle,
+};
 
 pub use iced_style::container::{Style, StyleSheet};
 
@@ -23,8 +25,10 @@ where
         &mut self,
         defaults: &Defaults,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
+        backdrop_blur: f32,
+        backdrop_tint: Color,
         style_sheet: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -37,18 +41,27 @@ where
             },
         };
 
-        let (content, mouse_interaction) = content.draw(
-            self,
-            &defaults,
-            content_layout,
-            cursor_position,
-            viewport,
-        );
+        let (content, mouse_interaction) =
+            content.draw(self, &defaults, content_layout, cursor, viewport);
+
+        let content = if let Some(background) = background(bounds, &style) {
+            Primitive::Group {
+                primitives: vec![background, content],
+            }
+        } else {
+            content
+        };
+
+        if backdrop_blur > 0.0 {
+            let backdrop = Primitive::Backdrop {
+                bounds,
+                blur_radius: backdrop_blur,
+                tint: backdrop_tint,
+            };
 
-        if let Some(background) = background(bounds, &style) {
             (
                 Primitive::Group {
-                    primitives: vec![background, content],
+                    primitives: vec![backdrop, content],
                 },
                 mouse_interaction,
             )
@@ -62,17 +75,36 @@ pub(crate) fn background<B: Backend>(
     bounds: Rectangle,
     style: &container::Style,
 ) -> Option<Primitive<B>> {
-    if style.background.is_some() || style.border_width > 0.0 {
+    let quad = if style.background.is_some() || style.border_width > 0.0 {
         Some(Primitive::Quad {
             bounds,
             background: style
                 .background
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
             border_radius: style.border_radius,
-            border_width: style.border_width,
+            border_width: style.border_width.into(),
             border_color: style.border_color,
+            border_style: style.border_style,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: style.background.is_some(),
         })
     } else {
         None
+    };
+
+    let shadow = style.shadow.map(|shadow| Primitive::Shadow {
+        bounds,
+        border_radius: style.border_radius,
+        color: shadow.color,
+        offset: shadow.offset,
+        blur_radius: shadow.blur_radius,
+    });
+
+    match (shadow, quad) {
+        (Some(shadow), Some(quad)) => Some(Primitive::Group {
+            primitives: vec![shadow, quad],
+        }),
+        (shadow, quad) => shadow.or(quad),
     }
 }
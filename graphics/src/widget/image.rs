@@ -6,7 +6,7 @@ use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::image;
 use iced_native::mouse;
-use iced_native::Layout;
+use iced_native::{Layout, Rectangle, Vector};
 
 pub use iced_native::image::{Handle, Image, Viewer};
 
@@ -21,12 +21,21 @@ where
     fn draw(
         &mut self,
         handle: image::Handle,
+        bounds: Rectangle,
         layout: Layout<'_>,
     ) -> Self::Output {
+        let clip = layout.bounds();
+        let primitive = Primitive::Image { handle, bounds };
+
         (
-            Primitive::Image {
-                handle,
-                bounds: layout.bounds(),
+            if bounds.width > clip.width || bounds.height > clip.height {
+                Primitive::Clip {
+                    bounds: clip,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(primitive),
+                }
+            } else {
+                primitive
             },
             mouse::Interaction::default(),
         )
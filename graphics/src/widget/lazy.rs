@@ -0,0 +1,104 @@
+//! Build some content only when its dependency changes.
+use crate::{Backend, Defaults, Primitive, Renderer, Theme};
+use iced_native::lazy;
+use iced_native::mouse;
+
+use std::sync::Arc;
+
+pub use iced_native::lazy::State;
+
+/// An element that builds its content only when its dependency changes.
+///
+/// This is an alias of an `iced_native` lazy with an `iced_wgpu::Renderer`.
+pub type Lazy<'a, Dependency, Message, Backend> =
+    iced_native::Lazy<'a, Dependency, Message, Renderer<Backend>>;
+
+impl<B> lazy::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Cached = (Arc<Primitive<B>>, mouse::Interaction);
+    type Key = (Defaults, Theme);
+
+    fn cache_key(&self, defaults: &Defaults) -> Self::Key {
+        (*defaults, *self.theme())
+    }
+
+    fn cache(
+        &mut self,
+        (primitive, mouse_interaction): Self::Output,
+    ) -> Self::Cached {
+        (Arc::new(primitive), mouse_interaction)
+    }
+
+    fn draw_cached(
+        &mut self,
+        (primitive, mouse_interaction): &Self::Cached,
+    ) -> Self::Output {
+        (
+            Primitive::Cached {
+                cache: primitive.clone(),
+            },
+            mouse_interaction.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::layout::{self, Layout};
+    use iced_native::{Element, Length, Size, Space};
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    fn draw(
+        renderer: &mut Renderer<Null>,
+        state: &mut State<(), (), Renderer<Null>>,
+    ) -> Arc<Primitive<Null>> {
+        let lazy: Element<'_, (), Renderer<Null>> =
+            Lazy::new(state, (), |_| {
+                Space::new(Length::Units(40), Length::Units(20)).into()
+            })
+            .into();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0));
+        let node = lazy.layout(renderer, &limits);
+        let viewport = node.bounds();
+
+        let (primitive, _) = lazy.draw(
+            renderer,
+            &Default::default(),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &viewport,
+        );
+
+        match primitive {
+            Primitive::Cached { cache } => cache,
+            _ => panic!("Cached primitive"),
+        }
+    }
+
+    #[test]
+    fn content_is_drawn_again_when_the_theme_changes() {
+        let mut renderer = Renderer::new(Null);
+        let mut state = State::new();
+
+        let first = draw(&mut renderer, &mut state);
+        let second = draw(&mut renderer, &mut state);
+
+        assert!(Arc::ptr_eq(&first, &second));
+
+        renderer.set_theme(Theme::DARK);
+
+        let third = draw(&mut renderer, &mut state);
+
+        assert!(!Arc::ptr_eq(&second, &third));
+    }
+}
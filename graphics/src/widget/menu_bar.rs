@@ -0,0 +1,268 @@
+//! Show a bar of dropdown menus, like File, Edit, and View.
+use crate::alignment;
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+
+use iced_native::menu_bar::{Menu, MenuItem};
+use iced_native::{
+    mouse, Background, BorderAlignment, BorderStyle, BorderWidth, Color, Font,
+    Layout, Padding, Rectangle, WritingMode,
+};
+
+pub use iced_native::menu_bar::State;
+pub use iced_style::menu_bar::{Style, StyleSheet};
+
+/// A horizontal bar of menus, each one opening a dropdown of menu items.
+///
+/// This is an alias of an `iced_native` menu bar with an
+/// `iced_graphics::Renderer`.
+pub type MenuBar<'a, Message, Backend> =
+    iced_native::MenuBar<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::menu_bar::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_PADDING: Padding = Padding {
+        top: 5,
+        right: 10,
+        bottom: 5,
+        left: 10,
+    };
+
+    const SEPARATOR_HEIGHT: u16 = 9;
+
+    const HINT_SPACING: u16 = 30;
+
+    fn draw<Message>(
+        &mut self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        menus: &[Menu<Message>],
+        focused: Option<usize>,
+        is_open: bool,
+        padding: Padding,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.active();
+        let bounds = layout.bounds();
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: 0.0,
+            border_width: style.border_width.into(),
+            border_color: style.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        }];
+
+        let mut is_mouse_over_entry = false;
+
+        for (index, (menu, entry)) in
+            menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = entry.bounds();
+            let is_mouse_over = cursor.is_over(bounds);
+
+            is_mouse_over_entry = is_mouse_over_entry || is_mouse_over;
+
+            // A focused menu is only outlined until it opens
+            if focused == Some(index) {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: style.selected_background,
+                    border_radius: 0.0,
+                    border_width: BorderWidth::new(if is_open {
+                        0.0
+                    } else {
+                        1.0
+                    }),
+                    border_color: match style.selected_background {
+                        Background::Color(color) => color,
+                        Background::Image { .. } | Background::Tiled { .. } => {
+                            Color::TRANSPARENT
+                        }
+                    },
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: is_open,
+                });
+            }
+
+            primitives.push(Primitive::Text {
+                content: menu.label().to_string(),
+                bounds: Rectangle {
+                    x: bounds.x + f32::from(padding.left),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size: f32::from(text_size),
+                font,
+                color: if is_open && focused == Some(index) {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Center,
+                letter_spacing: 0.0,
+                writing_mode: WritingMode::Horizontal,
+                background: None,
+            });
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_entry {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+
+    fn draw_menus<Message>(
+        &mut self,
+        cursor: mouse::Cursor,
+        menus: &[(Layout<'_>, &[MenuItem<Message>], Option<usize>)],
+        padding: Padding,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.active();
+        let menu_style = style_sheet.menu();
+
+        let mut primitives = Vec::new();
+        let mut is_mouse_over_item = false;
+
+        for (layout, items, highlighted) in menus {
+            primitives.push(Primitive::Quad {
+                bounds: layout.bounds(),
+                background: menu_style.background,
+                border_radius: 0.0,
+                border_width: menu_style.border_width.into(),
+                border_color: menu_style.border_color,
+                border_style: BorderStyle::Solid,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: 0.0,
+                fill: true,
+            });
+
+            for (index, (item, layout)) in
+                items.iter().zip(layout.children()).enumerate()
+            {
+                let bounds = layout.bounds();
+
+                if item.is_separator() {
+                    primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + f32::from(padding.left),
+                            y: bounds.center_y().floor(),
+                            width: bounds.width
+                                - f32::from(padding.horizontal()),
+                            height: 1.0,
+                        },
+                        background: Background::Color(style.separator_color),
+                        border_radius: 0.0,
+                        border_width: BorderWidth::ZERO,
+                        border_color: Color::TRANSPARENT,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
+                    });
+
+                    continue;
+                }
+
+                let is_highlighted = *highlighted == Some(index);
+
+                is_mouse_over_item = is_mouse_over_item
+                    || (item.is_enabled() && cursor.is_over(bounds));
+
+                if is_highlighted {
+                    primitives.push(Primitive::Quad {
+                        bounds,
+                        background: menu_style.selected_background,
+                        border_radius: 0.0,
+                        border_width: BorderWidth::ZERO,
+                        border_color: Color::TRANSPARENT,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
+                    });
+                }
+
+                let color = if !item.is_enabled() {
+                    style.disabled_text_color
+                } else if is_highlighted {
+                    menu_style.selected_text_color
+                } else {
+                    menu_style.text_color
+                };
+
+                primitives.push(Primitive::Text {
+                    content: item.label().to_string(),
+                    bounds: Rectangle {
+                        x: bounds.x + f32::from(padding.left),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size: f32::from(text_size),
+                    font,
+                    color,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Center,
+                    letter_spacing: 0.0,
+                    writing_mode: WritingMode::Horizontal,
+                    background: None,
+                });
+
+                let hint = if item.is_submenu() {
+                    Some(String::from(">"))
+                } else {
+                    item.shortcut_hint().map(str::to_string)
+                };
+
+                if let Some(hint) = hint {
+                    primitives.push(Primitive::Text {
+                        content: hint,
+                        bounds: Rectangle {
+                            x: bounds.x + bounds.width
+                                - f32::from(padding.right),
+                            y: bounds.center_y(),
+                            ..bounds
+                        },
+                        size: f32::from(text_size),
+                        font,
+                        color,
+                        horizontal_alignment: alignment::Horizontal::Right,
+                        vertical_alignment: alignment::Vertical::Center,
+                        letter_spacing: 0.0,
+                        writing_mode: WritingMode::Horizontal,
+                        background: None,
+                    });
+                }
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_item {
+                mouse::Interaction::Pointer
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
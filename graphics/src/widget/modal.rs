@@ -0,0 +1,56 @@
+//! Show a dialog on top of some content, blocking any interaction with it.
+use crate::modal;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{
+    mouse, BorderAlignment, BorderStyle, BorderWidth, Color, Element, Layout,
+    Rectangle,
+};
+
+pub use iced_style::modal::{Style, StyleSheet};
+
+/// An element that displays a dialog on top of its base content.
+///
+/// This is an alias of an `iced_native` modal with a default `Renderer`.
+pub type Modal<'a, Message, Backend> =
+    iced_native::Modal<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::modal::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn modal::StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        style_sheet: &Self::Style,
+        dialog: &Element<'_, Message, Self>,
+        dialog_layout: Layout<'_>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let dimming = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: 0.0,
+            border_width: BorderWidth::ZERO,
+            border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let (dialog, mouse_interaction) =
+            dialog.draw(self, defaults, dialog_layout, cursor, &bounds);
+
+        (
+            Primitive::Group {
+                primitives: vec![dimming, dialog],
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -0,0 +1,36 @@
+//! Edit a number by typing it or by stepping it up and down.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::number_input;
+
+pub use iced_native::number_input::State;
+
+/// A field holding a number, with buttons to decrement and increment it.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_graphics::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    iced_native::NumberInput<'a, T, Message, Renderer<Backend>>;
+
+impl<B> number_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(
+        &mut self,
+        (input, input_interaction): Self::Output,
+        (decrement, decrement_interaction): Self::Output,
+        (increment, increment_interaction): Self::Output,
+    ) -> Self::Output {
+        (
+            Primitive::Group {
+                primitives: vec![input, decrement, increment],
+            },
+            input_interaction
+                .max(decrement_interaction)
+                .max(increment_interaction),
+        )
+    }
+}
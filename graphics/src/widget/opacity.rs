@@ -0,0 +1,94 @@
+//! Fade some content.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::opacity;
+
+/// An element that draws its content with some opacity.
+///
+/// This is an alias of an `iced_native` opacity with an `iced_wgpu::Renderer`.
+pub type Opacity<'a, Message, Backend> =
+    iced_native::Opacity<'a, Message, Renderer<Backend>>;
+
+impl<B> opacity::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw(
+        &mut self,
+        (content, mouse_interaction): Self::Output,
+        opacity: f32,
+    ) -> Self::Output {
+        (
+            Primitive::Opacity {
+                opacity,
+                content: Box::new(content),
+            },
+            mouse_interaction,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProgressBar;
+    use iced_native::{layout, mouse, Element, Layout, Length, Size};
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    fn draw(
+        element: Element<'_, (), Renderer<Null>>,
+    ) -> (Primitive<Null>, String) {
+        let mut renderer = Renderer::new(Null);
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0));
+        let node = element.layout(&renderer, &limits);
+        let viewport = node.bounds();
+
+        let (primitive, _) = element.draw(
+            &mut renderer,
+            &Default::default(),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &viewport,
+        );
+
+        let debug = format!("{:?}", primitive);
+
+        (primitive, debug)
+    }
+
+    fn progress_bar() -> ProgressBar<Null> {
+        ProgressBar::new(0.0..=100.0, 50.0).width(Length::Units(100))
+    }
+
+    #[test]
+    fn content_is_wrapped_with_the_opacity() {
+        let (_, content) = draw(progress_bar().into());
+        let (primitive, _) =
+            draw(Opacity::<(), Null>::new(progress_bar(), 0.5).into());
+
+        match primitive {
+            Primitive::Opacity {
+                opacity,
+                content: faded,
+            } => {
+                assert_eq!(opacity, 0.5);
+                assert_eq!(format!("{:?}", faded), content);
+            }
+            primitive => panic!("Expected an opacity, got {:?}", primitive),
+        }
+    }
+
+    #[test]
+    fn full_opacity_passes_the_content_through() {
+        let (_, content) = draw(progress_bar().into());
+        let (_, opaque) =
+            draw(Opacity::<(), Null>::new(progress_bar(), 1.0).into());
+
+        assert_eq!(opaque, content);
+    }
+}
@@ -12,7 +12,10 @@ use crate::{Backend, Color, Primitive, Renderer};
 use iced_native::container;
 use iced_native::mouse;
 use iced_native::pane_grid;
-use iced_native::{Element, Layout, Point, Rectangle, Vector};
+use iced_native::{
+    BorderAlignment, BorderStyle, BorderWidth, Element, Layout, Point,
+    Rectangle, Vector,
+};
 
 pub use iced_native::pane_grid::{
     Axis, Configuration, Content, Direction, DragEvent, Node, Pane,
@@ -44,15 +47,13 @@ where
         resizing: Option<(Axis, Rectangle, bool)>,
         layout: Layout<'_>,
         style_sheet: &<Self as pane_grid::Renderer>::Style,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output {
-        let pane_cursor_position = if dragging.is_some() {
-            // TODO: Remove once cursor availability is encoded in the type
-            // system
-            Point::new(-1.0, -1.0)
+        let pane_cursor = if dragging.is_some() {
+            mouse::Cursor::Unavailable
         } else {
-            cursor_position
+            cursor
         };
 
         let mut mouse_interaction = mouse::Interaction::default();
@@ -63,13 +64,8 @@ where
             .zip(layout.children())
             .enumerate()
             .map(|(i, ((id, pane), layout))| {
-                let (primitive, new_mouse_interaction) = pane.draw(
-                    self,
-                    defaults,
-                    layout,
-                    pane_cursor_position,
-                    viewport,
-                );
+                let (primitive, new_mouse_interaction) =
+                    pane.draw(self, defaults, layout, pane_cursor, viewport);
 
                 if new_mouse_interaction > mouse_interaction {
                     mouse_interaction = new_mouse_interaction;
@@ -85,36 +81,38 @@ where
             })
             .collect();
 
-        let mut primitives = if let Some((index, layout, origin)) = dragged_pane
-        {
-            let pane = panes.remove(index);
-            let bounds = layout.bounds();
+        let mut primitives =
+            if let (Some((index, layout, origin)), Some(cursor_position)) =
+                (dragged_pane, cursor.position())
+            {
+                let pane = panes.remove(index);
+                let bounds = layout.bounds();
 
-            // TODO: Fix once proper layering is implemented.
-            // This is a pretty hacky way to achieve layering.
-            let clip = Primitive::Clip {
-                bounds: Rectangle {
-                    x: cursor_position.x - origin.x,
-                    y: cursor_position.y - origin.y,
-                    width: bounds.width + 0.5,
-                    height: bounds.height + 0.5,
-                },
-                offset: Vector::new(0, 0),
-                content: Box::new(Primitive::Translate {
-                    translation: Vector::new(
-                        cursor_position.x - bounds.x - origin.x,
-                        cursor_position.y - bounds.y - origin.y,
-                    ),
-                    content: Box::new(pane),
-                }),
-            };
+                // TODO: Fix once proper layering is implemented.
+                // This is a pretty hacky way to achieve layering.
+                let clip = Primitive::Clip {
+                    bounds: Rectangle {
+                        x: cursor_position.x - origin.x,
+                        y: cursor_position.y - origin.y,
+                        width: bounds.width + 0.5,
+                        height: bounds.height + 0.5,
+                    },
+                    offset: Vector::new(0, 0),
+                    content: Box::new(Primitive::Translate {
+                        translation: Vector::new(
+                            cursor_position.x - bounds.x - origin.x,
+                            cursor_position.y - bounds.y - origin.y,
+                        ),
+                        content: Box::new(pane),
+                    }),
+                };
 
-            panes.push(clip);
+                panes.push(clip);
 
-            panes
-        } else {
-            panes
-        };
+                panes
+            } else {
+                panes
+            };
 
         let (primitives, mouse_interaction) =
             if let Some((axis, split_region, is_picked)) = resizing {
@@ -148,8 +146,12 @@ where
                         },
                         background: highlight.color.into(),
                         border_radius: 0.0,
-                        border_width: 0.0,
+                        border_width: BorderWidth::ZERO,
                         border_color: Color::TRANSPARENT,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
                     });
                 }
 
@@ -185,27 +187,30 @@ where
         style_sheet: &<Self as container::Renderer>::Style,
         title_bar: Option<(&TitleBar<'_, Message, Self>, Layout<'_>)>,
         body: (&Element<'_, Message, Self>, Layout<'_>),
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output {
         let style = style_sheet.style();
         let (body, body_layout) = body;
 
         let (body_primitive, body_interaction) =
-            body.draw(self, defaults, body_layout, cursor_position, viewport);
+            body.draw(self, defaults, body_layout, cursor, viewport);
 
         let background = crate::widget::container::background(bounds, &style);
 
         if let Some((title_bar, title_bar_layout)) = title_bar {
-            let show_controls = bounds.contains(cursor_position);
+            let show_controls = cursor.is_over(bounds);
             let is_over_pick_area =
-                title_bar.is_over_pick_area(title_bar_layout, cursor_position);
+                cursor.position().map_or(false, |cursor_position| {
+                    title_bar
+                        .is_over_pick_area(title_bar_layout, cursor_position)
+                });
 
             let (title_bar_primitive, title_bar_interaction) = title_bar.draw(
                 self,
                 defaults,
                 title_bar_layout,
-                cursor_position,
+                cursor,
                 viewport,
                 show_controls,
             );
@@ -247,7 +252,7 @@ where
         style_sheet: &<Self as container::Renderer>::Style,
         content: (&Element<'_, Message, Self>, Layout<'_>),
         controls: Option<(&Element<'_, Message, Self>, Layout<'_>)>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output {
         let style = style_sheet.style();
@@ -261,20 +266,15 @@ where
 
         let background = crate::widget::container::background(bounds, &style);
 
-        let (title_primitive, title_interaction) = title_content.draw(
-            self,
-            &defaults,
-            title_layout,
-            cursor_position,
-            viewport,
-        );
+        let (title_primitive, title_interaction) =
+            title_content.draw(self, &defaults, title_layout, cursor, viewport);
 
         if let Some((controls, controls_layout)) = controls {
             let (controls_primitive, controls_interaction) = controls.draw(
                 self,
                 &defaults,
                 controls_layout,
-                cursor_position,
+                cursor,
                 viewport,
             );
 
@@ -3,7 +3,9 @@ use crate::alignment;
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 
-use iced_native::{mouse, Font, Padding, Point, Rectangle};
+use iced_native::{
+    mouse, BorderAlignment, BorderStyle, Font, Padding, Rectangle, WritingMode,
+};
 use iced_style::menu;
 
 pub use iced_native::pick_list::State;
@@ -28,7 +30,7 @@ where
     fn draw(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         selected: Option<String>,
         placeholder: Option<&str>,
         padding: Padding,
@@ -36,7 +38,7 @@ where
         font: Font,
         style: &Box<dyn StyleSheet>,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
         let is_selected = selected.is_some();
 
         let style = if is_mouse_over {
@@ -49,7 +51,11 @@ where
             bounds,
             background: style.background,
             border_color: style.border_color,
-            border_width: style.border_width,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+            border_width: style.border_width.into(),
             border_radius: style.border_radius,
         };
 
@@ -65,6 +71,9 @@ where
             color: style.text_color,
             horizontal_alignment: alignment::Horizontal::Right,
             vertical_alignment: alignment::Vertical::Center,
+            letter_spacing: 0.0,
+            writing_mode: WritingMode::Horizontal,
+            background: None,
         };
 
         (
@@ -86,6 +95,9 @@ where
                         },
                         horizontal_alignment: alignment::Horizontal::Left,
                         vertical_alignment: alignment::Vertical::Center,
+                        letter_spacing: 0.0,
+                        writing_mode: WritingMode::Horizontal,
+                        background: None,
                     };
 
                     vec![background, label, arrow_down]
@@ -5,7 +5,9 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::progress_bar;
-use iced_native::{Color, Rectangle};
+use iced_native::{
+    BorderAlignment, BorderStyle, BorderWidth, Color, Rectangle,
+};
 
 pub use iced_style::progress_bar::{Style, StyleSheet};
 
@@ -13,7 +15,8 @@ pub use iced_style::progress_bar::{Style, StyleSheet};
 ///
 /// This is an alias of an `iced_native` progress bar with an
 /// `iced_wgpu::Renderer`.
-pub type ProgressBar<Backend> = iced_native::ProgressBar<Renderer<Backend>>;
+pub type ProgressBar<Backend, T = f32> =
+    iced_native::ProgressBar<Renderer<Backend>, T>;
 
 impl<B> progress_bar::Renderer for Renderer<B>
 where
@@ -44,8 +47,12 @@ where
                 bounds: Rectangle { ..bounds },
                 background: style.background,
                 border_radius: style.border_radius,
-                border_width: 0.0,
+                border_width: BorderWidth::ZERO,
                 border_color: Color::TRANSPARENT,
+                border_style: BorderStyle::Solid,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: 0.0,
+                fill: true,
             }],
         };
 
@@ -58,8 +65,12 @@ where
                     },
                     background: style.bar,
                     border_radius: style.border_radius,
-                    border_width: 0.0,
+                    border_width: BorderWidth::ZERO,
                     border_color: Color::TRANSPARENT,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
                 };
 
                 Primitive::Group {
@@ -2,11 +2,13 @@
 use crate::canvas;
 use crate::{Backend, Defaults, Primitive, Renderer, Vector};
 
+use iced_native::qr_code;
 use iced_native::{
-    layout, mouse, Color, Element, Hasher, Layout, Length, Point, Rectangle,
-    Size, Widget,
+    layout, mouse, Background, BorderAlignment, BorderStyle, BorderWidth,
+    Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
 };
-use thiserror::Error;
+
+pub use iced_native::qr_code::{Error, QrCode};
 
 const DEFAULT_CELL_SIZE: u16 = 4;
 const QUIET_ZONE: usize = 2;
@@ -83,7 +85,7 @@ where
         _renderer: &mut Renderer<B>,
         _defaults: &Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> (Primitive<B>, mouse::Interaction) {
         let bounds = layout.bounds();
@@ -254,52 +256,69 @@ impl From<ErrorCorrection> for qrcode::EcLevel {
     }
 }
 
-/// An error that occurred when building a [`State`] for a [`QRCode`].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
-pub enum Error {
-    /// The data is too long to encode in a QR code for the chosen [`Version`].
-    #[error(
-        "The data is too long to encode in a QR code for the chosen version"
-    )]
-    DataTooLong,
-
-    /// The chosen [`Version`] and [`ErrorCorrection`] combination is invalid.
-    #[error(
-        "The chosen version and error correction level combination is invalid."
-    )]
-    InvalidVersion,
-
-    /// One or more characters in the provided data are not supported by the
-    /// chosen [`Version`].
-    #[error(
-        "One or more characters in the provided data are not supported by the \
-        chosen version"
-    )]
-    UnsupportedCharacterSet,
-
-    /// The chosen ECI designator is invalid. A valid designator should be
-    /// between 0 and 999999.
-    #[error(
-        "The chosen ECI designator is invalid. A valid designator should be \
-        between 0 and 999999."
-    )]
-    InvalidEciDesignator,
-
-    /// A character that does not belong to the character set was found.
-    #[error("A character that does not belong to the character set was found")]
-    InvalidCharacter,
-}
-
-impl From<qrcode::types::QrError> for Error {
-    fn from(error: qrcode::types::QrError) -> Self {
-        use qrcode::types::QrError;
-
-        match error {
-            QrError::DataTooLong => Error::DataTooLong,
-            QrError::InvalidVersion => Error::InvalidVersion,
-            QrError::UnsupportedCharacterSet => Error::UnsupportedCharacterSet,
-            QrError::InvalidEciDesignator => Error::InvalidEciDesignator,
-            QrError::InvalidCharacter => Error::InvalidCharacter,
+impl<B> qr_code::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        modules: &[bool],
+        width: usize,
+        module_size: f32,
+        dark: Color,
+        light: Color,
+    ) -> Self::Output {
+        let quad = |bounds, color| Primitive::Quad {
+            bounds,
+            background: Background::Color(color),
+            border_radius: 0.0,
+            border_width: BorderWidth::ZERO,
+            border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        };
+
+        let origin = Point::new(
+            bounds.x + qr_code::QUIET_ZONE as f32 * module_size,
+            bounds.y + qr_code::QUIET_ZONE as f32 * module_size,
+        );
+
+        let mut primitives = vec![quad(bounds, light)];
+
+        // Consecutive dark modules of a row are merged into a single quad
+        for (row, modules) in modules.chunks(width.max(1)).enumerate() {
+            let mut column = 0;
+
+            while column < modules.len() {
+                if !modules[column] {
+                    column += 1;
+                    continue;
+                }
+
+                let start = column;
+
+                while column < modules.len() && modules[column] {
+                    column += 1;
+                }
+
+                primitives.push(quad(
+                    Rectangle {
+                        x: origin.x + start as f32 * module_size,
+                        y: origin.y + row as f32 * module_size,
+                        width: (column - start) as f32 * module_size,
+                        height: module_size,
+                    },
+                    dark,
+                ));
+            }
         }
+
+        (
+            Primitive::Group { primitives },
+            mouse::Interaction::default(),
+        )
     }
 }
@@ -1,8 +1,10 @@
 //! Create choices using radio buttons.
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Styleable};
 use iced_native::mouse;
 use iced_native::radio;
-use iced_native::{Background, Color, Rectangle};
+use iced_native::{
+    Background, BorderAlignment, BorderStyle, BorderWidth, Color, Rectangle,
+};
 
 pub use iced_style::radio::{Style, StyleSheet};
 
@@ -28,8 +30,17 @@ where
         is_selected: bool,
         is_mouse_over: bool,
         (label, _): Self::Output,
-        style_sheet: &Self::Style,
+        style_sheet: Option<&Self::Style>,
     ) -> Self::Output {
+        let themed;
+        let style_sheet = match style_sheet {
+            Some(style_sheet) => style_sheet,
+            None => {
+                themed = Self::Style::from_theme(self.theme());
+                &themed
+            }
+        };
+
         let style = if is_mouse_over {
             style_sheet.hovered()
         } else {
@@ -43,8 +54,12 @@ where
             bounds,
             background: style.background,
             border_radius: size / 2.0,
-            border_width: style.border_width,
+            border_width: style.border_width.into(),
             border_color: style.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
         };
 
         (
@@ -59,8 +74,12 @@ where
                         },
                         background: Background::Color(style.dot_color),
                         border_radius: dot_size / 2.0,
-                        border_width: 0.0,
+                        border_width: BorderWidth::ZERO,
                         border_color: Color::TRANSPARENT,
+                        border_style: BorderStyle::Solid,
+                        border_alignment: BorderAlignment::Inside,
+                        border_offset: 0.0,
+                        fill: true,
                     };
 
                     vec![radio, radio_circle, label]
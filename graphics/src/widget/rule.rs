@@ -1,11 +1,19 @@
 //! Display a horizontal or vertical rule for dividing content.
 
-use crate::{Backend, Primitive, Renderer};
+use crate::backend;
+use crate::triangle;
+use crate::{Backend, Defaults, Primitive, Renderer, Styleable};
 use iced_native::mouse;
 use iced_native::rule;
-use iced_native::{Background, Color, Rectangle};
+use iced_native::text;
+use iced_native::{
+    alignment, Background, BorderAlignment, BorderStyle, BorderWidth, Color,
+    Font, Point, Rectangle, Size, Vector,
+};
 
-pub use iced_style::rule::{FillMode, Style, StyleSheet};
+pub use iced_style::rule::{CapStyle, FillMode, Style, StyleSheet};
+
+use std::f32::consts::PI;
 
 /// Display a horizontal or vertical rule for dividing content.
 ///
@@ -14,60 +22,351 @@ pub type Rule<Backend> = iced_native::Rule<Renderer<Backend>>;
 
 impl<B> rule::Renderer for Renderer<B>
 where
-    B: Backend,
+    B: Backend + backend::Text,
 {
     type Style = Box<dyn StyleSheet>;
 
     fn draw(
         &mut self,
+        defaults: &Defaults,
         bounds: Rectangle,
-        style_sheet: &Self::Style,
+        style_sheet: Option<&Self::Style>,
         is_horizontal: bool,
+        label: Option<rule::Label<'_, Font>>,
     ) -> Self::Output {
-        let style = style_sheet.style();
-
-        let line = if is_horizontal {
-            let line_y = (bounds.y + (bounds.height / 2.0)
-                - (style.width as f32 / 2.0))
-                .round();
-
-            let (offset, line_width) = style.fill_mode.fill(bounds.width);
-            let line_x = bounds.x + offset;
-
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: line_x,
-                    y: line_y,
-                    width: line_width,
-                    height: style.width as f32,
-                },
-                background: Background::Color(style.color),
-                border_radius: style.radius,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            }
+        let style = match style_sheet {
+            Some(style_sheet) => style_sheet.style(),
+            None => Self::Style::from_theme(self.theme()).style(),
+        };
+
+        let (start, length) = if is_horizontal {
+            (bounds.x, bounds.width)
         } else {
-            let line_x = (bounds.x + (bounds.width / 2.0)
-                - (style.width as f32 / 2.0))
-                .round();
-
-            let (offset, line_height) = style.fill_mode.fill(bounds.height);
-            let line_y = bounds.y + offset;
-
-            Primitive::Quad {
-                bounds: Rectangle {
-                    x: line_x,
-                    y: line_y,
-                    width: style.width as f32,
-                    height: line_height,
-                },
-                background: Background::Color(style.color),
-                border_radius: style.radius,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
+            (bounds.y, bounds.height)
+        };
+
+        let (offset, length) = style.fill_mode.fill(length);
+        let (line_start, line_end) = (start + offset, start + offset + length);
+
+        // The line is split in two segments around the gap of the label
+        let segments = match label.map(|label| label.gap) {
+            Some(gap) => {
+                let (gap_start, gap_end) = if is_horizontal {
+                    (gap.x, gap.x + gap.width)
+                } else {
+                    (gap.y, gap.y + gap.height)
+                };
+
+                vec![
+                    (line_start, gap_start.min(line_end)),
+                    (gap_end.max(line_start), line_end),
+                ]
             }
+            None => vec![(line_start, line_end)],
+        };
+
+        let mut primitives: Vec<Primitive<B>> = segments
+            .into_iter()
+            .filter(|(start, end)| end > start)
+            .flat_map(|(start, end)| {
+                segment(bounds, start, end, is_horizontal, &style)
+            })
+            .collect();
+
+        if let Some(label) = label {
+            let (text, _) = text::Renderer::draw(
+                self,
+                defaults,
+                label.bounds,
+                label.content,
+                label.size,
+                label.font,
+                None,
+                alignment::Horizontal::Left,
+                alignment::Vertical::Top,
+            );
+
+            primitives.push(text);
+        }
+
+        let primitive = if primitives.len() == 1 {
+            primitives.pop().unwrap()
+        } else {
+            Primitive::Group { primitives }
         };
 
-        (line, mouse::Interaction::default())
+        (primitive, mouse::Interaction::default())
+    }
+}
+
+/// Produces the primitives of a segment of the line of a rule, from `start`
+/// to `end` along its axis.
+fn segment<B: Backend>(
+    bounds: Rectangle,
+    start: f32,
+    end: f32,
+    is_horizontal: bool,
+    style: &Style,
+) -> Vec<Primitive<B>> {
+    let thickness = style.width as f32;
+    let cap_length = match style.cap {
+        CapStyle::Square => 0.0,
+        CapStyle::Round | CapStyle::None => thickness / 2.0,
+    };
+
+    let cap_length = cap_length.min((end - start) / 2.0);
+    let start = start + cap_length;
+    let length = end - start - cap_length;
+
+    let (line_bounds, caps) = if is_horizontal {
+        let line_y =
+            (bounds.y + (bounds.height / 2.0) - (thickness / 2.0)).round();
+
+        let center_y = line_y + thickness / 2.0;
+
+        (
+            Rectangle {
+                x: start,
+                y: line_y,
+                width: length,
+                height: thickness,
+            },
+            [
+                (Point::new(start, center_y), PI / 2.0),
+                (Point::new(start + length, center_y), -PI / 2.0),
+            ],
+        )
+    } else {
+        let line_x =
+            (bounds.x + (bounds.width / 2.0) - (thickness / 2.0)).round();
+
+        let center_x = line_x + thickness / 2.0;
+
+        (
+            Rectangle {
+                x: line_x,
+                y: start,
+                width: thickness,
+                height: length,
+            },
+            [
+                (Point::new(center_x, start), PI),
+                (Point::new(center_x, start + length), 0.0),
+            ],
+        )
+    };
+
+    let line = Primitive::Quad {
+        bounds: line_bounds,
+        background: Background::Color(style.color),
+        border_radius: style.radius,
+        border_width: BorderWidth::ZERO,
+        border_color: Color::TRANSPARENT,
+        border_style: BorderStyle::Solid,
+        border_alignment: BorderAlignment::Inside,
+        border_offset: 0.0,
+        fill: true,
+    };
+
+    let mut primitives = vec![line];
+
+    if style.cap == CapStyle::Round {
+        primitives.extend(caps.iter().map(|(center, start_angle)| {
+            round_cap(*center, thickness / 2.0, *start_angle, style.color)
+        }));
+    }
+
+    primitives
+}
+
+/// The amount of segments used to approximate a round cap.
+const CAP_SEGMENTS: u32 = 16;
+
+/// Produces a semicircle centered at `center`, sweeping half a turn clockwise
+/// from `start_angle`.
+fn round_cap<B: Backend>(
+    center: Point,
+    radius: f32,
+    start_angle: f32,
+    color: Color,
+) -> Primitive<B> {
+    let color = color.into_linear();
+
+    let vertex = |x: f32, y: f32| triangle::Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    let mut vertices = vec![vertex(radius, radius)];
+
+    vertices.extend((0..=CAP_SEGMENTS).map(|i| {
+        let angle = start_angle + PI * i as f32 / CAP_SEGMENTS as f32;
+
+        vertex(radius + radius * angle.cos(), radius + radius * angle.sin())
+    }));
+
+    let indices = (1..=CAP_SEGMENTS).flat_map(|i| vec![0, i, i + 1]).collect();
+
+    Primitive::Translate {
+        translation: Vector::new(center.x - radius, center.y - radius),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D { vertices, indices },
+            size: Size::new(radius * 2.0, radius * 2.0),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+    use iced_native::layout::{self, Layout};
+    use iced_native::Widget;
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    impl backend::Text for Null {
+        const ICON_FONT: Font = Font::Default;
+        const CHECKMARK_ICON: char = '✔';
+        const ARROW_DOWN_ICON: char = '▼';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        // Every character is half as wide as the text is tall
+        fn measure(
+            &self,
+            contents: &str,
+            size: f32,
+            _font: Font,
+            _bounds: Size,
+        ) -> (f32, f32) {
+            (contents.chars().count() as f32 * size / 2.0, size)
+        }
+
+        fn hit_test(
+            &self,
+            _contents: &str,
+            _size: f32,
+            _font: Font,
+            _bounds: Size,
+            _point: Point,
+            _nearest_only: bool,
+        ) -> Option<text::Hit> {
+            None
+        }
+
+        fn glyph_positions(
+            &self,
+            _contents: &str,
+            _size: f32,
+            _font: Font,
+            _bounds: Size,
+        ) -> Vec<Rectangle> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn unstyled_rule_uses_theme_colors() {
+        let mut renderer = Renderer::new(Null);
+
+        renderer.set_theme(Theme {
+            border: Color::from_rgb(1.0, 0.0, 0.5),
+            ..Theme::DARK
+        });
+
+        let rule: Rule<Null> = Rule::horizontal(10);
+        let bounds = Size::new(100.0, 100.0);
+
+        let node = <Rule<Null> as Widget<(), _>>::layout(
+            &rule,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+
+        let defaults = iced_native::Renderer::defaults(&renderer);
+
+        let (primitive, _) = <Rule<Null> as Widget<(), _>>::draw(
+            &rule,
+            &mut renderer,
+            &defaults,
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(bounds),
+        );
+
+        match primitive {
+            Primitive::Quad { background, .. } => assert_eq!(
+                background,
+                Background::Color(Color::from_rgba(1.0, 0.0, 0.5, 0.6))
+            ),
+            primitive => panic!("Unexpected primitive: {:?}", primitive),
+        }
+
+        assert_eq!(defaults.text.color, Color::WHITE);
+    }
+
+    #[test]
+    fn labeled_rule_leaves_a_gap_for_its_label() {
+        let mut renderer = Renderer::new(Null);
+
+        let rule: Rule<Null> =
+            Rule::horizontal(10).label("OR").label_padding(6);
+        let bounds = Size::new(200.0, 100.0);
+
+        let node = <Rule<Null> as Widget<(), _>>::layout(
+            &rule,
+            &renderer,
+            &layout::Limits::new(Size::ZERO, bounds),
+        );
+
+        // The rule grows to fit its label, which is centered
+        assert_eq!(node.size(), Size::new(200.0, 20.0));
+        assert_eq!(
+            node.children()[0].bounds(),
+            Rectangle::new(Point::new(90.0, 0.0), Size::new(20.0, 20.0))
+        );
+
+        let defaults = iced_native::Renderer::defaults(&renderer);
+
+        let (primitive, _) = <Rule<Null> as Widget<(), _>>::draw(
+            &rule,
+            &mut renderer,
+            &defaults,
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &Rectangle::with_size(bounds),
+        );
+
+        let primitives = match primitive {
+            Primitive::Group { primitives } => primitives,
+            primitive => panic!("Unexpected primitive: {:?}", primitive),
+        };
+
+        let segments: Vec<_> = primitives
+            .iter()
+            .filter_map(|primitive| match primitive {
+                Primitive::Quad { bounds, .. } => {
+                    Some((bounds.x, bounds.x + bounds.width))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // The gap is as wide as the measured label plus its padding
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].0 - segments[0].1, 20.0 + 2.0 * 6.0);
+
+        assert!(primitives.iter().any(|primitive| matches!(
+            primitive,
+            Primitive::Text { content, .. } if content == "OR"
+        )));
     }
 }
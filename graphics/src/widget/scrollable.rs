@@ -2,13 +2,17 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::scrollable;
-use iced_native::{Background, Color, Rectangle, Vector};
+use iced_native::{
+    Background, BorderAlignment, BorderStyle, Color, Rectangle, Vector,
+};
 
-pub use iced_native::scrollable::State;
+pub use iced_native::scrollable::{
+    Anchor, Direction, ScrollbarVisibility, State,
+};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with scrollbars,
+/// vertically, horizontally or both.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
@@ -29,6 +33,7 @@ where
         scrollbar_width: u16,
         scrollbar_margin: u16,
         scroller_width: u16,
+        style_sheet: &Self::Style,
     ) -> Option<scrollable::Scrollbar> {
         if content_bounds.height > bounds.height {
             let outer_width =
@@ -50,8 +55,12 @@ where
             };
 
             let ratio = bounds.height / content_bounds.height;
-            let scroller_height = bounds.height * ratio;
-            let y_offset = offset as f32 * ratio;
+            let scroller_height = (bounds.height * ratio)
+                .max(style_sheet.active().scroller.min_length)
+                .min(bounds.height);
+            let y_offset = offset as f32
+                / (content_bounds.height - bounds.height)
+                * (bounds.height - scroller_height);
 
             let scroller_bounds = Rectangle {
                 x: bounds.x + bounds.width
@@ -74,6 +83,64 @@ where
         }
     }
 
+    fn horizontal_scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        scrollbar_width: u16,
+        scrollbar_margin: u16,
+        scroller_width: u16,
+        style_sheet: &Self::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        if content_bounds.width > bounds.width {
+            let outer_height =
+                scrollbar_width.max(scroller_width) + 2 * scrollbar_margin;
+
+            let outer_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - outer_height as f32,
+                width: bounds.width,
+                height: outer_height as f32,
+            };
+
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height
+                    - f32::from(outer_height / 2 + scrollbar_width / 2),
+                width: bounds.width,
+                height: scrollbar_width as f32,
+            };
+
+            let ratio = bounds.width / content_bounds.width;
+            let scroller_width_along = (bounds.width * ratio)
+                .max(style_sheet.active().scroller.min_length)
+                .min(bounds.width);
+            let x_offset = offset as f32
+                / (content_bounds.width - bounds.width)
+                * (bounds.width - scroller_width_along);
+
+            let scroller_bounds = Rectangle {
+                x: scrollbar_bounds.x + x_offset,
+                y: bounds.y + bounds.height
+                    - f32::from(outer_height / 2 + scroller_width / 2),
+                width: scroller_width_along,
+                height: scroller_width as f32,
+            };
+
+            Some(scrollable::Scrollbar {
+                outer_bounds,
+                bounds: scrollbar_bounds,
+                margin: scrollbar_margin,
+                scroller: scrollable::Scroller {
+                    bounds: scroller_bounds,
+                },
+            })
+        } else {
+            None
+        }
+    }
+
     fn draw(
         &mut self,
         state: &scrollable::State,
@@ -81,65 +148,54 @@ where
         _content_bounds: Rectangle,
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
+        is_mouse_over_horizontal_scrollbar: bool,
         scrollbar: Option<scrollable::Scrollbar>,
-        offset: u32,
+        horizontal_scrollbar: Option<scrollable::Scrollbar>,
+        offset: Vector<u32>,
+        scrollbar_visibility: scrollable::ScrollbarVisibility,
         style_sheet: &Self::Style,
         (content, mouse_interaction): Self::Output,
     ) -> Self::Output {
         (
-            if let Some(scrollbar) = scrollbar {
+            if scrollbar.is_some() || horizontal_scrollbar.is_some() {
                 let clip = Primitive::Clip {
                     bounds,
-                    offset: Vector::new(0, offset),
+                    offset,
                     content: Box::new(content),
                 };
 
-                let style = if state.is_scroller_grabbed() {
-                    style_sheet.dragging()
-                } else if is_mouse_over_scrollbar {
-                    style_sheet.hovered()
-                } else {
-                    style_sheet.active()
-                };
+                let mut primitives = Vec::new();
 
-                let is_scrollbar_visible =
-                    style.background.is_some() || style.border_width > 0.0;
-
-                let scroller = if is_mouse_over
-                    || state.is_scroller_grabbed()
-                    || is_scrollbar_visible
-                {
-                    Primitive::Quad {
-                        bounds: scrollbar.scroller.bounds,
-                        background: Background::Color(style.scroller.color),
-                        border_radius: style.scroller.border_radius,
-                        border_width: style.scroller.border_width,
-                        border_color: style.scroller.border_color,
-                    }
-                } else {
-                    Primitive::None
-                };
+                if let Some(scrollbar) = scrollbar {
+                    primitives.extend(draw_scrollbar(
+                        &scrollbar,
+                        Axis::Vertical,
+                        state,
+                        state.is_vertical_scroller_grabbed(),
+                        is_mouse_over,
+                        is_mouse_over_scrollbar,
+                        scrollbar_visibility,
+                        style_sheet.as_ref(),
+                    ));
+                }
 
-                let scrollbar = if is_scrollbar_visible {
-                    Primitive::Quad {
-                        bounds: scrollbar.bounds,
-                        background: style
-                            .background
-                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        border_radius: style.border_radius,
-                        border_width: style.border_width,
-                        border_color: style.border_color,
-                    }
-                } else {
-                    Primitive::None
-                };
+                if let Some(scrollbar) = horizontal_scrollbar {
+                    primitives.extend(draw_scrollbar(
+                        &scrollbar,
+                        Axis::Horizontal,
+                        state,
+                        state.is_horizontal_scroller_grabbed(),
+                        is_mouse_over,
+                        is_mouse_over_horizontal_scrollbar,
+                        scrollbar_visibility,
+                        style_sheet.as_ref(),
+                    ));
+                }
 
                 let scroll = Primitive::Clip {
                     bounds,
                     offset: Vector::new(0, 0),
-                    content: Box::new(Primitive::Group {
-                        primitives: vec![scrollbar, scroller],
-                    }),
+                    content: Box::new(Primitive::Group { primitives }),
                 };
 
                 Primitive::Group {
@@ -148,7 +204,10 @@ where
             } else {
                 content
             },
-            if is_mouse_over_scrollbar || state.is_scroller_grabbed() {
+            if is_mouse_over_scrollbar
+                || is_mouse_over_horizontal_scrollbar
+                || state.is_scroller_grabbed()
+            {
                 mouse::Interaction::Idle
             } else {
                 mouse_interaction
@@ -156,3 +215,97 @@ where
         )
     }
 }
+
+#[derive(Debug, Clone, Copy)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+/// Produces the track and scroller quads of a single [`Scrollbar`].
+fn draw_scrollbar<B: Backend>(
+    scrollbar: &scrollable::Scrollbar,
+    axis: Axis,
+    state: &scrollable::State,
+    is_grabbed: bool,
+    is_mouse_over: bool,
+    is_mouse_over_scrollbar: bool,
+    scrollbar_visibility: ScrollbarVisibility,
+    style_sheet: &dyn StyleSheet,
+) -> [Primitive<B>; 2] {
+    let style = if is_grabbed {
+        style_sheet.dragging()
+    } else if is_mouse_over_scrollbar {
+        style_sheet.hovered()
+    } else {
+        style_sheet.active()
+    };
+
+    let has_track = style.background.is_some() || style.border_width > 0.0;
+
+    let is_shown = match scrollbar_visibility {
+        ScrollbarVisibility::Always => true,
+        ScrollbarVisibility::WhenScrolling => {
+            state.is_scrolling() || state.is_scroller_grabbed()
+        }
+        ScrollbarVisibility::WhenHovered => {
+            is_mouse_over || state.is_scroller_grabbed() || has_track
+        }
+    };
+
+    let is_track_visible = is_shown && has_track;
+
+    let scroller = if is_shown {
+        let (horizontal, vertical) = match axis {
+            Axis::Vertical => (style.scroller.margin, 0.0),
+            Axis::Horizontal => (0.0, style.scroller.margin),
+        };
+
+        Primitive::Quad {
+            bounds: shrink(scrollbar.scroller.bounds, horizontal, vertical),
+            background: Background::Color(style.scroller.color),
+            border_radius: style.scroller.border_radius,
+            border_width: style.scroller.border_width.into(),
+            border_color: style.scroller.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        }
+    } else {
+        Primitive::None
+    };
+
+    let track = if is_track_visible {
+        Primitive::Quad {
+            bounds: shrink(scrollbar.bounds, style.margin, style.margin),
+            background: style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            border_radius: style.border_radius,
+            border_width: style.border_width.into(),
+            border_color: style.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        }
+    } else {
+        Primitive::None
+    };
+
+    [track, scroller]
+}
+
+/// Shrinks some bounds by the given horizontal and vertical margins.
+fn shrink(bounds: Rectangle, horizontal: f32, vertical: f32) -> Rectangle {
+    let horizontal = horizontal.min(bounds.width / 2.0);
+    let vertical = vertical.min(bounds.height / 2.0);
+
+    Rectangle {
+        x: bounds.x + horizontal,
+        y: bounds.y + vertical,
+        width: bounds.width - 2.0 * horizontal,
+        height: bounds.height - 2.0 * vertical,
+    }
+}
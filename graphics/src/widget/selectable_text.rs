@@ -0,0 +1,90 @@
+//! Display text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::alignment;
+use iced_native::selectable_text;
+use iced_native::text;
+use iced_native::{
+    Background, BorderAlignment, BorderStyle, BorderWidth, Color, Font,
+    Rectangle, Size,
+};
+
+use std::ops::Range;
+
+pub use iced_native::selectable_text::State;
+
+/// A paragraph of text that can be selected and copied.
+///
+/// This is an alias of an `iced_native` selectable text with an
+/// `iced_wgpu::Renderer`.
+pub type SelectableText<'a, Backend> =
+    iced_native::SelectableText<'a, Renderer<Backend>>;
+
+impl<B> selectable_text::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        content: &str,
+        size: u16,
+        font: Font,
+        color: Option<Color>,
+        selection: Option<Range<usize>>,
+        selection_color: Color,
+    ) -> Self::Output {
+        let (text, mouse_interaction) = text::Renderer::draw(
+            self,
+            defaults,
+            bounds,
+            content,
+            size,
+            font,
+            color,
+            alignment::Horizontal::Left,
+            alignment::Vertical::Top,
+        );
+
+        let selection = match selection {
+            Some(selection) => selection,
+            None => return (text, mouse_interaction),
+        };
+
+        let glyphs = text::Renderer::glyph_positions(
+            self,
+            content,
+            f32::from(size),
+            font,
+            Size::new(bounds.width, bounds.height),
+        );
+
+        let mut primitives: Vec<_> = glyphs
+            .into_iter()
+            .skip(selection.start)
+            .take(selection.len())
+            .map(|glyph| Primitive::Quad {
+                bounds: Rectangle {
+                    x: bounds.x + glyph.x,
+                    y: bounds.y + glyph.y,
+                    ..glyph
+                },
+                background: Background::Color(selection_color),
+                border_radius: 0.0,
+                border_width: BorderWidth::ZERO,
+                border_color: Color::TRANSPARENT,
+                border_style: BorderStyle::Solid,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: 0.0,
+                fill: true,
+            })
+            .collect();
+
+        primitives.push(text);
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -4,7 +4,9 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::slider;
-use iced_native::{Background, Color, Point, Rectangle};
+use iced_native::{
+    Background, BorderAlignment, BorderStyle, BorderWidth, Color, Rectangle,
+};
 
 pub use iced_native::slider::State;
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
@@ -27,13 +29,13 @@ where
     fn draw(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         range: std::ops::RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
         let style = if is_dragging {
             style_sheet.dragging()
@@ -55,8 +57,12 @@ where
                 },
                 background: Background::Color(style.rail_colors.0),
                 border_radius: 0.0,
-                border_width: 0.0,
+                border_width: BorderWidth::ZERO,
                 border_color: Color::TRANSPARENT,
+                border_style: BorderStyle::Solid,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: 0.0,
+                fill: true,
             },
             Primitive::Quad {
                 bounds: Rectangle {
@@ -67,8 +73,12 @@ where
                 },
                 background: Background::Color(style.rail_colors.1),
                 border_radius: 0.0,
-                border_width: 0.0,
+                border_width: BorderWidth::ZERO,
                 border_color: Color::TRANSPARENT,
+                border_style: BorderStyle::Solid,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: 0.0,
+                fill: true,
             },
         );
 
@@ -103,8 +113,12 @@ where
             },
             background: Background::Color(style.handle.color),
             border_radius: handle_border_radius,
-            border_width: style.handle.border_width,
+            border_width: style.handle.border_width.into(),
             border_color: style.handle.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
         };
 
         (
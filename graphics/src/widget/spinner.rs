@@ -0,0 +1,187 @@
+//! Show that something is in progress with an animated spinner.
+use crate::triangle;
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::spinner;
+use iced_native::{Color, Point, Rectangle, Size, Vector};
+
+use std::f32::consts::PI;
+use std::time::Duration;
+
+pub use iced_style::spinner::{Style, StyleSheet};
+
+/// A ring that keeps turning while something is in progress.
+///
+/// This is an alias of an `iced_native` spinner with an `iced_wgpu::Renderer`.
+pub type Spinner<Backend> = iced_native::Spinner<Renderer<Backend>>;
+
+impl<B> spinner::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    const DEFAULT_SIZE: u16 = 24;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        (
+            arc(
+                bounds.center(),
+                bounds.width.min(bounds.height) / 2.0,
+                rotation(self.animation_time(), style.period),
+                style.color,
+            ),
+            mouse::Interaction::default(),
+        )
+    }
+}
+
+/// The amount of segments used to approximate the arc of a spinner.
+const SEGMENTS: u32 = 24;
+
+/// Returns the rotation, in radians, of a spinner completing a turn every
+/// `period` after the given `elapsed` time.
+pub(crate) fn rotation(elapsed: Duration, period: Duration) -> f32 {
+    let period = period.as_millis().max(1);
+
+    2.0 * PI * (elapsed.as_millis() % period) as f32 / period as f32
+}
+
+/// Produces a three-quarter ring centered at `center`, starting at `rotation`.
+pub(crate) fn arc<B: Backend>(
+    center: Point,
+    radius: f32,
+    rotation: f32,
+    color: Color,
+) -> Primitive<B> {
+    let thickness = (radius / 4.0).max(1.0);
+    let inner_radius = radius - thickness;
+    let color = color.into_linear();
+
+    let vertex = |distance: f32, angle: f32| triangle::Vertex2D {
+        position: [
+            radius + distance * angle.cos(),
+            radius + distance * angle.sin(),
+        ],
+        color,
+    };
+
+    let vertices = (0..=SEGMENTS)
+        .flat_map(|i| {
+            let angle = rotation + 1.5 * PI * i as f32 / SEGMENTS as f32;
+
+            vec![vertex(radius, angle), vertex(inner_radius, angle)]
+        })
+        .collect();
+
+    let indices = (0..SEGMENTS)
+        .flat_map(|i| {
+            let outer = i * 2;
+            let inner = outer + 1;
+
+            vec![outer, inner, outer + 2, inner, inner + 2, outer + 2]
+        })
+        .collect();
+
+    Primitive::Translate {
+        translation: Vector::new(center.x - radius, center.y - radius),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D { vertices, indices },
+            size: Size::new(radius * 2.0, radius * 2.0),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::Renderer as _;
+    use iced_native::{layout, Element, Layout};
+
+    use std::time::Instant;
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl crate::Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    fn first_vertex(primitive: Primitive<Null>) -> [f32; 2] {
+        match primitive {
+            Primitive::Translate { content, .. } => match *content {
+                Primitive::Mesh2D { buffers, .. } => {
+                    buffers.vertices[0].position
+                }
+                _ => panic!("Spinners are meshes"),
+            },
+            _ => panic!("Spinners are translated"),
+        }
+    }
+
+    #[test]
+    fn redraws_turn_the_spinner() {
+        let mut renderer = Renderer::new(Null);
+        let spinner: Element<'_, (), Renderer<Null>> =
+            Spinner::<Null>::new().into();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, 100.0));
+        let node = spinner.layout(&renderer, &limits);
+        let viewport = node.bounds();
+
+        let draw = |renderer: &mut Renderer<Null>| {
+            let (primitive, _) = spinner.draw(
+                renderer,
+                &Default::default(),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &viewport,
+            );
+
+            first_vertex(primitive)
+        };
+
+        let now = Instant::now();
+        renderer.advance_clock(now);
+
+        let first = draw(&mut renderer);
+        assert!(renderer.take_redraw_request());
+        assert!(!renderer.take_redraw_request());
+
+        // Drawing again without advancing the clock does not move it
+        assert_eq!(draw(&mut renderer), first);
+
+        renderer.advance_clock(now + Duration::from_millis(100));
+
+        assert_ne!(draw(&mut renderer), first);
+        assert!(renderer.take_redraw_request());
+    }
+
+    #[test]
+    fn rotation_advances_between_frames() {
+        let period = Duration::from_millis(1000);
+        let frame = Duration::from_millis(16);
+
+        let first = rotation(Duration::from_secs(42), period);
+        let second = rotation(Duration::from_secs(42) + frame, period);
+
+        assert!(second > first);
+        assert!((second - first - 2.0 * PI * 0.016).abs() < 1e-4);
+
+        // A faster spinner advances further in the same frame
+        let fast = Duration::from_millis(500);
+        let fast_first = rotation(Duration::from_secs(42), fast);
+        let fast_second = rotation(Duration::from_secs(42) + frame, fast);
+
+        assert!(fast_second - fast_first > second - first);
+
+        // A full turn starts over
+        assert_eq!(rotation(Duration::from_secs(42) + period, period), first);
+    }
+}
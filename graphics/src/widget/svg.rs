@@ -1,7 +1,7 @@
 //! Display vector graphics in your application.
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
-use iced_native::{mouse, svg, Layout};
+use iced_native::{mouse, svg, Layout, Rectangle, Vector};
 
 pub use iced_native::svg::{Handle, Svg};
 
@@ -16,12 +16,21 @@ where
     fn draw(
         &mut self,
         handle: svg::Handle,
+        bounds: Rectangle,
         layout: Layout<'_>,
     ) -> Self::Output {
+        let clip = layout.bounds();
+        let primitive = Primitive::Svg { handle, bounds };
+
         (
-            Primitive::Svg {
-                handle,
-                bounds: layout.bounds(),
+            if bounds.width > clip.width || bounds.height > clip.height {
+                Primitive::Clip {
+                    bounds: clip,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(primitive),
+                }
+            } else {
+                primitive
             },
             mouse::Interaction::default(),
         )
@@ -4,7 +4,9 @@ use crate::{Primitive, Renderer};
 use iced_native::alignment;
 use iced_native::mouse;
 use iced_native::text;
-use iced_native::{Color, Font, Point, Rectangle, Size};
+use iced_native::{
+    Color, Font, Point, Rectangle, Size, TextShadow, Vector, WritingMode,
+};
 
 /// A paragraph of text.
 ///
@@ -34,6 +36,42 @@ where
             .measure(content, f32::from(size), font, bounds)
     }
 
+    fn measure_with_letter_spacing(
+        &self,
+        content: &str,
+        size: u16,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+    ) -> (f32, f32) {
+        self.backend().measure_with_letter_spacing(
+            content,
+            f32::from(size),
+            font,
+            bounds,
+            letter_spacing,
+        )
+    }
+
+    fn measure_with_writing_mode(
+        &self,
+        content: &str,
+        size: u16,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) -> (f32, f32) {
+        self.backend().measure_with_writing_mode(
+            content,
+            f32::from(size),
+            font,
+            bounds,
+            letter_spacing,
+            writing_mode,
+        )
+    }
+
     fn hit_test(
         &self,
         content: &str,
@@ -53,6 +91,16 @@ where
         )
     }
 
+    fn glyph_positions(
+        &self,
+        content: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<Rectangle> {
+        self.backend().glyph_positions(content, size, font, bounds)
+    }
+
     fn draw(
         &mut self,
         defaults: &Self::Defaults,
@@ -85,8 +133,131 @@ where
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                letter_spacing: 0.0,
+                writing_mode: WritingMode::Horizontal,
+                background: None,
             },
             mouse::Interaction::default(),
         )
     }
+
+    fn draw_with_shadow(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        content: &str,
+        size: u16,
+        font: Font,
+        color: Option<Color>,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+        shadow: Option<TextShadow>,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) -> Self::Output {
+        let (mut text, interaction) = self.draw(
+            defaults,
+            bounds,
+            content,
+            size,
+            font,
+            color,
+            horizontal_alignment,
+            vertical_alignment,
+        );
+
+        set_layout(&mut text, letter_spacing, writing_mode);
+
+        let shadow = match shadow {
+            Some(shadow) => shadow,
+            None => return (text, interaction),
+        };
+
+        let mut primitives: Vec<_> = shadow_offsets(shadow.blur)
+            .iter()
+            .map(|(offset, weight)| {
+                let (mut shadow_text, _) = self.draw(
+                    defaults,
+                    bounds + shadow.offset + *offset,
+                    content,
+                    size,
+                    font,
+                    Some(Color {
+                        a: shadow.color.a * weight,
+                        ..shadow.color
+                    }),
+                    horizontal_alignment,
+                    vertical_alignment,
+                );
+
+                set_layout(&mut shadow_text, letter_spacing, writing_mode);
+
+                shadow_text
+            })
+            .collect();
+
+        primitives.push(text);
+
+        (Primitive::Group { primitives }, interaction)
+    }
+}
+
+fn set_layout<B: Backend>(
+    primitive: &mut Primitive<B>,
+    spacing: f32,
+    mode: WritingMode,
+) {
+    if let Primitive::Text {
+        letter_spacing,
+        writing_mode,
+        ..
+    } = primitive
+    {
+        *letter_spacing = spacing;
+        *writing_mode = mode;
+    }
+}
+
+/// Returns the offsets and opacity weights of the copies of some text that
+/// approximate a shadow with the given blur radius.
+///
+/// A sharp shadow is a single copy. A blurred shadow is a copy surrounded by
+/// a ring of fainter copies, whose opacities add up to about the opacity of
+/// the sharp shadow where they all overlap.
+fn shadow_offsets(blur: f32) -> Vec<(Vector, f32)> {
+    const RING: usize = 8;
+
+    if blur <= 0.0 {
+        return vec![(Vector::new(0.0, 0.0), 1.0)];
+    }
+
+    let radius = blur / 2.0;
+
+    std::iter::once((Vector::new(0.0, 0.0), 0.5))
+        .chain((0..RING).map(|i| {
+            let angle = i as f32 * 2.0 * f32::consts::PI / RING as f32;
+
+            (
+                Vector::new(radius * angle.cos(), radius * angle.sin()),
+                1.0 / RING as f32,
+            )
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sharp_shadows_are_a_single_opaque_copy() {
+        assert_eq!(shadow_offsets(0.0), vec![(Vector::new(0.0, 0.0), 1.0)]);
+
+        let blurred = shadow_offsets(4.0);
+
+        assert_eq!(blurred.len(), 9);
+        assert!(blurred
+            .iter()
+            .all(|(offset, _)| offset.x.abs() <= 2.0 && offset.y.abs() <= 2.0));
+    }
 }
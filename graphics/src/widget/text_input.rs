@@ -4,8 +4,8 @@
 use crate::alignment;
 use crate::backend::{self, Backend};
 use crate::{
-    Background, Color, Font, Point, Primitive, Rectangle, Renderer, Size,
-    Vector,
+    Background, BorderAlignment, BorderStyle, BorderWidth, Color, Font,
+    Primitive, Rectangle, Renderer, Size, Vector, WritingMode,
 };
 
 use iced_native::mouse;
@@ -71,7 +71,7 @@ where
         &mut self,
         bounds: Rectangle,
         text_bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         font: Font,
         size: u16,
         placeholder: &str,
@@ -79,7 +79,7 @@ where
         state: &text_input::State,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
         let style = if state.is_focused() {
             style_sheet.focused()
@@ -93,10 +93,31 @@ where
             bounds,
             background: style.background,
             border_radius: style.border_radius,
-            border_width: style.border_width,
+            border_width: style.border_width.into(),
             border_color: style.border_color,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
         };
 
+        // The text composed with an input method is shown in place, before
+        // being committed
+        #[cfg(feature = "ime")]
+        let composition = if state.is_focused() {
+            state.composition(value)
+        } else {
+            None
+        };
+
+        #[cfg(not(feature = "ime"))]
+        let composition: Option<(
+            text_input::Value,
+            std::ops::Range<usize>,
+        )> = None;
+
+        let value = composition.as_ref().map_or(value, |(value, _)| value);
+
         let text = value.to_string();
 
         let text_value = Primitive::Text {
@@ -119,12 +140,20 @@ where
             size: f32::from(size),
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Center,
+            letter_spacing: 0.0,
+            writing_mode: WritingMode::Horizontal,
+            background: None,
         };
 
         let (contents_primitive, offset) = if state.is_focused() {
             let cursor = state.cursor();
 
-            let (cursor_primitive, offset) = match cursor.state(value) {
+            let cursor_state = match &composition {
+                Some((_, composing)) => cursor::State::Index(composing.end),
+                None => cursor.state(value),
+            };
+
+            let (cursor_primitive, offset) = match cursor_state {
                 cursor::State::Index(position) => {
                     let (text_value_width, offset) =
                         measure_cursor_and_scroll_offset(
@@ -148,8 +177,12 @@ where
                                 style_sheet.value_color(),
                             ),
                             border_radius: 0.0,
-                            border_width: 0.0,
+                            border_width: BorderWidth::ZERO,
                             border_color: Color::TRANSPARENT,
+                            border_style: BorderStyle::Solid,
+                            border_alignment: BorderAlignment::Inside,
+                            border_offset: 0.0,
+                            fill: true,
                         },
                         offset,
                     )
@@ -192,8 +225,12 @@ where
                                 style_sheet.selection_color(),
                             ),
                             border_radius: 0.0,
-                            border_width: 0.0,
+                            border_width: BorderWidth::ZERO,
                             border_color: Color::TRANSPARENT,
+                            border_style: BorderStyle::Solid,
+                            border_alignment: BorderAlignment::Inside,
+                            border_offset: 0.0,
+                            fill: true,
                         },
                         if end == right {
                             right_offset
@@ -204,10 +241,47 @@ where
                 }
             };
 
+            let mut primitives = vec![cursor_primitive, text_value];
+
+            if let Some((_, composing)) = &composition {
+                let (start, _) = measure_cursor_and_scroll_offset(
+                    self,
+                    text_bounds,
+                    value,
+                    size,
+                    composing.start,
+                    font,
+                );
+
+                let (end, _) = measure_cursor_and_scroll_offset(
+                    self,
+                    text_bounds,
+                    value,
+                    size,
+                    composing.end,
+                    font,
+                );
+
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + start,
+                        y: text_bounds.center_y() + f32::from(size) / 2.0 - 1.0,
+                        width: end - start,
+                        height: 1.0,
+                    },
+                    background: Background::Color(style_sheet.value_color()),
+                    border_radius: 0.0,
+                    border_width: BorderWidth::ZERO,
+                    border_color: Color::TRANSPARENT,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
+                });
+            }
+
             (
-                Primitive::Group {
-                    primitives: vec![cursor_primitive, text_value],
-                },
+                Primitive::Group { primitives },
                 Vector::new(offset as u32, 0),
             )
         } else {
@@ -264,3 +338,95 @@ where
 
     (text_value_width, offset)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::text;
+    use iced_native::text_input::{Renderer as _, Value};
+    use iced_native::Point;
+
+    #[derive(Debug)]
+    struct Null;
+
+    impl Backend for Null {
+        type CustomRenderPrimitive = ();
+    }
+
+    impl backend::Text for Null {
+        const ICON_FONT: Font = Font::Default;
+        const CHECKMARK_ICON: char = '✔';
+        const ARROW_DOWN_ICON: char = '▼';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        // Every character is half as wide as the text is tall
+        fn measure(
+            &self,
+            contents: &str,
+            size: f32,
+            _font: Font,
+            _bounds: Size,
+        ) -> (f32, f32) {
+            (contents.chars().count() as f32 * size / 2.0, size)
+        }
+
+        fn hit_test(
+            &self,
+            _contents: &str,
+            _size: f32,
+            _font: Font,
+            _bounds: Size,
+            _point: Point,
+            _nearest_only: bool,
+        ) -> Option<text::Hit> {
+            None
+        }
+
+        fn glyph_positions(
+            &self,
+            contents: &str,
+            size: f32,
+            _font: Font,
+            _bounds: Size,
+        ) -> Vec<Rectangle> {
+            (0..contents.chars().count())
+                .map(|i| Rectangle {
+                    x: i as f32 * size / 2.0,
+                    y: 0.0,
+                    width: size / 2.0,
+                    height: size,
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn the_cursor_is_placed_at_the_nearest_glyph_boundary() {
+        let renderer = Renderer::new(Null);
+        let text_bounds = Rectangle::new(Point::ORIGIN, Size::new(200.0, 20.0));
+        let state = State::new();
+
+        // An accented "e" is a single grapheme made of two characters
+        let value = Value::new("e\u{301}ab");
+
+        let position = |x| {
+            renderer.find_cursor_position(
+                text_bounds,
+                Font::Default,
+                Some(20),
+                &value,
+                &state,
+                x,
+            )
+        };
+
+        assert_eq!(position(0.0), Some(0));
+        assert_eq!(position(12.0), Some(1));
+        assert_eq!(position(24.0), Some(1));
+        assert_eq!(position(26.0), Some(2));
+        assert_eq!(position(100.0), Some(3));
+    }
+}
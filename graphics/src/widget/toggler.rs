@@ -1,15 +1,12 @@
 //! Show toggle controls using togglers.
 use crate::backend::{self, Backend};
-use crate::{Primitive, Renderer};
+use crate::{Primitive, Renderer, Styleable};
 use iced_native::mouse;
 use iced_native::toggler;
-use iced_native::Rectangle;
+use iced_native::{BorderAlignment, BorderStyle, BorderWidth, Rectangle};
 
 pub use iced_style::toggler::{Style, StyleSheet};
 
-/// Makes sure that the border radius of the toggler looks good at every size.
-const BORDER_RADIUS_RATIO: f32 = 32.0 / 13.0;
-
 /// The space ratio between the background Quad and the Toggler bounds, and
 /// between the background Quad and foreground Quad.
 const SPACE_RATIO: f32 = 0.05;
@@ -34,15 +31,23 @@ where
         is_active: bool,
         is_mouse_over: bool,
         label: Option<Self::Output>,
-        style_sheet: &Self::Style,
+        style_sheet: Option<&Self::Style>,
     ) -> Self::Output {
+        let themed;
+        let style_sheet = match style_sheet {
+            Some(style_sheet) => style_sheet,
+            None => {
+                themed = Self::Style::from_theme(self.theme());
+                &themed
+            }
+        };
+
         let style = if is_mouse_over {
             style_sheet.hovered(is_active)
         } else {
             style_sheet.active(is_active)
         };
 
-        let border_radius = bounds.height as f32 / BORDER_RADIUS_RATIO;
         let space = SPACE_RATIO * bounds.height as f32;
 
         let toggler_background_bounds = Rectangle {
@@ -55,9 +60,15 @@ where
         let toggler_background = Primitive::Quad {
             bounds: toggler_background_bounds,
             background: style.background.into(),
-            border_radius,
-            border_width: 1.0,
+            border_radius: style
+                .track_border_radius
+                .min(toggler_background_bounds.height / 2.0),
+            border_width: BorderWidth::new(1.0),
             border_color: style.background_border.unwrap_or(style.background),
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
         };
 
         let toggler_foreground_bounds = Rectangle {
@@ -75,9 +86,15 @@ where
         let toggler_foreground = Primitive::Quad {
             bounds: toggler_foreground_bounds,
             background: style.foreground.into(),
-            border_radius,
-            border_width: 1.0,
+            border_radius: style
+                .thumb_border_radius
+                .min(toggler_foreground_bounds.height / 2.0),
+            border_width: BorderWidth::new(1.0),
             border_color: style.foreground_border.unwrap_or(style.foreground),
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
         };
 
         (
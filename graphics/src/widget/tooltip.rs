@@ -1,11 +1,13 @@
 //! Decorate content and apply alignment.
 use crate::backend::{self, Backend};
 use crate::defaults::{self, Defaults};
+use crate::triangle::{Mesh2D, Vertex2D};
 use crate::{Primitive, Renderer, Vector};
 
 use iced_native::container;
 use iced_native::layout::{self, Layout};
-use iced_native::{Element, Padding, Point, Rectangle, Size, Text};
+use iced_native::mouse;
+use iced_native::{Background, Element, Padding, Point, Rectangle, Size, Text};
 
 /// An element decorating some content.
 ///
@@ -25,7 +27,7 @@ where
     fn draw<Message>(
         &mut self,
         defaults: &Defaults,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         content_layout: Layout<'_>,
         viewport: &Rectangle,
         content: &Element<'_, Message, Self>,
@@ -34,18 +36,15 @@ where
         style_sheet: &<Self as container::Renderer>::Style,
         gap: u16,
         padding: u16,
+        arrow_size: Option<u16>,
+        cursor_offset: Option<Vector>,
     ) -> Self::Output {
-        let (content, mouse_interaction) = content.draw(
-            self,
-            &defaults,
-            content_layout,
-            cursor_position,
-            viewport,
-        );
+        let (content, mouse_interaction) =
+            content.draw(self, &defaults, content_layout, cursor, viewport);
 
         let bounds = content_layout.bounds();
 
-        if bounds.contains(cursor_position) {
+        if let Some(cursor_position) = cursor.position_over(bounds) {
             use iced_native::Widget;
 
             let gap = f32::from(gap);
@@ -65,42 +64,16 @@ where
             );
 
             let padding = f32::from(padding);
-            let text_bounds = text_layout.bounds();
-            let x_center = bounds.x + (bounds.width - text_bounds.width) / 2.0;
-            let y_center =
-                bounds.y + (bounds.height - text_bounds.height) / 2.0;
-
-            let mut tooltip_bounds = {
-                let offset = match position {
-                    Position::Top => Vector::new(
-                        x_center,
-                        bounds.y - text_bounds.height - gap - padding,
-                    ),
-                    Position::Bottom => Vector::new(
-                        x_center,
-                        bounds.y + bounds.height + gap + padding,
-                    ),
-                    Position::Left => Vector::new(
-                        bounds.x - text_bounds.width - gap - padding,
-                        y_center,
-                    ),
-                    Position::Right => Vector::new(
-                        bounds.x + bounds.width + gap + padding,
-                        y_center,
-                    ),
-                    Position::FollowCursor => Vector::new(
-                        cursor_position.x,
-                        cursor_position.y - text_bounds.height,
-                    ),
-                };
 
-                Rectangle {
-                    x: offset.x - padding,
-                    y: offset.y - padding,
-                    width: text_bounds.width + padding * 2.0,
-                    height: text_bounds.height + padding * 2.0,
-                }
-            };
+            let mut tooltip_bounds = place(
+                position,
+                bounds,
+                cursor_position,
+                text_layout.size(),
+                gap,
+                padding,
+                cursor_offset,
+            );
 
             if tooltip_bounds.x < viewport.x {
                 tooltip_bounds.x = viewport.x;
@@ -131,10 +104,57 @@ where
                     ),
                     &text_layout,
                 ),
-                cursor_position,
+                cursor,
                 viewport,
             );
 
+            let mut primitives = Vec::new();
+
+            if let Some(background) =
+                crate::container::background(tooltip_bounds, &style)
+            {
+                primitives.push(background);
+            }
+
+            // The arrow shares the color of the background, or of the border
+            // when the tooltip is only outlined or has an image background
+            let arrow_color = match style.background {
+                Some(Background::Color(color)) => Some(color),
+                _ if style.border_width > 0.0 => Some(style.border_color),
+                _ => None,
+            };
+
+            if let (Some(size), Some(color)) = (arrow_size, arrow_color) {
+                if let Some(vertices) =
+                    arrow(position, bounds, tooltip_bounds, f32::from(size))
+                {
+                    let color = color.into_linear();
+
+                    // The mesh is drawn relative to the viewport, which clips it
+                    primitives.push(Primitive::Translate {
+                        translation: Vector::new(viewport.x, viewport.y),
+                        content: Box::new(Primitive::Mesh2D {
+                            buffers: Mesh2D {
+                                vertices: vertices
+                                    .iter()
+                                    .map(|vertex| Vertex2D {
+                                        position: [
+                                            vertex.x - viewport.x,
+                                            vertex.y - viewport.y,
+                                        ],
+                                        color,
+                                    })
+                                    .collect(),
+                                indices: vec![0, 1, 2],
+                            },
+                            size: viewport.size(),
+                        }),
+                    });
+                }
+            }
+
+            primitives.push(tooltip);
+
             (
                 Primitive::Group {
                     primitives: vec![
@@ -142,20 +162,7 @@ where
                         Primitive::Clip {
                             bounds: *viewport,
                             offset: Vector::new(0, 0),
-                            content: Box::new(
-                                if let Some(background) =
-                                    crate::container::background(
-                                        tooltip_bounds,
-                                        &style,
-                                    )
-                                {
-                                    Primitive::Group {
-                                        primitives: vec![background, tooltip],
-                                    }
-                                } else {
-                                    tooltip
-                                },
-                            ),
+                            content: Box::new(Primitive::Group { primitives }),
                         },
                     ],
                 },
@@ -166,3 +173,189 @@ where
         }
     }
 }
+
+/// Returns the bounds of a tooltip with text of the given size, before they
+/// are moved inside the viewport.
+fn place(
+    position: Position,
+    content: Rectangle,
+    cursor_position: Point,
+    text: Size,
+    gap: f32,
+    padding: f32,
+    cursor_offset: Option<Vector>,
+) -> Rectangle {
+    let x_center = content.x + (content.width - text.width) / 2.0;
+    let y_center = content.y + (content.height - text.height) / 2.0;
+
+    let offset = match position {
+        Position::Top => {
+            Vector::new(x_center, content.y - text.height - gap - padding)
+        }
+        Position::Bottom => {
+            Vector::new(x_center, content.y + content.height + gap + padding)
+        }
+        Position::Left => {
+            Vector::new(content.x - text.width - gap - padding, y_center)
+        }
+        Position::Right => {
+            Vector::new(content.x + content.width + gap + padding, y_center)
+        }
+        Position::FollowCursor => match cursor_offset {
+            Some(cursor_offset) => Vector::new(
+                cursor_position.x + cursor_offset.x + padding,
+                cursor_position.y + cursor_offset.y + padding,
+            ),
+            None => {
+                Vector::new(cursor_position.x, cursor_position.y - text.height)
+            }
+        },
+    };
+
+    Rectangle {
+        x: offset.x - padding,
+        y: offset.y - padding,
+        width: text.width + padding * 2.0,
+        height: text.height + padding * 2.0,
+    }
+}
+
+/// Returns the triangle of an arrow pointing from the tooltip toward the
+/// content, with its tip first.
+///
+/// The arrow is centered on the content, but it never leaves the edge of the
+/// tooltip it is attached to.
+fn arrow(
+    position: Position,
+    content: Rectangle,
+    tooltip: Rectangle,
+    size: f32,
+) -> Option<[Point; 3]> {
+    let clamp = |value: f32, start: f32, length: f32| {
+        value.max(start + size).min(start + length - size)
+    };
+
+    let center_x =
+        clamp(content.x + content.width / 2.0, tooltip.x, tooltip.width);
+
+    let center_y =
+        clamp(content.y + content.height / 2.0, tooltip.y, tooltip.height);
+
+    let top = tooltip.y;
+    let bottom = tooltip.y + tooltip.height;
+    let left = tooltip.x;
+    let right = tooltip.x + tooltip.width;
+
+    match position {
+        Position::Top => Some([
+            Point::new(center_x, bottom + size),
+            Point::new(center_x - size, bottom),
+            Point::new(center_x + size, bottom),
+        ]),
+        Position::Bottom => Some([
+            Point::new(center_x, top - size),
+            Point::new(center_x + size, top),
+            Point::new(center_x - size, top),
+        ]),
+        Position::Left => Some([
+            Point::new(right + size, center_y),
+            Point::new(right, center_y + size),
+            Point::new(right, center_y - size),
+        ]),
+        Position::Right => Some([
+            Point::new(left - size, center_y),
+            Point::new(left, center_y - size),
+            Point::new(left, center_y + size),
+        ]),
+        Position::FollowCursor => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_arrows_point_down_at_the_center_of_the_content() {
+        let content = Rectangle {
+            x: 100.0,
+            y: 100.0,
+            width: 60.0,
+            height: 20.0,
+        };
+
+        let tooltip = Rectangle {
+            x: 90.0,
+            y: 60.0,
+            width: 100.0,
+            height: 30.0,
+        };
+
+        let [tip, left, right] =
+            arrow(Position::Top, content, tooltip, 5.0).unwrap();
+
+        assert_eq!(tip, Point::new(130.0, 95.0));
+        assert_eq!(left, Point::new(125.0, 90.0));
+        assert_eq!(right, Point::new(135.0, 90.0));
+        assert!(tip.y > left.y && tip.y > right.y);
+    }
+
+    #[test]
+    fn following_tooltips_are_offset_from_the_cursor() {
+        let content = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 200.0,
+            height: 100.0,
+        };
+
+        let cursor_position = Point::new(50.0, 40.0);
+        let text = Size::new(80.0, 20.0);
+
+        let below_right = place(
+            Position::FollowCursor,
+            content,
+            cursor_position,
+            text,
+            0.0,
+            5.0,
+            Some(Vector::new(12.0, 16.0)),
+        );
+
+        assert_eq!(
+            below_right,
+            Rectangle {
+                x: 62.0,
+                y: 56.0,
+                width: 90.0,
+                height: 30.0,
+            }
+        );
+
+        let above = place(
+            Position::FollowCursor,
+            content,
+            cursor_position,
+            text,
+            0.0,
+            5.0,
+            None,
+        );
+
+        assert_eq!(above.x, 45.0);
+        assert_eq!(above.y + above.height, cursor_position.y + 5.0);
+    }
+
+    #[test]
+    fn arrows_are_not_drawn_when_following_the_cursor() {
+        assert_eq!(
+            arrow(
+                Position::FollowCursor,
+                Rectangle::default(),
+                Rectangle::default(),
+                5.0
+            ),
+            None
+        );
+    }
+}
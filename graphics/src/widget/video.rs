@@ -0,0 +1,33 @@
+//! Play videos in your application.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{mouse, video, Layout, Rectangle, Vector};
+
+pub use iced_native::video::{Decoder, Frame, Handle, Video};
+
+impl<B> video::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw(
+        &mut self,
+        handle: video::Handle,
+        bounds: Rectangle,
+        layout: Layout<'_>,
+    ) -> Self::Output {
+        let clip = layout.bounds();
+        let primitive = Primitive::Video { handle, bounds };
+
+        (
+            if bounds.width > clip.width || bounds.height > clip.height {
+                Primitive::Clip {
+                    bounds: clip,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(primitive),
+                }
+            } else {
+                primitive
+            },
+            mouse::Interaction::default(),
+        )
+    }
+}
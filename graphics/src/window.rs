@@ -1,10 +1,12 @@
 //! Draw graphics to window surfaces.
 mod compositor;
+mod frame_pacer;
 
 #[cfg(feature = "opengl")]
 mod gl_compositor;
 
 pub use compositor::{Compositor, SurfaceError};
+pub use frame_pacer::{Clock, FramePacer, System};
 
 #[cfg(feature = "opengl")]
 pub use gl_compositor::GLCompositor;
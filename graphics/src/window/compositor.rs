@@ -1,5 +1,6 @@
 use crate::{Color, Error, Viewport};
 
+use iced_native::futures::channel::oneshot;
 use iced_native::mouse;
 
 use raw_window_handle::HasRawWindowHandle;
@@ -53,6 +54,26 @@ pub trait Compositor: Sized {
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         overlay: &[T],
     ) -> Result<mouse::Interaction, SurfaceError>;
+
+    /// Requests the pixels of the next frame drawn by the [`Compositor`] to be
+    /// sent through the given `sender`, as RGBA bytes.
+    ///
+    /// By default, frames cannot be captured and the `sender` is dropped.
+    fn capture_next_frame(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _sender: oneshot::Sender<Vec<u8>>,
+    ) {
+    }
+
+    /// Returns whether the [`Compositor`] is still preparing some resources
+    /// in the background, and another frame should be drawn once they are
+    /// ready.
+    ///
+    /// By default, there is never any pending work.
+    fn has_pending_work(&self, _renderer: &Self::Renderer) -> bool {
+        false
+    }
 }
 
 /// Result of an unsuccessful call to [`Compositor::draw`].
@@ -0,0 +1,227 @@
+use std::time::{Duration, Instant};
+
+/// A source of time for a [`FramePacer`].
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// The [`Clock`] of the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct System;
+
+impl Clock for System {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Holds the frames presented to a surface to a maximum rate.
+///
+/// A [`FramePacer`] is consulted right before presenting every frame. When the
+/// previous frame was presented less than an interval ago, the frame is
+/// deferred until the rest of the interval has passed. The [`FramePacer`]
+/// never blocks, so the event loop keeps handling input in the meantime and
+/// wakes up at the [`deadline`](Self::deadline) instead.
+#[derive(Debug, Clone)]
+pub struct FramePacer<C: Clock = System> {
+    interval: Option<Duration>,
+    last_frame: Option<Instant>,
+    deferred_frame: Option<Instant>,
+    clock: C,
+}
+
+impl FramePacer {
+    /// Creates a new [`FramePacer`] presenting at most `max_fps` frames per
+    /// second.
+    ///
+    /// A `max_fps` of `None` or zero does not limit the frame rate.
+    pub fn new(max_fps: Option<u32>) -> Self {
+        Self::with_clock(max_fps, System)
+    }
+}
+
+impl<C: Clock> FramePacer<C> {
+    /// Creates a new [`FramePacer`] measuring time with the given [`Clock`].
+    pub fn with_clock(max_fps: Option<u32>, clock: C) -> Self {
+        Self {
+            interval: max_fps
+                .filter(|max_fps| *max_fps > 0)
+                .map(|max_fps| Duration::from_secs(1) / max_fps),
+            last_frame: None,
+            deferred_frame: None,
+            clock,
+        }
+    }
+
+    /// Returns the minimum time between two frames, if the frame rate is
+    /// limited.
+    pub fn interval(&self) -> Option<Duration> {
+        self.interval
+    }
+
+    /// Returns whether a frame can be presented now, recording it if so.
+    ///
+    /// Otherwise, the frame is deferred until the
+    /// [`deadline`](Self::deadline) of the [`FramePacer`].
+    pub fn ready(&mut self) -> bool {
+        let now = self.clock.now();
+
+        let (interval, last_frame) = match (self.interval, self.last_frame) {
+            (Some(interval), Some(last_frame)) => (interval, last_frame),
+            _ => {
+                self.last_frame = Some(now);
+
+                return true;
+            }
+        };
+
+        let next_frame = last_frame + interval;
+
+        if now < next_frame {
+            self.deferred_frame = Some(next_frame);
+
+            return false;
+        }
+
+        self.deferred_frame = None;
+
+        // Late frames restart the pacing, instead of bursting to catch up
+        self.last_frame = Some(if now < next_frame + interval {
+            next_frame
+        } else {
+            now
+        });
+
+        true
+    }
+
+    /// Returns the time at which the deferred frame can be presented, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deferred_frame
+    }
+
+    /// Returns whether the deferred frame can be presented at the given
+    /// time, forgetting about it if so.
+    ///
+    /// The caller is expected to request a redraw when it returns `true`.
+    pub fn resume(&mut self, now: Instant) -> bool {
+        match self.deferred_frame {
+            Some(deferred_frame) if deferred_frame <= now => {
+                self.deferred_frame = None;
+
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone)]
+    struct Mock {
+        now: Rc<Cell<Instant>>,
+    }
+
+    impl Mock {
+        fn new() -> Self {
+            Self {
+                now: Rc::new(Cell::new(Instant::now())),
+            }
+        }
+
+        fn advance(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    impl Clock for Mock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn frames_are_throttled_to_the_interval() {
+        let clock = Mock::new();
+        let start = clock.now();
+        let mut pacer = FramePacer::with_clock(Some(50), clock.clone());
+
+        assert_eq!(pacer.interval(), Some(Duration::from_millis(20)));
+
+        let mut presented = Vec::new();
+        let mut deferred = 0;
+
+        for _ in 0..5 {
+            while !pacer.ready() {
+                let deadline = pacer.deadline().expect("Deferred frame");
+
+                // The event loop wakes up a bit late
+                assert!(!pacer.resume(clock.now()));
+                clock.now.set(deadline + Duration::from_millis(1));
+                assert!(pacer.resume(clock.now()));
+
+                deferred += 1;
+            }
+
+            presented.push(clock.now() - start);
+
+            // Rendering a frame takes 5ms
+            clock.advance(Duration::from_millis(5));
+        }
+
+        assert_eq!(
+            presented,
+            [0, 21, 41, 61, 81]
+                .iter()
+                .map(|millis| Duration::from_millis(*millis))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(deferred, 4);
+        assert_eq!(pacer.deadline(), None);
+    }
+
+    #[test]
+    fn late_frames_are_not_deferred() {
+        let clock = Mock::new();
+        let mut pacer = FramePacer::with_clock(Some(60), clock.clone());
+
+        assert!(pacer.ready());
+        clock.advance(Duration::from_millis(100));
+
+        assert!(pacer.ready());
+        assert_eq!(pacer.deadline(), None);
+
+        // The pacing restarts from the late frame
+        clock.advance(Duration::from_millis(10));
+
+        assert!(!pacer.ready());
+        assert_eq!(
+            pacer.deadline(),
+            Some(
+                clock.now() + Duration::from_secs(1) / 60
+                    - Duration::from_millis(10)
+            )
+        );
+    }
+
+    #[test]
+    fn uncapped_pacers_never_defer() {
+        let clock = Mock::new();
+
+        for max_fps in [None, Some(0)] {
+            let mut pacer = FramePacer::with_clock(max_fps, clock.clone());
+
+            assert_eq!(pacer.interval(), None);
+            assert!(pacer.ready());
+            assert!(pacer.ready());
+            assert_eq!(pacer.deadline(), None);
+        }
+    }
+}
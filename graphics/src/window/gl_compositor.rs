@@ -1,4 +1,5 @@
 use crate::{Color, Error, Size, Viewport};
+use iced_native::futures::channel::oneshot;
 use iced_native::mouse;
 
 use core::ffi::c_void;
@@ -60,4 +61,15 @@ pub trait GLCompositor: Sized {
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         overlay: &[T],
     ) -> mouse::Interaction;
+
+    /// Requests the pixels of the next frame drawn by the [`GLCompositor`] to
+    /// be sent through the given `sender`, as RGBA bytes.
+    ///
+    /// By default, frames cannot be captured and the `sender` is dropped.
+    fn capture_next_frame(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        _sender: oneshot::Sender<Vec<u8>>,
+    ) {
+    }
 }
@@ -1,5 +1,8 @@
 //! Access the clipboard.
+use crate::image;
+
 use std::fmt;
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -9,6 +12,29 @@ pub trait Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     fn write(&mut self, contents: String);
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    ///
+    /// By default, the [`Clipboard`] does not support images and `None` is
+    /// returned.
+    fn read_image(&self) -> Option<image::Handle> {
+        None
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// By default, the [`Clipboard`] does not support images and nothing is
+    /// written.
+    fn write_image(&mut self, _handle: &image::Handle) {}
+
+    /// Reads the paths of the files in the [`Clipboard`], like the ones put
+    /// there by copying files in a file manager.
+    ///
+    /// By default, the [`Clipboard`] does not support files and no paths are
+    /// returned.
+    fn read_files(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
 }
 
 /// A null implementation of the [`Clipboard`] trait.
@@ -2,6 +2,12 @@
 mod action;
 
 pub use action::Action;
+pub use iced_futures::abort::Handle;
+
+use crate::window;
+
+use iced_futures::futures::channel::{mpsc, oneshot};
+use iced_futures::futures::stream;
 
 use std::fmt;
 use std::future::Future;
@@ -32,6 +38,80 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that performs the action of the given future,
+    /// along with a [`Handle`] that can cancel it.
+    ///
+    /// The future is aborted as soon as the [`Handle`] is dropped or
+    /// cancelled. In that case, no result will be produced.
+    pub fn perform_cancellable<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> (Command<A>, Handle)
+    where
+        A: 'static,
+    {
+        Command::perform(future, f).cancellable()
+    }
+
+    /// Creates a [`Command`] that runs the future produced by the given
+    /// function, reporting its progress as it goes.
+    ///
+    /// The function receives a sender of progress items. Every item sent is
+    /// mapped to a result with `on_progress` as soon as it arrives. Once the
+    /// future finishes and every progress item has been reported, its output
+    /// is mapped to a final result with `on_done`.
+    pub fn run_with_progress<P, F, A>(
+        f: impl FnOnce(mpsc::UnboundedSender<P>) -> F,
+        on_progress: impl Fn(P) -> A + 'static + Send,
+        on_done: impl FnOnce(T) -> A + 'static + Send,
+    ) -> Command<A>
+    where
+        F: Future<Output = T> + 'static + Send,
+        P: 'static + Send,
+        T: 'static + Send,
+        A: 'static + Send,
+    {
+        use iced_futures::futures::{future, FutureExt, StreamExt};
+
+        let (sender, receiver) = mpsc::unbounded();
+        let (done_sender, done_receiver) = oneshot::channel();
+
+        let task = f(sender).map(move |output| {
+            let _ = done_sender.send(output);
+        });
+
+        // The progress receiver ends once the task drops its sender, so every
+        // progress item is reported before the final result.
+        let progress = stream::select(
+            receiver.map(Some),
+            task.into_stream().map(|_| None),
+        )
+        .filter_map(future::ready)
+        .map(on_progress);
+
+        let done = done_receiver
+            .map(move |output| output.ok().map(on_done))
+            .into_stream()
+            .filter_map(future::ready);
+
+        Command::single(Action::Stream(Box::pin(progress.chain(done))))
+    }
+
+    /// Creates a [`Command`] that captures the pixels of the next frame drawn
+    /// to the main window, producing a result with them.
+    ///
+    /// The pixels are provided as RGBA bytes, row by row from the top, at the
+    /// physical size of the window. No result is produced if the renderer does
+    /// not support capturing frames, or if the frame cannot be read.
+    pub fn capture_frame(
+        f: impl FnOnce(Vec<u8>) -> T + 'static + Send,
+    ) -> Self {
+        Self::single(Action::Window(
+            window::Id::MAIN,
+            window::Action::CaptureFrame(Box::new(f)),
+        ))
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
@@ -49,12 +129,44 @@ impl<T> Command<T> {
     ) -> Command<A>
     where
         T: 'static,
+        A: 'static,
     {
         let Command(command) = self;
 
         Command(command.map(move |action| action.map(f.clone())))
     }
 
+    /// Makes the asynchronous actions of the [`Command`] cancellable, returning
+    /// a [`Handle`] that aborts them when dropped or cancelled.
+    ///
+    /// Any other actions, like clipboard or window actions, are left as is.
+    pub fn cancellable(self) -> (Self, Handle)
+    where
+        T: 'static,
+    {
+        use iced_futures::futures::FutureExt;
+
+        let mut streams: Vec<iced_futures::BoxStream<T>> = Vec::new();
+        let mut actions = Vec::new();
+
+        for action in self.actions() {
+            match action {
+                Action::Future(future) => {
+                    streams.push(Box::pin(future.into_stream()))
+                }
+                Action::Stream(stream) => streams.push(stream),
+                action => actions.push(Command::single(action)),
+            }
+        }
+
+        let (stream, handle) =
+            iced_futures::abort::abortable(stream::select_all(streams));
+
+        actions.push(Command::single(Action::Stream(Box::pin(stream))));
+
+        (Command::batch(actions), handle)
+    }
+
     /// Returns all of the actions of the [`Command`].
     pub fn actions(self) -> Vec<Action<T>> {
         let Command(command) = self;
@@ -1,7 +1,9 @@
 use crate::clipboard;
+use crate::event;
 use crate::window;
 
 use std::fmt;
+use std::sync::Arc;
 
 /// An action that a [`Command`] can perform.
 ///
@@ -10,11 +12,19 @@ pub enum Action<T> {
     /// Run a [`Future`] to completion.
     Future(iced_futures::BoxFuture<T>),
 
+    /// Run a [`Stream`] to completion, producing a result for every item.
+    ///
+    /// [`Stream`]: iced_futures::futures::Stream
+    Stream(iced_futures::BoxStream<T>),
+
     /// Run a clipboard action.
     Clipboard(clipboard::Action<T>),
 
-    /// Run a window action.
-    Window(window::Action),
+    /// Run an action on the window with the given [`window::Id`].
+    Window(window::Id, window::Action<T>),
+
+    /// Produce a result with an [`event::Proxy`] of the application.
+    Proxy(Box<dyn FnOnce(event::Proxy<T>) -> T + Send>),
 }
 
 impl<T> Action<T> {
@@ -22,13 +32,27 @@ impl<T> Action<T> {
     pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
     where
         T: 'static,
+        A: 'static,
     {
-        use iced_futures::futures::FutureExt;
+        use iced_futures::futures::{FutureExt, StreamExt};
 
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
+            Self::Stream(stream) => Action::Stream(Box::pin(stream.map(f))),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
-            Self::Window(window) => Action::Window(window),
+            Self::Window(id, action) => Action::Window(id, action.map(f)),
+            Self::Proxy(produce) => {
+                let f = Arc::new(f);
+
+                Action::Proxy(Box::new(move |proxy: event::Proxy<A>| {
+                    let g = f.clone();
+                    let proxy = event::Proxy::new(move |message| {
+                        proxy.send(g(message))
+                    });
+
+                    f(produce(proxy))
+                }))
+            }
         }
     }
 }
@@ -37,10 +61,14 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Future(_) => write!(f, "Action::Future"),
+            Self::Stream(_) => write!(f, "Action::Stream"),
             Self::Clipboard(action) => {
                 write!(f, "Action::Clipboard({:?})", action)
             }
-            Self::Window(action) => write!(f, "Action::Window({:?})", action),
+            Self::Window(id, action) => {
+                write!(f, "Action::Window({:?}, {:?})", id, action)
+            }
+            Self::Proxy(_) => write!(f, "Action::Proxy"),
         }
     }
 }
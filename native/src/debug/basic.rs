@@ -1,30 +1,36 @@
 #![allow(missing_docs)]
+use crate::trace;
+
 use std::{collections::VecDeque, time};
 
 /// A bunch of time measurements for debugging purposes.
+///
+/// When the `trace` feature is enabled and the [`trace::Timings`] layer is
+/// part of the current subscriber, every measurement is taken from the
+/// [`trace`] span of its stage. Otherwise, the stages time themselves.
 #[derive(Debug)]
 pub struct Debug {
     is_enabled: bool,
 
-    startup_start: time::Instant,
+    startup: Stage,
     startup_duration: time::Duration,
 
-    update_start: time::Instant,
+    update: Stage,
     update_durations: TimeBuffer,
 
-    view_start: time::Instant,
+    view: Stage,
     view_durations: TimeBuffer,
 
-    layout_start: time::Instant,
+    layout: Stage,
     layout_durations: TimeBuffer,
 
-    event_start: time::Instant,
+    event: Stage,
     event_durations: TimeBuffer,
 
-    draw_start: time::Instant,
+    draw: Stage,
     draw_durations: TimeBuffer,
 
-    render_start: time::Instant,
+    render: Stage,
     render_durations: TimeBuffer,
 
     message_count: usize,
@@ -34,29 +40,27 @@ pub struct Debug {
 impl Debug {
     /// Creates a new [`struct@Debug`].
     pub fn new() -> Self {
-        let now = time::Instant::now();
-
         Self {
             is_enabled: false,
-            startup_start: now,
+            startup: Stage::default(),
             startup_duration: time::Duration::from_secs(0),
 
-            update_start: now,
+            update: Stage::default(),
             update_durations: TimeBuffer::new(200),
 
-            view_start: now,
+            view: Stage::default(),
             view_durations: TimeBuffer::new(200),
 
-            layout_start: now,
+            layout: Stage::default(),
             layout_durations: TimeBuffer::new(200),
 
-            event_start: now,
+            event: Stage::default(),
             event_durations: TimeBuffer::new(200),
 
-            draw_start: now,
+            draw: Stage::default(),
             draw_durations: TimeBuffer::new(200),
 
-            render_start: now,
+            render: Stage::default(),
             render_durations: TimeBuffer::new(50),
 
             message_count: 0,
@@ -69,65 +73,64 @@ impl Debug {
     }
 
     pub fn startup_started(&mut self) {
-        self.startup_start = time::Instant::now();
+        self.startup.start(trace::startup());
     }
 
     pub fn startup_finished(&mut self) {
-        self.startup_duration = time::Instant::now() - self.startup_start;
+        if let Some(duration) = self.startup.finish() {
+            self.startup_duration = duration;
+        }
     }
 
-    pub fn update_started(&mut self) {
-        self.update_start = time::Instant::now();
+    pub fn update_started<Message: std::fmt::Debug>(
+        &mut self,
+        message: &Message,
+    ) {
+        self.update.start(trace::update(message));
     }
 
     pub fn update_finished(&mut self) {
-        self.update_durations
-            .push(time::Instant::now() - self.update_start);
+        self.update_durations.extend(self.update.finish());
     }
 
     pub fn view_started(&mut self) {
-        self.view_start = time::Instant::now();
+        self.view.start(trace::view());
     }
 
     pub fn view_finished(&mut self) {
-        self.view_durations
-            .push(time::Instant::now() - self.view_start);
+        self.view_durations.extend(self.view.finish());
     }
 
     pub fn layout_started(&mut self) {
-        self.layout_start = time::Instant::now();
+        self.layout.start(trace::build());
     }
 
     pub fn layout_finished(&mut self) {
-        self.layout_durations
-            .push(time::Instant::now() - self.layout_start);
+        self.layout_durations.extend(self.layout.finish());
     }
 
     pub fn event_processing_started(&mut self) {
-        self.event_start = time::Instant::now();
+        self.event.start(trace::event_processing());
     }
 
     pub fn event_processing_finished(&mut self) {
-        self.event_durations
-            .push(time::Instant::now() - self.event_start);
+        self.event_durations.extend(self.event.finish());
     }
 
     pub fn draw_started(&mut self) {
-        self.draw_start = time::Instant::now();
+        self.draw.start(trace::draw());
     }
 
     pub fn draw_finished(&mut self) {
-        self.draw_durations
-            .push(time::Instant::now() - self.draw_start);
+        self.draw_durations.extend(self.draw.finish());
     }
 
     pub fn render_started(&mut self) {
-        self.render_start = time::Instant::now();
+        self.render.start(trace::render());
     }
 
     pub fn render_finished(&mut self) {
-        self.render_durations
-            .push(time::Instant::now() - self.render_start);
+        self.render_durations.extend(self.render.finish());
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
@@ -184,6 +187,24 @@ impl Debug {
     }
 }
 
+/// A stage of a frame, measured by its [`trace::Span`] if possible.
+#[derive(Debug, Default)]
+struct Stage {
+    current: Option<(time::Instant, trace::Span)>,
+}
+
+impl Stage {
+    fn start(&mut self, span: trace::Span) {
+        self.current = Some((time::Instant::now(), span));
+    }
+
+    fn finish(&mut self) -> Option<time::Duration> {
+        let (started_at, span) = self.current.take()?;
+
+        Some(span.finish().unwrap_or_else(|| started_at.elapsed()))
+    }
+}
+
 #[derive(Debug)]
 struct TimeBuffer {
     head: usize,
@@ -206,6 +227,12 @@ impl TimeBuffer {
         self.size = (self.size + 1).min(self.contents.len());
     }
 
+    fn extend(&mut self, duration: Option<time::Duration>) {
+        if let Some(duration) = duration {
+            self.push(duration);
+        }
+    }
+
     fn average(&self) -> time::Duration {
         let sum: time::Duration = if self.size == self.contents.len() {
             self.contents[..].iter().sum()
@@ -1,39 +1,85 @@
 #![allow(missing_docs)]
-#[derive(Debug)]
-pub struct Debug;
+use crate::trace;
+
+/// The [`trace`] spans of every stage, without any time measurements.
+#[derive(Debug, Default)]
+pub struct Debug {
+    startup: trace::Span,
+    update: trace::Span,
+    view: trace::Span,
+    layout: trace::Span,
+    event: trace::Span,
+    draw: trace::Span,
+    render: trace::Span,
+}
+
+// Without the `trace` feature, this is as cheap as no debugging at all
+#[cfg(not(feature = "trace"))]
+const _: [(); 0] = [(); std::mem::size_of::<Debug>()];
 
 impl Debug {
     pub fn new() -> Self {
-        Self
+        Self::default()
     }
 
-    pub fn startup_started(&mut self) {}
+    pub fn startup_started(&mut self) {
+        self.startup = trace::startup();
+    }
 
-    pub fn startup_finished(&mut self) {}
+    pub fn startup_finished(&mut self) {
+        self.startup = trace::Span::none();
+    }
 
-    pub fn update_started(&mut self) {}
+    pub fn update_started<Message: std::fmt::Debug>(
+        &mut self,
+        message: &Message,
+    ) {
+        self.update = trace::update(message);
+    }
 
-    pub fn update_finished(&mut self) {}
+    pub fn update_finished(&mut self) {
+        self.update = trace::Span::none();
+    }
 
-    pub fn view_started(&mut self) {}
+    pub fn view_started(&mut self) {
+        self.view = trace::view();
+    }
 
-    pub fn view_finished(&mut self) {}
+    pub fn view_finished(&mut self) {
+        self.view = trace::Span::none();
+    }
 
-    pub fn layout_started(&mut self) {}
+    pub fn layout_started(&mut self) {
+        self.layout = trace::build();
+    }
 
-    pub fn layout_finished(&mut self) {}
+    pub fn layout_finished(&mut self) {
+        self.layout = trace::Span::none();
+    }
 
-    pub fn event_processing_started(&mut self) {}
+    pub fn event_processing_started(&mut self) {
+        self.event = trace::event_processing();
+    }
 
-    pub fn event_processing_finished(&mut self) {}
+    pub fn event_processing_finished(&mut self) {
+        self.event = trace::Span::none();
+    }
 
-    pub fn draw_started(&mut self) {}
+    pub fn draw_started(&mut self) {
+        self.draw = trace::draw();
+    }
 
-    pub fn draw_finished(&mut self) {}
+    pub fn draw_finished(&mut self) {
+        self.draw = trace::Span::none();
+    }
 
-    pub fn render_started(&mut self) {}
+    pub fn render_started(&mut self) {
+        self.render = trace::render();
+    }
 
-    pub fn render_finished(&mut self) {}
+    pub fn render_finished(&mut self) {
+        self.render = trace::Span::none();
+    }
 
     pub fn log_message<Message: std::fmt::Debug>(
         &mut self,
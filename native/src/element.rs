@@ -1,9 +1,8 @@
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
-use crate::{
-    Clipboard, Color, Hasher, Layout, Length, Point, Rectangle, Widget,
-};
+use crate::{Clipboard, Color, Hasher, Layout, Length, Rectangle, Widget};
 
 /// A generic [`Widget`].
 ///
@@ -17,6 +16,7 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
     pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    type_name: &'static str,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -28,6 +28,7 @@ where
         widget: impl Widget<Message, Renderer> + 'a,
     ) -> Element<'a, Message, Renderer> {
         Element {
+            type_name: type_name_of(&widget),
             widget: Box::new(widget),
         }
     }
@@ -174,6 +175,7 @@ where
     {
         Element {
             widget: Box::new(Map::new(self.widget, f)),
+            type_name: self.type_name,
         }
     }
 
@@ -191,8 +193,11 @@ where
         Message: 'static,
         Renderer: 'a + layout::Debugger,
     {
+        let type_name = self.type_name;
+
         Element {
             widget: Box::new(Explain::new(self, color.into())),
+            type_name,
         }
     }
 
@@ -214,7 +219,10 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        let mut node = self.widget.layout(renderer, limits);
+        node.set_widget(self.type_name);
+
+        node
     }
 
     /// Processes a runtime [`Event`].
@@ -222,19 +230,13 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
-        self.widget.on_event(
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        )
+    ) -> event::Status<Message> {
+        self.widget
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
     }
 
     /// Draws the [`Element`] and its children using the given [`Layout`].
@@ -243,11 +245,11 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         self.widget
-            .draw(renderer, defaults, layout, cursor_position, viewport)
+            .draw(renderer, defaults, layout, cursor, viewport)
     }
 
     /// Computes the _layout_ hash of the [`Element`].
@@ -264,6 +266,10 @@ where
     }
 }
 
+fn type_name_of<T>(_: &T) -> &'static str {
+    std::any::type_name::<T>()
+}
+
 struct Map<'a, A, B, Renderer> {
     widget: Box<dyn Widget<A, Renderer> + 'a>,
     mapper: Box<dyn Fn(A) -> B>,
@@ -310,17 +316,17 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<B>,
-    ) -> event::Status {
+    ) -> event::Status<B> {
         let mut original_messages = Vec::new();
 
         let status = self.widget.on_event(
             event,
             layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             &mut original_messages,
@@ -330,7 +336,7 @@ where
             .drain(..)
             .for_each(|message| messages.push((self.mapper)(message)));
 
-        status
+        status.map(&self.mapper)
     }
 
     fn draw(
@@ -338,11 +344,11 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         self.widget
-            .draw(renderer, defaults, layout, cursor_position, viewport)
+            .draw(renderer, defaults, layout, cursor, viewport)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -400,19 +406,14 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
-        self.element.widget.on_event(
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        )
+    ) -> event::Status<Message> {
+        self.element
+            .widget
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
     }
 
     fn draw(
@@ -420,14 +421,14 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         renderer.explain(
             defaults,
             self.element.widget.as_ref(),
             layout,
-            cursor_position,
+            cursor,
             viewport,
             self.color,
         )
@@ -4,6 +4,12 @@ use crate::mouse;
 use crate::touch;
 use crate::window;
 
+mod proxy;
+
+pub use proxy::{proxy, Proxy};
+
+use std::convert::Infallible;
+
 /// A user interface event.
 ///
 /// _**Note:** This type is largely incomplete! If you need to track
@@ -47,13 +53,25 @@ pub enum MacOS {
 }
 
 /// The status of an [`Event`] after being processed.
+///
+/// A widget capturing an [`Event`] can also produce a `Message` in the same
+/// value, by returning [`Status::CapturedWithFeedback`] from
+/// [`Widget::on_event`]. The feedback ends up with the rest of the messages
+/// produced by the user interface. A [`Status`] without a `Message` type can
+/// not carry any feedback.
+///
+/// [`Widget::on_event`]: crate::Widget::on_event
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Status {
+pub enum Status<Message = Infallible> {
     /// The [`Event`] was **NOT** handled by any widget.
     Ignored,
 
     /// The [`Event`] was handled and processed by a widget.
     Captured,
+
+    /// The [`Event`] was handled and processed by a widget, which produced
+    /// the given `Message` as a result.
+    CapturedWithFeedback(Message),
 }
 
 impl Status {
@@ -62,17 +80,99 @@ impl Status {
     /// `Captured` takes precedence over `Ignored`:
     ///
     /// ```
-    /// use iced_native::event::Status;
+    /// type Status = iced_native::event::Status;
     ///
     /// assert_eq!(Status::Ignored.merge(Status::Ignored), Status::Ignored);
     /// assert_eq!(Status::Ignored.merge(Status::Captured), Status::Captured);
     /// assert_eq!(Status::Captured.merge(Status::Ignored), Status::Captured);
     /// assert_eq!(Status::Captured.merge(Status::Captured), Status::Captured);
     /// ```
+    ///
+    /// Only a [`Status`] without feedback can be merged, so no feedback is
+    /// ever lost. Use [`Status::push_feedback`] first.
     pub fn merge(self, b: Self) -> Self {
         match self {
             Status::Ignored => b,
             Status::Captured => Status::Captured,
+            Status::CapturedWithFeedback(never) => match never {},
+        }
+    }
+
+    /// Turns a [`Status`] without feedback into a [`Status`] that could carry
+    /// a `Message`.
+    ///
+    /// This is useful to return a merged [`Status`] from
+    /// [`Widget::on_event`].
+    ///
+    /// [`Widget::on_event`]: crate::Widget::on_event
+    pub fn cast<Message>(self) -> Status<Message> {
+        match self {
+            Status::Ignored => Status::Ignored,
+            Status::Captured => Status::Captured,
+            Status::CapturedWithFeedback(never) => match never {},
+        }
+    }
+}
+
+impl<Message> Status<Message> {
+    /// Returns whether the [`Event`] was captured, with or without feedback.
+    pub fn is_captured(&self) -> bool {
+        !matches!(self, Status::Ignored)
+    }
+
+    /// Applies a function to the feedback of the [`Status`], if any.
+    pub fn map<B>(self, f: impl FnOnce(Message) -> B) -> Status<B> {
+        match self {
+            Status::Ignored => Status::Ignored,
+            Status::Captured => Status::Captured,
+            Status::CapturedWithFeedback(message) => {
+                Status::CapturedWithFeedback(f(message))
+            }
         }
     }
+
+    /// Pushes the feedback of the [`Status`], if any, to the given list of
+    /// messages, returning a [`Status`] without feedback.
+    ///
+    /// ```
+    /// use iced_native::event::Status;
+    ///
+    /// let mut messages = Vec::new();
+    ///
+    /// let status = Status::CapturedWithFeedback("Pressed!");
+    ///
+    /// assert_eq!(status.push_feedback(&mut messages), Status::Captured);
+    /// assert_eq!(messages, vec!["Pressed!"]);
+    /// ```
+    pub fn push_feedback(self, messages: &mut Vec<Message>) -> Status {
+        match self {
+            Status::Ignored => Status::Ignored,
+            Status::Captured => Status::Captured,
+            Status::CapturedWithFeedback(message) => {
+                messages.push(message);
+
+                Status::Captured
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_feedback_is_pushed_before_merging() {
+        let mut messages = Vec::new();
+
+        let status = Status::CapturedWithFeedback(1)
+            .push_feedback(&mut messages)
+            .merge(
+                Status::CapturedWithFeedback(2).push_feedback(&mut messages),
+            );
+
+        assert_eq!(status, Status::Captured);
+        assert_eq!(messages, vec![1, 2]);
+        assert_eq!(Status::Ignored.cast::<u32>(), Status::Ignored);
+    }
 }
@@ -0,0 +1,96 @@
+use crate::command::{self, Command};
+
+use std::fmt;
+use std::sync::Arc;
+
+/// A handle that sends messages to a running application from anywhere,
+/// including other threads.
+///
+/// Every message sent through a [`Proxy`] wakes up the event loop and is fed
+/// straight to the `update` logic of the application, like the result of a
+/// [`Command`].
+///
+/// A [`Proxy`] does not keep the application alive. Once the application
+/// exits, sending a message does nothing and returns `false`.
+pub struct Proxy<Message> {
+    send: Arc<dyn Fn(Message) -> bool + Send + Sync>,
+}
+
+impl<Message> Proxy<Message> {
+    /// Creates a new [`Proxy`] that delivers messages with the given
+    /// function.
+    ///
+    /// The function must return `false` when the message can no longer be
+    /// delivered. You only need this if you are implementing a runtime.
+    pub fn new(send: impl Fn(Message) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            send: Arc::new(send),
+        }
+    }
+
+    /// Sends a message to the application.
+    ///
+    /// It returns `false` if the application has already exited.
+    pub fn send(&self, message: Message) -> bool {
+        (self.send)(message)
+    }
+}
+
+impl<Message> Clone for Proxy<Message> {
+    fn clone(&self) -> Self {
+        Self {
+            send: self.send.clone(),
+        }
+    }
+}
+
+impl<Message> fmt::Debug for Proxy<Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Proxy").finish()
+    }
+}
+
+/// Creates a [`Command`] that hands a [`Proxy`] of the application to the
+/// given function, producing a message with it.
+///
+/// The message is usually used to store the [`Proxy`] or to move it to
+/// another thread.
+pub fn proxy<Message>(
+    f: impl FnOnce(Proxy<Message>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Proxy(Box::new(f)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::mpsc;
+
+    #[test]
+    fn proxies_send_messages_from_other_threads() {
+        let (sender, receiver) = mpsc::channel();
+        let sender = std::sync::Mutex::new(sender);
+
+        let proxy = Proxy::new(move |message: u32| {
+            sender.lock().unwrap().send(message).is_ok()
+        });
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let proxy = proxy.clone();
+
+                std::thread::spawn(move || proxy.send(i))
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+
+        let mut received: Vec<_> = receiver.try_iter().collect();
+        received.sort_unstable();
+
+        assert_eq!(received, [0, 1, 2, 3]);
+    }
+}
@@ -1,10 +1,51 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher as _};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::Once;
+
 /// The hasher used to compare layouts.
+///
+/// It is a 64-bit [xxHash] seeded with a random value chosen once per
+/// process. The hashes it produces are stable for the whole lifetime of the
+/// process, which is all the layout cache of a
+/// [`UserInterface`](crate::UserInterface) needs, but they should not be
+/// stored or compared across processes.
+///
+/// Seeding makes it impossible to craft two views colliding on purpose.
+/// Accidental collisions are still possible, albeit unlikely. The
+/// `layout-fingerprint` feature lays out every cached layout again and
+/// compares the bounds of its nodes, detecting them as well as the widgets
+/// that leave something out of their hash.
+///
+/// [xxHash]: https://cyan4973.github.io/xxHash/
 #[derive(Debug)]
 pub struct Hasher(twox_hash::XxHash64);
 
+impl Hasher {
+    /// Creates a new [`Hasher`] with the given seed, instead of the seed of
+    /// the process.
+    pub fn with_seed(seed: u64) -> Self {
+        Hasher(twox_hash::XxHash64::with_seed(seed))
+    }
+
+    /// Returns the seed of the process, used by the default [`Hasher`].
+    pub fn process_seed() -> u64 {
+        static SEED: AtomicU64 = AtomicU64::new(0);
+        static INIT: Once = Once::new();
+
+        INIT.call_once(|| {
+            let seed = RandomState::new().build_hasher().finish();
+
+            SEED.store(seed, atomic::Ordering::Relaxed);
+        });
+
+        SEED.load(atomic::Ordering::Relaxed)
+    }
+}
+
 impl Default for Hasher {
     fn default() -> Self {
-        Hasher(twox_hash::XxHash64::default())
+        Hasher::with_seed(Hasher::process_seed())
     }
 }
 
@@ -17,3 +58,26 @@ impl core::hash::Hasher for Hasher {
         self.0.finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::Hash;
+
+    #[test]
+    fn default_hashers_agree_within_a_process() {
+        let hash = |seed: Option<u64>| {
+            let mut hasher = match seed {
+                Some(seed) => Hasher::with_seed(seed),
+                None => Hasher::default(),
+            };
+
+            "layout".hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(hash(None), hash(None));
+        assert_eq!(hash(None), hash(Some(Hasher::process_seed())));
+        assert_ne!(hash(Some(0)), hash(Some(1)));
+    }
+}
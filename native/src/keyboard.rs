@@ -1,2 +1,5 @@
 //! Track keyboard events.
+mod tracker;
+
 pub use iced_core::keyboard::*;
+pub use tracker::Tracker;
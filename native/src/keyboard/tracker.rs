@@ -0,0 +1,331 @@
+use crate::keyboard::{self, KeyCode, KeyLocation, Modifiers, ScanCode};
+use crate::{window, Event};
+
+use std::collections::HashSet;
+
+/// A tracker of the keys pressed in a window.
+///
+/// Windowing shells, like `winit`, report the text produced by a key
+/// separately, after the key itself, and they do not tell repeated presses
+/// apart. A [`Tracker`] marks the repeated [`keyboard::Event::KeyPressed`]
+/// events and produces a [`keyboard::Event::Input`] for every key press, with
+/// its text.
+///
+/// Text received without a key press, like the result of a compose sequence
+/// or a key unknown to the shell, produces a [`keyboard::Event::Input`] of its
+/// own, with an [`KeyCode::Unlabeled`] key.
+#[derive(Debug, Clone, Default)]
+pub struct Tracker {
+    pressed: HashSet<ScanCode>,
+    modifiers: Modifiers,
+}
+
+#[derive(Debug, Clone)]
+struct Press {
+    key: KeyCode,
+    physical_key: ScanCode,
+    location: KeyLocation,
+    modifiers: Modifiers,
+    repeat: bool,
+    text: String,
+    /// The index of the [`keyboard::Event::KeyPressed`] of the press in the
+    /// completed events, if there is one.
+    index: Option<usize>,
+}
+
+impl Tracker {
+    /// Creates a new [`Tracker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Completes the keyboard events in the given batch of events.
+    ///
+    /// The batch must contain every event of its key presses, like the events
+    /// received before a `MainEventsCleared` does. The
+    /// [`keyboard::Event::Input`] of a key press is placed after its
+    /// characters.
+    ///
+    /// A key press followed by its [`keyboard::Event::Input`] already is left
+    /// as is, so completing some events twice is harmless.
+    pub fn complete(&mut self, events: &mut Vec<Event>) {
+        let mut completed = Vec::with_capacity(events.len());
+        let mut press: Option<Press> = None;
+
+        for event in events.drain(..) {
+            match &event {
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    physical_key,
+                    location,
+                    modifiers,
+                    repeat,
+                }) => {
+                    finish(press.take(), &mut completed);
+
+                    let is_held = !self.pressed.insert(*physical_key);
+
+                    press = Some(Press {
+                        key: *key_code,
+                        physical_key: *physical_key,
+                        location: *location,
+                        modifiers: *modifiers,
+                        repeat: *repeat || is_held,
+                        text: String::new(),
+                        index: Some(completed.len()),
+                    });
+                }
+                Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+                    press
+                        .get_or_insert_with(|| Press {
+                            key: KeyCode::Unlabeled,
+                            physical_key: ScanCode(0),
+                            location: KeyLocation::Standard,
+                            modifiers: self.modifiers,
+                            repeat: false,
+                            text: String::new(),
+                            index: None,
+                        })
+                        .text
+                        .push(*c);
+                }
+                // The press was completed already
+                Event::Keyboard(keyboard::Event::Input { .. }) => {
+                    press = None;
+                }
+                Event::Keyboard(keyboard::Event::KeyReleased {
+                    physical_key,
+                    ..
+                }) => {
+                    finish(press.take(), &mut completed);
+
+                    let _ = self.pressed.remove(physical_key);
+                }
+                Event::Keyboard(keyboard::Event::ModifiersChanged(
+                    modifiers,
+                )) => {
+                    self.modifiers = *modifiers;
+                }
+                // The keys released while unfocused are never reported
+                Event::Window(window::Event::Unfocused) => {
+                    self.pressed.clear();
+                }
+                _ => {}
+            }
+
+            completed.push(event);
+        }
+
+        finish(press, &mut completed);
+
+        *events = completed;
+    }
+}
+
+/// Marks the [`keyboard::Event::KeyPressed`] of a pending press as repeated,
+/// if necessary, and produces its [`keyboard::Event::Input`].
+fn finish(press: Option<Press>, completed: &mut Vec<Event>) {
+    let press = match press {
+        Some(press) => press,
+        None => return,
+    };
+
+    if let Some(Event::Keyboard(keyboard::Event::KeyPressed {
+        repeat, ..
+    })) = press.index.and_then(|index| completed.get_mut(index))
+    {
+        *repeat = press.repeat;
+    }
+
+    completed.push(Event::Keyboard(keyboard::Event::Input {
+        key: press.key,
+        physical_key: press.physical_key,
+        location: press.location,
+        text: Some(press.text)
+            .filter(|text| !text.is_empty())
+            .map(keyboard::SmolStr::new),
+        modifiers: press.modifiers,
+        repeat: press.repeat,
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(key_code: KeyCode, scan_code: u32, is_pressed: bool) -> Event {
+        let physical_key = ScanCode(scan_code);
+        let location = KeyLocation::from(key_code);
+        let modifiers = Modifiers::empty();
+
+        Event::Keyboard(if is_pressed {
+            keyboard::Event::KeyPressed {
+                key_code,
+                physical_key,
+                location,
+                modifiers,
+                repeat: false,
+            }
+        } else {
+            keyboard::Event::KeyReleased {
+                key_code,
+                physical_key,
+                location,
+                modifiers,
+            }
+        })
+    }
+
+    fn inputs(events: &[Event]) -> Vec<(KeyCode, Option<&str>, bool)> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Keyboard(keyboard::Event::Input {
+                    key,
+                    text,
+                    repeat,
+                    ..
+                }) => Some((*key, text.as_deref(), *repeat)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn key_presses_are_completed_with_their_text() {
+        let mut tracker = Tracker::new();
+
+        let mut events = vec![
+            key(KeyCode::LShift, 42, true),
+            key(KeyCode::A, 30, true),
+            Event::Keyboard(keyboard::Event::CharacterReceived('A')),
+            key(KeyCode::A, 30, false),
+            key(KeyCode::LShift, 42, false),
+        ];
+
+        tracker.complete(&mut events);
+
+        assert_eq!(
+            inputs(&events),
+            vec![
+                (KeyCode::LShift, None, false),
+                (KeyCode::A, Some("A"), false)
+            ]
+        );
+
+        // The input of a key follows its characters
+        assert!(matches!(
+            events[4],
+            Event::Keyboard(keyboard::Event::Input {
+                key: KeyCode::A,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn held_keys_are_repeated() {
+        let mut tracker = Tracker::new();
+
+        for batch in 0..3 {
+            let mut events = vec![
+                key(KeyCode::Q, 16, true),
+                Event::Keyboard(keyboard::Event::CharacterReceived('a')),
+            ];
+
+            tracker.complete(&mut events);
+
+            assert_eq!(
+                inputs(&events),
+                vec![(KeyCode::Q, Some("a"), batch > 0)]
+            );
+            assert!(matches!(
+                events[0],
+                Event::Keyboard(keyboard::Event::KeyPressed { repeat, .. })
+                    if repeat == (batch > 0)
+            ));
+        }
+
+        let mut events = vec![
+            key(KeyCode::Q, 16, false),
+            key(KeyCode::Q, 16, true),
+            Event::Window(window::Event::Unfocused),
+            key(KeyCode::Q, 16, true),
+        ];
+
+        tracker.complete(&mut events);
+
+        assert_eq!(
+            inputs(&events),
+            vec![(KeyCode::Q, None, false), (KeyCode::Q, None, false)]
+        );
+    }
+
+    #[test]
+    fn completed_events_are_left_as_is() {
+        let mut tracker = Tracker::new();
+
+        let mut events = vec![
+            key(KeyCode::A, 30, true),
+            Event::Keyboard(keyboard::Event::CharacterReceived('a')),
+        ];
+
+        tracker.complete(&mut events);
+
+        let completed = events.clone();
+
+        tracker.complete(&mut events);
+
+        assert_eq!(events, completed);
+        assert_eq!(inputs(&events), vec![(KeyCode::A, Some("a"), false)]);
+    }
+
+    #[test]
+    fn text_without_a_key_press_is_an_input_of_its_own() {
+        let mut tracker = Tracker::new();
+
+        // A key unknown to the shell, followed by a compose sequence
+        let mut events = vec![
+            Event::Keyboard(keyboard::Event::CharacterReceived('ß')),
+            key(KeyCode::RAlt, 100, true),
+            key(KeyCode::RAlt, 100, false),
+            Event::Keyboard(keyboard::Event::CharacterReceived('é')),
+        ];
+
+        tracker.complete(&mut events);
+
+        assert_eq!(
+            inputs(&events),
+            vec![
+                (KeyCode::Unlabeled, Some("ß"), false),
+                (KeyCode::RAlt, None, false),
+                (KeyCode::Unlabeled, Some("é"), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn partially_completed_batches_are_completed() {
+        let mut tracker = Tracker::new();
+
+        let mut events = vec![
+            key(KeyCode::A, 30, true),
+            Event::Keyboard(keyboard::Event::CharacterReceived('a')),
+        ];
+
+        tracker.complete(&mut events);
+
+        events.push(key(KeyCode::B, 48, true));
+        events.push(Event::Keyboard(keyboard::Event::CharacterReceived('b')));
+
+        tracker.complete(&mut events);
+
+        assert_eq!(
+            inputs(&events),
+            vec![
+                (KeyCode::A, Some("a"), false),
+                (KeyCode::B, Some("b"), false)
+            ]
+        );
+    }
+}
@@ -1,13 +1,17 @@
 //! Position your widgets properly.
 mod debugger;
+mod edge_insets;
 mod limits;
 mod node;
+mod snapshot;
 
 pub mod flex;
 
 pub use debugger::Debugger;
+pub use edge_insets::EdgeInsets;
 pub use limits::Limits;
 pub use node::Node;
+pub use snapshot::{BoundsEntry, Snapshot};
 
 use crate::{Point, Rectangle, Vector};
 
@@ -55,6 +59,10 @@ impl<'a> Layout<'a> {
         }
     }
 
+    pub(crate) fn node(&self) -> &'a Node {
+        self.node
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
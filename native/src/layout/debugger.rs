@@ -1,4 +1,5 @@
-use crate::{Color, Layout, Point, Rectangle, Renderer, Widget};
+use crate::mouse;
+use crate::{Color, Layout, Rectangle, Renderer, Widget};
 
 /// A renderer able to graphically explain a [`Layout`].
 pub trait Debugger: Renderer {
@@ -17,7 +18,7 @@ pub trait Debugger: Renderer {
         defaults: &Self::Defaults,
         widget: &dyn Widget<Message, Self>,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         color: Color,
     ) -> Self::Output;
@@ -0,0 +1,42 @@
+use crate::Point;
+
+/// The distances from each edge of a window that its content should avoid.
+///
+/// On devices with camera notches, rounded corners, or system bars drawn on
+/// top of the window, the hardware reports these distances as a safe area.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EdgeInsets {
+    /// Top inset
+    pub top: f32,
+    /// Bottom inset
+    pub bottom: f32,
+    /// Left inset
+    pub left: f32,
+    /// Right inset
+    pub right: f32,
+}
+
+impl EdgeInsets {
+    /// No insets.
+    pub const ZERO: EdgeInsets = EdgeInsets {
+        top: 0.0,
+        bottom: 0.0,
+        left: 0.0,
+        right: 0.0,
+    };
+
+    /// Returns the total amount of horizontal [`EdgeInsets`].
+    pub fn horizontal(self) -> f32 {
+        self.left + self.right
+    }
+
+    /// Returns the total amount of vertical [`EdgeInsets`].
+    pub fn vertical(self) -> f32 {
+        self.top + self.bottom
+    }
+
+    /// Returns the origin of the safe area delimited by the [`EdgeInsets`].
+    pub fn origin(self) -> Point {
+        Point::new(self.left, self.top)
+    }
+}
@@ -1,7 +1,8 @@
+use crate::layout::EdgeInsets;
 use crate::{Length, Padding, Size};
 
 /// A set of size constraints for layouting.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Limits {
     min: Size,
     max: Size,
@@ -124,6 +125,14 @@ impl Limits {
         ))
     }
 
+    /// Shrinks the current [`Limits`] to the safe area delimited by the given
+    /// [`EdgeInsets`].
+    ///
+    /// The resulting layout must be moved to [`EdgeInsets::origin`].
+    pub fn safe_area(&self, insets: EdgeInsets) -> Limits {
+        self.shrink(Size::new(insets.horizontal(), insets.vertical()))
+    }
+
     /// Shrinks the current [`Limits`] by the given [`Size`].
     pub fn shrink(&self, size: Size) -> Limits {
         let min = Size::new(
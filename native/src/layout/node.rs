@@ -5,6 +5,7 @@ use crate::{Alignment, Point, Rectangle, Size};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    widget: Option<&'static str>,
 }
 
 impl Node {
@@ -23,6 +24,7 @@ impl Node {
                 height: size.height,
             },
             children,
+            widget: None,
         }
     }
 
@@ -41,6 +43,18 @@ impl Node {
         &self.children
     }
 
+    /// Returns the type name of the widget laid out by the [`Node`], if it
+    /// was produced by an [`Element`].
+    ///
+    /// [`Element`]: crate::Element
+    pub fn widget(&self) -> Option<&'static str> {
+        self.widget
+    }
+
+    pub(crate) fn set_widget(&mut self, type_name: &'static str) {
+        self.widget = Some(type_name);
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         &mut self,
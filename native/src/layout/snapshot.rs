@@ -0,0 +1,166 @@
+use crate::layout::Layout;
+use crate::{Point, Rectangle};
+
+use std::fmt;
+
+/// An immutable copy of the layout of a user interface, with absolute bounds.
+///
+/// It can be obtained with [`UserInterface::layout_snapshot`] to find which
+/// widget ended up where, in tests or in custom inspection tools. It is
+/// computed by the layout pass, so any renderer works, including
+/// [`renderer::Null`].
+///
+/// Its [`Display`](fmt::Display) implementation writes a compact text form,
+/// one widget per line, indented by depth:
+///
+/// ```text
+/// Column (0, 0) 400x60
+///   Text (0, 0) 400x20
+///   Row (0, 20) 400x40
+/// ```
+///
+/// [`UserInterface::layout_snapshot`]: crate::UserInterface::layout_snapshot
+/// [`renderer::Null`]: crate::renderer::Null
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    roots: Vec<BoundsEntry>,
+}
+
+/// The absolute bounds of a widget in a [`Snapshot`], and its children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundsEntry {
+    widget: &'static str,
+    bounds: Rectangle,
+    children: Vec<BoundsEntry>,
+}
+
+impl Snapshot {
+    /// Takes a [`Snapshot`] of the given [`Layout`] trees, from bottom to top.
+    pub fn new<'a>(layouts: impl IntoIterator<Item = Layout<'a>>) -> Self {
+        Snapshot {
+            roots: layouts.into_iter().map(BoundsEntry::new).collect(),
+        }
+    }
+
+    /// Returns the roots of the [`Snapshot`], from bottom to top.
+    ///
+    /// The first one is the root widget. The second one, if any, is the
+    /// overlay drawn on top of it.
+    pub fn roots(&self) -> &[BoundsEntry] {
+        &self.roots
+    }
+
+    /// Returns all the entries of the [`Snapshot`] containing the given
+    /// [`Point`], from the topmost to the bottommost.
+    pub fn find_at(&self, point: Point) -> Vec<&BoundsEntry> {
+        let mut entries = Vec::new();
+
+        for root in &self.roots {
+            root.visit(&mut |entry| {
+                if entry.bounds.contains(point) {
+                    entries.push(entry);
+                }
+            });
+        }
+
+        entries.reverse();
+        entries
+    }
+
+    /// Returns all the entries of the [`Snapshot`] laying out a widget with
+    /// the given short type name (like `"Button"`), in tree order.
+    pub fn find(&self, widget: &str) -> Vec<&BoundsEntry> {
+        let mut entries = Vec::new();
+
+        for root in &self.roots {
+            root.visit(&mut |entry| {
+                if entry.widget() == widget {
+                    entries.push(entry);
+                }
+            });
+        }
+
+        entries
+    }
+}
+
+impl BoundsEntry {
+    fn new(layout: Layout<'_>) -> Self {
+        BoundsEntry {
+            widget: layout.node().widget().unwrap_or(""),
+            bounds: layout.bounds(),
+            children: layout.children().map(BoundsEntry::new).collect(),
+        }
+    }
+
+    /// Returns the short type name of the widget of the [`BoundsEntry`],
+    /// without its module path and generic parameters.
+    ///
+    /// It is empty when the [`Node`] was not produced by an [`Element`].
+    ///
+    /// [`Node`]: crate::layout::Node
+    /// [`Element`]: crate::Element
+    pub fn widget(&self) -> &'static str {
+        let name = self.widget.split('<').next().unwrap_or_default();
+
+        name.rsplit("::").next().unwrap_or_default()
+    }
+
+    /// Returns the full type name of the widget of the [`BoundsEntry`].
+    pub fn type_name(&self) -> &'static str {
+        self.widget
+    }
+
+    /// Returns the absolute bounds of the [`BoundsEntry`].
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Returns the children of the [`BoundsEntry`].
+    pub fn children(&self) -> &[BoundsEntry] {
+        &self.children
+    }
+
+    fn visit<'a>(&'a self, f: &mut impl FnMut(&'a BoundsEntry)) {
+        f(self);
+
+        for child in &self.children {
+            child.visit(f);
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let widget = match self.widget() {
+            "" => "?",
+            widget => widget,
+        };
+
+        writeln!(
+            f,
+            "{:indent$}{} ({}, {}) {}x{}",
+            "",
+            widget,
+            self.bounds.x,
+            self.bounds.y,
+            self.bounds.width,
+            self.bounds.height,
+            indent = depth * 2,
+        )?;
+
+        for child in &self.children {
+            child.write(f, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for root in &self.roots {
+            root.write(f, 0)?;
+        }
+
+        Ok(())
+    }
+}
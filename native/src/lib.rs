@@ -39,11 +39,13 @@ pub mod event;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
+pub mod numeric;
 pub mod overlay;
 pub mod program;
 pub mod renderer;
 pub mod subscription;
 pub mod touch;
+pub mod trace;
 pub mod widget;
 pub mod window;
 
@@ -63,8 +65,9 @@ mod debug;
 
 pub use iced_core::alignment;
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, BorderAlignment, BorderStyle, BorderWidth, Color,
+    ContentFit, Font, Length, Padding, Point, Rectangle, Shadow, Size,
+    TextShadow, Vector, WritingMode,
 };
 pub use iced_futures::{executor, futures};
 
@@ -78,10 +81,11 @@ pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
 pub use layout::Layout;
+pub use numeric::Numeric;
 pub use overlay::Overlay;
 pub use program::Program;
 pub use renderer::Renderer;
 pub use runtime::Runtime;
 pub use subscription::Subscription;
-pub use user_interface::{Cache, UserInterface};
+pub use user_interface::{Cache, InterfaceStats, UserInterface};
 pub use widget::*;
@@ -56,6 +56,20 @@ impl Click {
         }
     }
 
+    /// Creates a new double [`Click`] with the given position.
+    ///
+    /// It is useful to track a [`mouse::Event::DoubleClicked`], which the
+    /// next consecutive [`Click`] turns into a triple click.
+    ///
+    /// [`mouse::Event::DoubleClicked`]: crate::mouse::Event::DoubleClicked
+    pub fn double(position: Point) -> Click {
+        Click {
+            kind: Kind::Double,
+            position,
+            time: Instant::now(),
+        }
+    }
+
     /// Returns the [`Kind`] of [`Click`].
     pub fn kind(&self) -> Kind {
         self.kind
@@ -0,0 +1,75 @@
+//! Convert the values of numeric widgets.
+
+/// A number that can be the value of a [`Slider`] or a [`ProgressBar`].
+///
+/// Values are converted through `f64` to compute positions. Integers are
+/// rounded when converted back, so they always land on an exact value.
+///
+/// [`Slider`]: crate::Slider
+/// [`ProgressBar`]: crate::ProgressBar
+pub trait Numeric: Copy + PartialOrd {
+    /// The number one, used as the default step of a [`Slider`].
+    ///
+    /// [`Slider`]: crate::Slider
+    const ONE: Self;
+
+    /// Converts the number into an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` into the closest number, saturating at the bounds
+    /// of its type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! float {
+    ($($type:ty),*) => {
+        $(
+            impl Numeric for $type {
+                const ONE: Self = 1.0;
+
+                fn to_f64(self) -> f64 {
+                    f64::from(self)
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $type
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! integer {
+    ($($type:ty),*) => {
+        $(
+            impl Numeric for $type {
+                const ONE: Self = 1;
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    // Casting saturates, and turns NaN into zero
+                    value.round() as $type
+                }
+            }
+        )*
+    };
+}
+
+float!(f32, f64);
+integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_are_rounded_and_saturated() {
+        assert_eq!(u8::from_f64(2.6), 3);
+        assert_eq!(u8::from_f64(300.0), u8::MAX);
+        assert_eq!(i32::from_f64(-2.5), -3);
+        assert_eq!(u64::from_f64(10_000_000_000.0), 10_000_000_000);
+    }
+}
@@ -8,6 +8,7 @@ pub use menu::Menu;
 
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::{Clipboard, Hasher, Layout, Point, Size};
 
 /// An interactive component that can be displayed on top of other widgets.
@@ -34,7 +35,7 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> Renderer::Output;
 
     /// Computes the _layout_ hash of the [`Overlay`].
@@ -55,7 +56,7 @@ where
     /// It receives:
     ///   * an [`Event`] describing user interaction
     ///   * the computed [`Layout`] of the [`Overlay`]
-    ///   * the current cursor position
+    ///   * the current [`mouse::Cursor`]
     ///   * a mutable `Message` list, allowing the [`Overlay`] to produce
     ///   new messages based on user interaction.
     ///   * the `Renderer`
@@ -66,11 +67,11 @@ where
         &mut self,
         _event: Event,
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         event::Status::Ignored
     }
 }
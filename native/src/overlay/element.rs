@@ -2,6 +2,7 @@ pub use crate::Overlay;
 
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::{Clipboard, Hasher, Layout, Point, Size, Vector};
 
 /// A generic [`Overlay`].
@@ -52,19 +53,13 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
-        self.overlay.on_event(
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        )
+    ) -> event::Status<Message> {
+        self.overlay
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
     }
 
     /// Draws the [`Element`] and its children using the given [`Layout`].
@@ -73,10 +68,9 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> Renderer::Output {
-        self.overlay
-            .draw(renderer, defaults, layout, cursor_position)
+        self.overlay.draw(renderer, defaults, layout, cursor)
     }
 
     /// Computes the _layout_ hash of the [`Element`].
@@ -116,17 +110,17 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<B>,
-    ) -> event::Status {
+    ) -> event::Status<B> {
         let mut original_messages = Vec::new();
 
         let event_status = self.content.on_event(
             event,
             layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             &mut original_messages,
@@ -136,7 +130,7 @@ where
             .drain(..)
             .for_each(|message| messages.push((self.mapper)(message)));
 
-        event_status
+        event_status.map(self.mapper)
     }
 
     fn draw(
@@ -144,10 +138,9 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> Renderer::Output {
-        self.content
-            .draw(renderer, defaults, layout, cursor_position)
+        self.content.draw(renderer, defaults, layout, cursor)
     }
 
     fn hash_layout(&self, state: &mut Hasher, position: Point) {
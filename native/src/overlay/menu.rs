@@ -218,15 +218,15 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         self.container.on_event(
             event.clone(),
             layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             messages,
@@ -238,22 +238,17 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
     ) -> Renderer::Output {
         let primitives = self.container.draw(
             renderer,
             defaults,
             layout,
-            cursor_position,
+            cursor,
             &layout.bounds(),
         );
 
-        renderer.decorate(
-            layout.bounds(),
-            cursor_position,
-            &self.style,
-            primitives,
-        )
+        renderer.decorate(layout.bounds(), cursor, &self.style, primitives)
     }
 }
 
@@ -319,16 +314,16 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
+                if cursor.is_over(bounds) {
                     if let Some(index) = *self.hovered_option {
                         if let Some(option) = self.options.get(index) {
                             *self.last_selection = Some(option.clone());
@@ -339,7 +334,7 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
                     let text_size =
                         self.text_size.unwrap_or(renderer.default_size());
 
@@ -353,7 +348,7 @@ where
             Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
                     let text_size =
                         self.text_size.unwrap_or(renderer.default_size());
 
@@ -381,13 +376,13 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         self::Renderer::draw(
             renderer,
             layout.bounds(),
-            cursor_position,
+            cursor,
             viewport,
             self.options,
             *self.hovered_option,
@@ -417,7 +412,7 @@ pub trait Renderer:
     fn decorate(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         style: &<Self as Renderer>::Style,
         primitive: Self::Output,
     ) -> Self::Output;
@@ -426,7 +421,7 @@ pub trait Renderer:
     fn draw<T: ToString>(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         options: &[T],
         hovered_option: Option<usize>,
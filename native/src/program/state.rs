@@ -1,5 +1,7 @@
+use crate::keyboard;
+use crate::mouse;
 use crate::{
-    Cache, Clipboard, Command, Debug, Event, Point, Program, Renderer, Size,
+    Cache, Clipboard, Command, Debug, Event, Program, Renderer, Size,
     UserInterface,
 };
 
@@ -15,6 +17,7 @@ where
     primitive: <P::Renderer as Renderer>::Output,
     queued_events: Vec<Event>,
     queued_messages: Vec<P::Message>,
+    keyboard: keyboard::Tracker,
 }
 
 impl<P> State<P>
@@ -26,7 +29,7 @@ where
     pub fn new(
         mut program: P,
         bounds: Size,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &mut P::Renderer,
         debug: &mut Debug,
     ) -> Self {
@@ -39,7 +42,7 @@ where
         );
 
         debug.draw_started();
-        let primitive = user_interface.draw(renderer, cursor_position);
+        let primitive = user_interface.draw(renderer, cursor);
         debug.draw_finished();
 
         let cache = Some(user_interface.into_cache());
@@ -50,6 +53,7 @@ where
             primitive,
             queued_events: Vec::new(),
             queued_messages: Vec::new(),
+            keyboard: keyboard::Tracker::new(),
         }
     }
 
@@ -85,12 +89,25 @@ where
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
+    /// The queued keyboard events are completed with a [`keyboard::Tracker`]
+    /// first, so the widgets receive a [`keyboard::Event::Input`] for every
+    /// key press.
+    ///
+    /// Animated widgets, like a spinner, request a redraw while being drawn.
+    /// If [`Renderer::take_redraw_request`] returns `true` afterwards, advance
+    /// the clock of the renderer and queue a
+    /// [`window::Event::RedrawRequested`] on the next frame to keep them
+    /// moving.
+    ///
     /// Returns the [`Command`] obtained from [`Program`] after updating it,
     /// only if an update was necessary.
+    ///
+    /// [`Renderer::take_redraw_request`]: crate::Renderer::take_redraw_request
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
     pub fn update(
         &mut self,
         bounds: Size,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &mut P::Renderer,
         clipboard: &mut dyn Clipboard,
         debug: &mut Debug,
@@ -106,9 +123,11 @@ where
         debug.event_processing_started();
         let mut messages = Vec::new();
 
+        self.keyboard.complete(&mut self.queued_events);
+
         let _ = user_interface.update(
             &self.queued_events,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             &mut messages,
@@ -120,7 +139,7 @@ where
 
         if messages.is_empty() {
             debug.draw_started();
-            self.primitive = user_interface.draw(renderer, cursor_position);
+            self.primitive = user_interface.draw(renderer, cursor);
             debug.draw_finished();
 
             self.cache = Some(user_interface.into_cache());
@@ -135,7 +154,7 @@ where
                 Command::batch(messages.into_iter().map(|message| {
                     debug.log_message(&message);
 
-                    debug.update_started();
+                    debug.update_started(&message);
                     let command = self.program.update(message);
                     debug.update_finished();
 
@@ -151,7 +170,7 @@ where
             );
 
             debug.draw_started();
-            self.primitive = user_interface.draw(renderer, cursor_position);
+            self.primitive = user_interface.draw(renderer, cursor);
             debug.draw_finished();
 
             self.cache = Some(user_interface.into_cache());
@@ -178,3 +197,71 @@ fn build_user_interface<'a, P: Program>(
 
     user_interface
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard::{KeyCode, KeyLocation, Modifiers, ScanCode};
+    use crate::renderer::Null;
+    use crate::{clipboard, text_input, Element, TextInput};
+
+    struct Form {
+        input: text_input::State,
+        value: String,
+    }
+
+    impl Program for Form {
+        type Renderer = Null;
+        type Message = String;
+
+        fn update(&mut self, value: String) -> Command<String> {
+            self.value = value;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, String, Null> {
+            TextInput::new(&mut self.input, "", &self.value, |value| value)
+                .into()
+        }
+    }
+
+    #[test]
+    fn key_presses_are_completed_before_an_update() {
+        let mut renderer = Null::new();
+        let mut debug = Debug::new();
+        let bounds = Size::new(200.0, 40.0);
+
+        let mut state = State::new(
+            Form {
+                input: text_input::State::focused(),
+                value: String::new(),
+            },
+            bounds,
+            mouse::Cursor::Unavailable,
+            &mut renderer,
+            &mut debug,
+        );
+
+        state.queue_event(Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: KeyCode::A,
+            physical_key: ScanCode(30),
+            location: KeyLocation::Standard,
+            modifiers: Modifiers::empty(),
+            repeat: false,
+        }));
+        state.queue_event(Event::Keyboard(keyboard::Event::CharacterReceived(
+            'a',
+        )));
+
+        let _ = state.update(
+            bounds,
+            mouse::Cursor::Unavailable,
+            &mut renderer,
+            &mut clipboard::Null,
+            &mut debug,
+        );
+
+        assert_eq!(state.program().value, "a");
+    }
+}
@@ -27,6 +27,8 @@ pub use null::Null;
 
 use crate::{layout, Element, Rectangle};
 
+use std::time::Instant;
+
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
 pub trait Renderer: Sized {
@@ -41,6 +43,16 @@ pub trait Renderer: Sized {
     /// This type can be leveraged to implement style inheritance.
     type Defaults: Default;
 
+    /// Returns the [`Defaults`] used to draw the root of a user interface.
+    ///
+    /// You should override this if your defaults depend on the state of the
+    /// [`Renderer`]. For instance, the text color of an active theme.
+    ///
+    /// [`Defaults`]: Self::Defaults
+    fn defaults(&self) -> Self::Defaults {
+        Self::Defaults::default()
+    }
+
     /// Lays out the elements of a user interface.
     ///
     /// You should override this if you need to perform any operations before or
@@ -53,6 +65,72 @@ pub trait Renderer: Sized {
         element.layout(self, limits)
     }
 
+    /// Returns the amount of primitives in some output of the [`Renderer`].
+    ///
+    /// It is reported by [`UserInterface::benchmark`]. By default, it returns
+    /// zero.
+    ///
+    /// [`UserInterface::benchmark`]: crate::UserInterface::benchmark
+    fn primitive_count(&self, _output: &Self::Output) -> usize {
+        0
+    }
+
+    /// Returns the amount of layers needed to draw some output of the
+    /// [`Renderer`].
+    ///
+    /// It is reported by [`UserInterface::benchmark`]. By default, it returns
+    /// zero.
+    ///
+    /// [`UserInterface::benchmark`]: crate::UserInterface::benchmark
+    fn layer_count(&self, _output: &Self::Output) -> usize {
+        0
+    }
+
+    /// Converts some output of the [`Renderer`] into an SVG document of the
+    /// given size, in logical pixels.
+    ///
+    /// It is used by [`UserInterface::draw_to_svg`]. By default, it returns an
+    /// empty document.
+    ///
+    /// [`UserInterface::draw_to_svg`]: crate::UserInterface::draw_to_svg
+    fn to_svg(
+        &self,
+        _output: &Self::Output,
+        width: u32,
+        height: u32,
+    ) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" \
+            height=\"{1}\" viewBox=\"0 0 {0} {1}\"></svg>",
+            width, height
+        )
+    }
+
+    /// Requests a redraw of the user interface on the next frame, so what is
+    /// being drawn can be animated.
+    ///
+    /// Widgets call it while drawing. By default, it does nothing.
+    fn request_redraw(&mut self) {}
+
+    /// Returns whether a redraw was requested since the last call.
+    ///
+    /// Shells call it after drawing. When it returns `true`, they advance the
+    /// animation clock of the [`Renderer`] and emit a
+    /// [`window::Event::RedrawRequested`] on the next frame. By default, it
+    /// returns `false`.
+    ///
+    /// [`window::Event::RedrawRequested`]: crate::window::Event::RedrawRequested
+    fn take_redraw_request(&mut self) -> bool {
+        false
+    }
+
+    /// Advances the animation clock of the [`Renderer`] to the given
+    /// [`Instant`].
+    ///
+    /// Animated widgets, like a spinner, are drawn at the time of this clock.
+    /// By default, it does nothing.
+    fn advance_clock(&mut self, _now: Instant) {}
+
     /// Overlays the `overlay` output with the given bounds on top of the `base`
     /// output.
     fn overlay(
@@ -1,21 +1,41 @@
 use crate::alignment;
+use crate::border;
 use crate::button;
+use crate::chart;
 use crate::checkbox;
 use crate::column;
 use crate::container;
+use crate::grid;
+use crate::lazy;
+use crate::menu_bar;
+use crate::modal;
+use crate::mouse;
+use crate::number_input;
+use crate::opacity;
 use crate::pane_grid;
 use crate::progress_bar;
+#[cfg(feature = "qr_code")]
+use crate::qr_code;
 use crate::radio;
 use crate::row;
+use crate::rule;
 use crate::scrollable;
+use crate::selectable_text;
 use crate::slider;
+use crate::space;
+use crate::spinner;
 use crate::text;
 use crate::text_input;
 use crate::toggler;
+use crate::video;
+use crate::wrap;
 use crate::{
-    Color, Element, Font, Layout, Padding, Point, Rectangle, Renderer, Size,
+    BorderStyle, Color, Element, Font, Layout, Padding, Point, Rectangle,
+    Renderer, Size, Vector,
 };
 
+use std::ops::Range;
+
 /// A renderer that does nothing.
 ///
 /// It can be useful if you are writing tests!
@@ -43,7 +63,7 @@ impl column::Renderer for Null {
         _defaults: &Self::Defaults,
         _content: &[Element<'_, Message, Self>],
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
     }
@@ -55,7 +75,31 @@ impl row::Renderer for Null {
         _defaults: &Self::Defaults,
         _content: &[Element<'_, Message, Self>],
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+    }
+}
+
+impl wrap::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
     }
@@ -90,6 +134,16 @@ impl text::Renderer for Null {
         None
     }
 
+    fn glyph_positions(
+        &self,
+        _contents: &str,
+        _size: f32,
+        _font: Self::Font,
+        _bounds: Size,
+    ) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
     fn draw(
         &mut self,
         _defaults: &Self::Defaults,
@@ -115,6 +169,20 @@ impl scrollable::Renderer for Null {
         _scrollbar_width: u16,
         _scrollbar_margin: u16,
         _scroller_width: u16,
+        _style: &Self::Style,
+    ) -> Option<scrollable::Scrollbar> {
+        None
+    }
+
+    fn horizontal_scrollbar(
+        &self,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _offset: u32,
+        _scrollbar_width: u16,
+        _scrollbar_margin: u16,
+        _scroller_width: u16,
+        _style: &Self::Style,
     ) -> Option<scrollable::Scrollbar> {
         None
     }
@@ -126,14 +194,32 @@ impl scrollable::Renderer for Null {
         _content_bounds: Rectangle,
         _is_mouse_over: bool,
         _is_mouse_over_scrollbar: bool,
+        _is_mouse_over_horizontal_scrollbar: bool,
         _scrollbar: Option<scrollable::Scrollbar>,
-        _offset: u32,
+        _horizontal_scrollbar: Option<scrollable::Scrollbar>,
+        _offset: Vector<u32>,
+        _scrollbar_visibility: scrollable::ScrollbarVisibility,
         _style: &Self::Style,
         _content: Self::Output,
     ) {
     }
 }
 
+impl selectable_text::Renderer for Null {
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _content: &str,
+        _size: u16,
+        _font: Font,
+        _color: Option<Color>,
+        _selection: Option<Range<usize>>,
+        _selection_color: Color,
+    ) {
+    }
+}
+
 impl text_input::Renderer for Null {
     type Style = ();
 
@@ -156,7 +242,7 @@ impl text_input::Renderer for Null {
         &mut self,
         _bounds: Rectangle,
         _text_bounds: Rectangle,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _font: Font,
         _size: u16,
         _placeholder: &str,
@@ -169,6 +255,7 @@ impl text_input::Renderer for Null {
 
 impl button::Renderer for Null {
     const DEFAULT_PADDING: Padding = Padding::ZERO;
+    const SPINNER_SIZE: u16 = 16;
 
     type Style = ();
 
@@ -176,9 +263,10 @@ impl button::Renderer for Null {
         &mut self,
         _defaults: &Self::Defaults,
         _bounds: Rectangle,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _is_disabled: bool,
         _is_pressed: bool,
+        _is_loading: bool,
         _style: &Self::Style,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
@@ -198,7 +286,7 @@ impl radio::Renderer for Null {
         _is_selected: bool,
         _is_mouse_over: bool,
         _label: Self::Output,
-        _style: &Self::Style,
+        _style: Option<&Self::Style>,
     ) {
     }
 }
@@ -213,7 +301,9 @@ impl checkbox::Renderer for Null {
         &mut self,
         _bounds: Rectangle,
         _is_checked: bool,
+        _is_indeterminate: bool,
         _is_mouse_over: bool,
+        _icon: Option<&checkbox::Icon<Font>>,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -228,7 +318,7 @@ impl slider::Renderer for Null {
     fn draw(
         &mut self,
         _bounds: Rectangle,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
         _is_dragging: bool,
@@ -237,6 +327,18 @@ impl slider::Renderer for Null {
     }
 }
 
+impl space::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle) {}
+}
+
+impl spinner::Renderer for Null {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 24;
+
+    fn draw(&mut self, _bounds: Rectangle, _style: &Self::Style) {}
+}
+
 impl progress_bar::Renderer for Null {
     type Style = ();
 
@@ -252,6 +354,68 @@ impl progress_bar::Renderer for Null {
     }
 }
 
+impl rule::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _style: Option<&Self::Style>,
+        _is_horizontal: bool,
+        _label: Option<rule::Label<'_, Font>>,
+    ) {
+    }
+}
+
+impl chart::Renderer for Null {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _plot: Rectangle,
+        _series: &[chart::Series],
+        _x_axis: &chart::Axis,
+        _y_axis: &chart::Axis,
+        _label_size: u16,
+        _style: &Self::Style,
+    ) {
+    }
+
+    fn draw_bars(
+        &mut self,
+        _bounds: Rectangle,
+        _plot: Rectangle,
+        _bars: &[chart::Bar],
+        _axis: &chart::Axis,
+        _hovered: Option<usize>,
+        _is_clickable: bool,
+        _color: Option<Color>,
+        _label_size: u16,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+#[cfg(feature = "qr_code")]
+impl qr_code::Renderer for Null {
+    fn draw(
+        &self,
+        _bounds: Rectangle,
+        _modules: &[bool],
+        _width: usize,
+        _module_size: f32,
+        _dark: Color,
+        _light: Color,
+    ) {
+    }
+}
+
+impl opacity::Renderer for Null {
+    fn draw(&mut self, _content: (), _opacity: f32) {}
+}
+
 impl container::Renderer for Null {
     type Style = ();
 
@@ -259,8 +423,10 @@ impl container::Renderer for Null {
         &mut self,
         _defaults: &Self::Defaults,
         _bounds: Rectangle,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
+        _backdrop_blur: f32,
+        _backdrop_tint: Color,
         _style: &Self::Style,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
@@ -268,6 +434,87 @@ impl container::Renderer for Null {
     }
 }
 
+impl lazy::Renderer for Null {
+    type Cached = ();
+    type Key = ();
+
+    fn cache_key(&self, _defaults: &()) {}
+
+    fn cache(&mut self, _output: ()) {}
+
+    fn draw_cached(&mut self, _cached: &()) {}
+}
+
+impl modal::Renderer for Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+        _style: &Self::Style,
+        _dialog: &Element<'_, Message, Self>,
+        _dialog_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl border::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _thickness: f32,
+        _radius: f32,
+        _color: Color,
+        _style: BorderStyle,
+        _offset: f32,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) {
+    }
+}
+
+impl menu_bar::Renderer for Null {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+    const SEPARATOR_HEIGHT: u16 = 1;
+    const HINT_SPACING: u16 = 0;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _menus: &[menu_bar::Menu<Message>],
+        _focused: Option<usize>,
+        _is_open: bool,
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+
+    fn draw_menus<Message>(
+        &mut self,
+        _cursor: mouse::Cursor,
+        _menus: &[(
+            Layout<'_>,
+            &[menu_bar::MenuItem<Message>],
+            Option<usize>,
+        )],
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
 impl pane_grid::Renderer for Null {
     type Style = ();
 
@@ -279,7 +526,7 @@ impl pane_grid::Renderer for Null {
         _resizing: Option<(pane_grid::Axis, Rectangle, bool)>,
         _layout: Layout<'_>,
         _style: &<Self as pane_grid::Renderer>::Style,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
     }
@@ -294,7 +541,7 @@ impl pane_grid::Renderer for Null {
             Layout<'_>,
         )>,
         _body: (&Element<'_, Message, Self>, Layout<'_>),
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
     }
@@ -306,7 +553,7 @@ impl pane_grid::Renderer for Null {
         _style: &<Self as container::Renderer>::Style,
         _content: (&Element<'_, Message, Self>, Layout<'_>),
         _controls: Option<(&Element<'_, Message, Self>, Layout<'_>)>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
     }
@@ -323,7 +570,21 @@ impl toggler::Renderer for Null {
         _is_checked: bool,
         _is_mouse_over: bool,
         _label: Option<Self::Output>,
-        _style: &Self::Style,
+        _style: Option<&Self::Style>,
+    ) {
+    }
+}
+
+impl number_input::Renderer for Null {
+    fn draw(&mut self, _input: (), _decrement: (), _increment: ()) {}
+}
+
+impl video::Renderer for Null {
+    fn draw(
+        &mut self,
+        _handle: video::Handle,
+        _bounds: Rectangle,
+        _layout: Layout<'_>,
     ) {
     }
 }
@@ -42,6 +42,7 @@ pub fn events() -> Subscription<Event> {
         f: |event, status| match status {
             event::Status::Ignored => Some(event),
             event::Status::Captured => None,
+            event::Status::CapturedWithFeedback(never) => match never {},
         },
     })
 }
@@ -1,6 +1,8 @@
 //! Build touch events.
 use crate::Point;
 
+use std::time::Duration;
+
 /// A touch interaction.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(missing_docs)]
@@ -16,6 +18,27 @@ pub enum Event {
 
     /// A touch interaction was canceled.
     FingerLost { id: Finger, position: Point },
+
+    /// A finger stayed still on the screen for a while.
+    ///
+    /// The `duration` is the time elapsed since the finger was pressed.
+    LongPress {
+        id: Finger,
+        position: Point,
+        duration: Duration,
+    },
+
+    /// Two fingers moved closer or further apart.
+    ///
+    /// The `scale_factor` is the ratio between the current distance of the
+    /// fingers and their distance in the previous event.
+    Pinch { center: Point, scale_factor: f32 },
+
+    /// Two fingers rotated around their center.
+    ///
+    /// The `angle_delta` is the rotation since the previous event, in
+    /// radians. It is positive when rotating clockwise on the screen.
+    Rotate { center: Point, angle_delta: f32 },
 }
 
 /// A unique identifier representing a finger on a touch interaction.
@@ -0,0 +1,230 @@
+//! Instrument the runtime with [`tracing`] spans.
+//!
+//! Every stage of a frame (updating the application, building its view,
+//! laying out, processing events, drawing and rendering) opens a [`Span`] that
+//! nests inside the stage that caused it, so tools like `tracing-chrome` or
+//! `tracing-tracy` can display the whole frame as a flame graph.
+//!
+//! The spans are only recorded when the `trace` feature is enabled. Otherwise,
+//! a [`Span`] is zero-sized and every function of this module compiles to
+//! nothing.
+//!
+//! The debug view of an application takes its timings from the same spans
+//! when the [`Timings`] layer is part of the current subscriber:
+//!
+//! ```ignore
+//! use tracing_subscriber::layer::SubscriberExt;
+//! use tracing_subscriber::util::SubscriberInitExt;
+//!
+//! tracing_subscriber::registry()
+//!     .with(iced_native::trace::Timings)
+//!     .with(tracing_chrome::ChromeLayerBuilder::new().build().0)
+//!     .init();
+//! ```
+//!
+//! [`tracing`]: https://docs.rs/tracing
+#[cfg(feature = "trace")]
+mod timings;
+
+#[cfg(feature = "trace")]
+pub use timings::Timings;
+
+use std::time::Duration;
+
+/// An entered span of the runtime.
+///
+/// The span is exited once dropped.
+#[derive(Debug, Default)]
+#[must_use = "a span is exited as soon as it is dropped"]
+pub struct Span {
+    #[cfg(feature = "trace")]
+    entered: Option<tracing::span::EnteredSpan>,
+}
+
+// A disabled `Span` must not take any space
+#[cfg(not(feature = "trace"))]
+const _: [(); 0] = [(); std::mem::size_of::<Span>()];
+
+impl Span {
+    /// Returns a [`Span`] that does not record anything.
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Exits the [`Span`], returning the time spent inside of it as measured
+    /// by the [`Timings`] layer of the current subscriber, if any.
+    pub fn finish(self) -> Option<Duration> {
+        #[cfg(feature = "trace")]
+        {
+            let entered = self.entered?;
+            let id = entered.id()?;
+
+            // Closing the span makes the layer measure it
+            drop(entered);
+
+            timings::take(&id)
+        }
+
+        #[cfg(not(feature = "trace"))]
+        {
+            None
+        }
+    }
+
+    #[cfg(feature = "trace")]
+    fn enter(span: tracing::Span) -> Self {
+        Self {
+            entered: Some(span.entered()),
+        }
+    }
+}
+
+macro_rules! span {
+    ($name:literal $(, $field:ident = $value:expr)*) => {{
+        #[cfg(feature = "trace")]
+        {
+            Span::enter(tracing::info_span!(
+                target: "iced",
+                $name
+                $(, $field = $value)*
+            ))
+        }
+
+        #[cfg(not(feature = "trace"))]
+        {
+            $(let _ = $value;)*
+
+            Span {}
+        }
+    }};
+}
+
+/// Enters the startup [`Span`] of an application.
+#[inline(always)]
+pub fn startup() -> Span {
+    span!("startup")
+}
+
+/// Enters the [`Span`] of updating an application with the given message.
+///
+/// The span records the type of the message, as well as its [`Debug`]
+/// representation. The message is only formatted when a subscriber records
+/// it.
+///
+/// [`Debug`]: std::fmt::Debug
+#[inline(always)]
+pub fn update<Message: std::fmt::Debug>(message: &Message) -> Span {
+    #[cfg(feature = "trace")]
+    {
+        Span::enter(tracing::info_span!(
+            target: "iced",
+            "update",
+            message_type = std::any::type_name::<Message>(),
+            message = ?message,
+        ))
+    }
+
+    #[cfg(not(feature = "trace"))]
+    {
+        let _ = message;
+
+        Span {}
+    }
+}
+
+/// Enters the [`Span`] of producing the view of an application.
+#[inline(always)]
+pub fn view() -> Span {
+    span!("view")
+}
+
+/// Enters the [`Span`] of building a [`UserInterface`], which lays it out
+/// unless its layout is cached.
+///
+/// [`UserInterface`]: crate::UserInterface
+#[inline(always)]
+pub fn build() -> Span {
+    span!("build")
+}
+
+/// Enters the [`Span`] of laying out the widgets of a [`UserInterface`],
+/// nested inside its [`build`] span.
+///
+/// [`UserInterface`]: crate::UserInterface
+#[inline(always)]
+pub fn layout() -> Span {
+    span!("layout")
+}
+
+/// Enters the [`Span`] of processing events with a [`UserInterface`].
+///
+/// [`UserInterface`]: crate::UserInterface
+#[inline(always)]
+pub fn event_processing() -> Span {
+    span!("event_processing")
+}
+
+/// Enters the [`Span`] of drawing a [`UserInterface`] into primitives.
+///
+/// [`UserInterface`]: crate::UserInterface
+#[inline(always)]
+pub fn draw() -> Span {
+    span!("draw")
+}
+
+/// Enters the [`Span`] of delivering events to the subscriptions of an
+/// application, and of tracking its new subscriptions.
+#[inline(always)]
+pub fn subscriptions() -> Span {
+    span!("subscriptions")
+}
+
+/// Enters the [`Span`] of rendering the primitives of a frame.
+#[inline(always)]
+pub fn render() -> Span {
+    span!("render")
+}
+
+/// Enters the [`Span`] of acquiring the next frame of a surface.
+#[inline(always)]
+pub fn acquire() -> Span {
+    span!("acquire")
+}
+
+/// Enters the [`Span`] of encoding the commands of a graphics pipeline.
+#[inline(always)]
+pub fn encode(pipeline: &'static str) -> Span {
+    span!("encode", pipeline = pipeline)
+}
+
+/// Enters the [`Span`] of submitting the encoded commands of a frame.
+#[inline(always)]
+pub fn submit() -> Span {
+    span!("submit")
+}
+
+/// Enters the [`Span`] of presenting a frame to a surface.
+#[inline(always)]
+pub fn present() -> Span {
+    span!("present")
+}
+
+/// Records that the layout of a [`UserInterface`] is recomputed because the
+/// hash of its widgets changed.
+///
+/// [`UserInterface`]: crate::UserInterface
+#[inline(always)]
+pub fn layout_invalidated() {
+    #[cfg(feature = "trace")]
+    tracing::debug!(target: "iced", "layout hash changed, relayouting");
+}
+
+/// Records that the cache with the given name is rebuilt.
+#[inline(always)]
+pub fn cache_rebuilt(cache: &'static str) {
+    #[cfg(feature = "trace")]
+    tracing::debug!(target: "iced", cache, "cache rebuilt");
+
+    #[cfg(not(feature = "trace"))]
+    let _ = cache;
+}
@@ -0,0 +1,118 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use tracing::span;
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// A [`Layer`] measuring the time spent inside the spans of the runtime.
+///
+/// When it is part of the current subscriber, the debug view of an
+/// application takes its timings from the spans measured by this layer.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings;
+
+/// The time spent inside a span, excluding the time it was not entered.
+#[derive(Debug, Default)]
+struct Busy {
+    entered_at: Option<Instant>,
+    total: Duration,
+}
+
+thread_local! {
+    // The last span of the runtime closed in this thread, which is the one
+    // being finished when it is taken
+    static LAST_CLOSED: Cell<Option<(u64, Duration)>> = Cell::new(None);
+}
+
+impl<S> Layer<S> for Timings
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(
+        &self,
+        attributes: &span::Attributes<'_>,
+        id: &span::Id,
+        context: Context<'_, S>,
+    ) {
+        if attributes.metadata().target() != "iced" {
+            return;
+        }
+
+        if let Some(span) = context.span(id) {
+            span.extensions_mut().insert(Busy::default());
+        }
+    }
+
+    fn on_enter(&self, id: &span::Id, context: Context<'_, S>) {
+        if let Some(span) = context.span(id) {
+            if let Some(busy) = span.extensions_mut().get_mut::<Busy>() {
+                busy.entered_at = Some(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, context: Context<'_, S>) {
+        if let Some(span) = context.span(id) {
+            if let Some(busy) = span.extensions_mut().get_mut::<Busy>() {
+                if let Some(entered_at) = busy.entered_at.take() {
+                    busy.total += entered_at.elapsed();
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: span::Id, context: Context<'_, S>) {
+        if let Some(span) = context.span(&id) {
+            if let Some(busy) = span.extensions().get::<Busy>() {
+                LAST_CLOSED
+                    .with(|last| last.set(Some((id.into_u64(), busy.total))));
+            }
+        }
+    }
+}
+
+/// Takes the time measured for the span with the given id, if it was the
+/// last one closed in this thread.
+pub(super) fn take(id: &span::Id) -> Option<Duration> {
+    LAST_CLOSED.with(|last| match last.take() {
+        Some((closed, duration)) if closed == id.into_u64() => Some(duration),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace;
+
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn finished_spans_are_measured() {
+        let subscriber = tracing_subscriber::registry().with(Timings);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = trace::view();
+
+            // Nested spans are closed before the one being finished
+            let _ = trace::layout().finish();
+
+            std::thread::sleep(Duration::from_millis(5));
+
+            let duration = span.finish().expect("Measured span");
+
+            assert!(duration >= Duration::from_millis(5));
+        });
+    }
+
+    #[test]
+    fn spans_are_not_measured_without_the_layer() {
+        let subscriber = tracing_subscriber::registry();
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert_eq!(trace::view().finish(), None);
+        });
+    }
+}
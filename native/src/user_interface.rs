@@ -1,9 +1,13 @@
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
-use crate::{Clipboard, Element, Layout, Point, Rectangle, Size};
+use crate::trace;
+use crate::{Clipboard, Element, Layout, Rectangle, Size};
 
 use std::hash::Hasher;
+use std::time::{Duration, Instant};
 
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
@@ -23,6 +27,9 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    safe_area: layout::EdgeInsets,
+    shortcuts: keyboard::Shortcuts<Message>,
+    stats: InterfaceStats,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -88,6 +95,30 @@ where
         cache: Cache,
         renderer: &mut Renderer,
     ) -> Self {
+        Self::build_in_safe_area(
+            root,
+            bounds,
+            layout::EdgeInsets::ZERO,
+            cache,
+            renderer,
+        )
+    }
+
+    /// Builds a user interface for an [`Element`], laid out in the safe area
+    /// of the given bounds delimited by some [`EdgeInsets`].
+    ///
+    /// The root [`Element`] avoids the insets, but its overlays can still use
+    /// the whole bounds.
+    ///
+    /// [`EdgeInsets`]: layout::EdgeInsets
+    pub fn build_in_safe_area<E: Into<Element<'a, Message, Renderer>>>(
+        root: E,
+        bounds: Size,
+        safe_area: layout::EdgeInsets,
+        cache: Cache,
+        renderer: &mut Renderer,
+    ) -> Self {
+        let started_at = Instant::now();
         let root = root.into();
 
         let (base, overlay) = {
@@ -98,19 +129,34 @@ where
                 hasher.finish()
             };
 
-            let layout_is_cached =
-                hash == cache.base.hash && bounds == cache.bounds;
+            let layout = |renderer: &mut Renderer| {
+                let _span = trace::layout();
+
+                let mut layout = renderer.layout(
+                    &root,
+                    &layout::Limits::new(Size::ZERO, bounds)
+                        .safe_area(safe_area),
+                );
+
+                layout.move_to(safe_area.origin());
+                layout
+            };
+
+            let hash_matches = hash == cache.base.hash;
+
+            let layout_is_cached = hash_matches
+                && bounds == cache.bounds
+                && safe_area == cache.safe_area
+                && confirms(hash, &cache.base.layout, || layout(renderer));
 
             let (layout, overlay) = if layout_is_cached {
                 (cache.base.layout, cache.overlay)
             } else {
-                (
-                    renderer.layout(
-                        &root,
-                        &layout::Limits::new(Size::ZERO, bounds),
-                    ),
-                    None,
-                )
+                if !hash_matches {
+                    trace::layout_invalidated();
+                }
+
+                (layout(renderer), None)
             };
 
             (Layer { layout, hash }, overlay)
@@ -121,20 +167,46 @@ where
             base,
             overlay,
             bounds,
+            safe_area,
+            shortcuts: keyboard::Shortcuts::new(),
+            stats: InterfaceStats {
+                layout_time: started_at.elapsed(),
+                ..InterfaceStats::default()
+            },
         }
     }
 
+    /// Sets the application-wide [`Shortcuts`] of the [`UserInterface`].
+    ///
+    /// The [`Shortcuts`] are consulted in [`update`](Self::update) before any
+    /// widget, and the events triggering them are captured.
+    ///
+    /// [`Shortcuts`]: keyboard::Shortcuts
+    pub fn with_shortcuts(
+        mut self,
+        shortcuts: keyboard::Shortcuts<Message>,
+    ) -> Self {
+        self.shortcuts = shortcuts;
+        self
+    }
+
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
     ///
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// Keyboard events triggering one of the [`Shortcuts`] of the
+    /// [`UserInterface`] produce the bound message and are captured without
+    /// reaching any widget.
+    ///
+    /// [`Shortcuts`]: keyboard::Shortcuts
+    ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
     /// completing [the previous example](#example):
     ///
     /// ```no_run
-    /// use iced_native::{clipboard, UserInterface, Cache, Size, Point};
+    /// use iced_native::{clipboard, mouse, UserInterface, Cache, Size};
     /// use iced_wgpu::Renderer;
     ///
     /// # mod iced_wgpu {
@@ -156,7 +228,7 @@ where
     /// let mut cache = Cache::new();
     /// let mut renderer = Renderer::new();
     /// let mut window_size = Size::new(1024.0, 768.0);
-    /// let mut cursor_position = Point::default();
+    /// let mut cursor = mouse::Cursor::Unavailable;
     /// let mut clipboard = clipboard::Null;
     ///
     /// // Initialize our event storage
@@ -176,7 +248,7 @@ where
     ///     // Update the user interface
     ///     let event_statuses = user_interface.update(
     ///         &events,
-    ///         cursor_position,
+    ///         cursor,
     ///         &renderer,
     ///         &mut clipboard,
     ///         &mut messages
@@ -193,11 +265,47 @@ where
     pub fn update(
         &mut self,
         events: &[Event],
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> Vec<event::Status> {
+        // The input of a key triggering a shortcut is captured too
+        let mut shortcut_key = None;
+
+        let shortcut_statuses: Vec<event::Status> = events
+            .iter()
+            .map(|event| match event {
+                Event::Keyboard(keyboard::Event::Input {
+                    physical_key,
+                    ..
+                }) if shortcut_key == Some(*physical_key) => {
+                    shortcut_key = None;
+
+                    event::Status::Captured
+                }
+                Event::Keyboard(keyboard_event) => {
+                    match self.shortcuts.handle(keyboard_event) {
+                        Some(message) => {
+                            messages.push(message);
+
+                            if let keyboard::Event::KeyPressed {
+                                physical_key,
+                                ..
+                            } = keyboard_event
+                            {
+                                shortcut_key = Some(*physical_key);
+                            }
+
+                            event::Status::Captured
+                        }
+                        None => event::Status::Ignored,
+                    }
+                }
+                _ => event::Status::Ignored,
+            })
+            .collect();
+
         let (base_cursor, overlay_statuses) = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
         {
@@ -211,46 +319,65 @@ where
             let event_statuses = events
                 .iter()
                 .cloned()
-                .map(|event| {
-                    overlay.on_event(
-                        event,
-                        Layout::new(&layer.layout),
-                        cursor_position,
-                        renderer,
-                        clipboard,
-                        messages,
-                    )
+                .zip(shortcut_statuses.iter())
+                .map(|(event, shortcut_status)| {
+                    if shortcut_status.is_captured() {
+                        return event::Status::Ignored;
+                    }
+
+                    deliver(event, |event| {
+                        overlay
+                            .on_event(
+                                event,
+                                Layout::new(&layer.layout),
+                                cursor,
+                                renderer,
+                                clipboard,
+                                messages,
+                            )
+                            .push_feedback(messages)
+                    })
                 })
                 .collect();
 
-            let base_cursor = if layer.layout.bounds().contains(cursor_position)
-            {
-                // TODO: Type-safe cursor availability
-                Point::new(-1.0, -1.0)
+            let base_cursor = if cursor.is_over(layer.layout.bounds()) {
+                mouse::Cursor::Unavailable
             } else {
-                cursor_position
+                cursor
             };
 
             self.overlay = Some(layer);
 
             (base_cursor, event_statuses)
         } else {
-            (cursor_position, vec![event::Status::Ignored; events.len()])
+            (cursor, vec![event::Status::Ignored; events.len()])
         };
 
         events
             .iter()
             .cloned()
             .zip(overlay_statuses.into_iter())
-            .map(|(event, overlay_status)| {
-                let event_status = self.root.widget.on_event(
-                    event,
-                    Layout::new(&self.base.layout),
-                    base_cursor,
-                    renderer,
-                    clipboard,
-                    messages,
-                );
+            .zip(shortcut_statuses.into_iter())
+            .map(|((event, overlay_status), shortcut_status)| {
+                if shortcut_status.is_captured() {
+                    return shortcut_status;
+                }
+
+                let root = &mut self.root;
+                let layout = &self.base.layout;
+
+                let event_status = deliver(event, |event| {
+                    root.widget
+                        .on_event(
+                            event,
+                            Layout::new(layout),
+                            base_cursor,
+                            renderer,
+                            clipboard,
+                            messages,
+                        )
+                        .push_feedback(messages)
+                });
 
                 event_status.merge(overlay_status)
             })
@@ -270,7 +397,7 @@ where
     /// [completing the last example](#example-1):
     ///
     /// ```no_run
-    /// use iced_native::{clipboard, UserInterface, Cache, Size, Point};
+    /// use iced_native::{clipboard, mouse, UserInterface, Cache, Size};
     /// use iced_wgpu::Renderer;
     ///
     /// # mod iced_wgpu {
@@ -292,7 +419,7 @@ where
     /// let mut cache = Cache::new();
     /// let mut renderer = Renderer::new();
     /// let mut window_size = Size::new(1024.0, 768.0);
-    /// let mut cursor_position = Point::default();
+    /// let mut cursor = mouse::Cursor::Unavailable;
     /// let mut clipboard = clipboard::Null;
     /// let mut events = Vec::new();
     /// let mut messages = Vec::new();
@@ -310,14 +437,14 @@ where
     ///     // Update the user interface
     ///     let event_statuses = user_interface.update(
     ///         &events,
-    ///         cursor_position,
+    ///         cursor,
     ///         &renderer,
     ///         &mut clipboard,
     ///         &mut messages
     ///     );
     ///
     ///     // Draw the user interface
-    ///     let mouse_cursor = user_interface.draw(&mut renderer, cursor_position);
+    ///     let mouse_cursor = user_interface.draw(&mut renderer, cursor);
     ///
     ///     cache = user_interface.into_cache();
     ///
@@ -332,9 +459,57 @@ where
     pub fn draw(
         &mut self,
         renderer: &mut Renderer,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
+    ) -> Renderer::Output {
+        let started_at = Instant::now();
+        let output = self.draw_layers(renderer, cursor);
+
+        self.stats.draw_time = started_at.elapsed();
+        self.stats.primitive_count = renderer.primitive_count(&output);
+        self.stats.layer_count = renderer.layer_count(&output);
+
+        output
+    }
+
+    /// Draws the [`UserInterface`] into a static SVG document of the given
+    /// size, in logical pixels.
+    ///
+    /// The cursor is considered to be out of the [`UserInterface`], so no
+    /// widget is drawn hovered. This can be used to take screenshots on a
+    /// server or to produce images for documentation.
+    ///
+    /// The conversion is performed by [`Renderer::to_svg`], and renderers
+    /// that do not support it produce an empty document.
+    pub fn draw_to_svg(
+        &mut self,
+        renderer: &mut Renderer,
+        width: u32,
+        height: u32,
+    ) -> String {
+        let output = self.draw(renderer, mouse::Cursor::Unavailable);
+
+        renderer.to_svg(&output, width, height)
+    }
+
+    /// Returns the [`InterfaceStats`] of the last layout and draw of the
+    /// [`UserInterface`].
+    ///
+    /// The layout time is measured when the [`UserInterface`] is built, and
+    /// it is close to zero when its layout is cached. The draw time and the
+    /// counts are measured by the last call to [`draw`](Self::draw), and they
+    /// are zero until then. This can be used to display an in-app performance
+    /// overlay.
+    pub fn benchmark(&self) -> InterfaceStats {
+        self.stats
+    }
+
+    fn draw_layers(
+        &mut self,
+        renderer: &mut Renderer,
+        cursor: mouse::Cursor,
     ) -> Renderer::Output {
         let viewport = Rectangle::with_size(self.bounds);
+        let defaults = renderer.defaults();
 
         let overlay = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
@@ -350,9 +525,9 @@ where
 
             let overlay_primitives = overlay.draw(
                 renderer,
-                &Renderer::Defaults::default(),
+                &defaults,
                 Layout::new(&layer.layout),
-                cursor_position,
+                cursor,
             );
 
             self.overlay = Some(layer);
@@ -363,15 +538,15 @@ where
         };
 
         if let Some((overlay_primitives, overlay_bounds)) = overlay {
-            let base_cursor = if overlay_bounds.contains(cursor_position) {
-                Point::new(-1.0, -1.0)
+            let base_cursor = if cursor.is_over(overlay_bounds) {
+                mouse::Cursor::Unavailable
             } else {
-                cursor_position
+                cursor
             };
 
             let base_primitives = self.root.widget.draw(
                 renderer,
-                &Renderer::Defaults::default(),
+                &defaults,
                 Layout::new(&self.base.layout),
                 base_cursor,
                 &viewport,
@@ -385,27 +560,60 @@ where
         } else {
             self.root.widget.draw(
                 renderer,
-                &Renderer::Defaults::default(),
+                &defaults,
                 Layout::new(&self.base.layout),
-                cursor_position,
+                cursor,
                 &viewport,
             )
         }
     }
 
+    /// Returns a [`Snapshot`] of the current layout of the [`UserInterface`],
+    /// including its overlay, if any.
+    ///
+    /// The overlay is only laid out once the [`UserInterface`] has processed
+    /// events or has been drawn.
+    ///
+    /// [`Snapshot`]: layout::Snapshot
+    pub fn layout_snapshot(&self) -> layout::Snapshot {
+        layout::Snapshot::new(
+            std::iter::once(&self.base)
+                .chain(self.overlay.as_ref())
+                .map(|layer| Layout::new(&layer.layout)),
+        )
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
+    ///
+    /// The current safe area of the [`UserInterface`] is kept.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
-        Self::build(
+        let safe_area = self.safe_area;
+
+        self.relayout_in_safe_area(bounds, safe_area, renderer)
+    }
+
+    /// Relayouts and returns a new [`UserInterface`] using the provided
+    /// bounds and safe area.
+    pub fn relayout_in_safe_area(
+        self,
+        bounds: Size,
+        safe_area: layout::EdgeInsets,
+        renderer: &mut Renderer,
+    ) -> Self {
+        Self::build_in_safe_area(
             self.root,
             bounds,
+            safe_area,
             Cache {
                 base: self.base,
                 overlay: self.overlay,
                 bounds: self.bounds,
+                safe_area: self.safe_area,
             },
             renderer,
         )
+        .with_shortcuts(self.shortcuts)
     }
 
     /// Extract the [`Cache`] of the [`UserInterface`], consuming it in the
@@ -415,6 +623,7 @@ where
             base: self.base,
             overlay: self.overlay,
             bounds: self.bounds,
+            safe_area: self.safe_area,
         }
     }
 
@@ -432,7 +641,14 @@ where
         };
 
         let layout = match cache {
-            Some(Layer { hash, layout }) if new_hash == hash => layout,
+            Some(Layer { hash, layout })
+                if new_hash == hash
+                    && confirms(hash, &layout, || {
+                        overlay.layout(renderer, bounds)
+                    }) =>
+            {
+                layout
+            }
             _ => overlay.layout(renderer, bounds),
         };
 
@@ -443,18 +659,123 @@ where
     }
 }
 
+/// Delivers an event to some widgets with the given handler.
+///
+/// A [`mouse::Event::DoubleClicked`] that is ignored is delivered again as the
+/// [`mouse::Event::ButtonPressed`] it replaced. This way, widgets unaware of
+/// double clicks still receive every press.
+fn deliver(
+    event: Event,
+    mut on_event: impl FnMut(Event) -> event::Status,
+) -> event::Status {
+    let button = match event {
+        Event::Mouse(mouse::Event::DoubleClicked(button, _)) => Some(button),
+        _ => None,
+    };
+
+    match (on_event(event), button) {
+        (event::Status::Ignored, Some(button)) => {
+            on_event(Event::Mouse(mouse::Event::ButtonPressed(button)))
+        }
+        (status, _) => status,
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Layer {
     layout: layout::Node,
     hash: u64,
 }
 
+/// Returns whether a cached layout, whose element hashes to the same value as
+/// before, can be reused.
+///
+/// With the `layout-fingerprint` feature, the layout is computed again and
+/// the bounds of its nodes are compared with the cached ones. A difference
+/// means the hash of some widget missed a change affecting its layout. It is
+/// logged, and the cached layout is not reused.
+#[cfg(feature = "layout-fingerprint")]
+fn confirms(
+    hash: u64,
+    cached: &layout::Node,
+    layout: impl FnOnce() -> layout::Node,
+) -> bool {
+    let fresh = layout();
+
+    if fingerprint(&fresh) == fingerprint(cached) {
+        return true;
+    }
+
+    log::warn!(
+        "The layout hash {:#x} did not change, but the layout did; \
+        a widget is not hashing everything its layout depends on",
+        hash
+    );
+
+    false
+}
+
+#[cfg(not(feature = "layout-fingerprint"))]
+fn confirms(
+    _hash: u64,
+    _cached: &layout::Node,
+    _layout: impl FnOnce() -> layout::Node,
+) -> bool {
+    true
+}
+
+/// Hashes the bounds of every node of a layout tree.
+#[cfg(feature = "layout-fingerprint")]
+fn fingerprint(node: &layout::Node) -> u64 {
+    use std::hash::Hash;
+
+    fn hash_bounds(node: &layout::Node, hasher: &mut crate::Hasher) {
+        let bounds = node.bounds();
+
+        bounds.x.to_bits().hash(hasher);
+        bounds.y.to_bits().hash(hasher);
+        bounds.width.to_bits().hash(hasher);
+        bounds.height.to_bits().hash(hasher);
+        node.children().len().hash(hasher);
+
+        for child in node.children() {
+            hash_bounds(child, hasher);
+        }
+    }
+
+    let hasher = &mut crate::Hasher::default();
+    hash_bounds(node, hasher);
+
+    hasher.finish()
+}
+
+/// The performance measurements of a [`UserInterface`].
+///
+/// They can be obtained with [`UserInterface::benchmark`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InterfaceStats {
+    /// The time spent laying out the [`UserInterface`] when it was built.
+    pub layout_time: Duration,
+
+    /// The time spent drawing the [`UserInterface`] the last time.
+    pub draw_time: Duration,
+
+    /// The amount of primitives produced by the last draw, as counted by the
+    /// `Renderer`.
+    pub primitive_count: usize,
+
+    /// The amount of layers produced by the last draw, as counted by the
+    /// `Renderer`.
+    pub layer_count: usize,
+}
+
 /// Reusable data of a specific [`UserInterface`].
 #[derive(Debug, Clone)]
 pub struct Cache {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    safe_area: layout::EdgeInsets,
 }
 
 impl Cache {
@@ -470,6 +791,7 @@ impl Cache {
             },
             overlay: None,
             bounds: Size::ZERO,
+            safe_area: layout::EdgeInsets::ZERO,
         }
     }
 }
@@ -479,3 +801,293 @@ impl Default for Cache {
         Cache::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{Column, Length, Point, Radio};
+
+    #[test]
+    fn changing_the_size_of_a_radio_relayouts() {
+        let mut renderer = Null::new();
+        let bounds = Size::new(400.0, 400.0);
+
+        let radio = |size| {
+            Radio::<_, Null>::new(0, "Radio", Some(0), |value: i32| value)
+                .width(Length::Shrink)
+                .size(size)
+        };
+
+        let user_interface = UserInterface::<i32, Null>::build(
+            radio(20),
+            bounds,
+            Cache::new(),
+            &mut renderer,
+        );
+        let small = user_interface.base.layout.size();
+
+        let user_interface = UserInterface::<i32, Null>::build(
+            radio(40),
+            bounds,
+            user_interface.into_cache(),
+            &mut renderer,
+        );
+        let large = user_interface.base.layout.size();
+
+        assert_ne!(small, large);
+        assert_eq!(large.height, 40.0);
+    }
+
+    #[cfg(feature = "layout-fingerprint")]
+    #[test]
+    fn layouts_missing_from_the_hash_are_not_reused() {
+        use crate::{Element, Widget};
+
+        // A widget leaving its size out of its hash
+        struct Square(f32);
+
+        impl Widget<i32, Null> for Square {
+            fn width(&self) -> Length {
+                Length::Shrink
+            }
+
+            fn height(&self) -> Length {
+                Length::Shrink
+            }
+
+            fn layout(
+                &self,
+                _renderer: &Null,
+                _limits: &layout::Limits,
+            ) -> layout::Node {
+                layout::Node::new(Size::new(self.0, self.0))
+            }
+
+            fn draw(
+                &self,
+                _renderer: &mut Null,
+                _defaults: &(),
+                _layout: Layout<'_>,
+                _cursor: mouse::Cursor,
+                _viewport: &Rectangle,
+            ) {
+            }
+
+            fn hash_layout(&self, _state: &mut crate::Hasher) {}
+        }
+
+        let mut renderer = Null::new();
+        let bounds = Size::new(400.0, 400.0);
+
+        let user_interface = UserInterface::<i32, Null>::build(
+            Element::new(Square(20.0)),
+            bounds,
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let user_interface = UserInterface::<i32, Null>::build(
+            Element::new(Square(40.0)),
+            bounds,
+            user_interface.into_cache(),
+            &mut renderer,
+        );
+
+        assert_eq!(user_interface.base.layout.size(), Size::new(40.0, 40.0));
+    }
+
+    #[test]
+    fn shortcuts_are_handled_before_the_widgets() {
+        use crate::keyboard::{
+            self, Hotkey, KeyCode, KeyLocation, Modifiers, ScanCode, Shortcuts,
+        };
+
+        #[derive(Debug, Clone, PartialEq)]
+        enum Message {
+            Save,
+        }
+
+        let mut renderer = Null::new();
+        let mut messages = Vec::new();
+
+        let mut user_interface = UserInterface::<Message, Null>::build(
+            Column::new(),
+            Size::new(400.0, 400.0),
+            Cache::new(),
+            &mut renderer,
+        )
+        .with_shortcuts(
+            Shortcuts::new()
+                .bind(Hotkey::new(Modifiers::CTRL, KeyCode::S), Message::Save),
+        );
+
+        let statuses = user_interface.update(
+            &[
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: KeyCode::S,
+                    physical_key: ScanCode(31),
+                    location: KeyLocation::Standard,
+                    modifiers: Modifiers::CTRL,
+                    repeat: false,
+                }),
+                Event::Keyboard(keyboard::Event::Input {
+                    key: KeyCode::S,
+                    physical_key: ScanCode(31),
+                    location: KeyLocation::Standard,
+                    text: None,
+                    modifiers: Modifiers::CTRL,
+                    repeat: false,
+                }),
+                Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: KeyCode::S,
+                    physical_key: ScanCode(31),
+                    location: KeyLocation::Standard,
+                    modifiers: Modifiers::empty(),
+                    repeat: false,
+                }),
+            ],
+            mouse::Cursor::Unavailable,
+            &renderer,
+            &mut crate::clipboard::Null,
+            &mut messages,
+        );
+
+        assert_eq!(messages, vec![Message::Save]);
+        assert_eq!(
+            statuses,
+            vec![
+                event::Status::Captured,
+                event::Status::Captured,
+                event::Status::Ignored
+            ]
+        );
+    }
+
+    #[test]
+    fn ignored_double_clicks_are_delivered_as_presses() {
+        use crate::{button, Button, Text};
+
+        let mut renderer = Null::new();
+        let mut state = button::State::new();
+        let mut messages = Vec::new();
+
+        let mut user_interface = UserInterface::<(), Null>::build(
+            Button::new(&mut state, Text::new("Increment")).on_press(()),
+            Size::new(400.0, 400.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let position = Point::new(5.0, 5.0);
+
+        let statuses = user_interface.update(
+            &[
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                Event::Mouse(mouse::Event::DoubleClicked(
+                    mouse::Button::Left,
+                    position,
+                )),
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            ],
+            mouse::Cursor::Available(position),
+            &renderer,
+            &mut crate::clipboard::Null,
+            &mut messages,
+        );
+
+        assert_eq!(messages, vec![(), ()]);
+        assert_eq!(statuses[2], event::Status::Captured);
+    }
+
+    #[test]
+    fn the_root_is_laid_out_in_the_safe_area() {
+        let mut renderer = Null::new();
+        let bounds = Size::new(400.0, 800.0);
+
+        let safe_area = layout::EdgeInsets {
+            top: 40.0,
+            bottom: 20.0,
+            left: 10.0,
+            right: 0.0,
+        };
+
+        let user_interface = UserInterface::<(), Null>::build_in_safe_area(
+            Column::new().width(Length::Fill).height(Length::Fill),
+            bounds,
+            safe_area,
+            Cache::new(),
+            &mut renderer,
+        );
+
+        assert_eq!(
+            user_interface.base.layout.bounds(),
+            Rectangle::new(Point::new(10.0, 40.0), Size::new(390.0, 740.0))
+        );
+    }
+
+    #[test]
+    fn layout_snapshots_locate_the_widgets() {
+        use crate::{button, Button, Row, Text};
+
+        fn button<'a>(
+            state: &'a mut button::State,
+            label: &str,
+        ) -> Button<'a, (), Null> {
+            Button::new(state, Text::new(label)).width(Length::Units(100))
+        }
+
+        let mut renderer = Null::new();
+        let mut states = [
+            button::State::new(),
+            button::State::new(),
+            button::State::new(),
+        ];
+        let [back, skip, next] = &mut states;
+
+        // The first page of the tour: a title, a paragraph, and its controls
+        let page = Column::new()
+            .spacing(20)
+            .push(Text::new("Welcome!").size(50))
+            .push(Text::new("This is a simple tour meant to showcase iced."))
+            .push(
+                Row::new()
+                    .spacing(20)
+                    .push(button(back, "Back"))
+                    .push(button(skip, "Skip"))
+                    .push(button(next, "Next")),
+            );
+
+        let user_interface = UserInterface::<(), Null>::build(
+            page,
+            Size::new(800.0, 600.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let snapshot = user_interface.layout_snapshot();
+        let buttons = snapshot.find("Button");
+
+        assert_eq!(buttons.len(), 3);
+        assert!(buttons[2].bounds().x >= 200.0);
+        assert!(buttons[0].bounds().x < buttons[1].bounds().x);
+        assert_eq!(buttons[0].bounds().y, buttons[2].bounds().y);
+
+        // The controls are below the paragraph
+        let texts = snapshot.find("Text");
+        assert!(texts[1].bounds().y < buttons[0].bounds().y);
+
+        // The innermost widget under the cursor comes first
+        let hovered = snapshot.find_at(Point::new(300.0, 90.0));
+        assert_eq!(hovered[0].widget(), "Text");
+        assert_eq!(hovered[1].bounds(), buttons[2].bounds());
+        assert_eq!(hovered[2].widget(), "Row");
+        assert_eq!(hovered[3].widget(), "Column");
+
+        assert!(snapshot
+            .to_string()
+            .lines()
+            .any(|line| line == "    Button (240, 80) 100x20"));
+    }
+}
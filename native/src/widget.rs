@@ -19,42 +19,91 @@
 //! ```
 //!
 //! [renderer]: crate::renderer
+pub mod border;
 pub mod button;
+pub mod chart;
 pub mod checkbox;
 pub mod column;
 pub mod container;
+pub mod context_menu;
+pub mod explained;
+pub mod grid;
+pub mod hover_area;
 pub mod image;
+pub mod lazy;
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
+pub mod opacity;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
+#[cfg(feature = "qr_code")]
+#[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
+pub mod qr_code;
 pub mod radio;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
 pub mod space;
+pub mod spinner;
 pub mod svg;
 pub mod text;
 pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
+pub mod video;
+pub mod wrap;
 
+#[doc(no_inline)]
+pub use border::Border;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use chart::{BarChart, LineChart};
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use explained::ExplainedWidget;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use hover_area::HoverArea;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use lazy::Lazy;
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::MarkdownView;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
+#[cfg(feature = "qr_code")]
+#[doc(no_inline)]
+pub use qr_code::QrCode;
 #[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
@@ -64,10 +113,14 @@ pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
 pub use text::Text;
@@ -77,11 +130,16 @@ pub use text_input::TextInput;
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use video::Video;
+#[doc(no_inline)]
+pub use wrap::Wrap;
 
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
-use crate::{Clipboard, Hasher, Layout, Length, Point, Rectangle};
+use crate::{Clipboard, Color, Hasher, Layout, Length, Rectangle};
 
 /// A component that displays information and allows interaction.
 ///
@@ -133,7 +191,7 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output;
 
@@ -155,7 +213,7 @@ where
     /// It receives:
     ///   * an [`Event`] describing user interaction
     ///   * the computed [`Layout`] of the [`Widget`]
-    ///   * the current cursor position
+    ///   * the current [`mouse::Cursor`]
     ///   * a mutable `Message` list, allowing the [`Widget`] to produce
     ///   new messages based on user interaction.
     ///   * the `Renderer`
@@ -166,11 +224,11 @@ where
         &mut self,
         _event: Event,
         _layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         event::Status::Ignored
     }
 
@@ -181,4 +239,16 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Wraps the [`Widget`] in an [`ExplainedWidget`], which outlines every
+    /// node of its [`Layout`] with the given [`Color`].
+    ///
+    /// This can be very useful for debugging your layout! The `Renderer`
+    /// draws the outlines, so it needs to be a [`layout::Debugger`].
+    fn explain(self, color: Color) -> ExplainedWidget<Self>
+    where
+        Self: Sized,
+    {
+        ExplainedWidget::new(self, color)
+    }
 }
@@ -0,0 +1,218 @@
+//! Draw an outline around some content.
+use std::hash::Hash;
+
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    BorderStyle, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Widget,
+};
+
+/// An element drawing a border around some content.
+///
+/// The border is drawn on top of the content, inside its bounds, and leaves
+/// the content underneath visible. It is normally used to highlight some
+/// content, like a drop target or a focused element.
+#[allow(missing_debug_implementations)]
+pub struct Border<'a, Message, Renderer> {
+    padding: Padding,
+    thickness: f32,
+    radius: f32,
+    color: Color,
+    style: BorderStyle,
+    offset: f32,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Border<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Border`] around the given content.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Border {
+            padding: Padding::ZERO,
+            thickness: 1.0,
+            radius: 0.0,
+            color: Color::BLACK,
+            style: BorderStyle::Solid,
+            offset: 0.0,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the [`Padding`] between the [`Border`] and its content.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the thickness of the [`Border`].
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
+
+    /// Sets the corner radius of the [`Border`].
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Border`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Sets the [`BorderStyle`] of the [`Border`].
+    pub fn style(mut self, style: BorderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the offset of the dash pattern of the [`Border`], along its
+    /// perimeter.
+    ///
+    /// The dash pattern stays still by default. Changing the offset over time
+    /// animates it.
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Border<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.content.width())
+            .height(self.content.height())
+            .pad(self.padding);
+
+        let mut content = self.content.layout(renderer, &limits);
+        let size = limits.resolve(content.size());
+
+        content.move_to(Point::new(
+            self.padding.left.into(),
+            self.padding.top.into(),
+        ));
+
+        layout::Node::with_children(size.pad(self.padding), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        self.content.widget.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor,
+            renderer,
+            clipboard,
+            messages,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            cursor,
+            viewport,
+            self.thickness,
+            self.radius,
+            self.color,
+            self.style,
+            self.offset,
+            &self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.padding.hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+}
+
+/// The renderer of a [`Border`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Border`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Border`] around its content.
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        thickness: f32,
+        radius: f32,
+        color: Color,
+        style: BorderStyle,
+        offset: f32,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Border<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        border: Border<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(border)
+    }
+}
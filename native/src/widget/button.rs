@@ -8,7 +8,7 @@ use crate::overlay;
 use crate::touch;
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
-    Widget,
+    Size, Widget,
 };
 use std::hash::Hash;
 
@@ -62,6 +62,7 @@ pub struct Button<'a, Message, Renderer: self::Renderer> {
     min_width: u32,
     min_height: u32,
     padding: Padding,
+    is_loading: bool,
     style: Renderer::Style,
 }
 
@@ -85,6 +86,7 @@ where
             min_width: 0,
             min_height: 0,
             padding: Renderer::DEFAULT_PADDING,
+            is_loading: false,
             style: Renderer::Style::default(),
         }
     }
@@ -126,6 +128,19 @@ where
         self
     }
 
+    /// Sets whether the [`Button`] is loading.
+    ///
+    /// A loading [`Button`] ignores presses and draws a spinner in place of
+    /// its content. The [`Button`] always reserves enough space for the
+    /// spinner, so toggling this does not change its size.
+    ///
+    /// The spinner keeps turning on its own, since a loading [`Button`]
+    /// requests a redraw every time it is drawn.
+    pub fn loading(mut self, is_loading: bool) -> Self {
+        self.is_loading = is_loading;
+        self
+    }
+
     /// Sets the style of the [`Button`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -173,12 +188,22 @@ where
             .pad(self.padding);
 
         let mut content = self.content.layout(renderer, &limits);
+
+        let spinner = f32::from(Renderer::SPINNER_SIZE);
+        let content_size = content.size();
+        let intrinsic = Size::new(
+            content_size.width.max(spinner),
+            content_size.height.max(spinner),
+        );
+
         content.move_to(Point::new(
-            self.padding.left.into(),
-            self.padding.top.into(),
+            f32::from(self.padding.left)
+                + (intrinsic.width - content_size.width) / 2.0,
+            f32::from(self.padding.top)
+                + (intrinsic.height - content_size.height) / 2.0,
         ));
 
-        let size = limits.resolve(content.size()).pad(self.padding);
+        let size = limits.resolve(intrinsic).pad(self.padding);
 
         layout::Node::with_children(size, vec![content])
     }
@@ -187,29 +212,31 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
-        if let event::Status::Captured = self.content.on_event(
+    ) -> event::Status<Message> {
+        let content_status = self.content.on_event(
             event.clone(),
             layout.children().next().unwrap(),
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             messages,
-        ) {
-            return event::Status::Captured;
+        );
+
+        if content_status.is_captured() {
+            return content_status;
         }
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if self.on_press.is_some() {
+                if self.on_press.is_some() && !self.is_loading {
                     let bounds = layout.bounds();
 
-                    if bounds.contains(cursor_position) {
+                    if cursor.is_over(bounds) {
                         self.state.is_pressed = true;
 
                         return event::Status::Captured;
@@ -224,7 +251,7 @@ where
                     if self.state.is_pressed {
                         self.state.is_pressed = false;
 
-                        if bounds.contains(cursor_position) {
+                        if cursor.is_over(bounds) && !self.is_loading {
                             messages.push(on_press);
                         }
 
@@ -246,15 +273,20 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
+        if self.is_loading {
+            renderer.request_redraw();
+        }
+
         renderer.draw(
             defaults,
             layout.bounds(),
-            cursor_position,
+            cursor,
             self.on_press.is_none(),
             self.state.is_pressed,
+            self.is_loading,
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -287,6 +319,9 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The default padding of a [`Button`].
     const DEFAULT_PADDING: Padding;
 
+    /// The size of the spinner of a loading [`Button`].
+    const SPINNER_SIZE: u16;
+
     /// The style supported by this renderer.
     type Style: Default;
 
@@ -295,9 +330,10 @@ pub trait Renderer: crate::Renderer + Sized {
         &mut self,
         defaults: &Self::Defaults,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         is_disabled: bool,
         is_pressed: bool,
+        is_loading: bool,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -316,3 +352,68 @@ where
         Element::new(button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{clipboard, Text};
+
+    fn button(state: &mut State, is_loading: bool) -> Button<'_, (), Null> {
+        Button::new(state, Text::new("Submit"))
+            .on_press(())
+            .loading(is_loading)
+    }
+
+    fn click(widget: &mut Button<'_, (), Null>, messages: &mut Vec<()>) {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = widget.layout(&renderer, &limits);
+
+        for event in vec![
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = widget.on_event(
+                Event::Mouse(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(5.0, 5.0)),
+                &renderer,
+                &mut clipboard,
+                messages,
+            );
+        }
+    }
+
+    #[test]
+    fn loading_button_ignores_presses() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        click(&mut button(&mut state, true), &mut messages);
+        assert!(messages.is_empty());
+
+        click(&mut button(&mut state, false), &mut messages);
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn layout_reserves_space_for_the_spinner() {
+        let renderer = Null::new();
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+
+        let mut state = State::new();
+        let idle = button(&mut state, false).layout(&renderer, &limits);
+        let loading = button(&mut state, true).layout(&renderer, &limits);
+
+        // The label of the `Null` renderer has no width, so the spinner is the
+        // larger of the two
+        assert_eq!(idle.size(), loading.size());
+        assert_eq!(
+            idle.size().width,
+            f32::from(<Null as self::Renderer>::SPINNER_SIZE)
+        );
+    }
+}
@@ -0,0 +1,775 @@
+//! Plot data points as lines or bars.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+const DEFAULT_HEIGHT: u16 = 200;
+const DEFAULT_TICKS: usize = 5;
+const DEFAULT_LABEL_SIZE: u16 = 14;
+const LABEL_SPACING: f32 = 4.0;
+const DEFAULT_BAR_WIDTH_RATIO: f32 = 0.8;
+
+/// A chart plotting some [`Series`] of data points as lines.
+///
+/// The axes are scaled automatically to fit the data, unless their ranges are
+/// set explicitly.
+///
+/// # Example
+/// ```
+/// # use iced_native::{chart, renderer::Null, Color};
+/// #
+/// # pub type LineChart = chart::LineChart<Null>;
+/// let chart = LineChart::new(vec![chart::Series {
+///     points: vec![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)],
+///     color: Color::from_rgb(0.2, 0.4, 0.8),
+///     stroke_width: 2.0,
+/// }])
+/// .y_range(0.0..=4.0);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct LineChart<Renderer: self::Renderer> {
+    series: Vec<Series>,
+    x_range: Option<RangeInclusive<f32>>,
+    y_range: Option<RangeInclusive<f32>>,
+    width: Length,
+    height: Length,
+    ticks: usize,
+    label_size: Option<u16>,
+    style: Renderer::Style,
+}
+
+/// A series of data points of a [`LineChart`], joined by a line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    /// The data points of the [`Series`], as `(x, y)` pairs.
+    pub points: Vec<(f32, f32)>,
+
+    /// The [`Color`] of the line of the [`Series`].
+    pub color: Color,
+
+    /// The width of the line of the [`Series`].
+    pub stroke_width: f32,
+}
+
+impl<Renderer: self::Renderer> LineChart<Renderer> {
+    /// Creates a new [`LineChart`] plotting the given [`Series`].
+    pub fn new(series: Vec<Series>) -> Self {
+        LineChart {
+            series,
+            x_range: None,
+            y_range: None,
+            width: Length::Fill,
+            height: Length::Units(DEFAULT_HEIGHT),
+            ticks: DEFAULT_TICKS,
+            label_size: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the bounds of the horizontal axis of the [`LineChart`].
+    pub fn x_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.x_range = Some(range);
+        self
+    }
+
+    /// Sets the bounds of the vertical axis of the [`LineChart`].
+    pub fn y_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.y_range = Some(range);
+        self
+    }
+
+    /// Sets the width of the [`LineChart`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`LineChart`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the approximate amount of grid lines of every axis of the
+    /// [`LineChart`].
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks.max(1);
+        self
+    }
+
+    /// Sets the text size of the axis labels of the [`LineChart`].
+    pub fn label_size(mut self, size: u16) -> Self {
+        self.label_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`LineChart`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the horizontal and vertical [`Axis`] of the [`LineChart`].
+    pub fn axes(&self) -> (Axis, Axis) {
+        let points = || self.series.iter().flat_map(|series| &series.points);
+
+        let axis =
+            |range: &Option<RangeInclusive<f32>>, values: Vec<f32>| match range
+            {
+                Some(range) => Axis::fixed(range.clone(), self.ticks),
+                None => Axis::fit(values, self.ticks),
+            };
+
+        (
+            axis(&self.x_range, points().map(|(x, _)| *x).collect()),
+            axis(&self.y_range, points().map(|(_, y)| *y).collect()),
+        )
+    }
+}
+
+/// An axis of a [`LineChart`], with its bounds and the values of its grid
+/// lines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis {
+    /// The value at the start of the [`Axis`].
+    pub min: f32,
+
+    /// The value at the end of the [`Axis`].
+    pub max: f32,
+
+    /// The distance between two consecutive ticks of the [`Axis`].
+    pub step: f32,
+
+    /// The values of the grid lines of the [`Axis`], in increasing order.
+    pub ticks: Vec<f32>,
+}
+
+impl Axis {
+    /// Creates an [`Axis`] fitting the given values, rounded out to the
+    /// closest ticks.
+    pub fn fit(values: Vec<f32>, ticks: usize) -> Self {
+        let values = values.into_iter().filter(|value| value.is_finite());
+
+        let (min, max) = values
+            .fold(None, |extents, value| match extents {
+                None => Some((value, value)),
+                Some((min, max)) => {
+                    Some((f32::min(min, value), f32::max(max, value)))
+                }
+            })
+            .unwrap_or((0.0, 1.0));
+
+        let (min, max) = if min == max {
+            let padding = (min.abs() * 0.5).max(1.0);
+
+            (min - padding, max + padding)
+        } else {
+            (min, max)
+        };
+
+        let step = step(max - min, ticks);
+
+        Axis::with_step(
+            (min / step).floor() * step,
+            (max / step).ceil() * step,
+            step,
+        )
+    }
+
+    /// Creates an [`Axis`] with the given bounds.
+    pub fn fixed(range: RangeInclusive<f32>, ticks: usize) -> Self {
+        let (min, max) = (*range.start(), *range.end());
+        let (min, max) = if min < max { (min, max) } else { (max, min) };
+        let span = if max > min { max - min } else { 1.0 };
+
+        Axis::with_step(min, min + span, step(span, ticks))
+    }
+
+    fn with_step(min: f32, max: f32, step: f32) -> Self {
+        let first = (min / step - 1e-3).ceil() as i64;
+        let last = (max / step + 1e-3).floor() as i64;
+
+        Axis {
+            min,
+            max,
+            step,
+            ticks: (first..=last).map(|i| i as f32 * step).collect(),
+        }
+    }
+
+    /// Returns the position of a value along the [`Axis`], from `0.0` at
+    /// its start to `1.0` at its end.
+    pub fn normalize(&self, value: f32) -> f32 {
+        (value - self.min) / (self.max - self.min)
+    }
+
+    /// Returns the label of a value of the [`Axis`], with as many decimals as
+    /// its step needs.
+    pub fn label(&self, value: f32) -> String {
+        let decimals = (-self.step.log10().floor()).max(0.0) as usize;
+
+        // Adding zero turns a negative zero into a positive one
+        format!("{:.*}", decimals, value + 0.0)
+    }
+}
+
+/// Returns a round distance between ticks, splitting the span into roughly
+/// the given amount of ticks.
+fn step(span: f32, ticks: usize) -> f32 {
+    let raw = span / ticks.max(1) as f32;
+    let magnitude = 10.0_f32.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+/// Returns the bounds of the plot of a chart, leaving room for the vertical
+/// labels to its left and the horizontal ones below it.
+fn plot<Renderer: self::Renderer>(
+    renderer: &Renderer,
+    bounds: Rectangle,
+    y_axis: &Axis,
+    label_size: u16,
+) -> Rectangle {
+    let label_width = y_axis
+        .ticks
+        .iter()
+        .map(|tick| {
+            let (width, _) = renderer.measure(
+                &y_axis.label(*tick),
+                label_size,
+                Default::default(),
+                Size::INFINITY,
+            );
+
+            width
+        })
+        .fold(0.0, f32::max);
+
+    let label_height = f32::from(label_size);
+
+    Rectangle {
+        x: bounds.x + label_width + LABEL_SPACING,
+        y: bounds.y + label_height / 2.0,
+        width: (bounds.width - label_width - LABEL_SPACING - label_height)
+            .max(0.0),
+        height: (bounds.height - label_height * 2.0 - LABEL_SPACING).max(0.0),
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for LineChart<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let (x_axis, y_axis) = self.axes();
+        let label_size = self.label_size.unwrap_or(DEFAULT_LABEL_SIZE);
+        let plot = plot(renderer, bounds, &y_axis, label_size);
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            plot,
+            &self.series,
+            &x_axis,
+            &y_axis,
+            label_size,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`LineChart`] and a [`BarChart`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`LineChart`] or a [`BarChart`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`LineChart`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`LineChart`]
+    ///   * the bounds of the plot, excluding the axis labels
+    ///   * the [`Series`] to plot
+    ///   * the horizontal [`Axis`]
+    ///   * the vertical [`Axis`]
+    ///   * the text size of the axis labels
+    ///   * the style of the [`LineChart`]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        plot: Rectangle,
+        series: &[Series],
+        x_axis: &Axis,
+        y_axis: &Axis,
+        label_size: u16,
+        style: &Self::Style,
+    ) -> Self::Output;
+
+    /// Draws a [`BarChart`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`BarChart`]
+    ///   * the bounds of the plot, excluding the labels
+    ///   * the [`Bar`] of every value
+    ///   * the vertical [`Axis`]
+    ///   * the index of the hovered [`Bar`], if any
+    ///   * whether the bars can be clicked
+    ///   * the [`Color`] of every bar, if it overrides the style
+    ///   * the text size of the labels
+    ///   * the style of the [`BarChart`]
+    fn draw_bars(
+        &mut self,
+        bounds: Rectangle,
+        plot: Rectangle,
+        bars: &[Bar],
+        axis: &Axis,
+        hovered: Option<usize>,
+        is_clickable: bool,
+        color: Option<Color>,
+        label_size: u16,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<LineChart<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(chart: LineChart<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(chart)
+    }
+}
+
+/// A chart displaying discrete values as vertical bars.
+///
+/// The vertical axis is scaled automatically to fit the largest value.
+/// Hovering a bar displays its value.
+///
+/// # Example
+/// ```
+/// # use iced_native::{chart, renderer::Null};
+/// #
+/// # pub type BarChart<Message> = chart::BarChart<Message, Null>;
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     BarClicked(usize),
+/// }
+///
+/// let chart = BarChart::new(
+///     vec![("Mon", 3.0), ("Tue", 5.0), ("Wed", 2.0)],
+///     Some(Message::BarClicked),
+/// )
+/// .bar_width_ratio(0.5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct BarChart<Message, Renderer: self::Renderer> {
+    data: Vec<(String, f32)>,
+    on_click: Option<fn(usize) -> Message>,
+    color: Option<Color>,
+    bar_width_ratio: f32,
+    width: Length,
+    height: Length,
+    ticks: usize,
+    label_size: Option<u16>,
+    style: Renderer::Style,
+}
+
+/// A bar of a [`BarChart`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+    /// The label of the [`Bar`], displayed below it.
+    pub label: String,
+
+    /// The value of the [`Bar`].
+    pub value: f32,
+
+    /// The bounds of the [`Bar`].
+    pub bounds: Rectangle,
+}
+
+impl<Message, Renderer: self::Renderer> BarChart<Message, Renderer> {
+    /// Creates a new [`BarChart`] with the given labeled values.
+    ///
+    /// The message produced by `on_click`, if any, receives the index of the
+    /// clicked bar.
+    pub fn new(
+        data: Vec<(impl ToString, f32)>,
+        on_click: Option<fn(usize) -> Message>,
+    ) -> Self {
+        BarChart {
+            data: data
+                .into_iter()
+                .map(|(label, value)| (label.to_string(), value))
+                .collect(),
+            on_click,
+            color: None,
+            bar_width_ratio: DEFAULT_BAR_WIDTH_RATIO,
+            width: Length::Fill,
+            height: Length::Units(DEFAULT_HEIGHT),
+            ticks: DEFAULT_TICKS,
+            label_size: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Color`] of every bar of the [`BarChart`], overriding the
+    /// colors of its style.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the width of the bars of the [`BarChart`], relative to the
+    /// space available to each of them.
+    pub fn bar_width_ratio(mut self, ratio: f32) -> Self {
+        self.bar_width_ratio = ratio.max(0.0).min(1.0);
+        self
+    }
+
+    /// Sets the width of the [`BarChart`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`BarChart`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the approximate amount of horizontal grid lines of the
+    /// [`BarChart`].
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks.max(1);
+        self
+    }
+
+    /// Sets the text size of the labels of the [`BarChart`].
+    pub fn label_size(mut self, size: u16) -> Self {
+        self.label_size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`BarChart`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the vertical [`Axis`] of the [`BarChart`], which always
+    /// includes zero.
+    pub fn axis(&self) -> Axis {
+        let mut values: Vec<f32> =
+            self.data.iter().map(|(_, value)| *value).collect();
+
+        values.push(0.0);
+
+        Axis::fit(values, self.ticks)
+    }
+
+    /// Returns the [`Bar`] of every value of the [`BarChart`], laid out in
+    /// the given plot.
+    pub fn bars(&self, plot: Rectangle, axis: &Axis) -> Vec<Bar> {
+        let slot = plot.width / self.data.len().max(1) as f32;
+        let width = slot * self.bar_width_ratio;
+
+        let to_y = |value| plot.y + (1.0 - axis.normalize(value)) * plot.height;
+        let zero = to_y(0.0);
+
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, (label, value))| {
+                let y = if value.is_finite() {
+                    to_y(*value)
+                } else {
+                    zero
+                };
+
+                Bar {
+                    label: label.clone(),
+                    value: *value,
+                    bounds: Rectangle {
+                        x: plot.x + i as f32 * slot + (slot - width) / 2.0,
+                        y: y.min(zero),
+                        width,
+                        height: (y - zero).abs(),
+                    },
+                }
+            })
+            .collect()
+    }
+
+    fn layout_bars(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+    ) -> (Rectangle, Axis, Vec<Bar>) {
+        let axis = self.axis();
+        let label_size = self.label_size.unwrap_or(DEFAULT_LABEL_SIZE);
+        let plot = plot(renderer, bounds, &axis, label_size);
+        let bars = self.bars(plot, &axis);
+
+        (plot, axis, bars)
+    }
+}
+
+/// Returns the index of the [`Bar`] under the cursor, if any.
+///
+/// The whole column of a bar is hoverable, so short bars are easy to reach.
+fn hovered_bar(
+    plot: Rectangle,
+    bars: &[Bar],
+    cursor: mouse::Cursor,
+) -> Option<usize> {
+    let position = cursor.position_over(plot)?;
+
+    bars.iter().position(|bar| {
+        position.x >= bar.bounds.x
+            && position.x <= bar.bounds.x + bar.bounds.width
+    })
+}
+
+impl<Message, Renderer> Widget<Message, Renderer>
+    for BarChart<Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if let Some(on_click) = self.on_click {
+                let (plot, _, bars) =
+                    self.layout_bars(renderer, layout.bounds());
+
+                if let Some(index) = hovered_bar(plot, &bars, cursor) {
+                    messages.push(on_click(index));
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let (plot, axis, bars) = self.layout_bars(renderer, bounds);
+
+        renderer.draw_bars(
+            bounds,
+            plot,
+            &bars,
+            &axis,
+            hovered_bar(plot, &bars, cursor),
+            self.on_click.is_some(),
+            self.color,
+            self.label_size.unwrap_or(DEFAULT_LABEL_SIZE),
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<BarChart<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        chart: BarChart<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(chart)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::Point;
+
+    #[test]
+    fn axes_are_fitted_to_round_ticks() {
+        let axis = Axis::fit(vec![0.3, 9.7, 4.2], 5);
+
+        assert_eq!(axis.min, 0.0);
+        assert_eq!(axis.max, 10.0);
+        assert_eq!(axis.ticks, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(axis.label(4.0), "4");
+    }
+
+    #[test]
+    fn fixed_ranges_are_kept() {
+        let axis = Axis::fixed(-0.25..=0.75, 4);
+
+        assert_eq!((axis.min, axis.max), (-0.25, 0.75));
+        assert_eq!(axis.ticks, vec![0.0, 0.5]);
+        assert_eq!(axis.label(0.5), "0.5");
+        assert_eq!(axis.normalize(0.25), 0.5);
+    }
+
+    #[test]
+    fn flat_series_are_padded() {
+        let chart = LineChart::<Null>::new(vec![Series {
+            points: vec![(0.0, 3.0), (2.0, 3.0)],
+            color: Color::BLACK,
+            stroke_width: 1.0,
+        }]);
+
+        let (x_axis, y_axis) = chart.axes();
+
+        assert_eq!((x_axis.min, x_axis.max), (0.0, 2.0));
+        assert!(y_axis.min < 3.0 && y_axis.max > 3.0);
+    }
+
+    #[test]
+    fn bars_are_scaled_to_the_largest_value() {
+        let chart =
+            BarChart::<(), Null>::new(vec![("a", 5.0), ("b", 10.0)], None)
+                .bar_width_ratio(0.5);
+
+        let axis = chart.axis();
+
+        assert_eq!((axis.min, axis.max), (0.0, 10.0));
+
+        let plot = Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height: 100.0,
+        };
+
+        let bars = chart.bars(plot, &axis);
+
+        assert_eq!(
+            bars[0].bounds,
+            Rectangle {
+                x: 12.5,
+                y: 50.0,
+                width: 25.0,
+                height: 50.0,
+            }
+        );
+        assert_eq!(bars[1].bounds.height, 100.0);
+        assert_eq!(
+            hovered_bar(
+                plot,
+                &bars,
+                mouse::Cursor::Available(Point::new(70.0, 90.0))
+            ),
+            Some(1)
+        );
+        assert_eq!(
+            hovered_bar(
+                plot,
+                &bars,
+                mouse::Cursor::Available(Point::new(50.0, 90.0))
+            ),
+            None
+        );
+    }
+}
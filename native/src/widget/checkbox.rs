@@ -1,15 +1,14 @@
 //! Show toggle controls using checkboxes.
 use std::hash::Hash;
 
-use crate::alignment::{self, Alignment};
+use crate::alignment;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
-use crate::row;
 use crate::text;
 use crate::touch;
 use crate::{
-    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Row,
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Size,
     Text, Widget,
 };
 
@@ -33,14 +32,18 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
     is_checked: bool,
+    is_indeterminate: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
+    label_position: LabelPosition,
+    toggle_on_label: bool,
     width: Length,
     size: u16,
     spacing: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
     text_color: Option<Color>,
+    icon: Option<Icon<Renderer::Font>>,
     style: Renderer::Style,
 }
 
@@ -61,18 +64,57 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     {
         Checkbox {
             is_checked,
+            is_indeterminate: false,
             on_toggle: Box::new(f),
             label: label.into(),
+            label_position: LabelPosition::After,
+            toggle_on_label: true,
             width: Length::Shrink,
             size: <Renderer as self::Renderer>::DEFAULT_SIZE,
             spacing: Renderer::DEFAULT_SPACING,
             text_size: None,
             font: Renderer::Font::default(),
             text_color: None,
+            icon: None,
             style: Renderer::Style::default(),
         }
     }
 
+    /// Sets whether the [`Checkbox`] is indeterminate.
+    ///
+    /// An indeterminate [`Checkbox`] is drawn with a dash, regardless of
+    /// whether it is checked or not. Clicking it checks it.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
+    /// Sets the [`Icon`] drawn inside the [`Checkbox`] when it is checked,
+    /// instead of the default checkmark.
+    pub fn icon(mut self, icon: Icon<Renderer::Font>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Sets the side of the box where the label of the [`Checkbox`] is
+    /// placed.
+    ///
+    /// By default, the label comes after the box.
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets whether clicking the label of the [`Checkbox`] toggles it.
+    ///
+    /// When disabled, only the box and a small area around it can be clicked,
+    /// which leaves the label free for other interactions. By default, the
+    /// whole [`Checkbox`] can be clicked.
+    pub fn toggle_on_label(mut self, toggle_on_label: bool) -> Self {
+        self.toggle_on_label = toggle_on_label;
+        self
+    }
+
     /// Sets the size of the [`Checkbox`].
     pub fn size(mut self, size: u16) -> Self {
         self.size = size;
@@ -118,10 +160,43 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
     }
 }
 
+impl<Message, Renderer: self::Renderer + text::Renderer>
+    Checkbox<Message, Renderer>
+{
+    /// Returns the bounds where a click toggles the [`Checkbox`].
+    fn toggle_bounds(&self, layout: Layout<'_>) -> Rectangle {
+        if self.toggle_on_label {
+            layout.bounds()
+        } else {
+            let bounds = layout.children().next().unwrap().bounds();
+
+            Rectangle {
+                x: bounds.x - BOX_PADDING,
+                y: bounds.y - BOX_PADDING,
+                width: bounds.width + BOX_PADDING * 2.0,
+                height: bounds.height + BOX_PADDING * 2.0,
+            }
+        }
+    }
+}
+
+/// The space around the box of a [`Checkbox`] that still toggles it when
+/// its label cannot be clicked.
+const BOX_PADDING: f32 = 4.0;
+
+/// The side of the box of a [`Checkbox`] where its label is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelPosition {
+    /// The label is placed before the box.
+    Before,
+    /// The label is placed after the box.
+    After,
+}
+
 impl<Message, Renderer> Widget<Message, Renderer>
     for Checkbox<Message, Renderer>
 where
-    Renderer: self::Renderer + text::Renderer + row::Renderer,
+    Renderer: self::Renderer + text::Renderer,
 {
     fn width(&self) -> Length {
         self.width
@@ -136,40 +211,60 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .push(
-                Text::new(&self.label)
-                    .font(self.font)
-                    .width(self.width)
-                    .size(self.text_size.unwrap_or(renderer.default_size())),
-            )
-            .layout(renderer, limits)
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let size = f32::from(self.size);
+        let spacing = f32::from(self.spacing);
+
+        // The label wraps in the width left by the box
+        let label = Widget::<Message, Renderer>::layout(
+            &Text::new(&self.label)
+                .font(self.font)
+                .width(self.width)
+                .size(self.text_size.unwrap_or(renderer.default_size())),
+            renderer,
+            &limits.shrink(Size::new(size + spacing, 0.0)),
+        );
+
+        let label_size = label.size();
+        let height = size.max(label_size.height);
+
+        let (box_x, label_x) = match self.label_position {
+            LabelPosition::Before => (label_size.width + spacing, 0.0),
+            LabelPosition::After => (0.0, size + spacing),
+        };
+
+        let mut checkbox = layout::Node::new(Size::new(size, size));
+        checkbox.move_to(Point::new(box_x, (height - size) / 2.0));
+
+        let mut label = label;
+        label.move_to(Point::new(label_x, (height - label_size.height) / 2.0));
+
+        layout::Node::with_children(
+            limits
+                .resolve(Size::new(size + spacing + label_size.width, height)),
+            vec![checkbox, label],
+        )
     }
 
     fn on_event(
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+                let mouse_over = cursor.is_over(self.toggle_bounds(layout));
 
                 if mouse_over {
-                    messages.push((self.on_toggle)(!self.is_checked));
+                    let is_checked = self.is_indeterminate || !self.is_checked;
+
+                    messages.push((self.on_toggle)(is_checked));
 
                     return event::Status::Captured;
                 }
@@ -185,10 +280,9 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        let bounds = layout.bounds();
         let mut children = layout.children();
 
         let checkbox_layout = children.next().unwrap();
@@ -207,13 +301,15 @@ where
             alignment::Vertical::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(self.toggle_bounds(layout));
 
         self::Renderer::draw(
             renderer,
             checkbox_bounds,
             self.is_checked,
+            self.is_indeterminate,
             is_mouse_over,
+            self.icon.as_ref(),
             label,
             &self.style,
         )
@@ -224,6 +320,11 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.label.hash(state);
+        self.label_position.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
     }
 }
 
@@ -233,7 +334,7 @@ where
 /// able to use a [`Checkbox`] in your user interface.
 ///
 /// [renderer]: crate::Renderer
-pub trait Renderer: crate::Renderer {
+pub trait Renderer: crate::Renderer + text::Renderer {
     /// The style supported by this renderer.
     type Style: Default;
 
@@ -248,22 +349,35 @@ pub trait Renderer: crate::Renderer {
     /// It receives:
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
+    ///   * whether the [`Checkbox`] is indeterminate or not
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * the custom [`Icon`] of the [`Checkbox`], if any
     ///   * the drawn label of the [`Checkbox`]
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_checked: bool,
+        is_indeterminate: bool,
         is_mouse_over: bool,
+        icon: Option<&Icon<<Self as text::Renderer>::Font>>,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
+/// The icon drawn inside a checked [`Checkbox`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Icon<Font> {
+    /// The font containing the icon.
+    pub font: Font,
+    /// The code point of the icon in the font.
+    pub code_point: char,
+}
+
 impl<'a, Message, Renderer> From<Checkbox<Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
+    Renderer: 'a + self::Renderer + text::Renderer,
     Message: 'a,
 {
     fn from(
@@ -272,3 +386,106 @@ where
         Element::new(checkbox)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+
+    fn click(checkbox: &mut Checkbox<bool, Null>) -> Vec<bool> {
+        let messages = click_at(checkbox, Point::new(5.0, 5.0));
+
+        assert!(!messages.is_empty());
+
+        messages
+    }
+
+    fn click_at(
+        checkbox: &mut Checkbox<bool, Null>,
+        position: Point,
+    ) -> Vec<bool> {
+        let node = checkbox.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        let mut messages = Vec::new();
+
+        let status = checkbox.on_event(
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Layout::new(&node),
+            mouse::Cursor::Available(position),
+            &Null,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+
+        assert_eq!(status == event::Status::Captured, !messages.is_empty());
+
+        messages
+    }
+
+    #[test]
+    fn clicking_an_indeterminate_checkbox_checks_it() {
+        let mut checkbox =
+            Checkbox::new(false, "Select all", |is_checked| is_checked)
+                .indeterminate(true);
+
+        assert_eq!(click(&mut checkbox), vec![true]);
+
+        let mut checkbox =
+            Checkbox::new(true, "Select all", |is_checked| is_checked)
+                .indeterminate(true);
+
+        assert_eq!(click(&mut checkbox), vec![true]);
+    }
+
+    #[test]
+    fn clicking_a_checkbox_toggles_it() {
+        let mut unchecked =
+            Checkbox::new(false, "Toggle", |is_checked| is_checked);
+        let mut checked =
+            Checkbox::new(true, "Toggle", |is_checked| is_checked);
+
+        assert_eq!(click(&mut unchecked), vec![true]);
+        assert_eq!(click(&mut checked), vec![false]);
+    }
+
+    #[test]
+    fn labels_can_be_placed_before_the_box() {
+        let checkbox = Checkbox::<bool, Null>::new(false, "Label", |_| true)
+            .width(Length::Fill)
+            .label_position(LabelPosition::Before);
+
+        let node = checkbox.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+        let layout = Layout::new(&node);
+        let mut children = layout.children();
+
+        let checkbox = children.next().unwrap().bounds();
+        let label = children.next().unwrap().bounds();
+
+        assert_eq!(label.x, 0.0);
+        assert_eq!(label.width, 200.0 - 20.0 - 15.0);
+        assert_eq!(checkbox.x, 180.0);
+        assert_eq!(checkbox.y, 0.0);
+    }
+
+    #[test]
+    fn labels_can_be_left_out_of_the_clickable_area() {
+        let label = Point::new(100.0, 10.0);
+
+        let mut checkbox =
+            Checkbox::new(false, "Label", |is_checked| is_checked)
+                .width(Length::Fill);
+
+        assert_eq!(click_at(&mut checkbox, label), vec![true]);
+
+        let mut checkbox = checkbox.toggle_on_label(false);
+
+        assert_eq!(click_at(&mut checkbox, label), vec![]);
+        assert_eq!(click_at(&mut checkbox, Point::new(22.0, 10.0)), vec![true]);
+    }
+}
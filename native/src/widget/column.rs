@@ -1,17 +1,24 @@
 //! Distribute content vertically.
-use std::hash::Hash;
+use std::hash::{Hash, Hasher as _};
 
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
 use crate::{
-    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
-    Rectangle, Widget,
+    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Rectangle,
+    Widget,
 };
 
 use std::u32;
 
 /// A container that distributes its contents vertically.
+///
+/// The children of a [`Column`] can be identified by a key, using
+/// [`Column::with_children_keyed`] or [`Column::push_keyed`]. Widget state is
+/// owned by your application, so it already follows the data it belongs to
+/// when a list is reordered. Keys let the layout cache tell such reorderings
+/// apart, even when the reordered children look the same.
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message, Renderer> {
     spacing: u16,
@@ -21,6 +28,7 @@ pub struct Column<'a, Message, Renderer> {
     max_width: u32,
     max_height: u32,
     align_items: Alignment,
+    keys: Vec<Option<u64>>,
     children: Vec<Element<'a, Message, Renderer>>,
 }
 
@@ -42,10 +50,23 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
             max_width: u32::MAX,
             max_height: u32::MAX,
             align_items: Alignment::Start,
+            keys: vec![None; children.len()],
             children,
         }
     }
 
+    /// Creates a [`Column`] with the given elements, each identified by a
+    /// unique key.
+    pub fn with_children_keyed<K: Hash>(
+        children: impl IntoIterator<Item = (K, Element<'a, Message, Renderer>)>,
+    ) -> Self {
+        children
+            .into_iter()
+            .fold(Self::new(), |column, (key, child)| {
+                column.push_keyed(key, child)
+            })
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -97,6 +118,21 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     where
         E: Into<Element<'a, Message, Renderer>>,
     {
+        self.keys.push(None);
+        self.children.push(child.into());
+        self
+    }
+
+    /// Adds an element to the [`Column`], identified by a unique key.
+    pub fn push_keyed<K, E>(mut self, key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        let mut hasher = Hasher::default();
+        key.hash(&mut hasher);
+
+        self.keys.push(Some(hasher.finish()));
         self.children.push(child.into());
         self
     }
@@ -141,25 +177,29 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         self.children
             .iter_mut()
             .zip(layout.children())
             .map(|(child, layout)| {
-                child.widget.on_event(
-                    event.clone(),
-                    layout,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    messages,
-                )
+                child
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                    .push_feedback(messages)
             })
             .fold(event::Status::Ignored, event::Status::merge)
+            .cast()
     }
 
     fn draw(
@@ -167,16 +207,10 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(
-            defaults,
-            &self.children,
-            layout,
-            cursor_position,
-            viewport,
-        )
+        renderer.draw(defaults, &self.children, layout, cursor, viewport)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -191,7 +225,8 @@ where
         self.spacing.hash(state);
         self.padding.hash(state);
 
-        for child in &self.children {
+        for (key, child) in self.keys.iter().zip(&self.children) {
+            key.hash(state);
             child.widget.hash_layout(state);
         }
     }
@@ -226,7 +261,7 @@ pub trait Renderer: crate::Renderer + Sized {
         defaults: &Self::Defaults,
         content: &[Element<'_, Message, Self>],
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output;
 }
@@ -243,3 +278,42 @@ where
         Element::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::Text;
+
+    fn hash(column: &Column<'_, (), Null>) -> u64 {
+        let mut hasher = Hasher::with_seed(0);
+        Widget::<(), Null>::hash_layout(column, &mut hasher);
+
+        hasher.finish()
+    }
+
+    fn keyed(keys: &[u32]) -> Column<'static, (), Null> {
+        Column::with_children_keyed(
+            keys.iter().map(|key| (*key, Text::new("Task").into())),
+        )
+    }
+
+    #[test]
+    fn reordering_keys_changes_the_layout_hash() {
+        assert_eq!(hash(&keyed(&[1, 2, 3])), hash(&keyed(&[1, 2, 3])));
+        assert_ne!(hash(&keyed(&[1, 2, 3])), hash(&keyed(&[2, 1, 3])));
+    }
+
+    #[test]
+    fn keys_stay_with_their_children_when_mixed_with_unkeyed_ones() {
+        let keyed_last = Column::<(), Null>::new()
+            .push(Text::new("Header"))
+            .push_keyed(1, Text::new("Task"));
+
+        let keyed_first = Column::<(), Null>::new()
+            .push_keyed(1, Text::new("Header"))
+            .push(Text::new("Task"));
+
+        assert_ne!(hash(&keyed_last), hash(&keyed_first));
+    }
+}
@@ -4,10 +4,11 @@ use std::hash::Hash;
 use crate::alignment::{self, Alignment};
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
-    Widget,
+    Clipboard, Color, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Widget,
 };
 
 use std::u32;
@@ -24,6 +25,8 @@ pub struct Container<'a, Message, Renderer: self::Renderer> {
     max_height: u32,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    backdrop_blur: f32,
+    backdrop_tint: Color,
     style: Renderer::Style,
     content: Element<'a, Message, Renderer>,
 }
@@ -45,6 +48,8 @@ where
             max_height: u32::MAX,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            backdrop_blur: 0.0,
+            backdrop_tint: Color::TRANSPARENT,
             style: Renderer::Style::default(),
             content: content.into(),
         }
@@ -104,6 +109,30 @@ where
         self
     }
 
+    /// Sets the blur radius applied to everything drawn behind the
+    /// [`Container`].
+    ///
+    /// This is useful to make a translucent [`Container`] look like frosted
+    /// glass. Renderers that cannot read back what they have drawn, like
+    /// `iced_glow`, do not blur anything and only draw the tint of the
+    /// backdrop.
+    #[cfg(feature = "backdrop-filter")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "backdrop-filter")))]
+    pub fn backdrop_blur(mut self, blur_radius: f32) -> Self {
+        self.backdrop_blur = blur_radius;
+        self
+    }
+
+    /// Sets the color blended over the blurred backdrop of the [`Container`].
+    ///
+    /// By default, it is transparent.
+    #[cfg(feature = "backdrop-filter")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "backdrop-filter")))]
+    pub fn backdrop_tint(mut self, tint: Color) -> Self {
+        self.backdrop_tint = tint;
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -157,15 +186,15 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         self.content.widget.on_event(
             event,
             layout.children().next().unwrap(),
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             messages,
@@ -177,14 +206,16 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         renderer.draw(
             defaults,
             layout.bounds(),
-            cursor_position,
+            cursor,
             viewport,
+            self.backdrop_blur,
+            self.backdrop_tint,
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -227,8 +258,10 @@ pub trait Renderer: crate::Renderer {
         &mut self,
         defaults: &Self::Defaults,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
+        backdrop_blur: f32,
+        backdrop_tint: Color,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
@@ -0,0 +1,331 @@
+//! Show a menu at the cursor position on right click.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// An element that displays a menu at the cursor position when its content is
+/// right clicked.
+///
+/// The menu is dismissed when the user clicks outside of it or when it
+/// produces a message (i.e. an entry of the menu is selected).
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    menu: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`ContextMenu`] with some local [`State`], the content
+    /// that can be right clicked, and the menu that will be displayed.
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        menu: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        ContextMenu {
+            state,
+            content: content.into(),
+            menu: menu.into(),
+        }
+    }
+}
+
+/// The local state of a [`ContextMenu`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    position: Option<Point>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the menu of the [`ContextMenu`] is currently open.
+    pub fn is_open(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// Closes the menu of the [`ContextMenu`].
+    pub fn close(&mut self) {
+        self.position = None;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if cursor.is_over(layout.bounds()) =>
+            {
+                self.state.position = cursor.position();
+
+                event::Status::Captured
+            }
+            _ => self
+                .content
+                .on_event(event, layout, cursor, renderer, clipboard, messages),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let ContextMenu {
+            state,
+            content,
+            menu,
+        } = self;
+
+        if let Some(position) = state.position {
+            Some(overlay::Element::new(
+                position,
+                Box::new(Overlay {
+                    state: &mut **state,
+                    menu,
+                }),
+            ))
+        } else {
+            content.overlay(layout)
+        }
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer> {
+    state: &'b mut State,
+    menu: &'b mut Element<'a, Message, Renderer>,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds)
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        let mut node = self.menu.layout(renderer, &limits);
+        let size = node.size();
+
+        node.move_to(Point::new(
+            position.x.min(bounds.width - size.width).max(0.0),
+            position.y.min(bounds.height - size.height).max(0.0),
+        ));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(layout.bounds()) =>
+            {
+                self.state.close();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let initial_messages = messages.len();
+
+        let status = self
+            .menu
+            .on_event(event, layout, cursor, renderer, clipboard, messages);
+
+        if messages.len() > initial_messages {
+            self.state.close();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Renderer::Output {
+        self.menu
+            .draw(renderer, defaults, layout, cursor, &layout.bounds())
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+
+        self.menu.hash_layout(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        context_menu: ContextMenu<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(context_menu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{clipboard, Container, Text};
+
+    fn context_menu(state: &mut State) -> ContextMenu<'_, (), Null> {
+        ContextMenu::new(
+            state,
+            Container::new(Text::new("Content"))
+                .width(Length::Units(100))
+                .height(Length::Units(100)),
+            Container::new(Text::new("Menu"))
+                .width(Length::Units(50))
+                .height(Length::Units(50)),
+        )
+    }
+
+    fn press(button: mouse::Button) -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(button))
+    }
+
+    #[test]
+    fn right_press_opens_and_outside_press_closes() {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let bounds = Size::new(400.0, 400.0);
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        {
+            let mut widget = context_menu(&mut state);
+            let node = widget.layout(&renderer, &limits);
+
+            let status = widget.on_event(
+                press(mouse::Button::Right),
+                Layout::new(&node),
+                mouse::Cursor::Available(Point::new(50.0, 50.0)),
+                &renderer,
+                &mut clipboard,
+                &mut messages,
+            );
+
+            assert_eq!(status, event::Status::Captured);
+            assert!(widget.overlay(Layout::new(&node)).is_some());
+        }
+
+        assert!(state.is_open());
+
+        {
+            let mut widget = context_menu(&mut state);
+            let node = widget.layout(&renderer, &limits);
+
+            let mut overlay = widget
+                .overlay(Layout::new(&node))
+                .expect("Context menu overlay");
+
+            let overlay_node = overlay.layout(&renderer, bounds);
+
+            let _ = overlay.on_event(
+                press(mouse::Button::Left),
+                Layout::new(&overlay_node),
+                mouse::Cursor::Available(Point::new(300.0, 300.0)),
+                &renderer,
+                &mut clipboard,
+                &mut messages,
+            );
+        }
+
+        assert!(!state.is_open());
+        assert!(messages.is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+//! Outline the layout of a widget and all of its children.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Rectangle, Widget,
+};
+
+/// A widget whose layout is drawn on top of it, with an outline around every
+/// node of its layout tree.
+///
+/// It is produced by [`Widget::explain`], and it behaves exactly like the
+/// widget it wraps otherwise. It is inspired by the `explain` attribute of
+/// Elm's `Html.Attributes`.
+///
+/// [`Widget::explain`]: crate::Widget::explain
+#[allow(missing_debug_implementations)]
+pub struct ExplainedWidget<W> {
+    widget: W,
+    color: Color,
+}
+
+impl<W> ExplainedWidget<W> {
+    /// Creates a new [`ExplainedWidget`] outlining the layout of the given
+    /// widget with some [`Color`].
+    pub fn new(widget: W, color: Color) -> Self {
+        ExplainedWidget { widget, color }
+    }
+
+    /// Returns the [`Color`] of the outlines of the [`ExplainedWidget`].
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns the widget of the [`ExplainedWidget`], dropping its outlines.
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+impl<Message, Renderer, W> Widget<Message, Renderer> for ExplainedWidget<W>
+where
+    Renderer: layout::Debugger,
+    W: Widget<Message, Renderer>,
+{
+    fn width(&self) -> Length {
+        self.widget.width()
+    }
+
+    fn height(&self) -> Length {
+        self.widget.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.widget.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        self.widget
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.explain(
+            defaults,
+            &self.widget,
+            layout,
+            cursor,
+            viewport,
+            self.color,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.widget.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.widget.overlay(layout)
+    }
+}
+
+impl<'a, Message, Renderer, W> From<ExplainedWidget<W>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + layout::Debugger,
+    W: 'a + Widget<Message, Renderer>,
+{
+    fn from(explained: ExplainedWidget<W>) -> Element<'a, Message, Renderer> {
+        Element::new(explained)
+    }
+}
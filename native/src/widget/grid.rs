@@ -0,0 +1,480 @@
+//! Distribute content in rows and columns.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that distributes its contents in rows and columns.
+///
+/// Children fill the cells of the [`Grid`] from left to right, starting a new
+/// row once every column is taken. A child can span multiple columns.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, Length, Text};
+/// #
+/// # pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Null>;
+/// let grid: Grid<'_, ()> =
+///     Grid::with_columns(vec![Length::Units(100), Length::Fill])
+///         .column_spacing(10)
+///         .row_spacing(5)
+///         .push(Text::new("Name"))
+///         .push(Text::new("Ferris"))
+///         .push_spanning(Text::new("A crab that loves Rust"), 2);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    spans: Vec<usize>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given widths of its columns.
+    ///
+    /// A [`Grid`] always has at least one column.
+    pub fn with_columns(columns: Vec<Length>) -> Self {
+        let columns = if columns.is_empty() {
+            vec![Length::Shrink]
+        } else {
+            columns
+        };
+
+        Grid {
+            columns,
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            spans: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ columns.
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ rows.
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Grid`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Adds an [`Element`] to the next cell of the [`Grid`].
+    pub fn push<E>(self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_spanning(child, 1)
+    }
+
+    /// Adds an [`Element`] to the [`Grid`], covering the given amount of
+    /// columns.
+    ///
+    /// If the current row does not have enough columns left, the
+    /// [`Element`] starts a new row. The span is limited to the amount of
+    /// columns of the [`Grid`].
+    pub fn push_spanning<E>(mut self, child: E, columns: u16) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.spans
+            .push(usize::from(columns).max(1).min(self.columns.len()));
+        self.children.push(child.into());
+        self
+    }
+
+    /// Returns the row and the column of every cell of the [`Grid`].
+    fn positions(&self) -> Vec<(usize, usize)> {
+        let mut row = 0;
+        let mut column = 0;
+
+        self.spans
+            .iter()
+            .map(|span| {
+                if column + span > self.columns.len() {
+                    row += 1;
+                    column = 0;
+                }
+
+                let position = (row, column);
+                column += span;
+
+                position
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let column_spacing = f32::from(self.column_spacing);
+        let row_spacing = f32::from(self.row_spacing);
+        let positions = self.positions();
+
+        let spacing = |span: usize| column_spacing * (span - 1) as f32;
+
+        // Fixed columns take their units, shrinking columns fit their
+        // widest cell, and filling columns share the rest
+        let mut widths: Vec<f32> = self
+            .columns
+            .iter()
+            .map(|column| match column {
+                Length::Units(units) => f32::from(*units),
+                _ => 0.0,
+            })
+            .collect();
+
+        let max_width = limits.max().width;
+        let fill_available = max_width.is_finite();
+
+        for ((child, span), (_, column)) in
+            self.children.iter().zip(&self.spans).zip(&positions)
+        {
+            let is_shrinking = match self.columns[*column] {
+                Length::Shrink => true,
+                Length::Fill | Length::FillPortion(_) => !fill_available,
+                Length::Units(_) => false,
+            };
+
+            if *span == 1 && is_shrinking {
+                let node = child.layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(max_width, limits.max().height),
+                    ),
+                );
+
+                widths[*column] = widths[*column].max(node.size().width);
+            }
+        }
+
+        if fill_available {
+            let taken: f32 = self
+                .columns
+                .iter()
+                .zip(&widths)
+                .filter(|(column, _)| column.fill_factor() == 0)
+                .map(|(_, width)| width)
+                .sum();
+
+            let remaining =
+                (max_width - taken - spacing(self.columns.len())).max(0.0);
+
+            let total_factor: u16 =
+                self.columns.iter().map(Length::fill_factor).sum();
+
+            for (column, width) in self.columns.iter().zip(widths.iter_mut()) {
+                let factor = column.fill_factor();
+
+                if factor > 0 {
+                    *width =
+                        remaining * f32::from(factor) / f32::from(total_factor);
+                }
+            }
+        }
+
+        let offsets: Vec<f32> = widths
+            .iter()
+            .scan(0.0, |x, width| {
+                let offset = *x;
+                *x += width + column_spacing;
+
+                Some(offset)
+            })
+            .collect();
+
+        let rows = positions.last().map(|(row, _)| row + 1).unwrap_or(0);
+        let mut heights = vec![0.0f32; rows];
+
+        let mut nodes: Vec<layout::Node> = self
+            .children
+            .iter()
+            .zip(&self.spans)
+            .zip(&positions)
+            .map(|((child, span), (row, column))| {
+                let width = widths[*column..*column + span].iter().sum::<f32>()
+                    + spacing(*span);
+
+                let node = child.layout(
+                    renderer,
+                    &layout::Limits::new(
+                        Size::ZERO,
+                        Size::new(width, limits.max().height),
+                    ),
+                );
+
+                heights[*row] = heights[*row].max(node.size().height);
+
+                node
+            })
+            .collect();
+
+        let mut y = 0.0;
+        let mut row_offsets = Vec::with_capacity(rows);
+
+        for height in &heights {
+            row_offsets.push(y);
+            y += height + row_spacing;
+        }
+
+        for (node, (row, column)) in nodes.iter_mut().zip(&positions) {
+            node.move_to(Point::new(
+                offsets[*column] + f32::from(self.padding.left),
+                row_offsets[*row] + f32::from(self.padding.top),
+            ));
+        }
+
+        let intrinsic_size = Size::new(
+            widths.iter().sum::<f32>() + spacing(self.columns.len()),
+            heights.iter().sum::<f32>()
+                + row_spacing * rows.saturating_sub(1) as f32,
+        );
+
+        let size = limits.resolve(intrinsic_size);
+
+        layout::Node::with_children(size.pad(self.padding), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                    .push_feedback(messages)
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+            .cast()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.columns.hash(state);
+        self.column_spacing.hash(state);
+        self.row_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+
+        self.spans.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Grid`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Grid`].
+    ///
+    /// It receives:
+    /// - the children of the [`Grid`], in the order of its cells
+    /// - the [`Layout`] of the [`Grid`] and its children
+    /// - the cursor position
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{Container, Text};
+
+    fn cell(width: u16, height: u16) -> Element<'static, (), Null> {
+        Container::new(Text::new("Cell"))
+            .width(Length::Units(width))
+            .height(Length::Units(height))
+            .into()
+    }
+
+    fn bounds(node: &layout::Node) -> Vec<Rectangle> {
+        node.children().iter().map(layout::Node::bounds).collect()
+    }
+
+    #[test]
+    fn cells_are_placed_in_rows_and_columns() {
+        let renderer = Null::new();
+
+        let grid: Grid<'_, (), Null> =
+            Grid::with_columns(vec![Length::Units(50), Length::Units(70)])
+                .column_spacing(10)
+                .row_spacing(5)
+                .push(cell(40, 20))
+                .push(cell(40, 30))
+                .push(cell(40, 20))
+                .push(cell(40, 20));
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = grid.layout(&renderer, &limits);
+
+        let positions: Vec<_> = bounds(&node)
+            .iter()
+            .map(|bounds| bounds.position())
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(60.0, 0.0),
+                Point::new(0.0, 35.0),
+                Point::new(60.0, 35.0),
+            ]
+        );
+        assert_eq!(node.size(), Size::new(130.0, 55.0));
+    }
+
+    #[test]
+    fn spanning_cells_cover_multiple_columns() {
+        let renderer = Null::new();
+
+        let grid: Grid<'_, (), Null> =
+            Grid::with_columns(vec![Length::Units(50), Length::Units(70)])
+                .column_spacing(10)
+                .push(cell(40, 20))
+                .push_spanning(
+                    Container::new(Text::new("Wide"))
+                        .width(Length::Fill)
+                        .height(Length::Units(20)),
+                    2,
+                );
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = grid.layout(&renderer, &limits);
+
+        // The spanning cell does not fit next to the first one
+        assert_eq!(
+            bounds(&node)[1],
+            Rectangle {
+                x: 0.0,
+                y: 20.0,
+                width: 130.0,
+                height: 20.0,
+            }
+        );
+    }
+}
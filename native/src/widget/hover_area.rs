@@ -0,0 +1,228 @@
+//! Produce messages when the cursor enters or leaves some content.
+//!
+//! A [`HoverArea`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::{Clipboard, Element, Hasher, Layout, Length, Rectangle, Widget};
+
+use std::hash::Hash;
+
+/// An element that produces messages when the cursor enters or leaves its
+/// content.
+///
+/// A [`HoverArea`] does not capture any event, so its content stays fully
+/// interactive.
+#[allow(missing_debug_implementations)]
+pub struct HoverArea<'a, Message, Renderer> {
+    state: &'a mut State,
+    on_hover: Option<Message>,
+    on_exit: Option<Message>,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> HoverArea<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`HoverArea`] with some local [`State`] and the content
+    /// to track.
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        HoverArea {
+            state,
+            on_hover: None,
+            on_exit: None,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the message that will be produced when the cursor enters the
+    /// [`HoverArea`].
+    pub fn on_hover(mut self, message: Message) -> Self {
+        self.on_hover = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the cursor leaves the
+    /// [`HoverArea`].
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+}
+
+/// The local state of a [`HoverArea`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_hovered: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the cursor is currently over the [`HoverArea`].
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for HoverArea<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let is_hovered = match event {
+            Event::Mouse(mouse::Event::CursorLeft) => Some(false),
+            Event::Mouse(_) => Some(cursor.is_over(layout.bounds())),
+            _ => None,
+        };
+
+        if let Some(is_hovered) = is_hovered {
+            if is_hovered != self.state.is_hovered {
+                self.state.is_hovered = is_hovered;
+
+                let message = if is_hovered {
+                    &self.on_hover
+                } else {
+                    &self.on_exit
+                };
+
+                if let Some(message) = message {
+                    messages.push(message.clone());
+                }
+            }
+        }
+
+        self.content
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+}
+
+impl<'a, Message, Renderer> From<HoverArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        hover_area: HoverArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(hover_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{clipboard, Container, Point, Size, Text};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Hovered,
+        Exited,
+    }
+
+    #[test]
+    fn cursor_in_and_out_produces_one_enter_and_one_exit() {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+
+        let path = [
+            Point::new(150.0, 150.0),
+            Point::new(50.0, 50.0),
+            Point::new(60.0, 60.0),
+            Point::new(70.0, 70.0),
+            Point::new(250.0, 250.0),
+            Point::new(300.0, 300.0),
+        ];
+
+        for position in path.iter().copied() {
+            let mut widget: HoverArea<'_, Message, Null> = HoverArea::new(
+                &mut state,
+                Container::new(Text::new("Content"))
+                    .width(Length::Units(100))
+                    .height(Length::Units(100)),
+            )
+            .on_hover(Message::Hovered)
+            .on_exit(Message::Exited);
+
+            let node = widget.layout(&renderer, &limits);
+
+            let _ = widget.on_event(
+                Event::Mouse(mouse::Event::CursorMoved { position }),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &renderer,
+                &mut clipboard,
+                &mut messages,
+            );
+        }
+
+        assert_eq!(messages, vec![Message::Hovered, Message::Exited]);
+        assert!(!state.is_hovered());
+    }
+}
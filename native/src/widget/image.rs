@@ -2,17 +2,21 @@
 pub mod viewer;
 pub use viewer::Viewer;
 
-use crate::layout;
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+pub use iced_core::image::{Data, Handle};
 
-use std::{
-    hash::{Hash, Hasher as _},
-    path::PathBuf,
-    sync::Arc,
+use crate::layout;
+use crate::mouse;
+use crate::{
+    ContentFit, Element, Hasher, Layout, Length, Rectangle, Size, Vector,
+    Widget,
 };
 
+use std::hash::Hash;
+
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// The way the image fits its bounds can be changed with a [`ContentFit`].
+///
 /// # Example
 ///
 /// ```
@@ -27,6 +31,7 @@ pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
 }
 
 impl Image {
@@ -36,6 +41,7 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
         }
     }
 
@@ -50,6 +56,14 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -69,24 +83,15 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
+        let image_size = renderer.measure_image(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        layout::Node::new(layout(
+            limits,
+            image_size,
+            self.width,
+            self.height,
+            self.content_fit,
+        ))
     }
 
     fn draw(
@@ -94,10 +99,16 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        let image_size = renderer.measure_image(&self.handle);
+
+        renderer.draw(
+            self.handle.clone(),
+            content_bounds(layout.bounds(), image_size, self.content_fit),
+            layout,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -107,113 +118,55 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
-/// An [`Image`] handle.
-#[derive(Debug, Clone)]
-pub struct Handle {
-    id: u64,
-    data: Arc<Data>,
-}
-
-impl Handle {
-    /// Creates an image [`Handle`] pointing to the image of the given path.
-    ///
-    /// Makes an educated guess about the image format by examining the data in the file.
-    pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
-        Self::from_data(Data::Path(path.into()))
-    }
-
-    /// Creates an image [`Handle`] containing the image pixels directly. This
-    /// function expects the input data to be provided as a `Vec<u8>` of BGRA
-    /// pixels.
-    ///
-    /// This is useful if you have already decoded your image.
-    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
-        Self::from_data(Data::Pixels {
-            width,
-            height,
-            pixels,
-        })
-    }
-
-    /// Creates an image [`Handle`] containing the image data directly.
-    ///
-    /// Makes an educated guess about the image format by examining the given data.
-    ///
-    /// This is useful if you already have your image loaded in-memory, maybe
-    /// because you downloaded or generated it procedurally.
-    pub fn from_memory(bytes: Vec<u8>) -> Handle {
-        Self::from_data(Data::Bytes(bytes))
-    }
-
-    fn from_data(data: Data) -> Handle {
-        let mut hasher = Hasher::default();
-        data.hash(&mut hasher);
-
-        Handle {
-            id: hasher.finish(),
-            data: Arc::new(data),
-        }
-    }
-
-    /// Returns the unique identifier of the [`Handle`].
-    pub fn id(&self) -> u64 {
-        self.id
-    }
-
-    /// Returns a reference to the image [`Data`].
-    pub fn data(&self) -> &Data {
-        &self.data
-    }
-}
-
-impl<T> From<T> for Handle
-where
-    T: Into<PathBuf>,
-{
-    fn from(path: T) -> Handle {
-        Handle::from_path(path.into())
-    }
-}
-
-impl Hash for Handle {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.id.hash(state);
+/// Computes the layout size of some content with the given natural size,
+/// fitted in the given limits with a [`ContentFit`].
+///
+/// A [`Length::Shrink`] dimension never grows beyond the fitted content.
+pub fn layout(
+    limits: &layout::Limits,
+    content_size: Size,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+) -> Size {
+    let raw_size = limits.width(width).height(height).resolve(content_size);
+
+    let full_size = content_fit.fit(content_size, raw_size);
+
+    Size {
+        width: match width {
+            Length::Shrink => raw_size.width.min(full_size.width),
+            _ => raw_size.width,
+        },
+        height: match height {
+            Length::Shrink => raw_size.height.min(full_size.height),
+            _ => raw_size.height,
+        },
     }
 }
 
-/// The data of an [`Image`].
-#[derive(Clone, Hash)]
-pub enum Data {
-    /// File data
-    Path(PathBuf),
-
-    /// In-memory data
-    Bytes(Vec<u8>),
-
-    /// Decoded image pixels in BGRA format.
-    Pixels {
-        /// The width of the image.
-        width: u32,
-        /// The height of the image.
-        height: u32,
-        /// The pixels.
-        pixels: Vec<u8>,
-    },
-}
-
-impl std::fmt::Debug for Data {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Data::Path(path) => write!(f, "Path({:?})", path),
-            Data::Bytes(_) => write!(f, "Bytes(...)"),
-            Data::Pixels { width, height, .. } => {
-                write!(f, "Pixels({} * {})", width, height)
-            }
-        }
-    }
+/// Returns the bounds of some content with the given natural size, fitted
+/// with a [`ContentFit`] and centered in the given bounds.
+///
+/// The returned bounds may overflow the given ones, in which case the
+/// content should be clipped.
+pub fn content_bounds(
+    bounds: Rectangle,
+    content_size: Size,
+    content_fit: ContentFit,
+) -> Rectangle {
+    let size = content_fit.fit(content_size, bounds.size());
+
+    let offset = Vector::new(
+        (bounds.width - size.width) / 2.0,
+        (bounds.height - size.height) / 2.0,
+    );
+
+    Rectangle::new(bounds.position() + offset, size)
 }
 
 /// The renderer of an [`Image`].
@@ -226,8 +179,24 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an [`Image`] located on the given path.
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
-    /// Draws an [`Image`].
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// Returns the natural [`Size`] of an [`Image`], in pixels.
+    ///
+    /// It can be used by widgets that lay out an image keeping its aspect
+    /// ratio.
+    fn measure_image(&self, handle: &Handle) -> Size {
+        let (width, height) = self.dimensions(handle);
+
+        Size::new(width as f32, height as f32)
+    }
+
+    /// Draws an [`Image`] in the given `bounds`, clipped to the bounds of the
+    /// given [`Layout`].
+    fn draw(
+        &mut self,
+        handle: Handle,
+        bounds: Rectangle,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -238,3 +207,30 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    #[test]
+    fn contain_preserves_the_aspect_ratio_within_the_bounds() {
+        let limits = layout::Limits::new(Size::ZERO, Size::new(300.0, 300.0));
+        let image_size = Size::new(400.0, 200.0);
+
+        let size = layout(
+            &limits,
+            image_size,
+            Length::Fill,
+            Length::Shrink,
+            ContentFit::Contain,
+        );
+
+        assert_eq!(size, Size::new(300.0, 150.0));
+
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(300.0, 300.0));
+        let content = content_bounds(bounds, image_size, ContentFit::Contain);
+
+        assert_eq!(content, Rectangle::new(Point::new(0.0, 75.0), size));
+    }
+}
@@ -165,18 +165,18 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         _messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
 
-        match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta })
-                if is_mouse_over =>
-            {
+        match (event, cursor.position_over(bounds)) {
+            (
+                Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                Some(cursor_position),
+            ) => {
                 match delta {
                     mouse::ScrollDelta::Lines { y, .. }
                     | mouse::ScrollDelta::Pixels { y, .. } => {
@@ -223,22 +223,24 @@ where
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                if is_mouse_over =>
-            {
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Some(cursor_position),
+            ) => {
                 self.state.cursor_grabbed_at = Some(cursor_position);
                 self.state.starting_offset = self.state.current_offset;
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
-                if self.state.cursor_grabbed_at.is_some() =>
-            {
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                _,
+            ) if self.state.cursor_grabbed_at.is_some() => {
                 self.state.cursor_grabbed_at = None;
 
                 event::Status::Captured
             }
-            Event::Mouse(mouse::Event::CursorMoved { position }) => {
+            (Event::Mouse(mouse::Event::CursorMoved { position }), _) => {
                 if let Some(origin) = self.state.cursor_grabbed_at {
                     let image_size = self.image_size(renderer, bounds.size());
 
@@ -285,7 +287,7 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
@@ -301,7 +303,7 @@ where
             image_top_left - self.state.offset(bounds, image_size)
         };
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
         self::Renderer::draw(
             renderer,
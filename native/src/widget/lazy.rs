@@ -0,0 +1,340 @@
+//! Build some content only when its dependency changes.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::trace;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
+
+use std::cell::RefCell;
+use std::hash::Hash;
+
+/// An element that builds its content only when its dependency changes.
+///
+/// The content, its layout and its drawn output are kept in a [`State`] and
+/// reused across frames for as long as the dependency stays the same. This
+/// can be useful to avoid rebuilding expensive subtrees on every frame.
+///
+/// The content must own all of its data, so it cannot contain widgets that
+/// borrow some local state.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Lazy<'a, Dependency, Message> =
+/// #     iced_native::Lazy<'a, Dependency, Message, Null>;
+/// # pub type State<Dependency, Message> =
+/// #     iced_native::lazy::State<Dependency, Message, Null>;
+/// let mut state = State::new();
+/// let count = 42;
+///
+/// let summary = Lazy::<_, ()>::new(&mut state, count, |count| {
+///     Text::new(format!("{} items", count)).into()
+/// });
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Lazy<'a, Dependency, Message, Renderer: self::Renderer> {
+    content: &'a mut Content<Dependency, Message, Renderer>,
+}
+
+impl<'a, Dependency, Message, Renderer> Lazy<'a, Dependency, Message, Renderer>
+where
+    Dependency: Hash + Eq,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Lazy`] with the given [`State`] and dependency.
+    ///
+    /// The `view` closure builds the content, and it is only called when the
+    /// dependency differs from the one of the previous [`Lazy`] using the
+    /// same [`State`].
+    pub fn new(
+        state: &'a mut State<Dependency, Message, Renderer>,
+        dependency: Dependency,
+        view: impl FnOnce(&Dependency) -> Element<'static, Message, Renderer>,
+    ) -> Self {
+        let is_outdated = match &state.content {
+            Some(content) => content.dependency != dependency,
+            None => true,
+        };
+
+        if is_outdated {
+            trace::cache_rebuilt("lazy");
+
+            let element = view(&dependency);
+
+            state.content = Some(Content {
+                dependency,
+                element,
+                layout: RefCell::new(None),
+                output: RefCell::new(None),
+            });
+        }
+
+        Lazy {
+            content: state.content.as_mut().expect("Lazy content"),
+        }
+    }
+}
+
+/// The local state of a [`Lazy`].
+///
+/// It keeps the content built for the last dependency.
+#[allow(missing_debug_implementations)]
+pub struct State<Dependency, Message, Renderer: self::Renderer> {
+    content: Option<Content<Dependency, Message, Renderer>>,
+}
+
+impl<Dependency, Message, Renderer> State<Dependency, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new empty [`State`].
+    pub fn new() -> Self {
+        State { content: None }
+    }
+
+    /// Forgets the content of the [`State`], forcing the next [`Lazy`] to
+    /// build it again.
+    pub fn clear(&mut self) {
+        self.content = None;
+    }
+}
+
+impl<Dependency, Message, Renderer> Default
+    for State<Dependency, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Content<Dependency, Message, Renderer: self::Renderer> {
+    dependency: Dependency,
+    element: Element<'static, Message, Renderer>,
+    layout: RefCell<Option<(layout::Limits, layout::Node)>>,
+    output: RefCell<Option<Output<Renderer::Key, Renderer::Cached>>>,
+}
+
+struct Output<Key, Cached> {
+    bounds: Rectangle,
+    cursor: Option<Point>,
+    viewport: Rectangle,
+    key: Key,
+    cached: Cached,
+}
+
+impl<'a, Dependency, Message, Renderer> Widget<Message, Renderer>
+    for Lazy<'a, Dependency, Message, Renderer>
+where
+    Dependency: Hash,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.element.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.element.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let mut cache = self.content.layout.borrow_mut();
+
+        match cache.as_ref() {
+            Some((cached_limits, node)) if cached_limits == limits => {
+                node.clone()
+            }
+            _ => {
+                let node = self.content.element.layout(renderer, limits);
+
+                *cache = Some((*limits, node.clone()));
+
+                node
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let status = self
+            .content
+            .element
+            .on_event(event, layout, cursor, renderer, clipboard, messages);
+
+        // The content may draw differently after handling an event
+        if status.is_captured() {
+            *self.content.output.borrow_mut() = None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let cursor_position = cursor.position_over(bounds);
+        let key = renderer.cache_key(defaults);
+
+        let mut cache = self.content.output.borrow_mut();
+
+        if let Some(output) = cache.as_ref() {
+            if output.bounds == bounds
+                && output.cursor == cursor_position
+                && output.viewport == *viewport
+                && output.key == key
+            {
+                return renderer.draw_cached(&output.cached);
+            }
+        }
+
+        let output = self
+            .content
+            .element
+            .draw(renderer, defaults, layout, cursor, viewport);
+
+        let cached = renderer.cache(output);
+        let output = renderer.draw_cached(&cached);
+
+        *cache = Some(Output {
+            bounds,
+            cursor: cursor_position,
+            viewport: *viewport,
+            key,
+            cached,
+        });
+
+        output
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        // The content is only rebuilt when its dependency changes
+        self.content.dependency.hash(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.element.overlay(layout)
+    }
+}
+
+/// The renderer of a [`Lazy`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Lazy`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// The drawn output of the content of a [`Lazy`], kept across frames.
+    type Cached;
+
+    /// The styling a [`Lazy`] was drawn with, besides its layout and the
+    /// cursor.
+    ///
+    /// A cached output is only reused while its key stays the same.
+    type Key: PartialEq;
+
+    /// Returns the [`Key`] of the output drawn with the given defaults.
+    ///
+    /// It should include anything else the content may be styled with, like
+    /// the active theme of the [`Renderer`].
+    ///
+    /// [`Key`]: Self::Key
+    fn cache_key(&self, defaults: &Self::Defaults) -> Self::Key;
+
+    /// Turns the drawn output of the content of a [`Lazy`] into something
+    /// that can be kept across frames.
+    fn cache(&mut self, output: Self::Output) -> Self::Cached;
+
+    /// Draws the cached output of the content of a [`Lazy`] again.
+    ///
+    /// It should be much cheaper than drawing the content itself.
+    fn draw_cached(&mut self, cached: &Self::Cached) -> Self::Output;
+}
+
+impl<'a, Dependency, Message, Renderer>
+    From<Lazy<'a, Dependency, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Dependency: 'a + Hash,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        lazy: Lazy<'a, Dependency, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(lazy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{Cache, Size, Text, UserInterface};
+
+    use std::cell::Cell;
+
+    #[test]
+    fn content_is_only_built_when_its_dependency_changes() {
+        let mut renderer = Null::new();
+        let mut state = State::new();
+        let builds = Cell::new(0);
+
+        let mut frame = |state: &mut State<u32, (), Null>,
+                         cache: Cache,
+                         dependency: u32| {
+            let lazy = Lazy::new(state, dependency, |count| {
+                builds.set(builds.get() + 1);
+
+                Text::new(format!("{} items", count)).into()
+            });
+
+            let mut user_interface = UserInterface::build(
+                lazy,
+                Size::new(400.0, 400.0),
+                cache,
+                &mut renderer,
+            );
+
+            user_interface.draw(&mut renderer, mouse::Cursor::Unavailable);
+
+            user_interface.into_cache()
+        };
+
+        let cache = frame(&mut state, Cache::new(), 1);
+        let cache = frame(&mut state, cache, 1);
+
+        assert_eq!(builds.get(), 1);
+
+        let _ = frame(&mut state, cache, 2);
+
+        assert_eq!(builds.get(), 2);
+    }
+}
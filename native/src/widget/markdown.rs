@@ -0,0 +1,661 @@
+//! Display CommonMark documents as a tree of widgets.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::widget::{Column, Container, Row, Rule, Text, Wrap};
+use crate::{
+    column, container, row, rule, text, wrap, Clipboard, Color, Element,
+    Hasher, Layout, Length, Padding, Rectangle, Widget,
+};
+
+use pulldown_cmark::{Event as Markdown, Parser, Tag};
+use std::hash::Hash;
+use std::iter::Peekable;
+
+const DEFAULT_TEXT_SIZE: u16 = 20;
+const DEFAULT_LINK_COLOR: Color = Color::from_rgb(0.2, 0.4, 0.8);
+
+/// A CommonMark document, displayed as a [`Column`] of widgets.
+///
+/// Headings are drawn as larger [`Text`], paragraphs are wrapped word by word,
+/// code blocks are placed in a [`Container`] and links produce a message when
+/// clicked.
+///
+/// The document is parsed once, when the [`MarkdownView`] is created, and it
+/// is turned into widgets when converted into an [`Element`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{markdown, renderer::Null};
+/// #
+/// # pub type MarkdownView<Message> = markdown::MarkdownView<Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let readme = MarkdownView::new("# iced\nA [GUI library](https://iced.rs).")
+///     .text_size(16)
+///     .on_link_click(Message::LinkClicked);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MarkdownView<Message, Renderer: self::Renderer> {
+    blocks: Vec<Block>,
+    text_size: u16,
+    spacing: u16,
+    link_color: Color,
+    code_font: Option<<Renderer as text::Renderer>::Font>,
+    code_style:
+        Option<Box<dyn Fn() -> <Renderer as container::Renderer>::Style>>,
+    on_link_click: Option<Box<dyn Fn(String) -> Message>>,
+}
+
+impl<Message, Renderer> MarkdownView<Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`MarkdownView`] of the given CommonMark source.
+    pub fn new(source: &str) -> Self {
+        MarkdownView {
+            blocks: parse(source),
+            text_size: DEFAULT_TEXT_SIZE,
+            spacing: DEFAULT_TEXT_SIZE / 2,
+            link_color: DEFAULT_LINK_COLOR,
+            code_font: None,
+            code_style: None,
+            on_link_click: None,
+        }
+    }
+
+    /// Sets the size of the body text of the [`MarkdownView`].
+    ///
+    /// Headings are scaled relative to it.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = size;
+        self
+    }
+
+    /// Sets the vertical spacing between the blocks of the [`MarkdownView`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the [`Color`] of the links of the [`MarkdownView`].
+    pub fn link_color(mut self, color: Color) -> Self {
+        self.link_color = color;
+        self
+    }
+
+    /// Sets the font of the code spans and code blocks of the
+    /// [`MarkdownView`].
+    pub fn code_font(
+        mut self,
+        font: impl Into<<Renderer as text::Renderer>::Font>,
+    ) -> Self {
+        self.code_font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Container`] of every code block of the
+    /// [`MarkdownView`].
+    pub fn code_style<S>(mut self, style: S) -> Self
+    where
+        S: 'static + Clone + Into<<Renderer as container::Renderer>::Style>,
+    {
+        self.code_style = Some(Box::new(move || style.clone().into()));
+        self
+    }
+
+    /// Sets the function that produces a message when a link of the
+    /// [`MarkdownView`] is clicked.
+    ///
+    /// It receives the destination of the link.
+    pub fn on_link_click<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        self.on_link_click = Some(Box::new(f));
+        self
+    }
+
+    fn heading_size(&self, level: u32) -> u16 {
+        let scale = match level {
+            1 => 2.0,
+            2 => 1.6,
+            3 => 1.3,
+            4 => 1.15,
+            _ => 1.0,
+        };
+
+        (f32::from(self.text_size) * scale).round() as u16
+    }
+
+    fn blocks<'a>(&self, blocks: &[Block]) -> Column<'a, Message, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a,
+    {
+        blocks.iter().fold(
+            Column::new().spacing(self.spacing).width(Length::Fill),
+            |column, block| column.push(self.block(block)),
+        )
+    }
+
+    fn block<'a>(&self, block: &Block) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a,
+    {
+        match block {
+            Block::Heading(level, lines) => {
+                self.lines(lines, self.heading_size(*level)).into()
+            }
+            Block::Paragraph(lines) => self.lines(lines, self.text_size).into(),
+            Block::Code(code) => {
+                let mut text = Text::new(code.clone()).size(self.text_size);
+
+                if let Some(font) = self.code_font {
+                    text = text.font(font);
+                }
+
+                let mut container = Container::new(text)
+                    .width(Length::Fill)
+                    .padding(self.text_size / 2);
+
+                if let Some(style) = &self.code_style {
+                    container = container.style(style());
+                }
+
+                container.into()
+            }
+            Block::Quote(blocks) => self
+                .blocks(blocks)
+                .padding(Padding {
+                    left: self.text_size,
+                    ..Padding::ZERO
+                })
+                .into(),
+            Block::List { start, items } => items
+                .iter()
+                .enumerate()
+                .fold(
+                    Column::new().spacing(self.spacing / 2),
+                    |column, (i, item)| {
+                        let marker = match start {
+                            Some(start) => format!("{}.", start + i as u64),
+                            None => String::from("•"),
+                        };
+
+                        column.push(
+                            Row::new()
+                                .spacing(self.text_size / 2)
+                                .width(Length::Fill)
+                                .push(Text::new(marker).size(self.text_size))
+                                .push(self.blocks(item)),
+                        )
+                    },
+                )
+                .into(),
+            Block::Rule => Rule::horizontal(self.spacing).into(),
+        }
+    }
+
+    fn lines<'a>(
+        &self,
+        lines: &[Vec<Span>],
+        size: u16,
+    ) -> Column<'a, Message, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a,
+    {
+        lines
+            .iter()
+            .fold(Column::new().width(Length::Fill), |column, line| {
+                let wrap = words(line).into_iter().fold(
+                    Wrap::new().spacing(size / 4).width(Length::Fill),
+                    |wrap, word| {
+                        if word.len() == 1 {
+                            wrap.push(self.span(&word[0], size))
+                        } else {
+                            wrap.push(word.iter().fold(
+                                Row::new(),
+                                |row, fragment| {
+                                    row.push(self.span(fragment, size))
+                                },
+                            ))
+                        }
+                    },
+                );
+
+                column.push(wrap)
+            })
+    }
+
+    fn span<'a>(&self, span: &Span, size: u16) -> Element<'a, Message, Renderer>
+    where
+        Message: 'a + Clone,
+        Renderer: 'a,
+    {
+        let mut text = Text::new(span.text.clone()).size(size);
+
+        if span.is_code {
+            if let Some(font) = self.code_font {
+                text = text.font(font);
+            }
+        }
+
+        match &span.link {
+            Some(destination) => {
+                let on_click = self
+                    .on_link_click
+                    .as_ref()
+                    .map(|on_link_click| on_link_click(destination.clone()));
+
+                Element::new(Link {
+                    content: text.color(self.link_color).into(),
+                    on_click,
+                })
+            }
+            None => text.into(),
+        }
+    }
+}
+
+/// The renderer of a [`MarkdownView`].
+///
+/// A [`MarkdownView`] is only made of other widgets, so any [renderer]
+/// supporting them is able to draw it.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer:
+    column::Renderer
+    + row::Renderer
+    + wrap::Renderer
+    + text::Renderer
+    + container::Renderer
+    + rule::Renderer
+{
+}
+
+impl<T> Renderer for T where
+    T: column::Renderer
+        + row::Renderer
+        + wrap::Renderer
+        + text::Renderer
+        + container::Renderer
+        + rule::Renderer
+{
+}
+
+impl<'a, Message, Renderer> From<MarkdownView<Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        markdown: MarkdownView<Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        markdown.blocks(&markdown.blocks).into()
+    }
+}
+
+/// Some text that produces a message when clicked.
+struct Link<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_click: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Link<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if let Some(on_click) = &self.on_click {
+                if cursor.is_over(layout.bounds()) {
+                    messages.push(on_click.clone());
+
+                    return event::Status::Captured;
+                }
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+}
+
+/// A block of a CommonMark document.
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading(u32, Vec<Vec<Span>>),
+    Paragraph(Vec<Vec<Span>>),
+    Code(String),
+    Quote(Vec<Block>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    Rule,
+}
+
+/// A run of text of a paragraph sharing the same style.
+#[derive(Debug, Clone, PartialEq)]
+struct Span {
+    text: String,
+    is_code: bool,
+    link: Option<String>,
+}
+
+fn parse(source: &str) -> Vec<Block> {
+    blocks(&mut Parser::new(source).peekable())
+}
+
+fn blocks<'a>(
+    events: &mut Peekable<impl Iterator<Item = Markdown<'a>>>,
+) -> Vec<Block> {
+    let mut blocks = Vec::new();
+
+    while let Some(event) = events.peek() {
+        if is_inline(event) {
+            // Tight list items contain their text without a paragraph
+            let lines = lines(events);
+
+            if lines.iter().any(|line| !line.is_empty()) {
+                blocks.push(Block::Paragraph(lines));
+            }
+
+            continue;
+        }
+
+        match events.next() {
+            Some(Markdown::Start(Tag::Heading(level))) => {
+                blocks.push(Block::Heading(level, lines(events)));
+            }
+            Some(Markdown::Start(Tag::Paragraph)) => {
+                blocks.push(Block::Paragraph(lines(events)));
+            }
+            Some(Markdown::Start(Tag::CodeBlock(_))) => {
+                let mut code = String::new();
+
+                for event in events.by_ref() {
+                    match event {
+                        Markdown::Text(text) => code.push_str(&text),
+                        Markdown::End(_) => break,
+                        _ => {}
+                    }
+                }
+
+                let code = code.trim_end_matches('\n');
+
+                blocks.push(Block::Code(String::from(code)));
+            }
+            Some(Markdown::Start(Tag::BlockQuote)) => {
+                blocks.push(Block::Quote(self::blocks(events)));
+            }
+            Some(Markdown::Start(Tag::List(start))) => {
+                let mut items = Vec::new();
+
+                while let Some(event) = events.next() {
+                    match event {
+                        Markdown::Start(Tag::Item) => {
+                            items.push(self::blocks(events));
+                        }
+                        _ => break,
+                    }
+                }
+
+                blocks.push(Block::List { start, items });
+            }
+            Some(Markdown::Rule) => blocks.push(Block::Rule),
+            Some(Markdown::Start(_)) => {
+                // Tables, footnotes and other extensions are not supported
+                skip(events);
+            }
+            Some(Markdown::End(_)) | None => break,
+            Some(_) => {}
+        }
+    }
+
+    blocks
+}
+
+fn lines<'a>(
+    events: &mut Peekable<impl Iterator<Item = Markdown<'a>>>,
+) -> Vec<Vec<Span>> {
+    let mut lines = vec![Vec::new()];
+    let mut link = None;
+
+    loop {
+        match events.peek() {
+            Some(Markdown::End(Tag::Paragraph))
+            | Some(Markdown::End(Tag::Heading(_))) => {
+                let _ = events.next();
+                break;
+            }
+            Some(event) if is_inline(event) => {}
+            _ => break,
+        }
+
+        let line = lines.last_mut().expect("Current line");
+
+        match events.next() {
+            Some(Markdown::Text(text)) => line.push(Span {
+                text: text.into_string(),
+                is_code: false,
+                link: link.clone(),
+            }),
+            Some(Markdown::Code(code)) => line.push(Span {
+                text: code.into_string(),
+                is_code: true,
+                link: link.clone(),
+            }),
+            Some(Markdown::SoftBreak) => line.push(Span {
+                text: String::from(" "),
+                is_code: false,
+                link: None,
+            }),
+            Some(Markdown::HardBreak) => lines.push(Vec::new()),
+            Some(Markdown::Start(Tag::Link(_, destination, _)))
+            | Some(Markdown::Start(Tag::Image(_, destination, _))) => {
+                link = Some(destination.into_string());
+            }
+            Some(Markdown::End(Tag::Link(..)))
+            | Some(Markdown::End(Tag::Image(..))) => {
+                link = None;
+            }
+            _ => {}
+        }
+    }
+
+    lines
+}
+
+fn is_inline(event: &Markdown<'_>) -> bool {
+    match event {
+        Markdown::Text(_)
+        | Markdown::Code(_)
+        | Markdown::Html(_)
+        | Markdown::SoftBreak
+        | Markdown::HardBreak => true,
+        Markdown::Start(tag) | Markdown::End(tag) => matches!(
+            tag,
+            Tag::Emphasis
+                | Tag::Strong
+                | Tag::Strikethrough
+                | Tag::Link(..)
+                | Tag::Image(..)
+        ),
+        _ => false,
+    }
+}
+
+fn skip<'a>(events: &mut impl Iterator<Item = Markdown<'a>>) {
+    let mut depth = 1;
+
+    for event in events {
+        match event {
+            Markdown::Start(_) => depth += 1,
+            Markdown::End(_) => depth -= 1,
+            _ => {}
+        }
+
+        if depth == 0 {
+            break;
+        }
+    }
+}
+
+/// Splits a line into words, made of one fragment per span they touch.
+fn words(line: &[Span]) -> Vec<Vec<Span>> {
+    let mut words = vec![Vec::new()];
+
+    for span in line {
+        for (i, part) in span.text.split(char::is_whitespace).enumerate() {
+            if i > 0 {
+                words.push(Vec::new());
+            }
+
+            if !part.is_empty() {
+                words.last_mut().expect("Current word").push(Span {
+                    text: String::from(part),
+                    ..span.clone()
+                });
+            }
+        }
+    }
+
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str) -> Span {
+        Span {
+            text: String::from(text),
+            is_code: false,
+            link: None,
+        }
+    }
+
+    #[test]
+    fn blocks_are_parsed_from_commonmark() {
+        let blocks = parse(
+            "# Title\n\nSome `code` and a [link](https://iced.rs).\n\n\
+            ```rust\nfn main() {}\n```\n\n- one\n- two\n\n---",
+        );
+
+        assert_eq!(blocks.len(), 5);
+        assert_eq!(blocks[0], Block::Heading(1, vec![vec![span("Title")]]));
+
+        assert_eq!(
+            blocks[1],
+            Block::Paragraph(vec![vec![
+                span("Some "),
+                Span {
+                    is_code: true,
+                    ..span("code")
+                },
+                span(" and a "),
+                Span {
+                    link: Some(String::from("https://iced.rs")),
+                    ..span("link")
+                },
+                span("."),
+            ]])
+        );
+
+        assert_eq!(blocks[2], Block::Code(String::from("fn main() {}")));
+
+        assert_eq!(
+            blocks[3],
+            Block::List {
+                start: None,
+                items: vec![
+                    vec![Block::Paragraph(vec![vec![span("one")]])],
+                    vec![Block::Paragraph(vec![vec![span("two")]])],
+                ],
+            }
+        );
+
+        assert_eq!(blocks[4], Block::Rule);
+    }
+
+    #[test]
+    fn hard_breaks_start_a_new_line() {
+        assert_eq!(
+            parse("first  \nsecond"),
+            vec![Block::Paragraph(vec![
+                vec![span("first")],
+                vec![span("second")],
+            ])]
+        );
+    }
+
+    #[test]
+    fn words_keep_adjacent_spans_together() {
+        let link = Span {
+            link: Some(String::from("https://iced.rs")),
+            ..span("iced")
+        };
+
+        let words = words(&[span("Try "), link.clone(), span(", now")]);
+
+        assert_eq!(
+            words,
+            vec![vec![span("Try")], vec![link, span(",")], vec![span("now")]]
+        );
+    }
+}
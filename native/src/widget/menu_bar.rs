@@ -0,0 +1,1233 @@
+//! Show a bar of dropdown menus, like File, Edit, and View.
+//!
+//! A [`MenuBar`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::text;
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+
+/// A horizontal bar of menus, each one opening a dropdown of [`MenuItem`]s.
+///
+/// A [`MenuBar`] can be used both with the mouse and the keyboard. Pressing
+/// and releasing `Alt` on its own focuses the bar, the arrow keys move through
+/// the menus, `Enter` activates the highlighted item, and `Escape` closes the
+/// innermost menu.
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> MenuBar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`MenuBar`] with some local [`State`] and the given
+    /// menus.
+    pub fn new(state: &'a mut State, menus: Vec<Menu<Message>>) -> Self {
+        MenuBar {
+            state,
+            menus,
+            width: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Adds a [`Menu`] to the [`MenuBar`].
+    pub fn push(mut self, menu: Menu<Message>) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the entries of the [`MenuBar`] and its menus.
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// A labeled list of [`MenuItem`]s.
+///
+/// A [`Menu`] is either a top-level entry of a [`MenuBar`] or a submenu of
+/// another [`Menu`].
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    label: String,
+    items: Vec<MenuItem<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates an empty [`Menu`] with the given label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Menu {
+            label: label.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Adds a [`MenuItem`] to the [`Menu`].
+    pub fn push(mut self, item: MenuItem<Message>) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Returns the label of the [`Menu`].
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the [`MenuItem`]s of the [`Menu`].
+    pub fn items(&self) -> &[MenuItem<Message>] {
+        &self.items
+    }
+}
+
+/// An entry of a [`Menu`].
+///
+/// It can be an action producing a message, a submenu, or a separator.
+#[derive(Debug, Clone)]
+pub struct MenuItem<Message> {
+    label: String,
+    shortcut: Option<String>,
+    is_enabled: bool,
+    kind: Kind<Message>,
+}
+
+#[derive(Debug, Clone)]
+enum Kind<Message> {
+    Action(Message),
+    Submenu(Menu<Message>),
+    Separator,
+}
+
+impl<Message> MenuItem<Message> {
+    /// Creates a new [`MenuItem`] that produces the given message when
+    /// activated.
+    pub fn new(label: impl Into<String>, on_activate: Message) -> Self {
+        MenuItem {
+            label: label.into(),
+            shortcut: None,
+            is_enabled: true,
+            kind: Kind::Action(on_activate),
+        }
+    }
+
+    /// Creates a new [`MenuItem`] that opens the given [`Menu`] when
+    /// highlighted.
+    pub fn submenu(menu: Menu<Message>) -> Self {
+        MenuItem {
+            label: menu.label.clone(),
+            shortcut: None,
+            is_enabled: true,
+            kind: Kind::Submenu(menu),
+        }
+    }
+
+    /// Creates a new [`MenuItem`] separating groups of related items.
+    pub fn separator() -> Self {
+        MenuItem {
+            label: String::new(),
+            shortcut: None,
+            is_enabled: false,
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Sets the shortcut hint of the [`MenuItem`], like `Ctrl+S`.
+    ///
+    /// The hint is only displayed. To make the shortcut work, register a
+    /// keyboard accelerator in your application.
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Sets whether the [`MenuItem`] can be activated.
+    pub fn enabled(mut self, is_enabled: bool) -> Self {
+        self.is_enabled = is_enabled;
+        self
+    }
+
+    /// Returns the label of the [`MenuItem`].
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the shortcut hint of the [`MenuItem`], if any.
+    pub fn shortcut_hint(&self) -> Option<&str> {
+        self.shortcut.as_deref()
+    }
+
+    /// Returns whether the [`MenuItem`] can be activated.
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Returns whether the [`MenuItem`] is a separator.
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, Kind::Separator)
+    }
+
+    /// Returns whether the [`MenuItem`] opens a submenu.
+    pub fn is_submenu(&self) -> bool {
+        matches!(self.kind, Kind::Submenu(_))
+    }
+
+    fn is_selectable(&self) -> bool {
+        self.is_enabled && !self.is_separator()
+    }
+
+    fn open_submenu(&self) -> Option<&Menu<Message>> {
+        match &self.kind {
+            Kind::Submenu(menu) if self.is_enabled => Some(menu),
+            _ => None,
+        }
+    }
+}
+
+/// The local state of a [`MenuBar`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct State {
+    focused: Option<usize>,
+    is_open: bool,
+    path: Vec<usize>,
+    is_alt_pressed: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether a menu of the [`MenuBar`] is currently open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Closes all the menus of the [`MenuBar`] and removes its focus.
+    pub fn close(&mut self) {
+        self.focused = None;
+        self.is_open = false;
+        self.path.clear();
+    }
+
+    fn open(&mut self, menu: usize) {
+        self.focused = Some(menu);
+        self.is_open = true;
+        self.path.clear();
+    }
+
+    /// Returns the items of every open menu, from the outermost to the
+    /// innermost, together with their highlighted item.
+    fn levels<'m, Message>(
+        &self,
+        menus: &'m [Menu<Message>],
+    ) -> Vec<(&'m [MenuItem<Message>], Option<usize>)> {
+        let mut levels = Vec::new();
+
+        let menu = match self.focused {
+            Some(focused) if self.is_open => menus.get(focused),
+            _ => None,
+        };
+
+        let mut items = match menu {
+            Some(menu) => &menu.items[..],
+            None => return levels,
+        };
+
+        for depth in 0.. {
+            let highlighted = self.path.get(depth).copied();
+
+            levels.push((items, highlighted));
+
+            // Highlighted submenus are open
+            match highlighted
+                .and_then(|index| items.get(index))
+                .and_then(MenuItem::open_submenu)
+            {
+                Some(submenu) => items = &submenu.items,
+                None => break,
+            }
+        }
+
+        levels
+    }
+
+    fn on_key_pressed<Message: Clone>(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+        repeat: bool,
+        menus: &[Menu<Message>],
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        use keyboard::KeyCode;
+
+        if menus.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        // `Alt` is left alone on press, so `Alt+F4`, `Alt+Tab` and `AltGr`
+        // keep working, and only toggles the focus once released
+        if is_alt(key_code) {
+            if !repeat {
+                self.is_alt_pressed = !(modifiers.shift()
+                    || modifiers.control()
+                    || modifiers.logo());
+            }
+
+            return event::Status::Ignored;
+        }
+
+        self.is_alt_pressed = false;
+
+        let focused = match self.focused {
+            Some(focused) => focused,
+            None => return event::Status::Ignored,
+        };
+
+        let levels = self.levels(menus);
+
+        match key_code {
+            KeyCode::Escape => {
+                if self.path.len() > 1 {
+                    let _ = self.path.pop();
+                } else if self.is_open {
+                    self.is_open = false;
+                    self.path.clear();
+                } else {
+                    self.close();
+                }
+            }
+            KeyCode::Left | KeyCode::Right => {
+                let is_submenu_open = levels.len() > self.path.len();
+
+                if key_code == KeyCode::Left && self.path.len() > 1 {
+                    let _ = self.path.pop();
+                } else if key_code == KeyCode::Right
+                    && is_submenu_open
+                    && !self.path.is_empty()
+                {
+                    let (items, _) = levels[levels.len() - 1];
+
+                    self.path.extend(next_selectable(items, None, true));
+                } else {
+                    let next = if key_code == KeyCode::Left {
+                        (focused + menus.len() - 1) % menus.len()
+                    } else {
+                        (focused + 1) % menus.len()
+                    };
+
+                    self.focused = Some(next);
+                    self.path.clear();
+
+                    if self.is_open {
+                        self.path.extend(next_selectable(
+                            &menus[next].items,
+                            None,
+                            true,
+                        ));
+                    }
+                }
+            }
+            KeyCode::Up | KeyCode::Down => {
+                let forward = key_code == KeyCode::Down;
+
+                if !self.is_open {
+                    self.open(focused);
+                    self.path.extend(next_selectable(
+                        &menus[focused].items,
+                        None,
+                        forward,
+                    ));
+                } else {
+                    let depth = self.path.len().max(1) - 1;
+                    let (items, highlighted) = levels[depth];
+
+                    self.path.truncate(depth);
+                    self.path.extend(
+                        next_selectable(items, highlighted, forward)
+                            .or(highlighted),
+                    );
+                }
+            }
+            KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => {
+                if !self.is_open {
+                    self.open(focused);
+                    self.path.extend(next_selectable(
+                        &menus[focused].items,
+                        None,
+                        true,
+                    ));
+                } else if levels.len() > self.path.len() {
+                    let (items, _) = levels[levels.len() - 1];
+
+                    self.path.extend(next_selectable(items, None, true));
+                } else if let Some(index) = self.path.last().copied() {
+                    let (items, _) = levels[levels.len() - 1];
+
+                    activate(self, &items[index], messages);
+                }
+            }
+            _ => return event::Status::Ignored,
+        }
+
+        event::Status::Captured
+    }
+
+    fn on_key_released<Message>(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        menus: &[Menu<Message>],
+    ) -> event::Status {
+        if !is_alt(key_code) || !std::mem::take(&mut self.is_alt_pressed) {
+            return event::Status::Ignored;
+        }
+
+        if menus.is_empty() {
+            return event::Status::Ignored;
+        }
+
+        if self.focused.is_some() {
+            self.close();
+        } else {
+            self.focused = Some(0);
+        }
+
+        event::Status::Captured
+    }
+}
+
+fn is_alt(key_code: keyboard::KeyCode) -> bool {
+    key_code == keyboard::KeyCode::LAlt || key_code == keyboard::KeyCode::RAlt
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MenuBar<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let height = f32::from(text_size + self.padding.vertical());
+
+        let mut x = 0.0;
+
+        let entries = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let (label_width, _) = renderer.measure(
+                    &menu.label,
+                    text_size,
+                    self.font,
+                    Size::new(f32::INFINITY, f32::INFINITY),
+                );
+
+                let width =
+                    label_width.round() + f32::from(self.padding.horizontal());
+
+                let mut entry = layout::Node::new(Size::new(width, height));
+                entry.move_to(Point::new(x, 0.0));
+
+                x += width;
+
+                entry
+            })
+            .collect();
+
+        let size = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .resolve(Size::new(x, height));
+
+        layout::Node::with_children(size, entries)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+                repeat,
+                ..
+            }) => self
+                .state
+                .on_key_pressed(
+                    key_code,
+                    modifiers,
+                    repeat,
+                    &self.menus,
+                    messages,
+                )
+                .cast(),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code, ..
+            }) => self.state.on_key_released(key_code, &self.menus).cast(),
+            // While open, the overlay handles the pointer
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !self.state.is_open =>
+            {
+                let entry = layout
+                    .children()
+                    .position(|entry| cursor.is_over(entry.bounds()));
+
+                match entry {
+                    Some(index) => {
+                        self.state.open(index);
+
+                        event::Status::Captured
+                    }
+                    None => {
+                        self.state.close();
+
+                        event::Status::Ignored
+                    }
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout,
+            cursor,
+            &self.menus,
+            self.state.focused,
+            self.state.is_open,
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+
+        for menu in &self.menus {
+            menu.label.hash(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let position = layout.position();
+
+        let entries = layout
+            .children()
+            .map(|entry| entry.bounds() + Vector::new(-position.x, -position.y))
+            .collect();
+
+        Some(overlay::Element::new(
+            position,
+            Box::new(Overlay {
+                state: &mut *self.state,
+                menus: &self.menus,
+                bar: layout.bounds().size(),
+                entries,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: &self.style,
+            }),
+        ))
+    }
+}
+
+struct Overlay<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    menus: &'a [Menu<Message>],
+    bar: Size,
+    entries: Vec<Rectangle>,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout_menu(
+        &self,
+        renderer: &Renderer,
+        items: &[MenuItem<Message>],
+        text_size: u16,
+    ) -> layout::Node {
+        let measure = |content: &str| {
+            let (width, _) = renderer.measure(
+                content,
+                text_size,
+                self.font,
+                Size::new(f32::INFINITY, f32::INFINITY),
+            );
+
+            width.round()
+        };
+
+        let item_height = f32::from(text_size + self.padding.vertical());
+
+        let width = items
+            .iter()
+            .map(|item| {
+                let hint = if item.is_submenu() {
+                    f32::from(text_size)
+                } else {
+                    item.shortcut
+                        .as_deref()
+                        .map(measure)
+                        .map(|width| width + f32::from(Renderer::HINT_SPACING))
+                        .unwrap_or(0.0)
+                };
+
+                measure(&item.label) + hint
+            })
+            .fold(0.0, f32::max)
+            + f32::from(self.padding.horizontal());
+
+        let mut y = 0.0;
+
+        let children = items
+            .iter()
+            .map(|item| {
+                let height = if item.is_separator() {
+                    f32::from(Renderer::SEPARATOR_HEIGHT)
+                } else {
+                    item_height
+                };
+
+                let mut node = layout::Node::new(Size::new(width, height));
+                node.move_to(Point::new(0.0, y));
+
+                y += height;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(Size::new(width, y), children)
+    }
+
+    fn on_bar_pressed(&mut self, entry: usize) {
+        if self.state.focused == Some(entry) {
+            self.state.close();
+        } else {
+            self.state.open(entry);
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let offset = Vector::new(position.x, position.y);
+
+        let bar = layout::Node::with_children(
+            self.bar,
+            self.entries
+                .iter()
+                .map(|entry| {
+                    let mut node = layout::Node::new(entry.size());
+                    node.move_to(entry.position());
+                    node
+                })
+                .collect(),
+        );
+
+        let anchor = self
+            .state
+            .focused
+            .and_then(|focused| self.entries.get(focused))
+            .map(|entry| *entry + offset)
+            .unwrap_or(Rectangle::with_size(Size::ZERO));
+
+        let mut origin = Point::new(anchor.x, anchor.y + anchor.height);
+        let mut nodes = vec![(position, bar)];
+
+        for (items, highlighted) in self.state.levels(self.menus) {
+            let node = self.layout_menu(renderer, items, text_size);
+            let size = node.size();
+
+            let menu_position = Point::new(
+                origin.x.min(bounds.width - size.width).max(0.0),
+                origin.y.min(bounds.height - size.height).max(0.0),
+            );
+
+            if let Some(item) =
+                highlighted.and_then(|index| node.children().get(index))
+            {
+                origin = Point::new(
+                    menu_position.x + size.width,
+                    menu_position.y + item.bounds().y,
+                );
+            }
+
+            nodes.push((menu_position, node));
+        }
+
+        // The overlay covers the bar and all of its open menus
+        let (min, max) = nodes.iter().fold(
+            (
+                Point::new(f32::INFINITY, f32::INFINITY),
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+            ),
+            |(min, max), (position, node)| {
+                let size = node.size();
+
+                (
+                    Point::new(min.x.min(position.x), min.y.min(position.y)),
+                    Point::new(
+                        max.x.max(position.x + size.width),
+                        max.y.max(position.y + size.height),
+                    ),
+                )
+            },
+        );
+
+        let children = nodes
+            .into_iter()
+            .map(|(position, mut node)| {
+                node.move_to(Point::new(
+                    position.x - min.x,
+                    position.y - min.y,
+                ));
+                node
+            })
+            .collect();
+
+        let mut node = layout::Node::with_children(
+            Size::new(max.x - min.x, max.y - min.y),
+            children,
+        );
+
+        node.move_to(min);
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let mut children = layout.children();
+        let bar = children.next().expect("Menu bar layout");
+
+        let entry = bar
+            .children()
+            .position(|entry| cursor.is_over(entry.bounds()));
+
+        let item = children.enumerate().find_map(|(depth, menu)| {
+            menu.children()
+                .position(|item| cursor.is_over(item.bounds()))
+                .map(|index| (depth, index))
+        });
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(entry) = entry {
+                    if self.state.focused != Some(entry) {
+                        self.state.open(entry);
+                    }
+                } else if let Some((depth, index)) = item {
+                    let is_selectable = self
+                        .state
+                        .levels(self.menus)
+                        .get(depth)
+                        .and_then(|(items, _)| items.get(index))
+                        .map_or(false, MenuItem::is_selectable);
+
+                    if is_selectable {
+                        self.state.path.truncate(depth);
+                        self.state.path.push(index);
+                    }
+                }
+
+                event::Status::Ignored
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(entry) = entry {
+                    self.on_bar_pressed(entry);
+                } else if let Some((depth, index)) = item {
+                    let levels = self.state.levels(self.menus);
+
+                    if let Some(item) = levels
+                        .get(depth)
+                        .and_then(|(items, _)| items.get(index))
+                    {
+                        activate(self.state, item, messages);
+                    }
+                } else {
+                    self.state.close();
+                }
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Renderer::Output {
+        let levels = self.state.levels(self.menus);
+
+        let menus: Vec<_> = layout
+            .children()
+            .skip(1)
+            .zip(levels)
+            .map(|(layout, (items, highlighted))| (layout, items, highlighted))
+            .collect();
+
+        renderer.draw_menus(
+            cursor,
+            &menus,
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+
+        self.state.focused.hash(state);
+        self.state.path.hash(state);
+    }
+}
+
+/// Activates the given [`MenuItem`], closing the menus if it produces a
+/// message.
+fn activate<Message: Clone>(
+    state: &mut State,
+    item: &MenuItem<Message>,
+    messages: &mut Vec<Message>,
+) {
+    if let Kind::Action(message) = &item.kind {
+        if item.is_enabled {
+            messages.push(message.clone());
+            state.close();
+        }
+    }
+}
+
+/// Returns the index of the next selectable item after the `current` one,
+/// wrapping around.
+fn next_selectable<Message>(
+    items: &[MenuItem<Message>],
+    current: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let count = items.len();
+
+    (1..=count)
+        .map(|step| match (current, forward) {
+            (Some(current), true) => (current + step) % count,
+            (Some(current), false) => (current + count - step % count) % count,
+            (None, true) => step - 1,
+            (None, false) => count - step,
+        })
+        .find(|&index| items[index].is_selectable())
+}
+
+/// The renderer of a [`MenuBar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MenuBar`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text::Renderer {
+    /// The default padding of the entries of a [`MenuBar`].
+    const DEFAULT_PADDING: Padding;
+
+    /// The height of a separator [`MenuItem`].
+    const SEPARATOR_HEIGHT: u16;
+
+    /// The space between the label and the shortcut hint of a [`MenuItem`].
+    const HINT_SPACING: u16;
+
+    /// The [`MenuBar`] style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the bar of a [`MenuBar`].
+    ///
+    /// It receives:
+    ///   * the [`Layout`] of the bar, with a child for every [`Menu`]
+    ///   * the current [`mouse::Cursor`]
+    ///   * the [`Menu`]s of the bar
+    ///   * the focused [`Menu`], if any
+    ///   * whether the focused [`Menu`] is open
+    fn draw<Message>(
+        &mut self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        menus: &[Menu<Message>],
+        focused: Option<usize>,
+        is_open: bool,
+        padding: Padding,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws the open menus of a [`MenuBar`].
+    ///
+    /// Every open menu is described by its [`Layout`], with a child for every
+    /// [`MenuItem`], its [`MenuItem`]s, and its highlighted [`MenuItem`], if
+    /// any. The menus are sorted from the outermost to the innermost.
+    fn draw_menus<Message>(
+        &mut self,
+        cursor: mouse::Cursor,
+        menus: &[(Layout<'_>, &[MenuItem<Message>], Option<usize>)],
+        padding: Padding,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        menu_bar: MenuBar<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(menu_bar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use keyboard::KeyCode;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        New,
+        Save,
+        Export,
+        Undo,
+    }
+
+    fn menus() -> Vec<Menu<Message>> {
+        vec![
+            Menu::new("File")
+                .push(MenuItem::new("New", Message::New))
+                .push(MenuItem::new("Save", Message::Save).shortcut("Ctrl+S"))
+                .push(MenuItem::separator())
+                .push(MenuItem::submenu(
+                    Menu::new("Export")
+                        .push(MenuItem::new("PNG", Message::Export)),
+                )),
+            Menu::new("Edit").push(MenuItem::new("Undo", Message::Undo)),
+        ]
+    }
+
+    fn send(
+        state: &mut State,
+        event: keyboard::Event,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+
+        let mut menu_bar = MenuBar::<'_, Message, Null>::new(state, menus());
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = menu_bar.layout(&renderer, &limits);
+
+        menu_bar.on_event(
+            Event::Keyboard(event),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &renderer,
+            &mut clipboard,
+            messages,
+        )
+    }
+
+    fn press_with(
+        state: &mut State,
+        key_code: KeyCode,
+        modifiers: keyboard::Modifiers,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        send(
+            state,
+            keyboard::Event::KeyPressed {
+                key_code,
+                physical_key: keyboard::ScanCode(0),
+                location: keyboard::KeyLocation::from(key_code),
+                modifiers,
+                repeat: false,
+            },
+            messages,
+        )
+    }
+
+    fn press(
+        state: &mut State,
+        key_code: KeyCode,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        press_with(state, key_code, keyboard::Modifiers::default(), messages)
+    }
+
+    fn release(
+        state: &mut State,
+        key_code: KeyCode,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        send(
+            state,
+            keyboard::Event::KeyReleased {
+                key_code,
+                physical_key: keyboard::ScanCode(0),
+                location: keyboard::KeyLocation::from(key_code),
+                modifiers: keyboard::Modifiers::default(),
+            },
+            messages,
+        )
+    }
+
+    fn tap(state: &mut State, key_code: KeyCode, messages: &mut Vec<Message>) {
+        let _ = press(state, key_code, messages);
+        let _ = release(state, key_code, messages);
+    }
+
+    #[test]
+    fn keys_are_ignored_until_alt_is_released() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        assert_eq!(
+            press(&mut state, KeyCode::Down, &mut messages),
+            event::Status::Ignored
+        );
+        assert!(!state.is_open());
+
+        assert_eq!(
+            press(&mut state, KeyCode::LAlt, &mut messages),
+            event::Status::Ignored
+        );
+        assert_eq!(
+            release(&mut state, KeyCode::LAlt, &mut messages),
+            event::Status::Captured
+        );
+        assert_eq!(
+            press(&mut state, KeyCode::Down, &mut messages),
+            event::Status::Captured
+        );
+        assert!(state.is_open());
+    }
+
+    #[test]
+    fn alt_combinations_do_not_focus_the_bar() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        let _ = press(&mut state, KeyCode::LAlt, &mut messages);
+        tap(&mut state, KeyCode::F4, &mut messages);
+
+        assert_eq!(
+            release(&mut state, KeyCode::LAlt, &mut messages),
+            event::Status::Ignored
+        );
+
+        let _ = press_with(
+            &mut state,
+            KeyCode::RAlt,
+            keyboard::Modifiers::CTRL,
+            &mut messages,
+        );
+
+        assert_eq!(
+            release(&mut state, KeyCode::RAlt, &mut messages),
+            event::Status::Ignored
+        );
+        assert_eq!(state, State::new());
+    }
+
+    #[test]
+    fn arrows_and_enter_activate_items_skipping_separators() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        for key_code in [
+            KeyCode::LAlt,
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Right,
+            KeyCode::Enter,
+        ]
+        .iter()
+        .copied()
+        {
+            tap(&mut state, key_code, &mut messages);
+        }
+
+        assert_eq!(messages, vec![Message::Export]);
+        assert_eq!(state, State::new());
+    }
+
+    #[test]
+    fn escape_closes_the_innermost_menu_first() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        for key_code in
+            [KeyCode::LAlt, KeyCode::Up, KeyCode::Right, KeyCode::Escape]
+                .iter()
+                .copied()
+        {
+            tap(&mut state, key_code, &mut messages);
+        }
+
+        assert!(state.is_open());
+        assert_eq!(state.path, vec![3]);
+
+        tap(&mut state, KeyCode::Escape, &mut messages);
+        assert!(!state.is_open());
+        assert_eq!(state.focused, Some(0));
+
+        tap(&mut state, KeyCode::Escape, &mut messages);
+        assert_eq!(state, State::new());
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn left_and_right_move_between_menus() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        for key_code in [KeyCode::LAlt, KeyCode::Down, KeyCode::Left]
+            .iter()
+            .copied()
+        {
+            tap(&mut state, key_code, &mut messages);
+        }
+
+        assert_eq!(state.focused, Some(1));
+
+        tap(&mut state, KeyCode::Enter, &mut messages);
+        assert_eq!(messages, vec![Message::Undo]);
+    }
+}
@@ -0,0 +1,478 @@
+//! Show a dialog on top of some content, blocking any interaction with it.
+use crate::alignment::{self, Alignment};
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// An element that displays a dialog on top of its base content.
+///
+/// While the dialog is present, the rest of the viewport is dimmed and every
+/// event is delivered to the dialog only. The base content is not hovered,
+/// cannot be clicked or scrolled, and any of its text inputs stop receiving
+/// keyboard input.
+///
+/// Pressing the dimmed area or `Escape` produces the message set with
+/// [`on_blur`], which is usually used to close the dialog.
+///
+/// [`on_blur`]: Modal::on_blur
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Cancel,
+/// }
+///
+/// let is_confirming = true;
+///
+/// let modal = Modal::new(
+///     Text::new("Form"),
+///     if is_confirming {
+///         Some(Text::new("Are you sure?").into())
+///     } else {
+///         None
+///     },
+/// )
+/// .on_blur(Message::Cancel);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer: self::Renderer> {
+    base: Element<'a, Message, Renderer>,
+    dialog: Option<Element<'a, Message, Renderer>>,
+    on_blur: Option<Message>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Modal`] with the given base content and the dialog to
+    /// display on top of it, if any.
+    pub fn new(
+        base: impl Into<Element<'a, Message, Renderer>>,
+        dialog: Option<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Modal {
+            base: base.into(),
+            dialog,
+            on_blur: None,
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the dimmed area around the
+    /// dialog is pressed or when `Escape` is pressed.
+    pub fn on_blur(mut self, message: Message) -> Self {
+        self.on_blur = Some(message);
+        self
+    }
+
+    /// Sets the alignment of the dialog in the horizontal axis of the
+    /// viewport.
+    ///
+    /// By default, the dialog is centered.
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the alignment of the dialog in the vertical axis of the
+    /// viewport.
+    ///
+    /// By default, the dialog is centered.
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.base.width()
+    }
+
+    fn height(&self) -> Length {
+        self.base.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        // The overlay of the dialog handles every event
+        if self.dialog.is_some() {
+            return event::Status::Ignored;
+        }
+
+        self.base
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let cursor = if self.dialog.is_some() {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        };
+
+        self.base.draw(renderer, defaults, layout, cursor, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.base.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let Modal {
+            base,
+            dialog,
+            on_blur,
+            horizontal_alignment,
+            vertical_alignment,
+            style,
+        } = self;
+
+        match dialog {
+            Some(dialog) => Some(overlay::Element::new(
+                Point::ORIGIN,
+                Box::new(Overlay {
+                    dialog,
+                    on_blur,
+                    horizontal_alignment: *horizontal_alignment,
+                    vertical_alignment: *vertical_alignment,
+                    style,
+                }),
+            )),
+            None => base.overlay(layout),
+        }
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer: self::Renderer> {
+    dialog: &'b mut Element<'a, Message, Renderer>,
+    on_blur: &'b Option<Message>,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    style: &'b Renderer::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds)
+            .width(Length::Shrink)
+            .height(Length::Shrink);
+
+        let mut dialog = self.dialog.layout(renderer, &limits);
+
+        dialog.align(
+            Alignment::from(self.horizontal_alignment),
+            Alignment::from(self.vertical_alignment),
+            bounds,
+        );
+
+        layout::Node::with_children(bounds, vec![dialog])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let dialog_layout = layout.children().next().unwrap();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if !cursor.is_over(dialog_layout.bounds()) =>
+            {
+                messages.extend(self.on_blur.clone());
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let status = self.dialog.on_event(
+            event.clone(),
+            dialog_layout,
+            cursor,
+            renderer,
+            clipboard,
+            messages,
+        );
+
+        // A focused text input in the dialog may claim `Escape` first
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if !status.is_captured() => {
+                messages.extend(self.on_blur.clone());
+
+                event::Status::Captured
+            }
+            _ => status,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            layout.bounds(),
+            cursor,
+            self.style,
+            self.dialog,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, _position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        Alignment::from(self.horizontal_alignment).hash(state);
+        Alignment::from(self.vertical_alignment).hash(state);
+
+        self.dialog.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the dialog of a [`Modal`] on top of the dimmed viewport.
+    ///
+    /// It receives:
+    ///   * the bounds of the whole viewport
+    ///   * the current cursor position
+    ///   * the style of the [`Modal`]
+    ///   * the dialog and its [`Layout`]
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        style: &Self::Style,
+        dialog: &Element<'_, Message, Self>,
+        dialog_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{clipboard, Container, Text};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Blur,
+    }
+
+    fn modal(is_open: bool) -> Modal<'static, Message, Null> {
+        let dialog = Container::new(Text::new("Dialog"))
+            .width(Length::Units(100))
+            .height(Length::Units(50));
+
+        Modal::new(
+            Container::new(Text::new("Base"))
+                .width(Length::Fill)
+                .height(Length::Fill),
+            if is_open { Some(dialog.into()) } else { None },
+        )
+        .on_blur(Message::Blur)
+    }
+
+    fn process(
+        modal: &mut Modal<'_, Message, Null>,
+        event: Event,
+        cursor: Point,
+    ) -> (event::Status<Message>, Vec<Message>) {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+
+        let bounds = Size::new(400.0, 400.0);
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let node = modal.layout(&renderer, &limits);
+        let cursor = mouse::Cursor::Available(cursor);
+
+        let base_status = modal.on_event(
+            event.clone(),
+            Layout::new(&node),
+            cursor,
+            &renderer,
+            &mut clipboard,
+            &mut messages,
+        );
+
+        assert_eq!(base_status, event::Status::Ignored);
+
+        let mut overlay =
+            modal.overlay(Layout::new(&node)).expect("Modal overlay");
+        let overlay_node = overlay.layout(&renderer, bounds);
+
+        assert_eq!(
+            overlay_node.children()[0].bounds(),
+            Rectangle {
+                x: 150.0,
+                y: 175.0,
+                width: 100.0,
+                height: 50.0,
+            }
+        );
+
+        let status = overlay.on_event(
+            event,
+            Layout::new(&overlay_node),
+            cursor,
+            &renderer,
+            &mut clipboard,
+            &mut messages,
+        );
+
+        (status, messages)
+    }
+
+    fn left_press() -> Event {
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+    }
+
+    #[test]
+    fn pressing_the_dimmed_area_blurs() {
+        let (status, messages) =
+            process(&mut modal(true), left_press(), Point::new(10.0, 10.0));
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Blur]);
+    }
+
+    #[test]
+    fn pressing_the_dialog_does_not_blur() {
+        let (_, messages) =
+            process(&mut modal(true), left_press(), Point::new(200.0, 200.0));
+
+        assert!(messages.is_empty());
+    }
+
+    #[test]
+    fn escape_blurs() {
+        let escape = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            physical_key: keyboard::ScanCode(1),
+            location: keyboard::KeyLocation::Standard,
+            modifiers: keyboard::Modifiers::default(),
+            repeat: false,
+        });
+
+        let (status, messages) =
+            process(&mut modal(true), escape, Point::new(200.0, 200.0));
+
+        assert_eq!(status, event::Status::Captured);
+        assert_eq!(messages, vec![Message::Blur]);
+    }
+
+    #[test]
+    fn closed_modal_has_no_overlay() {
+        let renderer = Null::new();
+        let mut modal = modal(false);
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+        let node = modal.layout(&renderer, &limits);
+
+        assert!(modal.overlay(Layout::new(&node)).is_none());
+    }
+}
@@ -0,0 +1,565 @@
+//! Edit a number by typing it or by stepping it up and down.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::widget::button::{self, Button};
+use crate::widget::text_input::{self, TextInput};
+use crate::widget::Text;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::fmt::Display;
+use std::hash::Hash;
+use std::ops::{Add, RangeInclusive, Sub};
+use std::str::FromStr;
+
+/// A field holding a number, with buttons to decrement and increment it.
+///
+/// The number can also be typed in the field, or stepped with the up and
+/// down arrow keys while the field is focused. It is always clamped to the
+/// range of the [`NumberInput`].
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, renderer::Null};
+/// #
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let mut state = number_input::State::new();
+/// let quantity = 3;
+///
+/// let input = NumberInput::new(
+///     &mut state,
+///     1..=10,
+///     quantity,
+///     Message::QuantityChanged,
+/// )
+/// .step(1);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer: self::Renderer> {
+    text: &'a mut String,
+    range: RangeInclusive<T>,
+    step: T,
+    value: T,
+    on_change: Box<dyn Fn(T) -> Message>,
+    width: Length,
+    spacing: u16,
+    input: TextInput<'a, Internal, Renderer>,
+    decrement: Button<'a, Internal, Renderer>,
+    increment: Button<'a, Internal, Renderer>,
+}
+
+/// The messages of the inner widgets of a [`NumberInput`].
+#[derive(Debug, Clone)]
+enum Internal {
+    Decrement,
+    Increment,
+    Input(String),
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + PartialOrd + Display + FromStr,
+    Renderer: 'a + self::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`NumberInput`]
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new value of the [`NumberInput`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: T,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let value = clamp(value, &range);
+
+        let State {
+            input,
+            decrement,
+            increment,
+            text,
+        } = state;
+
+        // The typed text is kept while the field is focused, even if it is
+        // not a valid number yet. Otherwise, it shows the current value.
+        if !input.is_focused() {
+            *text = value.to_string();
+        }
+
+        let input = TextInput::new(input, "", text, Internal::Input);
+
+        let mut decrement = Button::new(decrement, Text::new("-"));
+        let mut increment = Button::new(increment, Text::new("+"));
+
+        if value > *range.start() {
+            decrement = decrement.on_press(Internal::Decrement);
+        }
+
+        if value < *range.end() {
+            increment = increment.on_press(Internal::Increment);
+        }
+
+        NumberInput {
+            text,
+            range,
+            step: T::from(1),
+            value,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            spacing: 5,
+            input,
+            decrement,
+            increment,
+        }
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the spacing between the field and the buttons of the
+    /// [`NumberInput`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the field and the buttons of the
+    /// [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        let padding = padding.into();
+
+        self.input = self.input.padding(padding);
+        self.decrement = self.decrement.padding(padding);
+        self.increment = self.increment.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the field of the [`NumberInput`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.input = self.input.size(size);
+        self
+    }
+
+    /// Sets the font of the field of the [`NumberInput`].
+    pub fn font(
+        mut self,
+        font: <Renderer as crate::text::Renderer>::Font,
+    ) -> Self {
+        self.input = self.input.font(font);
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    pub fn input_style(
+        mut self,
+        style: impl Into<<Renderer as text_input::Renderer>::Style>,
+    ) -> Self {
+        self.input = self.input.style(style);
+        self
+    }
+
+    /// Sets the style of the buttons of the [`NumberInput`].
+    pub fn button_style(
+        mut self,
+        style: impl Into<<Renderer as button::Renderer>::Style> + Clone,
+    ) -> Self {
+        self.decrement = self.decrement.style(style.clone());
+        self.increment = self.increment.style(style);
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>,
+    Renderer: self::Renderer,
+{
+    fn change(&mut self, value: T, messages: &mut Vec<Message>) {
+        if value != self.value {
+            self.value = value;
+
+            messages.push((self.on_change)(value));
+        }
+    }
+
+    fn incremented(&self) -> T {
+        let end = *self.range.end();
+
+        // Subtracting first avoids overflowing near the end of the range
+        if end - self.value < self.step {
+            end
+        } else {
+            self.value + self.step
+        }
+    }
+
+    fn decremented(&self) -> T {
+        let start = *self.range.start();
+
+        if self.value - start < self.step {
+            start
+        } else {
+            self.value - self.step
+        }
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    input: text_input::State,
+    decrement: button::State,
+    increment: button::State,
+    text: String,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the field of the [`NumberInput`] is focused.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let spacing = f32::from(self.spacing);
+
+        let mut decrement = self.decrement.layout(renderer, &limits.loose());
+        let mut increment = self.increment.layout(renderer, &limits.loose());
+
+        // The field takes the space left by the buttons
+        let buttons =
+            decrement.size().width + increment.size().width + spacing * 2.0;
+
+        let mut input = self
+            .input
+            .layout(renderer, &limits.shrink(Size::new(buttons, 0.0)));
+
+        let height = input
+            .size()
+            .height
+            .max(decrement.size().height)
+            .max(increment.size().height);
+
+        input.move_to(Point::new(0.0, (height - input.size().height) / 2.0));
+
+        let x = input.size().width + spacing;
+        decrement
+            .move_to(Point::new(x, (height - decrement.size().height) / 2.0));
+
+        let x = x + decrement.size().width + spacing;
+        increment
+            .move_to(Point::new(x, (height - increment.size().height) / 2.0));
+
+        let size = Size::new(x + increment.size().width, height);
+
+        layout::Node::with_children(size, vec![input, decrement, increment])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let mut children = layout.children();
+        let mut internal = Vec::new();
+
+        let status = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Up,
+                ..
+            }) if self.input.state().is_focused() => {
+                internal.push(Internal::Increment);
+
+                event::Status::Captured
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if self.input.state().is_focused() => {
+                internal.push(Internal::Decrement);
+
+                event::Status::Captured
+            }
+            _ => {
+                let input = self.input.on_event(
+                    event.clone(),
+                    children.next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut internal,
+                );
+
+                let decrement = self.decrement.on_event(
+                    event.clone(),
+                    children.next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut internal,
+                );
+
+                let increment = self.increment.on_event(
+                    event,
+                    children.next().unwrap(),
+                    cursor,
+                    renderer,
+                    clipboard,
+                    &mut internal,
+                );
+
+                input
+                    .push_feedback(&mut internal)
+                    .merge(decrement.push_feedback(&mut internal))
+                    .merge(increment.push_feedback(&mut internal))
+            }
+        };
+
+        for message in internal {
+            match message {
+                Internal::Decrement => {
+                    let value = self.decremented();
+
+                    *self.text = value.to_string();
+                    self.change(value, messages);
+                }
+                Internal::Increment => {
+                    let value = self.incremented();
+
+                    *self.text = value.to_string();
+                    self.change(value, messages);
+                }
+                Internal::Input(text) => {
+                    if let Ok(value) = text.parse() {
+                        self.change(clamp(value, &self.range), messages);
+                    }
+
+                    *self.text = text;
+                }
+            }
+        }
+
+        status.cast()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+
+        let input = Widget::draw(
+            &self.input,
+            renderer,
+            defaults,
+            children.next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        let decrement = self.decrement.draw(
+            renderer,
+            defaults,
+            children.next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        let increment = self.increment.draw(
+            renderer,
+            defaults,
+            children.next().unwrap(),
+            cursor,
+            viewport,
+        );
+
+        self::Renderer::draw(renderer, input, decrement, increment)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.spacing.hash(state);
+        self.input.hash_layout(state);
+        self.decrement.hash_layout(state);
+        self.increment.hash_layout(state);
+    }
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`NumberInput`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text_input::Renderer + button::Renderer {
+    /// Draws a [`NumberInput`] out of its drawn field and buttons.
+    fn draw(
+        &mut self,
+        input: Self::Output,
+        decrement: Self::Output,
+        increment: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a
+        + Copy
+        + PartialOrd
+        + Display
+        + FromStr
+        + Add<Output = T>
+        + Sub<Output = T>,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+fn clamp<T: Copy + PartialOrd>(value: T, range: &RangeInclusive<T>) -> T {
+    if value < *range.start() {
+        *range.start()
+    } else if value > *range.end() {
+        *range.end()
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::mouse;
+    use crate::renderer::Null;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Message {
+        Changed(u32),
+    }
+
+    fn click_increment(state: &mut State, value: u32) -> Vec<Message> {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0));
+
+        let mut widget: NumberInput<'_, u32, Message, Null> =
+            NumberInput::new(state, 0..=10, value, Message::Changed).step(3);
+
+        let node = widget.layout(&renderer, &limits);
+        let increment = Layout::new(&node).children().nth(2).unwrap();
+        let position = increment.bounds().center();
+
+        for event in [
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ]
+        .iter()
+        {
+            let _ = widget.on_event(
+                Event::Mouse(*event),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &renderer,
+                &mut clipboard,
+                &mut messages,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    fn clicking_increment_steps_up_clamped_at_the_max() {
+        let mut state = State::new();
+
+        assert_eq!(click_increment(&mut state, 3), vec![Message::Changed(6)]);
+        assert_eq!(state.text, "6");
+
+        assert_eq!(click_increment(&mut state, 9), vec![Message::Changed(10)]);
+        assert_eq!(state.text, "10");
+
+        assert_eq!(click_increment(&mut state, 10), vec![]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Fade some content.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::{Clipboard, Element, Hasher, Layout, Length, Rectangle, Widget};
+
+use std::hash::Hash;
+
+/// An element that draws its content with some opacity.
+///
+/// It is laid out like its content, and events pass through unchanged.
+///
+/// # Example
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Opacity<'a, Message> = iced_native::Opacity<'a, Message, Null>;
+/// let faded = Opacity::<()>::new(Text::new("Disabled"), 0.5);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Opacity<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    opacity: f32,
+}
+
+impl<'a, Message, Renderer> Opacity<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Opacity`] with the given content and opacity, from
+    /// `0.0` (invisible) to `1.0` (opaque).
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        opacity: f32,
+    ) -> Self {
+        Opacity {
+            content: content.into(),
+            opacity: opacity.max(0.0).min(1.0),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Opacity<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        self.content
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let content = self
+            .content
+            .draw(renderer, defaults, layout, cursor, viewport);
+
+        if self.opacity < 1.0 {
+            renderer.draw(content, self.opacity)
+        } else {
+            content
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout)
+    }
+}
+
+/// The renderer of an [`Opacity`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use an [`Opacity`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Fades the drawn content of an [`Opacity`].
+    ///
+    /// It receives the output of the content and an opacity lower than `1.0`.
+    fn draw(&mut self, content: Self::Output, opacity: f32) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Opacity<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        opacity: Opacity<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(opacity)
+    }
+}
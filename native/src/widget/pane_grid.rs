@@ -39,6 +39,8 @@ use crate::{
     Widget,
 };
 
+use node::Constraints;
+
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
 ///
@@ -54,9 +56,10 @@ use crate::{
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Minimum sizes and collapsible panes
 /// * Hotkey support
 /// * Configurable modifier keys
-/// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
+/// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, `collapse`, etc.)
 ///
 /// ## Example
 ///
@@ -95,6 +98,7 @@ pub struct PaneGrid<'a, Message, Renderer: self::Renderer> {
     width: Length,
     height: Length,
     spacing: u16,
+    collapsed_thickness: u16,
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(u16, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
@@ -127,6 +131,7 @@ where
             width: Length::Fill,
             height: Length::Fill,
             spacing: 0,
+            collapsed_thickness: 30,
             on_click: None,
             on_drag: None,
             on_resize: None,
@@ -152,6 +157,14 @@ where
         self
     }
 
+    /// Sets the thickness of a collapsed [`Pane`] of the [`PaneGrid`].
+    ///
+    /// Defaults to 30 units, which fits most title bars.
+    pub fn collapsed_thickness(mut self, units: u16) -> Self {
+        self.collapsed_thickness = units;
+        self
+    }
+
     /// Sets the message that will be produced when a [`Pane`] of the
     /// [`PaneGrid`] is clicked.
     pub fn on_click<F>(mut self, f: F) -> Self
@@ -203,10 +216,22 @@ impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
 {
+    fn constraints(&self) -> Constraints {
+        let min_sizes = self
+            .elements
+            .iter()
+            .map(|(pane, content)| (*pane, content.min_size))
+            .collect();
+
+        self.state
+            .constraints(min_sizes, f32::from(self.collapsed_thickness))
+    }
+
     fn click_pane(
         &mut self,
         layout: Layout<'_>,
         cursor_position: Point,
+        is_double_click: bool,
         messages: &mut Vec<Message>,
     ) {
         let mut clicked_region =
@@ -219,8 +244,20 @@ where
                 messages.push(on_click(*pane));
             }
 
+            let is_over_pick_area =
+                content.can_be_picked_at(layout, cursor_position);
+
+            if is_over_pick_area
+                && is_double_click
+                && self.state.is_collapsed(pane)
+            {
+                self.state.set_collapsed(pane, false);
+
+                return;
+            }
+
             if let Some(on_drag) = &self.on_drag {
-                if content.can_be_picked_at(layout, cursor_position) {
+                if is_over_pick_area {
                     let pane_position = layout.position();
 
                     let origin = cursor_position
@@ -244,9 +281,12 @@ where
             if let Some((split, _)) = self.state.picked_split() {
                 let bounds = layout.bounds();
 
+                let constraints = self.constraints();
+
                 let splits = self.state.split_regions(
                     f32::from(self.spacing),
                     Size::new(bounds.width, bounds.height),
+                    &constraints,
                 );
 
                 if let Some((axis, rectangle, _)) = splits.get(&split) {
@@ -265,6 +305,14 @@ where
                         }
                     };
 
+                    let ratio = self.state.clamp_ratio(
+                        &split,
+                        ratio,
+                        rectangle,
+                        f32::from(self.spacing),
+                        &constraints,
+                    );
+
                     messages.push(on_resize(ResizeEvent { split, ratio }));
 
                     return event::Status::Captured;
@@ -336,7 +384,11 @@ where
         let limits = limits.width(self.width).height(self.height);
         let size = limits.resolve(Size::ZERO);
 
-        let regions = self.state.pane_regions(f32::from(self.spacing), size);
+        let regions = self.state.pane_regions(
+            f32::from(self.spacing),
+            size,
+            &self.constraints(),
+        );
 
         let children = self
             .elements
@@ -361,11 +413,11 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         let mut event_status = event::Status::Ignored;
 
         match event {
@@ -373,9 +425,14 @@ where
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let bounds = layout.bounds();
 
-                if bounds.contains(cursor_position) {
+                if let Some(cursor_position) = cursor.position_over(bounds) {
                     event_status = event::Status::Captured;
 
+                    let is_double_click = matches!(
+                        self.state.click(cursor_position),
+                        mouse::click::Kind::Double
+                    );
+
                     match self.on_resize {
                         Some((leeway, _)) => {
                             let relative_cursor = Point::new(
@@ -386,6 +443,7 @@ where
                             let splits = self.state.split_regions(
                                 f32::from(self.spacing),
                                 Size::new(bounds.width, bounds.height),
+                                &self.constraints(),
                             );
 
                             let clicked_split = hovered_split(
@@ -395,17 +453,32 @@ where
                             );
 
                             if let Some((split, axis, _)) = clicked_split {
-                                self.state.pick_split(&split, axis);
+                                // Double clicking the split of a collapsed
+                                // pane restores it
+                                match self.state.collapsed_pane_of(&split) {
+                                    Some(pane) if is_double_click => {
+                                        self.state.set_collapsed(&pane, false);
+                                    }
+                                    _ => {
+                                        self.state.pick_split(&split, axis);
+                                    }
+                                }
                             } else {
                                 self.click_pane(
                                     layout,
                                     cursor_position,
+                                    is_double_click,
                                     messages,
                                 );
                             }
                         }
                         None => {
-                            self.click_pane(layout, cursor_position, messages);
+                            self.click_pane(
+                                layout,
+                                cursor_position,
+                                is_double_click,
+                                messages,
+                            );
                         }
                     }
                 }
@@ -417,9 +490,7 @@ where
                     if let Some(on_drag) = &self.on_drag {
                         let mut dropped_region =
                             self.elements.iter().zip(layout.children()).filter(
-                                |(_, layout)| {
-                                    layout.bounds().contains(cursor_position)
-                                },
+                                |(_, layout)| cursor.is_over(layout.bounds()),
                             );
 
                         let event = match dropped_region.next() {
@@ -446,8 +517,10 @@ where
             }
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
-                event_status =
-                    self.trigger_resize(layout, cursor_position, messages);
+                if let Some(cursor_position) = cursor.position() {
+                    event_status =
+                        self.trigger_resize(layout, cursor_position, messages);
+                }
             }
             _ => {}
         }
@@ -463,7 +536,7 @@ where
                 content.on_event(
                     event.clone(),
                     layout,
-                    cursor_position,
+                    cursor,
                     renderer,
                     clipboard,
                     messages,
@@ -471,6 +544,7 @@ where
                 )
             })
             .fold(event_status, event::Status::merge)
+            .cast()
     }
 
     fn draw(
@@ -478,18 +552,22 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
+        let constraints = self.constraints();
+
         let picked_split = self
             .state
             .picked_split()
             .and_then(|(split, axis)| {
                 let bounds = layout.bounds();
 
-                let splits = self
-                    .state
-                    .split_regions(f32::from(self.spacing), bounds.size());
+                let splits = self.state.split_regions(
+                    f32::from(self.spacing),
+                    bounds.size(),
+                    &constraints,
+                );
 
                 let (_axis, region, ratio) = splits.get(&split)?;
 
@@ -505,14 +583,13 @@ where
                 Some((leeway, _)) => {
                     let bounds = layout.bounds();
 
-                    let relative_cursor = Point::new(
-                        cursor_position.x - bounds.x,
-                        cursor_position.y - bounds.y,
-                    );
+                    let relative_cursor = cursor.position_in(bounds)?;
 
-                    let splits = self
-                        .state
-                        .split_regions(f32::from(self.spacing), bounds.size());
+                    let splits = self.state.split_regions(
+                        f32::from(self.spacing),
+                        bounds.size(),
+                        &constraints,
+                    );
 
                     let (_split, axis, region) = hovered_split(
                         splits.iter(),
@@ -537,7 +614,7 @@ where
             picked_split,
             layout,
             &self.style,
-            cursor_position,
+            cursor,
             viewport,
         )
     }
@@ -550,6 +627,7 @@ where
 
         self.width.hash(state);
         self.height.hash(state);
+        self.collapsed_thickness.hash(state);
         self.state.hash_layout(state);
 
         for (_, element) in &self.elements {
@@ -586,7 +664,7 @@ pub trait Renderer: crate::Renderer + container::Renderer + Sized {
     /// - the [`Pane`] that is currently being dragged
     /// - the [`Axis`] that is currently being resized
     /// - the [`Layout`] of the [`PaneGrid`] and its elements
-    /// - the cursor position
+    /// - the current [`mouse::Cursor`]
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -595,7 +673,7 @@ pub trait Renderer: crate::Renderer + container::Renderer + Sized {
         resizing: Option<(Axis, Rectangle, bool)>,
         layout: Layout<'_>,
         style: &<Self as self::Renderer>::Style,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output;
 
@@ -605,7 +683,7 @@ pub trait Renderer: crate::Renderer + container::Renderer + Sized {
     /// - the [`TitleBar`] of the [`Pane`], if any
     /// - the [`Content`] of the [`Pane`]
     /// - the [`Layout`] of the [`Pane`] and its elements
-    /// - the cursor position
+    /// - the current [`mouse::Cursor`]
     fn draw_pane<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -613,7 +691,7 @@ pub trait Renderer: crate::Renderer + container::Renderer + Sized {
         style: &<Self as container::Renderer>::Style,
         title_bar: Option<(&TitleBar<'_, Message, Self>, Layout<'_>)>,
         body: (&Element<'_, Message, Self>, Layout<'_>),
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output;
 
@@ -624,7 +702,7 @@ pub trait Renderer: crate::Renderer + container::Renderer + Sized {
     /// - the style of the [`TitleBar`]
     /// - the content of the [`TitleBar`] with its layout
     /// - the controls of the [`TitleBar`] with their [`Layout`], if any
-    /// - the cursor position
+    /// - the current [`mouse::Cursor`]
     fn draw_title_bar<Message>(
         &mut self,
         defaults: &Self::Defaults,
@@ -632,7 +710,7 @@ pub trait Renderer: crate::Renderer + container::Renderer + Sized {
         style: &<Self as container::Renderer>::Style,
         content: (&Element<'_, Message, Self>, Layout<'_>),
         controls: Option<(&Element<'_, Message, Self>, Layout<'_>)>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output;
 }
@@ -54,6 +54,13 @@ impl Axis {
         }
     }
 
+    pub(super) fn extent(&self, rectangle: &Rectangle) -> f32 {
+        match self {
+            Axis::Horizontal => rectangle.height,
+            Axis::Vertical => rectangle.width,
+        }
+    }
+
     pub(super) fn split_line_bounds(
         &self,
         rectangle: Rectangle,
@@ -1,6 +1,7 @@
 use crate::container;
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
 use crate::pane_grid::{self, TitleBar};
 use crate::{Clipboard, Element, Hasher, Layout, Point, Rectangle, Size};
@@ -13,6 +14,7 @@ pub struct Content<'a, Message, Renderer: pane_grid::Renderer> {
     title_bar: Option<TitleBar<'a, Message, Renderer>>,
     body: Element<'a, Message, Renderer>,
     style: <Renderer as container::Renderer>::Style,
+    pub(super) min_size: Size,
 }
 
 impl<'a, Message, Renderer> Content<'a, Message, Renderer>
@@ -25,6 +27,7 @@ where
             title_bar: None,
             body: body.into(),
             style: Default::default(),
+            min_size: Size::ZERO,
         }
     }
 
@@ -45,6 +48,18 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the minimum [`Size`] of the [`Content`].
+    ///
+    /// The splits of the [`PaneGrid`] are clamped to keep it, both when
+    /// dragging them and when the [`PaneGrid`] shrinks. The neighboring panes
+    /// absorb the difference, as long as they fit.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn min_size(mut self, min_size: Size) -> Self {
+        self.min_size = min_size;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Content<'a, Message, Renderer>
@@ -59,7 +74,7 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         if let Some(title_bar) = &self.title_bar {
@@ -73,7 +88,7 @@ where
                 &self.style,
                 Some((title_bar, title_bar_layout)),
                 (&self.body, body_layout),
-                cursor_position,
+                cursor,
                 viewport,
             )
         } else {
@@ -83,7 +98,7 @@ where
                 &self.style,
                 None,
                 (&self.body, layout),
-                cursor_position,
+                cursor,
                 viewport,
             )
         }
@@ -145,7 +160,7 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
@@ -159,7 +174,7 @@ where
             event_status = title_bar.on_event(
                 event.clone(),
                 children.next().unwrap(),
-                cursor_position,
+                cursor,
                 renderer,
                 clipboard,
                 messages,
@@ -176,17 +191,22 @@ where
             self.body.on_event(
                 event,
                 body_layout,
-                cursor_position,
+                cursor,
                 renderer,
                 clipboard,
                 messages,
             )
         };
 
-        event_status.merge(body_status)
+        event_status.merge(body_status.push_feedback(messages))
     }
 
     pub(crate) fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        (self.min_size.width as u32).hash(state);
+        (self.min_size.height as u32).hash(state);
+
         if let Some(title_bar) = &self.title_bar {
             title_bar.hash_layout(state);
         }
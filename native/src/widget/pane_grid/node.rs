@@ -3,7 +3,7 @@ use crate::{
     Rectangle, Size,
 };
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// A layout node of a [`PaneGrid`].
 ///
@@ -31,6 +31,22 @@ pub enum Node {
     Pane(Pane),
 }
 
+/// The constraints of the panes of a [`Node`] when computing its regions.
+///
+/// The ratios of the splits are adjusted on the fly to satisfy them, without
+/// changing the ratios stored in the [`Node`].
+#[derive(Debug, Clone, Default)]
+pub struct Constraints {
+    /// The minimum size of each [`Pane`].
+    pub min_sizes: HashMap<Pane, Size>,
+
+    /// The progress of each collapsed [`Pane`], in [0.0, 1.0].
+    pub collapsed: HashMap<Pane, f32>,
+
+    /// The thickness of a fully collapsed [`Pane`].
+    pub collapsed_thickness: f32,
+}
+
 impl Node {
     /// Returns an iterator over each [`Split`] in this [`Node`].
     pub fn splits(&self) -> impl Iterator<Item = &Split> {
@@ -59,6 +75,15 @@ impl Node {
         &self,
         spacing: f32,
         size: Size,
+    ) -> BTreeMap<Pane, Rectangle> {
+        self.constrained_pane_regions(spacing, size, &Constraints::default())
+    }
+
+    pub(crate) fn constrained_pane_regions(
+        &self,
+        spacing: f32,
+        size: Size,
+        constraints: &Constraints,
     ) -> BTreeMap<Pane, Rectangle> {
         let mut regions = BTreeMap::new();
 
@@ -70,6 +95,7 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
+            constraints,
             &mut regions,
         );
 
@@ -83,6 +109,15 @@ impl Node {
         &self,
         spacing: f32,
         size: Size,
+    ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
+        self.constrained_split_regions(spacing, size, &Constraints::default())
+    }
+
+    pub(crate) fn constrained_split_regions(
+        &self,
+        spacing: f32,
+        size: Size,
+        constraints: &Constraints,
     ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
         let mut splits = BTreeMap::new();
 
@@ -94,12 +129,76 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
+            constraints,
             &mut splits,
         );
 
         splits
     }
 
+    pub(crate) fn find_split(&self, split: &Split) -> Option<&Node> {
+        match self {
+            Node::Split { id, a, b, .. } => {
+                if id == split {
+                    Some(self)
+                } else {
+                    a.find_split(split).or_else(move || b.find_split(split))
+                }
+            }
+            Node::Pane(_) => None,
+        }
+    }
+
+    pub(crate) fn parent_axis(&self, pane: &Pane) -> Option<Axis> {
+        match self {
+            Node::Split { axis, a, b, .. } => {
+                if a.pane() == Some(*pane) || b.pane() == Some(*pane) {
+                    Some(*axis)
+                } else {
+                    a.parent_axis(pane).or_else(|| b.parent_axis(pane))
+                }
+            }
+            Node::Pane(_) => None,
+        }
+    }
+
+    /// Clamps the ratio of this split [`Node`] in the given region, so the
+    /// minimum sizes of the panes on both sides fit.
+    ///
+    /// When both sides do not fit, the remaining space is shared evenly.
+    pub(crate) fn clamp_ratio(
+        &self,
+        ratio: f32,
+        region: &Rectangle,
+        spacing: f32,
+        constraints: &Constraints,
+    ) -> f32 {
+        match self {
+            Node::Split { axis, a, b, .. } => {
+                let extent = axis.extent(region);
+
+                if extent <= 0.0 {
+                    return ratio;
+                }
+
+                let min_a =
+                    a.min_extent(*axis, Some(*axis), spacing, constraints);
+                let min_b =
+                    b.min_extent(*axis, Some(*axis), spacing, constraints);
+
+                let lower = (min_a + spacing / 2.0) / extent;
+                let upper = (extent - min_b - spacing / 2.0) / extent;
+
+                if lower > upper {
+                    (lower + upper) / 2.0
+                } else {
+                    ratio.max(lower).min(upper)
+                }
+            }
+            Node::Pane(_) => ratio,
+        }
+    }
+
     pub(crate) fn find(&mut self, pane: &Pane) -> Option<&mut Node> {
         match self {
             Node::Split { a, b, .. } => {
@@ -180,6 +279,102 @@ impl Node {
         }
     }
 
+    /// Returns the minimum extent of this [`Node`] along the given [`Axis`].
+    ///
+    /// Nested splits in the same [`Axis`] add up their minimums, while
+    /// perpendicular splits only need to fit the largest one.
+    fn min_extent(
+        &self,
+        axis: Axis,
+        parent: Option<Axis>,
+        spacing: f32,
+        constraints: &Constraints,
+    ) -> f32 {
+        match self {
+            Node::Split {
+                axis: split_axis,
+                a,
+                b,
+                ..
+            } => {
+                let parent = Some(*split_axis);
+
+                let a = a.min_extent(axis, parent, spacing, constraints);
+                let b = b.min_extent(axis, parent, spacing, constraints);
+
+                if *split_axis == axis {
+                    a + b + spacing
+                } else {
+                    a.max(b)
+                }
+            }
+            Node::Pane(pane) => {
+                let min_size = constraints
+                    .min_sizes
+                    .get(pane)
+                    .copied()
+                    .unwrap_or(Size::ZERO);
+
+                let min = match axis {
+                    Axis::Horizontal => min_size.height,
+                    Axis::Vertical => min_size.width,
+                };
+
+                // A collapsed pane only shrinks along the axis of its parent
+                match constraints.collapsed.get(pane) {
+                    Some(progress) if parent == Some(axis) => {
+                        let collapsed =
+                            constraints.collapsed_thickness.min(min);
+
+                        min + (collapsed - min) * progress
+                    }
+                    _ => min,
+                }
+            }
+        }
+    }
+
+    /// Returns the ratio of this split [`Node`] in the given region, once
+    /// its collapsed panes and minimum sizes are taken into account.
+    fn constrained_ratio(
+        &self,
+        region: &Rectangle,
+        spacing: f32,
+        constraints: &Constraints,
+    ) -> f32 {
+        match self {
+            Node::Split {
+                axis, ratio, a, b, ..
+            } => {
+                let extent = axis.extent(region);
+                let mut ratio = *ratio;
+
+                if extent > 0.0 {
+                    let collapsed = (constraints.collapsed_thickness
+                        + spacing / 2.0)
+                        / extent;
+
+                    let progress = |node: &Node| {
+                        node.pane()
+                            .and_then(|pane| constraints.collapsed.get(&pane))
+                            .copied()
+                    };
+
+                    if let Some(progress) = progress(a) {
+                        ratio += (collapsed - ratio) * progress;
+                    }
+
+                    if let Some(progress) = progress(b) {
+                        ratio += (1.0 - collapsed - ratio) * progress;
+                    }
+                }
+
+                self.clamp_ratio(ratio, region, spacing, constraints)
+            }
+            Node::Pane(_) => 0.0,
+        }
+    }
+
     fn first_pane(&self) -> Pane {
         match self {
             Node::Split { a, .. } => a.first_pane(),
@@ -191,16 +386,18 @@ impl Node {
         &self,
         spacing: f32,
         current: &Rectangle,
+        constraints: &Constraints,
         regions: &mut BTreeMap<Pane, Rectangle>,
     ) {
         match self {
-            Node::Split {
-                axis, ratio, a, b, ..
-            } => {
-                let (region_a, region_b) = axis.split(current, *ratio, spacing);
+            Node::Split { axis, a, b, .. } => {
+                let ratio =
+                    self.constrained_ratio(current, spacing, constraints);
 
-                a.compute_regions(spacing, &region_a, regions);
-                b.compute_regions(spacing, &region_b, regions);
+                let (region_a, region_b) = axis.split(current, ratio, spacing);
+
+                a.compute_regions(spacing, &region_a, constraints, regions);
+                b.compute_regions(spacing, &region_b, constraints, regions);
             }
             Node::Pane(pane) => {
                 let _ = regions.insert(*pane, *current);
@@ -212,22 +409,20 @@ impl Node {
         &self,
         spacing: f32,
         current: &Rectangle,
+        constraints: &Constraints,
         splits: &mut BTreeMap<Split, (Axis, Rectangle, f32)>,
     ) {
         match self {
-            Node::Split {
-                axis,
-                ratio,
-                a,
-                b,
-                id,
-            } => {
-                let (region_a, region_b) = axis.split(current, *ratio, spacing);
+            Node::Split { axis, a, b, id, .. } => {
+                let ratio =
+                    self.constrained_ratio(current, spacing, constraints);
 
-                let _ = splits.insert(*id, (*axis, *current, *ratio));
+                let (region_a, region_b) = axis.split(current, ratio, spacing);
 
-                a.compute_splits(spacing, &region_a, splits);
-                b.compute_splits(spacing, &region_b, splits);
+                let _ = splits.insert(*id, (*axis, *current, ratio));
+
+                a.compute_splits(spacing, &region_a, constraints, splits);
+                b.compute_splits(spacing, &region_b, constraints, splits);
             }
             Node::Pane(_) => {}
         }
@@ -256,3 +451,93 @@ impl std::hash::Hash for Node {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    /// A vertical split of `Pane(0)` and a nested vertical split of
+    /// `Pane(1)` and `Pane(2)`.
+    fn three_columns(ratio: f32) -> Node {
+        Node::Split {
+            id: Split(3),
+            axis: Axis::Vertical,
+            ratio,
+            a: Box::new(Node::Pane(Pane(0))),
+            b: Box::new(Node::Split {
+                id: Split(4),
+                axis: Axis::Vertical,
+                ratio: 0.5,
+                a: Box::new(Node::Pane(Pane(1))),
+                b: Box::new(Node::Pane(Pane(2))),
+            }),
+        }
+    }
+
+    fn min_width(width: f32) -> Constraints {
+        Constraints {
+            min_sizes: (0..3)
+                .map(|pane| (Pane(pane), Size::new(width, 0.0)))
+                .collect(),
+            ..Constraints::default()
+        }
+    }
+
+    #[test]
+    fn minimum_sizes_cascade_to_nested_splits() {
+        let node = three_columns(0.9);
+        let regions = node.constrained_pane_regions(
+            0.0,
+            Size::new(600.0, 100.0),
+            &min_width(150.0),
+        );
+
+        assert_eq!(regions[&Pane(0)].width, 300.0);
+        assert_eq!(regions[&Pane(1)].width, 150.0);
+        assert_eq!(regions[&Pane(2)].width, 150.0);
+
+        // The stored ratio is kept
+        assert_eq!(
+            node.split_regions(0.0, Size::new(600.0, 100.0))[&Split(3)].2,
+            0.9
+        );
+    }
+
+    #[test]
+    fn dragged_ratios_are_clamped() {
+        let node = three_columns(0.5);
+        let region = Rectangle::new(Point::ORIGIN, Size::new(600.0, 100.0));
+
+        assert_eq!(
+            node.clamp_ratio(0.05, &region, 0.0, &min_width(150.0)),
+            0.25
+        );
+        assert_eq!(node.clamp_ratio(0.4, &region, 0.0, &min_width(150.0)), 0.4);
+        assert_eq!(
+            node.clamp_ratio(0.95, &region, 0.0, &min_width(150.0)),
+            0.5
+        );
+    }
+
+    #[test]
+    fn collapsed_panes_shrink_to_their_thickness() {
+        let node = three_columns(0.5);
+        let size = Size::new(600.0, 100.0);
+
+        let mut constraints = min_width(100.0);
+        constraints.collapsed_thickness = 30.0;
+        let _ = constraints.collapsed.insert(Pane(0), 1.0);
+
+        let regions = node.constrained_pane_regions(0.0, size, &constraints);
+
+        assert_eq!(regions[&Pane(0)].width, 30.0);
+        assert_eq!(regions[&Pane(1)].width, 285.0);
+
+        let _ = constraints.collapsed.insert(Pane(0), 0.5);
+
+        let regions = node.constrained_pane_regions(0.0, size, &constraints);
+
+        assert_eq!(regions[&Pane(0)].width, 165.0);
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
-    pane_grid::{Axis, Configuration, Direction, Node, Pane, Split},
+    mouse,
+    pane_grid::{
+        node::Constraints, Axis, Configuration, Direction, Node, Pane, Split,
+    },
     Hasher, Point, Rectangle, Size,
 };
 
 use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
 
 /// The state of a [`PaneGrid`].
 ///
@@ -48,6 +52,9 @@ impl<T> State<T> {
                 layout,
                 last_id,
                 action: Action::Idle,
+                collapses: BTreeMap::new(),
+                transition: Duration::ZERO,
+                last_click: None,
             },
         }
     }
@@ -183,10 +190,55 @@ impl<T> State<T> {
         let _ = self.internal.layout.resize(split, ratio);
     }
 
+    /// Collapses the given [`Pane`] into a thin bar along the [`Axis`] of
+    /// its [`Split`], remembering its previous size.
+    ///
+    /// A [`Pane`] that is not split, like the only [`Pane`] of a [`State`],
+    /// cannot be collapsed.
+    pub fn collapse(&mut self, pane: &Pane) {
+        if self.internal.layout.parent_axis(pane).is_some() {
+            self.internal.set_collapsed(pane, true);
+        }
+    }
+
+    /// Restores the previous size of the given collapsed [`Pane`].
+    pub fn restore(&mut self, pane: &Pane) {
+        self.internal.set_collapsed(pane, false);
+    }
+
+    /// Returns whether the given [`Pane`] is collapsed or collapsing.
+    pub fn is_collapsed(&self, pane: &Pane) -> bool {
+        self.internal.is_collapsed(pane)
+    }
+
+    /// Sets the [`Duration`] of the transition when collapsing or restoring
+    /// a [`Pane`].
+    ///
+    /// By default, panes collapse and restore instantly.
+    pub fn set_transition(&mut self, duration: Duration) {
+        self.internal.transition = duration;
+    }
+
+    /// Returns whether a [`Pane`] is collapsing or restoring.
+    ///
+    /// The transition is sampled every time the [`PaneGrid`] is laid out, so
+    /// your application should keep redrawing while this returns `true`.
+    ///
+    /// [`PaneGrid`]: crate::widget::PaneGrid
+    pub fn is_animating(&self) -> bool {
+        let now = Instant::now();
+
+        self.internal.collapses.values().any(|collapse| {
+            !collapse.is_finished(now, self.internal.transition)
+        })
+    }
+
     /// Closes the given [`Pane`] and returns its internal state and its closest
     /// sibling, if it exists.
     pub fn close(&mut self, pane: &Pane) -> Option<(T, Pane)> {
         if let Some(sibling) = self.internal.layout.remove(pane) {
+            let _ = self.internal.collapses.remove(pane);
+
             self.panes.remove(pane).map(|state| (state, sibling))
         } else {
             None
@@ -229,6 +281,42 @@ pub struct Internal {
     layout: Node,
     last_id: usize,
     action: Action,
+    collapses: BTreeMap<Pane, Collapse>,
+    transition: Duration,
+    last_click: Option<mouse::Click>,
+}
+
+/// The collapse transition of a [`Pane`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Collapse {
+    is_collapsed: bool,
+    from: f32,
+    started_at: Instant,
+}
+
+impl Collapse {
+    /// Returns how collapsed the [`Pane`] is at the given [`Instant`], in
+    /// [0.0, 1.0].
+    fn progress(&self, now: Instant, transition: Duration) -> f32 {
+        let target = if self.is_collapsed { 1.0 } else { 0.0 };
+
+        let t = if transition == Duration::ZERO {
+            1.0
+        } else {
+            let elapsed = now.saturating_duration_since(self.started_at);
+
+            (elapsed.as_secs_f32() / transition.as_secs_f32()).min(1.0)
+        };
+
+        // Ease in and out
+        let t = t * t * (3.0 - 2.0 * t);
+
+        self.from + (target - self.from) * t
+    }
+
+    fn is_finished(&self, now: Instant, transition: Duration) -> bool {
+        now.saturating_duration_since(self.started_at) >= transition
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -257,16 +345,111 @@ impl Internal {
         &self,
         spacing: f32,
         size: Size,
+        constraints: &Constraints,
     ) -> BTreeMap<Pane, Rectangle> {
-        self.layout.pane_regions(spacing, size)
+        self.layout
+            .constrained_pane_regions(spacing, size, constraints)
     }
 
     pub fn split_regions(
         &self,
         spacing: f32,
         size: Size,
+        constraints: &Constraints,
     ) -> BTreeMap<Split, (Axis, Rectangle, f32)> {
-        self.layout.split_regions(spacing, size)
+        self.layout
+            .constrained_split_regions(spacing, size, constraints)
+    }
+
+    /// Returns the [`Constraints`] of the panes with the given minimum sizes,
+    /// sampling the collapse transitions at the current time.
+    pub fn constraints(
+        &self,
+        min_sizes: HashMap<Pane, Size>,
+        collapsed_thickness: f32,
+    ) -> Constraints {
+        let now = Instant::now();
+
+        Constraints {
+            min_sizes,
+            collapsed: self
+                .collapses
+                .iter()
+                .map(|(pane, collapse)| {
+                    (*pane, collapse.progress(now, self.transition))
+                })
+                .filter(|(_, progress)| *progress > 0.0)
+                .collect(),
+            collapsed_thickness,
+        }
+    }
+
+    pub fn clamp_ratio(
+        &self,
+        split: &Split,
+        ratio: f32,
+        region: &Rectangle,
+        spacing: f32,
+        constraints: &Constraints,
+    ) -> f32 {
+        match self.layout.find_split(split) {
+            Some(node) => node.clamp_ratio(ratio, region, spacing, constraints),
+            None => ratio,
+        }
+    }
+
+    pub fn is_collapsed(&self, pane: &Pane) -> bool {
+        self.collapses
+            .get(pane)
+            .map(|collapse| collapse.is_collapsed)
+            .unwrap_or(false)
+    }
+
+    /// Returns the collapsed [`Pane`] at either side of the given [`Split`],
+    /// if any.
+    pub fn collapsed_pane_of(&self, split: &Split) -> Option<Pane> {
+        match self.layout.find_split(split)? {
+            Node::Split { a, b, .. } => {
+                [a, b].iter().find_map(|node| match ***node {
+                    Node::Pane(pane) if self.is_collapsed(&pane) => Some(pane),
+                    _ => None,
+                })
+            }
+            Node::Pane(_) => None,
+        }
+    }
+
+    pub fn set_collapsed(&mut self, pane: &Pane, is_collapsed: bool) {
+        let now = Instant::now();
+
+        let from = self
+            .collapses
+            .get(pane)
+            .map(|collapse| collapse.progress(now, self.transition))
+            .unwrap_or(0.0);
+
+        if !is_collapsed && from <= 0.0 {
+            let _ = self.collapses.remove(pane);
+            return;
+        }
+
+        let _ = self.collapses.insert(
+            *pane,
+            Collapse {
+                is_collapsed,
+                from,
+                started_at: now,
+            },
+        );
+    }
+
+    /// Registers a click at the given position, returning its kind.
+    pub fn click(&mut self, position: Point) -> mouse::click::Kind {
+        let click = mouse::Click::new(position, self.last_click);
+
+        self.last_click = Some(click);
+
+        click.kind()
     }
 
     pub fn pick_pane(&mut self, pane: &Pane, origin: Point) {
@@ -297,5 +480,14 @@ impl Internal {
         use std::hash::Hash;
 
         self.layout.hash(hasher);
+
+        // Collapse transitions change the layout over time
+        let now = Instant::now();
+
+        for (pane, collapse) in &self.collapses {
+            pane.hash(hasher);
+            ((collapse.progress(now, self.transition) * 1_000.0) as u32)
+                .hash(hasher);
+        }
     }
 }
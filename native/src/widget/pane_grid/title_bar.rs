@@ -1,6 +1,7 @@
 use crate::container;
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
 use crate::pane_grid;
 use crate::{
@@ -87,7 +88,7 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
         show_controls: bool,
     ) -> Renderer::Output {
@@ -115,7 +116,7 @@ where
             &self.style,
             (&self.content, title_layout),
             controls,
-            cursor_position,
+            cursor,
             viewport,
         )
     }
@@ -206,7 +207,7 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
@@ -223,7 +224,7 @@ where
             controls.on_event(
                 event.clone(),
                 controls_layout,
-                cursor_position,
+                cursor,
                 renderer,
                 clipboard,
                 messages,
@@ -235,13 +236,15 @@ where
         let title_status = self.content.on_event(
             event,
             title_layout,
-            cursor_position,
+            cursor,
             renderer,
             clipboard,
             messages,
         );
 
-        control_status.merge(title_status)
+        control_status
+            .push_feedback(messages)
+            .merge(title_status.push_feedback(messages))
     }
 
     pub(crate) fn overlay(
@@ -9,12 +9,16 @@ use crate::scrollable;
 use crate::text;
 use crate::touch;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
-    Size, Widget,
+    Clipboard, Element, Hasher, Layout, Length, Padding, Rectangle, Size,
+    Widget,
 };
 use std::borrow::Cow;
+use std::marker::PhantomData;
 
 /// A widget for selecting a single value from a list of options.
+///
+/// The options are displayed with their [`ToString`] implementation, unless
+/// their labels are provided with [`PickList::with_labels`].
 #[allow(missing_debug_implementations)]
 pub struct PickList<'a, T, Message, Renderer: self::Renderer>
 where
@@ -24,11 +28,13 @@ where
     keyboard_modifiers: &'a mut keyboard::Modifiers,
     is_open: &'a mut bool,
     hovered_option: &'a mut Option<usize>,
-    last_selection: &'a mut Option<T>,
+    last_selection: &'a mut Option<String>,
     on_selected: Box<dyn Fn(T) -> Message>,
     options: Cow<'a, [T]>,
+    labels: Vec<String>,
     placeholder: Option<String>,
     selected: Option<T>,
+    selected_label: Option<String>,
     width: Length,
     padding: Padding,
     text_size: Option<u16>,
@@ -43,7 +49,8 @@ pub struct State<T> {
     keyboard_modifiers: keyboard::Modifiers,
     is_open: bool,
     hovered_option: Option<usize>,
-    last_selection: Option<T>,
+    last_selection: Option<String>,
+    options: PhantomData<T>,
 }
 
 impl<T> Default for State<T> {
@@ -54,6 +61,7 @@ impl<T> Default for State<T> {
             is_open: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
+            options: PhantomData,
         }
     }
 }
@@ -72,6 +80,73 @@ where
         options: impl Into<Cow<'a, [T]>>,
         selected: Option<T>,
         on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let options = options.into();
+        let labels = options.iter().map(ToString::to_string).collect();
+        let selected_label = selected.as_ref().map(ToString::to_string);
+
+        Self::from_parts(
+            state,
+            options,
+            labels,
+            selected,
+            selected_label,
+            on_selected,
+        )
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    PickList<'a, T, Message, Renderer>
+where
+    T: PartialEq,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`PickList`] with the given [`State`], a list of options
+    /// paired with their labels, the current selected value, and the message
+    /// to produce when an option is selected.
+    ///
+    /// The options are only compared to find the selected one, so they can
+    /// be any key identifying your data, like the id of a struct.
+    pub fn with_labels(
+        state: &'a mut State<T>,
+        options: impl IntoIterator<Item = (T, String)>,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let (options, labels): (Vec<T>, Vec<String>) =
+            options.into_iter().unzip();
+
+        let selected_label = selected
+            .as_ref()
+            .and_then(|selected| {
+                options.iter().position(|option| option == selected)
+            })
+            .map(|index| labels[index].clone());
+
+        Self::from_parts(
+            state,
+            Cow::Owned(options),
+            labels,
+            selected,
+            selected_label,
+            on_selected,
+        )
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    PickList<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    fn from_parts(
+        state: &'a mut State<T>,
+        options: Cow<'a, [T]>,
+        labels: Vec<String>,
+        selected: Option<T>,
+        selected_label: Option<String>,
+        on_selected: impl Fn(T) -> Message + 'static,
     ) -> Self {
         let State {
             menu,
@@ -79,6 +154,7 @@ where
             is_open,
             hovered_option,
             last_selection,
+            ..
         } = state;
 
         Self {
@@ -88,9 +164,11 @@ where
             hovered_option,
             last_selection,
             on_selected: Box::new(on_selected),
-            options: options.into(),
+            options,
+            labels,
             placeholder: None,
             selected,
+            selected_label,
             width: Length::Shrink,
             text_size: None,
             padding: Renderer::DEFAULT_PADDING,
@@ -142,7 +220,7 @@ where
 impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
     for PickList<'a, T, Message, Renderer>
 where
-    T: Clone + ToString + Eq,
+    T: Clone + PartialEq,
     [T]: ToOwned<Owned = Vec<T>>,
     Message: 'static,
     Renderer: self::Renderer + scrollable::Renderer + 'a,
@@ -183,10 +261,12 @@ where
                     width.round() as u32
                 };
 
-                let labels = self.options.iter().map(ToString::to_string);
-
-                let labels_width =
-                    labels.map(|label| measure(&label)).max().unwrap_or(100);
+                let labels_width = self
+                    .labels
+                    .iter()
+                    .map(|label| measure(label))
+                    .max()
+                    .unwrap_or(100);
 
                 let placeholder_width = self
                     .placeholder
@@ -221,10 +301,7 @@ where
             Length::Shrink => {
                 self.placeholder.hash(state);
 
-                self.options
-                    .iter()
-                    .map(ToString::to_string)
-                    .for_each(|label| label.hash(state));
+                self.labels.hash(state);
             }
             _ => {
                 self.width.hash(state);
@@ -236,21 +313,21 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
                 let event_status = if *self.is_open {
-                    // TODO: Encode cursor availability in the type system
-                    *self.is_open =
-                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                    // The menu hides the cursor from its pick list while
+                    // the cursor is over it
+                    *self.is_open = cursor.position().is_none();
 
                     event::Status::Captured
-                } else if layout.bounds().contains(cursor_position) {
+                } else if cursor.is_over(layout.bounds()) {
                     let selected = self.selected.as_ref();
 
                     *self.is_open = true;
@@ -264,8 +341,16 @@ where
                     event::Status::Ignored
                 };
 
-                if let Some(last_selection) = self.last_selection.take() {
-                    messages.push((self.on_selected)(last_selection));
+                // The menu selects the hovered label, which is used to find
+                // the option, as labels may not be unique
+                let last_selection = self
+                    .last_selection
+                    .take()
+                    .and_then(|_| *self.hovered_option)
+                    .and_then(|index| self.options.get(index));
+
+                if let Some(last_selection) = last_selection {
+                    messages.push((self.on_selected)(last_selection.clone()));
 
                     *self.is_open = false;
 
@@ -277,7 +362,7 @@ where
             Event::Mouse(mouse::Event::WheelScrolled {
                 delta: mouse::ScrollDelta::Lines { y, .. },
             }) if self.keyboard_modifiers.command()
-                && layout.bounds().contains(cursor_position)
+                && cursor.is_over(layout.bounds())
                 && !*self.is_open =>
             {
                 fn find_next<'a, T: PartialEq>(
@@ -325,14 +410,14 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
         self::Renderer::draw(
             renderer,
             layout.bounds(),
-            cursor_position,
-            self.selected.as_ref().map(ToString::to_string),
+            cursor,
+            self.selected_label.clone(),
             self.placeholder.as_ref().map(String::as_str),
             self.padding,
             self.text_size.unwrap_or(renderer.default_size()),
@@ -350,7 +435,7 @@ where
 
             let mut menu = Menu::new(
                 &mut self.menu,
-                &self.options,
+                &self.labels,
                 &mut self.hovered_option,
                 &mut self.last_selection,
             )
@@ -392,7 +477,7 @@ pub trait Renderer: text::Renderer + menu::Renderer {
     fn draw(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         selected: Option<String>,
         placeholder: Option<&str>,
         padding: Padding,
@@ -405,7 +490,7 @@ pub trait Renderer: text::Renderer + menu::Renderer {
 impl<'a, T: 'a, Message, Renderer> Into<Element<'a, Message, Renderer>>
     for PickList<'a, T, Message, Renderer>
 where
-    T: Clone + ToString + Eq,
+    T: Clone + PartialEq,
     [T]: ToOwned<Owned = Vec<T>>,
     Renderer: self::Renderer + 'a,
     Message: 'static,
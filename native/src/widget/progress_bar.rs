@@ -1,12 +1,16 @@
 //! Provide progress feedback to your users.
+use crate::mouse;
 use crate::{
-    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    layout, Element, Hasher, Layout, Length, Numeric, Rectangle, Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
 
 /// A bar that displays progress.
 ///
+/// The values of a [`ProgressBar`] are `f32` by default, but any [`Numeric`]
+/// type can be used.
+///
 /// # Example
 /// ```
 /// # use iced_native::renderer::Null;
@@ -19,23 +23,39 @@ use std::{hash::Hash, ops::RangeInclusive};
 ///
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
-pub struct ProgressBar<Renderer: self::Renderer> {
-    range: RangeInclusive<f32>,
-    value: f32,
+pub struct ProgressBar<Renderer: self::Renderer, T = f32> {
+    range: RangeInclusive<T>,
+    value: T,
     width: Length,
     height: Option<Length>,
     style: Renderer::Style,
 }
 
-impl<Renderer: self::Renderer> ProgressBar<Renderer> {
+impl<Renderer, T> ProgressBar<Renderer, T>
+where
+    Renderer: self::Renderer,
+    T: Numeric,
+{
     /// Creates a new [`ProgressBar`].
     ///
     /// It expects:
     ///   * an inclusive range of possible values
     ///   * the current value of the [`ProgressBar`]
-    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+    pub fn new(range: RangeInclusive<T>, value: T) -> Self {
+        let value = if value >= *range.start() {
+            value
+        } else {
+            *range.start()
+        };
+
+        let value = if value <= *range.end() {
+            value
+        } else {
+            *range.end()
+        };
+
         ProgressBar {
-            value: value.max(*range.start()).min(*range.end()),
+            value,
             range,
             width: Length::Fill,
             height: None,
@@ -62,9 +82,11 @@ impl<Renderer: self::Renderer> ProgressBar<Renderer> {
     }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for ProgressBar<Renderer>
+impl<Message, Renderer, T> Widget<Message, Renderer>
+    for ProgressBar<Renderer, T>
 where
     Renderer: self::Renderer,
+    T: Numeric,
 {
     fn width(&self) -> Length {
         self.width
@@ -95,13 +117,17 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
+        let start = self.range.start().to_f64();
+        let end = self.range.end().to_f64();
+
+        // Values are relative to the start, so large ones keep their precision
         renderer.draw(
             layout.bounds(),
-            self.range.clone(),
-            self.value,
+            0.0..=(end - start) as f32,
+            (self.value.to_f64() - start) as f32,
             &self.style,
         )
     }
@@ -132,8 +158,9 @@ pub trait Renderer: crate::Renderer {
     ///
     /// It receives:
     ///   * the bounds of the [`ProgressBar`]
-    ///   * the range of values of the [`ProgressBar`]
-    ///   * the current value of the [`ProgressBar`]
+    ///   * the range of values of the [`ProgressBar`], relative to its start
+    ///   * the current value of the [`ProgressBar`], relative to the start of
+    ///     its range
     ///   * maybe a specific background of the [`ProgressBar`]
     ///   * maybe a specific active color of the [`ProgressBar`]
     fn draw(
@@ -145,14 +172,15 @@ pub trait Renderer: crate::Renderer {
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<ProgressBar<Renderer>>
+impl<'a, Message, Renderer, T> From<ProgressBar<Renderer, T>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer,
     Message: 'a,
+    T: 'a + Numeric,
 {
     fn from(
-        progress_bar: ProgressBar<Renderer>,
+        progress_bar: ProgressBar<Renderer, T>,
     ) -> Element<'a, Message, Renderer> {
         Element::new(progress_bar)
     }
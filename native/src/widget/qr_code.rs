@@ -0,0 +1,281 @@
+//! Encode and display information in a QR code, module by module.
+use crate::mouse;
+use crate::{
+    layout, Color, Element, Hasher, Layout, Length, Rectangle, Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+
+/// The amount of light modules surrounding the code on every side.
+pub const QUIET_ZONE: usize = 2;
+
+const DEFAULT_SIZE: u32 = 4;
+
+/// A QR code encoding some data, drawn module by module.
+///
+/// The data is encoded once, when the [`QrCode`] is created, so no state needs
+/// to be kept around.
+///
+/// # Example
+/// ```
+/// # use iced_native::{Color, QrCode};
+/// #
+/// let qr_code = QrCode::new("https://github.com/hecrj/iced")
+///     .expect("Encode data")
+///     .size(8)
+///     .dark_color(Color::from_rgb(0.1, 0.1, 0.3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct QrCode {
+    modules: Vec<bool>,
+    width: usize,
+    size: u32,
+    dark: Color,
+    light: Color,
+}
+
+impl QrCode {
+    /// Encodes the given data in a new [`QrCode`].
+    ///
+    /// The smallest version fitting the data is chosen, with a medium error
+    /// correction level.
+    pub fn new(data: impl Into<String>) -> Result<Self, Error> {
+        let encoded = qrcode::QrCode::new(data.into().as_bytes())?;
+
+        let width = encoded.width();
+        let modules = encoded
+            .into_colors()
+            .into_iter()
+            .map(|color| color == qrcode::Color::Dark)
+            .collect();
+
+        Ok(QrCode {
+            modules,
+            width,
+            size: DEFAULT_SIZE,
+            dark: Color::BLACK,
+            light: Color::WHITE,
+        })
+    }
+
+    /// Sets the size of every module of the [`QrCode`], in pixels.
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the [`Color`] of the dark modules of the [`QrCode`].
+    pub fn dark_color(mut self, color: Color) -> Self {
+        self.dark = color;
+        self
+    }
+
+    /// Sets the [`Color`] of the light modules of the [`QrCode`].
+    pub fn light_color(mut self, color: Color) -> Self {
+        self.light = color;
+        self
+    }
+
+    /// Returns the amount of modules on every side of the [`QrCode`], without
+    /// its quiet zone.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns whether the module at the given row and column is dark.
+    pub fn is_dark(&self, row: usize, column: usize) -> bool {
+        row < self.width
+            && column < self.width
+            && self.modules[row * self.width + column]
+    }
+
+    fn side_length(&self) -> f32 {
+        ((self.width + 2 * QUIET_ZONE) as u32 * self.size) as f32
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for QrCode
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        let side_length = self.side_length();
+
+        layout::Node::new(Size::new(side_length, side_length))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(
+            layout.bounds(),
+            &self.modules,
+            self.width,
+            self.size as f32,
+            self.dark,
+            self.light,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`QrCode`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`QrCode`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`QrCode`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`QrCode`], including its [`QUIET_ZONE`]
+    ///   * whether every module is dark, row by row
+    ///   * the amount of modules on every side
+    ///   * the size of a module
+    ///   * the [`Color`] of the dark modules
+    ///   * the [`Color`] of the light modules
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        modules: &[bool],
+        width: usize,
+        module_size: f32,
+        dark: Color,
+        light: Color,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<QrCode> for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(qr_code: QrCode) -> Element<'a, Message, Renderer> {
+        Element::new(qr_code)
+    }
+}
+
+/// An error that occurred when encoding some data in a QR code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The data is too long to encode in a QR code for the chosen version.
+    DataTooLong,
+
+    /// The chosen version and error correction combination is invalid.
+    InvalidVersion,
+
+    /// One or more characters in the provided data are not supported by the
+    /// chosen version.
+    UnsupportedCharacterSet,
+
+    /// The chosen ECI designator is invalid. A valid designator should be
+    /// between 0 and 999999.
+    InvalidEciDesignator,
+
+    /// A character that does not belong to the character set was found.
+    InvalidCharacter,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::DataTooLong => {
+                "The data is too long to encode in a QR code for the chosen \
+                version"
+            }
+            Error::InvalidVersion => {
+                "The chosen version and error correction level combination is \
+                invalid."
+            }
+            Error::UnsupportedCharacterSet => {
+                "One or more characters in the provided data are not supported \
+                by the chosen version"
+            }
+            Error::InvalidEciDesignator => {
+                "The chosen ECI designator is invalid. A valid designator \
+                should be between 0 and 999999."
+            }
+            Error::InvalidCharacter => {
+                "A character that does not belong to the character set was \
+                found"
+            }
+        })
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<qrcode::types::QrError> for Error {
+    fn from(error: qrcode::types::QrError) -> Self {
+        use qrcode::types::QrError;
+
+        match error {
+            QrError::DataTooLong => Error::DataTooLong,
+            QrError::InvalidVersion => Error::InvalidVersion,
+            QrError::UnsupportedCharacterSet => Error::UnsupportedCharacterSet,
+            QrError::InvalidEciDesignator => Error::InvalidEciDesignator,
+            QrError::InvalidCharacter => Error::InvalidCharacter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    #[test]
+    fn layout_fits_the_modules_and_the_quiet_zone() {
+        let qr_code = QrCode::new("iced").expect("Encode data").size(3);
+
+        // The smallest version has 21 modules on every side
+        assert_eq!(qr_code.width(), 21);
+
+        let node = <QrCode as Widget<(), Null>>::layout(
+            &qr_code,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::INFINITY),
+        );
+
+        let side_length = ((21 + 2 * QUIET_ZONE) * 3) as f32;
+
+        assert_eq!(node.size(), Size::new(side_length, side_length));
+    }
+
+    #[test]
+    fn finder_patterns_are_dark() {
+        let qr_code = QrCode::new("iced").expect("Encode data");
+        let last = qr_code.width() - 1;
+
+        assert!(qr_code.is_dark(0, 0));
+        assert!(qr_code.is_dark(0, last));
+        assert!(qr_code.is_dark(last, 0));
+        assert!(!qr_code.is_dark(last + 1, 0));
+    }
+}
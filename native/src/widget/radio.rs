@@ -9,8 +9,8 @@ use crate::row;
 use crate::text;
 use crate::touch;
 use crate::{
-    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Row,
-    Text, Widget,
+    Clipboard, Color, Element, Hasher, Layout, Length, Rectangle, Row, Text,
+    Widget,
 };
 
 /// A circular button representing a choice.
@@ -50,7 +50,7 @@ pub struct Radio<Message, Renderer: self::Renderer + text::Renderer> {
     text_size: Option<u16>,
     text_color: Option<Color>,
     font: Renderer::Font,
-    style: Renderer::Style,
+    style: Option<Renderer::Style>,
 }
 
 impl<Message, Renderer: self::Renderer + text::Renderer>
@@ -76,17 +76,46 @@ where
         V: Eq + Copy,
         F: 'static + Fn(V) -> Message,
     {
+        Self::from_parts(Some(value) == selected, f(value), label.into())
+    }
+
+    /// Creates a new [`Radio`] button identified by an index.
+    ///
+    /// It expects:
+    ///   * the index of the value related to the [`Radio`] button
+    ///   * the label of the [`Radio`] button
+    ///   * the index of the current selected value
+    ///   * a function that will be called when the [`Radio`] is selected. It
+    ///     receives the index of the radio and must produce a `Message`.
+    ///
+    /// This is useful when the values cannot be copied or compared, like
+    /// structs holding a `String`: the index can be used to find the value
+    /// in your own list.
+    pub fn new_with(
+        value_index: usize,
+        label: impl Into<String>,
+        selected_index: Option<usize>,
+        f: impl Fn(usize) -> Message,
+    ) -> Self {
+        Self::from_parts(
+            Some(value_index) == selected_index,
+            f(value_index),
+            label.into(),
+        )
+    }
+
+    fn from_parts(is_selected: bool, on_click: Message, label: String) -> Self {
         Radio {
-            is_selected: Some(value) == selected,
-            on_click: f(value),
-            label: label.into(),
+            is_selected,
+            on_click,
+            label,
             width: Length::Shrink,
             size: <Renderer as self::Renderer>::DEFAULT_SIZE,
             spacing: Renderer::DEFAULT_SPACING, //15
             text_size: None,
             text_color: None,
             font: Default::default(),
-            style: Renderer::Style::default(),
+            style: None,
         }
     }
 
@@ -128,7 +157,7 @@ where
 
     /// Sets the style of the [`Radio`] button.
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
-        self.style = style.into();
+        self.style = Some(style.into());
         self
     }
 }
@@ -172,15 +201,15 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout.bounds().contains(cursor_position) {
+                if cursor.is_over(layout.bounds()) {
                     messages.push(self.on_click.clone());
 
                     return event::Status::Captured;
@@ -197,7 +226,7 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
@@ -219,7 +248,7 @@ where
             alignment::Vertical::Center,
         );
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
         self::Renderer::draw(
             renderer,
@@ -227,7 +256,7 @@ where
             self.is_selected,
             is_mouse_over,
             label,
-            &self.style,
+            self.style.as_ref(),
         )
     }
 
@@ -236,6 +265,10 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.label.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
     }
 }
 
@@ -247,7 +280,7 @@ where
 /// [renderer]: crate::renderer
 pub trait Renderer: crate::Renderer {
     /// The style supported by this renderer.
-    type Style: Default;
+    type Style;
 
     /// The default size of a [`Radio`] button.
     const DEFAULT_SIZE: u16;
@@ -262,13 +295,15 @@ pub trait Renderer: crate::Renderer {
     ///   * whether the [`Radio`] is selected or not
     ///   * whether the mouse is over the [`Radio`] or not
     ///   * the drawn label of the [`Radio`]
+    ///   * the style of the [`Radio`], if it was set explicitly. Otherwise,
+    ///     the renderer should resolve it from its active theme.
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
         label: Self::Output,
-        style: &Self::Style,
+        style: Option<&Self::Style>,
     ) -> Self::Output;
 }
 
@@ -282,3 +317,39 @@ where
         Element::new(radio)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    #[derive(Debug)]
+    struct Server {
+        name: String,
+    }
+
+    #[test]
+    fn index_based_radios_select_by_index() {
+        let servers = [
+            Server {
+                name: String::from("Europe"),
+            },
+            Server {
+                name: String::from("Asia"),
+            },
+        ];
+
+        let radios: Vec<Radio<usize, Null>> = servers
+            .iter()
+            .enumerate()
+            .map(|(i, server)| {
+                Radio::new_with(i, server.name.clone(), Some(1), |i| i)
+            })
+            .collect();
+
+        assert!(!radios[0].is_selected);
+        assert!(radios[1].is_selected);
+        assert_eq!(radios[0].on_click, 0);
+        assert_eq!(radios[1].on_click, 1);
+    }
+}
@@ -1,10 +1,11 @@
 //! Distribute content horizontally.
 use crate::event::{self, Event};
 use crate::layout;
+use crate::mouse;
 use crate::overlay;
 use crate::{
-    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
-    Rectangle, Widget,
+    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Rectangle,
+    Widget,
 };
 
 use std::hash::Hash;
@@ -140,25 +141,29 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         self.children
             .iter_mut()
             .zip(layout.children())
             .map(|(child, layout)| {
-                child.widget.on_event(
-                    event.clone(),
-                    layout,
-                    cursor_position,
-                    renderer,
-                    clipboard,
-                    messages,
-                )
+                child
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                    .push_feedback(messages)
             })
             .fold(event::Status::Ignored, event::Status::merge)
+            .cast()
     }
 
     fn draw(
@@ -166,16 +171,10 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(
-            defaults,
-            &self.children,
-            layout,
-            cursor_position,
-            viewport,
-        )
+        renderer.draw(defaults, &self.children, layout, cursor, viewport)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -225,7 +224,7 @@ pub trait Renderer: crate::Renderer + Sized {
         defaults: &Self::Defaults,
         children: &[Element<'_, Message, Self>],
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Self::Output;
 }
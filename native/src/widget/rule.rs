@@ -1,5 +1,7 @@
 //! Display a horizontal or vertical rule for dividing content.
 
+use crate::mouse;
+use crate::text;
 use std::hash::Hash;
 
 use crate::{
@@ -7,22 +9,35 @@ use crate::{
 };
 
 /// Display a horizontal or vertical rule for dividing content.
-#[derive(Debug, Copy, Clone)]
+///
+/// A [`Rule`] can have a label in its middle, drawn in a gap of its line.
+#[derive(Debug, Clone)]
 pub struct Rule<Renderer: self::Renderer> {
     width: Length,
     height: Length,
-    style: Renderer::Style,
+    style: Option<Renderer::Style>,
     is_horizontal: bool,
+    label: Option<String>,
+    label_size: Option<u16>,
+    label_font: Renderer::Font,
+    label_padding: u16,
 }
 
 impl<Renderer: self::Renderer> Rule<Renderer> {
+    /// The default padding between the label of a [`Rule`] and its line.
+    pub const DEFAULT_LABEL_PADDING: u16 = 8;
+
     /// Creates a horizontal [`Rule`] for dividing content by the given vertical spacing.
     pub fn horizontal(spacing: u16) -> Self {
         Rule {
             width: Length::Fill,
             height: Length::from(Length::Units(spacing)),
-            style: Renderer::Style::default(),
+            style: None,
             is_horizontal: true,
+            label: None,
+            label_size: None,
+            label_font: Default::default(),
+            label_padding: Self::DEFAULT_LABEL_PADDING,
         }
     }
 
@@ -31,14 +46,45 @@ impl<Renderer: self::Renderer> Rule<Renderer> {
         Rule {
             width: Length::from(Length::Units(spacing)),
             height: Length::Fill,
-            style: Renderer::Style::default(),
+            style: None,
             is_horizontal: false,
+            label: None,
+            label_size: None,
+            label_font: Default::default(),
+            label_padding: Self::DEFAULT_LABEL_PADDING,
         }
     }
 
     /// Sets the style of the [`Rule`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
-        self.style = style.into();
+        self.style = Some(style.into());
+        self
+    }
+
+    /// Sets the label of the [`Rule`], drawn in its middle.
+    ///
+    /// The spacing of the [`Rule`] grows to fit the label, if needed.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the text size of the label of the [`Rule`].
+    pub fn label_size(mut self, size: u16) -> Self {
+        self.label_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the label of the [`Rule`].
+    pub fn label_font(mut self, font: Renderer::Font) -> Self {
+        self.label_font = font;
+        self
+    }
+
+    /// Sets the padding between the label of the [`Rule`] and its line, on
+    /// each side of the label.
+    pub fn label_padding(mut self, padding: u16) -> Self {
+        self.label_padding = padding;
         self
     }
 }
@@ -57,50 +103,152 @@ where
 
     fn layout(
         &self,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits = limits.width(self.width).height(self.height);
+        let label = match &self.label {
+            Some(label) => label,
+            None => {
+                let limits = limits.width(self.width).height(self.height);
+
+                return layout::Node::new(limits.resolve(Size::ZERO));
+            }
+        };
+
+        let (label_width, label_height) = renderer.measure(
+            label,
+            self.label_size.unwrap_or(renderer.default_size()),
+            self.label_font,
+            Size::INFINITY,
+        );
+
+        // The label widens the rule across its line, if needed
+        let limits = if self.is_horizontal {
+            limits.min_height(label_height.ceil() as u32)
+        } else {
+            limits.min_width(label_width.ceil() as u32)
+        }
+        .width(self.width)
+        .height(self.height);
+
+        let size = limits.resolve(Size::new(label_width, label_height));
+
+        let mut label = layout::Node::new(Size::new(label_width, label_height));
 
-        layout::Node::new(limits.resolve(Size::ZERO))
+        label.move_to(Point::new(
+            (size.width - label_width) / 2.0,
+            (size.height - label_height) / 2.0,
+        ));
+
+        layout::Node::with_children(size, vec![label])
     }
 
     fn draw(
         &self,
         renderer: &mut Renderer,
-        _defaults: &Renderer::Defaults,
+        defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(layout.bounds(), &self.style, self.is_horizontal)
+        let label = self.label.as_ref().zip(layout.children().next()).map(
+            |(content, layout)| Label {
+                content,
+                bounds: layout.bounds(),
+                gap: gap(
+                    layout.bounds(),
+                    self.label_padding,
+                    self.is_horizontal,
+                ),
+                size: self.label_size.unwrap_or(renderer.default_size()),
+                font: self.label_font,
+            },
+        );
+
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            self.style.as_ref(),
+            self.is_horizontal,
+            label,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        // The style of a rule is only drawn, so its size and label are all its
+        // layout depends on.
         self.width.hash(state);
         self.height.hash(state);
+        self.label.hash(state);
+        self.label_size.hash(state);
+    }
+}
+
+/// The label of a [`Rule`].
+#[derive(Debug, Clone, Copy)]
+pub struct Label<'a, Font> {
+    /// The text of the [`Label`].
+    pub content: &'a str,
+
+    /// The bounds of the text of the [`Label`].
+    pub bounds: Rectangle,
+
+    /// The gap left in the line of the [`Rule`] for the [`Label`].
+    pub gap: Rectangle,
+
+    /// The text size of the [`Label`].
+    pub size: u16,
+
+    /// The font of the [`Label`].
+    pub font: Font,
+}
+
+/// Returns the gap left in the line of a [`Rule`] for a label with the given
+/// bounds and padding.
+fn gap(label: Rectangle, padding: u16, is_horizontal: bool) -> Rectangle {
+    let padding = f32::from(padding);
+
+    if is_horizontal {
+        Rectangle {
+            x: label.x - padding,
+            width: label.width + padding * 2.0,
+            ..label
+        }
+    } else {
+        Rectangle {
+            y: label.y - padding,
+            height: label.height + padding * 2.0,
+            ..label
+        }
     }
 }
 
 /// The renderer of a [`Rule`].
-pub trait Renderer: crate::Renderer {
+pub trait Renderer: text::Renderer {
     /// The style supported by this renderer.
-    type Style: Default;
+    type Style;
 
     /// Draws a [`Rule`].
     ///
     /// It receives:
+    ///   * the default styling of text
     ///   * the bounds of the [`Rule`]
-    ///   * the style of the [`Rule`]
+    ///   * the style of the [`Rule`], if it was set explicitly. Otherwise,
+    ///     the renderer should resolve it from its active theme.
     ///   * whether the [`Rule`] is horizontal (true) or vertical (false)
+    ///   * the [`Label`] of the [`Rule`], if any, whose gap should be left
+    ///     out of the line
     fn draw(
         &mut self,
+        defaults: &Self::Defaults,
         bounds: Rectangle,
-        style: &Self::Style,
+        style: Option<&Self::Style>,
         is_horizontal: bool,
+        label: Option<Label<'_, Self::Font>>,
     ) -> Self::Output;
 }
 
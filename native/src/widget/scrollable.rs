@@ -1,6 +1,7 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::column;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
@@ -12,8 +13,8 @@ use crate::{
 
 use std::{f32, hash::Hash, u32};
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with scrollbars,
+/// vertically, horizontally or both.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
@@ -22,6 +23,8 @@ pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     scrollbar_width: u16,
     scrollbar_margin: u16,
     scroller_width: u16,
+    scrollbar_visibility: ScrollbarVisibility,
+    direction: Direction,
     content: Column<'a, Message, Renderer>,
     on_scroll: Option<Box<dyn Fn(f32) -> Message>>,
     style: Renderer::Style,
@@ -37,6 +40,8 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
             scrollbar_width: 10,
             scrollbar_margin: 0,
             scroller_width: 10,
+            scrollbar_visibility: ScrollbarVisibility::default(),
+            direction: Direction::default(),
             content: Column::new(),
             on_scroll: None,
             style: Renderer::Style::default(),
@@ -110,10 +115,51 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`ScrollbarVisibility`] of the [`Scrollable`].
+    ///
+    /// An [`Always`] visible scrollbar reserves its width in the layout, so it
+    /// never covers the content. Otherwise, it is drawn over the content.
+    ///
+    /// [`Always`]: ScrollbarVisibility::Always
+    pub fn scrollbar_visibility(
+        mut self,
+        scrollbar_visibility: ScrollbarVisibility,
+    ) -> Self {
+        self.scrollbar_visibility = scrollbar_visibility;
+        self
+    }
+
+    /// Sets the [`Direction`] in which the [`Scrollable`] can be scrolled.
+    ///
+    /// Content that scrolls horizontally is laid out with an unbounded width,
+    /// so it should not use a [`Length::Fill`] width.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the [`Anchor`] of the vertical scrolling offset of the
+    /// [`Scrollable`].
+    ///
+    /// With [`Anchor::End`], content shorter than the [`Scrollable`] sits at
+    /// its bottom, and the offset is kept from the bottom: the [`Scrollable`]
+    /// stays scrolled to the bottom while its content grows, like a chat.
+    ///
+    /// Changing the [`Anchor`] of a [`State`] scrolls it to the new anchor.
+    pub fn anchor(self, anchor: Anchor) -> Self {
+        self.state.set_anchor(anchor);
+        self
+    }
+
     /// Sets a function to call when the [`Scrollable`] is scrolled.
     ///
-    /// The function takes the new relative offset of the [`Scrollable`]
-    /// (e.g. `0` means top, while `1` means bottom).
+    /// The function takes the new relative vertical offset of the
+    /// [`Scrollable`] (e.g. `0` means top, while `1` means bottom).
+    ///
+    /// It is called whenever the offset changes, whether it was scrolled with
+    /// the mouse wheel, a touch, the scroller, or programmatically with
+    /// [`State::snap_to`]. A programmatic change is reported on the next event
+    /// processed by the [`Scrollable`].
     pub fn on_scroll(mut self, f: impl Fn(f32) -> Message + 'static) -> Self {
         self.on_scroll = Some(Box::new(f));
         self
@@ -135,22 +181,209 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     }
 
     fn notify_on_scroll(
-        &self,
+        &mut self,
         bounds: Rectangle,
         content_bounds: Rectangle,
         messages: &mut Vec<Message>,
     ) {
-        if content_bounds.height <= bounds.height {
+        let offset =
+            match Self::relative_offset(self.state, bounds, content_bounds) {
+                Some(offset) => offset,
+                None => return,
+            };
+
+        if self.state.last_notified_offset == Some(offset) {
             return;
         }
 
+        self.state.last_notified_offset = Some(offset);
+
         if let Some(on_scroll) = &self.on_scroll {
-            messages.push(on_scroll(
-                self.state.offset.absolute(bounds, content_bounds)
-                    / (content_bounds.height - bounds.height),
-            ));
+            messages.push(on_scroll(offset));
         }
     }
+
+    fn relative_offset(
+        state: &State,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<f32> {
+        if content_bounds.height <= bounds.height {
+            return None;
+        }
+
+        Some(
+            state.vertical_offset(bounds, content_bounds)
+                / (content_bounds.height - bounds.height),
+        )
+    }
+
+    /// Returns the space reserved for a scrollbar in the layout.
+    fn reserved_space(&self) -> f32 {
+        match self.scrollbar_visibility {
+            ScrollbarVisibility::Always => f32::from(
+                self.scrollbar_width.max(self.scroller_width)
+                    + 2 * self.scrollbar_margin,
+            ),
+            ScrollbarVisibility::WhenScrolling
+            | ScrollbarVisibility::WhenHovered => 0.0,
+        }
+    }
+
+    fn offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector<u32> {
+        Vector::new(
+            self.state.horizontal_offset(bounds, content_bounds),
+            self.state.offset(bounds, content_bounds),
+        )
+    }
+
+    fn scroll(
+        &mut self,
+        delta_x: f32,
+        delta_y: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if self.direction.is_vertical() {
+            self.state.scroll(delta_y, bounds, content_bounds);
+        }
+
+        if self.direction.is_horizontal() {
+            self.state
+                .scroll_horizontally(delta_x, bounds, content_bounds);
+        }
+    }
+
+    fn scrollbars(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> (Option<Scrollbar>, Option<Scrollbar>) {
+        let offset = self.offset(bounds, content_bounds);
+
+        let scrollbar = if self.direction.is_vertical() {
+            renderer.scrollbar(
+                bounds,
+                content_bounds,
+                offset.y,
+                self.scrollbar_width,
+                self.scrollbar_margin,
+                self.scroller_width,
+                &self.style,
+            )
+        } else {
+            None
+        };
+
+        let horizontal_scrollbar = if self.direction.is_horizontal() {
+            renderer.horizontal_scrollbar(
+                bounds,
+                content_bounds,
+                offset.x,
+                self.scrollbar_width,
+                self.scrollbar_margin,
+                self.scroller_width,
+                &self.style,
+            )
+        } else {
+            None
+        };
+
+        (scrollbar, horizontal_scrollbar)
+    }
+}
+
+/// The visibility of the scrollbar of a [`Scrollable`].
+///
+/// The scrollbar is never shown when the content fits in the
+/// [`Scrollable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollbarVisibility {
+    /// The scrollbar is always shown, next to the content.
+    Always,
+
+    /// The scrollbar is shown over the content while it is being scrolled,
+    /// until the cursor moves without scrolling.
+    WhenScrolling,
+
+    /// The scrollbar is shown over the content while the cursor is over the
+    /// [`Scrollable`].
+    ///
+    /// A scrollbar whose style has a track background or border is always
+    /// shown.
+    WhenHovered,
+}
+
+impl Default for ScrollbarVisibility {
+    fn default() -> Self {
+        ScrollbarVisibility::WhenHovered
+    }
+}
+
+/// The direction in which a [`Scrollable`] can be scrolled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The content can be scrolled vertically.
+    Vertical,
+
+    /// The content can be scrolled horizontally.
+    Horizontal,
+
+    /// The content can be scrolled both vertically and horizontally, each
+    /// axis with its own scrollbar.
+    Both,
+}
+
+impl Direction {
+    /// Returns whether the [`Direction`] allows vertical scrolling.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Vertical | Direction::Both)
+    }
+
+    /// Returns whether the [`Direction`] allows horizontal scrolling.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Horizontal | Direction::Both)
+    }
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::Vertical
+    }
+}
+
+/// The edge of the content of a [`Scrollable`] that its vertical offset is
+/// measured from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// The offset is measured from the top of the content.
+    Start,
+
+    /// The offset is measured from the bottom of the content, which is
+    /// aligned to the bottom of the [`Scrollable`] when it fits.
+    End,
+}
+
+impl Anchor {
+    /// Converts an offset measured from the [`Anchor`] into one measured
+    /// from the top, and vice versa.
+    fn flip(self, offset: f32, hidden_content: f32) -> f32 {
+        match self {
+            Anchor::Start => offset,
+            Anchor::End => (hidden_content - offset).max(0.0),
+        }
+    }
+}
+
+impl Default for Anchor {
+    fn default() -> Self {
+        Anchor::Start
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -176,13 +409,48 @@ where
             .width(Widget::<Message, Renderer>::width(&self.content))
             .height(self.height);
 
+        let reserved_width = if self.direction.is_vertical() {
+            self.reserved_space()
+        } else {
+            0.0
+        };
+
+        let reserved_height = if self.direction.is_horizontal() {
+            self.reserved_space()
+        } else {
+            0.0
+        };
+
         let child_limits = layout::Limits::new(
-            Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
+            Size::new((limits.min().width - reserved_width).max(0.0), 0.0),
+            Size::new(
+                if self.direction.is_horizontal() {
+                    f32::INFINITY
+                } else {
+                    (limits.max().width - reserved_width).max(0.0)
+                },
+                if self.direction.is_vertical() {
+                    f32::INFINITY
+                } else {
+                    (limits.max().height - reserved_height).max(0.0)
+                },
+            ),
         );
 
-        let content = self.content.layout(renderer, &child_limits);
-        let size = limits.resolve(content.size());
+        let mut content = self.content.layout(renderer, &child_limits);
+        let content_size = content.size();
+
+        let size = limits.resolve(Size::new(
+            content_size.width + reserved_width,
+            content_size.height + reserved_height,
+        ));
+
+        if self.state.anchor == Anchor::End {
+            content.move_to(Point::new(
+                0.0,
+                (size.height - reserved_height - content_size.height).max(0.0),
+            ));
+        }
 
         layout::Node::with_children(size, vec![content])
     }
@@ -191,73 +459,104 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(
-            bounds,
-            content_bounds,
-            offset,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
-        );
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        // Report any programmatic change of the offset
+        if self.state.last_notified_offset.is_some() {
+            self.notify_on_scroll(bounds, content_bounds, messages);
+        } else {
+            self.state.last_notified_offset =
+                Self::relative_offset(self.state, bounds, content_bounds);
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if !self.state.is_scroller_grabbed() {
+                    self.state.is_scrolling = false;
+                }
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        let (scrollbar, horizontal_scrollbar) =
+            self.scrollbars(renderer, bounds, content_bounds);
+        let is_mouse_over_scrollbar = is_over(scrollbar.as_ref(), cursor);
+        let is_mouse_over_horizontal_scrollbar =
+            is_over(horizontal_scrollbar.as_ref(), cursor);
 
         let event_status = {
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(
-                    cursor_position.x,
-                    cursor_position.y
-                        + self.state.offset(bounds, content_bounds) as f32,
-                )
-            } else {
-                // TODO: Make `cursor_position` an `Option<Point>` so we can encode
-                // cursor availability.
-                // This will probably happen naturally once we add multi-window
-                // support.
-                Point::new(cursor_position.x, -1.0)
-            };
+            let cursor = content_cursor(
+                cursor,
+                is_mouse_over
+                    && !is_mouse_over_scrollbar
+                    && !is_mouse_over_horizontal_scrollbar,
+                self.offset(bounds, content_bounds),
+            );
 
             self.content.on_event(
                 event.clone(),
                 content,
-                cursor_position,
+                cursor,
                 renderer,
                 clipboard,
                 messages,
             )
         };
 
-        if let event::Status::Captured = event_status {
-            return event::Status::Captured;
+        if event_status.is_captured() {
+            return event_status;
         }
 
         if is_mouse_over {
             match event {
+                // The horizontal movement of the wheel is handled when it
+                // arrives as a `HorizontalScrolled` event
                 Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
+                    let delta_y = match delta {
+                        // TODO: Configurable speed (?)
                         mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
+                            y * mouse::ScrollDelta::PIXELS_PER_LINE
                         }
-                    }
+                        mouse::ScrollDelta::Pixels { y, .. } => y,
+                    };
+
+                    // Holding shift turns vertical wheel input into
+                    // horizontal scrolling, and so does a horizontal-only
+                    // scrollable
+                    let (delta_x, delta_y) = if self.direction.is_horizontal()
+                        && (self.state.keyboard_modifiers.shift()
+                            || !self.direction.is_vertical())
+                    {
+                        (delta_y, 0.0)
+                    } else {
+                        (0.0, delta_y)
+                    };
+
+                    self.scroll(delta_x, delta_y, bounds, content_bounds);
+                    self.state.is_scrolling = true;
+                    self.notify_on_scroll(bounds, content_bounds, messages);
 
+                    return event::Status::Captured;
+                }
+                // A scrollable that cannot scroll horizontally leaves the
+                // movement to any scrollable around it
+                Event::Mouse(mouse::Event::HorizontalScrolled(delta_x))
+                    if self.direction.is_horizontal() =>
+                {
+                    self.scroll(delta_x, 0.0, bounds, content_bounds);
+                    self.state.is_scrolling = true;
                     self.notify_on_scroll(bounds, content_bounds, messages);
 
                     return event::Status::Captured;
@@ -266,23 +565,29 @@ where
                     match event {
                         touch::Event::FingerPressed { .. } => {
                             self.state.scroll_box_touched_at =
-                                Some(cursor_position);
+                                cursor.position();
                         }
                         touch::Event::FingerMoved { .. } => {
-                            if let Some(scroll_box_touched_at) =
-                                self.state.scroll_box_touched_at
-                            {
+                            if let (
+                                Some(scroll_box_touched_at),
+                                Some(cursor_position),
+                            ) = (
+                                self.state.scroll_box_touched_at,
+                                cursor.position(),
+                            ) {
                                 let delta =
-                                    cursor_position.y - scroll_box_touched_at.y;
+                                    cursor_position - scroll_box_touched_at;
 
-                                self.state.scroll(
-                                    delta,
+                                self.scroll(
+                                    delta.x,
+                                    delta.y,
                                     bounds,
                                     content_bounds,
                                 );
 
                                 self.state.scroll_box_touched_at =
                                     Some(cursor_position);
+                                self.state.is_scrolling = true;
 
                                 self.notify_on_scroll(
                                     bounds,
@@ -295,6 +600,9 @@ where
                         | touch::Event::FingerLost { .. } => {
                             self.state.scroll_box_touched_at = None;
                         }
+                        touch::Event::LongPress { .. }
+                        | touch::Event::Pinch { .. }
+                        | touch::Event::Rotate { .. } => {}
                     }
 
                     return event::Status::Captured;
@@ -311,22 +619,41 @@ where
                 | Event::Touch(touch::Event::FingerLifted { .. })
                 | Event::Touch(touch::Event::FingerLost { .. }) => {
                     self.state.scroller_grabbed_at = None;
+                    self.state.horizontal_scroller_grabbed_at = None;
 
                     return event::Status::Captured;
                 }
                 Event::Mouse(mouse::Event::CursorMoved { .. })
                 | Event::Touch(touch::Event::FingerMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
-                    {
-                        self.state.scroll_to(
-                            scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
-                                cursor_position,
-                            ),
-                            bounds,
-                            content_bounds,
-                        );
+                    if let Some(cursor_position) = cursor.position() {
+                        if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                            (scrollbar, self.state.scroller_grabbed_at)
+                        {
+                            self.state.scroll_to(
+                                scrollbar.scroll_percentage(
+                                    scroller_grabbed_at,
+                                    cursor_position,
+                                    Axis::Vertical,
+                                ),
+                                bounds,
+                                content_bounds,
+                            );
+                        }
+
+                        if let (Some(scrollbar), Some(scroller_grabbed_at)) = (
+                            horizontal_scrollbar,
+                            self.state.horizontal_scroller_grabbed_at,
+                        ) {
+                            self.state.scroll_horizontally_to(
+                                scrollbar.scroll_percentage(
+                                    scroller_grabbed_at,
+                                    cursor_position,
+                                    Axis::Horizontal,
+                                ),
+                                bounds,
+                                content_bounds,
+                            );
+                        }
 
                         self.notify_on_scroll(bounds, content_bounds, messages);
 
@@ -335,27 +662,59 @@ where
                 }
                 _ => {}
             }
-        } else if is_mouse_over_scrollbar {
+        } else if is_mouse_over_scrollbar || is_mouse_over_horizontal_scrollbar
+        {
             match event {
                 Event::Mouse(mouse::Event::ButtonPressed(
                     mouse::Button::Left,
                 ))
                 | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
+                    if let Some(cursor_position) = cursor.position() {
+                        if let Some(scrollbar) =
+                            scrollbar.filter(|_| is_mouse_over_scrollbar)
                         {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
+                            if let Some(scroller_grabbed_at) = scrollbar
+                                .grab_scroller(cursor_position, Axis::Vertical)
+                            {
+                                self.state.scroll_to(
+                                    scrollbar.scroll_percentage(
+                                        scroller_grabbed_at,
+                                        cursor_position,
+                                        Axis::Vertical,
+                                    ),
+                                    bounds,
+                                    content_bounds,
+                                );
+
+                                self.state.scroller_grabbed_at =
+                                    Some(scroller_grabbed_at);
+                            }
+                        } else if let Some(scrollbar) = horizontal_scrollbar
+                            .filter(|_| is_mouse_over_horizontal_scrollbar)
+                        {
+                            if let Some(scroller_grabbed_at) = scrollbar
+                                .grab_scroller(
                                     cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
+                                    Axis::Horizontal,
+                                )
+                            {
+                                self.state.scroll_horizontally_to(
+                                    scrollbar.scroll_percentage(
+                                        scroller_grabbed_at,
+                                        cursor_position,
+                                        Axis::Horizontal,
+                                    ),
+                                    bounds,
+                                    content_bounds,
+                                );
+
+                                self.state.horizontal_scroller_grabbed_at =
+                                    Some(scroller_grabbed_at);
+                            }
+                        }
 
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
+                        if self.state.is_scroller_grabbed() {
+                            self.state.is_scrolling = true;
 
                             self.notify_on_scroll(
                                 bounds,
@@ -379,42 +738,38 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(
-            bounds,
-            content_bounds,
-            offset,
-            self.scrollbar_width,
-            self.scrollbar_margin,
-            self.scroller_width,
-        );
+        let offset = self.offset(bounds, content_bounds);
+        let (scrollbar, horizontal_scrollbar) =
+            self.scrollbars(renderer, bounds, content_bounds);
 
-        let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let is_mouse_over = cursor.is_over(bounds);
+        let is_mouse_over_scrollbar = is_over(scrollbar.as_ref(), cursor);
+        let is_mouse_over_horizontal_scrollbar =
+            is_over(horizontal_scrollbar.as_ref(), cursor);
 
         let content = {
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
-            } else {
-                Point::new(cursor_position.x, -1.0)
-            };
+            let cursor = content_cursor(
+                cursor,
+                is_mouse_over
+                    && !is_mouse_over_scrollbar
+                    && !is_mouse_over_horizontal_scrollbar,
+                offset,
+            );
 
             self.content.draw(
                 renderer,
                 defaults,
                 content_layout,
-                cursor_position,
+                cursor,
                 &Rectangle {
-                    y: bounds.y + offset as f32,
+                    x: bounds.x + offset.x as f32,
+                    y: bounds.y + offset.y as f32,
                     ..bounds
                 },
             )
@@ -427,8 +782,11 @@ where
             content_layout.bounds(),
             is_mouse_over,
             is_mouse_over_scrollbar,
+            is_mouse_over_horizontal_scrollbar,
             scrollbar,
+            horizontal_scrollbar,
             offset,
+            self.scrollbar_visibility,
             &self.style,
             content,
         )
@@ -440,6 +798,12 @@ where
 
         self.height.hash(state);
         self.max_height.hash(state);
+        self.scrollbar_visibility.hash(state);
+        self.direction.hash(state);
+        self.state.anchor.hash(state);
+        self.scrollbar_width.hash(state);
+        self.scrollbar_margin.hash(state);
+        self.scroller_width.hash(state);
 
         self.content.hash_layout(state)
     }
@@ -456,9 +820,15 @@ where
                 let bounds = layout.bounds();
                 let content_layout = layout.children().next().unwrap();
                 let content_bounds = content_layout.bounds();
-                let offset = state.offset(bounds, content_bounds);
-
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
+                let offset = Vector::new(
+                    state.horizontal_offset(bounds, content_bounds),
+                    state.offset(bounds, content_bounds),
+                );
+
+                overlay.translate(Vector::new(
+                    -(offset.x as f32),
+                    -(offset.y as f32),
+                ))
             })
     }
 }
@@ -467,21 +837,33 @@ where
 #[derive(Debug, Clone, Copy)]
 pub struct State {
     scroller_grabbed_at: Option<f32>,
+    horizontal_scroller_grabbed_at: Option<f32>,
     scroll_box_touched_at: Option<Point>,
     offset: Offset,
+    horizontal_offset: Offset,
+    is_scrolling: bool,
+    last_notified_offset: Option<f32>,
+    keyboard_modifiers: keyboard::Modifiers,
+    anchor: Anchor,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             scroller_grabbed_at: None,
+            horizontal_scroller_grabbed_at: None,
             scroll_box_touched_at: None,
             offset: Offset::Absolute(0.0),
+            horizontal_offset: Offset::Absolute(0.0),
+            is_scrolling: false,
+            last_notified_offset: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+            anchor: Anchor::default(),
         }
     }
 }
 
-/// The local state of a [`Scrollable`].
+/// A scrolling offset along an axis, measured from the [`Anchor`].
 #[derive(Debug, Clone, Copy)]
 enum Offset {
     Absolute(f32),
@@ -489,16 +871,15 @@ enum Offset {
 }
 
 impl Offset {
-    fn absolute(self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+    fn absolute(self, viewport: f32, content: f32) -> f32 {
         match self {
             Self::Absolute(absolute) => {
-                let hidden_content =
-                    (content_bounds.height - bounds.height).max(0.0);
+                let hidden_content = (content - viewport).max(0.0);
 
                 absolute.min(hidden_content)
             }
             Self::Relative(percentage) => {
-                ((content_bounds.height - bounds.height) * percentage).max(0.0)
+                ((content - viewport) * percentage).max(0.0)
             }
         }
     }
@@ -522,10 +903,35 @@ impl State {
             return;
         }
 
-        self.offset = Offset::Absolute(
-            (self.offset.absolute(bounds, content_bounds) - delta_y)
+        let hidden_content = content_bounds.height - bounds.height;
+
+        let offset = (self.vertical_offset(bounds, content_bounds) - delta_y)
+            .max(0.0)
+            .min(hidden_content);
+
+        self.offset =
+            Offset::Absolute(self.anchor.flip(offset, hidden_content));
+    }
+
+    /// Apply a horizontal scrolling offset to the current [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
+    pub fn scroll_horizontally(
+        &mut self,
+        delta_x: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if bounds.width >= content_bounds.width {
+            return;
+        }
+
+        self.horizontal_offset = Offset::Absolute(
+            (self
+                .horizontal_offset
+                .absolute(bounds.width, content_bounds.width)
+                - delta_x)
                 .max(0.0)
-                .min((content_bounds.height - bounds.height) as f32),
+                .min(content_bounds.width - bounds.width),
         );
     }
 
@@ -543,36 +949,120 @@ impl State {
         self.unsnap(bounds, content_bounds);
     }
 
+    /// Scrolls the [`Scrollable`] horizontally to a relative amount.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar at
+    /// the right.
+    pub fn scroll_horizontally_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.snap_horizontally_to(percentage);
+        self.unsnap(bounds, content_bounds);
+    }
+
     /// Snaps the scroll position to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
     pub fn snap_to(&mut self, percentage: f32) {
-        self.offset = Offset::Relative(percentage.max(0.0).min(1.0));
+        let percentage = percentage.max(0.0).min(1.0);
+
+        self.offset = Offset::Relative(self.anchor.flip(percentage, 1.0));
     }
 
-    /// Unsnaps the current scroll position, if snapped, given the bounds of the
-    /// [`Scrollable`] and its contents.
+    /// Snaps the horizontal scroll position to a relative amount.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar at
+    /// the right.
+    pub fn snap_horizontally_to(&mut self, percentage: f32) {
+        self.horizontal_offset = Offset::Relative(percentage.max(0.0).min(1.0));
+    }
+
+    /// Unsnaps the current scroll position of both axes, if snapped, given
+    /// the bounds of the [`Scrollable`] and its contents.
     pub fn unsnap(&mut self, bounds: Rectangle, content_bounds: Rectangle) {
-        self.offset =
-            Offset::Absolute(self.offset.absolute(bounds, content_bounds));
+        self.offset = Offset::Absolute(
+            self.offset.absolute(bounds.height, content_bounds.height),
+        );
+
+        self.horizontal_offset = Offset::Absolute(
+            self.horizontal_offset
+                .absolute(bounds.width, content_bounds.width),
+        );
     }
 
-    /// Returns the current scrolling offset of the [`State`], given the bounds
-    /// of the [`Scrollable`] and its contents.
+    /// Returns the current vertical scrolling offset of the [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
     pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        self.offset.absolute(bounds, content_bounds) as u32
+        self.vertical_offset(bounds, content_bounds) as u32
+    }
+
+    /// Returns the current horizontal scrolling offset of the [`State`],
+    /// given the bounds of the [`Scrollable`] and its contents.
+    pub fn horizontal_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> u32 {
+        self.horizontal_offset
+            .absolute(bounds.width, content_bounds.width) as u32
+    }
+
+    /// Returns the [`Anchor`] of the vertical offset of the [`State`].
+    pub fn anchor(&self) -> Anchor {
+        self.anchor
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns whether any scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
+        self.is_vertical_scroller_grabbed()
+            || self.is_horizontal_scroller_grabbed()
+    }
+
+    /// Returns whether the vertical scroller is currently grabbed or not.
+    pub fn is_vertical_scroller_grabbed(&self) -> bool {
         self.scroller_grabbed_at.is_some()
     }
 
+    /// Returns whether the horizontal scroller is currently grabbed or not.
+    pub fn is_horizontal_scroller_grabbed(&self) -> bool {
+        self.horizontal_scroller_grabbed_at.is_some()
+    }
+
     /// Returns whether the scroll box is currently touched or not.
     pub fn is_scroll_box_touched(&self) -> bool {
         self.scroll_box_touched_at.is_some()
     }
+
+    /// Returns whether the [`Scrollable`] has been scrolled since the cursor
+    /// last moved without scrolling.
+    pub fn is_scrolling(&self) -> bool {
+        self.is_scrolling
+    }
+
+    fn set_anchor(&mut self, anchor: Anchor) {
+        if self.anchor != anchor {
+            self.anchor = anchor;
+            self.offset = Offset::Absolute(0.0);
+        }
+    }
+
+    /// Returns the vertical offset from the top of the content.
+    fn vertical_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> f32 {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+
+        self.anchor.flip(
+            self.offset.absolute(bounds.height, content_bounds.height),
+            hidden_content,
+        )
+    }
 }
 
 /// The scrollbar of a [`Scrollable`].
@@ -593,15 +1083,13 @@ pub struct Scrollbar {
 }
 
 impl Scrollbar {
-    fn is_mouse_over(&self, cursor_position: Point) -> bool {
-        self.outer_bounds.contains(cursor_position)
-    }
-
-    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
+    fn grab_scroller(&self, cursor_position: Point, axis: Axis) -> Option<f32> {
         if self.outer_bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+                let (position, start, length) =
+                    axis.project(cursor_position, self.scroller.bounds);
+
+                (position - start) / length
             } else {
                 0.5
             })
@@ -614,11 +1102,57 @@ impl Scrollbar {
         &self,
         grabbed_at: f32,
         cursor_position: Point,
+        axis: Axis,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        let (position, start, length) =
+            axis.project(cursor_position, self.bounds);
+        let (_, _, scroller_length) =
+            axis.project(cursor_position, self.scroller.bounds);
+
+        (position - start - scroller_length * grabbed_at)
+            / (length - scroller_length)
+    }
+}
+
+/// The axis along which a [`Scrollbar`] moves its [`Scroller`].
+#[derive(Debug, Clone, Copy)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+impl Axis {
+    /// Returns the coordinate of the point, the start and the length of the
+    /// bounds along the [`Axis`].
+    fn project(self, point: Point, bounds: Rectangle) -> (f32, f32, f32) {
+        match self {
+            Axis::Vertical => (point.y, bounds.y, bounds.height),
+            Axis::Horizontal => (point.x, bounds.x, bounds.width),
+        }
+    }
+}
+
+fn is_over(scrollbar: Option<&Scrollbar>, cursor: mouse::Cursor) -> bool {
+    scrollbar
+        .map(|scrollbar| cursor.is_over(scrollbar.outer_bounds))
+        .unwrap_or(false)
+}
+
+/// Returns the cursor of the content of a [`Scrollable`], translated by the
+/// scrolling offset.
+fn content_cursor(
+    cursor: mouse::Cursor,
+    is_over_content: bool,
+    offset: Vector<u32>,
+) -> mouse::Cursor {
+    match cursor.position() {
+        Some(position) if is_over_content => {
+            mouse::Cursor::Available(Point::new(
+                position.x + offset.x as f32,
+                position.y + offset.y as f32,
+            ))
+        }
+        _ => mouse::Cursor::Unavailable,
     }
 }
 
@@ -639,8 +1173,8 @@ pub trait Renderer: column::Renderer + Sized {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the vertical [`Scrollbar`] given the bounds and content bounds
+    /// of a [`Scrollable`].
     fn scrollbar(
         &self,
         bounds: Rectangle,
@@ -649,6 +1183,20 @@ pub trait Renderer: column::Renderer + Sized {
         scrollbar_width: u16,
         scrollbar_margin: u16,
         scroller_width: u16,
+        style: &Self::Style,
+    ) -> Option<Scrollbar>;
+
+    /// Returns the horizontal [`Scrollbar`] given the bounds and content
+    /// bounds of a [`Scrollable`].
+    fn horizontal_scrollbar(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        offset: u32,
+        scrollbar_width: u16,
+        scrollbar_margin: u16,
+        scroller_width: u16,
+        style: &Self::Style,
     ) -> Option<Scrollbar>;
 
     /// Draws the [`Scrollable`].
@@ -658,9 +1206,12 @@ pub trait Renderer: column::Renderer + Sized {
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
-    /// - the scrolling offset
+    /// - whether the mouse is over the vertical [`Scrollbar`] or not
+    /// - whether the mouse is over the horizontal [`Scrollbar`] or not
+    /// - an optional vertical [`Scrollbar`] to be rendered
+    /// - an optional horizontal [`Scrollbar`] to be rendered
+    /// - the scrolling offset of both axes
+    /// - the [`ScrollbarVisibility`]
     /// - the drawn content
     fn draw(
         &mut self,
@@ -669,8 +1220,11 @@ pub trait Renderer: column::Renderer + Sized {
         content_bounds: Rectangle,
         is_mouse_over: bool,
         is_mouse_over_scrollbar: bool,
+        is_mouse_over_horizontal_scrollbar: bool,
         scrollbar: Option<Scrollbar>,
-        offset: u32,
+        horizontal_scrollbar: Option<Scrollbar>,
+        offset: Vector<u32>,
+        scrollbar_visibility: ScrollbarVisibility,
         style: &Self::Style,
         content: Self::Output,
     ) -> Self::Output;
@@ -688,3 +1242,278 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{clipboard, Space, Text};
+
+    fn scrollable(state: &mut State) -> Scrollable<'_, f32, Null> {
+        (0..10)
+            .fold(Scrollable::new(state), |scrollable, i| {
+                scrollable.push(Text::new(format!("Item {}", i)))
+            })
+            .width(Length::Fill)
+            .height(Length::Units(50))
+            .on_scroll(|offset| offset)
+    }
+
+    #[test]
+    fn on_scroll_reports_every_change_once() {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+        let position = Point::new(100.0, 25.0);
+
+        let mut process = |state: &mut State, event| {
+            let mut widget = scrollable(state);
+            let node = widget.layout(&renderer, &limits);
+
+            let _ = widget.on_event(
+                event,
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &renderer,
+                &mut clipboard,
+                &mut messages,
+            );
+        };
+
+        let wheel = || {
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            })
+        };
+        let cursor_moved =
+            || Event::Mouse(mouse::Event::CursorMoved { position });
+
+        process(&mut state, cursor_moved());
+        process(&mut state, wheel());
+        process(&mut state, cursor_moved());
+
+        state.snap_to(1.0);
+        process(&mut state, cursor_moved());
+        process(&mut state, wheel());
+
+        // Ten items of 20 units in 50 units: 150 hidden units
+        assert_eq!(messages, vec![60.0 / 150.0, 1.0]);
+    }
+
+    #[test]
+    fn an_always_visible_scrollbar_reserves_its_width() {
+        let renderer = Null::new();
+        let mut state = State::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+
+        let overlaid = scrollable(&mut state).layout(&renderer, &limits);
+
+        let reserved = scrollable(&mut state)
+            .scrollbar_visibility(ScrollbarVisibility::Always)
+            .scrollbar_margin(2)
+            .layout(&renderer, &limits);
+
+        assert_eq!(overlaid.size().width, 200.0);
+        assert_eq!(overlaid.children()[0].size().width, 200.0);
+
+        assert_eq!(reserved.size().width, 200.0);
+        assert_eq!(reserved.children()[0].size().width, 186.0);
+    }
+
+    #[test]
+    fn an_end_anchor_keeps_growing_content_pinned_to_the_bottom() {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+
+        // Items of 20 units in 50 units
+        let mut layout = |state: &mut State, items: u16, delta| {
+            let mut widget: Scrollable<'_, f32, Null> = Scrollable::new(state)
+                .anchor(Anchor::End)
+                .height(Length::Units(50))
+                .push(Space::new(Length::Fill, Length::Units(items * 20)));
+
+            let node = widget.layout(&renderer, &limits);
+
+            if let Some(delta) = delta {
+                let _ = widget.on_event(
+                    Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                    Layout::new(&node),
+                    mouse::Cursor::Available(Point::new(100.0, 25.0)),
+                    &renderer,
+                    &mut clipboard,
+                    &mut messages,
+                );
+            }
+
+            (node.bounds(), node.children()[0].bounds())
+        };
+
+        // Short content sits at the bottom
+        let (_, content_bounds) = layout(&mut state, 1, None);
+        assert_eq!(content_bounds.y, 30.0);
+
+        let (bounds, content_bounds) = layout(&mut state, 10, None);
+        assert_eq!(content_bounds.y, 0.0);
+        assert_eq!(state.offset(bounds, content_bounds), 150);
+
+        let (bounds, content_bounds) = layout(&mut state, 20, None);
+        assert_eq!(state.offset(bounds, content_bounds), 350);
+
+        // Scrolling up stops the pinning
+        let _ = layout(
+            &mut state,
+            20,
+            Some(mouse::ScrollDelta::Pixels { x: 0.0, y: 100.0 }),
+        );
+
+        let (bounds, content_bounds) = layout(&mut state, 25, None);
+        assert_eq!(state.offset(bounds, content_bounds), 350);
+
+        // Until the bottom is reached again
+        let _ = layout(
+            &mut state,
+            25,
+            Some(mouse::ScrollDelta::Pixels { x: 0.0, y: -200.0 }),
+        );
+
+        let (bounds, content_bounds) = layout(&mut state, 30, None);
+        assert_eq!(state.offset(bounds, content_bounds), 550);
+    }
+
+    #[test]
+    fn horizontal_wheel_input_offsets_content_horizontally() {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+        let mut state = State::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+        let position = Point::new(100.0, 10.0);
+
+        // Wheel input with horizontal movement, as produced by a shell
+        let wheel = |x, y| {
+            let delta = mouse::ScrollDelta::Lines { x, y };
+            let wheel_scrolled = mouse::Event::WheelScrolled { delta };
+
+            if x == 0.0 {
+                vec![wheel_scrolled]
+            } else {
+                vec![
+                    wheel_scrolled,
+                    mouse::Event::HorizontalScrolled(
+                        x * mouse::ScrollDelta::PIXELS_PER_LINE,
+                    ),
+                ]
+            }
+        };
+
+        let mut scroll = |state: &mut State, events: Vec<mouse::Event>| {
+            let mut widget: Scrollable<'_, f32, Null> = Scrollable::new(state)
+                .direction(Direction::Horizontal)
+                .push(Space::new(Length::Units(400), Length::Units(20)));
+
+            let node = widget.layout(&renderer, &limits);
+            let bounds = node.bounds();
+            let content_bounds = node.children()[0].bounds();
+
+            for event in events {
+                let _ = widget.on_event(
+                    Event::Mouse(event),
+                    Layout::new(&node),
+                    mouse::Cursor::Available(position),
+                    &renderer,
+                    &mut clipboard,
+                    &mut messages,
+                );
+            }
+
+            (bounds, content_bounds)
+        };
+
+        let (bounds, content_bounds) = scroll(&mut state, wheel(-1.0, 0.0));
+
+        assert_eq!(bounds.width, 200.0);
+        assert_eq!(content_bounds.width, 400.0);
+        assert_eq!(state.horizontal_offset(bounds, content_bounds), 60);
+        assert_eq!(state.offset(bounds, content_bounds), 0);
+
+        // Vertical wheel input scrolls a horizontal-only scrollable too
+        let _ = scroll(&mut state, wheel(0.0, -1.0));
+
+        assert_eq!(state.horizontal_offset(bounds, content_bounds), 120);
+
+        for _ in 0..5 {
+            let _ = scroll(&mut state, wheel(-1.0, 0.0));
+        }
+
+        assert_eq!(
+            state.horizontal_offset(bounds, content_bounds),
+            (content_bounds.width - bounds.width) as u32
+        );
+    }
+
+    #[test]
+    fn horizontal_scrolls_are_left_to_horizontal_scrollables() {
+        let renderer = Null::new();
+        let mut clipboard = clipboard::Null;
+        let mut messages = Vec::new();
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(200.0, 400.0));
+
+        let mut scroll = |state: &mut State, direction| {
+            let (status, bounds, content_bounds) = {
+                let mut widget: Scrollable<'_, f32, Null> =
+                    Scrollable::new(state)
+                        .direction(direction)
+                        .height(Length::Units(50))
+                        .push(Space::new(
+                            Length::Units(400),
+                            Length::Units(100),
+                        ));
+
+                let node = widget.layout(&renderer, &limits);
+
+                let status = widget.on_event(
+                    Event::Mouse(mouse::Event::HorizontalScrolled(-30.0)),
+                    Layout::new(&node),
+                    mouse::Cursor::Available(Point::new(100.0, 25.0)),
+                    &renderer,
+                    &mut clipboard,
+                    &mut messages,
+                );
+
+                (status, node.bounds(), node.children()[0].bounds())
+            };
+
+            (
+                status,
+                state.horizontal_offset(bounds, content_bounds),
+                state.offset(bounds, content_bounds),
+            )
+        };
+
+        let mut state = State::new();
+
+        assert_eq!(
+            scroll(&mut state, Direction::Vertical),
+            (event::Status::Ignored, 0, 0)
+        );
+
+        for direction in [Direction::Horizontal, Direction::Both] {
+            let mut state = State::new();
+
+            assert_eq!(
+                scroll(&mut state, direction),
+                (event::Status::Captured, 30, 0)
+            );
+        }
+    }
+}
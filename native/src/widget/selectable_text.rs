@@ -0,0 +1,430 @@
+//! Display text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::text;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+use std::ops::Range;
+
+/// A paragraph of text that can be selected with the mouse and copied to the
+/// [`Clipboard`].
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{renderer::Null, selectable_text};
+/// #
+/// # pub type SelectableText<'a> =
+/// #     iced_native::SelectableText<'a, Null>;
+/// let mut state = selectable_text::State::new();
+///
+/// SelectableText::new(&mut state, "Copy me!").size(20);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    selection_color: Color,
+    font: Renderer::Font,
+    width: Length,
+    height: Length,
+}
+
+impl<'a, Renderer: self::Renderer> SelectableText<'a, Renderer> {
+    /// Creates a new [`SelectableText`] with some local [`State`] and the
+    /// given contents.
+    pub fn new(state: &'a mut State, content: impl Into<String>) -> Self {
+        SelectableText {
+            state,
+            content: content.into(),
+            size: None,
+            color: None,
+            selection_color: Color::from_rgb(0.8, 0.8, 1.0),
+            font: Default::default(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Color`] of the highlight behind the selected text.
+    pub fn selection_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.selection_color = color.into();
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`Font`]: Renderer::Font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Returns the character offset of the given point of the
+    /// [`SelectableText`], or `None` if there is no text under it.
+    fn hit(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        position: Point,
+    ) -> Option<usize> {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        renderer
+            .hit_test(
+                &self.content,
+                f32::from(size),
+                self.font,
+                Size::new(bounds.width, bounds.height),
+                Point::new(position.x - bounds.x, position.y - bounds.y),
+                true,
+            )
+            .map(text::Hit::cursor)
+    }
+}
+
+/// The local state of a [`SelectableText`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    selection: Option<(usize, usize)>,
+    is_dragging: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], with nothing selected.
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the character range currently selected, if any.
+    ///
+    /// The range is empty when the [`SelectableText`] was clicked without
+    /// dragging. It still receives the keyboard shortcuts in that case.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.selection
+            .map(|(anchor, head)| anchor.min(head)..anchor.max(head))
+    }
+
+    /// Clears the selection of the [`SelectableText`].
+    pub fn clear(&mut self) {
+        self.selection = None;
+        self.is_dragging = false;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for SelectableText<'a, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let (width, height) =
+            renderer.measure(&self.content, size, self.font, limits.max());
+
+        layout::Node::new(limits.resolve(Size::new(width, height)))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                if let Some(position) = cursor.position_over(bounds) {
+                    let offset =
+                        self.hit(renderer, bounds, position).unwrap_or(0);
+
+                    self.state.selection = Some((offset, offset));
+                    self.state.is_dragging = true;
+
+                    return event::Status::Captured;
+                }
+
+                self.state.clear();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+                if self.state.is_dragging =>
+            {
+                if let (Some((anchor, _)), Some(offset)) =
+                    (self.state.selection, self.hit(renderer, bounds, position))
+                {
+                    self.state.selection = Some((anchor, offset));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.is_dragging =>
+            {
+                self.state.is_dragging = false;
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(pressed @ keyboard::Event::KeyPressed { .. })
+                if self.state.selection.is_some() =>
+            {
+                if keyboard::Shortcut::SELECT_ALL.matches(&pressed) {
+                    self.state.selection =
+                        Some((0, self.content.chars().count()));
+
+                    return event::Status::Captured;
+                }
+
+                if keyboard::Shortcut::COPY.matches(&pressed) {
+                    if let Some(range) = self.state.selection() {
+                        if !range.is_empty() {
+                            clipboard.write(
+                                self.content
+                                    .chars()
+                                    .skip(range.start)
+                                    .take(range.len())
+                                    .collect(),
+                            );
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            &self.content,
+            self.size.unwrap_or(renderer.default_size()),
+            self.font,
+            self.color,
+            self.state.selection().filter(|range| !range.is_empty()),
+            self.selection_color,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash(state);
+        self.size.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`SelectableText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SelectableText`] in your user interface.
+///
+/// [renderer]: crate::Renderer
+pub trait Renderer: text::Renderer {
+    /// Draws a [`SelectableText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`SelectableText`]
+    ///   * the contents of the [`SelectableText`]
+    ///   * the size of the [`SelectableText`]
+    ///   * the color of the [`SelectableText`]
+    ///   * the range of selected characters, if any
+    ///   * the color of the highlight behind the selected characters
+    fn draw(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        color: Option<Color>,
+        selection: Option<Range<usize>>,
+        selection_color: Color,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        selectable_text: SelectableText<'a, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    #[derive(Default)]
+    struct Mock {
+        contents: Option<String>,
+    }
+
+    impl Clipboard for Mock {
+        fn read(&self) -> Option<String> {
+            self.contents.clone()
+        }
+
+        fn write(&mut self, contents: String) {
+            self.contents = Some(contents);
+        }
+    }
+
+    fn press(
+        text: &mut SelectableText<'_, Null>,
+        node: &layout::Node,
+        clipboard: &mut Mock,
+        event: Event,
+    ) -> event::Status<()> {
+        Widget::<(), Null>::on_event(
+            text,
+            event,
+            Layout::new(node),
+            mouse::Cursor::Available(Point::new(5.0, 5.0)),
+            &Null,
+            clipboard,
+            &mut Vec::new(),
+        )
+    }
+
+    fn shortcut(shortcut: keyboard::Shortcut) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: shortcut.key,
+            physical_key: keyboard::ScanCode(0),
+            location: keyboard::KeyLocation::Standard,
+            modifiers: shortcut.modifiers,
+            repeat: false,
+        })
+    }
+
+    #[test]
+    fn selecting_all_and_copying_writes_the_whole_content() {
+        let mut state = State::new();
+        let mut text = SelectableText::new(&mut state, "Héllo, world!")
+            .width(Length::Units(200));
+
+        let node = Widget::<(), Null>::layout(
+            &text,
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+
+        let mut clipboard = Mock::default();
+
+        for event in [
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+            shortcut(keyboard::Shortcut::SELECT_ALL),
+        ] {
+            assert_eq!(
+                press(&mut text, &node, &mut clipboard, event),
+                event::Status::Captured
+            );
+        }
+
+        assert_eq!(text.state.selection(), Some(0..13));
+
+        let _ = press(
+            &mut text,
+            &node,
+            &mut clipboard,
+            shortcut(keyboard::Shortcut::COPY),
+        );
+
+        assert_eq!(clipboard.read().as_deref(), Some("Héllo, world!"));
+    }
+
+    #[test]
+    fn shortcuts_are_ignored_until_the_text_is_clicked() {
+        let mut state = State::new();
+        let mut text = SelectableText::new(&mut state, "Hello");
+
+        let node = layout::Node::new(Size::new(100.0, 20.0));
+        let mut clipboard = Mock::default();
+
+        assert_eq!(
+            press(
+                &mut text,
+                &node,
+                &mut clipboard,
+                shortcut(keyboard::Shortcut::SELECT_ALL)
+            ),
+            event::Status::Ignored
+        );
+
+        assert_eq!(text.state.selection(), None);
+        assert_eq!(clipboard.read(), None);
+    }
+}
@@ -6,7 +6,8 @@ use crate::layout;
 use crate::mouse;
 use crate::touch;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    Clipboard, Element, Hasher, Layout, Length, Numeric, Point, Rectangle,
+    Size, Widget,
 };
 
 use std::{hash::Hash, ops::RangeInclusive};
@@ -17,7 +18,8 @@ use std::{hash::Hash, ops::RangeInclusive};
 /// A [`Slider`] will try to fill the horizontal space of its container.
 ///
 /// The [`Slider`] range of numeric values is generic and its step size defaults
-/// to 1 unit.
+/// to 1 unit. Integer sliders always produce exact values, even for ranges too
+/// large for an `f32`.
 ///
 /// # Example
 /// ```
@@ -51,7 +53,7 @@ pub struct Slider<'a, T, Message, Renderer: self::Renderer> {
 
 impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
 where
-    T: Copy + From<u8> + std::cmp::PartialOrd,
+    T: Numeric,
     Message: Clone,
     Renderer: self::Renderer,
 {
@@ -89,7 +91,7 @@ where
             state,
             value,
             range,
-            step: T::from(1),
+            step: T::ONE,
             on_change: Box::new(on_change),
             on_release: None,
             width: Length::Fill,
@@ -150,7 +152,7 @@ impl State {
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for Slider<'a, T, Message, Renderer>
 where
-    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    T: Numeric,
     Message: Clone,
     Renderer: self::Renderer,
 {
@@ -179,39 +181,41 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
-        let mut change = || {
+    ) -> event::Status<Message> {
+        let mut change = |cursor_position: Point| {
             let bounds = layout.bounds();
             if cursor_position.x <= bounds.x {
                 messages.push((self.on_change)(*self.range.start()));
             } else if cursor_position.x >= bounds.x + bounds.width {
                 messages.push((self.on_change)(*self.range.end()));
             } else {
-                let step = self.step.into();
-                let start = (*self.range.start()).into();
-                let end = (*self.range.end()).into();
+                let step = self.step.to_f64();
+                let start = self.range.start().to_f64();
+                let end = self.range.end().to_f64();
 
                 let percent = f64::from(cursor_position.x - bounds.x)
                     / f64::from(bounds.width);
 
                 let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
 
-                if let Some(value) = T::from_f64(value) {
-                    messages.push((self.on_change)(value));
-                }
+                // The last step may overshoot an end that is not a multiple
+                let value = (steps * step + start).min(end);
+
+                messages.push((self.on_change)(T::from_f64(value)));
             }
         };
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                if layout.bounds().contains(cursor_position) {
-                    change();
+                if let Some(cursor_position) =
+                    cursor.position_over(layout.bounds())
+                {
+                    change(cursor_position);
                     self.state.is_dragging = true;
 
                     return event::Status::Captured;
@@ -232,7 +236,9 @@ where
             Event::Mouse(mouse::Event::CursorMoved { .. })
             | Event::Touch(touch::Event::FingerMoved { .. }) => {
                 if self.state.is_dragging {
-                    change();
+                    if let Some(cursor_position) = cursor.position() {
+                        change(cursor_position);
+                    }
 
                     return event::Status::Captured;
                 }
@@ -248,17 +254,18 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        let start = *self.range.start();
-        let end = *self.range.end();
+        let start = self.range.start().to_f64();
+        let end = self.range.end().to_f64();
 
+        // Values are relative to the start, so large ones keep their precision
         renderer.draw(
             layout.bounds(),
-            cursor_position,
-            start.into() as f32..=end.into() as f32,
-            self.value.into() as f32,
+            cursor,
+            0.0..=(end - start) as f32,
+            (self.value.to_f64() - start) as f32,
             self.state.is_dragging,
             &self.style,
         )
@@ -288,15 +295,16 @@ pub trait Renderer: crate::Renderer {
     /// Draws a [`Slider`].
     ///
     /// It receives:
-    ///   * the current cursor position
+    ///   * the current [`mouse::Cursor`]
     ///   * the bounds of the [`Slider`]
     ///   * the local state of the [`Slider`]
-    ///   * the range of values of the [`Slider`]
-    ///   * the current value of the [`Slider`]
+    ///   * the range of values of the [`Slider`], relative to its start
+    ///   * the current value of the [`Slider`], relative to the start of its
+    ///     range
     fn draw(
         &mut self,
         bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         range: RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
@@ -307,7 +315,7 @@ pub trait Renderer: crate::Renderer {
 impl<'a, T, Message, Renderer> From<Slider<'a, T, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
-    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    T: 'a + Numeric,
     Message: 'a + Clone,
     Renderer: 'a + self::Renderer,
 {
@@ -1,9 +1,8 @@
 //! Distribute content vertically.
+use crate::mouse;
 use std::hash::Hash;
 
-use crate::{
-    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
-};
+use crate::{layout, Element, Hasher, Layout, Length, Rectangle, Size, Widget};
 
 /// An amount of empty space.
 ///
@@ -64,7 +63,7 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
         renderer.draw(layout.bounds())
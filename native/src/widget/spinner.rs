@@ -0,0 +1,128 @@
+//! Show that something is in progress with an animated spinner.
+use crate::layout;
+use crate::mouse;
+use crate::{Element, Hasher, Layout, Length, Rectangle, Size, Widget};
+
+use std::hash::Hash;
+
+/// A ring that keeps turning while something is in progress.
+///
+/// A [`Spinner`] requests a redraw every time it is drawn, so it keeps turning
+/// on its own while it is shown. Its rotation is derived from the animation
+/// clock of the renderer.
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// #
+/// # pub type Spinner = iced_native::Spinner<Null>;
+/// Spinner::new().size(32);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Spinner<Renderer: self::Renderer> {
+    size: Option<u16>,
+    style: Renderer::Style,
+}
+
+impl<Renderer: self::Renderer> Spinner<Renderer> {
+    /// Creates a new [`Spinner`].
+    pub fn new() -> Self {
+        Spinner {
+            size: None,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the size of the [`Spinner`], which is both its width and its
+    /// height.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`Spinner`].
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn length(&self) -> Length {
+        Length::Units(self.size.unwrap_or(Renderer::DEFAULT_SIZE))
+    }
+}
+
+impl<Renderer: self::Renderer> Default for Spinner<Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Spinner<Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.length()
+    }
+
+    fn height(&self) -> Length {
+        self.length()
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.length()).height(self.length());
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.request_redraw();
+        renderer.draw(layout.bounds(), &self.style)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+/// The renderer of a [`Spinner`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Spinner`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// The default size of a [`Spinner`].
+    const DEFAULT_SIZE: u16;
+
+    /// Draws a [`Spinner`] in the given bounds, rotated according to the
+    /// current time.
+    fn draw(&mut self, bounds: Rectangle, style: &Self::Style) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Spinner<Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+{
+    fn from(spinner: Spinner<Renderer>) -> Element<'a, Message, Renderer> {
+        Element::new(spinner)
+    }
+}
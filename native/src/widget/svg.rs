@@ -1,6 +1,10 @@
 //! Display vector graphics in your application.
+use crate::image;
 use crate::layout;
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::mouse;
+use crate::{
+    ContentFit, Element, Hasher, Layout, Length, Rectangle, Size, Widget,
+};
 
 use std::{
     hash::{Hash, Hasher as _},
@@ -14,11 +18,14 @@ use std::{
 ///
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
+///
+/// The way the image fits its bounds can be changed with a [`ContentFit`].
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
 }
 
 impl Svg {
@@ -28,6 +35,7 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
         }
     }
 
@@ -48,6 +56,14 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Svg`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -69,22 +85,13 @@ where
     ) -> layout::Node {
         let (width, height) = renderer.dimensions(&self.handle);
 
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        layout::Node::new(image::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        ))
     }
 
     fn draw(
@@ -92,10 +99,20 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        renderer.draw(
+            self.handle.clone(),
+            image::content_bounds(
+                layout.bounds(),
+                Size::new(width as f32, height as f32),
+                self.content_fit,
+            ),
+            layout,
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -104,6 +121,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
@@ -112,6 +130,7 @@ where
 pub struct Handle {
     id: u64,
     data: Arc<Data>,
+    quality: Quality,
 }
 
 impl Handle {
@@ -137,9 +156,16 @@ impl Handle {
         Handle {
             id: hasher.finish(),
             data: Arc::new(data),
+            quality: Quality::default(),
         }
     }
 
+    /// Sets the rasterization [`Quality`] of the [`Handle`].
+    pub fn with_raster_quality(mut self, quality: Quality) -> Handle {
+        self.quality = quality;
+        self
+    }
+
     /// Returns the unique identifier of the [`Handle`].
     pub fn id(&self) -> u64 {
         self.id
@@ -149,6 +175,37 @@ impl Handle {
     pub fn data(&self) -> &Data {
         &self.data
     }
+
+    /// Returns the rasterization [`Quality`] of the [`Handle`].
+    pub fn raster_quality(&self) -> Quality {
+        self.quality
+    }
+}
+
+/// The rasterization quality of the vector image of a [`Handle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quality {
+    /// The image is parsed and rasterized in the background, at the nearest
+    /// larger size of a set of sizes growing geometrically.
+    ///
+    /// The rasterizations are reused while the image is resized, so zooming
+    /// never blocks and zooming out always stays crisp. Until the right size
+    /// is ready, the nearest available size is scaled instead.
+    ///
+    /// This is the default, and it works best for large and detailed images.
+    Balanced,
+
+    /// The image is rasterized right away, at the exact size it is drawn at.
+    ///
+    /// This works best for small images, like icons, which must be crisp from
+    /// the first frame they appear in.
+    Exact,
+}
+
+impl Default for Quality {
+    fn default() -> Quality {
+        Quality::Balanced
+    }
 }
 
 impl Hash for Handle {
@@ -188,8 +245,14 @@ pub trait Renderer: crate::Renderer {
     /// Returns the default dimensions of an [`Svg`] for the given [`Handle`].
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
-    /// Draws an [`Svg`].
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    /// Draws an [`Svg`] in the given `bounds`, clipped to the bounds of the
+    /// given [`Layout`].
+    fn draw(
+        &mut self,
+        handle: Handle,
+        bounds: Rectangle,
+        layout: Layout<'_>,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
@@ -1,8 +1,10 @@
 //! Write some text for your users to read.
 use crate::alignment;
 use crate::layout;
+use crate::mouse;
 use crate::{
-    Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, TextShadow,
+    Widget, WritingMode,
 };
 
 pub use iced_core::text::Hit;
@@ -32,6 +34,9 @@ pub struct Text<Renderer: self::Renderer> {
     height: Length,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    shadow: Option<TextShadow>,
+    letter_spacing: f32,
+    writing_mode: WritingMode,
 }
 
 impl<Renderer: self::Renderer> Text<Renderer> {
@@ -46,6 +51,9 @@ impl<Renderer: self::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            shadow: None,
+            letter_spacing: 0.0,
+            writing_mode: WritingMode::Horizontal,
         }
     }
 
@@ -98,6 +106,34 @@ impl<Renderer: self::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`TextShadow`] of the [`Text`].
+    ///
+    /// The shadow is only drawn, so it does not change the layout of the
+    /// [`Text`].
+    pub fn shadow(mut self, shadow: TextShadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Sets the extra space between every two consecutive characters of the
+    /// [`Text`], also known as tracking.
+    ///
+    /// Negative values bring the characters closer together.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the [`WritingMode`] of the [`Text`].
+    ///
+    /// With [`WritingMode::VerticalRL`], the characters of every line are
+    /// stacked from top to bottom, and lines are placed from right to left.
+    /// Lines are broken when they exceed the height of the [`Text`].
+    pub fn writing_mode(mut self, writing_mode: WritingMode) -> Self {
+        self.writing_mode = writing_mode;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -123,8 +159,14 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        let (width, height) = renderer.measure_with_writing_mode(
+            &self.content,
+            size,
+            self.font,
+            bounds,
+            self.letter_spacing,
+            self.writing_mode,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
@@ -136,10 +178,10 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        _cursor_position: Point,
+        _cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(
+        renderer.draw_with_shadow(
             defaults,
             layout.bounds(),
             &self.content,
@@ -148,6 +190,9 @@ where
             self.color,
             self.horizontal_alignment,
             self.vertical_alignment,
+            self.shadow,
+            self.letter_spacing,
+            self.writing_mode,
         )
     }
 
@@ -159,6 +204,8 @@ where
         self.size.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.writing_mode.hash(state);
     }
 }
 
@@ -185,6 +232,43 @@ pub trait Renderer: crate::Renderer {
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Measures the [`Text`] like [`Renderer::measure`], adding the given
+    /// spacing between every two consecutive characters.
+    ///
+    /// By default, the letter spacing is ignored.
+    fn measure_with_letter_spacing(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        bounds: Size,
+        _letter_spacing: f32,
+    ) -> (f32, f32) {
+        self.measure(content, size, font, bounds)
+    }
+
+    /// Measures the [`Text`] like [`Renderer::measure_with_letter_spacing`],
+    /// laying out its lines with the given [`WritingMode`].
+    ///
+    /// By default, the writing mode is ignored.
+    fn measure_with_writing_mode(
+        &self,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        bounds: Size,
+        letter_spacing: f32,
+        _writing_mode: WritingMode,
+    ) -> (f32, f32) {
+        self.measure_with_letter_spacing(
+            content,
+            size,
+            font,
+            bounds,
+            letter_spacing,
+        )
+    }
+
     /// Tests whether the provided point is within the boundaries of [`Text`]
     /// laid out with the given parameters, returning information about
     /// the nearest character.
@@ -202,6 +286,20 @@ pub trait Renderer: crate::Renderer {
         nearest_only: bool,
     ) -> Option<Hit>;
 
+    /// Returns the bounding rectangle of every glyph of [`Text`] laid out with
+    /// the given parameters, in order.
+    ///
+    /// The rectangles are relative to the top-left corner of the [`Text`].
+    /// They can be used to paint the highlight of a selection spanning
+    /// multiple characters.
+    fn glyph_positions(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Self::Font,
+        bounds: Size,
+    ) -> Vec<Rectangle>;
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
@@ -222,6 +320,39 @@ pub trait Renderer: crate::Renderer {
         horizontal_alignment: alignment::Horizontal,
         vertical_alignment: alignment::Vertical,
     ) -> Self::Output;
+
+    /// Draws a [`Text`] fragment with an optional [`TextShadow`] behind it.
+    ///
+    /// It receives the same arguments as [`Renderer::draw`], followed by the
+    /// shadow, the letter spacing, and the [`WritingMode`] of the [`Text`].
+    ///
+    /// By default, the shadow, the letter spacing, and the writing mode are
+    /// ignored and the [`Text`] is drawn on its own.
+    fn draw_with_shadow(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        content: &str,
+        size: u16,
+        font: Self::Font,
+        color: Option<Color>,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+        _shadow: Option<TextShadow>,
+        _letter_spacing: f32,
+        _writing_mode: WritingMode,
+    ) -> Self::Output {
+        self.draw(
+            defaults,
+            bounds,
+            content,
+            size,
+            font,
+            color,
+            horizontal_alignment,
+            vertical_alignment,
+        )
+    }
 }
 
 impl<'a, Message, Renderer> From<Text<Renderer>>
@@ -245,6 +376,9 @@ impl<Renderer: self::Renderer> Clone for Text<Renderer> {
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            shadow: self.shadow,
+            letter_spacing: self.letter_spacing,
+            writing_mode: self.writing_mode,
         }
     }
 }
@@ -2,6 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
@@ -10,6 +11,7 @@ pub use cursor::Cursor;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
 use crate::event::{self, Event};
 use crate::keyboard;
@@ -22,6 +24,8 @@ use crate::{
     Size, Widget,
 };
 
+#[cfg(feature = "ime")]
+use std::ops::Range;
 use std::u32;
 
 /// A field that can be filled with text.
@@ -85,10 +89,14 @@ where
     where
         F: 'static + Fn(String) -> Message,
     {
+        let value = Value::new(value);
+
+        state.history.sync(&value);
+
         TextInput {
             state,
             placeholder: String::from(placeholder),
-            value: Value::new(value),
+            value,
             is_secure: false,
             font: Default::default(),
             width: Length::Fill,
@@ -168,7 +176,7 @@ where
         &self,
         renderer: &mut Renderer,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         value: Option<&Value>,
     ) -> Renderer::Output {
         let value = value.unwrap_or(&self.value);
@@ -180,7 +188,7 @@ where
                 renderer,
                 bounds,
                 text_bounds,
-                cursor_position,
+                cursor,
                 self.font,
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
@@ -193,7 +201,7 @@ where
                 renderer,
                 bounds,
                 text_bounds,
-                cursor_position,
+                cursor,
                 self.font,
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
@@ -245,26 +253,40 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Mouse(mouse::Event::DoubleClicked(
+                mouse::Button::Left,
+                _,
+            ))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
-                let is_clicked = layout.bounds().contains(cursor_position);
+                let cursor_position = cursor.position_over(layout.bounds());
 
-                self.state.is_focused = is_clicked;
+                self.state.is_focused = cursor_position.is_some();
 
-                if is_clicked {
+                #[cfg(feature = "ime")]
+                if !self.state.is_focused {
+                    self.state.composing = None;
+                }
+
+                if let Some(cursor_position) = cursor_position {
                     let text_layout = layout.children().next().unwrap();
                     let target = cursor_position.x - text_layout.bounds().x;
 
-                    let click = mouse::Click::new(
-                        cursor_position,
-                        self.state.last_click,
-                    );
+                    let click = match event {
+                        Event::Mouse(mouse::Event::DoubleClicked(..)) => {
+                            mouse::Click::double(cursor_position)
+                        }
+                        _ => mouse::Click::new(
+                            cursor_position,
+                            self.state.last_click,
+                        ),
+                    };
 
                     match click.kind() {
                         click::Kind::Single => {
@@ -362,27 +384,106 @@ where
                     return event::Status::Captured;
                 }
             }
-            Event::Keyboard(keyboard::Event::CharacterReceived(c))
-                if self.state.is_focused
-                    && self.state.is_pasting.is_none()
-                    && !self.state.keyboard_modifiers.command()
-                    && !c.is_control() =>
+            #[cfg(feature = "ime")]
+            Event::Keyboard(keyboard::Event::ImeComposing(text))
+                if self.state.is_focused =>
             {
+                self.state.composing =
+                    Some(text).filter(|text| !text.is_empty());
+
+                return event::Status::Captured;
+            }
+            #[cfg(feature = "ime")]
+            Event::Keyboard(keyboard::Event::ImeCommit(text))
+                if self.state.is_focused =>
+            {
+                self.state.composing = None;
+
+                let content: String =
+                    text.chars().filter(|c| !c.is_control()).collect();
+
+                if content.is_empty() {
+                    return event::Status::Captured;
+                }
+
+                let before =
+                    history::Entry::new(&self.value, self.state.cursor);
                 let mut editor =
                     Editor::new(&mut self.value, &mut self.state.cursor);
 
-                editor.insert(c);
+                editor.paste(Value::new(&content));
 
                 let message = (self.on_change)(editor.contents());
                 messages.push(message);
 
+                self.state.history.push(
+                    history::Edit::Insertion,
+                    before,
+                    &self.value,
+                    self.state.cursor,
+                );
+
                 return event::Status::Captured;
             }
-            Event::Keyboard(keyboard::Event::KeyPressed {
-                key_code, ..
+            // The input method handles the keys pressed while composing
+            #[cfg(feature = "ime")]
+            Event::Keyboard(keyboard::Event::Input { .. })
+            | Event::Keyboard(keyboard::Event::CharacterReceived(_))
+            | Event::Keyboard(keyboard::Event::KeyPressed { .. })
+                if self.state.is_focused && self.state.composing.is_some() =>
+            {
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                text: Some(text),
+                ..
+            }) if self.state.is_focused
+                && self.state.is_pasting.is_none()
+                && !platform::is_command_modifier_pressed(
+                    self.state.keyboard_modifiers,
+                )
+                && text.chars().any(|c| !c.is_control()) =>
+            {
+                let before =
+                    history::Entry::new(&self.value, self.state.cursor);
+                let mut editor =
+                    Editor::new(&mut self.value, &mut self.state.cursor);
+
+                for c in text.chars().filter(|c| !c.is_control()) {
+                    editor.insert(c);
+                }
+
+                let message = (self.on_change)(editor.contents());
+                messages.push(message);
+
+                self.state.history.push(
+                    history::Edit::Insertion,
+                    before,
+                    &self.value,
+                    self.state.cursor,
+                );
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key: key_code,
+                physical_key,
+                location,
+                text,
+                repeat,
+                ..
             }) if self.state.is_focused => {
                 let modifiers = self.state.keyboard_modifiers;
 
+                let pressed = keyboard::Event::Input {
+                    key: key_code,
+                    physical_key,
+                    location,
+                    text,
+                    modifiers,
+                    repeat,
+                };
+
                 match key_code {
                     keyboard::KeyCode::Enter => {
                         if let Some(on_submit) = self.on_submit.clone() {
@@ -390,6 +491,9 @@ where
                         }
                     }
                     keyboard::KeyCode::Backspace => {
+                        let before =
+                            history::Entry::new(&self.value, self.state.cursor);
+
                         if platform::is_jump_modifier_pressed(modifiers)
                             && self
                                 .state
@@ -417,8 +521,18 @@ where
 
                         let message = (self.on_change)(editor.contents());
                         messages.push(message);
+
+                        self.state.history.push(
+                            history::Edit::Deletion,
+                            before,
+                            &self.value,
+                            self.state.cursor,
+                        );
                     }
                     keyboard::KeyCode::Delete => {
+                        let before =
+                            history::Entry::new(&self.value, self.state.cursor);
+
                         if platform::is_jump_modifier_pressed(modifiers)
                             && self
                                 .state
@@ -448,6 +562,13 @@ where
 
                         let message = (self.on_change)(editor.contents());
                         messages.push(message);
+
+                        self.state.history.push(
+                            history::Edit::Deletion,
+                            before,
+                            &self.value,
+                            self.state.cursor,
+                        );
                     }
                     keyboard::KeyCode::Left => {
                         if platform::is_jump_modifier_pressed(modifiers)
@@ -507,9 +628,7 @@ where
                             self.state.cursor.move_to(self.value.len());
                         }
                     }
-                    keyboard::KeyCode::C
-                        if self.state.keyboard_modifiers.command() =>
-                    {
+                    _ if keyboard::Shortcut::COPY.matches(&pressed) => {
                         match self.state.cursor.selection(&self.value) {
                             Some((start, end)) => {
                                 clipboard.write(
@@ -519,9 +638,7 @@ where
                             None => {}
                         }
                     }
-                    keyboard::KeyCode::X
-                        if self.state.keyboard_modifiers.command() =>
-                    {
+                    _ if keyboard::Shortcut::CUT.matches(&pressed) => {
                         match self.state.cursor.selection(&self.value) {
                             Some((start, end)) => {
                                 clipboard.write(
@@ -531,6 +648,8 @@ where
                             None => {}
                         }
 
+                        let before =
+                            history::Entry::new(&self.value, self.state.cursor);
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
@@ -540,41 +659,77 @@ where
 
                         let message = (self.on_change)(editor.contents());
                         messages.push(message);
+
+                        self.state.history.push(
+                            history::Edit::Cut,
+                            before,
+                            &self.value,
+                            self.state.cursor,
+                        );
+                    }
+                    _ if keyboard::Shortcut::PASTE.matches(&pressed) => {
+                        let content = match self.state.is_pasting.take() {
+                            Some(content) => content,
+                            None => {
+                                let content: String = clipboard
+                                    .read()
+                                    .unwrap_or(String::new())
+                                    .chars()
+                                    .filter(|c| !c.is_control())
+                                    .collect();
+
+                                Value::new(&content)
+                            }
+                        };
+
+                        let before =
+                            history::Entry::new(&self.value, self.state.cursor);
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                        );
+
+                        editor.paste(content.clone());
+
+                        let message = (self.on_change)(editor.contents());
+                        messages.push(message);
+
+                        self.state.history.push(
+                            history::Edit::Paste,
+                            before,
+                            &self.value,
+                            self.state.cursor,
+                        );
+
+                        self.state.is_pasting = Some(content);
                     }
                     keyboard::KeyCode::V => {
-                        if self.state.keyboard_modifiers.command() {
-                            let content = match self.state.is_pasting.take() {
-                                Some(content) => content,
-                                None => {
-                                    let content: String = clipboard
-                                        .read()
-                                        .unwrap_or(String::new())
-                                        .chars()
-                                        .filter(|c| !c.is_control())
-                                        .collect();
-
-                                    Value::new(&content)
-                                }
+                        self.state.is_pasting = None;
+                    }
+                    _ if keyboard::Shortcut::UNDO.matches(&pressed)
+                        || keyboard::Shortcut::REDO.matches(&pressed) =>
+                    {
+                        let entry =
+                            if keyboard::Shortcut::REDO.matches(&pressed) {
+                                self.state
+                                    .history
+                                    .redo(&self.value, self.state.cursor)
+                            } else {
+                                self.state
+                                    .history
+                                    .undo(&self.value, self.state.cursor)
                             };
 
-                            let mut editor = Editor::new(
-                                &mut self.value,
-                                &mut self.state.cursor,
-                            );
-
-                            editor.paste(content.clone());
+                        if let Some(entry) = entry {
+                            self.value = entry.value;
+                            self.state.cursor = entry.cursor;
 
-                            let message = (self.on_change)(editor.contents());
+                            let message =
+                                (self.on_change)(self.value.to_string());
                             messages.push(message);
-
-                            self.state.is_pasting = Some(content);
-                        } else {
-                            self.state.is_pasting = None;
                         }
                     }
-                    keyboard::KeyCode::A
-                        if self.state.keyboard_modifiers.command() =>
-                    {
+                    _ if keyboard::Shortcut::SELECT_ALL.matches(&pressed) => {
                         self.state.cursor.select_all(&self.value);
                     }
                     keyboard::KeyCode::Escape => {
@@ -582,6 +737,11 @@ where
                         self.state.is_dragging = false;
                         self.state.is_pasting = None;
 
+                        #[cfg(feature = "ime")]
+                        {
+                            self.state.composing = None;
+                        }
+
                         self.state.keyboard_modifiers =
                             keyboard::Modifiers::default();
                     }
@@ -595,6 +755,28 @@ where
 
                 return event::Status::Captured;
             }
+            // The legacy events of the keys are handled by their `Input`, but
+            // they are captured all the same
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                return match key_code {
+                    keyboard::KeyCode::Tab
+                    | keyboard::KeyCode::Up
+                    | keyboard::KeyCode::Down => event::Status::Ignored,
+                    _ => event::Status::Captured,
+                };
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && self.state.is_pasting.is_none()
+                    && !platform::is_command_modifier_pressed(
+                        self.state.keyboard_modifiers,
+                    )
+                    && !c.is_control() =>
+            {
+                return event::Status::Captured;
+            }
             Event::Keyboard(keyboard::Event::KeyReleased {
                 key_code, ..
             }) if self.state.is_focused => {
@@ -628,10 +810,10 @@ where
         renderer: &mut Renderer,
         _defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        self.draw(renderer, layout, cursor_position, None)
+        self.draw(renderer, layout, cursor, None)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -678,7 +860,7 @@ pub trait Renderer: text::Renderer + Sized {
     /// It receives:
     /// - the bounds of the [`TextInput`]
     /// - the bounds of the text (i.e. the current value)
-    /// - the cursor position
+    /// - the current [`mouse::Cursor`]
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
     /// - the current [`State`]
@@ -686,7 +868,7 @@ pub trait Renderer: text::Renderer + Sized {
         &mut self,
         bounds: Rectangle,
         text_bounds: Rectangle,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         font: Self::Font,
         size: u16,
         placeholder: &str,
@@ -697,6 +879,10 @@ pub trait Renderer: text::Renderer + Sized {
 
     /// Computes the position of the text cursor at the given X coordinate of
     /// a [`TextInput`].
+    ///
+    /// The cursor is placed after every grapheme whose glyphs are centered
+    /// before the coordinate. It returns `None` if the [`Renderer`] produces
+    /// no glyph positions for the [`Value`].
     fn find_cursor_position(
         &self,
         text_bounds: Rectangle,
@@ -709,16 +895,39 @@ pub trait Renderer: text::Renderer + Sized {
         let size = size.unwrap_or(self.default_size());
 
         let offset = self.offset(text_bounds, font, size, &value, &state);
+        let target = x + offset;
 
-        self.hit_test(
+        let glyphs = self.glyph_positions(
             &value.to_string(),
             size.into(),
             font,
             Size::INFINITY,
-            Point::new(x + offset, text_bounds.height / 2.0),
-            true,
-        )
-        .map(text::Hit::cursor)
+        );
+
+        if glyphs.is_empty() {
+            return None;
+        }
+
+        // Glyphs are laid out one per character, while the cursor moves over
+        // graphemes
+        let mut chars = 0;
+
+        let position = (0..value.len())
+            .take_while(|index| {
+                let start = chars;
+                chars +=
+                    value.select(*index, index + 1).to_string().chars().count();
+
+                match (glyphs.get(start), glyphs.get(chars - 1)) {
+                    (Some(first), Some(last)) => {
+                        (first.x + last.x + last.width) / 2.0 < target
+                    }
+                    _ => false,
+                }
+            })
+            .count();
+
+        Some(position)
     }
 }
 
@@ -743,7 +952,10 @@ pub struct State {
     is_pasting: Option<Value>,
     last_click: Option<mouse::Click>,
     cursor: Cursor,
+    history: History,
     keyboard_modifiers: keyboard::Modifiers,
+    #[cfg(feature = "ime")]
+    composing: Option<String>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
@@ -761,7 +973,10 @@ impl State {
             is_pasting: None,
             last_click: None,
             cursor: Cursor::default(),
+            history: History::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            #[cfg(feature = "ime")]
+            composing: None,
         }
     }
 
@@ -783,6 +998,41 @@ impl State {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = false;
+
+        #[cfg(feature = "ime")]
+        {
+            self.composing = None;
+        }
+    }
+
+    /// Returns the text being composed with an input method, if any.
+    #[cfg(feature = "ime")]
+    pub fn composing(&self) -> Option<&str> {
+        self.composing.as_deref()
+    }
+
+    /// Returns the [`Value`] displayed while composing text with an input
+    /// method, along with the range of the composing text in it.
+    ///
+    /// The composing text replaces the current selection, if any.
+    #[cfg(feature = "ime")]
+    pub fn composition(&self, value: &Value) -> Option<(Value, Range<usize>)> {
+        let composing = Value::new(self.composing.as_deref()?);
+
+        let (mut composed, start) = match self.cursor.selection(value) {
+            Some((left, right)) => {
+                let mut composed = value.clone();
+                composed.remove_many(left, right);
+
+                (composed, left)
+            }
+            None => (value.clone(), self.cursor.end(value)),
+        };
+
+        let end = start + composing.len();
+        composed.insert_many(start, composing);
+
+        Some((composed, start..end))
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -804,6 +1054,14 @@ impl State {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Clears the undo and redo history of the [`TextInput`].
+    ///
+    /// The history is already cleared when the [`TextInput`] is built with a
+    /// value different from the one produced by its last edit.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
 }
 
 mod platform {
@@ -816,4 +1074,202 @@ mod platform {
             modifiers.control()
         }
     }
+
+    /// Returns whether the characters received while the given modifiers are
+    /// held trigger a command instead of being typed.
+    ///
+    /// `AltGr`, which types many characters of international layouts, is
+    /// reported as `Ctrl+Alt` on Windows.
+    pub fn is_command_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.logo()
+        } else {
+            modifiers.control() && !modifiers.alt()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::Size;
+
+    fn type_with(
+        modifiers: keyboard::Modifiers,
+        key: keyboard::KeyCode,
+        c: char,
+    ) -> Vec<String> {
+        let mut state = State::focused();
+        let mut input =
+            TextInput::<String, Null>::new(&mut state, "", "", |value| value);
+
+        let node = input.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+        let mut messages = Vec::new();
+
+        // The shells produce every event of a key press
+        for event in [
+            keyboard::Event::ModifiersChanged(modifiers),
+            keyboard::Event::KeyPressed {
+                key_code: key,
+                physical_key: keyboard::ScanCode(0),
+                location: keyboard::KeyLocation::Standard,
+                modifiers,
+                repeat: false,
+            },
+            keyboard::Event::CharacterReceived(c),
+            keyboard::Event::Input {
+                key,
+                physical_key: keyboard::ScanCode(0),
+                location: keyboard::KeyLocation::Standard,
+                text: Some(c.to_string().into()),
+                modifiers,
+                repeat: false,
+            },
+        ] {
+            let _ = input.on_event(
+                Event::Keyboard(event),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &Null,
+                &mut clipboard::Null,
+                &mut messages,
+            );
+        }
+
+        messages
+    }
+
+    #[test]
+    #[cfg(feature = "ime")]
+    fn composed_text_is_shown_in_place_until_committed() {
+        let mut state = State::focused();
+        state.move_cursor_to_end();
+
+        let mut input =
+            TextInput::<String, Null>::new(&mut state, "", "ab", |value| value);
+
+        let node = input.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+        let mut messages = Vec::new();
+
+        let mut send = |input: &mut TextInput<'_, String, Null>, event| {
+            input.on_event(
+                Event::Keyboard(event),
+                Layout::new(&node),
+                mouse::Cursor::Unavailable,
+                &Null,
+                &mut clipboard::Null,
+                &mut messages,
+            )
+        };
+
+        for composing in ["に", "にほ", "日本"] {
+            let _ = send(
+                &mut input,
+                keyboard::Event::ImeComposing(composing.to_string()),
+            );
+        }
+
+        // Keys pressed while composing belong to the input method
+        assert_eq!(
+            send(&mut input, keyboard::Event::CharacterReceived('x')),
+            event::Status::Captured
+        );
+
+        assert_eq!(input.state.composing(), Some("日本"));
+        assert_eq!(
+            input.state.composition(&input.value),
+            Some((Value::new("ab日本"), 2..4))
+        );
+
+        let _ = send(&mut input, keyboard::Event::ImeCommit("日本".into()));
+
+        assert_eq!(input.state.composing(), None);
+        assert_eq!(messages, vec!["ab日本"]);
+    }
+
+    #[test]
+    fn double_clicks_select_a_word_and_triple_clicks_everything() {
+        let mut state = State::new();
+        let mut input = TextInput::<String, Null>::new(
+            &mut state,
+            "",
+            "hello world",
+            |value| value,
+        );
+
+        let node = input.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 40.0)),
+        );
+        let position = Point::new(5.0, 5.0);
+
+        let click = |input: &mut TextInput<'_, String, Null>, event| {
+            let _ = input.on_event(
+                Event::Mouse(event),
+                Layout::new(&node),
+                mouse::Cursor::Available(position),
+                &Null,
+                &mut clipboard::Null,
+                &mut Vec::new(),
+            );
+
+            input.state.cursor().state(&input.value)
+        };
+
+        let _ =
+            click(&mut input, mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(
+            click(
+                &mut input,
+                mouse::Event::DoubleClicked(mouse::Button::Left, position)
+            ),
+            cursor::State::Selection { start: 0, end: 5 }
+        );
+        assert_eq!(
+            click(&mut input, mouse::Event::ButtonPressed(mouse::Button::Left)),
+            cursor::State::Selection { start: 0, end: 11 }
+        );
+    }
+
+    #[test]
+    fn characters_typed_with_alt_gr_are_inserted() {
+        let alt_gr = keyboard::Modifiers::CTRL | keyboard::Modifiers::ALT;
+
+        assert_eq!(
+            type_with(
+                keyboard::Modifiers::empty(),
+                keyboard::KeyCode::Key2,
+                'é'
+            ),
+            vec!["é"]
+        );
+        assert_eq!(
+            type_with(
+                keyboard::Modifiers::SHIFT,
+                keyboard::KeyCode::Apostrophe,
+                'Ä'
+            ),
+            vec!["Ä"]
+        );
+
+        if !cfg!(target_os = "macos") {
+            assert_eq!(type_with(alt_gr, keyboard::KeyCode::Q, '@'), vec!["@"]);
+        }
+
+        assert!(type_with(
+            keyboard::Modifiers::COMMAND,
+            keyboard::KeyCode::C,
+            'c'
+        )
+        .is_empty());
+    }
 }
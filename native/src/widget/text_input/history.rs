@@ -0,0 +1,276 @@
+use crate::text_input::{Cursor, Value};
+
+use std::time::{Duration, Instant};
+
+/// The maximum amount of undo steps kept by a [`History`].
+const MAX_ENTRIES: usize = 100;
+
+/// Consecutive insertions closer in time than this are merged into a single
+/// undo step.
+const COALESCE_WINDOW: Duration = Duration::from_secs(1);
+
+/// The edit history of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+    last_insertion: Option<(Instant, Cursor)>,
+    latest: Option<Value>,
+}
+
+/// A snapshot of the value and the cursor of a text input.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub value: Value,
+    pub cursor: Cursor,
+}
+
+impl Entry {
+    pub fn new(value: &Value, cursor: Cursor) -> Self {
+        Self {
+            value: value.clone(),
+            cursor,
+        }
+    }
+}
+
+/// The kind of an edit recorded in a [`History`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    Insertion,
+    Deletion,
+    Paste,
+    Cut,
+}
+
+impl History {
+    /// Records an edit, given the [`Entry`] before it and the resulting value
+    /// and cursor.
+    ///
+    /// Edits that do not change the value are ignored.
+    pub fn push(
+        &mut self,
+        edit: Edit,
+        before: Entry,
+        value: &Value,
+        cursor: Cursor,
+    ) {
+        self.push_at(edit, before, value, cursor, Instant::now());
+    }
+
+    fn push_at(
+        &mut self,
+        edit: Edit,
+        before: Entry,
+        value: &Value,
+        cursor: Cursor,
+        now: Instant,
+    ) {
+        if before.value == *value {
+            return;
+        }
+
+        let is_continuation = edit == Edit::Insertion
+            && match self.last_insertion {
+                Some((at, last_cursor)) => {
+                    now.duration_since(at) <= COALESCE_WINDOW
+                        && last_cursor == before.cursor
+                }
+                None => false,
+            };
+
+        if !is_continuation {
+            self.undo.push(before);
+
+            if self.undo.len() > MAX_ENTRIES {
+                let _ = self.undo.remove(0);
+            }
+        }
+
+        self.redo.clear();
+        self.last_insertion = if edit == Edit::Insertion {
+            Some((now, cursor))
+        } else {
+            None
+        };
+        self.latest = Some(value.clone());
+    }
+
+    /// Reverts the last undo step, given the current value and cursor.
+    ///
+    /// Returns the [`Entry`] to restore, if any.
+    pub fn undo(&mut self, value: &Value, cursor: Cursor) -> Option<Entry> {
+        let entry = self.undo.pop()?;
+
+        self.redo.push(Entry::new(value, cursor));
+        self.restore(entry)
+    }
+
+    /// Reapplies the last undone step, given the current value and cursor.
+    ///
+    /// Returns the [`Entry`] to restore, if any.
+    pub fn redo(&mut self, value: &Value, cursor: Cursor) -> Option<Entry> {
+        let entry = self.redo.pop()?;
+
+        self.undo.push(Entry::new(value, cursor));
+        self.restore(entry)
+    }
+
+    /// Clears the [`History`] if the given value was not produced by the
+    /// last recorded edit.
+    pub fn sync(&mut self, value: &Value) {
+        if self.latest.as_ref().map_or(false, |latest| latest != value) {
+            self.clear();
+        }
+    }
+
+    /// Clears the [`History`].
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn restore(&mut self, entry: Entry) -> Option<Entry> {
+        self.last_insertion = None;
+        self.latest = Some(entry.value.clone());
+
+        Some(entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_str(
+        history: &mut History,
+        value: &mut Value,
+        cursor: &mut Cursor,
+        text: &str,
+        now: Instant,
+    ) {
+        for c in text.chars() {
+            let before = Entry::new(value, *cursor);
+            let position = cursor.end(value);
+
+            value.insert(position, c);
+            cursor.move_to(position + 1);
+
+            history.push_at(Edit::Insertion, before, value, *cursor, now);
+        }
+    }
+
+    #[test]
+    fn consecutive_insertions_are_merged() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let now = Instant::now();
+
+        type_str(&mut history, &mut value, &mut cursor, "hello", now);
+
+        let entry = history.undo(&value, cursor).unwrap();
+
+        assert_eq!(entry.value, Value::new(""));
+        assert!(history.undo(&entry.value, entry.cursor).is_none());
+    }
+
+    #[test]
+    fn insertions_after_the_window_are_not_merged() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let now = Instant::now();
+
+        type_str(&mut history, &mut value, &mut cursor, "ab", now);
+        type_str(
+            &mut history,
+            &mut value,
+            &mut cursor,
+            "c",
+            now + COALESCE_WINDOW * 2,
+        );
+
+        let entry = history.undo(&value, cursor).unwrap();
+
+        assert_eq!(entry.value, Value::new("ab"));
+    }
+
+    #[test]
+    fn insertions_after_cursor_movement_are_not_merged() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let now = Instant::now();
+
+        type_str(&mut history, &mut value, &mut cursor, "ab", now);
+        cursor.move_to(0);
+        type_str(&mut history, &mut value, &mut cursor, "c", now);
+
+        let entry = history.undo(&value, cursor).unwrap();
+
+        assert_eq!(entry.value, Value::new("ab"));
+        assert_eq!(entry.cursor.state(&entry.value), cursor_at(0));
+    }
+
+    #[test]
+    fn pastes_create_distinct_steps_and_can_be_redone() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let now = Instant::now();
+
+        type_str(&mut history, &mut value, &mut cursor, "a word", now);
+
+        let before = Entry::new(&value, cursor);
+        cursor.select_range(2, 6);
+        value.remove_many(2, 6);
+        value.insert_many(2, Value::new("sentence"));
+        cursor.move_to(10);
+        history.push_at(Edit::Paste, before, &value, cursor, now);
+
+        let pasted = value.clone();
+        let entry = history.undo(&value, cursor).unwrap();
+        assert_eq!(entry.value, Value::new("a word"));
+
+        let entry = history.redo(&entry.value, entry.cursor).unwrap();
+        assert_eq!(entry.value, pasted);
+    }
+
+    #[test]
+    fn new_edits_truncate_the_redo_branch() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let now = Instant::now();
+
+        type_str(&mut history, &mut value, &mut cursor, "a", now);
+
+        let entry = history.undo(&value, cursor).unwrap();
+        value = entry.value;
+        cursor = entry.cursor;
+
+        type_str(&mut history, &mut value, &mut cursor, "b", now);
+
+        assert!(history.redo(&value, cursor).is_none());
+    }
+
+    #[test]
+    fn foreign_values_clear_the_history() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+        let mut cursor = Cursor::default();
+        let now = Instant::now();
+
+        type_str(&mut history, &mut value, &mut cursor, "a", now);
+
+        history.sync(&Value::new("a"));
+        assert!(history.clone().undo(&value, cursor).is_some());
+
+        history.sync(&Value::new("something else"));
+        assert!(history.undo(&value, cursor).is_none());
+    }
+
+    fn cursor_at(index: usize) -> crate::text_input::cursor::State {
+        crate::text_input::cursor::State::Index(index)
+    }
+}
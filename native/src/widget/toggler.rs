@@ -8,8 +8,8 @@ use crate::mouse;
 use crate::row;
 use crate::text;
 use crate::{
-    Alignment, Clipboard, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Row, Text, Widget,
+    Alignment, Clipboard, Element, Event, Hasher, Layout, Length, Rectangle,
+    Row, Text, Widget,
 };
 
 /// A toggler widget
@@ -38,7 +38,7 @@ pub struct Toggler<Message, Renderer: self::Renderer + text::Renderer> {
     text_alignment: alignment::Horizontal,
     spacing: u16,
     font: Renderer::Font,
-    style: Renderer::Style,
+    style: Option<Renderer::Style>,
 }
 
 impl<Message, Renderer: self::Renderer + text::Renderer>
@@ -70,7 +70,7 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
             text_alignment: alignment::Horizontal::Left,
             spacing: 0,
             font: Renderer::Font::default(),
-            style: Renderer::Style::default(),
+            style: None,
         }
     }
 
@@ -112,7 +112,7 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
 
     /// Sets the style of the [`Toggler`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
-        self.style = style.into();
+        self.style = Some(style.into());
         self
     }
 }
@@ -162,14 +162,14 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _renderer: &Renderer,
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
+    ) -> event::Status<Message> {
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                let mouse_over = layout.bounds().contains(cursor_position);
+                let mouse_over = cursor.is_over(layout.bounds());
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_active));
@@ -188,7 +188,7 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
         let bounds = layout.bounds();
@@ -217,7 +217,7 @@ where
         let toggler_layout = children.next().unwrap();
         let toggler_bounds = toggler_layout.bounds();
 
-        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over = cursor.is_over(bounds);
 
         self::Renderer::draw(
             renderer,
@@ -225,7 +225,7 @@ where
             self.is_active,
             is_mouse_over,
             label,
-            &self.style,
+            self.style.as_ref(),
         )
     }
 
@@ -233,7 +233,11 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
-        self.label.hash(state)
+        self.label.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.text_size.hash(state);
     }
 }
 
@@ -245,7 +249,7 @@ where
 /// [renderer]: ../../renderer/index.html
 pub trait Renderer: crate::Renderer {
     /// The style supported by this renderer.
-    type Style: Default;
+    type Style;
 
     /// The default size of a [`Toggler`].
     const DEFAULT_SIZE: u16;
@@ -257,14 +261,15 @@ pub trait Renderer: crate::Renderer {
     ///   * whether the [`Toggler`] is activated or not
     ///   * whether the mouse is over the [`Toggler`] or not
     ///   * the drawn label of the [`Toggler`]
-    ///   * the style of the [`Toggler`]
+    ///   * the style of the [`Toggler`], if it was set explicitly. Otherwise,
+    ///     the renderer should resolve it from its active theme.
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_active: bool,
         is_mouse_over: bool,
         label: Option<Self::Output>,
-        style: &Self::Style,
+        style: Option<&Self::Style>,
     ) -> Self::Output;
 }
 
@@ -3,10 +3,11 @@ use std::hash::Hash;
 
 use iced_core::Rectangle;
 
+use crate::mouse;
 use crate::widget::container;
 use crate::widget::text::{self, Text};
 use crate::{
-    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Vector,
     Widget,
 };
 
@@ -19,6 +20,8 @@ pub struct Tooltip<'a, Message, Renderer: self::Renderer> {
     style: <Renderer as container::Renderer>::Style,
     gap: u16,
     padding: u16,
+    arrow: Option<u16>,
+    cursor_offset: Option<Vector>,
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
@@ -40,6 +43,8 @@ where
             style: Default::default(),
             gap: 0,
             padding: Renderer::DEFAULT_PADDING,
+            arrow: None,
+            cursor_offset: None,
         }
     }
 
@@ -69,6 +74,26 @@ where
         self
     }
 
+    /// Sets the size of an arrow pointing from the [`Tooltip`] toward its
+    /// content.
+    ///
+    /// The arrow is not drawn when the [`Tooltip`] follows the cursor.
+    pub fn arrow(mut self, size: u16) -> Self {
+        self.arrow = Some(size);
+        self
+    }
+
+    /// Sets the offset from the cursor to the top left corner of the
+    /// [`Tooltip`] when it follows the cursor.
+    ///
+    /// For instance, a positive offset makes the [`Tooltip`] trail below and
+    /// to the right of the cursor. By default, the [`Tooltip`] sits right
+    /// above the cursor.
+    pub fn cursor_offset(mut self, offset: Vector) -> Self {
+        self.cursor_offset = Some(offset);
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     pub fn style(
         mut self,
@@ -119,19 +144,14 @@ where
         &mut self,
         event: Event,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         renderer: &Renderer,
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
-    ) -> event::Status {
-        self.content.widget.on_event(
-            event,
-            layout,
-            cursor_position,
-            renderer,
-            clipboard,
-            messages,
-        )
+    ) -> event::Status<Message> {
+        self.content
+            .widget
+            .on_event(event, layout, cursor, renderer, clipboard, messages)
     }
 
     fn draw(
@@ -139,13 +159,13 @@ where
         renderer: &mut Renderer,
         defaults: &Renderer::Defaults,
         layout: Layout<'_>,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) -> Renderer::Output {
         self::Renderer::draw(
             renderer,
             defaults,
-            cursor_position,
+            cursor,
             layout,
             viewport,
             &self.content,
@@ -154,6 +174,8 @@ where
             &self.style,
             self.gap,
             self.padding,
+            self.arrow,
+            self.cursor_offset,
         )
     }
 
@@ -161,6 +183,8 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        // The tooltip is laid out on its own while drawing, so its gap and
+        // padding do not affect the layout of the content.
         self.content.hash_layout(state);
     }
 }
@@ -184,7 +208,7 @@ pub trait Renderer:
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
-        cursor_position: Point,
+        cursor: mouse::Cursor,
         content_layout: Layout<'_>,
         viewport: &Rectangle,
         content: &Element<'_, Message, Self>,
@@ -193,6 +217,8 @@ pub trait Renderer:
         style: &<Self as container::Renderer>::Style,
         gap: u16,
         padding: u16,
+        arrow: Option<u16>,
+        cursor_offset: Option<Vector>,
     ) -> Self::Output;
 }
 
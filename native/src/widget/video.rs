@@ -0,0 +1,576 @@
+//! Play videos in your application.
+use crate::image;
+use crate::layout;
+use crate::mouse;
+use crate::{
+    ContentFit, Element, Hasher, Layout, Length, Rectangle, Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A frame of a video, drawn at the current position of its [`Handle`].
+///
+/// The way the video fits its bounds can be changed with a [`ContentFit`].
+#[derive(Debug, Clone)]
+pub struct Video {
+    handle: Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+}
+
+impl Video {
+    /// Creates a new [`Video`] playing the given [`Handle`].
+    pub fn new(handle: Handle) -> Self {
+        Video {
+            handle,
+            width: Length::Fill,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+        }
+    }
+
+    /// Sets the width of the [`Video`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Video`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Video`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Video
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = self.handle.dimensions();
+
+        layout::Node::new(image::layout(
+            limits,
+            Size::new(width as f32, height as f32),
+            self.width,
+            self.height,
+            self.content_fit,
+        ))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let (width, height) = self.handle.dimensions();
+
+        renderer.draw(
+            self.handle.clone(),
+            image::content_bounds(
+                layout.bounds(),
+                Size::new(width as f32, height as f32),
+                self.content_fit,
+            ),
+            layout,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Video>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.content_fit.hash(state);
+    }
+}
+
+/// A decoded frame of a video, in the planar YUV 4:2:0 format (I420).
+#[derive(Clone)]
+pub struct Frame {
+    /// The position of the [`Frame`] in its video.
+    pub timestamp: Duration,
+
+    /// The width of the [`Frame`], in pixels.
+    pub width: u32,
+
+    /// The height of the [`Frame`], in pixels.
+    pub height: u32,
+
+    /// The luma plane, with one byte per pixel.
+    pub y: Vec<u8>,
+
+    /// The blue-difference chroma plane, with one byte per 2x2 pixels.
+    pub u: Vec<u8>,
+
+    /// The red-difference chroma plane, with one byte per 2x2 pixels.
+    pub v: Vec<u8>,
+}
+
+impl Frame {
+    /// Returns the dimensions of the chroma planes of the [`Frame`].
+    pub fn chroma_dimensions(&self) -> (u32, u32) {
+        ((self.width + 1) / 2, (self.height + 1) / 2)
+    }
+}
+
+impl fmt::Debug for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Frame")
+            .field("timestamp", &self.timestamp)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+/// A source of decoded video frames, like a GStreamer or an ffmpeg pipeline.
+///
+/// A [`Handle`] decodes frames on a worker thread, so a [`Decoder`] may take
+/// its time in [`Decoder::frame_at`] without stalling the renderer.
+pub trait Decoder: Send {
+    /// Returns the dimensions of the frames of the video.
+    fn dimensions(&self) -> (u32, u32);
+
+    /// Returns the duration of the video, if it is known.
+    fn duration(&self) -> Option<Duration>;
+
+    /// Moves the decoder to the given position.
+    fn seek(&mut self, position: Duration);
+
+    /// Returns the latest frame decoded at or before the given position, if
+    /// it has not been returned already.
+    fn frame_at(&mut self, position: Duration) -> Option<Frame>;
+}
+
+/// A handle to a video and its playback.
+///
+/// Cloning a [`Handle`] shares its playback, so it can be controlled from
+/// the state of an application while a [`Video`] draws it.
+#[derive(Clone)]
+pub struct Handle {
+    id: u64,
+    dimensions: (u32, u32),
+    duration: Option<Duration>,
+    player: Arc<Mutex<Player>>,
+    latest: Arc<Mutex<Latest>>,
+}
+
+struct Player {
+    clock: Clock,
+    worker: mpsc::Sender<Request>,
+}
+
+/// The latest frame finished by the worker of a [`Handle`].
+///
+/// Seeking starts a new generation, so frames of the previous position that
+/// finish afterwards are discarded.
+#[derive(Default)]
+struct Latest {
+    generation: u64,
+    frame: Option<Arc<Frame>>,
+}
+
+enum Request {
+    Seek(Duration),
+    Decode { position: Duration, generation: u64 },
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Clock {
+    Playing { started_at: Instant, from: Duration },
+    Paused { at: Duration },
+}
+
+impl Clock {
+    fn position(self) -> Duration {
+        match self {
+            Clock::Playing { started_at, from } => from + started_at.elapsed(),
+            Clock::Paused { at } => at,
+        }
+    }
+}
+
+impl Handle {
+    /// Creates a new [`Handle`] for the video produced by the given
+    /// [`Decoder`].
+    ///
+    /// The video starts paused at its beginning. The [`Decoder`] is moved to
+    /// a worker thread, which stops once every clone of the [`Handle`] is
+    /// dropped.
+    pub fn new(decoder: impl Decoder + 'static) -> Handle {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let dimensions = decoder.dimensions();
+        let duration = decoder.duration();
+        let latest = Arc::new(Mutex::new(Latest::default()));
+
+        let (sender, receiver) = mpsc::channel();
+
+        let _ = thread::Builder::new()
+            .name(String::from("iced video decoder"))
+            .spawn({
+                let latest = latest.clone();
+
+                move || decode(Box::new(decoder), receiver, latest)
+            })
+            .expect("Spawn video decoder");
+
+        Handle {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            dimensions,
+            duration,
+            player: Arc::new(Mutex::new(Player {
+                clock: Clock::Paused {
+                    at: Duration::from_secs(0),
+                },
+                worker: sender,
+            })),
+            latest,
+        }
+    }
+
+    /// Returns the unique identifier of the [`Handle`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the dimensions of the video.
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.dimensions
+    }
+
+    /// Returns the duration of the video, if it is known.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Starts or resumes playing the video.
+    pub fn play(&self) {
+        let mut player = self.player();
+
+        if let Clock::Paused { at } = player.clock {
+            player.clock = Clock::Playing {
+                started_at: Instant::now(),
+                from: at,
+            };
+        }
+    }
+
+    /// Pauses the video at its current position.
+    pub fn pause(&self) {
+        let mut player = self.player();
+
+        player.clock = Clock::Paused {
+            at: self.clamp(player.clock.position()),
+        };
+    }
+
+    /// Moves the playback to the given position, clamped to the duration of
+    /// the video.
+    pub fn seek(&self, position: Duration) {
+        let mut player = self.player();
+        let position = self.clamp(position);
+
+        {
+            let mut latest = self.latest();
+
+            latest.generation += 1;
+            latest.frame = None;
+        }
+
+        let _ = player.worker.send(Request::Seek(position));
+
+        player.clock = match player.clock {
+            Clock::Playing { .. } => Clock::Playing {
+                started_at: Instant::now(),
+                from: position,
+            },
+            Clock::Paused { .. } => Clock::Paused { at: position },
+        };
+    }
+
+    /// Returns whether the video is playing.
+    ///
+    /// A video stops playing once it reaches its end.
+    pub fn is_playing(&self) -> bool {
+        let clock = self.player().clock;
+
+        matches!(clock, Clock::Playing { .. })
+            && self
+                .duration
+                .map_or(true, |duration| clock.position() < duration)
+    }
+
+    /// Returns the current position of the playback.
+    pub fn position(&self) -> Duration {
+        self.clamp(self.player().clock.position())
+    }
+
+    /// Returns the latest frame decoded for the playback, if there is one.
+    ///
+    /// This never waits for the [`Decoder`]: it asks the worker of the
+    /// [`Handle`] for the frame at the current position and returns the last
+    /// one it finished.
+    pub fn frame(&self) -> Option<Arc<Frame>> {
+        let player = self.player();
+        let latest = self.latest();
+
+        let _ = player.worker.send(Request::Decode {
+            position: self.clamp(player.clock.position()),
+            generation: latest.generation,
+        });
+
+        latest.frame.clone()
+    }
+
+    fn clamp(&self, position: Duration) -> Duration {
+        match self.duration {
+            Some(duration) => position.min(duration),
+            None => position,
+        }
+    }
+
+    fn player(&self) -> MutexGuard<'_, Player> {
+        self.player.lock().expect("Lock video player")
+    }
+
+    fn latest(&self) -> MutexGuard<'_, Latest> {
+        self.latest.lock().expect("Lock latest video frame")
+    }
+}
+
+/// Runs the [`Decoder`] of a [`Handle`] until every clone of it is dropped.
+///
+/// Pending decode requests are coalesced, so a slow [`Decoder`] only works
+/// on the latest position asked for.
+fn decode(
+    mut decoder: Box<dyn Decoder>,
+    requests: mpsc::Receiver<Request>,
+    latest: Arc<Mutex<Latest>>,
+) {
+    while let Ok(request) = requests.recv() {
+        let mut pending = None;
+
+        for request in std::iter::once(request).chain(requests.try_iter()) {
+            match request {
+                Request::Seek(position) => {
+                    decoder.seek(position);
+                    pending = None;
+                }
+                Request::Decode {
+                    position,
+                    generation,
+                } => {
+                    pending = Some((position, generation));
+                }
+            }
+        }
+
+        if let Some((position, generation)) = pending {
+            if let Some(frame) = decoder.frame_at(position) {
+                let mut latest =
+                    latest.lock().expect("Lock latest video frame");
+
+                if latest.generation == generation {
+                    latest.frame = Some(Arc::new(frame));
+                }
+            }
+        }
+    }
+}
+
+impl Hash for Handle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Debug for Handle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Handle").field("id", &self.id).finish()
+    }
+}
+
+/// The renderer of a [`Video`].
+///
+/// Your [renderer] will need to implement this trait before being able to use
+/// a [`Video`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer {
+    /// Draws the current frame of a [`Video`] in the given `bounds`, clipped
+    /// to the bounds of the given [`Layout`].
+    fn draw(
+        &mut self,
+        handle: Handle,
+        bounds: Rectangle,
+        layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Video> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(video: Video) -> Element<'a, Message, Renderer> {
+        Element::new(video)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Still {
+        seeked_to: Option<Duration>,
+    }
+
+    impl Decoder for Still {
+        fn dimensions(&self) -> (u32, u32) {
+            (4, 2)
+        }
+
+        fn duration(&self) -> Option<Duration> {
+            Some(Duration::from_secs(10))
+        }
+
+        fn seek(&mut self, position: Duration) {
+            self.seeked_to = Some(position);
+        }
+
+        fn frame_at(&mut self, position: Duration) -> Option<Frame> {
+            Some(Frame {
+                timestamp: self.seeked_to.unwrap_or(position),
+                width: 4,
+                height: 2,
+                y: vec![0; 8],
+                u: vec![0; 2],
+                v: vec![0; 2],
+            })
+        }
+    }
+
+    struct Blocked {
+        release: mpsc::Receiver<()>,
+    }
+
+    impl Decoder for Blocked {
+        fn dimensions(&self) -> (u32, u32) {
+            (4, 2)
+        }
+
+        fn duration(&self) -> Option<Duration> {
+            None
+        }
+
+        fn seek(&mut self, _position: Duration) {}
+
+        fn frame_at(&mut self, position: Duration) -> Option<Frame> {
+            self.release.recv().ok()?;
+
+            Some(Frame {
+                timestamp: position,
+                width: 4,
+                height: 2,
+                y: vec![0; 8],
+                u: vec![0; 2],
+                v: vec![0; 2],
+            })
+        }
+    }
+
+    fn decoded(handle: &Handle) -> Arc<Frame> {
+        let started_at = Instant::now();
+
+        loop {
+            if let Some(frame) = handle.frame() {
+                return frame;
+            }
+
+            assert!(started_at.elapsed() < Duration::from_secs(5));
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn frames_are_decoded_without_waiting_for_the_decoder() {
+        let (release, receiver) = mpsc::channel();
+        let handle = Handle::new(Blocked { release: receiver });
+
+        assert!(handle.frame().is_none());
+        assert!(handle.frame().is_none());
+
+        release.send(()).expect("Release decoder");
+
+        assert_eq!(decoded(&handle).timestamp, Duration::from_secs(0));
+    }
+
+    #[test]
+    fn seeking_a_paused_video_moves_its_position() {
+        let handle = Handle::new(Still { seeked_to: None });
+
+        assert!(!handle.is_playing());
+        assert_eq!(handle.position(), Duration::from_secs(0));
+
+        handle.seek(Duration::from_secs(4));
+
+        assert!(!handle.is_playing());
+        assert_eq!(handle.position(), Duration::from_secs(4));
+        assert_eq!(decoded(&handle).timestamp, Duration::from_secs(4));
+
+        // Seeking past the end stops at it
+        handle.seek(Duration::from_secs(60));
+
+        assert_eq!(handle.position(), Duration::from_secs(10));
+
+        handle.play();
+
+        assert!(!handle.is_playing());
+    }
+
+    #[test]
+    fn pausing_a_video_keeps_its_position() {
+        let handle = Handle::new(Still { seeked_to: None });
+
+        handle.play();
+        assert!(handle.is_playing());
+
+        handle.pause();
+        let position = handle.position();
+
+        assert!(!handle.is_playing());
+        assert_eq!(handle.position(), position);
+        assert_eq!(handle.clone().position(), position);
+    }
+}
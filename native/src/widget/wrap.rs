@@ -0,0 +1,344 @@
+//! Distribute content horizontally, wrapping it into multiple lines.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::{
+    Alignment, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::u32;
+
+/// A container that distributes its contents horizontally and wraps them to
+/// a new line when they exceed its width.
+///
+/// It is useful to lay out a variable amount of small elements, like the
+/// tags of a tag cloud.
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    spacing: u16,
+    line_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    align_items: Alignment,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`].
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            spacing: 0,
+            line_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_items: Alignment::Start,
+            children,
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ elements of the same line.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ lines.
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`Wrap`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Wrap`].
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Wrap`].
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of each line of the
+    /// [`Wrap`].
+    pub fn align_items(mut self, align: Alignment) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Wrap`].
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height)
+            .pad(self.padding);
+
+        let max_width = limits.max().width;
+        let spacing = f32::from(self.spacing);
+        let line_spacing = f32::from(self.line_spacing);
+
+        let child_limits = limits.loose();
+
+        let mut nodes: Vec<layout::Node> =
+            Vec::with_capacity(self.children.len());
+
+        let mut line_start = 0;
+        let mut x = 0.0f32;
+        let mut y = 0.0f32;
+        let mut line_height = 0.0f32;
+        let mut width = 0.0f32;
+
+        let align_line = |line: &mut [layout::Node], line_height: f32| {
+            for node in line {
+                node.align(
+                    Alignment::Start,
+                    self.align_items,
+                    Size::new(0.0, line_height),
+                );
+            }
+        };
+
+        for child in &self.children {
+            let mut node = child.layout(renderer, &child_limits);
+            let size = node.size();
+
+            if x > 0.0 && x + size.width > max_width {
+                align_line(&mut nodes[line_start..], line_height);
+
+                line_start = nodes.len();
+                x = 0.0;
+                y += line_height + line_spacing;
+                line_height = 0.0;
+            }
+
+            node.move_to(Point::new(
+                x + f32::from(self.padding.left),
+                y + f32::from(self.padding.top),
+            ));
+
+            width = width.max(x + size.width);
+            line_height = line_height.max(size.height);
+            x += size.width + spacing;
+
+            nodes.push(node);
+        }
+
+        align_line(&mut nodes[line_start..], line_height);
+
+        let size = limits.resolve(Size::new(width, y + line_height));
+
+        layout::Node::with_children(size.pad(self.padding), nodes)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status<Message> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .map(|(child, layout)| {
+                child
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        messages,
+                    )
+                    .push_feedback(messages)
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+            .cast()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor, viewport)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.align_items.hash(state);
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+        self.padding.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.children
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| child.widget.overlay(layout))
+            .next()
+    }
+}
+
+/// The renderer of a [`Wrap`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Wrap`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Wrap`].
+    ///
+    /// It receives:
+    /// - the children of the [`Wrap`]
+    /// - the [`Layout`] of the [`Wrap`] and its children
+    /// - the cursor position
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wrap: Wrap<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wrap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::{Container, Text};
+
+    #[test]
+    fn children_wrap_when_exceeding_the_width() {
+        let renderer = Null::new();
+
+        let wrap: Wrap<'_, (), Null> = Wrap::with_children(
+            (0..3)
+                .map(|_| {
+                    Container::new(Text::new("Tag"))
+                        .width(Length::Units(40))
+                        .height(Length::Units(20))
+                        .into()
+                })
+                .collect(),
+        )
+        .spacing(10)
+        .line_spacing(5);
+
+        let limits = layout::Limits::new(Size::ZERO, Size::new(100.0, 400.0));
+        let node = wrap.layout(&renderer, &limits);
+
+        let positions: Vec<_> = node
+            .children()
+            .iter()
+            .map(|child| child.bounds().position())
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                Point::new(0.0, 0.0),
+                Point::new(50.0, 0.0),
+                Point::new(0.0, 25.0),
+            ]
+        );
+        assert_eq!(node.size(), Size::new(90.0, 45.0));
+    }
+}
@@ -1,6 +1,10 @@
 //! Build window-based GUI applications.
 mod action;
 mod event;
+mod id;
+mod settings;
 
 pub use action::Action;
 pub use event::Event;
+pub use id::Id;
+pub use settings::Settings;
@@ -1,6 +1,15 @@
+use crate::window::Settings;
+
+use std::fmt;
+
 /// An operation to be performed on some window.
-#[derive(Debug)]
-pub enum Action {
+///
+/// The window is identified by the [`Id`] of the [`command::Action`] that
+/// carries the [`Action`].
+///
+/// [`Id`]: crate::window::Id
+/// [`command::Action`]: crate::command::Action
+pub enum Action<T> {
     /// Resize the window.
     Resize {
         /// The new logical width of the window
@@ -15,4 +24,76 @@ pub enum Action {
         /// The new logical y location of the window
         y: i32,
     },
+    /// Set the scale factor override of the window.
+    ///
+    /// The override is multiplied with the scale factor reported by the
+    /// windowing system to obtain the scale factor of the user interface.
+    SetScaleFactorOverride(f32),
+    /// Fetch the current scale factor override of the window and produce `T`
+    /// with the result.
+    FetchScaleFactorOverride(Box<dyn Fn(f32) -> T>),
+    /// Capture the pixels of the next frame drawn to the window and produce
+    /// `T` with them.
+    ///
+    /// The pixels are provided as RGBA bytes, row by row from the top, at the
+    /// physical size of the window.
+    CaptureFrame(Box<dyn FnOnce(Vec<u8>) -> T + Send>),
+    /// Open the window with the given [`Settings`].
+    ///
+    /// Only runtimes supporting multiple windows perform this action.
+    Spawn(Settings),
+    /// Close the window.
+    ///
+    /// Only runtimes supporting multiple windows perform this action.
+    Close,
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a window [`Action`] using the provided closure.
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Resize { width, height } => Action::Resize { width, height },
+            Self::Move { x, y } => Action::Move { x, y },
+            Self::SetScaleFactorOverride(scale_factor) => {
+                Action::SetScaleFactorOverride(scale_factor)
+            }
+            Self::FetchScaleFactorOverride(o) => {
+                Action::FetchScaleFactorOverride(Box::new(move |s| f(o(s))))
+            }
+            Self::CaptureFrame(o) => {
+                Action::CaptureFrame(Box::new(move |frame| f(o(frame))))
+            }
+            Self::Spawn(settings) => Action::Spawn(settings),
+            Self::Close => Action::Close,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resize { width, height } => write!(
+                f,
+                "Action::Resize {{ width: {}, height: {} }}",
+                width, height
+            ),
+            Self::Move { x, y } => {
+                write!(f, "Action::Move {{ x: {}, y: {} }}", x, y)
+            }
+            Self::SetScaleFactorOverride(scale_factor) => {
+                write!(f, "Action::SetScaleFactorOverride({})", scale_factor)
+            }
+            Self::FetchScaleFactorOverride(_) => {
+                write!(f, "Action::FetchScaleFactorOverride")
+            }
+            Self::CaptureFrame(_) => write!(f, "Action::CaptureFrame"),
+            Self::Spawn(settings) => {
+                write!(f, "Action::Spawn({:?})", settings)
+            }
+            Self::Close => write!(f, "Action::Close"),
+        }
+    }
 }
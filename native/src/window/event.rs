@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
@@ -48,4 +49,60 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// The window is about to be redrawn to animate its contents.
+    ///
+    /// It is emitted on the frame after a widget called
+    /// [`Renderer::request_redraw`] while being drawn, with the [`Instant`] of
+    /// that frame.
+    ///
+    /// [`Renderer::request_redraw`]: crate::Renderer::request_redraw
+    RedrawRequested(Instant),
+}
+
+impl Event {
+    /// Returns whether the window became active (`Some(true)`) or inactive
+    /// (`Some(false)`) with this [`Event`].
+    ///
+    /// A window is inactive while it is unfocused or minimized. Events that do
+    /// not change the activity of the window return `None`.
+    pub fn activity(&self) -> Option<bool> {
+        match self {
+            Event::Focused => Some(true),
+            Event::Unfocused => Some(false),
+            // Minimized windows are resized to zero on most platforms
+            Event::Resized {
+                width: 0,
+                height: 0,
+            } => Some(false),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimizing_and_unfocusing_deactivate_the_window() {
+        assert_eq!(Event::Focused.activity(), Some(true));
+        assert_eq!(Event::Unfocused.activity(), Some(false));
+        assert_eq!(
+            Event::Resized {
+                width: 0,
+                height: 0
+            }
+            .activity(),
+            Some(false)
+        );
+        assert_eq!(
+            Event::Resized {
+                width: 800,
+                height: 600
+            }
+            .activity(),
+            None
+        );
+    }
 }
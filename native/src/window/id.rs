@@ -0,0 +1,35 @@
+use std::sync::atomic::{self, AtomicU64};
+
+/// The identifier of a window.
+///
+/// The window opened by a runtime on startup is always [`Id::MAIN`]. Every
+/// other window gets a unique identifier when it is spawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+static COUNT: AtomicU64 = AtomicU64::new(1);
+
+impl Id {
+    /// The identifier of the main window.
+    pub const MAIN: Id = Id(0);
+
+    /// Creates a new unique [`Id`].
+    pub fn unique() -> Id {
+        Id(COUNT.fetch_add(1, atomic::Ordering::Relaxed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_ids_never_match_the_main_window() {
+        let a = Id::unique();
+        let b = Id::unique();
+
+        assert_ne!(a, b);
+        assert_ne!(a, Id::MAIN);
+        assert_ne!(b, Id::MAIN);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::frame::{Frame, Target};
+use crate::image;
+use crate::text;
+use crate::Settings;
+
+use iced_graphics::backend;
+use iced_graphics::font;
+use iced_graphics::{Layer, Primitive, Viewport};
+use iced_native::mouse;
+use iced_native::{Color, Font, Rectangle, Size};
+
+use std::cell::RefCell;
+
+/// A software graphics backend for [`iced`].
+///
+/// It rasterizes quads, meshes, text, images and SVGs on the CPU. Custom
+/// primitives are skipped, and backdrops are only tinted.
+///
+/// Raster and vector images need the `image` and `svg` features,
+/// respectively.
+///
+/// [`iced`]: https://github.com/hecrj/iced
+#[derive(Debug)]
+pub struct Backend {
+    text_pipeline: text::Pipeline,
+    image_pipeline: RefCell<image::Pipeline>,
+    default_text_size: u16,
+    samples: u32,
+}
+
+impl Backend {
+    /// Creates a new [`Backend`].
+    pub fn new(settings: Settings) -> Self {
+        Self {
+            text_pipeline: text::Pipeline::new(settings.default_font),
+            image_pipeline: RefCell::new(image::Pipeline::new()),
+            default_text_size: settings.default_text_size,
+            samples: settings
+                .antialiasing
+                .map(|antialiasing| antialiasing.sample_count())
+                .unwrap_or(1),
+        }
+    }
+
+    /// Draws the provided primitives in a new [`Frame`] of the physical size
+    /// of the [`Viewport`], cleared with the given background color.
+    ///
+    /// The text provided as overlay will be rendered on top of the primitives.
+    /// This is useful for rendering debug information.
+    pub fn draw<T: AsRef<str>>(
+        &mut self,
+        viewport: &Viewport,
+        background_color: Color,
+        (primitive, mouse_interaction): &(
+            Primitive<Backend>,
+            mouse::Interaction,
+        ),
+        overlay_text: &[T],
+    ) -> (Frame, mouse::Interaction) {
+        let viewport_size = viewport.physical_size();
+        let scale_factor = viewport.scale_factor() as f32;
+
+        let mut target = Target::new(
+            viewport_size.width,
+            viewport_size.height,
+            background_color,
+        );
+
+        let mut layers = Layer::generate(primitive, viewport);
+        layers.push(Layer::overlay(overlay_text, viewport));
+
+        for layer in layers {
+            self.flush(&mut target, scale_factor, &layer);
+        }
+
+        self.image_pipeline.get_mut().trim();
+
+        (target.into_frame(), mouse_interaction.clone())
+    }
+
+    fn flush(
+        &mut self,
+        target: &mut Target,
+        scale_factor: f32,
+        layer: &Layer<'_, Backend>,
+    ) {
+        let bounds = (layer.bounds * scale_factor).snap();
+
+        if let Some(backdrop) = &layer.backdrop {
+            target.fill_quad(&backdrop.tint_quad(), scale_factor, bounds);
+        }
+
+        for quad in &layer.quads {
+            target.fill_quad(quad, scale_factor, bounds);
+        }
+
+        for mesh in &layer.meshes {
+            if let Some(clip_bounds) =
+                mesh.clip_bounds.intersection(&layer.bounds)
+            {
+                target.fill_mesh(
+                    mesh,
+                    scale_factor,
+                    (clip_bounds * scale_factor).snap(),
+                    self.samples,
+                );
+            }
+        }
+
+        if !layer.textured_meshes.is_empty() {
+            log::warn!(
+                "Textured meshes are not supported by the software backend"
+            );
+        }
+
+        if !layer.videos.is_empty() {
+            log::warn!("Videos are not supported by the software backend");
+        }
+
+        for image in &layer.images {
+            self.image_pipeline.get_mut().draw(
+                target,
+                image,
+                scale_factor,
+                bounds,
+            );
+        }
+
+        for text in &layer.text {
+            self.text_pipeline.draw(target, text, scale_factor, bounds);
+        }
+    }
+}
+
+impl iced_graphics::Backend for Backend {
+    type CustomRenderPrimitive = ();
+
+    fn trim_measurements(&mut self) {
+        self.text_pipeline.trim_measurement_cache()
+    }
+}
+
+impl backend::Text for Backend {
+    const ICON_FONT: Font = font::ICONS;
+    const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
+    const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+
+    fn default_size(&self) -> u16 {
+        self.default_text_size
+    }
+
+    fn measure(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(contents, size, font, bounds)
+    }
+
+    fn hit_test(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<text::Hit> {
+        self.text_pipeline.hit_test(
+            contents,
+            size,
+            font,
+            bounds,
+            point,
+            nearest_only,
+        )
+    }
+
+    fn glyph_positions(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<Rectangle> {
+        self.text_pipeline
+            .glyph_positions(contents, size, font, bounds)
+    }
+}
+
+#[cfg(feature = "image")]
+impl backend::Image for Backend {
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
+        self.image_pipeline.borrow_mut().dimensions(handle)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl backend::Svg for Backend {
+    fn viewport_dimensions(
+        &self,
+        handle: &iced_native::svg::Handle,
+    ) -> (u32, u32) {
+        self.image_pipeline.borrow_mut().viewport_dimensions(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_native::alignment;
+    use iced_native::{
+        Background, BorderAlignment, BorderStyle, BorderWidth, WritingMode,
+    };
+
+    #[test]
+    fn quads_and_text_are_rasterized() {
+        let mut backend = Backend::new(Settings::default());
+        let viewport = Viewport::with_physical_size(Size::new(200, 100), 1.0);
+
+        let primitive = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        x: 10.0,
+                        y: 10.0,
+                        width: 40.0,
+                        height: 20.0,
+                    },
+                    background: Background::Color(Color::from_rgb(
+                        1.0, 0.0, 0.0,
+                    )),
+                    border_radius: 0.0,
+                    border_width: BorderWidth::ZERO,
+                    border_color: Color::TRANSPARENT,
+                    border_style: BorderStyle::Solid,
+                    border_alignment: BorderAlignment::Inside,
+                    border_offset: 0.0,
+                    fill: true,
+                },
+                Primitive::Text {
+                    content: String::from("iced"),
+                    bounds: Rectangle {
+                        x: 10.0,
+                        y: 50.0,
+                        width: 180.0,
+                        height: 40.0,
+                    },
+                    color: Color::BLACK,
+                    size: 40.0,
+                    font: Font::Default,
+                    horizontal_alignment: alignment::Horizontal::Left,
+                    vertical_alignment: alignment::Vertical::Top,
+                    letter_spacing: 0.0,
+                    writing_mode: WritingMode::Horizontal,
+                    background: None,
+                },
+            ],
+        };
+
+        let (frame, _) = backend.draw::<&str>(
+            &viewport,
+            Color::WHITE,
+            &(primitive, mouse::Interaction::Idle),
+            &[],
+        );
+
+        assert_eq!((frame.width(), frame.height()), (200, 100));
+
+        // The quad is filled, and it does not bleed out of its bounds
+        assert_eq!(frame.pixel(30, 20), [255, 0, 0, 255]);
+        assert_eq!(frame.pixel(9, 9), [255, 255, 255, 255]);
+        assert_eq!(frame.pixel(50, 30), [255, 255, 255, 255]);
+
+        // The text leaves dark glyphs in its bounds, and nothing below them
+        let text_pixels = (50..90)
+            .flat_map(|y| (10..190).map(move |x| (x, y)))
+            .map(|(x, y)| frame.pixel(x, y));
+
+        assert!(text_pixels
+            .clone()
+            .any(|[r, g, b, _]| r < 64 && g < 64 && b < 64));
+        assert!(text_pixels.any(|[r, _, _, _]| r == 255));
+        assert!((0..200).all(|x| frame.pixel(x, 95) == [255, 255, 255, 255]));
+    }
+}
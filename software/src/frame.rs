@@ -0,0 +1,507 @@
+use crate::quad::{
+    border_alpha, drop_shadow_alpha, inner_shadow_alpha, rounded_box_distance,
+};
+
+use iced_graphics::layer;
+use iced_native::{Color, Rectangle};
+
+/// The pixels drawn by a [`Backend`], in RGBA with 8 bits per channel.
+///
+/// The channels are in __sRGB__ and not premultiplied, like the pixels of a
+/// regular PNG image.
+///
+/// [`Backend`]: crate::Backend
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// Returns the width of the [`Frame`], in physical pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Frame`], in physical pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the RGBA channels of the pixel at the given physical
+    /// coordinates.
+    ///
+    /// # Panics
+    /// Panics if the coordinates are out of the bounds of the [`Frame`].
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height, "Pixel out of bounds");
+
+        let index = (y * self.width + x) as usize * 4;
+
+        [
+            self.pixels[index],
+            self.pixels[index + 1],
+            self.pixels[index + 2],
+            self.pixels[index + 3],
+        ]
+    }
+
+    /// Returns the RGBA bytes of the [`Frame`], row by row.
+    pub fn as_rgba(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Turns the [`Frame`] into its RGBA bytes, row by row.
+    pub fn into_rgba(self) -> Vec<u8> {
+        self.pixels
+    }
+}
+
+/// A surface where primitives are rasterized, with __linear__ RGBA pixels
+/// premultiplied by their alpha.
+///
+/// Blending in linear space matches the sRGB framebuffers of the GPU
+/// renderers.
+#[derive(Debug)]
+pub(crate) struct Target {
+    width: u32,
+    height: u32,
+    pixels: Vec<[f32; 4]>,
+}
+
+/// A rounded box, in physical coordinates, outside of which the pixels of a
+/// raster are discarded.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Mask {
+    pub bounds: Rectangle,
+    pub border_radius: f32,
+    pub opacity: f32,
+}
+
+impl Target {
+    pub fn new(width: u32, height: u32, background_color: Color) -> Self {
+        let [r, g, b, a] = background_color.into_linear();
+
+        Target {
+            width,
+            height,
+            pixels: vec![[r * a, g * a, b * a, a]; (width * height) as usize],
+        }
+    }
+
+    /// Blends a __linear__, non-premultiplied color over the pixel at the
+    /// given coordinates, scaling its alpha by some coverage.
+    pub fn blend(&mut self, x: u32, y: u32, color: [f32; 4], coverage: f32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        let alpha = color[3] * coverage.max(0.0).min(1.0);
+
+        if alpha <= 0.0 {
+            return;
+        }
+
+        let pixel = &mut self.pixels[(y * self.width + x) as usize];
+
+        for (channel, source) in pixel.iter_mut().zip(&color).take(3) {
+            *channel = source * alpha + *channel * (1.0 - alpha);
+        }
+
+        pixel[3] = alpha + pixel[3] * (1.0 - alpha);
+    }
+
+    /// Rasterizes a [`layer::Quad`] in logical coordinates, within some
+    /// physical clip bounds.
+    ///
+    /// Dashed and dotted borders are drawn solid.
+    pub fn fill_quad(
+        &mut self,
+        quad: &layer::Quad,
+        scale_factor: f32,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        let position = [
+            quad.position[0] * scale_factor,
+            quad.position[1] * scale_factor,
+        ];
+        let size = [quad.size[0] * scale_factor, quad.size[1] * scale_factor];
+        let border_radius = quad.border_radius * scale_factor;
+
+        let (x_range, y_range) = self.pixel_ranges(
+            clip_bounds,
+            Rectangle {
+                x: position[0],
+                y: position[1],
+                width: size[0],
+                height: size[1],
+            },
+        );
+
+        if quad.shadow_kind == layer::Quad::DROP_SHADOW {
+            for y in y_range {
+                for x in x_range.clone() {
+                    let alpha = drop_shadow_alpha(
+                        quad,
+                        [
+                            (x as f32 + 0.5) / scale_factor,
+                            (y as f32 + 0.5) / scale_factor,
+                        ],
+                    );
+
+                    self.blend(x, y, quad.shadow_color, alpha);
+                }
+            }
+
+            return;
+        }
+
+        for y in y_range {
+            for x in x_range.clone() {
+                let point = [x as f32 + 0.5, y as f32 + 0.5];
+
+                let distance =
+                    rounded_box_distance(point, position, size, border_radius);
+
+                let coverage = 0.5 - distance;
+
+                if coverage <= 0.0 {
+                    continue;
+                }
+
+                let mut color = quad.color;
+
+                if quad.shadow_color[3] > 0.0 {
+                    let shadow_alpha = inner_shadow_alpha(
+                        quad,
+                        [point[0] / scale_factor, point[1] / scale_factor],
+                    ) * quad.shadow_color[3];
+
+                    color = over(quad.shadow_color, shadow_alpha, color);
+                }
+
+                let border_mix = border_alpha(
+                    quad,
+                    [point[0] / scale_factor, point[1] / scale_factor],
+                );
+
+                if border_mix > 0.0 {
+                    color = mix(color, quad.border_color, border_mix);
+                }
+
+                self.blend(x, y, color, coverage);
+            }
+        }
+    }
+
+    /// Rasterizes a [`layer::Mesh`] in logical coordinates, within some
+    /// physical clip bounds.
+    ///
+    /// The edges of the triangles are antialiased with the given amount of
+    /// samples per pixel. A single sample disables antialiasing.
+    pub fn fill_mesh(
+        &mut self,
+        mesh: &layer::Mesh<'_>,
+        scale_factor: f32,
+        clip_bounds: Rectangle<u32>,
+        samples: u32,
+    ) {
+        let vertices = &mesh.buffers.vertices;
+        let (columns, rows) = sample_grid(samples);
+
+        let position = |index: u32| {
+            let vertex = vertices[index as usize].position;
+
+            [
+                (mesh.origin.x + vertex[0]) * scale_factor,
+                (mesh.origin.y + vertex[1]) * scale_factor,
+            ]
+        };
+
+        for triangle in mesh.buffers.indices.chunks_exact(3) {
+            let [a, b, c] = [
+                position(triangle[0]),
+                position(triangle[1]),
+                position(triangle[2]),
+            ];
+
+            let area = edge(a, b, c);
+
+            if area == 0.0 {
+                continue;
+            }
+
+            let min_x = a[0].min(b[0]).min(c[0]);
+            let min_y = a[1].min(b[1]).min(c[1]);
+
+            let (x_range, y_range) = self.pixel_ranges(
+                clip_bounds,
+                Rectangle {
+                    x: min_x,
+                    y: min_y,
+                    width: a[0].max(b[0]).max(c[0]) - min_x,
+                    height: a[1].max(b[1]).max(c[1]) - min_y,
+                },
+            );
+
+            for y in y_range {
+                for x in x_range.clone() {
+                    let is_inside = |point: [f32; 2]| {
+                        edge(b, c, point) / area >= 0.0
+                            && edge(c, a, point) / area >= 0.0
+                            && edge(a, b, point) / area >= 0.0
+                    };
+
+                    let mut covered = 0;
+
+                    for row in 0..rows {
+                        for column in 0..columns {
+                            let sample = [
+                                x as f32
+                                    + (column as f32 + 0.5) / columns as f32,
+                                y as f32 + (row as f32 + 0.5) / rows as f32,
+                            ];
+
+                            if is_inside(sample) {
+                                covered += 1;
+                            }
+                        }
+                    }
+
+                    if covered == 0 {
+                        continue;
+                    }
+
+                    // The color is interpolated at the center of the pixel
+                    let point = [x as f32 + 0.5, y as f32 + 0.5];
+
+                    let weights = [
+                        edge(b, c, point) / area,
+                        edge(c, a, point) / area,
+                        edge(a, b, point) / area,
+                    ];
+
+                    let mut color = [0.0; 4];
+
+                    for (index, weight) in triangle.iter().zip(&weights) {
+                        let vertex = vertices[*index as usize].color;
+
+                        for (channel, source) in color.iter_mut().zip(&vertex) {
+                            *channel += source * weight;
+                        }
+                    }
+
+                    for channel in &mut color {
+                        *channel = channel.max(0.0).min(1.0);
+                    }
+
+                    self.blend(
+                        x,
+                        y,
+                        color,
+                        covered as f32 / (columns * rows) as f32,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Draws some __sRGB__, non-premultiplied RGBA pixels stretched over some
+    /// physical bounds, within some clip bounds and an optional [`Mask`].
+    ///
+    /// The pixels are filtered bilinearly.
+    pub fn draw_raster(
+        &mut self,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+        bounds: Rectangle,
+        mask: Option<Mask>,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        if width == 0
+            || height == 0
+            || bounds.width <= 0.0
+            || bounds.height <= 0.0
+        {
+            return;
+        }
+
+        // Texels are filtered premultiplied, so transparent ones do not
+        // bleed their color
+        let texel = |x: u32, y: u32| {
+            let index = (y * width + x) as usize * 4;
+
+            let [r, g, b, a] = Color::from_rgba8(
+                pixels[index],
+                pixels[index + 1],
+                pixels[index + 2],
+                f32::from(pixels[index + 3]) / 255.0,
+            )
+            .into_linear();
+
+            [r * a, g * a, b * a, a]
+        };
+
+        let (x_range, y_range) = self.pixel_ranges(clip_bounds, bounds);
+
+        let coverage = |x: u32, y: u32| match mask {
+            Some(mask) => {
+                let distance = rounded_box_distance(
+                    [x as f32 + 0.5, y as f32 + 0.5],
+                    [mask.bounds.x, mask.bounds.y],
+                    [mask.bounds.width, mask.bounds.height],
+                    mask.border_radius,
+                );
+
+                (0.5 - distance).min(1.0) * mask.opacity
+            }
+            None => 1.0,
+        };
+
+        for y in y_range {
+            let v = ((y as f32 + 0.5 - bounds.y) / bounds.height
+                * height as f32
+                - 0.5)
+                .max(0.0)
+                .min((height - 1) as f32);
+
+            let y0 = v.floor() as u32;
+            let y1 = (y0 + 1).min(height - 1);
+
+            for x in x_range.clone() {
+                let coverage = coverage(x, y);
+
+                if coverage <= 0.0 {
+                    continue;
+                }
+
+                let u = ((x as f32 + 0.5 - bounds.x) / bounds.width
+                    * width as f32
+                    - 0.5)
+                    .max(0.0)
+                    .min((width - 1) as f32);
+
+                let x0 = u.floor() as u32;
+                let x1 = (x0 + 1).min(width - 1);
+
+                let top = mix(texel(x0, y0), texel(x1, y0), u - x0 as f32);
+                let bottom = mix(texel(x0, y1), texel(x1, y1), u - x0 as f32);
+
+                let [r, g, b, a] = mix(top, bottom, v - y0 as f32);
+
+                if a > 0.0 {
+                    self.blend(x, y, [r / a, g / a, b / a, a], coverage);
+                }
+            }
+        }
+    }
+
+    /// Returns the ranges of physical pixels covered by some physical bounds,
+    /// within some clip bounds and the [`Target`].
+    pub fn pixel_ranges(
+        &self,
+        clip_bounds: Rectangle<u32>,
+        bounds: Rectangle,
+    ) -> (std::ops::Range<u32>, std::ops::Range<u32>) {
+        let range = |start: f32, end: f32, clip_start: u32, clip_end: u32| {
+            let start = (start.floor().max(0.0) as u32).max(clip_start);
+            let end = (end.ceil().max(0.0) as u32).min(clip_end);
+
+            start..end.max(start)
+        };
+
+        (
+            range(
+                bounds.x,
+                bounds.x + bounds.width,
+                clip_bounds.x,
+                (clip_bounds.x + clip_bounds.width).min(self.width),
+            ),
+            range(
+                bounds.y,
+                bounds.y + bounds.height,
+                clip_bounds.y,
+                (clip_bounds.y + clip_bounds.height).min(self.height),
+            ),
+        )
+    }
+
+    /// Converts the [`Target`] into an sRGB [`Frame`].
+    pub fn into_frame(self) -> Frame {
+        let mut pixels = Vec::with_capacity(self.pixels.len() * 4);
+
+        for [r, g, b, a] in self.pixels {
+            let unpremultiply =
+                |channel: f32| if a > 0.0 { channel / a } else { 0.0 };
+
+            pixels.extend_from_slice(&[
+                to_srgb8(unpremultiply(r)),
+                to_srgb8(unpremultiply(g)),
+                to_srgb8(unpremultiply(b)),
+                (a.max(0.0).min(1.0) * 255.0).round() as u8,
+            ]);
+        }
+
+        Frame {
+            width: self.width,
+            height: self.height,
+            pixels,
+        }
+    }
+}
+
+/// Returns the columns and rows of the grid of samples taken in a pixel.
+fn sample_grid(samples: u32) -> (u32, u32) {
+    match samples {
+        0 | 1 => (1, 1),
+        2 => (2, 1),
+        3..=4 => (2, 2),
+        5..=8 => (4, 2),
+        _ => (4, 4),
+    }
+}
+
+/// Returns twice the signed area of the triangle `a`, `b`, `c`.
+fn edge(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> f32 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+fn mix(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ]
+}
+
+/// Composites a color with the given alpha over another one, without
+/// premultiplied alpha.
+fn over(top: [f32; 4], alpha: f32, bottom: [f32; 4]) -> [f32; 4] {
+    let result_alpha = alpha + bottom[3] * (1.0 - alpha);
+
+    if result_alpha <= 0.0 {
+        return [0.0; 4];
+    }
+
+    let channel = |index: usize| {
+        (top[index] * alpha + bottom[index] * bottom[3] * (1.0 - alpha))
+            / result_alpha
+    };
+
+    [channel(0), channel(1), channel(2), result_alpha]
+}
+
+fn to_srgb8(linear: f32) -> u8 {
+    let linear = linear.max(0.0).min(1.0);
+
+    let srgb = if linear <= 0.0031308 {
+        linear * 12.92
+    } else {
+        1.055 * linear.powf(1.0 / 2.4) - 0.055
+    };
+
+    (srgb * 255.0).round() as u8
+}
@@ -0,0 +1,435 @@
+use crate::frame::Target;
+
+use iced_graphics::layer;
+use iced_native::Rectangle;
+
+#[cfg(feature = "image")]
+use crate::frame::Mask;
+
+#[cfg(feature = "image")]
+use iced_native::{image, Size};
+
+#[cfg(feature = "svg")]
+use iced_native::svg;
+
+use std::collections::{HashMap, HashSet};
+
+/// Decoded pixels in __sRGB__ RGBA, not premultiplied.
+#[derive(Debug)]
+pub struct Raster {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// Creates a [`Raster`] from some BGRA pixels.
+    #[cfg(feature = "image")]
+    fn from_bgra(width: u32, height: u32, mut pixels: Vec<u8>) -> Self {
+        pixels
+            .chunks_exact_mut(4)
+            .for_each(|pixel| pixel.swap(0, 2));
+
+        Raster {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// A parsed vector image.
+#[cfg(feature = "svg")]
+struct Svg(usvg::Tree);
+
+#[cfg(feature = "svg")]
+impl std::fmt::Debug for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Svg")
+    }
+}
+
+/// Decodes and rasterizes the images of a [`Backend`], caching the results
+/// between frames.
+///
+/// Entries that are not drawn in a frame are dropped on [`trim`].
+///
+/// [`Backend`]: crate::Backend
+/// [`trim`]: Pipeline::trim
+#[derive(Debug)]
+pub struct Pipeline {
+    #[cfg(feature = "image")]
+    rasters: HashMap<u64, Option<Raster>>,
+
+    #[cfg(feature = "svg")]
+    svgs: HashMap<u64, Option<Svg>>,
+
+    #[cfg(feature = "svg")]
+    rasterized_svgs: HashMap<(u64, u32, u32), Option<Raster>>,
+
+    hits: HashSet<u64>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Pipeline {
+            #[cfg(feature = "image")]
+            rasters: HashMap::new(),
+
+            #[cfg(feature = "svg")]
+            svgs: HashMap::new(),
+
+            #[cfg(feature = "svg")]
+            rasterized_svgs: HashMap::new(),
+
+            hits: HashSet::new(),
+        }
+    }
+
+    /// Returns the dimensions of a raster image, or `(1, 1)` if it could not
+    /// be loaded.
+    #[cfg(feature = "image")]
+    pub fn dimensions(&mut self, handle: &image::Handle) -> (u32, u32) {
+        self.load_raster(handle)
+            .map(|raster| (raster.width, raster.height))
+            .unwrap_or((1, 1))
+    }
+
+    /// Returns the viewport dimensions of a vector image, or `(1, 1)` if it
+    /// could not be parsed.
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(&mut self, handle: &svg::Handle) -> (u32, u32) {
+        self.load_svg(handle)
+            .map(|tree| {
+                let size = tree.svg_node().size;
+
+                (size.width() as u32, size.height() as u32)
+            })
+            .unwrap_or((1, 1))
+    }
+
+    /// Draws a [`layer::Image`] in logical coordinates, within some physical
+    /// clip bounds.
+    pub fn draw(
+        &mut self,
+        target: &mut Target,
+        image: &layer::Image,
+        scale_factor: f32,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        match image {
+            layer::Image::Raster { handle, bounds } => {
+                #[cfg(feature = "image")]
+                {
+                    if let Some(raster) = self.load_raster(handle) {
+                        target.draw_raster(
+                            raster.width,
+                            raster.height,
+                            &raster.pixels,
+                            *bounds * scale_factor,
+                            None,
+                            clip_bounds,
+                        );
+                    }
+                }
+
+                #[cfg(not(feature = "image"))]
+                {
+                    let _ = (handle, bounds, target, scale_factor, clip_bounds);
+
+                    log::warn!(
+                        "Raster images need the `image` feature of the \
+                        software backend"
+                    );
+                }
+            }
+            layer::Image::Vector { handle, bounds } => {
+                #[cfg(feature = "svg")]
+                {
+                    let bounds = *bounds * scale_factor;
+
+                    if let Some(raster) = self.rasterize_svg(
+                        handle,
+                        bounds.width.ceil() as u32,
+                        bounds.height.ceil() as u32,
+                    ) {
+                        target.draw_raster(
+                            raster.width,
+                            raster.height,
+                            &raster.pixels,
+                            bounds,
+                            None,
+                            clip_bounds,
+                        );
+                    }
+                }
+
+                #[cfg(not(feature = "svg"))]
+                {
+                    let _ = (handle, bounds, target, scale_factor, clip_bounds);
+
+                    log::warn!(
+                        "Vector images need the `svg` feature of the \
+                        software backend"
+                    );
+                }
+            }
+            layer::Image::Background {
+                handle,
+                bounds,
+                border_radius,
+                pattern,
+                opacity,
+            } => {
+                #[cfg(feature = "image")]
+                {
+                    if let Some(raster) = self.load_raster(handle) {
+                        let mask = Mask {
+                            bounds: *bounds * scale_factor,
+                            border_radius: border_radius * scale_factor,
+                            opacity: *opacity,
+                        };
+
+                        let size = Size::new(
+                            raster.width as f32,
+                            raster.height as f32,
+                        );
+
+                        for tile in pattern.tiles(*bounds, size) {
+                            target.draw_raster(
+                                raster.width,
+                                raster.height,
+                                &raster.pixels,
+                                tile * scale_factor,
+                                Some(mask),
+                                clip_bounds,
+                            );
+                        }
+                    }
+                }
+
+                #[cfg(not(feature = "image"))]
+                {
+                    let _ = (
+                        handle,
+                        bounds,
+                        border_radius,
+                        pattern,
+                        opacity,
+                        target,
+                        scale_factor,
+                        clip_bounds,
+                    );
+
+                    log::warn!(
+                        "Image backgrounds need the `image` feature of the \
+                        software backend"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Drops the entries that were not used since the last call.
+    pub fn trim(&mut self) {
+        let hits = &self.hits;
+
+        #[cfg(feature = "image")]
+        self.rasters.retain(|id, _| hits.contains(id));
+
+        #[cfg(feature = "svg")]
+        {
+            self.svgs.retain(|id, _| hits.contains(id));
+            self.rasterized_svgs
+                .retain(|(id, _, _), _| hits.contains(id));
+        }
+
+        self.hits.clear();
+    }
+
+    #[cfg(feature = "image")]
+    fn load_raster(&mut self, handle: &image::Handle) -> Option<&Raster> {
+        let _ = self.hits.insert(handle.id());
+
+        self.rasters
+            .entry(handle.id())
+            .or_insert_with(|| decode(handle.data()))
+            .as_ref()
+    }
+
+    #[cfg(feature = "svg")]
+    fn load_svg(&mut self, handle: &svg::Handle) -> Option<&usvg::Tree> {
+        let _ = self.hits.insert(handle.id());
+
+        self.svgs
+            .entry(handle.id())
+            .or_insert_with(|| parse(handle.data()).map(Svg))
+            .as_ref()
+            .map(|svg| &svg.0)
+    }
+
+    #[cfg(feature = "svg")]
+    fn rasterize_svg(
+        &mut self,
+        handle: &svg::Handle,
+        width: u32,
+        height: u32,
+    ) -> Option<&Raster> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let key = (handle.id(), width, height);
+
+        if !self.rasterized_svgs.contains_key(&key) {
+            let raster = self.load_svg(handle).and_then(|tree| {
+                let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+                let _ = resvg::render(
+                    tree,
+                    if width > height {
+                        usvg::FitTo::Width(width)
+                    } else {
+                        usvg::FitTo::Height(height)
+                    },
+                    pixmap.as_mut(),
+                )?;
+
+                Some(Raster {
+                    width,
+                    height,
+                    pixels: unpremultiply(pixmap.take()),
+                })
+            });
+
+            let _ = self.rasterized_svgs.insert(key, raster);
+        }
+
+        self.rasterized_svgs.get(&key).and_then(Option::as_ref)
+    }
+}
+
+#[cfg(feature = "image")]
+fn decode(data: &image::Data) -> Option<Raster> {
+    let image = match data {
+        image::Data::Path(path) => image_rs::open(path).ok()?,
+        image::Data::Bytes(bytes) => image_rs::load_from_memory(bytes).ok()?,
+        image::Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => {
+            if pixels.len() != *width as usize * *height as usize * 4 {
+                return None;
+            }
+
+            return Some(Raster::from_bgra(*width, *height, pixels.clone()));
+        }
+        image::Data::Indexed { width, height, .. } => {
+            let pixels = data.expand_palette()?;
+
+            return Some(Raster::from_bgra(*width, *height, pixels));
+        }
+    };
+
+    let image = image.to_rgba8();
+
+    Some(Raster {
+        width: image.width(),
+        height: image.height(),
+        pixels: image.into_raw(),
+    })
+}
+
+#[cfg(feature = "svg")]
+fn parse(data: &svg::Data) -> Option<usvg::Tree> {
+    let options = usvg::Options::default();
+
+    match data {
+        svg::Data::Path(path) => {
+            let contents = std::fs::read_to_string(path).ok()?;
+
+            usvg::Tree::from_str(&contents, &options.to_ref()).ok()
+        }
+        svg::Data::Bytes(bytes) => {
+            usvg::Tree::from_data(bytes, &options.to_ref()).ok()
+        }
+    }
+}
+
+/// Converts the premultiplied pixels of a `tiny_skia::Pixmap` into straight
+/// alpha.
+#[cfg(feature = "svg")]
+fn unpremultiply(mut pixels: Vec<u8>) -> Vec<u8> {
+    for pixel in pixels.chunks_exact_mut(4) {
+        let alpha = pixel[3];
+
+        if alpha > 0 && alpha < 255 {
+            for channel in &mut pixel[..3] {
+                *channel = (u16::from(*channel) * 255 / u16::from(alpha))
+                    .min(255) as u8;
+            }
+        }
+    }
+
+    pixels
+}
+
+#[cfg(all(test, feature = "image"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bgra_pixels_are_decoded_as_rgba() {
+        let handle = image::Handle::from_pixels(
+            1,
+            2,
+            vec![0, 0, 255, 255, 255, 0, 0, 128],
+        );
+
+        let mut pipeline = Pipeline::new();
+
+        assert_eq!(pipeline.dimensions(&handle), (1, 2));
+        assert_eq!(
+            pipeline.load_raster(&handle).unwrap().pixels,
+            vec![255, 0, 0, 255, 0, 0, 255, 128]
+        );
+    }
+
+    #[test]
+    fn image_backgrounds_are_tiled_within_their_rounded_corners() {
+        use crate::frame::Target;
+        use iced_graphics::layer::Pattern;
+        use iced_native::{Color, Point};
+
+        let handle = image::Handle::from_pixels(1, 1, vec![0, 0, 255, 255]);
+        let bounds = Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0));
+
+        let mut pipeline = Pipeline::new();
+        let mut target = Target::new(10, 10, Color::TRANSPARENT);
+
+        pipeline.draw(
+            &mut target,
+            &layer::Image::Background {
+                handle,
+                bounds,
+                border_radius: 5.0,
+                pattern: Pattern::Tiled(2.0),
+                opacity: 0.5,
+            },
+            1.0,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: 10,
+                height: 10,
+            },
+        );
+
+        let frame = target.into_frame();
+
+        assert_eq!(frame.pixel(0, 0), [0, 0, 0, 0]);
+        assert_eq!(frame.pixel(5, 5), [255, 0, 0, 128]);
+        assert_eq!(frame.pixel(8, 5), [255, 0, 0, 128]);
+    }
+}
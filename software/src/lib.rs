@@ -0,0 +1,51 @@
+//! A software renderer for [`iced_native`].
+//!
+//! It draws the same [`Primitive`] tree as the GPU renderers, on the CPU, into
+//! an RGBA [`Frame`]. It needs no GPU nor window, which makes it a good fit for
+//! headless environments and deterministic snapshot tests.
+//!
+//! Its [`window::Compositor`] presents the frames on a window with
+//! [`softbuffer`], for machines where the GPU renderers cannot run. With the
+//! `wgpu` feature, it draws them with [`iced_wgpu`] instead whenever a
+//! graphics adapter can be used, and only falls back to the CPU otherwise.
+//! Custom `wgpu` primitives are not drawn in that mode, and images need the
+//! matching features of [`iced_wgpu`] to be enabled as well.
+//!
+//! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+//! [`Primitive`]: iced_graphics::Primitive
+//! [`softbuffer`]: https://github.com/john01dav/softbuffer
+//! [`iced_wgpu`]: https://github.com/hecrj/iced/tree/master/wgpu
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![deny(unused_results)]
+#![forbid(rust_2018_idioms)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod backend;
+mod frame;
+mod image;
+mod quad;
+mod text;
+
+pub mod settings;
+pub mod widget;
+pub mod window;
+
+pub use backend::Backend;
+pub use frame::Frame;
+pub use settings::Settings;
+
+#[doc(no_inline)]
+pub use widget::*;
+
+pub use iced_graphics::{
+    Error, Hinting, Primitive, Styleable, Theme, Viewport,
+};
+
+pub use iced_native::alignment;
+pub use iced_native::{Alignment, Background, Color, Command, Length, Vector};
+
+/// A software graphics renderer for [`iced`].
+///
+/// [`iced`]: https://github.com/hecrj/iced
+pub type Renderer = iced_graphics::Renderer<Backend>;
@@ -0,0 +1,261 @@
+use iced_graphics::layer;
+
+/// Returns the opacity of the inner shadow of a [`layer::Quad`] at the given
+/// position, before applying the alpha of its shadow color.
+pub fn inner_shadow_alpha(quad: &layer::Quad, position: [f32; 2]) -> f32 {
+    // Distance to the edge of the quad shifted by the shadow offset,
+    // negative inside
+    let distance = rounded_box_distance(
+        position,
+        [
+            quad.position[0] + quad.shadow_offset[0],
+            quad.position[1] + quad.shadow_offset[1],
+        ],
+        quad.size,
+        quad.border_radius,
+    );
+
+    if quad.shadow_blur > 0.0 {
+        smoothstep(-quad.shadow_blur, quad.shadow_blur, distance)
+    } else if distance >= 0.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Returns the opacity of the drop shadow of a [`layer::Quad`] at the given
+/// position, before applying the alpha of its shadow color.
+///
+/// The shadow is cast by the box left inside the quad when shrunk by its
+/// shadow blur, which already includes the shadow offset.
+pub fn drop_shadow_alpha(quad: &layer::Quad, position: [f32; 2]) -> f32 {
+    let blur = quad.shadow_blur;
+    let size = [quad.size[0] - blur * 2.0, quad.size[1] - blur * 2.0];
+
+    // Distance to the edge of the casting box, negative inside
+    let distance = rounded_box_distance(
+        position,
+        [quad.position[0] + blur, quad.position[1] + blur],
+        size,
+        quad.border_radius,
+    );
+
+    if blur > 0.0 {
+        1.0 - smoothstep(-blur, blur, distance)
+    } else if distance <= 0.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Returns the opacity of the border of a [`layer::Quad`] at the given
+/// position, before applying its dash pattern and the alpha of its border
+/// color.
+pub fn border_alpha(quad: &layer::Quad, position: [f32; 2]) -> f32 {
+    let [top, right, bottom, left] = quad.border_width;
+    let max_width = top.max(right).max(bottom).max(left);
+
+    if max_width <= 0.0 {
+        return 0.0;
+    }
+
+    let radius = quad.border_radius.min(quad.size[0].min(quad.size[1]) / 2.0);
+    let internal_radius = (radius - max_width).max(0.0);
+
+    // Distance to the box left inside of the border
+    let internal_distance = distance_alg(
+        position,
+        [quad.position[0] + left, quad.position[1] + top],
+        [quad.size[0] - left - right, quad.size[1] - top - bottom],
+        internal_radius,
+    );
+
+    smoothstep(
+        (internal_radius - 0.5).max(0.0),
+        internal_radius + 0.5,
+        internal_distance,
+    )
+}
+
+/// Returns the signed distance from a point to a rounded box, negative
+/// inside.
+pub fn rounded_box_distance(
+    point: [f32; 2],
+    position: [f32; 2],
+    size: [f32; 2],
+    border_radius: f32,
+) -> f32 {
+    let radius = border_radius.min(size[0].min(size[1]) / 2.0);
+    let half_size = [size[0] / 2.0, size[1] / 2.0];
+
+    let q = [
+        (point[0] - position[0] - half_size[0]).abs() - half_size[0] + radius,
+        (point[1] - position[1] - half_size[1]).abs() - half_size[1] + radius,
+    ];
+
+    q[0].max(0.0).hypot(q[1].max(0.0)) + q[0].max(q[1]).min(0.0) - radius
+}
+
+/// Returns the distance from a position to a rounded box, zero inside.
+fn distance_alg(
+    position: [f32; 2],
+    origin: [f32; 2],
+    size: [f32; 2],
+    radius: f32,
+) -> f32 {
+    let top_left = [origin[0] + radius, origin[1] + radius];
+    let bottom_right =
+        [origin[0] + size[0] - radius, origin[1] + size[1] - radius];
+
+    let distance = [
+        (top_left[0] - position[0])
+            .max(position[0] - bottom_right[0])
+            .max(0.0),
+        (top_left[1] - position[1])
+            .max(position[1] - bottom_right[1])
+            .max(0.0),
+    ];
+
+    distance[0].hypot(distance[1])
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).max(0.0).min(1.0);
+
+    t * t * (3.0 - 2.0 * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced_graphics::{Layer, Primitive, Viewport};
+    use iced_native::{
+        Background, BorderAlignment, BorderStyle, BorderWidth, Color, Point,
+        Rectangle, Size, Vector,
+    };
+
+    fn quads(primitive: Primitive<crate::Backend>) -> Vec<layer::Quad> {
+        let viewport = Viewport::with_physical_size(Size::new(200, 200), 1.0);
+        let layers = Layer::generate(&primitive, &viewport);
+
+        layers[0].quads.clone()
+    }
+
+    fn quad(primitive: Primitive<crate::Backend>) -> layer::Quad {
+        quads(primitive)[0]
+    }
+
+    fn inner_shadow(blur_radius: f32) -> layer::Quad {
+        quad(Primitive::InnerShadow {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            border_radius: 0.0,
+            color: Color::BLACK,
+            offset: Vector::new(4.0, 4.0),
+            blur_radius,
+        })
+    }
+
+    #[test]
+    fn only_the_top_edge_of_a_border_is_drawn_when_the_others_are_zero() {
+        let quad = quad(Primitive::Quad {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0,
+            border_width: BorderWidth::from([2.0, 0.0, 0.0, 0.0]),
+            border_color: Color::BLACK,
+            border_style: BorderStyle::Solid,
+            border_alignment: BorderAlignment::Inside,
+            border_offset: 0.0,
+            fill: true,
+        });
+
+        assert_eq!(border_alpha(&quad, [50.0, 1.0]), 1.0);
+        assert_eq!(border_alpha(&quad, [50.0, 50.0]), 0.0);
+        assert_eq!(border_alpha(&quad, [99.0, 50.0]), 0.0);
+        assert_eq!(border_alpha(&quad, [50.0, 99.0]), 0.0);
+        assert_eq!(border_alpha(&quad, [1.0, 50.0]), 0.0);
+    }
+
+    #[test]
+    fn borders_are_aligned_with_the_bounds() {
+        let aligned = |border_alignment| {
+            quads(Primitive::Quad {
+                bounds: Rectangle::new(
+                    Point::new(10.0, 10.0),
+                    Size::new(50.0, 30.0),
+                ),
+                background: Background::Color(Color::WHITE),
+                border_radius: 0.0,
+                border_width: BorderWidth::new(2.0),
+                border_color: Color::BLACK,
+                border_style: BorderStyle::Solid,
+                border_alignment,
+                border_offset: 0.0,
+                fill: true,
+            })
+        };
+
+        // The border covers the edges of the bounds, and the fill the rest
+        let inside = aligned(BorderAlignment::Inside);
+
+        assert_eq!(border_alpha(&inside[0], [11.0, 20.0]), 1.0);
+        assert_eq!(border_alpha(&inside[0], [13.0, 20.0]), 0.0);
+
+        // The border surrounds the fill, drawn on its own
+        let outside = aligned(BorderAlignment::Outside);
+
+        assert_eq!(border_alpha(&outside[0], [11.0, 20.0]), 0.0);
+        assert_eq!(border_alpha(&outside[1], [9.0, 20.0]), 1.0);
+        assert_eq!(border_alpha(&outside[1], [11.0, 20.0]), 0.0);
+    }
+
+    #[test]
+    fn inner_shadow_without_blur_is_crisp() {
+        let quad = inner_shadow(0.0);
+
+        // An inset band as wide as the offset along the top and left edges
+        assert_eq!(inner_shadow_alpha(&quad, [50.0, 1.0]), 1.0);
+        assert_eq!(inner_shadow_alpha(&quad, [1.0, 50.0]), 1.0);
+        assert_eq!(inner_shadow_alpha(&quad, [50.0, 3.9]), 1.0);
+        assert_eq!(inner_shadow_alpha(&quad, [50.0, 4.1]), 0.0);
+        assert_eq!(inner_shadow_alpha(&quad, [50.0, 50.0]), 0.0);
+
+        // No shadow on the opposite edges
+        assert_eq!(inner_shadow_alpha(&quad, [99.0, 50.0]), 0.0);
+        assert_eq!(inner_shadow_alpha(&quad, [50.0, 99.0]), 0.0);
+    }
+
+    #[test]
+    fn inner_shadow_with_blur_is_soft() {
+        let quad = inner_shadow(4.0);
+
+        let edge = inner_shadow_alpha(&quad, [50.0, 4.0]);
+        let inside = inner_shadow_alpha(&quad, [50.0, 6.0]);
+
+        assert!(edge > 0.0 && edge < 1.0);
+        assert!(inside > 0.0 && inside < edge);
+        assert_eq!(inner_shadow_alpha(&quad, [50.0, 50.0]), 0.0);
+    }
+
+    #[test]
+    fn drop_shadows_cover_the_blur_around_the_offset_box() {
+        let quad = quad(Primitive::Shadow {
+            bounds: Rectangle::new(
+                Point::new(10.0, 10.0),
+                Size::new(80.0, 40.0),
+            ),
+            border_radius: 4.0,
+            color: Color::BLACK,
+            offset: Vector::new(0.0, 2.0),
+            blur_radius: 6.0,
+        });
+
+        // Opaque beneath the box, half transparent at its edge and gone at
+        // the end of the blur
+        assert_eq!(drop_shadow_alpha(&quad, [50.0, 32.0]), 1.0);
+        assert_eq!(drop_shadow_alpha(&quad, [50.0, 52.0]), 0.5);
+        assert_eq!(drop_shadow_alpha(&quad, [50.0, 58.0]), 0.0);
+    }
+}
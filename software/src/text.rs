@@ -0,0 +1,345 @@
+use crate::frame::Target;
+
+use iced_graphics::font;
+use iced_graphics::layer;
+use iced_native::alignment;
+use iced_native::Rectangle;
+
+use glyph_brush::ab_glyph;
+use std::{cell::RefCell, collections::HashMap};
+
+pub use iced_native::text::Hit;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    font_map: RefCell<HashMap<String, glyph_brush::FontId>>,
+    measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+}
+
+impl Pipeline {
+    pub fn new(default_font: Option<&[u8]>) -> Self {
+        let default_font = default_font.map(|slice| slice.to_vec());
+
+        // TODO: Font customization
+        #[cfg(feature = "default_system_font")]
+        let default_font = {
+            default_font.or_else(|| {
+                font::Source::new()
+                    .load(&[font::Family::SansSerif, font::Family::Serif])
+                    .ok()
+            })
+        };
+
+        let default_font =
+            default_font.unwrap_or_else(|| font::FALLBACK.to_vec());
+
+        let font = ab_glyph::FontArc::try_from_vec(default_font)
+            .unwrap_or_else(|_| {
+                log::warn!(
+                    "System font failed to load. Falling back to \
+                    embedded font..."
+                );
+
+                ab_glyph::FontArc::try_from_slice(font::FALLBACK)
+                    .expect("Load fallback font")
+            });
+
+        let measure_brush =
+            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+
+        Pipeline {
+            font_map: RefCell::new(HashMap::new()),
+            measure_brush: RefCell::new(measure_brush),
+        }
+    }
+
+    /// Rasterizes a [`layer::Text`] in logical coordinates, within some
+    /// physical clip bounds.
+    ///
+    /// The glyphs are laid out by the same brush that measures text, so they
+    /// land exactly where the widgets expect them.
+    pub fn draw(
+        &self,
+        target: &mut Target,
+        text: &layer::Text<'_>,
+        scale_factor: f32,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        use ab_glyph::Font;
+        use glyph_brush::GlyphCruncher;
+
+        let font_id = self.find_font(text.font);
+
+        let section = glyph_brush::Section {
+            screen_position: (
+                (text.bounds.x * scale_factor).round(),
+                (text.bounds.y * scale_factor).round(),
+            ),
+            bounds: (
+                (text.bounds.width * scale_factor).ceil(),
+                (text.bounds.height * scale_factor).ceil(),
+            ),
+            text: vec![glyph_brush::Text {
+                text: text.content,
+                scale: (text.size * scale_factor).into(),
+                font_id,
+                extra: glyph_brush::Extra::default(),
+            }],
+            layout: glyph_brush::Layout::default()
+                .h_align(match text.horizontal_alignment {
+                    alignment::Horizontal::Left => {
+                        glyph_brush::HorizontalAlign::Left
+                    }
+                    alignment::Horizontal::Center => {
+                        glyph_brush::HorizontalAlign::Center
+                    }
+                    alignment::Horizontal::Right => {
+                        glyph_brush::HorizontalAlign::Right
+                    }
+                })
+                .v_align(match text.vertical_alignment {
+                    alignment::Vertical::Top => glyph_brush::VerticalAlign::Top,
+                    alignment::Vertical::Center => {
+                        glyph_brush::VerticalAlign::Center
+                    }
+                    alignment::Vertical::Bottom => {
+                        glyph_brush::VerticalAlign::Bottom
+                    }
+                }),
+        };
+
+        let mut brush = self.measure_brush.borrow_mut();
+        let fonts = brush.fonts().to_vec();
+
+        for section_glyph in brush.glyphs(section) {
+            let font = &fonts[section_glyph.font_id.0];
+
+            let outline = match font.outline_glyph(section_glyph.glyph.clone())
+            {
+                Some(outline) => outline,
+                None => continue,
+            };
+
+            let glyph_bounds = outline.px_bounds();
+
+            let (x_range, y_range) = target.pixel_ranges(
+                clip_bounds,
+                Rectangle {
+                    x: glyph_bounds.min.x,
+                    y: glyph_bounds.min.y,
+                    width: glyph_bounds.width(),
+                    height: glyph_bounds.height(),
+                },
+            );
+
+            outline.draw(|x, y, coverage| {
+                let x = glyph_bounds.min.x as i64 + i64::from(x);
+                let y = glyph_bounds.min.y as i64 + i64::from(y);
+
+                if x < 0 || y < 0 {
+                    return;
+                }
+
+                let (x, y) = (x as u32, y as u32);
+
+                if x_range.contains(&x) && y_range.contains(&y) {
+                    target.blend(x, y, text.color, coverage);
+                }
+            });
+        }
+    }
+
+    pub fn measure(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        use glyph_brush::GlyphCruncher;
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: glyph_brush::FontId(font_id),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        if let Some(bounds) =
+            self.measure_brush.borrow_mut().glyph_bounds(section)
+        {
+            (bounds.width().ceil(), bounds.height().ceil())
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    pub fn hit_test(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+        point: iced_native::Point,
+        nearest_only: bool,
+    ) -> Option<Hit> {
+        let bounds = self.glyph_bounds(content, size, font, bounds);
+
+        // Implements computation of the character index based on the byte index
+        // within the input string.
+        let char_index = |byte_index| {
+            let mut b_count = 0;
+            for (i, utf8_len) in
+                content.chars().map(|c| c.len_utf8()).enumerate()
+            {
+                if byte_index < (b_count + utf8_len) {
+                    return i;
+                }
+                b_count += utf8_len;
+            }
+            return byte_index;
+        };
+
+        if !nearest_only {
+            for (idx, bounds) in bounds.iter() {
+                if bounds.contains(point) {
+                    return Some(Hit::CharOffset(char_index(*idx)));
+                }
+            }
+        }
+
+        let nearest = bounds
+            .into_iter()
+            .map(|(index, bounds)| (index, bounds.center()))
+            .min_by(|(_, center_a), (_, center_b)| {
+                center_a
+                    .distance(point)
+                    .partial_cmp(&center_b.distance(point))
+                    .unwrap_or(std::cmp::Ordering::Greater)
+            });
+
+        nearest.map(|(idx, center)| {
+            Hit::NearestCharOffset(char_index(idx), point - center)
+        })
+    }
+
+    pub fn glyph_positions(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<iced_native::Rectangle> {
+        self.glyph_bounds(content, size, font, bounds)
+            .into_iter()
+            .map(|(_, bounds)| bounds)
+            .collect()
+    }
+
+    /// Returns the byte index and the bounding box of every glyph of some
+    /// laid out content, in order.
+    fn glyph_bounds(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<(usize, iced_native::Rectangle)> {
+        use glyph_brush::GlyphCruncher;
+
+        let glyph_brush::FontId(font_id) = self.find_font(font);
+
+        let section = glyph_brush::Section {
+            bounds: (bounds.width, bounds.height),
+            text: vec![glyph_brush::Text {
+                text: content,
+                scale: size.into(),
+                font_id: glyph_brush::FontId(font_id),
+                extra: glyph_brush::Extra::default(),
+            }],
+            ..Default::default()
+        };
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        use ab_glyph::{Font, ScaleFont};
+        let font = mb.fonts()[font_id].clone().into_scaled(size);
+
+        mb.glyphs(section)
+            .map(
+                |glyph_brush::SectionGlyph {
+                     byte_index, glyph, ..
+                 }| {
+                    (
+                        *byte_index,
+                        iced_native::Rectangle::new(
+                            iced_native::Point::new(
+                                glyph.position.x
+                                    - font.h_side_bearing(glyph.id),
+                                glyph.position.y - font.ascent(),
+                            ),
+                            iced_native::Size::new(
+                                font.h_advance(glyph.id),
+                                font.ascent() - font.descent(),
+                            ),
+                        ),
+                    )
+                },
+            )
+            .collect()
+    }
+
+    pub fn trim_measurement_cache(&mut self) {
+        // TODO: We should probably use a `GlyphCalculator` for this. However,
+        // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
+        // This makes stuff quite inconvenient. A manual method for trimming the
+        // cache would make our lives easier.
+        loop {
+            let action = self
+                .measure_brush
+                .borrow_mut()
+                .process_queued(|_, _| {}, |_| {});
+
+            match action {
+                Ok(_) => break,
+                Err(glyph_brush::BrushError::TextureTooSmall { suggested }) => {
+                    let (width, height) = suggested;
+
+                    self.measure_brush
+                        .borrow_mut()
+                        .resize_texture(width, height);
+                }
+            }
+        }
+    }
+
+    pub fn find_font(&self, font: iced_native::Font) -> glyph_brush::FontId {
+        match font {
+            iced_native::Font::Default => glyph_brush::FontId(0),
+            iced_native::Font::External { name, bytes } => {
+                if let Some(font_id) = self.font_map.borrow().get(name) {
+                    return *font_id;
+                }
+
+                let font = ab_glyph::FontArc::try_from_slice(bytes)
+                    .expect("Load font");
+
+                let font_id = self.measure_brush.borrow_mut().add_font(font);
+
+                let _ = self
+                    .font_map
+                    .borrow_mut()
+                    .insert(String::from(name), font_id);
+
+                font_id
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Use the widgets supported out-of-the-box.
+//!
+//! # Re-exports
+//! For convenience, the contents of this module are available at the root
+//! module. Therefore, you can directly type:
+//!
+//! ```
+//! use iced_software::{button, Button};
+//! ```
+use crate::Renderer;
+
+pub mod border;
+pub mod button;
+pub mod chart;
+pub mod checkbox;
+pub mod container;
+pub mod context_menu;
+pub mod hover_area;
+pub mod lazy;
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+pub mod menu_bar;
+pub mod modal;
+pub mod number_input;
+pub mod opacity;
+pub mod pane_grid;
+pub mod pick_list;
+pub mod progress_bar;
+pub mod radio;
+pub mod rule;
+pub mod scrollable;
+pub mod selectable_text;
+pub mod slider;
+pub mod spinner;
+pub mod text_input;
+pub mod toggler;
+pub mod tooltip;
+
+#[doc(no_inline)]
+pub use border::Border;
+#[doc(no_inline)]
+pub use button::Button;
+#[doc(no_inline)]
+pub use chart::{BarChart, LineChart};
+#[doc(no_inline)]
+pub use checkbox::Checkbox;
+#[doc(no_inline)]
+pub use container::Container;
+#[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use hover_area::HoverArea;
+#[doc(no_inline)]
+pub use lazy::Lazy;
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::MarkdownView;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
+pub use pane_grid::PaneGrid;
+#[doc(no_inline)]
+pub use pick_list::PickList;
+#[doc(no_inline)]
+pub use progress_bar::ProgressBar;
+#[doc(no_inline)]
+pub use radio::Radio;
+#[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
+pub use scrollable::Scrollable;
+#[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
+pub use slider::Slider;
+#[doc(no_inline)]
+pub use spinner::Spinner;
+#[doc(no_inline)]
+pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use toggler::Toggler;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+
+#[cfg(feature = "canvas")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+pub mod canvas;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use canvas::Canvas;
+
+#[cfg(feature = "qr_code")]
+#[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
+pub mod qr_code;
+
+#[cfg(feature = "qr_code")]
+#[doc(no_inline)]
+pub use qr_code::{QRCode, QrCode};
+
+pub use iced_native::{Image, Space};
+
+/// A container that distributes its contents vertically.
+pub type Column<'a, Message> = iced_native::Column<'a, Message, Renderer>;
+
+/// A container that distributes its contents in rows and columns.
+pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Renderer>;
+
+/// A container that distributes its contents horizontally.
+pub type Row<'a, Message> = iced_native::Row<'a, Message, Renderer>;
+
+/// A container that distributes its contents horizontally, wrapping them into
+/// multiple lines.
+pub type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Renderer>;
+
+/// A paragraph of text.
+pub type Text = iced_native::Text<Renderer>;
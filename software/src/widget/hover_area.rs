@@ -0,0 +1,13 @@
+//! Produce messages when the cursor enters or leaves some content.
+//!
+//! A [`HoverArea`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::hover_area::State;
+
+/// An element that produces messages when the cursor enters or leaves its
+/// content.
+///
+/// This is an alias of an `iced_native` hover area with an
+/// `iced_software::Renderer`.
+pub type HoverArea<'a, Message> = iced_native::HoverArea<'a, Message, Renderer>;
@@ -0,0 +1,15 @@
+//! Build some content only when its dependency changes.
+//!
+//! A [`Lazy`] keeps its content in some local [`State`].
+use crate::Renderer;
+
+/// The local state of a [`Lazy`].
+pub type State<Dependency, Message> =
+    iced_native::lazy::State<Dependency, Message, Renderer>;
+
+/// An element that builds its content only when its dependency changes.
+///
+/// This is an alias of an `iced_native` lazy with an
+/// `iced_software::Renderer`.
+pub type Lazy<'a, Dependency, Message> =
+    iced_native::Lazy<'a, Dependency, Message, Renderer>;
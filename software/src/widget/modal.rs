@@ -0,0 +1,10 @@
+//! Show a dialog on top of some content, blocking any interaction with it.
+use crate::Renderer;
+
+pub use iced_graphics::modal::{Style, StyleSheet};
+
+/// An element that displays a dialog on top of its base content.
+///
+/// This is an alias of an `iced_native` modal with an
+/// `iced_software::Renderer`.
+pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Edit a number by typing it or by stepping it up and down.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::number_input::State;
+
+/// A field holding a number, with buttons to decrement and increment it.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_software::Renderer`.
+pub type NumberInput<'a, T, Message> =
+    iced_native::NumberInput<'a, T, Message, Renderer>;
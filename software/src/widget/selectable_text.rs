@@ -0,0 +1,12 @@
+//! Display text that can be selected and copied.
+//!
+//! A [`SelectableText`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::selectable_text::State;
+
+/// A paragraph of text that can be selected and copied.
+///
+/// This is an alias of an `iced_native` selectable text with an
+/// `iced_software::Renderer`.
+pub type SelectableText<'a> = iced_native::SelectableText<'a, Renderer>;
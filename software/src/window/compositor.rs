@@ -0,0 +1,343 @@
+use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+
+use iced_native::futures::channel::oneshot;
+use iced_native::mouse;
+use raw_window_handle::HasRawWindowHandle;
+
+#[cfg(feature = "wgpu")]
+use iced_graphics::{layer::Layer, window::Compositor as _};
+
+/// A window graphics backend for iced that presents the frames of the
+/// software [`Backend`] with `softbuffer`.
+///
+/// It does not need a GPU. When the `wgpu` feature is enabled, it tries to
+/// draw the frames with `iced_wgpu` first, and only renders them on the CPU
+/// if no graphics adapter can be used. Custom `wgpu` primitives are not
+/// drawn in that case.
+#[allow(missing_debug_implementations)]
+pub struct Compositor {
+    captures: Vec<oneshot::Sender<Vec<u8>>>,
+    #[cfg(feature = "wgpu")]
+    gpu: Option<Gpu>,
+}
+
+/// A window surface where the frames of a [`Compositor`] are presented.
+#[allow(missing_debug_implementations)]
+pub struct Surface {
+    kind: Kind,
+}
+
+enum Kind {
+    Software {
+        context: softbuffer::GraphicsContext<Window>,
+        buffer: Vec<u32>,
+    },
+    #[cfg(feature = "wgpu")]
+    Wgpu(iced_wgpu::wgpu::Surface),
+}
+
+impl iced_graphics::window::Compositor for Compositor {
+    type Settings = Settings;
+    type Renderer = Renderer;
+    type Surface = Surface;
+
+    fn new<W: HasRawWindowHandle>(
+        settings: Self::Settings,
+        _compatible_window: Option<&W>,
+    ) -> Result<(Self, Renderer), Error> {
+        let backend = Backend::new(settings);
+
+        Ok((
+            Compositor {
+                captures: Vec::new(),
+                #[cfg(feature = "wgpu")]
+                gpu: Gpu::request(settings, _compatible_window),
+            },
+            Renderer::new(backend),
+        ))
+    }
+
+    fn create_surface<W: HasRawWindowHandle>(&mut self, window: &W) -> Surface {
+        #[cfg(feature = "wgpu")]
+        if let Some(gpu) = &mut self.gpu {
+            return Surface {
+                kind: Kind::Wgpu(gpu.compositor.create_surface(window)),
+            };
+        }
+
+        let window = Window::new(window.raw_window_handle())
+            .expect("The window is not supported by the software compositor");
+
+        #[allow(unsafe_code)]
+        let context = unsafe { softbuffer::GraphicsContext::new(window) }
+            .unwrap_or_else(|error| {
+                panic!("Create software graphics context: {}", error)
+            });
+
+        Surface {
+            kind: Kind::Software {
+                context,
+                buffer: Vec::new(),
+            },
+        }
+    }
+
+    fn configure_surface(
+        &mut self,
+        surface: &mut Self::Surface,
+        width: u32,
+        height: u32,
+    ) {
+        match &mut surface.kind {
+            Kind::Software { buffer, .. } => {
+                *buffer = Vec::with_capacity((width * height) as usize);
+            }
+            #[cfg(feature = "wgpu")]
+            Kind::Wgpu(surface) => {
+                if let Some(gpu) = &mut self.gpu {
+                    gpu.compositor.configure_surface(surface, width, height);
+                }
+            }
+        }
+    }
+
+    fn draw<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        surface: &mut Self::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        output: &<Self::Renderer as iced_native::Renderer>::Output,
+        overlay: &[T],
+    ) -> Result<mouse::Interaction, iced_graphics::window::SurfaceError> {
+        let (context, buffer) = match &mut surface.kind {
+            Kind::Software { context, buffer } => (context, buffer),
+            #[cfg(feature = "wgpu")]
+            Kind::Wgpu(surface) => {
+                let gpu = self
+                    .gpu
+                    .as_mut()
+                    .expect("A wgpu surface is drawn with wgpu");
+
+                return gpu.draw(
+                    &mut self.captures,
+                    surface,
+                    viewport,
+                    background_color,
+                    output,
+                    overlay,
+                );
+            }
+        };
+
+        let (frame, mouse_interaction) = renderer.backend_mut().draw(
+            viewport,
+            background_color,
+            output,
+            overlay,
+        );
+
+        for sender in self.captures.drain(..) {
+            let _ = sender.send(frame.as_rgba().to_vec());
+        }
+
+        // `softbuffer` expects `0RGB` pixels, ignoring alpha
+        buffer.clear();
+        buffer.extend(frame.as_rgba().chunks_exact(4).map(|pixel| {
+            u32::from(pixel[0]) << 16
+                | u32::from(pixel[1]) << 8
+                | u32::from(pixel[2])
+        }));
+
+        context.set_buffer(buffer, frame.width() as u16, frame.height() as u16);
+
+        Ok(mouse_interaction)
+    }
+
+    fn capture_next_frame(
+        &mut self,
+        _renderer: &mut Self::Renderer,
+        sender: oneshot::Sender<Vec<u8>>,
+    ) {
+        self.captures.push(sender);
+    }
+
+    fn has_pending_work(&self, _renderer: &Self::Renderer) -> bool {
+        #[cfg(feature = "wgpu")]
+        if let Some(gpu) = &self.gpu {
+            return gpu.compositor.has_pending_work_for(&gpu.backend);
+        }
+
+        false
+    }
+}
+
+/// The `wgpu` compositor and backend drawing the frames of a [`Compositor`],
+/// when a graphics adapter can be used.
+#[cfg(feature = "wgpu")]
+struct Gpu {
+    compositor: iced_wgpu::window::Compositor,
+    backend: iced_wgpu::Backend,
+}
+
+#[cfg(feature = "wgpu")]
+impl Gpu {
+    /// Requests a `wgpu` compositor for the given [`Settings`], returning
+    /// `None` if no graphics adapter can be used.
+    fn request<W: HasRawWindowHandle>(
+        settings: Settings,
+        compatible_window: Option<&W>,
+    ) -> Option<Self> {
+        let settings = iced_wgpu::Settings {
+            default_font: settings.default_font,
+            default_text_size: settings.default_text_size,
+            text_multithreading: settings.text_multithreading,
+            antialiasing: settings.antialiasing,
+            ..iced_wgpu::Settings::from_env()
+        };
+
+        let compositor = iced_native::futures::executor::block_on(
+            iced_wgpu::window::Compositor::request(settings, compatible_window),
+        );
+
+        match compositor {
+            Some(compositor) => {
+                let backend = compositor.create_backend();
+
+                Some(Gpu {
+                    compositor,
+                    backend,
+                })
+            }
+            None => {
+                log::warn!(
+                    "No graphics adapter can be used, \
+                    falling back to software rendering"
+                );
+
+                None
+            }
+        }
+    }
+
+    /// Draws the primitives of the software [`Backend`] with `wgpu`, leaving
+    /// their custom primitives out.
+    fn draw<T: AsRef<str>>(
+        &mut self,
+        captures: &mut Vec<oneshot::Sender<Vec<u8>>>,
+        surface: &mut iced_wgpu::wgpu::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        (primitive, mouse_interaction): &(
+            iced_graphics::Primitive<Backend>,
+            mouse::Interaction,
+        ),
+        overlay: &[T],
+    ) -> Result<mouse::Interaction, iced_graphics::window::SurfaceError> {
+        for sender in captures.drain(..) {
+            self.backend.capture_next_frame(sender);
+        }
+
+        let mut layers = Layer::generate(primitive, viewport);
+        layers.push(Layer::overlay(overlay, viewport));
+
+        let layers: Vec<Layer<'_, iced_wgpu::Backend>> =
+            layers.into_iter().map(Layer::without_customs).collect();
+
+        self.compositor.draw_layers(
+            &mut self.backend,
+            surface,
+            viewport,
+            background_color,
+            &layers,
+        )?;
+
+        Ok(mouse_interaction.clone())
+    }
+}
+
+/// The raw handle of a window, in the version of `raw-window-handle` used by
+/// `softbuffer`.
+struct Window(raw_window_handle_softbuffer::RawWindowHandle);
+
+impl Window {
+    /// Converts the raw handle of a window, returning `None` if its platform
+    /// is not supported.
+    fn new(handle: raw_window_handle::RawWindowHandle) -> Option<Self> {
+        use raw_window_handle::RawWindowHandle as Handle;
+        use raw_window_handle_softbuffer as softbuffer_handle;
+
+        let handle = match handle {
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            Handle::Xlib(handle) => {
+                let mut xlib = softbuffer_handle::XlibHandle::empty();
+                xlib.window = handle.window;
+                xlib.display = handle.display;
+
+                softbuffer_handle::RawWindowHandle::Xlib(xlib)
+            }
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            Handle::Xcb(handle) => {
+                let mut xcb = softbuffer_handle::XcbHandle::empty();
+                xcb.window = handle.window;
+                xcb.connection = handle.connection;
+
+                softbuffer_handle::RawWindowHandle::Xcb(xcb)
+            }
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            Handle::Wayland(handle) => {
+                let mut wayland = softbuffer_handle::WaylandHandle::empty();
+                wayland.surface = handle.surface;
+                wayland.display = handle.display;
+
+                softbuffer_handle::RawWindowHandle::Wayland(wayland)
+            }
+            #[cfg(target_os = "windows")]
+            Handle::Windows(handle) => {
+                let mut win32 = softbuffer_handle::Win32Handle::empty();
+                win32.hwnd = handle.hwnd;
+                win32.hinstance = handle.hinstance;
+
+                softbuffer_handle::RawWindowHandle::Win32(win32)
+            }
+            #[cfg(target_os = "macos")]
+            Handle::MacOS(handle) => {
+                let mut app_kit = softbuffer_handle::AppKitHandle::empty();
+                app_kit.ns_window = handle.ns_window;
+                app_kit.ns_view = handle.ns_view;
+
+                softbuffer_handle::RawWindowHandle::AppKit(app_kit)
+            }
+            _ => return None,
+        };
+
+        Some(Window(handle))
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe impl raw_window_handle_softbuffer::HasRawWindowHandle for Window {
+    fn raw_window_handle(
+        &self,
+    ) -> raw_window_handle_softbuffer::RawWindowHandle {
+        self.0
+    }
+}
@@ -1,3 +1,4 @@
+use crate::keyboard;
 use crate::window;
 use crate::{Color, Command, Element, Executor, Settings, Subscription};
 
@@ -16,7 +17,11 @@ use crate::{Color, Command, Element, Executor, Settings, Subscription};
 /// interface.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. With the `trace` feature enabled, the same
+/// stages are recorded as `tracing` spans, which can be inspected with tools
+/// like `tracing-chrome` or `tracing-tracy`. The debug view then takes its
+/// timings from those spans when the `iced_native::trace::Timings` layer is
+/// part of the subscriber.
 ///
 /// # Examples
 /// [The repository has a bunch of examples] that use the [`Application`] trait:
@@ -185,6 +190,26 @@ pub trait Application: Sized {
         false
     }
 
+    /// Returns the keyboard accelerators of the [`Application`], as
+    /// [`Shortcuts`] binding each [`Hotkey`] to the message it produces.
+    ///
+    /// Accelerators are checked before the widgets see a key press. A key
+    /// press matching an accelerator produces its message and is captured
+    /// without being delivered to the widgets. Therefore, `Ctrl+S` can save
+    /// even while a text input is focused. Leave an accelerator out (for
+    /// instance, while a text input is focused) to let the widgets claim its
+    /// key press instead.
+    ///
+    /// Currently, accelerators only have an effect in native platforms.
+    ///
+    /// By default, it returns no accelerators.
+    ///
+    /// [`Shortcuts`]: keyboard::Shortcuts
+    /// [`Hotkey`]: keyboard::Hotkey
+    fn keyboard_accelerators(&self) -> keyboard::Shortcuts<Self::Message> {
+        keyboard::Shortcuts::new()
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -286,6 +311,10 @@ where
     fn should_exit(&self) -> bool {
         self.0.should_exit()
     }
+
+    fn keyboard_accelerators(&self) -> keyboard::Shortcuts<A::Message> {
+        self.0.keyboard_accelerators()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,3 @@
+//! Handle events and send messages from outside of the application.
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::event::{proxy, Event, Proxy, Status};
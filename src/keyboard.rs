@@ -1,2 +1,5 @@
 //! Listen and react to keyboard events.
-pub use crate::runtime::keyboard::{Event, KeyCode, Modifiers};
+pub use crate::runtime::keyboard::{
+    Event, Hotkey, KeyCode, KeyLocation, Modifiers, ScanCode, Shortcut,
+    Shortcuts, SmolStr,
+};
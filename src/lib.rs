@@ -184,29 +184,36 @@ mod result;
 mod sandbox;
 
 pub mod clipboard;
+pub mod event;
 pub mod executor;
 pub mod keyboard;
 pub mod mouse;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    not(feature = "software"),
+    feature = "wgpu"
+))]
+pub mod multi_window;
 pub mod settings;
 pub mod widget;
 pub mod window;
 
-#[cfg(all(
-    any(
-        feature = "tokio",
-        feature = "tokio_old",
-        feature = "async-std",
-        feature = "smol"
-    ),
-    not(target_arch = "wasm32")
+#[cfg(any(
+    feature = "tokio",
+    feature = "tokio_old",
+    feature = "async-std",
+    feature = "smol",
+    target_arch = "wasm32"
 ))]
 #[cfg_attr(
     docsrs,
     doc(cfg(any(
         feature = "tokio",
         feature = "tokio_old",
-        feature = "async-std"
-        feature = "smol"
+        feature = "async-std",
+        feature = "smol",
+        target_arch = "wasm32"
     )))
 )]
 pub mod time;
@@ -214,6 +221,7 @@ pub mod time;
 #[cfg(all(
     not(target_arch = "wasm32"),
     not(feature = "glow"),
+    not(feature = "software"),
     feature = "wgpu"
 ))]
 use iced_winit as runtime;
@@ -224,6 +232,14 @@ use iced_glutin as runtime;
 #[cfg(all(
     not(target_arch = "wasm32"),
     not(feature = "glow"),
+    feature = "software"
+))]
+use iced_winit as runtime;
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    not(feature = "software"),
     feature = "wgpu"
 ))]
 use iced_wgpu as renderer;
@@ -231,6 +247,13 @@ use iced_wgpu as renderer;
 #[cfg(all(not(target_arch = "wasm32"), feature = "glow"))]
 use iced_glow as renderer;
 
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    feature = "software"
+))]
+use iced_software as renderer;
+
 #[cfg(target_arch = "wasm32")]
 use iced_web as runtime;
 
@@ -241,6 +264,13 @@ pub use application::Application;
 pub use element::Element;
 pub use error::Error;
 pub use executor::Executor;
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    not(feature = "software"),
+    feature = "wgpu"
+))]
+pub use multi_window::MultiWindowApplication;
 pub use result::Result;
 pub use sandbox::Sandbox;
 pub use settings::Settings;
@@ -248,6 +278,7 @@ pub use settings::Settings;
 pub use runtime::alignment;
 pub use runtime::futures;
 pub use runtime::{
-    Alignment, Background, Color, Command, Font, Length, Point, Rectangle,
-    Size, Subscription, Vector,
+    Alignment, Background, BorderAlignment, BorderStyle, BorderWidth, Color,
+    Command, ContentFit, Font, Length, Point, Rectangle, Shadow, Size,
+    Subscription, TextShadow, Vector, WritingMode,
 };
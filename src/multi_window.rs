@@ -0,0 +1,201 @@
+//! Run native applications with multiple windows.
+use crate::window;
+use crate::{Color, Command, Element, Executor, Settings, Subscription};
+
+pub use crate::runtime::event::{Event, Status};
+
+/// An interactive native application with multiple windows.
+///
+/// It starts with a single window, identified by [`window::Id::MAIN`]. More
+/// windows can be opened with [`window::spawn`] and closed with
+/// [`window::close`]. Every window displays its own [`view`](#tymethod.view),
+/// while the state, the messages and the subscriptions are shared by all of
+/// them.
+///
+/// When the user requests a window to close, it is closed if
+/// [`Settings::exit_on_close_request`] is enabled. By default, the
+/// application exits once its last window is closed.
+///
+/// Multiple windows are currently only supported by the `wgpu` renderer.
+///
+/// # Example
+/// The [`multi_window`] example opens an inspector palette in a secondary
+/// window.
+///
+/// [`multi_window`]: https://github.com/hecrj/iced/tree/0.3/examples/multi_window
+pub trait MultiWindowApplication: Sized {
+    /// The [`Executor`] that will run commands and subscriptions.
+    ///
+    /// The [default executor] can be a good starting point!
+    ///
+    /// [`Executor`]: Self::Executor
+    /// [default executor]: crate::executor::Default
+    type Executor: Executor;
+
+    /// The type of __messages__ your [`MultiWindowApplication`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`MultiWindowApplication`].
+    type Flags;
+
+    /// Initializes the [`MultiWindowApplication`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    ///
+    /// [`run`]: Self::run
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given
+    /// [`window::Id`].
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the
+    /// [`MultiWindowApplication`].
+    ///
+    /// The messages produced in any window are handled here.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the event [`Subscription`] for the current state of the
+    /// application.
+    ///
+    /// Subscriptions receive the events of every window.
+    ///
+    /// By default, this method returns an empty [`Subscription`].
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    fn view(&mut self, window: window::Id) -> Element<'_, Self::Message>;
+
+    /// Produces a message, if any, for an [`Event`] that happened in the
+    /// window with the given [`window::Id`], after its widgets have
+    /// processed it.
+    ///
+    /// By default, it returns `None`.
+    fn event(
+        &self,
+        _window: window::Id,
+        _event: Event,
+        _status: Status,
+    ) -> Option<Self::Message> {
+        None
+    }
+
+    /// Returns the background color of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, it returns [`Color::WHITE`].
+    fn background_color(&self, _window: window::Id) -> Color {
+        Color::WHITE
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+
+    /// Returns whether the [`MultiWindowApplication`] should be terminated.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the [`MultiWindowApplication`] should be terminated
+    /// once its last window is closed.
+    ///
+    /// By default, it returns `true`.
+    fn exit_on_last_window_closed(&self) -> bool {
+        true
+    }
+
+    /// Runs the [`MultiWindowApplication`].
+    ///
+    /// This method will take control of the current thread and __will NOT
+    /// return__ unless there is an [`Error`] during startup.
+    ///
+    /// [`Error`]: crate::Error
+    fn run(settings: Settings<Self::Flags>) -> crate::Result
+    where
+        Self: 'static,
+    {
+        let renderer_settings = crate::renderer::Settings {
+            default_font: settings.default_font,
+            default_text_size: settings.default_text_size,
+            text_multithreading: settings.text_multithreading,
+            antialiasing: if settings.antialiasing {
+                Some(crate::renderer::settings::Antialiasing::MSAAx4)
+            } else {
+                None
+            },
+            ..crate::renderer::Settings::from_env()
+        };
+
+        Ok(crate::runtime::multi_window::run::<
+            Instance<Self>,
+            Self::Executor,
+            crate::renderer::window::Compositor,
+        >(settings.into(), renderer_settings)?)
+    }
+}
+
+struct Instance<A: MultiWindowApplication>(A);
+
+impl<A> crate::runtime::MultiWindowApplication for Instance<A>
+where
+    A: MultiWindowApplication,
+{
+    type Renderer = crate::renderer::Renderer;
+    type Message = A::Message;
+    type Flags = A::Flags;
+
+    fn new(flags: Self::Flags) -> (Self, Command<A::Message>) {
+        let (app, command) = A::new(flags);
+
+        (Instance(app), command)
+    }
+
+    fn title(&self, window: window::Id) -> String {
+        self.0.title(window)
+    }
+
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        self.0.update(message)
+    }
+
+    fn view(&mut self, window: window::Id) -> Element<'_, Self::Message> {
+        self.0.view(window)
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        self.0.subscription()
+    }
+
+    fn event(
+        &self,
+        window: window::Id,
+        event: Event,
+        status: Status,
+    ) -> Option<Self::Message> {
+        self.0.event(window, event, status)
+    }
+
+    fn background_color(&self, window: window::Id) -> Color {
+        self.0.background_color(window)
+    }
+
+    fn scale_factor(&self, window: window::Id) -> f64 {
+        self.0.scale_factor(window)
+    }
+
+    fn should_exit(&self) -> bool {
+        self.0.should_exit()
+    }
+
+    fn exit_on_last_window_closed(&self) -> bool {
+        self.0.exit_on_last_window_closed()
+    }
+}
@@ -1,6 +1,8 @@
 //! Configure your application.
 use crate::window;
 
+use std::time::Duration;
+
 /// The settings of an application.
 #[derive(Debug, Clone)]
 pub struct Settings<Flags> {
@@ -53,6 +55,38 @@ pub struct Settings<Flags> {
     ///
     /// By default, it is enabled.
     pub exit_on_close_request: bool,
+
+    /// The initial scale factor override of the window.
+    ///
+    /// It is multiplied with the scale factor reported by the operating
+    /// system, zooming the whole user interface. It is clamped to the
+    /// `[0.5, 3.0]` range and can be changed at runtime with
+    /// [`window::set_scale_factor_override`].
+    ///
+    /// The default value is 1.0. It will be ignored on the Web.
+    pub scale_factor_override: f32,
+
+    /// The maximum amount of frames presented per second.
+    ///
+    /// Capping the frame rate saves battery on animated user interfaces, at
+    /// the cost of smoothness.
+    ///
+    /// By default, the frame rate is not limited. It will be ignored on the
+    /// Web.
+    pub max_fps: Option<u32>,
+
+    /// The time a finger needs to stay still on the screen to produce a long
+    /// press.
+    ///
+    /// The default value is 500 milliseconds. It will be ignored on the Web.
+    pub long_press_duration: Duration,
+
+    /// The maximum time between two clicks of a mouse button to produce a
+    /// double click.
+    ///
+    /// The default value is 500 milliseconds, the default of most operating
+    /// systems. It will be ignored on the Web.
+    pub double_click_interval: Duration,
 }
 
 impl<Flags> Settings<Flags> {
@@ -71,6 +105,10 @@ impl<Flags> Settings<Flags> {
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
             exit_on_close_request: default_settings.exit_on_close_request,
+            scale_factor_override: default_settings.scale_factor_override,
+            max_fps: default_settings.max_fps,
+            long_press_duration: default_settings.long_press_duration,
+            double_click_interval: default_settings.double_click_interval,
         }
     }
 }
@@ -89,6 +127,10 @@ where
             text_multithreading: false,
             antialiasing: false,
             exit_on_close_request: true,
+            scale_factor_override: 1.0,
+            max_fps: None,
+            long_press_duration: Duration::from_millis(500),
+            double_click_interval: Duration::from_millis(500),
         }
     }
 }
@@ -101,6 +143,10 @@ impl<Flags> From<Settings<Flags>> for iced_winit::Settings<Flags> {
             window: settings.window.into(),
             flags: settings.flags,
             exit_on_close_request: settings.exit_on_close_request,
+            scale_factor_override: settings.scale_factor_override,
+            max_fps: settings.max_fps,
+            long_press_duration: settings.long_press_duration,
+            double_click_interval: settings.double_click_interval,
         }
     }
 }
@@ -1,12 +1,63 @@
 //! Listen and react to time.
+#[cfg(not(target_arch = "wasm32"))]
+use crate::runtime::{event, window, Event};
 use crate::Subscription;
 
+pub use iced_futures::time::Instant;
+
 /// Returns a [`Subscription`] that produces messages at a set interval.
 ///
 /// The first message is produced after a `duration`, and then continues to
 /// produce more messages every `duration` after that.
-pub fn every(
-    duration: std::time::Duration,
-) -> Subscription<std::time::Instant> {
+pub fn every(duration: std::time::Duration) -> Subscription<Instant> {
     iced_futures::time::every(duration)
 }
+
+/// Returns a [`Subscription`] that produces messages at every multiple of the
+/// given `duration` since the Unix epoch.
+///
+/// Unlike [`every`], the ticks stay aligned to the wall clock and do not drift.
+/// For instance, a `duration` of one second produces a message at the start of
+/// every second.
+pub fn every_aligned(duration: std::time::Duration) -> Subscription<Instant> {
+    iced_futures::time::every_aligned(duration)
+}
+
+/// Returns a [`Subscription`] that produces a single message at the given
+/// [`Instant`].
+pub fn at(instant: Instant) -> Subscription<Instant> {
+    iced_futures::time::at(instant)
+}
+
+/// Returns a [`Subscription`] that produces a message at every [`Instant`] of
+/// the given schedule.
+///
+/// The schedule is expected to be sorted. The subscription is identified by
+/// the type of the schedule and its first [`Instant`].
+pub fn schedule<I>(instants: I) -> Subscription<Instant>
+where
+    I: IntoIterator<Item = Instant>,
+    I::IntoIter: Send + 'static,
+{
+    iced_futures::time::schedule(instants)
+}
+
+/// Returns a [`Subscription`] that produces messages at a set interval while
+/// the window of the application is active.
+///
+/// The interval is suspended while the window is unfocused or minimized, and
+/// starts over once the window is active again.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn every_while_active(
+    duration: std::time::Duration,
+) -> Subscription<Instant> {
+    iced_futures::time::every_while(duration, activity)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn activity((event, _status): &(Event, event::Status)) -> Option<bool> {
+    match event {
+        Event::Window(window_event) => window_event.activity(),
+        _ => None,
+    }
+}
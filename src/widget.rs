@@ -16,25 +16,50 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, toggler, tooltip, Column, Row,
-        Space, Text,
+        border, button, chart, checkbox, container, context_menu, hover_area,
+        lazy, menu_bar, modal, number_input, opacity, pane_grid, pick_list,
+        progress_bar, radio, rule, scrollable, selectable_text, slider,
+        spinner, text_input, toggler, tooltip, Column, Grid, Row, Space, Text,
+        Wrap,
     };
 
-    #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
+    #[cfg(any(
+        feature = "canvas",
+        feature = "glow_canvas",
+        feature = "software_canvas"
+    ))]
     #[cfg_attr(
         docsrs,
-        doc(cfg(any(feature = "canvas", feature = "glow_canvas")))
+        doc(cfg(any(
+            feature = "canvas",
+            feature = "glow_canvas",
+            feature = "software_canvas"
+        )))
     )]
     pub use crate::renderer::widget::canvas;
 
-    #[cfg(any(feature = "qr_code", feature = "glow_qr_code"))]
+    #[cfg(any(
+        feature = "qr_code",
+        feature = "glow_qr_code",
+        feature = "software_qr_code"
+    ))]
     #[cfg_attr(
         docsrs,
-        doc(cfg(any(feature = "qr_code", feature = "glow_qr_code")))
+        doc(cfg(any(
+            feature = "qr_code",
+            feature = "glow_qr_code",
+            feature = "software_qr_code"
+        )))
     )]
     pub use crate::renderer::widget::qr_code;
 
+    #[cfg(any(feature = "markdown", feature = "glow_markdown"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "markdown", feature = "glow_markdown")))
+    )]
+    pub use crate::renderer::widget::markdown;
+
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
@@ -45,24 +70,48 @@ mod platform {
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
     pub mod svg {
         //! Display vector graphics in your user interface.
-        pub use crate::runtime::svg::{Handle, Svg};
+        pub use crate::runtime::svg::{Handle, Quality, Svg};
+    }
+
+    pub mod video {
+        //! Play videos in your user interface.
+        //!
+        //! Videos are only drawn by the `wgpu` renderer.
+        pub use crate::runtime::video::{Decoder, Frame, Handle, Video};
     }
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
+        border::Border, button::Button, chart::BarChart, chart::LineChart,
+        checkbox::Checkbox, container::Container, context_menu::ContextMenu,
+        hover_area::HoverArea, image::Image, lazy::Lazy, menu_bar::MenuBar,
+        modal::Modal, number_input::NumberInput, opacity::Opacity,
         pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
+        radio::Radio, rule::Rule, scrollable::Scrollable,
+        selectable_text::SelectableText, slider::Slider, spinner::Spinner,
         svg::Svg, text_input::TextInput, toggler::Toggler, tooltip::Tooltip,
+        video::Video,
     };
 
-    #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
+    #[cfg(any(
+        feature = "canvas",
+        feature = "glow_canvas",
+        feature = "software_canvas"
+    ))]
     #[doc(no_inline)]
     pub use canvas::Canvas;
 
-    #[cfg(any(feature = "qr_code", feature = "glow_qr_code"))]
+    #[cfg(any(
+        feature = "qr_code",
+        feature = "glow_qr_code",
+        feature = "software_qr_code"
+    ))]
+    #[doc(no_inline)]
+    pub use qr_code::{QRCode, QrCode};
+
+    #[cfg(any(feature = "markdown", feature = "glow_markdown"))]
     #[doc(no_inline)]
-    pub use qr_code::QRCode;
+    pub use markdown::MarkdownView;
 }
 
 #[cfg(target_arch = "wasm32")]
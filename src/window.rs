@@ -11,4 +11,33 @@ pub use position::Position;
 pub use settings::Settings;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::window::{move_to, resize};
+pub use crate::runtime::window::{
+    capture_frame, move_to, resize, scale_factor_override,
+    set_scale_factor_override,
+};
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    not(feature = "software"),
+    feature = "wgpu"
+))]
+pub use crate::runtime::window::{close, target, Event, Id};
+
+/// Spawns a new window with the given [`Settings`], returning its [`Id`]
+/// together with the [`Command`] that opens it.
+///
+/// Only a [`MultiWindowApplication`] can spawn windows. The position and the
+/// icon of the [`Settings`] are ignored for now.
+///
+/// [`Command`]: crate::Command
+/// [`MultiWindowApplication`]: crate::MultiWindowApplication
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(feature = "glow"),
+    not(feature = "software"),
+    feature = "wgpu"
+))]
+pub fn spawn<Message>(settings: Settings) -> (Id, crate::Command<Message>) {
+    crate::runtime::window::spawn(settings.into())
+}
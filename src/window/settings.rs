@@ -64,3 +64,18 @@ impl From<Settings> for iced_winit::settings::Window {
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<Settings> for iced_winit::window::Settings {
+    fn from(settings: Settings) -> Self {
+        Self {
+            size: settings.size,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+        }
+    }
+}
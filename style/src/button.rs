@@ -1,5 +1,5 @@
 //! Allow your users to perform actions by pressing a button.
-use iced_core::{Background, Color, Vector};
+use iced_core::{Background, BorderStyle, Color, Shadow, Vector};
 
 /// The appearance of a button.
 #[derive(Debug, Clone, Copy)]
@@ -9,7 +9,9 @@ pub struct Style {
     pub border_radius: f32,
     pub border_width: f32,
     pub border_color: Color,
+    pub border_style: BorderStyle,
     pub text_color: Color,
+    pub shadow: Option<Shadow>,
 }
 
 impl std::default::Default for Style {
@@ -20,7 +22,9 @@ impl std::default::Default for Style {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
             text_color: Color::BLACK,
+            shadow: None,
         }
     }
 }
@@ -32,8 +36,14 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style {
         let active = self.active();
 
+        // Raise the button slightly
         Style {
             shadow_offset: active.shadow_offset + Vector::new(0.0, 1.0),
+            shadow: active.shadow.map(|shadow| Shadow {
+                offset: shadow.offset + Vector::new(0.0, 1.0),
+                blur_radius: shadow.blur_radius + 1.0,
+                ..shadow
+            }),
             ..active
         }
     }
@@ -41,6 +51,7 @@ pub trait StyleSheet {
     fn pressed(&self) -> Style {
         Style {
             shadow_offset: Vector::default(),
+            shadow: None,
             ..self.active()
         }
     }
@@ -50,11 +61,22 @@ pub trait StyleSheet {
 
         Style {
             shadow_offset: Vector::default(),
+            shadow: None,
             background: active.background.map(|background| match background {
                 Background::Color(color) => Background::Color(Color {
                     a: color.a * 0.5,
                     ..color
                 }),
+                Background::Image {
+                    handle,
+                    fit,
+                    opacity,
+                } => Background::Image {
+                    handle,
+                    fit,
+                    opacity: opacity * 0.5,
+                },
+                tiled @ Background::Tiled { .. } => tiled,
             }),
             text_color: Color {
                 a: active.text_color.a * 0.5,
@@ -63,6 +85,14 @@ pub trait StyleSheet {
             ..active
         }
     }
+
+    fn loading(&self) -> Style {
+        Style {
+            shadow_offset: Vector::default(),
+            shadow: None,
+            ..self.active()
+        }
+    }
 }
 
 struct Default;
@@ -75,7 +105,9 @@ impl StyleSheet for Default {
             border_radius: 2.0,
             border_width: 1.0,
             border_color: [0.7, 0.7, 0.7].into(),
+            border_style: BorderStyle::Solid,
             text_color: Color::BLACK,
+            shadow: None,
         }
     }
 }
@@ -0,0 +1,56 @@
+//! Plot data in charts.
+use iced_core::{Background, Color};
+
+/// The appearance of a chart.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub grid_color: Color,
+    pub label_color: Color,
+    pub tooltip_background: Background,
+}
+
+/// A set of rules that dictate the style of a chart.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+
+    /// Produces the color of the bar at the given index of a bar chart.
+    fn bar_color(&self, index: usize) -> Color {
+        PALETTE[index % PALETTE.len()]
+    }
+}
+
+const PALETTE: [Color; 4] = [
+    Color::from_rgb(0.35, 0.55, 0.85),
+    Color::from_rgb(0.90, 0.55, 0.25),
+    Color::from_rgb(0.40, 0.70, 0.40),
+    Color::from_rgb(0.80, 0.35, 0.40),
+];
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: None,
+            grid_color: Color::from_rgba(0.0, 0.0, 0.0, 0.1),
+            label_color: Color::from_rgb(0.4, 0.4, 0.4),
+            tooltip_background: Background::Color(Color::WHITE),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
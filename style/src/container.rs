@@ -1,5 +1,5 @@
 //! Decorate content and apply alignment.
-use iced_core::{Background, Color};
+use iced_core::{Background, BorderStyle, Color, Shadow};
 
 /// The appearance of a container.
 #[derive(Debug, Clone, Copy)]
@@ -9,6 +9,8 @@ pub struct Style {
     pub border_radius: f32,
     pub border_width: f32,
     pub border_color: Color,
+    pub border_style: BorderStyle,
+    pub shadow: Option<Shadow>,
 }
 
 impl std::default::Default for Style {
@@ -19,6 +21,8 @@ impl std::default::Default for Style {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            shadow: None,
         }
     }
 }
@@ -39,6 +43,8 @@ impl StyleSheet for Default {
             border_radius: 0.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            border_style: BorderStyle::Solid,
+            shadow: None,
         }
     }
 }
@@ -4,12 +4,15 @@
 //! widgets.
 //!
 //! ![The foundations of the Iced ecosystem](https://github.com/hecrj/iced/blob/0525d76ff94e828b7b21634fa94a747022001c83/docs/graphs/foundations.png?raw=true)
-pub use iced_core::{Background, Color};
+pub use iced_core::{Background, Color, Shadow};
 
 pub mod button;
+pub mod chart;
 pub mod checkbox;
 pub mod container;
 pub mod menu;
+pub mod menu_bar;
+pub mod modal;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -17,5 +20,12 @@ pub mod radio;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
+pub mod spinner;
 pub mod text_input;
+pub mod theme;
 pub mod toggler;
+
+pub use theme::{Styleable, Theme};
+
+#[cfg(feature = "serde")]
+pub use theme::ThemeError;
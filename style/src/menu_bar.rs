@@ -0,0 +1,66 @@
+use crate::menu;
+use iced_core::{Background, Color};
+
+/// The appearance of a menu bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+    pub disabled_text_color: Color,
+    pub separator_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Background::Color([0.95, 0.95, 0.95].into()),
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            text_color: Color::BLACK,
+            selected_text_color: Color::WHITE,
+            selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+            disabled_text_color: [0.6, 0.6, 0.6].into(),
+            separator_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a menu bar.
+pub trait StyleSheet {
+    /// Produces the style of the bar.
+    fn active(&self) -> Style;
+
+    /// Produces the style of the open menus.
+    fn menu(&self) -> menu::Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn menu(&self) -> menu::Style {
+        menu::Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,38 @@
+//! Dim some content behind a dialog.
+use iced_core::{Background, Color};
+
+/// The appearance of the area around the dialog of a modal.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+}
+
+/// A set of rules that dictate the style of a modal.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.5)),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,4 +1,5 @@
 //! Create choices using radio buttons.
+use crate::theme::{Styleable, Theme};
 use iced_core::{Background, Color};
 
 /// The appearance of a radio button.
@@ -17,29 +18,35 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style;
 }
 
-struct Default;
+struct Themed(Theme);
 
-impl StyleSheet for Default {
+impl StyleSheet for Themed {
     fn active(&self) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            dot_color: Color::from_rgb(0.3, 0.3, 0.3),
+            background: Background::Color(self.0.background),
+            dot_color: self.0.accent,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: self.0.border,
         }
     }
 
     fn hovered(&self) -> Style {
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
+            background: Background::Color(self.0.hovered),
             ..self.active()
         }
     }
 }
 
+impl Styleable for Box<dyn StyleSheet> {
+    fn from_theme(theme: &Theme) -> Self {
+        Box::new(Themed(*theme))
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::from_theme(&Theme::default())
     }
 }
 
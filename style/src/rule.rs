@@ -1,5 +1,6 @@
 //! Display a horizontal or vertical rule for dividing content.
 
+use crate::theme::{Styleable, Theme};
 use iced_core::Color;
 
 /// The fill mode of a rule.
@@ -66,6 +67,28 @@ impl FillMode {
     }
 }
 
+/// The shape of the ends of a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    /// Square ends, filling the whole length of the rule.
+    Square,
+    /// Semicircular ends, with a diameter equal to the width of the rule.
+    ///
+    /// The ends are drawn inside the length of the rule.
+    Round,
+    /// No ends at all.
+    ///
+    /// The rule is shortened on each end by half of its width, leaving the
+    /// space that a cap would fill empty.
+    None,
+}
+
+impl std::default::Default for CapStyle {
+    fn default() -> Self {
+        CapStyle::Square
+    }
+}
+
 /// The appearance of a rule.
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
@@ -77,6 +100,8 @@ pub struct Style {
     pub radius: f32,
     /// The [`FillMode`] of the rule.
     pub fill_mode: FillMode,
+    /// The [`CapStyle`] of the ends of the rule.
+    pub cap: CapStyle,
 }
 
 impl std::default::Default for Style {
@@ -86,6 +111,7 @@ impl std::default::Default for Style {
             width: 1,
             radius: 0.0,
             fill_mode: FillMode::Full,
+            cap: CapStyle::Square,
         }
     }
 }
@@ -96,17 +122,29 @@ pub trait StyleSheet {
     fn style(&self) -> Style;
 }
 
-struct Default;
+struct Themed(Theme);
 
-impl StyleSheet for Default {
+impl StyleSheet for Themed {
     fn style(&self) -> Style {
-        Style::default()
+        Style {
+            color: Color {
+                a: 0.6,
+                ..self.0.border
+            },
+            ..Style::default()
+        }
+    }
+}
+
+impl Styleable for Box<dyn StyleSheet> {
+    fn from_theme(theme: &Theme) -> Self {
+        Box::new(Themed(*theme))
     }
 }
 
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::from_theme(&Theme::default())
     }
 }
 
@@ -8,6 +8,8 @@ pub struct Scrollbar {
     pub border_radius: f32,
     pub border_width: f32,
     pub border_color: Color,
+    /// The space between the bounds of the scrollbar and its track.
+    pub margin: f32,
     pub scroller: Scroller,
 }
 
@@ -18,6 +20,15 @@ pub struct Scroller {
     pub border_radius: f32,
     pub border_width: f32,
     pub border_color: Color,
+    /// The horizontal space between the scroller and the sides of the
+    /// scrollbar.
+    pub margin: f32,
+    /// The minimum length of the scroller, so it stays easy to grab when the
+    /// content is very long.
+    ///
+    /// Only the length of the active style is used, since the length of the
+    /// scroller cannot change while it is hovered or dragged.
+    pub min_length: f32,
 }
 
 /// A set of rules that dictate the style of a scrollable.
@@ -43,11 +54,14 @@ impl StyleSheet for Default {
             border_radius: 5.0,
             border_width: 0.0,
             border_color: Color::TRANSPARENT,
+            margin: 0.0,
             scroller: Scroller {
                 color: [0.0, 0.0, 0.0, 0.7].into(),
                 border_radius: 5.0,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
+                margin: 0.0,
+                min_length: 0.0,
             },
         }
     }
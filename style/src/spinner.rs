@@ -0,0 +1,43 @@
+//! Show that something is in progress.
+use iced_core::Color;
+
+use std::time::Duration;
+
+/// The appearance of a spinner.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub color: Color,
+    /// The time it takes the spinner to complete a turn.
+    pub period: Duration,
+}
+
+/// A set of rules that dictate the style of a spinner.
+pub trait StyleSheet {
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            color: Color::from_rgb(0.3, 0.3, 0.3),
+            period: Duration::from_millis(1000),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
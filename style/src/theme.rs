@@ -0,0 +1,270 @@
+//! Share a set of colors between the styles of different widgets.
+use iced_core::Color;
+
+/// A set of colors that the default styles of widgets are derived from.
+///
+/// Widgets without an explicit style resolve their stylesheet from the
+/// active [`Theme`] of the renderer. See [`Styleable`].
+///
+/// The default [`Theme`] is [`Theme::LIGHT`], which produces the same colors
+/// as the default stylesheets of the widgets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// The background color of widgets, like the inside of a radio button.
+    pub background: Color,
+    /// The background color of hovered widgets.
+    pub hovered: Color,
+    /// The color of elements on top of others, like the thumb of a toggler.
+    pub surface: Color,
+    /// The color of text.
+    pub text: Color,
+    /// The color of highlighted elements, like the track of an active
+    /// toggler.
+    pub primary: Color,
+    /// The color of the marks of selected elements, like the dot of a
+    /// selected radio button.
+    pub accent: Color,
+    /// The color of borders and rules.
+    pub border: Color,
+    /// The color of inactive elements, like the track of an inactive toggler.
+    pub inactive: Color,
+}
+
+impl Theme {
+    /// A [`Theme`] with dark text on a light background.
+    pub const LIGHT: Theme = Theme {
+        background: Color::from_rgb(0.95, 0.95, 0.95),
+        hovered: Color::from_rgb(0.90, 0.90, 0.90),
+        surface: Color::WHITE,
+        text: Color::BLACK,
+        primary: Color::from_rgb(0.0, 1.0, 0.0),
+        accent: Color::from_rgb(0.3, 0.3, 0.3),
+        border: Color::from_rgb(0.6, 0.6, 0.6),
+        inactive: Color::from_rgb(0.7, 0.7, 0.7),
+    };
+
+    /// A [`Theme`] with light text on a dark background.
+    pub const DARK: Theme = Theme {
+        background: Color::from_rgb(0.2, 0.21, 0.24),
+        hovered: Color::from_rgb(0.24, 0.25, 0.28),
+        surface: Color::from_rgb(0.9, 0.9, 0.92),
+        text: Color::WHITE,
+        primary: Color::from_rgb(0.3, 0.75, 0.45),
+        accent: Color::from_rgb(0.3, 0.75, 0.45),
+        border: Color::from_rgb(0.45, 0.45, 0.5),
+        inactive: Color::from_rgb(0.35, 0.36, 0.4),
+    };
+}
+
+#[cfg(feature = "serde")]
+impl Theme {
+    /// The names of the color tokens required by [`Theme::from_serde`].
+    pub const TOKENS: [&'static str; 8] = [
+        "background",
+        "hovered",
+        "surface",
+        "text",
+        "primary",
+        "accent",
+        "border",
+        "inactive",
+    ];
+
+    /// Deserializes a [`Theme`] from a JSON object of named color tokens.
+    ///
+    /// Every token in [`Theme::TOKENS`] must be present, and every color must
+    /// be a hex string in the `#rrggbb` or `#rrggbbaa` format. For instance:
+    ///
+    /// ```json
+    /// {
+    ///     "background": "#f2f2f2",
+    ///     "hovered": "#e6e6e6",
+    ///     "surface": "#ffffff",
+    ///     "text": "#000000",
+    ///     "primary": "#00ff00",
+    ///     "accent": "#4d4d4d",
+    ///     "border": "#999999",
+    ///     "inactive": "#b3b3b3"
+    /// }
+    /// ```
+    ///
+    /// Unknown tokens are ignored.
+    pub fn from_serde(json: &str) -> Result<Theme, ThemeError> {
+        let tokens: TokenSet = serde_json::from_str(json)
+            .map_err(|error| ThemeError::Invalid(error.to_string()))?;
+
+        let color = |name: &str, value: &Option<String>| {
+            let value = value
+                .as_ref()
+                .ok_or_else(|| ThemeError::MissingToken(name.to_owned()))?;
+
+            parse_hex(value)
+                .ok_or_else(|| ThemeError::InvalidColor(name.to_owned()))
+        };
+
+        Ok(Theme {
+            background: color("background", &tokens.background)?,
+            hovered: color("hovered", &tokens.hovered)?,
+            surface: color("surface", &tokens.surface)?,
+            text: color("text", &tokens.text)?,
+            primary: color("primary", &tokens.primary)?,
+            accent: color("accent", &tokens.accent)?,
+            border: color("border", &tokens.border)?,
+            inactive: color("inactive", &tokens.inactive)?,
+        })
+    }
+}
+
+/// The color tokens of a [`Theme`], as they are deserialized.
+///
+/// Tokens are optional here, so that a missing one is reported by its name.
+#[cfg(feature = "serde")]
+#[derive(serde_crate::Deserialize)]
+#[serde(crate = "serde_crate")]
+struct TokenSet {
+    background: Option<String>,
+    hovered: Option<String>,
+    surface: Option<String>,
+    text: Option<String>,
+    primary: Option<String>,
+    accent: Option<String>,
+    border: Option<String>,
+    inactive: Option<String>,
+}
+
+/// An error produced when loading a [`Theme`] fails.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeError {
+    /// The input is not a JSON object of strings.
+    Invalid(String),
+
+    /// A required color token is missing.
+    MissingToken(String),
+
+    /// The color of a token is not a valid hex color.
+    InvalidColor(String),
+}
+
+#[cfg(feature = "serde")]
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThemeError::Invalid(error) => write!(f, "invalid theme: {}", error),
+            ThemeError::MissingToken(name) => {
+                write!(f, "missing color token: {}", name)
+            }
+            ThemeError::InvalidColor(name) => {
+                write!(f, "invalid color for token: {}", name)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for ThemeError {}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::LIGHT
+    }
+}
+
+/// A stylesheet that can be resolved from a [`Theme`].
+///
+/// Renderers use it to produce the stylesheet of widgets that were not given
+/// an explicit style.
+pub trait Styleable {
+    /// Produces a stylesheet following the given [`Theme`].
+    fn from_theme(theme: &Theme) -> Self;
+}
+
+#[cfg(feature = "serde")]
+fn parse_hex(value: &str) -> Option<Color> {
+    let hex = value.strip_prefix('#')?;
+
+    if !hex.is_ascii() {
+        return None;
+    }
+
+    let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+
+    match hex.len() {
+        6 => Some(Color::from_rgb8(channel(0)?, channel(2)?, channel(4)?)),
+        8 => Some(Color::from_rgba8(
+            channel(0)?,
+            channel(2)?,
+            channel(4)?,
+            f32::from(channel(6)?) / 255.0,
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn themes_are_parsed_from_color_tokens() {
+        let theme = Theme::from_serde(
+            r##"{
+                "background": "#f2f2f2",
+                "hovered": "#e6e6e6",
+                "surface": "#ffffff",
+                "text": "#000000",
+                "primary": "#00ff00",
+                "accent": "#4d4d4d80",
+                "border": "#999999",
+                "inactive": "#b3b3b3",
+                "unknown": "ignored"
+            }"##,
+        );
+
+        assert_eq!(
+            theme,
+            Ok(Theme {
+                background: Color::from_rgb8(0xf2, 0xf2, 0xf2),
+                hovered: Color::from_rgb8(0xe6, 0xe6, 0xe6),
+                surface: Color::WHITE,
+                text: Color::BLACK,
+                primary: Color::from_rgb(0.0, 1.0, 0.0),
+                accent: Color::from_rgba8(0x4d, 0x4d, 0x4d, 128.0 / 255.0),
+                border: Color::from_rgb8(0x99, 0x99, 0x99),
+                inactive: Color::from_rgb8(0xb3, 0xb3, 0xb3),
+            })
+        );
+    }
+
+    #[test]
+    fn missing_and_invalid_tokens_are_reported() {
+        let tokens = |primary: &str| {
+            format!(
+                r##"{{
+                    "background": "#f2f2f2",
+                    "hovered": "#e6e6e6",
+                    "surface": "#ffffff",
+                    "text": "#000000",
+                    {}
+                    "accent": "#4d4d4d",
+                    "border": "#999999",
+                    "inactive": "#b3b3b3"
+                }}"##,
+                primary
+            )
+        };
+
+        assert_eq!(
+            Theme::from_serde(&tokens("")),
+            Err(ThemeError::MissingToken(String::from("primary")))
+        );
+        assert_eq!(
+            Theme::from_serde(&tokens(r#""primary": "green","#)),
+            Err(ThemeError::InvalidColor(String::from("primary")))
+        );
+        assert!(matches!(
+            Theme::from_serde("[]"),
+            Err(ThemeError::Invalid(_))
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 //! Show toggle controls using togglers.
+use crate::theme::{Styleable, Theme};
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -8,6 +9,28 @@ pub struct Style {
     pub background_border: Option<Color>,
     pub foreground: Color,
     pub foreground_border: Option<Color>,
+    /// The border radius of the track of the toggler.
+    ///
+    /// It is clamped to half the height of the track, so a big enough value
+    /// produces a fully rounded track, while `0.0` produces a rectangular one.
+    pub track_border_radius: f32,
+    /// The border radius of the thumb of the toggler.
+    ///
+    /// It is clamped to half the size of the thumb.
+    pub thumb_border_radius: f32,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: Color::from_rgb(0.7, 0.7, 0.7),
+            background_border: None,
+            foreground: Color::WHITE,
+            foreground_border: None,
+            track_border_radius: f32::INFINITY,
+            thumb_border_radius: f32::INFINITY,
+        }
+    }
 }
 
 /// A set of rules that dictate the style of a toggler.
@@ -17,33 +40,40 @@ pub trait StyleSheet {
     fn hovered(&self, is_active: bool) -> Style;
 }
 
-struct Default;
+struct Themed(Theme);
 
-impl StyleSheet for Default {
+impl StyleSheet for Themed {
     fn active(&self, is_active: bool) -> Style {
         Style {
             background: if is_active {
-                Color::from_rgb(0.0, 1.0, 0.0)
+                self.0.primary
             } else {
-                Color::from_rgb(0.7, 0.7, 0.7)
+                self.0.inactive
             },
             background_border: None,
-            foreground: Color::WHITE,
+            foreground: self.0.surface,
             foreground_border: None,
+            ..Style::default()
         }
     }
 
     fn hovered(&self, is_active: bool) -> Style {
         Style {
-            foreground: Color::from_rgb(0.95, 0.95, 0.95),
+            foreground: self.0.background,
             ..self.active(is_active)
         }
     }
 }
 
+impl Styleable for Box<dyn StyleSheet> {
+    fn from_theme(theme: &Theme) -> Self {
+        Box::new(Themed(*theme))
+    }
+}
+
 impl std::default::Default for Box<dyn StyleSheet> {
     fn default() -> Self {
-        Box::new(Default)
+        Box::from_theme(&Theme::default())
     }
 }
 
@@ -1,6 +1,8 @@
 //! Style your widgets.
 use crate::bumpalo;
-use crate::{Alignment, Background, Color, Length, Padding};
+use crate::{Alignment, Background, Color, ContentFit, Length, Padding};
+
+use iced_core::image;
 
 use std::collections::BTreeMap;
 
@@ -190,9 +192,43 @@ pub fn color(Color { r, g, b, a }: Color) -> String {
 }
 
 /// Returns the style value for the given [`Background`].
+///
+/// The opacity of an image background is not supported, and neither is the
+/// scale of its tiles.
 pub fn background(background: Background) -> String {
     match background {
         Background::Color(c) => color(c),
+        Background::Image { handle, fit, .. } => match image_url(handle) {
+            Some(url) => format!(
+                "{} center / {} no-repeat",
+                url,
+                match fit {
+                    ContentFit::Contain | ContentFit::ScaleDown => "contain",
+                    ContentFit::Cover => "cover",
+                    ContentFit::Fill => "100% 100%",
+                    ContentFit::None => "auto",
+                }
+            ),
+            None => String::from("none"),
+        },
+        Background::Tiled { handle, .. } => match image_url(handle) {
+            Some(url) => format!("{} top left repeat", url),
+            None => String::from("none"),
+        },
+    }
+}
+
+/// Returns the `url()` of the given image, if it is still alive and the
+/// browser is able to decode its data.
+fn image_url(reference: image::Reference) -> Option<String> {
+    match reference.handle()?.data() {
+        image::Data::Path(path) => {
+            path.to_str().map(|path| format!("url(\"{}\")", path))
+        }
+        image::Data::Bytes(bytes) => {
+            Some(format!("url(\"data:;base64,{}\")", base64::encode(bytes)))
+        }
+        image::Data::Pixels { .. } | image::Data::Indexed { .. } => None,
     }
 }
 
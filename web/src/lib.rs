@@ -82,8 +82,8 @@ pub use iced_futures::executor;
 pub use iced_futures::futures;
 
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, BorderStyle, Color, ContentFit, Font, Length,
+    Padding, Point, Rectangle, Size, TextShadow, Vector,
 };
 
 #[doc(no_inline)]
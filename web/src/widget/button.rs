@@ -1,7 +1,7 @@
 //! Allow your users to perform actions by pressing a button.
 //!
 //! A [`Button`] has some local [`State`].
-use crate::{css, Background, Bus, Css, Element, Length, Padding, Widget};
+use crate::{css, Bus, Css, Element, Length, Padding, Widget};
 
 pub use iced_style::button::{Style, StyleSheet};
 
@@ -145,9 +145,7 @@ where
 
         let background = match style.background {
             None => String::from("none"),
-            Some(background) => match background {
-                Background::Color(color) => css::color(color),
-            },
+            Some(background) => css::background(background),
         };
 
         let mut node = button(bump)
@@ -2,6 +2,7 @@ use crate::css;
 use crate::{Alignment, Bus, Css, Element, Length, Padding, Widget};
 
 use dodrio::bumpalo;
+use std::hash::Hash;
 use std::u32;
 
 /// A container that distributes its contents vertically.
@@ -39,6 +40,19 @@ impl<'a, Message> Column<'a, Message> {
         }
     }
 
+    /// Creates a [`Column`] with the given elements, each identified by a
+    /// unique key.
+    ///
+    /// The keys are only used by native renderers to cache layouts. The
+    /// elements are diffed in order on the web.
+    pub fn with_children_keyed<K: Hash>(
+        children: impl IntoIterator<Item = (K, Element<'a, Message>)>,
+    ) -> Self {
+        Self::with_children(
+            children.into_iter().map(|(_, child)| child).collect(),
+        )
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -93,6 +107,15 @@ impl<'a, Message> Column<'a, Message> {
         self.children.push(child.into());
         self
     }
+
+    /// Adds an element to the [`Column`], identified by a unique key.
+    pub fn push_keyed<K, E>(self, _key: K, child: E) -> Self
+    where
+        K: Hash,
+        E: Into<Element<'a, Message>>,
+    {
+        self.push(child)
+    }
 }
 
 impl<'a, Message> Widget<Message> for Column<'a, Message> {
@@ -70,6 +70,30 @@ impl<Message> Radio<Message> {
         }
     }
 
+    /// Creates a new [`Radio`] button identified by an index.
+    ///
+    /// It expects:
+    ///   * the index of the value related to the [`Radio`] button
+    ///   * the label of the [`Radio`] button
+    ///   * the index of the current selected value
+    ///   * a function that will be called when the [`Radio`] is selected. It
+    ///   receives the index of the radio and must produce a `Message`.
+    pub fn new_with(
+        value_index: usize,
+        label: impl Into<String>,
+        selected_index: Option<usize>,
+        f: impl Fn(usize) -> Message,
+    ) -> Self {
+        Radio {
+            is_selected: Some(value_index) == selected_index,
+            on_click: f(value_index),
+            label: label.into(),
+            id: None,
+            name: None,
+            style: Default::default(),
+        }
+    }
+
     /// Sets the style of the [`Radio`] button.
     pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
         self.style = style.into();
@@ -0,0 +1,252 @@
+//! Measures the encoding of a synthetic frame with 5k quads and 1k sections of
+//! text, and counts the heap allocations of a steady-state frame.
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use iced_graphics::layer::{self, Layer};
+use iced_graphics::{
+    Background, BorderAlignment, BorderStyle, BorderWidth, Color, Font, Point,
+    Primitive, Rectangle, Size, Vector, Viewport, WritingMode,
+};
+use iced_native::alignment;
+use iced_native::mouse;
+use iced_wgpu::{Backend, Settings};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Counter;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+const QUADS: usize = 5_000;
+const TEXTS: usize = 1_000;
+
+fn frame() -> (Primitive<Backend>, mouse::Interaction) {
+    let quads = (0..QUADS).map(|i| Primitive::Quad {
+        bounds: Rectangle::new(
+            Point::new((i % 100) as f32 * 10.0, (i / 100) as f32 * 10.0),
+            Size::new(8.0, 8.0),
+        ),
+        background: Background::Color(Color::from_rgb(0.2, 0.4, 0.8)),
+        border_radius: 2.0,
+        border_width: BorderWidth::ZERO,
+        border_color: Color::TRANSPARENT,
+        border_style: BorderStyle::Solid,
+        border_alignment: BorderAlignment::Inside,
+        border_offset: 0.0,
+        fill: true,
+    });
+
+    let texts = (0..TEXTS).map(|i| Primitive::Text {
+        content: format!("Section {}", i),
+        bounds: Rectangle::new(
+            Point::new((i % 10) as f32 * 100.0, (i / 10) as f32 * 20.0),
+            Size::new(100.0, 20.0),
+        ),
+        color: Color::BLACK,
+        size: 16.0,
+        font: Font::Default,
+        horizontal_alignment: alignment::Horizontal::Left,
+        vertical_alignment: alignment::Vertical::Top,
+        letter_spacing: 0.0,
+        writing_mode: WritingMode::Horizontal,
+        background: None,
+    });
+
+    // Some clips to produce a few layers, like scrollables do
+    let clipped = (0..10).map(|i| Primitive::Clip {
+        bounds: Rectangle::new(
+            Point::new(0.0, i as f32 * 100.0),
+            Size::new(1000.0, 100.0),
+        ),
+        offset: Vector::new(0, 0),
+        content: Box::new(Primitive::Group {
+            primitives: vec![Primitive::Quad {
+                bounds: Rectangle::new(
+                    Point::new(0.0, i as f32 * 100.0),
+                    Size::new(1000.0, 100.0),
+                ),
+                background: Background::Color(Color::WHITE),
+                border_radius: 0.0,
+                border_width: BorderWidth::ZERO,
+                border_color: Color::TRANSPARENT,
+                border_style: BorderStyle::Solid,
+                border_alignment: BorderAlignment::Inside,
+                border_offset: 0.0,
+                fill: true,
+            }],
+        }),
+    });
+
+    (
+        Primitive::Group {
+            primitives: quads.chain(texts).chain(clipped).collect(),
+        },
+        mouse::Interaction::default(),
+    )
+}
+
+fn allocations(mut f: impl FnMut()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn layers(c: &mut Criterion) {
+    let (primitive, _) = frame();
+    let viewport = Viewport::with_physical_size(Size::new(1024, 768), 1.0);
+
+    let mut group = c.benchmark_group("layers");
+
+    group.bench_function("generate", |b| {
+        b.iter(|| Layer::generate(&primitive, &viewport))
+    });
+
+    let mut pool = layer::Pool::new();
+
+    group.bench_function("pool", |b| {
+        b.iter(|| {
+            let layers = pool.generate(&primitive, &viewport);
+            pool.recycle(layers);
+        })
+    });
+
+    group.finish();
+
+    let fresh = allocations(|| {
+        let _ = Layer::generate(&primitive, &viewport);
+    });
+
+    let pooled = allocations(|| {
+        let layers = pool.generate(&primitive, &viewport);
+        pool.recycle(layers);
+    });
+
+    println!("layers: {} allocations fresh, {} pooled", fresh, pooled);
+}
+
+fn draw(c: &mut Criterion) {
+    let instance = wgpu::Instance::new(wgpu::Backends::all());
+
+    let adapter = futures::executor::block_on(instance.request_adapter(
+        &wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::default(),
+            compatible_surface: None,
+        },
+    ));
+
+    let adapter = match adapter {
+        Some(adapter) => adapter,
+        None => {
+            println!("draw: skipped, no graphics adapter available");
+            return;
+        }
+    };
+
+    let (device, queue) = futures::executor::block_on(adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            label: Some("iced_wgpu::benches::encode device"),
+            features: wgpu::Features::empty(),
+            limits: wgpu::Limits::default(),
+        },
+        None,
+    ))
+    .expect("Request device");
+
+    let format = wgpu::TextureFormat::Rgba8UnormSrgb;
+    let device = std::sync::Arc::new(device);
+    let queue = std::sync::Arc::new(queue);
+
+    let mut backend = Backend::new(
+        device.clone(),
+        queue.clone(),
+        Settings::default(),
+        format,
+    );
+
+    let viewport = Viewport::with_physical_size(Size::new(1024, 768), 1.0);
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("iced_wgpu::benches::encode target"),
+        size: wgpu::Extent3d {
+            width: 1024,
+            height: 768,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+    });
+
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let output = frame();
+    let overlay: [&str; 0] = [];
+
+    let mut staging_belt = wgpu::util::StagingBelt::new(4 * 1024 * 1024);
+    let mut local_pool = futures::executor::LocalPool::new();
+
+    let mut encode = || {
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu::benches::encode encoder"),
+            });
+
+        let _ = backend.draw(
+            &device,
+            &mut staging_belt,
+            &mut encoder,
+            &view,
+            &viewport,
+            &output,
+            &overlay,
+        );
+
+        staging_belt.finish();
+        queue.submit(Some(encoder.finish()));
+
+        use futures::task::SpawnExt;
+
+        local_pool
+            .spawner()
+            .spawn(staging_belt.recall())
+            .expect("Recall staging belt");
+
+        local_pool.run_until_stalled();
+    };
+
+    c.bench_function("draw", |b| b.iter(&mut encode));
+
+    println!(
+        "draw: {} allocations in a steady frame",
+        allocations(encode)
+    );
+}
+
+criterion_group!(benches, layers, draw);
+criterion_main!(benches);
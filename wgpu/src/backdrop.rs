@@ -0,0 +1,273 @@
+use iced_graphics::layer;
+use iced_native::{Rectangle, Size};
+
+use bytemuck::{Pod, Zeroable};
+use std::mem;
+use wgpu::util::DeviceExt;
+
+/// The largest blur radius supported, in physical pixels.
+const MAX_RADIUS: f32 = 64.0;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    layout: wgpu::BindGroupLayout,
+    format: wgpu::TextureFormat,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Pipeline {
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::backdrop layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float {
+                                filterable: false,
+                            },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::backdrop pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&layout],
+            });
+
+        let shader =
+            device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::backdrop::shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/backdrop.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::backdrop pipeline"),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[wgpu::ColorTargetState {
+                        format,
+                        blend: None,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            });
+
+        Pipeline {
+            pipeline,
+            layout,
+            format,
+        }
+    }
+
+    /// Blurs the region of the `target` behind the given [`layer::Backdrop`].
+    ///
+    /// The blur is separable: a horizontal pass reads the `target` into an
+    /// intermediate texture, and a vertical pass writes it back tinted.
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        backdrop: &layer::Backdrop,
+        scale_factor: f32,
+        target: &wgpu::TextureView,
+        target_size: Size<u32>,
+    ) {
+        let region = (backdrop.bounds * scale_factor).snap();
+
+        let region = Rectangle {
+            width: region.width.min(target_size.width.saturating_sub(region.x)),
+            height: region
+                .height
+                .min(target_size.height.saturating_sub(region.y)),
+            ..region
+        };
+
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        let radius = (backdrop.blur_radius * scale_factor).min(MAX_RADIUS);
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::backdrop texture"),
+            size: wgpu::Extent3d {
+                width: region.width,
+                height: region.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let horizontal = self.bind_group(
+            device,
+            target,
+            Uniforms {
+                tint: [0.0, 0.0, 0.0, 0.0],
+                offset: [region.x as f32, region.y as f32],
+                source_size: [
+                    target_size.width as f32,
+                    target_size.height as f32,
+                ],
+                direction: [1.0, 0.0],
+                radius,
+                _padding: 0.0,
+            },
+        );
+
+        let vertical = self.bind_group(
+            device,
+            &view,
+            Uniforms {
+                tint: backdrop.tint,
+                offset: [-(region.x as f32), -(region.y as f32)],
+                source_size: [region.width as f32, region.height as f32],
+                direction: [0.0, 1.0],
+                radius,
+                _padding: 0.0,
+            },
+        );
+
+        {
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::backdrop horizontal pass"),
+                    color_attachments: &[wgpu::RenderPassColorAttachment {
+                        view: &view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                            store: true,
+                        },
+                    }],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &horizontal, &[]);
+            render_pass.draw(0..3, 0..1);
+        }
+
+        {
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::backdrop vertical pass"),
+                    color_attachments: &[wgpu::RenderPassColorAttachment {
+                        view: target,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: true,
+                        },
+                    }],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &vertical, &[]);
+            render_pass.set_viewport(
+                region.x as f32,
+                region.y as f32,
+                region.width as f32,
+                region.height as f32,
+                0.0,
+                1.0,
+            );
+            render_pass.set_scissor_rect(
+                region.x,
+                region.y,
+                region.width,
+                region.height,
+            );
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+
+    fn bind_group(
+        &self,
+        device: &wgpu::Device,
+        source: &wgpu::TextureView,
+        uniforms: Uniforms,
+    ) -> wgpu::BindGroup {
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::backdrop uniforms buffer"),
+                contents: bytemuck::bytes_of(&uniforms),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::backdrop bind group"),
+            layout: &self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(source),
+                },
+            ],
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    tint: [f32; 4],
+    offset: [f32; 2],
+    source_size: [f32; 2],
+    direction: [f32; 2],
+    radius: f32,
+    _padding: f32,
+}
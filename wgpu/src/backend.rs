@@ -1,23 +1,46 @@
 use std::sync::Arc;
 
+#[cfg(feature = "backdrop-filter")]
+use crate::backdrop;
 use crate::direct_wgpu;
 use crate::quad;
 use crate::text;
 use crate::triangle;
-use crate::{Settings, Transformation};
+use crate::video;
+use crate::{ColorSpace, Settings, Transformation};
 
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::layer::Layer;
+use iced_graphics::layer::{self, Layer};
 use iced_graphics::{Primitive, Viewport};
 use iced_native::alignment;
+use iced_native::futures::channel::oneshot;
 use iced_native::mouse;
-use iced_native::{Font, Size};
+use iced_native::trace;
+use iced_native::{Font, Rectangle, Size, Vector, WritingMode};
 
 #[cfg(any(feature = "image_rs", feature = "svg"))]
 use crate::image;
 
-pub use direct_wgpu::DirectWgpuJob;
+pub use direct_wgpu::{ComputeStage, DirectWgpuJob, RenderStage};
+
+/// A region of a frame drawn by a [`Backend`] with its own [`Viewport`].
+///
+/// It allows drawing independent user interfaces with different scale factors
+/// into the same frame.
+#[allow(missing_debug_implementations)]
+pub struct SubViewport<'a> {
+    /// The physical position of the region in the frame.
+    pub origin: Vector<u32>,
+
+    /// The [`Viewport`] of the region.
+    ///
+    /// Its physical size is the size of the region in the frame.
+    pub viewport: &'a Viewport,
+
+    /// The primitive to draw in the region, alongside its mouse interaction.
+    pub primitive: &'a (Primitive<Backend>, mouse::Interaction),
+}
 
 /// A [`wgpu`] graphics backend for [`iced`].
 ///
@@ -28,17 +51,32 @@ pub struct Backend {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     format: wgpu::TextureFormat,
+    present_mode: wgpu::PresentMode,
+    color_space: ColorSpace,
 
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
 
+    #[cfg(feature = "image_rs")]
+    textured_triangle_pipeline: triangle::textured::Pipeline,
+
     #[cfg(any(feature = "image_rs", feature = "svg"))]
     image_pipeline: image::Pipeline,
 
+    video_pipeline: video::Pipeline,
+
+    #[cfg(feature = "backdrop-filter")]
+    backdrop_pipeline: backdrop::Pipeline,
+
     wgpu_area_pipeline: direct_wgpu::Pipeline,
 
+    layers: layer::Pool<Backend>,
+    staged_bytes: u64,
+
     default_text_size: u16,
+    frame_captures: Vec<oneshot::Sender<Vec<u8>>>,
+    current_frame: Option<Arc<wgpu::TextureView>>,
 }
 
 impl Backend {
@@ -54,6 +92,8 @@ impl Backend {
             format,
             settings.default_font,
             settings.text_multithreading,
+            settings.hinting,
+            settings.subpixel_positioning,
         );
 
         let quad_pipeline = quad::Pipeline::new(&*device, format);
@@ -63,26 +103,108 @@ impl Backend {
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         let image_pipeline = image::Pipeline::new(&*device, format);
 
+        #[cfg(feature = "image_rs")]
+        let textured_triangle_pipeline = triangle::textured::Pipeline::new(
+            &*device,
+            format,
+            settings.antialiasing,
+            image_pipeline.texture_layout(),
+        );
+
+        let video_pipeline = video::Pipeline::new(&*device, format);
+
+        #[cfg(feature = "backdrop-filter")]
+        let backdrop_pipeline = backdrop::Pipeline::new(&*device, format);
+
         let wgpu_area_pipeline = direct_wgpu::Pipeline::new();
 
         Self {
             device,
             queue,
             format,
+            present_mode: settings.present_mode,
+            color_space: settings.color_space,
 
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
 
+            #[cfg(feature = "image_rs")]
+            textured_triangle_pipeline,
+
             #[cfg(any(feature = "image_rs", feature = "svg"))]
             image_pipeline,
 
+            video_pipeline,
+
+            #[cfg(feature = "backdrop-filter")]
+            backdrop_pipeline,
+
             wgpu_area_pipeline,
 
+            layers: layer::Pool::new(),
+            staged_bytes: 0,
+
             default_text_size: settings.default_text_size,
+            frame_captures: Vec::new(),
+            current_frame: None,
         }
     }
 
+    /// Requests the pixels of the next frame presented by the [`Backend`] to be
+    /// sent through the given `sender`, as RGBA bytes.
+    ///
+    /// Every request made before the next frame is fulfilled with it.
+    pub fn capture_next_frame(&mut self, sender: oneshot::Sender<Vec<u8>>) {
+        self.frame_captures.push(sender);
+    }
+
+    pub(crate) fn take_frame_captures(
+        &mut self,
+    ) -> Vec<oneshot::Sender<Vec<u8>>> {
+        std::mem::take(&mut self.frame_captures)
+    }
+
+    /// Returns the `TextureView` of the frame currently being rendered, if any.
+    ///
+    /// It is available while a compositor renders a frame, until the frame is
+    /// presented. This allows external code to encode its own
+    /// [`wgpu::RenderPass`] into the same texture, before or after the
+    /// primitives of [`iced`], without going through a [`DirectWgpuJob`].
+    ///
+    /// [`iced`]: https://github.com/hecrj/iced
+    pub fn current_frame_texture(&self) -> Option<&wgpu::TextureView> {
+        self.current_frame.as_deref()
+    }
+
+    pub(crate) fn set_current_frame(
+        &mut self,
+        frame: Option<Arc<wgpu::TextureView>>,
+    ) {
+        self.current_frame = frame;
+    }
+
+    /// Returns an estimate of the bytes written to the staging belt since
+    /// the last call, and resets it.
+    pub(crate) fn take_staged_bytes(&mut self) -> u64 {
+        std::mem::take(&mut self.staged_bytes)
+    }
+
+    /// Queues some compute work to run in the next frame drawn by the
+    /// [`Backend`], before anything is rendered.
+    ///
+    /// The queued [`ComputeStage`] list is dispatched in order, in the
+    /// command encoder of the frame, before the render pass of any primitive.
+    /// Any buffer written by it can therefore be read by a [`DirectWgpuJob`]
+    /// of the same frame. Then, the queue is emptied.
+    ///
+    /// A custom widget can queue compute work while it is drawn, using
+    /// `Renderer::backend_mut`. Unlike [`DirectWgpuJob::with_compute`], the
+    /// work runs even if the widget does not produce a [`DirectWgpuJob`].
+    pub fn queue_compute(&mut self, stage: impl ComputeStage + 'static) {
+        self.wgpu_area_pipeline.queue_compute(Box::new(stage));
+    }
+
     /// Draws the provided primitives in the given `TextureView`.
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
@@ -100,21 +222,58 @@ impl Backend {
         ),
         overlay_text: &[T],
     ) -> mouse::Interaction {
+        let overlay_text = self.overlay_with_stats(overlay_text);
+
+        let mut layers = self.layers.generate(primitive, viewport);
+        layers.push(Layer::overlay(&overlay_text, viewport));
+
+        self.draw_layers(
+            device,
+            staging_belt,
+            encoder,
+            frame,
+            viewport,
+            &layers,
+        );
+
+        self.layers.recycle(layers);
+
+        mouse_interaction.clone()
+    }
+
+    /// Draws the provided layers in the given `TextureView`, in order.
+    ///
+    /// The layers of the primitives of another [`Backend`] can be drawn with
+    /// [`Layer::without_customs`].
+    pub fn draw_layers(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        viewport: &Viewport,
+        layers: &[Layer<'_, Backend>],
+    ) {
         log::debug!("Drawing");
 
         let target_size = viewport.physical_size();
         let scale_factor = viewport.scale_factor() as f32;
         let transformation = viewport.projection();
 
-        let mut layers = Layer::generate(primitive, viewport);
-        layers.push(Layer::overlay(overlay_text, viewport));
+        self.wgpu_area_pipeline.dispatch_queued(
+            device,
+            staging_belt,
+            encoder,
+            frame,
+            scale_factor,
+        );
 
         for layer in layers {
             self.flush(
                 device,
                 scale_factor,
                 transformation,
-                &layer,
+                layer,
                 staging_belt,
                 encoder,
                 &frame,
@@ -126,7 +285,122 @@ impl Backend {
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         self.image_pipeline.trim_cache();
 
-        *mouse_interaction
+        self.video_pipeline.trim_cache();
+    }
+
+    /// Draws the provided [`SubViewport`] list in the given `TextureView`.
+    ///
+    /// Each [`SubViewport`] is scaled with its own scale factor and clipped to
+    /// its own region, which should fit in the `target` viewport of the frame.
+    /// The text provided as overlay will be rendered on top of all of them.
+    ///
+    /// It returns the highest mouse interaction of the drawn primitives.
+    pub fn draw_viewports<T: AsRef<str>>(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        target: &Viewport,
+        viewports: &[SubViewport<'_>],
+        overlay_text: &[T],
+    ) -> mouse::Interaction {
+        log::debug!("Drawing {} viewports", viewports.len());
+
+        let target_size = target.physical_size();
+        let transformation = target.projection();
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        self.wgpu_area_pipeline.dispatch_queued(
+            device,
+            staging_belt,
+            encoder,
+            frame,
+            target.scale_factor() as f32,
+        );
+
+        for sub_viewport in viewports {
+            let (primitive, interaction) = sub_viewport.primitive;
+            let scale_factor = sub_viewport.viewport.scale_factor() as f32;
+
+            let layers = self.layers.generate_at(
+                primitive,
+                sub_viewport.viewport,
+                sub_viewport.origin,
+            );
+
+            for layer in &layers {
+                self.flush(
+                    device,
+                    scale_factor,
+                    transformation,
+                    layer,
+                    staging_belt,
+                    encoder,
+                    &frame,
+                    target_size.width,
+                    target_size.height,
+                );
+            }
+
+            self.layers.recycle(layers);
+
+            if *interaction > mouse_interaction {
+                mouse_interaction = interaction.clone();
+            }
+        }
+
+        let overlay_text = self.overlay_with_stats(overlay_text);
+        let overlay = Layer::overlay(&overlay_text, target);
+
+        self.flush(
+            device,
+            target.scale_factor() as f32,
+            transformation,
+            &overlay,
+            staging_belt,
+            encoder,
+            &frame,
+            target_size.width,
+            target_size.height,
+        );
+
+        #[cfg(any(feature = "image_rs", feature = "svg"))]
+        self.image_pipeline.trim_cache();
+
+        self.video_pipeline.trim_cache();
+
+        mouse_interaction
+    }
+
+    /// Returns whether some work, like the rasterization of an SVG, is still
+    /// running in the background, or whether a video is playing.
+    ///
+    /// Another frame should be drawn to display its result.
+    pub fn has_pending_work(&self) -> bool {
+        if self.video_pipeline.is_playing() {
+            return true;
+        }
+
+        #[cfg(any(feature = "image_rs", feature = "svg"))]
+        return self.image_pipeline.has_pending_work();
+
+        #[cfg(not(any(feature = "image_rs", feature = "svg")))]
+        false
+    }
+
+    /// Appends the statistics of the caches to a non-empty debug overlay.
+    fn overlay_with_stats<T: AsRef<str>>(&self, lines: &[T]) -> Vec<String> {
+        let mut overlay: Vec<String> =
+            lines.iter().map(|line| line.as_ref().to_owned()).collect();
+
+        #[cfg(any(feature = "image_rs", feature = "svg"))]
+        if !overlay.is_empty() {
+            overlay.extend(self.image_pipeline.stats());
+        }
+
+        overlay
     }
 
     fn flush(
@@ -143,7 +417,37 @@ impl Backend {
     ) {
         let bounds = (layer.bounds * scale_factor).snap();
 
+        self.staged_bytes += staged_bytes(layer);
+
+        if let Some(backdrop) = &layer.backdrop {
+            let _span = trace::encode("backdrop");
+
+            #[cfg(feature = "backdrop-filter")]
+            self.backdrop_pipeline.draw(
+                device,
+                encoder,
+                backdrop,
+                scale_factor,
+                target,
+                Size::new(target_width, target_height),
+            );
+
+            #[cfg(not(feature = "backdrop-filter"))]
+            self.quad_pipeline.draw(
+                device,
+                staging_belt,
+                encoder,
+                &[backdrop.tint_quad()],
+                transformation,
+                scale_factor,
+                bounds,
+                target,
+            );
+        }
+
         if !layer.quads.is_empty() {
+            let _span = trace::encode("quad");
+
             self.quad_pipeline.draw(
                 device,
                 staging_belt,
@@ -157,6 +461,8 @@ impl Backend {
         }
 
         if !layer.meshes.is_empty() {
+            let _span = trace::encode("triangle");
+
             let scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor);
 
@@ -173,9 +479,49 @@ impl Backend {
             );
         }
 
+        #[cfg(feature = "image_rs")]
+        {
+            let _span = trace::encode("textured_triangle");
+
+            let meshes: Vec<_> = layer
+                .textured_meshes
+                .iter()
+                .filter_map(|mesh| {
+                    self.image_pipeline
+                        .upload(device, encoder, mesh.handle)
+                        .map(|region| (mesh, region))
+                })
+                .collect();
+
+            if !meshes.is_empty() {
+                let scaled = transformation
+                    * Transformation::scale(scale_factor, scale_factor);
+
+                self.textured_triangle_pipeline.draw(
+                    device,
+                    staging_belt,
+                    encoder,
+                    target,
+                    target_width,
+                    target_height,
+                    scaled,
+                    scale_factor,
+                    &meshes,
+                    self.image_pipeline.texture(device),
+                );
+            }
+        }
+
+        #[cfg(not(feature = "image_rs"))]
+        if !layer.textured_meshes.is_empty() {
+            log::warn!("Textured meshes need the `image_rs` feature");
+        }
+
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         {
             if !layer.images.is_empty() {
+                let _span = trace::encode("image");
+
                 let scaled = transformation
                     * Transformation::scale(scale_factor, scale_factor);
 
@@ -192,7 +538,26 @@ impl Backend {
             }
         }
 
+        if !layer.videos.is_empty() {
+            let _span = trace::encode("video");
+
+            let scaled = transformation
+                * Transformation::scale(scale_factor, scale_factor);
+
+            self.video_pipeline.draw(
+                device,
+                staging_belt,
+                encoder,
+                &layer.videos,
+                scaled,
+                bounds,
+                target,
+            );
+        }
+
         if !layer.customs.is_empty() {
+            let _span = trace::encode("custom");
+
             self.wgpu_area_pipeline.draw(
                 device,
                 staging_belt,
@@ -206,16 +571,36 @@ impl Backend {
         //self.wgpu_area_pipeline.draw(device, staging_belt, encoder, target, scale_factor);
 
         if !layer.text.is_empty() {
+            let _span = trace::encode("text");
+
+            #[cfg(feature = "color-emoji")]
+            let mut emoji = Vec::new();
+
             for text in layer.text.iter() {
+                let (_, sections) = self.text_pipeline.sections(
+                    text.content,
+                    self.text_pipeline.find_font(text.font),
+                    wgpu_glyph::ab_glyph::PxScale {
+                        x: text.size * scale_factor,
+                        y: text.size * scale_factor,
+                    },
+                    wgpu_glyph::Extra {
+                        color: text.color,
+                        z: 0.0,
+                    },
+                );
+
+                let letter_spacing = text.letter_spacing * scale_factor;
+                let writing_mode = text.writing_mode;
+
                 // Target physical coordinates directly to avoid blurry text
                 let text = wgpu_glyph::Section {
-                    // TODO: We `round` here to avoid rerasterizing text when
-                    // its position changes slightly. This can make text feel a
-                    // bit "jumpy". We may be able to do better once we improve
-                    // our text rendering/caching pipeline.
-                    screen_position: (
-                        (text.bounds.x * scale_factor).round(),
-                        (text.bounds.y * scale_factor).round(),
+                    // We snap the position to avoid rerasterizing text when
+                    // it changes slightly. Unless subpixel positioning is
+                    // enabled, this can make text feel a bit "jumpy".
+                    screen_position: self.text_pipeline.snap_origin(
+                        text.bounds.x * scale_factor,
+                        text.bounds.y * scale_factor,
                     ),
                     // TODO: Fix precision issues with some scale factors.
                     //
@@ -230,18 +615,7 @@ impl Backend {
                         (text.bounds.width * scale_factor).ceil(),
                         (text.bounds.height * scale_factor).ceil(),
                     ),
-                    text: vec![wgpu_glyph::Text {
-                        text: text.content,
-                        scale: wgpu_glyph::ab_glyph::PxScale {
-                            x: text.size * scale_factor,
-                            y: text.size * scale_factor,
-                        },
-                        font_id: self.text_pipeline.find_font(text.font),
-                        extra: wgpu_glyph::Extra {
-                            color: text.color,
-                            z: 0.0,
-                        },
-                    }],
+                    text: sections,
                     layout: wgpu_glyph::Layout::default()
                         .h_align(match text.horizontal_alignment {
                             alignment::Horizontal::Left => {
@@ -268,7 +642,19 @@ impl Backend {
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                // Color emoji are drawn as images, in logical coordinates
+                #[cfg(feature = "color-emoji")]
+                emoji.extend(
+                    self.text_pipeline
+                        .emoji_images(&text, letter_spacing, writing_mode)
+                        .into_iter()
+                        .map(|(handle, bounds)| layer::Image::Raster {
+                            handle,
+                            bounds: bounds * (1.0 / scale_factor),
+                        }),
+                );
+
+                self.text_pipeline.queue(text, letter_spacing, writing_mode);
             }
 
             self.text_pipeline.draw_queued(
@@ -284,6 +670,23 @@ impl Backend {
                     height: bounds.height,
                 },
             );
+
+            #[cfg(feature = "color-emoji")]
+            if !emoji.is_empty() {
+                let scaled = transformation
+                    * Transformation::scale(scale_factor, scale_factor);
+
+                self.image_pipeline.draw(
+                    device,
+                    staging_belt,
+                    encoder,
+                    &emoji,
+                    scaled,
+                    bounds,
+                    target,
+                    scale_factor,
+                );
+            }
         }
     }
     /// Get the wgpu::Device used for rendering.
@@ -301,6 +704,22 @@ impl Backend {
     pub fn get_format(&self) -> wgpu::TextureFormat {
         self.format.clone()
     }
+
+    /// Get the present mode of the surface this [`Backend`] renders to.
+    ///
+    /// It may differ from the one requested in the [`Settings`] if the
+    /// graphics adapter does not support it.
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.present_mode
+    }
+
+    /// Get the [`ColorSpace`] of the surface this [`Backend`] renders to.
+    ///
+    /// It may differ from the one requested in the [`Settings`] if the
+    /// surface does not support it.
+    pub fn color_space(&self) -> ColorSpace {
+        self.color_space
+    }
 }
 
 impl iced_graphics::Backend for Backend {
@@ -326,7 +745,51 @@ impl backend::Text for Backend {
         font: Font,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            bounds,
+            0.0,
+            WritingMode::Horizontal,
+        )
+    }
+
+    fn measure_with_letter_spacing(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            bounds,
+            letter_spacing,
+            WritingMode::Horizontal,
+        )
+    }
+
+    fn measure_with_writing_mode(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure(
+            contents,
+            size,
+            font,
+            bounds,
+            letter_spacing,
+            writing_mode,
+        )
     }
 
     fn hit_test(
@@ -347,6 +810,17 @@ impl backend::Text for Backend {
             nearest_only,
         )
     }
+
+    fn glyph_positions(
+        &self,
+        contents: &str,
+        size: f32,
+        font: Font,
+        bounds: Size,
+    ) -> Vec<Rectangle> {
+        self.text_pipeline
+            .glyph_positions(contents, size, font, bounds)
+    }
 }
 
 #[cfg(feature = "image_rs")]
@@ -365,3 +839,23 @@ impl backend::Svg for Backend {
         self.image_pipeline.viewport_dimensions(handle)
     }
 }
+
+/// Estimates the bytes of the quads and meshes of a [`Layer`] written to the
+/// staging belt while drawing it.
+fn staged_bytes(layer: &Layer<'_, Backend>) -> u64 {
+    use iced_graphics::triangle::Vertex2D;
+    use std::mem;
+
+    let quads = layer.quads.len() * mem::size_of::<layer::Quad>();
+
+    let meshes: usize = layer
+        .meshes
+        .iter()
+        .map(|mesh| {
+            mesh.buffers.vertices.len() * mem::size_of::<Vertex2D>()
+                + mesh.buffers.indices.len() * mem::size_of::<u32>()
+        })
+        .sum();
+
+    (quads + meshes) as u64
+}
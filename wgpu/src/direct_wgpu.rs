@@ -4,10 +4,17 @@ use iced_graphics::Rectangle;
 
 /// A render job containing [`wgpu::RenderBundle`] to describe what to render
 /// and [`Rectangle`] to describe where to render it.
+///
+/// A job can also have a [`ComputeStage`] to run some compute work right
+/// before its render bundle, and a [`RenderStage`] to encode its own render
+/// passes right after it. See [`DirectWgpuJob::with_compute`] and
+/// [`DirectWgpuJob::with_render`].
 #[derive(Clone, Debug)]
 pub struct DirectWgpuJob {
     bundle: Rc<wgpu::RenderBundle>,
     bounds: Rectangle,
+    compute: Option<Rc<dyn ComputeStage>>,
+    render: Option<Rc<dyn RenderStage>>,
 }
 
 impl DirectWgpuJob {
@@ -20,17 +27,162 @@ impl DirectWgpuJob {
     /// [`DirectWgpuJob`] internally stores the Bundle in an [`Rc`],
     /// so if you already have it in `Rc` we don't have to create a new one.
     pub fn new_rc(bundle: Rc<wgpu::RenderBundle>, bounds: Rectangle) -> Self {
-        Self { bundle, bounds }
+        Self {
+            bundle,
+            bounds,
+            compute: None,
+            render: None,
+        }
+    }
+
+    /// Runs the given [`ComputeStage`] before the render bundle of the
+    /// [`DirectWgpuJob`].
+    ///
+    /// The compute pass of a job is encoded into the command encoder of the
+    /// frame right before the render pass of its bundle, after the primitives
+    /// drawn before the job and before the primitives drawn after it. The
+    /// whole frame is still submitted at once, and any buffer written by the
+    /// compute pass can be read by the render bundle.
+    pub fn with_compute(self, stage: impl ComputeStage + 'static) -> Self {
+        self.with_compute_rc(Rc::new(stage))
+    }
+
+    /// Runs the given [`ComputeStage`] before the render bundle of the
+    /// [`DirectWgpuJob`].
+    ///
+    /// This is the same as [`DirectWgpuJob::with_compute`], but it reuses a
+    /// [`ComputeStage`] that is already in an [`Rc`].
+    pub fn with_compute_rc(mut self, stage: Rc<dyn ComputeStage>) -> Self {
+        self.compute = Some(stage);
+        self
+    }
+
+    /// Runs the given [`RenderStage`] after the render bundle of the
+    /// [`DirectWgpuJob`].
+    ///
+    /// The [`RenderStage`] receives the texture view of the frame being
+    /// rendered, after the primitives drawn before the job and before the
+    /// primitives drawn after it. This way, it can encode its own
+    /// [`wgpu::RenderPass`] into the frame.
+    pub fn with_render(self, stage: impl RenderStage + 'static) -> Self {
+        self.with_render_rc(Rc::new(stage))
     }
+
+    /// Runs the given [`RenderStage`] after the render bundle of the
+    /// [`DirectWgpuJob`].
+    ///
+    /// This is the same as [`DirectWgpuJob::with_render`], but it reuses a
+    /// [`RenderStage`] that is already in an [`Rc`].
+    pub fn with_render_rc(mut self, stage: Rc<dyn RenderStage>) -> Self {
+        self.render = Some(stage);
+        self
+    }
+}
+
+/// Some compute work that runs right before the render bundle of a
+/// [`DirectWgpuJob`].
+///
+/// It can be used to simulate particles or animate geometry on the GPU every
+/// frame, without an extra queue submission.
+pub trait ComputeStage: std::fmt::Debug {
+    /// Records the compute work of the [`ComputeStage`] in the given
+    /// [`wgpu::ComputePass`].
+    ///
+    /// The resources used by the pass must be borrowed from the
+    /// [`ComputeStage`] itself.
+    fn dispatch<'a>(&'a self, pass: &mut wgpu::ComputePass<'a>);
+}
+
+/// Some rendering that a [`DirectWgpuJob`] encodes itself, right after its
+/// render bundle.
+///
+/// It can be used to composite the user interface with the output of another
+/// renderer, like a game engine or a media player, in the same frame.
+pub trait RenderStage: std::fmt::Debug {
+    /// Encodes the rendering of the [`RenderStage`] in the given
+    /// [`wgpu::CommandEncoder`].
+    ///
+    /// The `target` is the texture view of the frame being rendered, and the
+    /// `bounds` are those of the [`DirectWgpuJob`].
+    fn encode(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        bounds: Rectangle,
+    );
+}
+
+/// A pass encoded by the [`Pipeline`], by index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pass {
+    Queued(usize),
+    Compute(usize),
+    Render(usize),
+    Encode(usize),
+}
+
+/// Returns the passes to encode for some queued compute jobs and some render
+/// jobs, given whether each render job has a [`ComputeStage`] and a
+/// [`RenderStage`].
+///
+/// The queued compute jobs always come first.
+fn passes(
+    queued: usize,
+    stages: impl Iterator<Item = (bool, bool)>,
+) -> Vec<Pass> {
+    let jobs =
+        stages
+            .enumerate()
+            .flat_map(|(index, (has_compute, has_render))| {
+                let compute = if has_compute {
+                    Some(Pass::Compute(index))
+                } else {
+                    None
+                };
+
+                let render = if has_render {
+                    Some(Pass::Encode(index))
+                } else {
+                    None
+                };
+
+                compute
+                    .into_iter()
+                    .chain(Some(Pass::Render(index)))
+                    .chain(render)
+            });
+
+    (0..queued).map(Pass::Queued).chain(jobs).collect()
 }
 
 #[derive(Debug)]
-pub struct Pipeline;
+pub struct Pipeline {
+    queued: Vec<Box<dyn ComputeStage>>,
+}
 
 impl Pipeline {
     pub fn new() -> Self {
-        Self
+        Self { queued: Vec::new() }
     }
+
+    pub fn queue_compute(&mut self, stage: Box<dyn ComputeStage>) {
+        self.queued.push(stage);
+    }
+
+    /// Encodes the queued compute jobs, without any render job.
+    pub fn dispatch_queued(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        scale: f32,
+    ) {
+        if !self.queued.is_empty() {
+            self.draw(device, staging_belt, encoder, &[], target, scale);
+        }
+    }
+
     pub fn draw(
         &mut self,
         _device: &wgpu::Device,
@@ -40,30 +192,132 @@ impl Pipeline {
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
-        for job in jobs {
-            let DirectWgpuJob { bundle, bounds } = job;
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: None,
-                    color_attachments: &[wgpu::RenderPassColorAttachment {
-                        view: target,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: true,
+        let passes = passes(
+            self.queued.len(),
+            jobs.iter()
+                .map(|job| (job.compute.is_some(), job.render.is_some())),
+        );
+
+        for pass in passes {
+            match pass {
+                Pass::Queued(index) => {
+                    let mut compute_pass = encoder.begin_compute_pass(
+                        &wgpu::ComputePassDescriptor {
+                            label: Some(
+                                "iced_wgpu::direct_wgpu queued compute",
+                            ),
+                        },
+                    );
+
+                    self.queued[index].dispatch(&mut compute_pass);
+                }
+                Pass::Compute(index) => {
+                    if let Some(stage) = &jobs[index].compute {
+                        let mut compute_pass = encoder.begin_compute_pass(
+                            &wgpu::ComputePassDescriptor {
+                                label: Some("iced_wgpu::direct_wgpu compute"),
+                            },
+                        );
+
+                        stage.dispatch(&mut compute_pass);
+                    }
+                }
+                Pass::Render(index) => {
+                    let DirectWgpuJob { bundle, bounds, .. } = jobs[index];
+                    let mut render_pass = encoder.begin_render_pass(
+                        &wgpu::RenderPassDescriptor {
+                            label: None,
+                            color_attachments: &[
+                                wgpu::RenderPassColorAttachment {
+                                    view: target,
+                                    resolve_target: None,
+                                    ops: wgpu::Operations {
+                                        load: wgpu::LoadOp::Load,
+                                        store: true,
+                                    },
+                                },
+                            ],
+                            depth_stencil_attachment: None,
                         },
-                    }],
-                    depth_stencil_attachment: None,
-                });
-            render_pass.set_viewport(
-                bounds.x,
-                bounds.y,
-                bounds.width,
-                bounds.height,
-                0.0,
-                1.0,
-            );
-            render_pass.execute_bundles(std::iter::once(&**bundle));
+                    );
+                    render_pass.set_viewport(
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                        0.0,
+                        1.0,
+                    );
+                    render_pass.execute_bundles(std::iter::once(&**bundle));
+                }
+                Pass::Encode(index) => {
+                    let DirectWgpuJob { bounds, render, .. } = jobs[index];
+
+                    if let Some(stage) = render {
+                        stage.encode(encoder, target, *bounds);
+                    }
+                }
+            }
         }
+
+        self.queued.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compute_passes_run_right_before_their_render_bundle() {
+        let passes = passes(
+            0,
+            vec![(true, false), (false, false), (true, false)].into_iter(),
+        );
+
+        assert_eq!(
+            passes,
+            vec![
+                Pass::Compute(0),
+                Pass::Render(0),
+                Pass::Render(1),
+                Pass::Compute(2),
+                Pass::Render(2),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_stages_run_right_after_their_render_bundle() {
+        let passes = passes(0, vec![(false, true), (true, true)].into_iter());
+
+        assert_eq!(
+            passes,
+            vec![
+                Pass::Render(0),
+                Pass::Encode(0),
+                Pass::Compute(1),
+                Pass::Render(1),
+                Pass::Encode(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn queued_compute_jobs_are_encoded_before_any_render_pass() {
+        let passes = passes(2, vec![(true, false), (false, false)].into_iter());
+
+        assert_eq!(
+            passes,
+            vec![
+                Pass::Queued(0),
+                Pass::Queued(1),
+                Pass::Compute(0),
+                Pass::Render(0),
+                Pass::Render(1),
+            ]
+        );
+
+        assert_eq!(super::passes(1, std::iter::empty()), vec![Pass::Queued(0)]);
     }
 }
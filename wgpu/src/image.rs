@@ -17,7 +17,7 @@ use std::mem;
 use bytemuck::{Pod, Zeroable};
 
 #[cfg(feature = "image_rs")]
-use iced_native::image;
+use iced_native::{image, Size};
 
 #[cfg(feature = "svg")]
 use iced_native::svg;
@@ -39,6 +39,7 @@ pub struct Pipeline {
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
     texture_atlas: Atlas,
+    batch: Vec<Instance>,
 }
 
 impl Pipeline {
@@ -170,6 +171,9 @@ impl Pipeline {
                                 3 => Float32x2,
                                 4 => Float32x2,
                                 5 => Sint32,
+                                6 => Float32x4,
+                                7 => Float32,
+                                8 => Float32,
                             ),
                         },
                     ],
@@ -258,6 +262,7 @@ impl Pipeline {
             texture_version: texture_atlas.layer_count(),
             texture_layout,
             texture_atlas,
+            batch: Vec::new(),
         }
     }
 
@@ -271,10 +276,7 @@ impl Pipeline {
 
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
-        let mut cache = self.vector_cache.borrow_mut();
-        let svg = cache.load(&handle);
-
-        svg.viewport_dimensions()
+        self.vector_cache.borrow_mut().viewport_dimensions(handle)
     }
 
     pub fn draw(
@@ -288,7 +290,10 @@ impl Pipeline {
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
-        let instances: &mut Vec<Instance> = &mut Vec::new();
+        // The instances of the previous draw are cleared to keep their
+        // allocation
+        let mut instances = std::mem::take(&mut self.batch);
+        instances.clear();
 
         #[cfg(feature = "image_rs")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -296,6 +301,9 @@ impl Pipeline {
         #[cfg(feature = "svg")]
         let mut vector_cache = self.vector_cache.borrow_mut();
 
+        #[cfg(feature = "svg")]
+        vector_cache.receive(device, encoder, &mut self.texture_atlas);
+
         for image in images {
             match &image {
                 #[cfg(feature = "image_rs")]
@@ -310,13 +318,58 @@ impl Pipeline {
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
                             atlas_entry,
-                            instances,
+                            Mask::bounds(*bounds),
+                            &mut instances,
                         );
                     }
                 }
                 #[cfg(not(feature = "image_rs"))]
                 layer::Image::Raster { .. } => {}
 
+                #[cfg(feature = "image_rs")]
+                layer::Image::Background {
+                    handle,
+                    bounds,
+                    border_radius,
+                    pattern,
+                    opacity,
+                } => {
+                    let (width, height) =
+                        raster_cache.load(handle).dimensions();
+
+                    let tiles = pattern
+                        .tiles(*bounds, Size::new(width as f32, height as f32));
+
+                    if tiles.is_empty() {
+                        continue;
+                    }
+
+                    if let Some(atlas_entry) = raster_cache.upload(
+                        handle,
+                        device,
+                        encoder,
+                        &mut self.texture_atlas,
+                    ) {
+                        let mask = Mask {
+                            radius: *border_radius,
+                            opacity: *opacity,
+                            ..Mask::bounds(*bounds)
+                        };
+
+                        for tile in tiles {
+                            add_instances(
+                                [tile.x, tile.y],
+                                [tile.width, tile.height],
+                                atlas_entry,
+                                mask,
+                                &mut instances,
+                            );
+                        }
+                    }
+                }
+                #[cfg(not(feature = "image_rs"))]
+                layer::Image::Background { .. } => {}
+
                 #[cfg(feature = "svg")]
                 layer::Image::Vector { handle, bounds } => {
                     let size = [bounds.width, bounds.height];
@@ -333,7 +386,8 @@ impl Pipeline {
                             [bounds.x, bounds.y],
                             size,
                             atlas_entry,
-                            instances,
+                            Mask::bounds(*bounds),
+                            &mut instances,
                         );
                     }
                 }
@@ -343,28 +397,12 @@ impl Pipeline {
         }
 
         if instances.is_empty() {
+            self.batch = instances;
+
             return;
         }
 
-        let texture_version = self.texture_atlas.layer_count();
-
-        if self.texture_version != texture_version {
-            log::info!("Atlas has grown. Recreating bind group...");
-
-            self.texture =
-                device.create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("iced_wgpu::image texture atlas bind group"),
-                    layout: &self.texture_layout,
-                    entries: &[wgpu::BindGroupEntry {
-                        binding: 0,
-                        resource: wgpu::BindingResource::TextureView(
-                            &self.texture_atlas.view(),
-                        ),
-                    }],
-                });
-
-            self.texture_version = texture_version;
-        }
+        self.update_texture(device);
 
         {
             let mut uniforms_buffer = staging_belt.write_buffer(
@@ -442,6 +480,95 @@ impl Pipeline {
 
             i += Instance::MAX;
         }
+
+        self.batch = instances;
+    }
+
+    /// Uploads a raster image to the texture atlas, returning the [`Region`]
+    /// it occupies.
+    ///
+    /// Images that are too big to fit in a single allocation of the atlas
+    /// are not supported and produce `None`.
+    #[cfg(feature = "image_rs")]
+    pub fn upload(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        handle: &image::Handle,
+    ) -> Option<Region> {
+        let mut raster_cache = self.raster_cache.borrow_mut();
+
+        match raster_cache.upload(
+            handle,
+            device,
+            encoder,
+            &mut self.texture_atlas,
+        )? {
+            atlas::Entry::Contiguous(allocation) => {
+                Some(Region::new(allocation))
+            }
+            atlas::Entry::Fragmented { .. } => {
+                log::warn!("Image is too big to be used as a texture");
+
+                None
+            }
+        }
+    }
+
+    /// Returns the bind group of the texture atlas, recreating it if the
+    /// atlas has grown.
+    #[cfg(feature = "image_rs")]
+    pub fn texture(&mut self, device: &wgpu::Device) -> &wgpu::BindGroup {
+        self.update_texture(device);
+
+        &self.texture
+    }
+
+    /// Returns the layout of the bind group of the texture atlas.
+    #[cfg(feature = "image_rs")]
+    pub fn texture_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.texture_layout
+    }
+
+    fn update_texture(&mut self, device: &wgpu::Device) {
+        let texture_version = self.texture_atlas.layer_count();
+
+        if self.texture_version != texture_version {
+            log::info!("Atlas has grown. Recreating bind group...");
+
+            self.texture =
+                device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("iced_wgpu::image texture atlas bind group"),
+                    layout: &self.texture_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(
+                            &self.texture_atlas.view(),
+                        ),
+                    }],
+                });
+
+            self.texture_version = texture_version;
+        }
+    }
+
+    /// Returns whether some images are still being prepared in the
+    /// background, and another frame should be drawn once they are ready.
+    pub fn has_pending_work(&self) -> bool {
+        #[cfg(feature = "svg")]
+        return self.vector_cache.borrow().has_pending_work();
+
+        #[cfg(not(feature = "svg"))]
+        false
+    }
+
+    /// Returns the statistics of the image caches, as lines of text.
+    pub fn stats(&self) -> Vec<String> {
+        #[cfg(feature = "svg")]
+        return self.vector_cache.borrow().stats();
+
+        #[cfg(not(feature = "svg"))]
+        Vec::new()
     }
 
     pub fn trim_cache(&mut self) {
@@ -453,6 +580,48 @@ impl Pipeline {
     }
 }
 
+/// The region of the texture atlas occupied by an image, in normalized
+/// texture coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Region {
+    /// The position of the top-left texel of the image.
+    pub position: [f32; 2],
+
+    /// The distance from the top-left to the bottom-right texel of the image.
+    pub size: [f32; 2],
+
+    /// The layer of the atlas containing the image.
+    pub layer: u32,
+}
+
+impl Region {
+    fn new(allocation: &atlas::Allocation) -> Self {
+        let (x, y) = allocation.position();
+        let (width, height) = allocation.size();
+
+        Region {
+            position: [
+                (x as f32 + 0.5) / atlas::SIZE as f32,
+                (y as f32 + 0.5) / atlas::SIZE as f32,
+            ],
+            size: [
+                (width as f32 - 1.0) / atlas::SIZE as f32,
+                (height as f32 - 1.0) / atlas::SIZE as f32,
+            ],
+            layer: allocation.layer() as u32,
+        }
+    }
+
+    /// Maps texture coordinates of the image into the atlas.
+    #[cfg(feature = "image_rs")]
+    pub fn map(&self, [u, v]: [f32; 2]) -> [f32; 2] {
+        [
+            self.position[0] + u.max(0.0).min(1.0) * self.size[0],
+            self.position[1] + v.max(0.0).min(1.0) * self.size[1],
+        ]
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy, Zeroable, Pod)]
 pub struct Vertex {
@@ -484,12 +653,35 @@ struct Instance {
     _position_in_atlas: [f32; 2],
     _size_in_atlas: [f32; 2],
     _layer: u32,
+    _mask_bounds: [f32; 4],
+    _mask_radius: f32,
+    _opacity: f32,
 }
 
 impl Instance {
     pub const MAX: usize = 1_000;
 }
 
+/// The rounded box, in logical coordinates, outside of which the pixels of an
+/// image are discarded.
+#[derive(Debug, Clone, Copy)]
+struct Mask {
+    bounds: [f32; 4],
+    radius: f32,
+    opacity: f32,
+}
+
+impl Mask {
+    /// A [`Mask`] keeping every pixel of an image with the given bounds.
+    fn bounds(bounds: Rectangle) -> Self {
+        Mask {
+            bounds: [bounds.x, bounds.y, bounds.width, bounds.height],
+            radius: 0.0,
+            opacity: 1.0,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Zeroable, Pod)]
 struct Uniforms {
@@ -500,11 +692,18 @@ fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
     entry: &atlas::Entry,
+    mask: Mask,
     instances: &mut Vec<Instance>,
 ) {
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(
+                image_position,
+                image_size,
+                allocation,
+                mask,
+                instances,
+            );
         }
         atlas::Entry::Fragmented { fragments, size } => {
             let scaling_x = image_size[0] / size.0 as f32;
@@ -527,7 +726,7 @@ fn add_instances(
                     fragment_height as f32 * scaling_y,
                 ];
 
-                add_instance(position, size, allocation, instances);
+                add_instance(position, size, allocation, mask, instances);
             }
         }
     }
@@ -538,24 +737,20 @@ fn add_instance(
     position: [f32; 2],
     size: [f32; 2],
     allocation: &atlas::Allocation,
+    mask: Mask,
     instances: &mut Vec<Instance>,
 ) {
-    let (x, y) = allocation.position();
-    let (width, height) = allocation.size();
-    let layer = allocation.layer();
+    let region = Region::new(allocation);
 
     let instance = Instance {
         _position: position,
         _size: size,
-        _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
-        ],
-        _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
-        ],
-        _layer: layer as u32,
+        _position_in_atlas: region.position,
+        _size_in_atlas: region.size,
+        _layer: region.layer,
+        _mask_bounds: mask.bounds,
+        _mask_radius: mask.radius,
+        _opacity: mask.opacity,
     };
 
     instances.push(instance);
@@ -85,6 +85,19 @@ impl Cache {
                     Memory::Invalid
                 }
             }
+            // Indexed images are expanded as soon as they are loaded, since
+            // the atlas only stores BGRA pixels
+            image::Data::Indexed { width, height, .. } => {
+                if let Some(image) =
+                    handle.data().expand_palette().and_then(|pixels| {
+                        image_rs::ImageBuffer::from_vec(*width, *height, pixels)
+                    })
+                {
+                    Memory::Host(image)
+                } else {
+                    Memory::Invalid
+                }
+            }
         };
 
         self.insert(handle, memory);
@@ -222,3 +235,33 @@ where
 
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uploaded_pixels(handle: &image::Handle) -> Option<Vec<u8>> {
+        match Cache::new().load(handle) {
+            Memory::Host(image) => Some(image.as_raw().clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn indexed_images_upload_the_same_pixels_as_their_expansion() {
+        let palette =
+            vec![[40, 80, 120, 255], [0, 0, 0, 0], [255, 255, 255, 128]];
+        let indices = vec![0, 1, 2, 2, 1, 0];
+
+        let expanded: Vec<u8> = indices
+            .iter()
+            .flat_map(|index| palette[*index as usize].to_vec())
+            .collect();
+
+        let indexed = image::Handle::from_indexed(3, 2, indices, palette);
+        let pixels = image::Handle::from_pixels(3, 2, expanded.clone());
+
+        assert_eq!(uploaded_pixels(&indexed), Some(expanded));
+        assert_eq!(uploaded_pixels(&indexed), uploaded_pixels(&pixels));
+    }
+}
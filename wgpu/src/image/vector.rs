@@ -4,6 +4,21 @@ use iced_native::svg;
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
+use std::sync::mpsc;
+use std::thread;
+
+/// The maximum amount of memory used by the rasterizations of a [`Cache`],
+/// in bytes.
+const MEMORY_CAP: usize = 128 * 1024 * 1024;
+
+/// The ratio between the sizes of two consecutive rasterization buckets.
+const BUCKET_RATIO: f32 = 1.5;
+
+/// The largest width or height of a rasterization bucket.
+///
+/// Larger sizes are rasterized exactly, as a bucket would waste too much
+/// memory.
+const MAX_BUCKET_SIZE: u32 = 4096;
 
 pub enum Svg {
     Loaded(usvg::Tree),
@@ -11,6 +26,27 @@ pub enum Svg {
 }
 
 impl Svg {
+    fn parse(data: &svg::Data) -> Svg {
+        let tree = match data {
+            svg::Data::Path(path) => {
+                fs::read_to_string(path).ok().and_then(|contents| {
+                    usvg::Tree::from_str(
+                        &contents,
+                        &usvg::Options::default().to_ref(),
+                    )
+                    .ok()
+                })
+            }
+            svg::Data::Bytes(bytes) => usvg::Tree::from_data(
+                &bytes,
+                &usvg::Options::default().to_ref(),
+            )
+            .ok(),
+        };
+
+        tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
+    }
+
     pub fn viewport_dimensions(&self) -> (u32, u32) {
         match self {
             Svg::Loaded(tree) => {
@@ -21,56 +57,238 @@ impl Svg {
             Svg::NotFound => (1, 1),
         }
     }
+
+    /// Rasterizes the [`Svg`] with the given size, returning its BGRA pixels.
+    fn rasterize(&self, width: u32, height: u32) -> Option<Vec<u8>> {
+        match self {
+            Svg::Loaded(tree) => {
+                let mut img = tiny_skia::Pixmap::new(width, height)?;
+
+                let _ = resvg::render(
+                    tree,
+                    if width > height {
+                        usvg::FitTo::Width(width)
+                    } else {
+                        usvg::FitTo::Height(height)
+                    },
+                    img.as_mut(),
+                )?;
+
+                let mut rgba = img.take();
+                rgba.chunks_exact_mut(4).for_each(|rgba| rgba.swap(0, 2));
+
+                Some(rgba)
+            }
+            Svg::NotFound => None,
+        }
+    }
+}
+
+/// Reads the viewport dimensions of an SVG from the attributes of its root
+/// element, without parsing the rest of the document.
+///
+/// This is what a layout needs, so the document itself can be parsed in the
+/// background. Compressed documents are parsed right away, as their root
+/// element cannot be read on its own.
+fn viewport_dimensions(data: &svg::Data) -> (u32, u32) {
+    let contents;
+
+    let bytes = match data {
+        svg::Data::Path(path) => match fs::read(path) {
+            Ok(read) => {
+                contents = read;
+
+                &contents[..]
+            }
+            Err(_) => return (1, 1),
+        },
+        svg::Data::Bytes(bytes) => &bytes[..],
+    };
+
+    // Gzip compressed
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        return Svg::parse(data).viewport_dimensions();
+    }
+
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(root_dimensions)
+        .unwrap_or((1, 1))
+}
+
+fn root_dimensions(document: &str) -> Option<(u32, u32)> {
+    use xmlparser::Token;
+
+    let mut width = None;
+    let mut height = None;
+    let mut view_box = None;
+
+    for token in xmlparser::Tokenizer::from(document) {
+        match token.ok()? {
+            Token::ElementStart { local, .. } if local.as_str() != "svg" => {
+                return None;
+            }
+            Token::Attribute { local, value, .. } => match local.as_str() {
+                "width" => width = length(value.as_str()),
+                "height" => height = length(value.as_str()),
+                "viewBox" => {
+                    let numbers: Vec<f32> = value
+                        .as_str()
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .filter(|number| !number.is_empty())
+                        .map(str::parse)
+                        .collect::<Result<_, _>>()
+                        .ok()?;
+
+                    if let [_, _, width, height] = numbers[..] {
+                        view_box = Some((width, height));
+                    }
+                }
+                _ => {}
+            },
+            Token::ElementEnd { .. } => break,
+            _ => {}
+        }
+    }
+
+    // Relative sizes are relative to the view box
+    let width = width.or_else(|| view_box.map(|(width, _)| width))?;
+    let height = height.or_else(|| view_box.map(|(_, height)| height))?;
+
+    Some((width as u32, height as u32))
+}
+
+/// Parses an absolute SVG length into pixels.
+fn length(value: &str) -> Option<f32> {
+    let value = value.trim();
+
+    let (number, unit) = value.split_at(
+        value
+            .find(|c: char| !(c.is_ascii_digit() || "+-.".contains(c)))
+            .unwrap_or(value.len()),
+    );
+
+    let pixels = match unit.trim() {
+        "" | "px" => 1.0,
+        "in" => 96.0,
+        "cm" => 96.0 / 2.54,
+        "mm" => 96.0 / 25.4,
+        "pt" => 4.0 / 3.0,
+        "pc" => 16.0,
+        _ => return None,
+    };
+
+    number.parse::<f32>().ok().map(|number| number * pixels)
+}
+
+/// The parsed trees of the SVGs being rasterized.
+#[derive(Debug, Default)]
+struct Trees {
+    svgs: HashMap<u64, Svg>,
+}
+
+impl Trees {
+    fn process(&mut self, request: Request) -> Option<Raster> {
+        match request {
+            Request::Rasterize { key, handle } => {
+                let (id, width, height) = key;
+
+                let pixels = self
+                    .svgs
+                    .entry(id)
+                    .or_insert_with(|| Svg::parse(handle.data()))
+                    .rasterize(width, height);
+
+                Some(Raster { key, pixels })
+            }
+            Request::Forget(id) => {
+                let _ = self.svgs.remove(&id);
+
+                None
+            }
+        }
+    }
+}
+
+/// The id of an SVG and the size of one of its rasterizations.
+type Key = (u64, u32, u32);
+
+#[derive(Debug)]
+struct Rasterization {
+    entry: atlas::Entry,
+    last_used: u64,
+}
+
+impl Rasterization {
+    fn bytes((_, width, height): Key) -> usize {
+        width as usize * height as usize * 4
+    }
 }
 
 #[derive(Debug)]
 pub struct Cache {
-    svgs: HashMap<u64, Svg>,
-    rasterized: HashMap<(u64, u32, u32), atlas::Entry>,
+    viewports: HashMap<u64, (u32, u32)>,
     svg_hits: HashSet<u64>,
-    rasterized_hits: HashSet<(u64, u32, u32)>,
+    rasterized: HashMap<Key, Rasterization>,
+    pending: HashSet<Key>,
+    failed: HashSet<Key>,
+    parsed: HashSet<u64>,
+    trees: Trees,
+    worker: Option<Worker>,
+    frame: u64,
+    memory: usize,
+    stats: Stats,
 }
 
 impl Cache {
     pub fn new() -> Self {
         Self {
-            svgs: HashMap::new(),
-            rasterized: HashMap::new(),
+            viewports: HashMap::new(),
             svg_hits: HashSet::new(),
-            rasterized_hits: HashSet::new(),
+            rasterized: HashMap::new(),
+            pending: HashSet::new(),
+            failed: HashSet::new(),
+            parsed: HashSet::new(),
+            trees: Trees::default(),
+            worker: Worker::spawn(),
+            frame: 0,
+            memory: 0,
+            stats: Stats::default(),
         }
     }
 
-    pub fn load(&mut self, handle: &svg::Handle) -> &Svg {
-        if self.svgs.contains_key(&handle.id()) {
-            return self.svgs.get(&handle.id()).unwrap();
-        }
+    /// Returns the viewport dimensions of the SVG of the given handle.
+    pub fn viewport_dimensions(&mut self, handle: &svg::Handle) -> (u32, u32) {
+        *self
+            .viewports
+            .entry(handle.id())
+            .or_insert_with(|| viewport_dimensions(handle.data()))
+    }
 
-        let svg = match handle.data() {
-            svg::Data::Path(path) => {
-                let tree = fs::read_to_string(path).ok().and_then(|contents| {
-                    usvg::Tree::from_str(
-                        &contents,
-                        &usvg::Options::default().to_ref(),
-                    )
-                    .ok()
-                });
+    /// Returns whether some rasterizations are still being computed in the
+    /// background.
+    pub fn has_pending_work(&self) -> bool {
+        !self.pending.is_empty()
+    }
 
-                tree.map(Svg::Loaded).unwrap_or(Svg::NotFound)
-            }
-            svg::Data::Bytes(bytes) => {
-                match usvg::Tree::from_data(
-                    &bytes,
-                    &usvg::Options::default().to_ref(),
-                ) {
-                    Ok(tree) => Svg::Loaded(tree),
-                    Err(_) => Svg::NotFound,
-                }
-            }
+    /// Uploads the rasterizations finished in the background since the last
+    /// call.
+    pub fn receive(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_atlas: &mut Atlas,
+    ) {
+        let rasters = match &self.worker {
+            Some(worker) => worker.results.try_iter().collect(),
+            None => Vec::new(),
         };
 
-        let _ = self.svgs.insert(handle.id(), svg);
-        self.svgs.get(&handle.id()).unwrap()
+        for Raster { key, pixels } in rasters {
+            let _ = self.pending.remove(&key);
+
+            self.insert(key, pixels, device, encoder, texture_atlas);
+        }
     }
 
     pub fn upload(
@@ -84,82 +302,304 @@ impl Cache {
     ) -> Option<&atlas::Entry> {
         let id = handle.id();
 
-        let (width, height) = (
+        let target = (
             (scale * width).ceil() as u32,
             (scale * height).ceil() as u32,
         );
 
-        // TODO: Optimize!
-        // We currently rerasterize the SVG when its size changes. This is slow
-        // as heck. A GPU rasterizer like `pathfinder` may perform better.
-        // It would be cool to be able to smooth resize the `svg` example.
-        if self.rasterized.contains_key(&(id, width, height)) {
-            let _ = self.svg_hits.insert(id);
-            let _ = self.rasterized_hits.insert((id, width, height));
-
-            return self.rasterized.get(&(id, width, height));
+        if target.0 == 0 || target.1 == 0 {
+            return None;
         }
 
-        match self.load(handle) {
-            Svg::Loaded(tree) => {
-                if width == 0 || height == 0 {
-                    return None;
+        let _ = self.svg_hits.insert(id);
+
+        let (width, height) = match handle.raster_quality() {
+            svg::Quality::Balanced => {
+                bucket(target, self.viewport_dimensions(handle))
+            }
+            svg::Quality::Exact => target,
+        };
+
+        let key = (id, width, height);
+
+        if !self.rasterized.contains_key(&key) && !self.failed.contains(&key) {
+            let request = || Request::Rasterize {
+                key,
+                handle: handle.clone(),
+            };
+
+            let _ = self.parsed.insert(id);
+
+            match (&self.worker, handle.raster_quality()) {
+                (Some(worker), svg::Quality::Balanced) => {
+                    if self.pending.insert(key) {
+                        worker.request(request());
+                    }
+                }
+                _ => {
+                    if let Some(Raster { key, pixels }) =
+                        self.trees.process(request())
+                    {
+                        self.insert(
+                            key,
+                            pixels,
+                            device,
+                            encoder,
+                            texture_atlas,
+                        );
+                    }
                 }
+            }
+        }
 
-                // TODO: Optimize!
-                // We currently rerasterize the SVG when its size changes. This is slow
-                // as heck. A GPU rasterizer like `pathfinder` may perform better.
-                // It would be cool to be able to smooth resize the `svg` example.
-                let mut img = tiny_skia::Pixmap::new(width, height)?;
+        let key = if self.rasterized.contains_key(&key) {
+            self.stats.hits += 1;
 
-                let _ = resvg::render(
-                    tree,
-                    if width > height {
-                        usvg::FitTo::Width(width)
-                    } else {
-                        usvg::FitTo::Height(height)
-                    },
-                    img.as_mut(),
-                )?;
+            key
+        } else {
+            // Scale the nearest available rasterization until the right one
+            // is ready
+            let fallback = self
+                .rasterized
+                .keys()
+                .filter(|(other, _, _)| *other == id)
+                .min_by_key(|(_, width, _)| {
+                    (*width < target.0, (*width as i64 - target.0 as i64).abs())
+                })
+                .copied()?;
 
-                let mut rgba = img.take();
-                rgba.chunks_exact_mut(4).for_each(|rgba| rgba.swap(0, 2));
+            self.stats.fallbacks += 1;
+
+            fallback
+        };
+
+        let rasterization = self.rasterized.get_mut(&key)?;
+        rasterization.last_used = self.frame;
 
-                let allocation = texture_atlas.upload(
+        Some(&rasterization.entry)
+    }
+
+    /// Uploads the pixels of a rasterization to the texture atlas.
+    ///
+    /// A rasterization that fails, or that does not fit in the atlas, is
+    /// reported once and not retried while its SVG stays on screen.
+    fn insert(
+        &mut self,
+        key: Key,
+        pixels: Option<Vec<u8>>,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_atlas: &mut Atlas,
+    ) {
+        let (id, width, height) = key;
+
+        let entry = pixels.and_then(|pixels| {
+            texture_atlas.upload(
+                width,
+                height,
+                bytemuck::cast_slice(&pixels),
+                device,
+                encoder,
+            )
+        });
+
+        let entry = match entry {
+            Some(entry) => entry,
+            None => {
+                log::warn!(
+                    "Failed to rasterize SVG {} at {}x{}",
+                    id,
                     width,
-                    height,
-                    bytemuck::cast_slice(rgba.as_slice()),
-                    device,
-                    encoder,
-                )?;
-                log::debug!("allocating {} {}x{}", id, width, height);
+                    height
+                );
 
-                let _ = self.svg_hits.insert(id);
-                let _ = self.rasterized_hits.insert((id, width, height));
-                let _ = self.rasterized.insert((id, width, height), allocation);
+                let _ = self.failed.insert(key);
 
-                self.rasterized.get(&(id, width, height))
+                return;
+            }
+        };
+
+        log::debug!("allocating {} {}x{}", id, width, height);
+
+        self.memory += Rasterization::bytes(key);
+        self.stats.rasterizations += 1;
+
+        let _ = self.rasterized.insert(
+            key,
+            Rasterization {
+                entry,
+                last_used: self.frame,
+            },
+        );
+
+        self.evict(texture_atlas);
+    }
+
+    /// Evicts the least recently used rasterizations until the memory cap is
+    /// respected, keeping the ones used in the current frame.
+    fn evict(&mut self, atlas: &mut Atlas) {
+        while self.memory > MEMORY_CAP {
+            let frame = self.frame;
+
+            let oldest = self
+                .rasterized
+                .iter()
+                .filter(|(_, rasterization)| rasterization.last_used < frame)
+                .min_by_key(|(_, rasterization)| rasterization.last_used)
+                .map(|(key, _)| *key);
+
+            match oldest.and_then(|key| self.rasterized.remove_entry(&key)) {
+                Some((key, rasterization)) => {
+                    atlas.remove(&rasterization.entry);
+
+                    self.memory -= Rasterization::bytes(key);
+                }
+                None => break,
             }
-            Svg::NotFound => None,
         }
     }
 
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let svg_hits = &self.svg_hits;
-        let rasterized_hits = &self.rasterized_hits;
+        let memory = &mut self.memory;
+
+        let trees = &mut self.trees;
+        let worker = &self.worker;
 
-        self.svgs.retain(|k, _| svg_hits.contains(k));
-        self.rasterized.retain(|k, entry| {
-            let retain = rasterized_hits.contains(k);
+        // The parsed trees of the SVGs off screen are dropped, wherever they
+        // were parsed
+        self.parsed.retain(|id| {
+            let retain = svg_hits.contains(id);
 
             if !retain {
-                atlas.remove(entry);
+                let _ = trees.process(Request::Forget(*id));
+
+                if let Some(worker) = worker {
+                    worker.request(Request::Forget(*id));
+                }
             }
 
             retain
         });
+
+        // The rasterizations of an SVG are kept at every size while it stays
+        // on screen, as long as the memory cap allows it
+        self.viewports.retain(|id, _| svg_hits.contains(id));
+        self.failed.retain(|(id, _, _)| svg_hits.contains(id));
+        self.rasterized.retain(|key, rasterization| {
+            let (id, _, _) = key;
+            let retain = svg_hits.contains(id);
+
+            if !retain {
+                atlas.remove(&rasterization.entry);
+
+                *memory -= Rasterization::bytes(*key);
+            }
+
+            retain
+        });
+
         self.svg_hits.clear();
-        self.rasterized_hits.clear();
+        self.frame += 1;
+    }
+
+    /// Returns the statistics of the [`Cache`], as lines of text.
+    pub fn stats(&self) -> Vec<String> {
+        vec![
+            format!(
+                "SVG rasterizations: {} ({:.1} MiB), {} pending",
+                self.rasterized.len(),
+                self.memory as f32 / (1024.0 * 1024.0),
+                self.pending.len(),
+            ),
+            format!(
+                "SVG draws: {} exact, {} scaled, {} rasterized",
+                self.stats.hits,
+                self.stats.fallbacks,
+                self.stats.rasterizations,
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Default)]
+struct Stats {
+    hits: usize,
+    fallbacks: usize,
+    rasterizations: usize,
+}
+
+/// Returns the size of the rasterization bucket that fits the given target
+/// size, for an SVG with the given viewport dimensions.
+///
+/// The buckets grow geometrically by [`BUCKET_RATIO`], and the nearest larger
+/// bucket is picked, so downscaling always keeps the image crisp.
+fn bucket(
+    (width, height): (u32, u32),
+    (viewport_width, viewport_height): (u32, u32),
+) -> (u32, u32) {
+    let viewport_width = viewport_width.max(1) as f32;
+    let viewport_height = viewport_height.max(1) as f32;
+
+    let scale =
+        (width as f32 / viewport_width).max(height as f32 / viewport_height);
+
+    let bucket = BUCKET_RATIO.powf(scale.log(BUCKET_RATIO).ceil());
+
+    let size = (
+        ((viewport_width * bucket).ceil() as u32).max(width),
+        ((viewport_height * bucket).ceil() as u32).max(height),
+    );
+
+    if size.0 > MAX_BUCKET_SIZE || size.1 > MAX_BUCKET_SIZE {
+        (width, height)
+    } else {
+        size
+    }
+}
+
+/// A background thread parsing and rasterizing SVGs.
+#[derive(Debug)]
+struct Worker {
+    requests: mpsc::Sender<Request>,
+    results: mpsc::Receiver<Raster>,
+}
+
+enum Request {
+    Rasterize { key: Key, handle: svg::Handle },
+    Forget(u64),
+}
+
+struct Raster {
+    key: Key,
+    pixels: Option<Vec<u8>>,
+}
+
+impl Worker {
+    fn spawn() -> Option<Worker> {
+        let (requests, pending) = mpsc::channel::<Request>();
+        let (finished, results) = mpsc::channel();
+
+        let _ = thread::Builder::new()
+            .name(String::from("iced_wgpu svg rasterizer"))
+            .spawn(move || {
+                let mut trees = Trees::default();
+
+                for request in pending {
+                    if let Some(raster) = trees.process(request) {
+                        if finished.send(raster).is_err() {
+                            break;
+                        }
+                    }
+                }
+            })
+            .ok()?;
+
+        Some(Worker { requests, results })
+    }
+
+    fn request(&self, request: Request) {
+        let _ = self.requests.send(request);
     }
 }
 
@@ -171,3 +611,46 @@ impl std::fmt::Debug for Svg {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_are_the_nearest_larger_size() {
+        // A 100x50 viewport drawn at 1x, 1.2x and 1.5x
+        assert_eq!(bucket((100, 50), (100, 50)), (100, 50));
+        assert_eq!(bucket((120, 60), (100, 50)), (150, 75));
+        assert_eq!(bucket((150, 75), (100, 50)), (150, 75));
+
+        // Zooming out picks a smaller, but still larger than needed, bucket
+        assert_eq!(bucket((60, 30), (100, 50)), (67, 34));
+
+        // Huge sizes are rasterized exactly
+        assert_eq!(bucket((5000, 2500), (100, 50)), (5000, 2500));
+    }
+
+    #[test]
+    fn viewports_are_read_from_the_root_element() {
+        assert_eq!(
+            root_dimensions(
+                r#"<?xml version="1.0"?>
+                <!-- A map -->
+                <svg xmlns="http://www.w3.org/2000/svg" width="200"
+                    height="1in"><rect width="10" height="10"/></svg>"#
+            ),
+            Some((200, 96))
+        );
+
+        // Relative sizes use the view box
+        assert_eq!(
+            root_dimensions(
+                r#"<svg width="100%" viewBox="0 0 300.5,150"></svg>"#
+            ),
+            Some((300, 150))
+        );
+
+        assert_eq!(root_dimensions("<svg></svg>"), None);
+        assert_eq!(root_dimensions("<html></html>"), None);
+    }
+}
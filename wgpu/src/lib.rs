@@ -36,14 +36,18 @@ mod backend;
 mod direct_wgpu;
 mod quad;
 mod text;
+mod video;
 
 pub use iced_graphics::{
-    Antialiasing, Color, Defaults, Error, Primitive, Viewport,
+    Antialiasing, Color, Defaults, Error, Hinting, Primitive, Styleable, Theme,
+    Viewport,
 };
 pub use wgpu;
 
-pub use backend::{Backend, DirectWgpuJob};
-pub use settings::Settings;
+pub use backend::{
+    Backend, ComputeStage, DirectWgpuJob, RenderStage, SubViewport,
+};
+pub use settings::{ColorSpace, Settings};
 
 #[doc(no_inline)]
 pub use widget::*;
@@ -53,6 +57,9 @@ pub(crate) use iced_graphics::Transformation;
 #[cfg(any(feature = "image_rs", feature = "svg"))]
 mod image;
 
+#[cfg(feature = "backdrop-filter")]
+mod backdrop;
+
 /// A [`wgpu`] graphics renderer for [`iced`].
 ///
 /// [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
@@ -14,6 +14,8 @@ pub struct Pipeline {
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
+    uniforms: Option<Uniforms>,
+    uploaded: Vec<u8>,
 }
 
 impl Pipeline {
@@ -92,7 +94,14 @@ impl Pipeline {
                                 3 => Float32x4,
                                 4 => Float32x4,
                                 5 => Float32,
-                                6 => Float32,
+                                6 => Float32x4,
+                                7 => Uint32,
+                                8 => Float32x2,
+                                9 => Float32,
+                                10 => Float32x4,
+                                11 => Float32x2,
+                                12 => Float32,
+                                13 => Uint32,
                             ),
                         },
                     ],
@@ -158,6 +167,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
+            uniforms: None,
+            uploaded: Vec::new(),
         }
     }
 
@@ -174,7 +185,12 @@ impl Pipeline {
     ) {
         let uniforms = Uniforms::new(transformation, scale);
 
-        {
+        // The uniforms only change with the viewport
+        let uniforms_changed = self.uniforms.map_or(true, |last| {
+            bytemuck::bytes_of(&last) != bytemuck::bytes_of(&uniforms)
+        });
+
+        if uniforms_changed {
             let mut constants_buffer = staging_belt.write_buffer(
                 encoder,
                 &self.constants_buffer,
@@ -185,6 +201,8 @@ impl Pipeline {
             );
 
             constants_buffer.copy_from_slice(bytemuck::bytes_of(&uniforms));
+
+            self.uniforms = Some(uniforms);
         }
 
         let mut i = 0;
@@ -196,15 +214,22 @@ impl Pipeline {
 
             let instance_bytes = bytemuck::cast_slice(&instances[i..end]);
 
-            let mut instance_buffer = staging_belt.write_buffer(
-                encoder,
-                &self.instances,
-                0,
-                wgpu::BufferSize::new(instance_bytes.len() as u64).unwrap(),
-                device,
-            );
+            // The instances of a frame that did not change, or of a layer equal
+            // to the previous one, are already in the buffer
+            if instance_bytes != self.uploaded.as_slice() {
+                let mut instance_buffer = staging_belt.write_buffer(
+                    encoder,
+                    &self.instances,
+                    0,
+                    wgpu::BufferSize::new(instance_bytes.len() as u64).unwrap(),
+                    device,
+                );
 
-            instance_buffer.copy_from_slice(instance_bytes);
+                instance_buffer.copy_from_slice(instance_bytes);
+
+                self.uploaded.clear();
+                self.uploaded.extend_from_slice(instance_bytes);
+            }
 
             {
                 let mut render_pass =
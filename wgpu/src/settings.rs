@@ -1,5 +1,5 @@
 //! Configure a renderer.
-pub use crate::Antialiasing;
+pub use crate::{Antialiasing, Hinting};
 
 /// The settings of a [`Backend`].
 ///
@@ -8,12 +8,33 @@ pub use crate::Antialiasing;
 pub struct Settings {
     /// The present mode of the [`Backend`].
     ///
+    /// By default, it is `Mailbox`, falling back to `Fifo` where it is not
+    /// supported.
+    ///
     /// [`Backend`]: crate::Backend
     pub present_mode: wgpu::PresentMode,
 
-    /// The internal graphics backend to use.
+    /// The color space of the surfaces of the [`Backend`].
+    ///
+    /// By default, it is `Srgb`.
+    ///
+    /// [`Backend`]: crate::Backend
+    pub color_space: ColorSpace,
+
+    /// The internal graphics backends to use.
+    ///
+    /// By default, all the backends are allowed.
     pub internal_backend: wgpu::Backends,
 
+    /// The power preference used to choose the graphics adapter.
+    ///
+    /// On systems with both an integrated and a discrete GPU, `LowPower`
+    /// favors the integrated one, saving battery, while `HighPerformance`
+    /// favors the discrete one.
+    ///
+    /// By default, it is `LowPower`.
+    pub power_preference: wgpu::PowerPreference,
+
     /// The bytes of the font that will be used by default.
     ///
     /// If `None` is provided, a default system font will be chosen.
@@ -34,6 +55,21 @@ pub struct Settings {
     ///
     /// By default, it is `None`.
     pub antialiasing: Option<Antialiasing>,
+
+    /// The hinting strategy that will be used for the glyphs of text.
+    ///
+    /// By default, it is `None`, placing glyphs at their exact positions.
+    pub hinting: Hinting,
+
+    /// If enabled, text is placed horizontally at the closest quarter of a
+    /// pixel, instead of being snapped to whole pixels, spacing its glyphs
+    /// more evenly.
+    ///
+    /// This overrides the horizontal snapping of [`Hinting::Full`], at the
+    /// cost of rasterizing up to four versions of every glyph.
+    ///
+    /// By default, it is disabled.
+    pub subpixel_positioning: bool,
 }
 
 impl Settings {
@@ -58,17 +94,145 @@ impl Settings {
             ..Self::default()
         }
     }
+
+    /// Returns the [`Settings`] with the given graphics backends.
+    ///
+    /// Only adapters of these backends will be considered when creating the
+    /// [`Backend`]. For instance, `wgpu::Backends::VULKAN` forces Vulkan over
+    /// OpenGL on Linux, while `wgpu::Backends::GL` is useful in environments
+    /// where only OpenGL is available.
+    ///
+    /// [`Backend`]: crate::Backend
+    pub fn backends(self, backends: wgpu::Backends) -> Self {
+        Settings {
+            internal_backend: backends,
+            ..self
+        }
+    }
+
+    /// Returns the [`Settings`] with a `present_mode` that is supported.
+    ///
+    /// The `is_supported` closure tells whether the surface can present with a
+    /// given mode. If the requested `present_mode` is not available, it falls
+    /// back to `wgpu::PresentMode::Fifo`, which is supported everywhere.
+    pub fn with_supported_present_mode(
+        self,
+        is_supported: impl Fn(wgpu::PresentMode) -> bool,
+    ) -> Self {
+        if is_supported(self.present_mode) {
+            self
+        } else {
+            log::warn!(
+                "Present mode {:?} is not supported by the surface, \
+                falling back to {:?}",
+                self.present_mode,
+                wgpu::PresentMode::Fifo
+            );
+
+            Settings {
+                present_mode: wgpu::PresentMode::Fifo,
+                ..self
+            }
+        }
+    }
+
+    /// Returns the [`Settings`] with a `color_space` whose surface format is
+    /// supported, alongside that format.
+    ///
+    /// The `default_format` is the sRGB format preferred by the surface, and
+    /// `is_supported` tells whether the surface can present a given format. If
+    /// no format of the requested `color_space` is supported, it falls back to
+    /// [`ColorSpace::Srgb`] and the `default_format`.
+    pub fn with_supported_color_space(
+        self,
+        default_format: wgpu::TextureFormat,
+        is_supported: impl Fn(wgpu::TextureFormat) -> bool,
+    ) -> (Self, wgpu::TextureFormat) {
+        if self.color_space == ColorSpace::Srgb {
+            return (self, default_format);
+        }
+
+        match self
+            .color_space
+            .formats()
+            .iter()
+            .copied()
+            .find(|format| is_supported(*format))
+        {
+            Some(format) => (self, format),
+            None => {
+                log::warn!(
+                    "Color space {:?} is not supported by the surface, \
+                    falling back to {:?} ({:?})",
+                    self.color_space,
+                    ColorSpace::Srgb,
+                    default_format
+                );
+
+                (
+                    Settings {
+                        color_space: ColorSpace::Srgb,
+                        ..self
+                    },
+                    default_format,
+                )
+            }
+        }
+    }
+}
+
+/// The color space of the output of a [`Backend`].
+///
+/// [`Backend`]: crate::Backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Standard dynamic range output, encoded with the sRGB transfer
+    /// function.
+    Srgb,
+
+    /// High dynamic range output in half-precision floats with a linear
+    /// transfer function, also known as scRGB.
+    ///
+    /// Colors keep the sRGB primaries, but may exceed `1.0` to produce
+    /// brighter highlights on HDR displays.
+    ExtendedLinearSrgb,
+}
+
+impl ColorSpace {
+    /// Returns the surface formats of the [`ColorSpace`], in order of
+    /// preference.
+    pub fn formats(self) -> &'static [wgpu::TextureFormat] {
+        match self {
+            ColorSpace::Srgb => &[
+                wgpu::TextureFormat::Bgra8UnormSrgb,
+                wgpu::TextureFormat::Rgba8UnormSrgb,
+            ],
+            ColorSpace::ExtendedLinearSrgb => {
+                &[wgpu::TextureFormat::Rgba16Float]
+            }
+        }
+    }
+}
+
+impl Default for ColorSpace {
+    fn default() -> Self {
+        ColorSpace::Srgb
+    }
 }
 
 impl Default for Settings {
     fn default() -> Settings {
         Settings {
             present_mode: wgpu::PresentMode::Mailbox,
+            color_space: ColorSpace::default(),
             internal_backend: wgpu::Backends::all(),
+            power_preference: wgpu::PowerPreference::LowPower,
             default_font: None,
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: None,
+            hinting: Hinting::None,
+            subpixel_positioning: false,
         }
     }
 }
@@ -87,3 +251,61 @@ fn backend_from_env() -> Option<wgpu::Backends> {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_present_mode_falls_back_to_fifo() {
+        let settings = Settings::default();
+
+        assert_eq!(settings.present_mode, wgpu::PresentMode::Mailbox);
+
+        assert_eq!(
+            settings
+                .with_supported_present_mode(|mode| {
+                    mode == wgpu::PresentMode::Fifo
+                })
+                .present_mode,
+            wgpu::PresentMode::Fifo
+        );
+
+        assert_eq!(
+            settings.with_supported_present_mode(|_| true).present_mode,
+            wgpu::PresentMode::Mailbox
+        );
+    }
+
+    #[test]
+    fn unsupported_color_space_falls_back_to_srgb() {
+        let settings = Settings {
+            color_space: ColorSpace::ExtendedLinearSrgb,
+            ..Settings::default()
+        };
+
+        let (fallback, format) = settings.with_supported_color_space(
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+            |_| false,
+        );
+
+        assert_eq!(fallback.color_space, ColorSpace::Srgb);
+        assert_eq!(format, wgpu::TextureFormat::Bgra8UnormSrgb);
+
+        let (hdr, format) = settings.with_supported_color_space(
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+            |format| format == wgpu::TextureFormat::Rgba16Float,
+        );
+
+        assert_eq!(hdr.color_space, ColorSpace::ExtendedLinearSrgb);
+        assert_eq!(format, wgpu::TextureFormat::Rgba16Float);
+    }
+
+    #[test]
+    fn backends_override_the_internal_backend() {
+        let settings = Settings::default().backends(wgpu::Backends::VULKAN);
+
+        assert_eq!(settings.internal_backend, wgpu::Backends::VULKAN);
+        assert_eq!(Settings::default().internal_backend, wgpu::Backends::all());
+    }
+}
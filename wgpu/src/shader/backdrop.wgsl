@@ -0,0 +1,57 @@
+[[block]]
+struct Uniforms {
+    tint: vec4<f32>;
+    offset: vec2<f32>;
+    source_size: vec2<f32>;
+    direction: vec2<f32>;
+    radius: f32;
+};
+
+[[group(0), binding(0)]] var<uniform> uniforms: Uniforms;
+[[group(0), binding(1)]] var source: texture_2d<f32>;
+
+[[stage(vertex)]]
+fn vs_main(
+    [[builtin(vertex_index)]] vertex_index: u32
+) -> [[builtin(position)]] vec4<f32> {
+    // A single triangle covering the whole viewport
+    let uv = vec2<f32>(
+        f32((vertex_index << 1u) & 2u),
+        f32(vertex_index & 2u)
+    );
+
+    return vec4<f32>(uv * 2.0 - vec2<f32>(1.0, 1.0), 0.0, 1.0);
+}
+
+[[stage(fragment)]]
+fn fs_main(
+    [[builtin(position)]] position: vec4<f32>
+) -> [[location(0)]] vec4<f32> {
+    let center = floor(position.xy) + uniforms.offset;
+    let last_texel = uniforms.source_size - vec2<f32>(1.0, 1.0);
+    let sigma = max(uniforms.radius / 2.0, 0.0001);
+    let samples = i32(ceil(uniforms.radius));
+
+    var color: vec4<f32> = vec4<f32>(0.0, 0.0, 0.0, 0.0);
+    var total: f32 = 0.0;
+
+    for (var i: i32 = -samples; i <= samples; i = i + 1) {
+        let texel = clamp(
+            center + uniforms.direction * f32(i),
+            vec2<f32>(0.0, 0.0),
+            last_texel
+        );
+
+        let weight = exp(-f32(i * i) / (2.0 * sigma * sigma));
+
+        color = color + textureLoad(source, vec2<i32>(texel), 0) * weight;
+        total = total + weight;
+    }
+
+    let blurred = color / total;
+
+    return vec4<f32>(
+        mix(blurred.rgb, uniforms.tint.rgb, uniforms.tint.a),
+        blurred.a
+    );
+}
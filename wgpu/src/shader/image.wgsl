@@ -14,12 +14,19 @@ struct VertexInput {
     [[location(3)]] atlas_pos: vec2<f32>;
     [[location(4)]] atlas_scale: vec2<f32>;
     [[location(5)]] layer: i32;
+    [[location(6)]] mask_bounds: vec4<f32>;
+    [[location(7)]] mask_radius: f32;
+    [[location(8)]] opacity: f32;
 };
 
 struct VertexOutput {
     [[builtin(position)]] position: vec4<f32>;
     [[location(0)]] uv: vec2<f32>;
     [[location(1)]] layer: f32; // this should be an i32, but naga currently reads that as requiring interpolation.
+    [[location(2)]] logical_position: vec2<f32>;
+    [[location(3)]] mask_bounds: vec4<f32>;
+    [[location(4)]] mask_radius: f32;
+    [[location(5)]] opacity: f32;
 };
 
 [[stage(vertex)]]
@@ -28,6 +35,10 @@ fn vs_main(input: VertexInput) -> VertexOutput {
 
     out.uv = vec2<f32>(input.v_pos * input.atlas_scale + input.atlas_pos);
     out.layer = f32(input.layer);
+    out.logical_position = input.pos + input.v_pos * input.scale;
+    out.mask_bounds = input.mask_bounds;
+    out.mask_radius = input.mask_radius;
+    out.opacity = input.opacity;
 
     var transform: mat4x4<f32> = mat4x4<f32>(
         vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
@@ -41,7 +52,44 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     return out;
 }
 
+fn distance_alg(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32
+) -> f32 {
+    var inner_size: vec2<f32> = size - vec2<f32>(radius, radius) * 2.0;
+    var top_left: vec2<f32> = position + vec2<f32>(radius, radius);
+    var bottom_right: vec2<f32> = top_left + inner_size;
+
+    var top_left_distance: vec2<f32> = top_left - frag_coord;
+    var bottom_right_distance: vec2<f32> = frag_coord - bottom_right;
+
+    var dist: vec2<f32> = vec2<f32>(
+        max(max(top_left_distance.x, bottom_right_distance.x), 0.0),
+        max(max(top_left_distance.y, bottom_right_distance.y), 0.0)
+    );
+
+    return sqrt(dist.x * dist.x + dist.y * dist.y);
+}
+
 [[stage(fragment)]]
 fn fs_main(input: VertexOutput) -> [[location(0)]] vec4<f32> {
-    return textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+    var color: vec4<f32> = textureSample(u_texture, u_sampler, input.uv, i32(input.layer));
+
+    // Tiles and fitted images are masked by the rounded box of their quad
+    var dist: f32 = distance_alg(
+        input.logical_position,
+        input.mask_bounds.xy,
+        input.mask_bounds.zw,
+        input.mask_radius
+    );
+
+    var mask_alpha: f32 = 1.0 - smoothStep(
+        max(input.mask_radius - 0.5, 0.0),
+        input.mask_radius + 0.5,
+        dist
+    );
+
+    return vec4<f32>(color.xyz, color.w * input.opacity * mask_alpha);
 }
@@ -13,7 +13,14 @@ struct VertexInput {
     [[location(3)]] color: vec4<f32>;
     [[location(4)]] border_color: vec4<f32>;
     [[location(5)]] border_radius: f32;
-    [[location(6)]] border_width: f32;
+    [[location(6)]] border_width: vec4<f32>;
+    [[location(7)]] border_style: u32;
+    [[location(8)]] border_dash: vec2<f32>;
+    [[location(9)]] border_offset: f32;
+    [[location(10)]] shadow_color: vec4<f32>;
+    [[location(11)]] shadow_offset: vec2<f32>;
+    [[location(12)]] shadow_blur: f32;
+    [[location(13)]] shadow_kind: u32;
 };
 
 struct VertexOutput {
@@ -23,7 +30,14 @@ struct VertexOutput {
     [[location(2)]] pos: vec2<f32>;
     [[location(3)]] scale: vec2<f32>;
     [[location(4)]] border_radius: f32;
-    [[location(5)]] border_width: f32;
+    [[location(5)]] border_width: vec4<f32>;
+    [[location(6)]] border_style: f32;
+    [[location(7)]] border_dash: vec2<f32>;
+    [[location(8)]] border_offset: f32;
+    [[location(9)]] shadow_color: vec4<f32>;
+    [[location(10)]] shadow_offset: vec2<f32>;
+    [[location(11)]] shadow_blur: f32;
+    [[location(12)]] shadow_kind: f32;
 };
 
 [[stage(vertex)]]
@@ -51,6 +65,13 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     out.scale = scale;
     out.border_radius = border_radius * globals.scale;
     out.border_width = input.border_width * globals.scale;
+    out.border_style = f32(input.border_style);
+    out.border_dash = input.border_dash * globals.scale;
+    out.border_offset = input.border_offset * globals.scale;
+    out.shadow_color = input.shadow_color;
+    out.shadow_offset = input.shadow_offset * globals.scale;
+    out.shadow_blur = input.shadow_blur * globals.scale;
+    out.shadow_kind = f32(input.shadow_kind);
     out.position = globals.transform * transform * vec4<f32>(input.v_pos, 0.0, 1.0);
 
     return out;
@@ -77,23 +98,259 @@ fn distance_alg(
     return sqrt(dist.x * dist.x + dist.y * dist.y);
 }
 
+fn rounded_box_distance(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32
+) -> f32 {
+    var half_size: vec2<f32> = size / 2.0;
+
+    var q: vec2<f32> = abs(frag_coord - position - half_size)
+        - half_size + vec2<f32>(radius, radius);
+
+    return length(max(q, vec2<f32>(0.0, 0.0)))
+        + min(max(q.x, q.y), 0.0) - radius;
+}
+
+// Distance travelled along the perimeter of a rounded box, clockwise from the
+// start of its top edge, to reach the point closest to `frag_coord`.
+fn perimeter_position(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32
+) -> f32 {
+    var top_left: vec2<f32> = position + vec2<f32>(radius, radius);
+    var bottom_right: vec2<f32> = position + size - vec2<f32>(radius, radius);
+    var inner: vec2<f32> = bottom_right - top_left;
+    var arc: f32 = 1.5707964 * radius;
+
+    if (frag_coord.x > bottom_right.x && frag_coord.y < top_left.y) {
+        var d: vec2<f32> = frag_coord - vec2<f32>(bottom_right.x, top_left.y);
+
+        return inner.x + atan2(d.x, -d.y) * radius;
+    }
+
+    if (frag_coord.x > bottom_right.x && frag_coord.y > bottom_right.y) {
+        var d: vec2<f32> = frag_coord - bottom_right;
+
+        return inner.x + inner.y + arc + atan2(d.y, d.x) * radius;
+    }
+
+    if (frag_coord.x < top_left.x && frag_coord.y > bottom_right.y) {
+        var d: vec2<f32> = frag_coord - vec2<f32>(top_left.x, bottom_right.y);
+
+        return 2.0 * inner.x + inner.y + 2.0 * arc
+            + atan2(-d.x, d.y) * radius;
+    }
+
+    if (frag_coord.x < top_left.x && frag_coord.y < top_left.y) {
+        var d: vec2<f32> = frag_coord - top_left;
+
+        return 2.0 * inner.x + 2.0 * inner.y + 3.0 * arc
+            + atan2(-d.y, -d.x) * radius;
+    }
+
+    var to_top: f32 = frag_coord.y - position.y;
+    var to_right: f32 = position.x + size.x - frag_coord.x;
+    var to_bottom: f32 = position.y + size.y - frag_coord.y;
+    var to_left: f32 = frag_coord.x - position.x;
+    var nearest: f32 = min(min(to_top, to_right), min(to_bottom, to_left));
+
+    if (nearest == to_top) {
+        return frag_coord.x - top_left.x;
+    }
+
+    if (nearest == to_right) {
+        return inner.x + arc + frag_coord.y - top_left.y;
+    }
+
+    if (nearest == to_bottom) {
+        return inner.x + inner.y + 2.0 * arc + bottom_right.x - frag_coord.x;
+    }
+
+    return 2.0 * inner.x + inner.y + 3.0 * arc + bottom_right.y - frag_coord.y;
+}
+
+// Coverage of the dash pattern of a border at `frag_coord`.
+//
+// The pattern is measured along the center line of the border and stretched
+// so a whole number of periods fits its perimeter. This keeps the pattern
+// still unless `offset` changes.
+fn dash_alpha(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32,
+    border_width: f32,
+    border_style: f32,
+    dash: vec2<f32>,
+    offset: f32
+) -> f32 {
+    var period: f32 = dash.x + dash.y;
+
+    if (period <= 0.0) {
+        return 1.0;
+    }
+
+    var half_width: f32 = border_width / 2.0;
+    var center_position: vec2<f32> = position + vec2<f32>(half_width, half_width);
+    var center_size: vec2<f32> = size - vec2<f32>(border_width, border_width);
+    var center_radius: f32 = max(radius - half_width, 0.0);
+
+    var perimeter: f32 = 2.0 * (center_size.x + center_size.y)
+        + (6.2831855 - 8.0) * center_radius;
+
+    var stretch: f32 = perimeter / (max(round(perimeter / period), 1.0) * period);
+    var stretched_period: f32 = period * stretch;
+    var stretched_dash: f32 = dash.x * stretch;
+
+    var along: f32 = perimeter_position(
+        frag_coord,
+        center_position,
+        center_size,
+        center_radius
+    ) + offset;
+
+    var phase: f32 = along - floor(along / stretched_period) * stretched_period;
+
+    if (border_style > 1.5) {
+        var across: f32 = rounded_box_distance(
+            frag_coord,
+            center_position,
+            center_size,
+            center_radius
+        );
+
+        var dot_distance: f32 = length(vec2<f32>(phase - stretched_dash / 2.0, across));
+
+        return 1.0 - smoothStep(half_width - 0.5, half_width + 0.5, dot_distance);
+    }
+
+    return clamp(
+        0.5 + max(min(phase, stretched_dash - phase), phase - stretched_period),
+        0.0,
+        1.0
+    );
+}
+
+// Opacity of the inner shadow of a rounded box at `frag_coord`.
+//
+// The shadow covers the region of the box left uncovered when the box is
+// shifted by `offset`. A `blur` of zero produces a crisp edge.
+fn inner_shadow_alpha(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32,
+    offset: vec2<f32>,
+    blur: f32
+) -> f32 {
+    var shadow_distance: f32 = rounded_box_distance(
+        frag_coord - offset,
+        position,
+        size,
+        radius
+    );
+
+    if (blur > 0.0) {
+        return smoothStep(-blur, blur, shadow_distance);
+    }
+
+    return step(0.0, shadow_distance);
+}
+
+// Opacity of the drop shadow of a quad at `frag_coord`.
+//
+// The shadow is cast by the box left inside the quad when shrunk by `blur`,
+// which already includes the shadow offset. A `blur` of zero produces a crisp
+// edge.
+fn drop_shadow_alpha(
+    frag_coord: vec2<f32>,
+    position: vec2<f32>,
+    size: vec2<f32>,
+    radius: f32,
+    blur: f32
+) -> f32 {
+    var box_size: vec2<f32> = size - vec2<f32>(blur, blur) * 2.0;
+
+    var shadow_distance: f32 = rounded_box_distance(
+        frag_coord,
+        position + vec2<f32>(blur, blur),
+        box_size,
+        min(radius, min(box_size.x, box_size.y) / 2.0)
+    );
+
+    if (blur > 0.0) {
+        return 1.0 - smoothStep(-blur, blur, shadow_distance);
+    }
+
+    return step(shadow_distance, 0.0);
+}
 
 [[stage(fragment)]]
 fn fs_main(
     input: VertexOutput
 ) -> [[location(0)]] vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    if (input.shadow_kind > 0.5) {
+        return vec4<f32>(
+            input.shadow_color.xyz,
+            input.shadow_color.w * drop_shadow_alpha(
+                vec2<f32>(input.position.x, input.position.y),
+                input.pos,
+                input.scale,
+                input.border_radius,
+                input.shadow_blur
+            )
+        );
+    }
 
-    if (input.border_width > 0.0) {
+    var background: vec4<f32> = input.color;
+
+    if (input.shadow_color.w > 0.0) {
+        var shadow_alpha: f32 = input.shadow_color.w * inner_shadow_alpha(
+            vec2<f32>(input.position.x, input.position.y),
+            input.pos,
+            input.scale,
+            input.border_radius,
+            input.shadow_offset,
+            input.shadow_blur
+        );
+
+        var alpha: f32 = shadow_alpha + background.w * (1.0 - shadow_alpha);
+
+        if (alpha > 0.0) {
+            background = vec4<f32>(
+                (input.shadow_color.xyz * shadow_alpha
+                    + background.xyz * background.w * (1.0 - shadow_alpha)) / alpha,
+                alpha
+            );
+        }
+    }
+
+    var mixed_color: vec4<f32> = background;
+
+    // The widths of the top, right, bottom and left edges of the border
+    var border_width: vec4<f32> = input.border_width;
+    var max_border_width: f32 = max(
+        max(border_width.x, border_width.y),
+        max(border_width.z, border_width.w)
+    );
+
+    if (max_border_width > 0.0) {
         var internal_border: f32 = max(
-            input.border_radius - input.border_width,
+            input.border_radius - max_border_width,
             0.0
         );
 
         var internal_distance: f32 = distance_alg(
             vec2<f32>(input.position.x, input.position.y),
-            input.pos + vec2<f32>(input.border_width, input.border_width),
-            input.scale - vec2<f32>(input.border_width * 2.0, input.border_width * 2.0),
+            input.pos + vec2<f32>(border_width.w, border_width.x),
+            input.scale - vec2<f32>(
+                border_width.y + border_width.w,
+                border_width.x + border_width.z
+            ),
             internal_border
         );
 
@@ -103,7 +360,20 @@ fn fs_main(
             internal_distance
         );
 
-        mixed_color = mix(input.color, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
+        if (input.border_style > 0.5) {
+            border_mix = border_mix * dash_alpha(
+                vec2<f32>(input.position.x, input.position.y),
+                input.pos,
+                input.scale,
+                input.border_radius,
+                max_border_width,
+                input.border_style,
+                input.border_dash,
+                input.border_offset
+            );
+        }
+
+        mixed_color = mix(background, input.border_color, vec4<f32>(border_mix, border_mix, border_mix, border_mix));
     }
 
     var dist: f32 = distance_alg(
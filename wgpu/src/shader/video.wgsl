@@ -0,0 +1,79 @@
+[[block]]
+struct Globals {
+    transform: mat4x4<f32>;
+};
+
+[[group(0), binding(0)]] var<uniform> globals: Globals;
+[[group(0), binding(1)]] var u_sampler: sampler;
+[[group(1), binding(0)]] var u_y: texture_2d<f32>;
+[[group(1), binding(1)]] var u_u: texture_2d<f32>;
+[[group(1), binding(2)]] var u_v: texture_2d<f32>;
+
+struct VertexInput {
+    [[builtin(vertex_index)]] vertex_index: u32;
+    [[location(0)]] pos: vec2<f32>;
+    [[location(1)]] scale: vec2<f32>;
+};
+
+struct VertexOutput {
+    [[builtin(position)]] position: vec4<f32>;
+    [[location(0)]] uv: vec2<f32>;
+};
+
+[[stage(vertex)]]
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    // Two triangles covering the unit square: (0, 0), (1, 0), (1, 1) and
+    // (0, 0), (1, 1), (0, 1)
+    var i: u32 = input.vertex_index;
+    var uv: vec2<f32> = vec2<f32>(
+        select(0.0, 1.0, i == 1u || i == 2u || i == 4u),
+        select(0.0, 1.0, i == 2u || i == 4u || i == 5u)
+    );
+
+    var transform: mat4x4<f32> = mat4x4<f32>(
+        vec4<f32>(input.scale.x, 0.0, 0.0, 0.0),
+        vec4<f32>(0.0, input.scale.y, 0.0, 0.0),
+        vec4<f32>(0.0, 0.0, 1.0, 0.0),
+        vec4<f32>(input.pos, 0.0, 1.0)
+    );
+
+    out.uv = uv;
+    out.position = globals.transform * transform * vec4<f32>(uv, 0.0, 1.0);
+
+    return out;
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    return select(
+        pow((c + 0.055) / 1.055, 2.4),
+        c / 12.92,
+        c <= 0.04045
+    );
+}
+
+[[stage(fragment)]]
+fn fs_main(input: VertexOutput) -> [[location(0)]] vec4<f32> {
+    // BT.601, limited range
+    var y: f32 = (textureSample(u_y, u_sampler, input.uv).r - 0.0627451) * 1.164383;
+    var u: f32 = textureSample(u_u, u_sampler, input.uv).r - 0.5;
+    var v: f32 = textureSample(u_v, u_sampler, input.uv).r - 0.5;
+
+    var rgb: vec3<f32> = clamp(
+        vec3<f32>(
+            y + 1.596027 * v,
+            y - 0.391762 * u - 0.812968 * v,
+            y + 2.017232 * u
+        ),
+        vec3<f32>(0.0, 0.0, 0.0),
+        vec3<f32>(1.0, 1.0, 1.0)
+    );
+
+    return vec4<f32>(
+        srgb_to_linear(rgb.x),
+        srgb_to_linear(rgb.y),
+        srgb_to_linear(rgb.z),
+        1.0
+    );
+}
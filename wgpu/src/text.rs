@@ -1,6 +1,7 @@
-use crate::Transformation;
+use crate::{Hinting, Transformation};
 
-use iced_graphics::font;
+use iced_graphics::font::{self, Hinted};
+use iced_native::WritingMode;
 
 use std::{cell::RefCell, collections::HashMap};
 use wgpu_glyph::ab_glyph;
@@ -12,6 +13,11 @@ pub struct Pipeline {
     draw_brush: RefCell<wgpu_glyph::GlyphBrush<()>>,
     draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    emoji_font: Option<wgpu_glyph::FontId>,
+    hinting: Hinting,
+    subpixel_positioning: bool,
+    #[cfg(feature = "color-emoji")]
+    emoji_images: RefCell<HashMap<u16, Option<iced_native::image::Handle>>>,
 }
 
 impl Pipeline {
@@ -20,6 +26,8 @@ impl Pipeline {
         format: wgpu::TextureFormat,
         default_font: Option<&[u8]>,
         multithreading: bool,
+        hinting: Hinting,
+        subpixel_positioning: bool,
     ) -> Self {
         let default_font = default_font.map(|slice| slice.to_vec());
 
@@ -48,24 +56,56 @@ impl Pipeline {
                     .expect("Load fallback font")
             });
 
-        let draw_brush =
+        let mut draw_brush =
             wgpu_glyph::GlyphBrushBuilder::using_font(font.clone())
                 .initial_cache_size((2048, 2048))
                 .draw_cache_multithread(multithreading)
                 .build(device, format);
 
-        let measure_brush =
+        let mut measure_brush =
             glyph_brush::GlyphBrushBuilder::using_font(font).build();
 
+        #[cfg(feature = "color-emoji")]
+        let emoji_font = font::emoji::load()
+            .and_then(|bytes| ab_glyph::FontArc::try_from_vec(bytes).ok())
+            .map(|font| {
+                let _ = measure_brush.add_font(font.clone());
+
+                draw_brush.add_font(font)
+            });
+
+        #[cfg(not(feature = "color-emoji"))]
+        let emoji_font = None;
+
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
             measure_brush: RefCell::new(measure_brush),
+            emoji_font,
+            hinting,
+            subpixel_positioning,
+            #[cfg(feature = "color-emoji")]
+            emoji_images: RefCell::new(HashMap::new()),
         }
     }
 
-    pub fn queue(&mut self, section: wgpu_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue(
+        &mut self,
+        section: wgpu_glyph::Section<'_>,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) {
+        let layout = Hinted::new(
+            section.layout,
+            self.hinting,
+            self.subpixel_positioning,
+            letter_spacing,
+            writing_mode,
+        );
+
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, &layout);
     }
 
     pub fn draw_queued(
@@ -96,24 +136,36 @@ impl Pipeline {
         size: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
     ) -> (f32, f32) {
         use wgpu_glyph::GlyphCruncher;
 
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
+        let (_, text) = self.sections(
+            content,
+            self.find_font(font),
+            size.into(),
+            wgpu_glyph::Extra::default(),
+        );
 
         let section = wgpu_glyph::Section {
             bounds: (bounds.width, bounds.height),
-            text: vec![wgpu_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: wgpu_glyph::FontId(font_id),
-                extra: wgpu_glyph::Extra::default(),
-            }],
+            text,
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
+        let layout = Hinted::new(
+            section.layout,
+            Hinting::None,
+            false,
+            letter_spacing,
+            writing_mode,
+        );
+
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
         {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
@@ -130,47 +182,7 @@ impl Pipeline {
         point: iced_native::Point,
         nearest_only: bool,
     ) -> Option<Hit> {
-        use wgpu_glyph::GlyphCruncher;
-
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
-
-        let section = wgpu_glyph::Section {
-            bounds: (bounds.width, bounds.height),
-            text: vec![wgpu_glyph::Text {
-                text: content,
-                scale: size.into(),
-                font_id: wgpu_glyph::FontId(font_id),
-                extra: wgpu_glyph::Extra::default(),
-            }],
-            ..Default::default()
-        };
-
-        let mut mb = self.measure_brush.borrow_mut();
-
-        // The underlying type is FontArc, so clones are cheap.
-        use wgpu_glyph::ab_glyph::{Font, ScaleFont};
-        let font = mb.fonts()[font_id].clone().into_scaled(size);
-
-        // Implements an iterator over the glyph bounding boxes.
-        let bounds = mb.glyphs(section).map(
-            |wgpu_glyph::SectionGlyph {
-                 byte_index, glyph, ..
-             }| {
-                (
-                    *byte_index,
-                    iced_native::Rectangle::new(
-                        iced_native::Point::new(
-                            glyph.position.x - font.h_side_bearing(glyph.id),
-                            glyph.position.y - font.ascent(),
-                        ),
-                        iced_native::Size::new(
-                            font.h_advance(glyph.id),
-                            font.ascent() - font.descent(),
-                        ),
-                    ),
-                )
-            },
-        );
+        let bounds = self.glyph_bounds(content, size, font, bounds);
 
         // Implements computation of the character index based on the byte index
         // within the input string.
@@ -188,14 +200,15 @@ impl Pipeline {
         };
 
         if !nearest_only {
-            for (idx, bounds) in bounds.clone() {
+            for (idx, bounds) in bounds.iter() {
                 if bounds.contains(point) {
-                    return Some(Hit::CharOffset(char_index(idx)));
+                    return Some(Hit::CharOffset(char_index(*idx)));
                 }
             }
         }
 
         let nearest = bounds
+            .into_iter()
             .map(|(index, bounds)| (index, bounds.center()))
             .min_by(|(_, center_a), (_, center_b)| {
                 center_a
@@ -209,6 +222,254 @@ impl Pipeline {
         })
     }
 
+    pub fn glyph_positions(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<iced_native::Rectangle> {
+        self.glyph_bounds(content, size, font, bounds)
+            .into_iter()
+            .map(|(_, bounds)| bounds)
+            .collect()
+    }
+
+    /// Returns the byte index and the bounding box of every glyph of some
+    /// laid out content, in order.
+    fn glyph_bounds(
+        &self,
+        content: &str,
+        size: f32,
+        font: iced_native::Font,
+        bounds: iced_native::Size,
+    ) -> Vec<(usize, iced_native::Rectangle)> {
+        use wgpu_glyph::GlyphCruncher;
+
+        let (starts, text) = self.sections(
+            content,
+            self.find_font(font),
+            size.into(),
+            wgpu_glyph::Extra::default(),
+        );
+
+        let section = wgpu_glyph::Section {
+            bounds: (bounds.width, bounds.height),
+            text,
+            ..Default::default()
+        };
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        use wgpu_glyph::ab_glyph::{Font, ScaleFont};
+        let fonts: Vec<_> = mb
+            .fonts()
+            .iter()
+            .map(|font| font.clone().into_scaled(size))
+            .collect();
+
+        mb.glyphs(section)
+            .map(
+                |wgpu_glyph::SectionGlyph {
+                     section_index,
+                     byte_index,
+                     glyph,
+                     font_id,
+                 }| {
+                    let font = &fonts[font_id.0];
+
+                    (
+                        starts[*section_index] + *byte_index,
+                        iced_native::Rectangle::new(
+                            iced_native::Point::new(
+                                glyph.position.x
+                                    - font.h_side_bearing(glyph.id),
+                                glyph.position.y - font.ascent(),
+                            ),
+                            iced_native::Size::new(
+                                font.h_advance(glyph.id),
+                                font.ascent() - font.descent(),
+                            ),
+                        ),
+                    )
+                },
+            )
+            .collect()
+    }
+
+    /// Splits some content into the sections of text laid out by the brushes,
+    /// alongside the byte index where each section starts.
+    ///
+    /// Emoji missing from the given font are laid out with the emoji font,
+    /// if one was found.
+    pub fn sections<'a>(
+        &self,
+        content: &'a str,
+        font_id: wgpu_glyph::FontId,
+        scale: ab_glyph::PxScale,
+        extra: wgpu_glyph::Extra,
+    ) -> (Vec<usize>, Vec<wgpu_glyph::Text<'a>>) {
+        let text = |text, font_id, extra| wgpu_glyph::Text {
+            text,
+            scale,
+            font_id,
+            extra,
+        };
+
+        let emoji_font = match self.emoji_font {
+            Some(emoji_font) if font_id != emoji_font => emoji_font,
+            _ => return (vec![0], vec![text(content, font_id, extra)]),
+        };
+
+        #[cfg(feature = "color-emoji")]
+        {
+            use ab_glyph::Font;
+
+            let brush = self.measure_brush.borrow();
+            let fonts = brush.fonts();
+
+            let runs = font::emoji::runs(
+                content,
+                |c| fonts[font_id.0].glyph_id(c).0 == 0,
+                |c| fonts[emoji_font.0].glyph_id(c).0 != 0,
+            );
+
+            runs.into_iter()
+                .flat_map(|run| {
+                    let is_emoji = run.is_emoji;
+
+                    run.pieces()
+                        .into_iter()
+                        .map(move |(start, piece)| (start, piece, is_emoji))
+                })
+                .map(|(start, piece, is_emoji)| {
+                    if !is_emoji {
+                        return (start, text(piece, font_id, extra));
+                    }
+
+                    // Color glyphs are drawn as images on top of the text,
+                    // so their outlines, if any, are hidden
+                    let c = piece.chars().next().unwrap_or_default();
+                    let glyph = fonts[emoji_font.0].glyph_id(c);
+
+                    let extra = if self.emoji_image(glyph).is_some() {
+                        wgpu_glyph::Extra {
+                            color: [0.0; 4],
+                            ..extra
+                        }
+                    } else {
+                        extra
+                    };
+
+                    (start, text(piece, emoji_font, extra))
+                })
+                .unzip()
+        }
+
+        #[cfg(not(feature = "color-emoji"))]
+        {
+            let _ = emoji_font;
+
+            (vec![0], vec![text(content, font_id, extra)])
+        }
+    }
+
+    /// Returns the color emoji of a queued [`wgpu_glyph::Section`], with
+    /// their bounds in the same coordinates.
+    #[cfg(feature = "color-emoji")]
+    pub fn emoji_images(
+        &self,
+        section: &wgpu_glyph::Section<'_>,
+        letter_spacing: f32,
+        writing_mode: WritingMode,
+    ) -> Vec<(iced_native::image::Handle, iced_native::Rectangle)> {
+        use ab_glyph::{Font, ScaleFont};
+        use wgpu_glyph::GlyphCruncher;
+
+        let emoji_font = match self.emoji_font {
+            Some(emoji_font) => emoji_font,
+            None => return Vec::new(),
+        };
+
+        if section.text.iter().all(|text| text.font_id != emoji_font) {
+            return Vec::new();
+        }
+
+        let (font, glyphs) = {
+            let mut brush = self.measure_brush.borrow_mut();
+            let font = brush.fonts()[emoji_font.0].clone();
+
+            let layout = Hinted::new(
+                section.layout,
+                self.hinting,
+                self.subpixel_positioning,
+                letter_spacing,
+                writing_mode,
+            );
+
+            let glyphs: Vec<_> = brush
+                .glyphs_custom_layout(section, &layout)
+                .filter(|glyph| glyph.font_id == emoji_font)
+                .map(|wgpu_glyph::SectionGlyph { glyph, .. }| glyph.clone())
+                .collect();
+
+            (font, glyphs)
+        };
+
+        glyphs
+            .into_iter()
+            .filter_map(|glyph| {
+                let handle = self.emoji_image(glyph.id)?;
+                let font = font.as_scaled(glyph.scale);
+                let size = font.height();
+
+                Some((
+                    handle,
+                    iced_native::Rectangle {
+                        x: glyph.position.x,
+                        y: glyph.position.y - font.ascent(),
+                        width: font.h_advance(glyph.id).max(size),
+                        height: size,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns the color bitmap of a glyph of the emoji font, if it has one.
+    ///
+    /// Bitmaps are decoded by the image pipeline from their largest strike,
+    /// and scaled down from there.
+    #[cfg(feature = "color-emoji")]
+    fn emoji_image(
+        &self,
+        glyph: ab_glyph::GlyphId,
+    ) -> Option<iced_native::image::Handle> {
+        use ab_glyph::Font;
+
+        let emoji_font = self.emoji_font?;
+
+        self.emoji_images
+            .borrow_mut()
+            .entry(glyph.0)
+            .or_insert_with(|| {
+                let brush = self.measure_brush.borrow();
+                let image = brush.fonts()[emoji_font.0]
+                    .glyph_raster_image(glyph, u16::MAX)?;
+
+                match image.format {
+                    ab_glyph::GlyphImageFormat::Png => {
+                        Some(iced_native::image::Handle::from_memory(
+                            image.data.to_vec(),
+                        ))
+                    }
+                    _ => None,
+                }
+            })
+            .clone()
+    }
+
     pub fn trim_measurement_cache(&mut self) {
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
@@ -233,6 +494,19 @@ impl Pipeline {
         }
     }
 
+    /// Returns the physical position where a section of text starting at the
+    /// given one is drawn.
+    ///
+    /// It is snapped to whole pixels, unless subpixel positioning is enabled
+    /// and it is only snapped to a quarter of a pixel horizontally.
+    pub fn snap_origin(&self, x: f32, y: f32) -> (f32, f32) {
+        if self.subpixel_positioning {
+            (font::quantize(x), y.round())
+        } else {
+            (x.round(), y.round())
+        }
+    }
+
     pub fn find_font(&self, font: iced_native::Font) -> wgpu_glyph::FontId {
         match font {
             iced_native::Font::Default => wgpu_glyph::FontId(0),
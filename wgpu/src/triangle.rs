@@ -5,10 +5,15 @@ use iced_graphics::layer;
 use bytemuck::{Pod, Zeroable};
 use std::mem;
 
-pub use iced_graphics::triangle::{Mesh2D, Vertex2D};
+pub use iced_graphics::triangle::{
+    Mesh2D, TexturedMesh2D, Vertex2D, Vertex2DTextured,
+};
 
 mod msaa;
 
+#[cfg(feature = "image_rs")]
+pub(crate) mod textured;
+
 const UNIFORM_BUFFER_SIZE: usize = 50;
 const VERTEX_BUFFER_SIZE: usize = 10_000;
 const INDEX_BUFFER_SIZE: usize = 10_000;
@@ -22,6 +27,8 @@ pub(crate) struct Pipeline {
     uniforms_buffer: Buffer<Uniforms>,
     vertex_buffer: Buffer<Vertex2D>,
     index_buffer: Buffer<u32>,
+    uniforms: Vec<Uniforms>,
+    offsets: Vec<(wgpu::BufferAddress, wgpu::BufferAddress, usize)>,
 }
 
 #[derive(Debug)]
@@ -210,6 +217,8 @@ impl Pipeline {
                 INDEX_BUFFER_SIZE,
                 wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
             ),
+            uniforms: Vec::new(),
+            offsets: Vec::new(),
         }
     }
 
@@ -264,12 +273,10 @@ impl Pipeline {
                 });
         }
 
-        let mut uniforms: Vec<Uniforms> = Vec::with_capacity(meshes.len());
-        let mut offsets: Vec<(
-            wgpu::BufferAddress,
-            wgpu::BufferAddress,
-            usize,
-        )> = Vec::with_capacity(meshes.len());
+        // The lists of the previous draw are cleared to keep their allocations
+        self.uniforms.clear();
+        self.offsets.clear();
+
         let mut last_vertex = 0;
         let mut last_index = 0;
 
@@ -312,8 +319,8 @@ impl Pipeline {
                         index_buffer.copy_from_slice(indices);
                     }
 
-                    uniforms.push(transform);
-                    offsets.push((
+                    self.uniforms.push(transform);
+                    self.offsets.push((
                         last_vertex as u64,
                         last_index as u64,
                         mesh.buffers.indices.len(),
@@ -326,7 +333,7 @@ impl Pipeline {
             }
         }
 
-        let uniforms = bytemuck::cast_slice(&uniforms);
+        let uniforms = bytemuck::cast_slice(&self.uniforms);
 
         if let Some(uniforms_size) =
             wgpu::BufferSize::new(uniforms.len() as u64)
@@ -370,8 +377,8 @@ impl Pipeline {
 
             render_pass.set_pipeline(&self.pipeline);
 
-            for (i, (vertex_offset, index_offset, indices)) in
-                offsets.into_iter().enumerate()
+            for (i, &(vertex_offset, index_offset, indices)) in
+                self.offsets.iter().enumerate()
             {
                 let clip_bounds = (meshes[i].clip_bounds * scale_factor).snap();
 
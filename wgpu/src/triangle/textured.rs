@@ -0,0 +1,407 @@
+//! Draw meshes of triangles that sample the image texture atlas.
+use super::{msaa, Buffer, Uniforms};
+use super::{INDEX_BUFFER_SIZE, UNIFORM_BUFFER_SIZE, VERTEX_BUFFER_SIZE};
+use crate::image::Region;
+use crate::{settings, Transformation};
+use iced_graphics::layer;
+
+use bytemuck::{Pod, Zeroable};
+use std::mem;
+
+#[derive(Debug)]
+pub(crate) struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    blit: Option<msaa::Blit>,
+    constants_layout: wgpu::BindGroupLayout,
+    constants: wgpu::BindGroup,
+    sampler: wgpu::BindGroup,
+    uniforms_buffer: Buffer<Uniforms>,
+    vertex_buffer: Buffer<Vertex>,
+    index_buffer: Buffer<u32>,
+}
+
+impl Pipeline {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        antialiasing: Option<settings::Antialiasing>,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) -> Pipeline {
+        let constants_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::triangle::textured uniforms layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: true,
+                        min_binding_size: wgpu::BufferSize::new(
+                            mem::size_of::<Uniforms>() as u64,
+                        ),
+                    },
+                    count: None,
+                }],
+            });
+
+        let uniforms_buffer = Buffer::new(
+            "iced_wgpu::triangle::textured uniforms buffer",
+            device,
+            UNIFORM_BUFFER_SIZE,
+            wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        );
+
+        let constants =
+            constants(device, &constants_layout, &uniforms_buffer.raw);
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let sampler_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::triangle::textured sampler layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler {
+                        comparison: false,
+                        filtering: true,
+                    },
+                    count: None,
+                }],
+            });
+
+        let sampler = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::triangle::textured sampler bind group"),
+            layout: &sampler_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            }],
+        });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::triangle::textured pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[
+                    &constants_layout,
+                    texture_layout,
+                    &sampler_layout,
+                ],
+            });
+
+        let shader =
+            device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::triangle::textured::shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("../shader/textured_triangle.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::triangle::textured pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: mem::size_of::<Vertex>() as u64,
+                        step_mode: wgpu::VertexStepMode::Vertex,
+                        attributes: &wgpu::vertex_attr_array!(
+                            // Position
+                            0 => Float32x2,
+                            // Position in atlas
+                            1 => Float32x2,
+                            // Color
+                            2 => Float32x4,
+                            // Layer
+                            3 => Sint32,
+                        ),
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::SrcAlpha,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: u32::from(
+                        antialiasing.map(|a| a.sample_count()).unwrap_or(1),
+                    ),
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            });
+
+        Pipeline {
+            pipeline,
+            blit: antialiasing.map(|a| msaa::Blit::new(device, format, a)),
+            constants_layout,
+            constants,
+            sampler,
+            uniforms_buffer,
+            vertex_buffer: Buffer::new(
+                "iced_wgpu::triangle::textured vertex buffer",
+                device,
+                VERTEX_BUFFER_SIZE,
+                wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            ),
+            index_buffer: Buffer::new(
+                "iced_wgpu::triangle::textured index buffer",
+                device,
+                INDEX_BUFFER_SIZE,
+                wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST,
+            ),
+        }
+    }
+
+    /// Draws the given meshes, sampling the [`Region`] of the atlas
+    /// `texture` where their image was uploaded.
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        target_width: u32,
+        target_height: u32,
+        transformation: Transformation,
+        scale_factor: f32,
+        meshes: &[(&layer::TexturedMesh<'_>, Region)],
+        texture: &wgpu::BindGroup,
+    ) {
+        let (total_vertices, total_indices) =
+            meshes
+                .iter()
+                .fold((0, 0), |(vertices, indices), (mesh, _)| {
+                    (
+                        vertices + mesh.buffers.vertices.len(),
+                        indices + mesh.buffers.indices.len(),
+                    )
+                });
+
+        let _ = self.vertex_buffer.expand(device, total_vertices);
+        let _ = self.index_buffer.expand(device, total_indices);
+
+        if self.uniforms_buffer.expand(device, meshes.len()) {
+            self.constants = constants(
+                device,
+                &self.constants_layout,
+                &self.uniforms_buffer.raw,
+            );
+        }
+
+        let mut uniforms: Vec<Uniforms> = Vec::with_capacity(meshes.len());
+        let mut draws = Vec::with_capacity(meshes.len());
+        let mut last_vertex = 0;
+        let mut last_index = 0;
+
+        for (mesh, region) in meshes {
+            let vertices: Vec<Vertex> = mesh
+                .buffers
+                .vertices
+                .iter()
+                .map(|vertex| Vertex {
+                    _position: vertex.position,
+                    _uv: region.map(vertex.uv),
+                    _color: vertex.color,
+                    _layer: region.layer,
+                })
+                .collect();
+
+            let vertices = bytemuck::cast_slice(&vertices);
+            let indices = bytemuck::cast_slice(&mesh.buffers.indices);
+
+            if let (Some(vertices_size), Some(indices_size)) = (
+                wgpu::BufferSize::new(vertices.len() as u64),
+                wgpu::BufferSize::new(indices.len() as u64),
+            ) {
+                staging_belt
+                    .write_buffer(
+                        encoder,
+                        &self.vertex_buffer.raw,
+                        (mem::size_of::<Vertex>() * last_vertex) as u64,
+                        vertices_size,
+                        device,
+                    )
+                    .copy_from_slice(vertices);
+
+                staging_belt
+                    .write_buffer(
+                        encoder,
+                        &self.index_buffer.raw,
+                        (mem::size_of::<u32>() * last_index) as u64,
+                        indices_size,
+                        device,
+                    )
+                    .copy_from_slice(indices);
+
+                uniforms.push(
+                    (transformation
+                        * Transformation::translate(
+                            mesh.origin.x,
+                            mesh.origin.y,
+                        ))
+                    .into(),
+                );
+
+                draws.push((
+                    last_vertex as u64,
+                    last_index as u64,
+                    mesh.buffers.indices.len(),
+                    mesh.clip_bounds,
+                ));
+
+                last_vertex += mesh.buffers.vertices.len();
+                last_index += mesh.buffers.indices.len();
+            }
+        }
+
+        let uniforms = bytemuck::cast_slice(&uniforms);
+
+        if let Some(uniforms_size) =
+            wgpu::BufferSize::new(uniforms.len() as u64)
+        {
+            staging_belt
+                .write_buffer(
+                    encoder,
+                    &self.uniforms_buffer.raw,
+                    0,
+                    uniforms_size,
+                    device,
+                )
+                .copy_from_slice(uniforms);
+        }
+
+        {
+            let (attachment, resolve_target, load) =
+                if let Some(blit) = &mut self.blit {
+                    let (attachment, resolve_target) =
+                        blit.targets(device, target_width, target_height);
+
+                    (
+                        attachment,
+                        Some(resolve_target),
+                        wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    )
+                } else {
+                    (target, None, wgpu::LoadOp::Load)
+                };
+
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::triangle::textured render pass"),
+                    color_attachments: &[wgpu::RenderPassColorAttachment {
+                        view: attachment,
+                        resolve_target,
+                        ops: wgpu::Operations { load, store: true },
+                    }],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(1, texture, &[]);
+            render_pass.set_bind_group(2, &self.sampler, &[]);
+
+            for (i, (vertex_offset, index_offset, indices, clip_bounds)) in
+                draws.into_iter().enumerate()
+            {
+                let clip_bounds = (clip_bounds * scale_factor).snap();
+
+                render_pass.set_scissor_rect(
+                    clip_bounds.x,
+                    clip_bounds.y,
+                    clip_bounds.width,
+                    clip_bounds.height,
+                );
+
+                render_pass.set_bind_group(
+                    0,
+                    &self.constants,
+                    &[(mem::size_of::<Uniforms>() * i) as u32],
+                );
+
+                render_pass.set_index_buffer(
+                    self.index_buffer
+                        .raw
+                        .slice(index_offset * mem::size_of::<u32>() as u64..),
+                    wgpu::IndexFormat::Uint32,
+                );
+
+                render_pass.set_vertex_buffer(
+                    0,
+                    self.vertex_buffer.raw.slice(
+                        vertex_offset * mem::size_of::<Vertex>() as u64..,
+                    ),
+                );
+
+                render_pass.draw_indexed(0..indices as u32, 0, 0..1);
+            }
+        }
+
+        if let Some(blit) = &mut self.blit {
+            blit.draw(encoder, target);
+        }
+    }
+}
+
+fn constants(
+    device: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    uniforms: &wgpu::Buffer,
+) -> wgpu::BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("iced_wgpu::triangle::textured uniforms bind group"),
+        layout,
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                buffer: uniforms,
+                offset: 0,
+                size: wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64),
+            }),
+        }],
+    })
+}
+
+/// A [`Vertex2DTextured`] with its texture coordinates mapped into the atlas.
+///
+/// [`Vertex2DTextured`]: super::Vertex2DTextured
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Vertex {
+    _position: [f32; 2],
+    _uv: [f32; 2],
+    _color: [f32; 4],
+    _layer: u32,
+}
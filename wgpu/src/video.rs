@@ -0,0 +1,516 @@
+use crate::Transformation;
+
+use iced_graphics::layer;
+use iced_native::video;
+use iced_native::Rectangle;
+
+use bytemuck::{Pod, Zeroable};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+/// Draws the current frames of videos, converting them from YUV to RGB.
+#[derive(Debug)]
+pub struct Pipeline {
+    pipeline: wgpu::RenderPipeline,
+    uniforms: wgpu::Buffer,
+    constants: wgpu::BindGroup,
+    texture_layout: wgpu::BindGroupLayout,
+    entries: HashMap<u64, Entry>,
+    hits: HashSet<u64>,
+}
+
+/// The textures of a video, holding the planes of its last uploaded frame.
+#[derive(Debug)]
+struct Entry {
+    handle: video::Handle,
+    size: (u32, u32),
+    frame: Option<Arc<video::Frame>>,
+    planes: [wgpu::Texture; 3],
+    bind_group: wgpu::BindGroup,
+    instance: wgpu::Buffer,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::video constants layout"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(
+                                mem::size_of::<Uniforms>() as u64,
+                            ),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler {
+                            comparison: false,
+                            filtering: true,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::video uniforms buffer"),
+            size: mem::size_of::<Uniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::video constants bind group"),
+            layout: &constant_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer(
+                        wgpu::BufferBinding {
+                            buffer: &uniforms,
+                            offset: 0,
+                            size: None,
+                        },
+                    ),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        let plane_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::FRAGMENT,
+            ty: wgpu::BindingType::Texture {
+                sample_type: wgpu::TextureSampleType::Float {
+                    filterable: true,
+                },
+                view_dimension: wgpu::TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::video planes layout"),
+                entries: &[plane_entry(0), plane_entry(1), plane_entry(2)],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::video pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::video::shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/video.wgsl"),
+                )),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::video pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[wgpu::VertexBufferLayout {
+                        array_stride: mem::size_of::<Instance>() as u64,
+                        step_mode: wgpu::VertexStepMode::Instance,
+                        attributes: &wgpu::vertex_attr_array!(
+                            0 => Float32x2,
+                            1 => Float32x2,
+                        ),
+                    }],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::REPLACE),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            });
+
+        Pipeline {
+            pipeline,
+            uniforms,
+            constants,
+            texture_layout,
+            entries: HashMap::new(),
+            hits: HashSet::new(),
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        videos: &[layer::Video],
+        transformation: Transformation,
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
+        let mut drawn = Vec::with_capacity(videos.len());
+
+        for video in videos {
+            let id = video.handle.id();
+            let _ = self.hits.insert(id);
+
+            // Videos without a decoded frame yet are skipped
+            let frame = match video.handle.frame() {
+                Some(frame) => frame,
+                None => continue,
+            };
+
+            let size = (frame.width, frame.height);
+
+            // The textures are recreated when the size of the video changes
+            let is_outdated = self
+                .entries
+                .get(&id)
+                .map_or(true, |entry| entry.size != size);
+
+            if is_outdated {
+                let _ = self.entries.insert(
+                    id,
+                    Entry::new(
+                        device,
+                        &self.texture_layout,
+                        video.handle.clone(),
+                        size,
+                    ),
+                );
+            }
+
+            let entry = self.entries.get_mut(&id).expect("Get video entry");
+
+            let is_new_frame = entry
+                .frame
+                .as_ref()
+                .map_or(true, |uploaded| !Arc::ptr_eq(uploaded, &frame));
+
+            if is_new_frame {
+                entry.upload(device, encoder, &frame);
+                entry.frame = Some(frame);
+            }
+
+            let mut instance = staging_belt.write_buffer(
+                encoder,
+                &entry.instance,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Instance>() as u64)
+                    .unwrap(),
+                device,
+            );
+
+            instance.copy_from_slice(bytemuck::bytes_of(&Instance {
+                _position: [video.bounds.x, video.bounds.y],
+                _size: [video.bounds.width, video.bounds.height],
+            }));
+
+            drawn.push(id);
+        }
+
+        if drawn.is_empty() {
+            return;
+        }
+
+        {
+            let mut uniforms = staging_belt.write_buffer(
+                encoder,
+                &self.uniforms,
+                0,
+                wgpu::BufferSize::new(mem::size_of::<Uniforms>() as u64)
+                    .unwrap(),
+                device,
+            );
+
+            uniforms.copy_from_slice(bytemuck::bytes_of(&Uniforms {
+                transform: transformation.into(),
+            }));
+        }
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("iced_wgpu::video render pass"),
+                color_attachments: &[wgpu::RenderPassColorAttachment {
+                    view: target,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load,
+                        store: true,
+                    },
+                }],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.constants, &[]);
+        render_pass.set_scissor_rect(
+            bounds.x,
+            bounds.y,
+            bounds.width,
+            bounds.height,
+        );
+
+        for entry in drawn.iter().filter_map(|id| self.entries.get(id)) {
+            render_pass.set_bind_group(1, &entry.bind_group, &[]);
+            render_pass.set_vertex_buffer(0, entry.instance.slice(..));
+            render_pass.draw(0..6, 0..1);
+        }
+    }
+
+    /// Returns whether any of the videos drawn in the last frame is playing,
+    /// and another frame should be drawn to show its next frame.
+    pub fn is_playing(&self) -> bool {
+        self.entries.values().any(|entry| entry.handle.is_playing())
+    }
+
+    /// Drops the textures of the videos that were not drawn since the last
+    /// call.
+    pub fn trim_cache(&mut self) {
+        let hits = &self.hits;
+
+        self.entries.retain(|id, _| hits.contains(id));
+        self.hits.clear();
+    }
+}
+
+impl Entry {
+    fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        handle: video::Handle,
+        (width, height): (u32, u32),
+    ) -> Self {
+        let plane = |label, width, height| {
+            device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width: width.max(1),
+                    height: height.max(1),
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::R8Unorm,
+                usage: wgpu::TextureUsages::COPY_DST
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+            })
+        };
+
+        let (chroma_width, chroma_height) = ((width + 1) / 2, (height + 1) / 2);
+
+        let planes = [
+            plane("iced_wgpu::video luma plane", width, height),
+            plane(
+                "iced_wgpu::video blue chroma plane",
+                chroma_width,
+                chroma_height,
+            ),
+            plane(
+                "iced_wgpu::video red chroma plane",
+                chroma_width,
+                chroma_height,
+            ),
+        ];
+
+        let views: Vec<_> = planes
+            .iter()
+            .map(|plane| {
+                plane.create_view(&wgpu::TextureViewDescriptor::default())
+            })
+            .collect();
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::video planes bind group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&views[0]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&views[1]),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::TextureView(&views[2]),
+                },
+            ],
+        });
+
+        let instance = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::video instance buffer"),
+            size: mem::size_of::<Instance>() as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Entry {
+            handle,
+            size: (width, height),
+            frame: None,
+            planes,
+            bind_group,
+            instance,
+        }
+    }
+
+    fn upload(
+        &self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &video::Frame,
+    ) {
+        let (chroma_width, chroma_height) = frame.chroma_dimensions();
+
+        upload_plane(
+            device,
+            encoder,
+            &self.planes[0],
+            &frame.y,
+            frame.width,
+            frame.height,
+        );
+        upload_plane(
+            device,
+            encoder,
+            &self.planes[1],
+            &frame.u,
+            chroma_width,
+            chroma_height,
+        );
+        upload_plane(
+            device,
+            encoder,
+            &self.planes[2],
+            &frame.v,
+            chroma_width,
+            chroma_height,
+        );
+    }
+}
+
+fn upload_plane(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    texture: &wgpu::Texture,
+    data: &[u8],
+    width: u32,
+    height: u32,
+) {
+    use wgpu::util::DeviceExt;
+
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    if data.len() < (width * height) as usize {
+        log::warn!("Video plane is smaller than {}x{}", width, height);
+        return;
+    }
+
+    // It is a webgpu requirement that:
+    //   BufferCopyView.layout.bytes_per_row % wgpu::COPY_BYTES_PER_ROW_ALIGNMENT == 0
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    let padding = (align - width % align) % align;
+    let padded_width = (width + padding) as usize;
+
+    let mut padded_data = vec![0; padded_width * height as usize];
+
+    for (row, pixels) in data
+        .chunks(width as usize)
+        .take(height as usize)
+        .enumerate()
+    {
+        let offset = row * padded_width;
+
+        padded_data[offset..offset + pixels.len()].copy_from_slice(pixels);
+    }
+
+    let buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("iced_wgpu::video staging buffer"),
+        contents: &padded_data,
+        usage: wgpu::BufferUsages::COPY_SRC,
+    });
+
+    encoder.copy_buffer_to_texture(
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: NonZeroU32::new(padded_width as u32),
+                rows_per_image: NonZeroU32::new(height),
+            },
+        },
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+    );
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Instance {
+    _position: [f32; 2],
+    _size: [f32; 2],
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct Uniforms {
+    transform: [f32; 16],
+}
@@ -0,0 +1,7 @@
+//! Draw an outline around some content.
+use crate::Renderer;
+
+/// An element drawing a border around some content.
+///
+/// This is an alias of an `iced_native` border with an `iced_wgpu::Renderer`.
+pub type Border<'a, Message> = iced_native::Border<'a, Message, Renderer>;
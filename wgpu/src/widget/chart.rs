@@ -0,0 +1,16 @@
+//! Plot data points as lines or bars.
+use crate::Renderer;
+
+pub use iced_graphics::chart::{Axis, Bar, Series, Style, StyleSheet};
+
+/// A chart plotting some series of data points as lines.
+///
+/// This is an alias of an `iced_native` line chart with an
+/// `iced_wgpu::Renderer`.
+pub type LineChart = iced_native::LineChart<Renderer>;
+
+/// A chart displaying discrete values as vertical bars.
+///
+/// This is an alias of an `iced_native` bar chart with an
+/// `iced_wgpu::Renderer`.
+pub type BarChart<Message> = iced_native::BarChart<Message, Renderer>;
@@ -0,0 +1,9 @@
+//! Display CommonMark documents as a tree of widgets.
+use crate::Renderer;
+
+/// A CommonMark document, displayed as a column of widgets.
+///
+/// This is an alias of an `iced_native` markdown view with an
+/// `iced_wgpu::Renderer`.
+pub type MarkdownView<Message> =
+    iced_native::markdown::MarkdownView<Message, Renderer>;
@@ -0,0 +1,7 @@
+//! Fade some content.
+use crate::Renderer;
+
+/// An element that draws its content with some opacity.
+///
+/// This is an alias of an `iced_native` opacity with an `iced_wgpu::Renderer`.
+pub type Opacity<'a, Message> = iced_native::Opacity<'a, Message, Renderer>;
@@ -0,0 +1,9 @@
+//! Show that something is in progress with an animated spinner.
+use crate::Renderer;
+
+pub use iced_graphics::spinner::{Style, StyleSheet};
+
+/// A ring that keeps turning while something is in progress.
+///
+/// This is an alias of an `iced_native` spinner with an `iced_wgpu::Renderer`.
+pub type Spinner = iced_native::Spinner<Renderer>;
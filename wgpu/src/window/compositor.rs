@@ -1,9 +1,13 @@
+use std::cell::Cell;
+use std::rc::Rc;
 use std::sync::Arc;
 
-use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
+use crate::{Backend, Color, ColorSpace, Error, Renderer, Settings, Viewport};
 
-use futures::task::SpawnExt;
-use iced_native::{futures, mouse};
+use futures::channel::oneshot;
+use futures::task::{LocalSpawnExt, SpawnExt};
+use iced_graphics::layer::Layer;
+use iced_native::{futures, mouse, trace, Size};
 use raw_window_handle::HasRawWindowHandle;
 
 /// A window graphics backend for iced powered by `wgpu`.
@@ -14,12 +18,15 @@ pub struct Compositor {
     device: Arc<wgpu::Device>,
     queue: Arc<wgpu::Queue>,
     staging_belt: wgpu::util::StagingBelt,
+    chunk_size: u64,
     local_pool: futures::executor::LocalPool,
+    pending_captures: Rc<Cell<usize>>,
     format: wgpu::TextureFormat,
 }
 
 impl Compositor {
     const CHUNK_SIZE: u64 = 10 * 1024;
+    const MAX_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
 
     /// Requests a new [`Compositor`] with the given [`Settings`].
     ///
@@ -36,16 +43,16 @@ impl Compositor {
 
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: if settings.antialiasing.is_none() {
-                    wgpu::PowerPreference::LowPower
-                } else {
-                    wgpu::PowerPreference::HighPerformance
-                },
+                power_preference: settings.power_preference,
                 compatible_surface: compatible_surface.as_ref(),
             })
             .await?;
 
-        let format = compatible_surface
+        let settings = settings.with_supported_present_mode(|mode| {
+            can_present_with(&adapter, mode)
+        });
+
+        let default_format = compatible_surface
             .as_ref()
             .and_then(|surface| surface.get_preferred_format(&adapter))?;
 
@@ -66,6 +73,15 @@ impl Compositor {
             .await
             .ok()?;
 
+        let (settings, format) =
+            settings.with_supported_color_space(default_format, |format| {
+                adapter
+                    .get_texture_format_features(format)
+                    .allowed_usages
+                    .contains(target_usage())
+                    && can_present(&adapter, default_format, format)
+            });
+
         let device = Arc::new(device);
         let queue = Arc::new(queue);
 
@@ -78,11 +94,125 @@ impl Compositor {
             device,
             queue,
             staging_belt,
+            chunk_size: Self::CHUNK_SIZE,
             local_pool,
+            pending_captures: Rc::new(Cell::new(0)),
             format,
         })
     }
 
+    /// Returns the present mode used by the surfaces of this [`Compositor`].
+    ///
+    /// It may differ from the one requested in the [`Settings`] if the
+    /// graphics adapter does not support it.
+    pub fn present_mode(&self) -> wgpu::PresentMode {
+        self.settings.present_mode
+    }
+
+    /// Returns the [`ColorSpace`] used by the surfaces of this [`Compositor`].
+    ///
+    /// It may differ from the one requested in the [`Settings`] if the
+    /// surface does not support it.
+    pub fn color_space(&self) -> ColorSpace {
+        self.settings.color_space
+    }
+
+    /// Returns the format of the surfaces of this [`Compositor`].
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.format
+    }
+
+    /// Encodes the copy of the texture of a presented frame to a readable
+    /// buffer.
+    fn encode_capture(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+        size: Size<u32>,
+    ) -> Capture {
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        };
+
+        // Rows of a texture copy must be aligned
+        let padded_bytes_per_row = {
+            let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+            let bytes_per_row = size.width * 4;
+
+            (bytes_per_row + alignment - 1) / alignment * alignment
+        };
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu::window::Compositor capture buffer"),
+            size: u64::from(padded_bytes_per_row * size.height),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: std::num::NonZeroU32::new(
+                        padded_bytes_per_row,
+                    ),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        Capture {
+            buffer,
+            size,
+            padded_bytes_per_row,
+            is_bgra: matches!(
+                self.format,
+                wgpu::TextureFormat::Bgra8Unorm
+                    | wgpu::TextureFormat::Bgra8UnormSrgb
+            ),
+        }
+    }
+
+    /// Maps a submitted [`Capture`] in the background, and sends its RGBA
+    /// pixels through the given senders once it is read.
+    ///
+    /// The senders are dropped without a frame if the buffer cannot be mapped.
+    fn read_capture(
+        &mut self,
+        senders: Vec<oneshot::Sender<Vec<u8>>>,
+        capture: Capture,
+    ) {
+        let mapping = capture.buffer.slice(..).map_async(wgpu::MapMode::Read);
+        let pending_captures = self.pending_captures.clone();
+
+        pending_captures.set(pending_captures.get() + 1);
+
+        self.local_pool
+            .spawner()
+            .spawn_local(async move {
+                if mapping.await.is_ok() {
+                    let pixels = capture.read();
+
+                    for sender in senders {
+                        let _ = sender.send(pixels.clone());
+                    }
+                }
+
+                pending_captures.set(pending_captures.get() - 1);
+            })
+            .expect("Read frame capture");
+    }
+
     /// Creates a new rendering [`Backend`] for this [`Compositor`].
     pub fn create_backend(&self) -> Backend {
         Backend::new(
@@ -92,6 +222,220 @@ impl Compositor {
             self.format,
         )
     }
+
+    /// Returns whether the given [`Backend`] or the frame captures of this
+    /// [`Compositor`] still have work to finish.
+    pub fn has_pending_work_for(&self, backend: &Backend) -> bool {
+        backend.has_pending_work() || self.pending_captures.get() > 0
+    }
+
+    /// Draws the provided layers with the given [`Backend`] in the surface,
+    /// and presents the frame.
+    ///
+    /// This allows the layers generated for another renderer to be presented
+    /// through `wgpu`.
+    pub fn draw_layers(
+        &mut self,
+        backend: &mut Backend,
+        surface: &mut wgpu::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        layers: &[Layer<'_, Backend>],
+    ) -> Result<(), iced_graphics::window::SurfaceError> {
+        self.present(
+            backend,
+            surface,
+            viewport,
+            background_color,
+            |backend, device, staging_belt, encoder, view| {
+                backend.draw_layers(
+                    device,
+                    staging_belt,
+                    encoder,
+                    view,
+                    viewport,
+                    layers,
+                )
+            },
+        )
+    }
+
+    /// Draws a frame in the given surface with the provided closure and
+    /// presents it.
+    fn present<R>(
+        &mut self,
+        backend: &mut Backend,
+        surface: &mut wgpu::Surface,
+        viewport: &Viewport,
+        background_color: Color,
+        draw: impl FnOnce(
+            &mut Backend,
+            &wgpu::Device,
+            &mut wgpu::util::StagingBelt,
+            &mut wgpu::CommandEncoder,
+            &wgpu::TextureView,
+        ) -> R,
+    ) -> Result<R, iced_graphics::window::SurfaceError> {
+        let frame = {
+            let _span = trace::acquire();
+
+            surface.get_current_frame()
+        };
+
+        match frame {
+            Ok(frame) => {
+                let mut encoder = self.device.create_command_encoder(
+                    &wgpu::CommandEncoderDescriptor {
+                        label: Some("iced_wgpu encoder"),
+                    },
+                );
+
+                let view = Arc::new(
+                    frame
+                        .output
+                        .texture
+                        .create_view(&wgpu::TextureViewDescriptor::default()),
+                );
+
+                backend.set_current_frame(Some(view.clone()));
+
+                clear(&mut encoder, &view, background_color);
+
+                let drawn = draw(
+                    backend,
+                    &self.device,
+                    &mut self.staging_belt,
+                    &mut encoder,
+                    &view,
+                );
+
+                let senders = backend.take_frame_captures();
+
+                let capture = if senders.is_empty() {
+                    None
+                } else if !is_capturable(self.format) {
+                    // Captures are read back as 8-bit RGBA pixels, so the
+                    // senders are dropped without a frame
+                    log::warn!(
+                        "Frames in {:?} cannot be captured",
+                        self.format
+                    );
+
+                    None
+                } else {
+                    let capture = self.encode_capture(
+                        &mut encoder,
+                        &frame.output.texture,
+                        viewport.physical_size(),
+                    );
+
+                    Some((senders, capture))
+                };
+
+                // Submit work
+                {
+                    let _span = trace::submit();
+
+                    self.staging_belt.finish();
+                    self.queue.submit(Some(encoder.finish()));
+                }
+
+                if let Some((senders, capture)) = capture {
+                    self.read_capture(senders, capture);
+                }
+
+                // Recall staging buffers and read the finished captures
+                self.device.poll(wgpu::Maintain::Poll);
+
+                self.local_pool
+                    .spawner()
+                    .spawn(self.staging_belt.recall())
+                    .expect("Recall staging belt");
+
+                self.local_pool.run_until_stalled();
+
+                // Grow the chunks of the staging belt to the largest frame
+                // seen so far, so it stops allocating new buffers every frame
+                let staged = backend.take_staged_bytes();
+
+                if staged > self.chunk_size {
+                    self.chunk_size =
+                        staged.next_power_of_two().min(Self::MAX_CHUNK_SIZE);
+
+                    self.staging_belt =
+                        wgpu::util::StagingBelt::new(self.chunk_size);
+                }
+
+                // The frame is presented once dropped
+                backend.set_current_frame(None);
+
+                {
+                    let _span = trace::present();
+
+                    drop(frame);
+                }
+
+                Ok(drawn)
+            }
+            Err(error) => match error {
+                wgpu::SurfaceError::Timeout => {
+                    Err(iced_graphics::window::SurfaceError::Timeout)
+                }
+                wgpu::SurfaceError::Outdated => {
+                    Err(iced_graphics::window::SurfaceError::Outdated)
+                }
+                wgpu::SurfaceError::Lost => {
+                    Err(iced_graphics::window::SurfaceError::Lost)
+                }
+                wgpu::SurfaceError::OutOfMemory => {
+                    Err(iced_graphics::window::SurfaceError::OutOfMemory)
+                }
+            },
+        }
+    }
+}
+
+/// The copy of a presented frame, waiting to be read.
+struct Capture {
+    buffer: wgpu::Buffer,
+    size: Size<u32>,
+    padded_bytes_per_row: u32,
+    is_bgra: bool,
+}
+
+impl Capture {
+    /// Reads the RGBA pixels of the mapped buffer of the [`Capture`].
+    fn read(self) -> Vec<u8> {
+        let Capture {
+            buffer,
+            size,
+            padded_bytes_per_row,
+            is_bgra,
+        } = self;
+
+        let data = buffer.slice(..).get_mapped_range();
+        let bytes_per_row = size.width as usize * 4;
+
+        let mut pixels =
+            Vec::with_capacity(bytes_per_row * size.height as usize);
+
+        for row in data.chunks(padded_bytes_per_row as usize) {
+            for pixel in row[..bytes_per_row].chunks_exact(4) {
+                if is_bgra {
+                    pixels.extend_from_slice(&[
+                        pixel[2], pixel[1], pixel[0], pixel[3],
+                    ]);
+                } else {
+                    pixels.extend_from_slice(pixel);
+                }
+            }
+        }
+
+        drop(data);
+        buffer.unmap();
+
+        pixels
+    }
 }
 
 impl iced_graphics::window::Compositor for Compositor {
@@ -133,7 +477,7 @@ impl iced_graphics::window::Compositor for Compositor {
         surface.configure(
             &self.device,
             &wgpu::SurfaceConfiguration {
-                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                usage: target_usage(),
                 format: self.format,
                 present_mode: self.settings.present_mode,
                 width,
@@ -142,6 +486,18 @@ impl iced_graphics::window::Compositor for Compositor {
         );
     }
 
+    fn capture_next_frame(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        sender: oneshot::Sender<Vec<u8>>,
+    ) {
+        renderer.backend_mut().capture_next_frame(sender);
+    }
+
+    fn has_pending_work(&self, renderer: &Self::Renderer) -> bool {
+        self.has_pending_work_for(renderer.backend())
+    }
+
     fn draw<T: AsRef<str>>(
         &mut self,
         renderer: &mut Self::Renderer,
@@ -151,83 +507,118 @@ impl iced_graphics::window::Compositor for Compositor {
         output: &<Self::Renderer as iced_native::Renderer>::Output,
         overlay: &[T],
     ) -> Result<mouse::Interaction, iced_graphics::window::SurfaceError> {
-        match surface.get_current_frame() {
-            Ok(frame) => {
-                let mut encoder = self.device.create_command_encoder(
-                    &wgpu::CommandEncoderDescriptor {
-                        label: Some("iced_wgpu encoder"),
-                    },
-                );
-
-                let view = &frame
-                    .output
-                    .texture
-                    .create_view(&wgpu::TextureViewDescriptor::default());
-
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[wgpu::RenderPassColorAttachment {
-                            view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear({
-                                    let [r, g, b, a] =
-                                        background_color.into_linear();
-
-                                    wgpu::Color {
-                                        r: f64::from(r),
-                                        g: f64::from(g),
-                                        b: f64::from(b),
-                                        a: f64::from(a),
-                                    }
-                                }),
-                                store: true,
-                            },
-                        }],
-                        depth_stencil_attachment: None,
-                    });
-
-                let mouse_interaction = renderer.backend_mut().draw(
-                    &mut self.device,
-                    &mut self.staging_belt,
-                    &mut encoder,
+        self.present(
+            renderer.backend_mut(),
+            surface,
+            viewport,
+            background_color,
+            |backend, device, staging_belt, encoder, view| {
+                backend.draw(
+                    device,
+                    staging_belt,
+                    encoder,
                     view,
                     viewport,
                     output,
                     overlay,
-                );
+                )
+            },
+        )
+    }
+}
 
-                // Submit work
-                self.staging_belt.finish();
-                self.queue.submit(Some(encoder.finish()));
+/// Returns whether the surfaces of the given adapter can present with the
+/// given mode.
+///
+/// `wgpu` cannot list the present modes of a surface yet, so they are taken
+/// from the backend of the adapter: the OpenGL and WebGPU surfaces of `wgpu`
+/// only present in `Fifo`, and Metal and DirectX 11 have no `Mailbox`.
+fn can_present_with(adapter: &wgpu::Adapter, mode: wgpu::PresentMode) -> bool {
+    use wgpu::PresentMode::{Fifo, Immediate, Mailbox};
 
-                // Recall staging buffers
-                self.local_pool
-                    .spawner()
-                    .spawn(self.staging_belt.recall())
-                    .expect("Recall staging belt");
+    match (adapter.get_info().backend, mode) {
+        (_, Fifo) => true,
+        (wgpu::Backend::Vulkan, Immediate | Mailbox)
+        | (wgpu::Backend::Dx12, Immediate | Mailbox)
+        | (wgpu::Backend::Metal, Immediate)
+        | (wgpu::Backend::Dx11, Immediate) => true,
+        _ => false,
+    }
+}
 
-                self.local_pool.run_until_stalled();
+/// Returns whether the surfaces of the given adapter can present the given
+/// format, knowing the format they prefer.
+///
+/// `wgpu` cannot list the formats of a surface yet. Besides the preferred one,
+/// only the half-precision float format is known to be presented by the
+/// swapchains of Metal and DirectX 12.
+fn can_present(
+    adapter: &wgpu::Adapter,
+    preferred_format: wgpu::TextureFormat,
+    format: wgpu::TextureFormat,
+) -> bool {
+    if format == preferred_format {
+        return true;
+    }
 
-                Ok(mouse_interaction)
-            }
-            Err(error) => match error {
-                wgpu::SurfaceError::Timeout => {
-                    Err(iced_graphics::window::SurfaceError::Timeout)
-                }
-                wgpu::SurfaceError::Outdated => {
-                    Err(iced_graphics::window::SurfaceError::Outdated)
-                }
-                wgpu::SurfaceError::Lost => {
-                    Err(iced_graphics::window::SurfaceError::Lost)
-                }
-                wgpu::SurfaceError::OutOfMemory => {
-                    Err(iced_graphics::window::SurfaceError::OutOfMemory)
-                }
-            },
+    match adapter.get_info().backend {
+        wgpu::Backend::Metal | wgpu::Backend::Dx12 => {
+            format == wgpu::TextureFormat::Rgba16Float
         }
+        _ => false,
+    }
+}
+
+/// Returns the usage of the textures rendered by the [`Compositor`].
+fn target_usage() -> wgpu::TextureUsages {
+    // Captures copy the presented frames
+    let usage =
+        wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC;
+
+    // Backdrops read back the target to blur it
+    if cfg!(feature = "backdrop-filter") {
+        usage | wgpu::TextureUsages::TEXTURE_BINDING
+    } else {
+        usage
     }
 }
+
+/// Returns whether frames of the given format can be captured.
+fn is_capturable(format: wgpu::TextureFormat) -> bool {
+    matches!(
+        format,
+        wgpu::TextureFormat::Rgba8Unorm
+            | wgpu::TextureFormat::Rgba8UnormSrgb
+            | wgpu::TextureFormat::Bgra8Unorm
+            | wgpu::TextureFormat::Bgra8UnormSrgb
+    )
+}
+
+/// Clears the given `TextureView` with the background [`Color`].
+fn clear(
+    encoder: &mut wgpu::CommandEncoder,
+    view: &wgpu::TextureView,
+    background_color: Color,
+) {
+    let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("iced_wgpu::window::Compositor render pass"),
+        color_attachments: &[wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear({
+                    let [r, g, b, a] = background_color.into_linear();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                store: true,
+            },
+        }],
+        depth_stencil_attachment: None,
+    });
+}
@@ -1,23 +1,32 @@
 //! Create interactive, native cross-platform applications.
+mod input;
 mod state;
 
 pub use state::State;
 
+pub(crate) use input::Input;
+
 use crate::clipboard::{self, Clipboard};
 use crate::conversion;
+use crate::keyboard;
+use crate::layout;
 use crate::mouse;
+use crate::trace;
 use crate::{
     Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime, Settings,
-    Size, Subscription,
+    Size, Subscription, Viewport,
 };
 
 use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::window;
 use iced_native::program::Program;
-use iced_native::{Cache, UserInterface};
+use iced_native::{Cache, Renderer as _, UserInterface};
 
+use std::cell::Cell;
 use std::mem::ManuallyDrop;
+use std::rc::Rc;
+use std::time::Instant;
 
 /// An interactive, native cross-platform application.
 ///
@@ -99,6 +108,25 @@ pub trait Application: Program {
     fn should_exit(&self) -> bool {
         false
     }
+
+    /// Returns the keyboard accelerators of the [`Application`], as
+    /// [`Shortcuts`] binding each [`Hotkey`] to the message it produces.
+    ///
+    /// The [`Shortcuts`] are installed in the [`UserInterface`] of the
+    /// [`Application`], which checks them before the widgets see a key press.
+    /// A key press matching an accelerator produces its message and is
+    /// captured without being delivered to the widgets. Therefore, `Ctrl+S`
+    /// can save even while a text input is focused. Leave an accelerator out
+    /// (for instance, while a text input is focused) to let the widgets claim
+    /// its key press instead.
+    ///
+    /// By default, it returns no accelerators.
+    ///
+    /// [`Shortcuts`]: keyboard::Shortcuts
+    /// [`Hotkey`]: keyboard::Hotkey
+    fn keyboard_accelerators(&self) -> keyboard::Shortcuts<Self::Message> {
+        keyboard::Shortcuts::new()
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -150,11 +178,15 @@ where
 
     let mut clipboard = Clipboard::connect(&window);
 
+    let mut state = State::new(&application, &window);
+    state.set_scale_factor_override(&window, settings.scale_factor_override);
+
     run_command(
         init_command,
         &mut runtime,
         &mut clipboard,
         &mut proxy,
+        &mut state,
         &window,
     );
     runtime.track(subscription);
@@ -162,6 +194,7 @@ where
     let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let wake_up_at = Rc::new(Cell::new(None));
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -172,8 +205,15 @@ where
         proxy,
         debug,
         receiver,
+        state,
         window,
         settings.exit_on_close_request,
+        window::FramePacer::new(settings.max_fps),
+        Input::new(
+            settings.long_press_duration,
+            settings.double_click_interval,
+        ),
+        wake_up_at.clone(),
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
@@ -206,7 +246,10 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => match wake_up_at.get() {
+                    Some(instant) => ControlFlow::WaitUntil(instant),
+                    None => ControlFlow::Wait,
+                },
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -222,8 +265,12 @@ async fn run_instance<A, E, C>(
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut state: State<A>,
     window: winit::window::Window,
     exit_on_close_request: bool,
+    mut frame_pacer: window::FramePacer,
+    mut input: Input,
+    wake_up_at: Rc<Cell<Option<Instant>>>,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
@@ -234,7 +281,6 @@ async fn run_instance<A, E, C>(
 
     let mut surface = compositor.create_surface(&window);
 
-    let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
 
     let physical_size = state.physical_size();
@@ -250,14 +296,14 @@ async fn run_instance<A, E, C>(
         Cache::default(),
         &mut renderer,
         state.logical_size(),
+        state.safe_area(),
         &mut debug,
     ));
 
-    let mut primitive =
-        user_interface.draw(&mut renderer, state.cursor_position());
+    let mut primitive = user_interface.draw(&mut renderer, state.cursor());
+    let mut is_animating = renderer.take_redraw_request();
     let mut mouse_interaction = mouse::Interaction::default();
 
-    let mut events = Vec::new();
     let mut messages = Vec::new();
 
     debug.startup_finished();
@@ -265,15 +311,34 @@ async fn run_instance<A, E, C>(
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
+                let now = Instant::now();
+
+                input.recognize(now);
+
+                if frame_pacer.resume(now) {
+                    window.request_redraw();
+                }
+
+                // Widgets that requested a redraw are animated to the time of
+                // this frame
+                if is_animating {
+                    renderer.advance_clock(now);
+                    input.push(iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    ));
+                }
+
+                if input.is_empty() && messages.is_empty() {
                     continue;
                 }
 
                 debug.event_processing_started();
 
+                let events = input.take();
+
                 let statuses = user_interface.update(
                     &events,
-                    state.cursor_position(),
+                    state.cursor(),
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
@@ -281,8 +346,12 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
-                for event in events.drain(..).zip(statuses.into_iter()) {
-                    runtime.broadcast(event);
+                {
+                    let _span = trace::subscriptions();
+
+                    for event in events.into_iter().zip(statuses) {
+                        runtime.broadcast(event);
+                    }
                 }
 
                 if !messages.is_empty() {
@@ -297,6 +366,7 @@ async fn run_instance<A, E, C>(
                         &mut proxy,
                         &mut debug,
                         &mut messages,
+                        &mut state,
                         &window,
                     );
 
@@ -310,6 +380,7 @@ async fn run_instance<A, E, C>(
                         cache,
                         &mut renderer,
                         state.logical_size(),
+                        state.safe_area(),
                         &mut debug,
                     ));
 
@@ -319,17 +390,27 @@ async fn run_instance<A, E, C>(
                 }
 
                 debug.draw_started();
-                primitive =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                primitive = user_interface.draw(&mut renderer, state.cursor());
+                is_animating = renderer.take_redraw_request();
                 debug.draw_finished();
 
                 window.request_redraw();
             }
+            event::Event::RedrawEventsCleared => {
+                // Wake up for the next long press or deferred frame
+                wake_up_at.set(
+                    input
+                        .deadline()
+                        .into_iter()
+                        .chain(frame_pacer.deadline())
+                        .min(),
+                );
+            }
             event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
                 event::MacOS::ReceivedUrl(url),
             )) => {
                 use iced_native::event;
-                events.push(iced_native::Event::PlatformSpecific(
+                input.push(iced_native::Event::PlatformSpecific(
                     event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(
                         url,
                     )),
@@ -345,6 +426,12 @@ async fn run_instance<A, E, C>(
                     continue;
                 }
 
+                // Frames over the maximum rate are presented once the event
+                // loop wakes up at the deadline of the pacer
+                if !frame_pacer.ready() {
+                    continue;
+                }
+
                 debug.render_started();
                 let current_viewport_version = state.viewport_version();
 
@@ -354,13 +441,18 @@ async fn run_instance<A, E, C>(
                     debug.layout_started();
                     user_interface = ManuallyDrop::new(
                         ManuallyDrop::into_inner(user_interface)
-                            .relayout(logical_size, &mut renderer),
+                            .relayout_in_safe_area(
+                                logical_size,
+                                state.safe_area(),
+                                &mut renderer,
+                            ),
                     );
                     debug.layout_finished();
 
                     debug.draw_started();
-                    primitive = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    primitive =
+                        user_interface.draw(&mut renderer, state.cursor());
+                    is_animating = renderer.take_redraw_request();
                     debug.draw_finished();
 
                     compositor.configure_surface(
@@ -372,43 +464,22 @@ async fn run_instance<A, E, C>(
                     viewport_version = current_viewport_version;
                 }
 
-                match compositor.draw(
+                for sender in state.take_frame_captures() {
+                    compositor.capture_next_frame(&mut renderer, sender);
+                }
+
+                present(
+                    &mut compositor,
                     &mut renderer,
                     &mut surface,
+                    &window,
                     state.viewport(),
                     state.background_color(),
                     &primitive,
-                    &debug.overlay(),
-                ) {
-                    Ok(new_mouse_interaction) => {
-                        debug.render_finished();
-
-                        if new_mouse_interaction != mouse_interaction {
-                            window.set_cursor_icon(
-                                conversion::mouse_interaction(
-                                    new_mouse_interaction,
-                                ),
-                            );
-
-                            mouse_interaction = new_mouse_interaction;
-                        }
-
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
-                    }
-                    Err(error) => match error {
-                        // This is an unrecoverable error.
-                        window::SurfaceError::OutOfMemory => {
-                            panic!("{}", error);
-                        }
-                        _ => {
-                            debug.render_finished();
-
-                            // Try rendering again next frame.
-                            window.request_redraw();
-                        }
-                    },
-                }
+                    &mut debug,
+                    &mut mouse_interaction,
+                    is_animating,
+                );
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -422,13 +493,11 @@ async fn run_instance<A, E, C>(
 
                 state.update(&window, &window_event, &mut debug);
 
-                if let Some(event) = conversion::window_event(
+                input.track(
                     &window_event,
                     state.scale_factor(),
                     state.modifiers(),
-                ) {
-                    events.push(event);
-                }
+                );
             }
             _ => {}
         }
@@ -438,6 +507,71 @@ async fn run_instance<A, E, C>(
     drop(ManuallyDrop::into_inner(user_interface));
 }
 
+/// Draws the output of a user interface to the surface of a window.
+///
+/// The mouse cursor of the window is updated to the [`mouse::Interaction`]
+/// of the frame, and another redraw is requested while the frame is not
+/// final.
+pub(crate) fn present<C: window::Compositor>(
+    compositor: &mut C,
+    renderer: &mut C::Renderer,
+    surface: &mut C::Surface,
+    window: &winit::window::Window,
+    viewport: &Viewport,
+    background_color: Color,
+    output: &<C::Renderer as iced_native::Renderer>::Output,
+    debug: &mut Debug,
+    mouse_interaction: &mut mouse::Interaction,
+    is_animating: bool,
+) {
+    match compositor.draw(
+        renderer,
+        surface,
+        viewport,
+        background_color,
+        output,
+        &debug.overlay(),
+    ) {
+        Ok(new_mouse_interaction) => {
+            debug.render_finished();
+
+            if new_mouse_interaction != *mouse_interaction {
+                window.set_cursor_visible(conversion::cursor_visible(
+                    &new_mouse_interaction,
+                ));
+                window.set_cursor_icon(conversion::mouse_interaction(
+                    new_mouse_interaction.clone(),
+                ));
+
+                *mouse_interaction = new_mouse_interaction;
+            }
+
+            // Draw again until the resources prepared in the background, like
+            // rasterized SVGs, are displayed
+            if compositor.has_pending_work(renderer) {
+                window.request_redraw();
+            }
+
+            // Keep drawing frames while widgets are animating
+            if is_animating {
+                window.request_redraw();
+            }
+        }
+        Err(error) => match error {
+            // This is an unrecoverable error.
+            window::SurfaceError::OutOfMemory => {
+                panic!("{}", error);
+            }
+            _ => {
+                debug.render_finished();
+
+                // Try rendering again next frame.
+                window.request_redraw();
+            }
+        },
+    }
+}
+
 /// Returns true if the provided event should cause an [`Application`] to
 /// exit.
 pub fn requests_exit(
@@ -469,14 +603,20 @@ pub fn build_user_interface<'a, A: Application>(
     cache: Cache,
     renderer: &mut A::Renderer,
     size: Size,
+    safe_area: layout::EdgeInsets,
     debug: &mut Debug,
 ) -> UserInterface<'a, A::Message, A::Renderer> {
+    let shortcuts = application.keyboard_accelerators();
+
     debug.view_started();
     let view = application.view();
     debug.view_finished();
 
     debug.layout_started();
-    let user_interface = UserInterface::build(view, size, cache, renderer);
+    let user_interface = UserInterface::build_in_safe_area(
+        view, size, safe_area, cache, renderer,
+    )
+    .with_shortcuts(shortcuts);
     debug.layout_finished();
 
     user_interface
@@ -491,31 +631,36 @@ pub fn update<A: Application, E: Executor>(
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
+    state: &mut State<A>,
     window: &winit::window::Window,
 ) {
     for message in messages.drain(..) {
         debug.log_message(&message);
 
-        debug.update_started();
+        debug.update_started(&message);
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        run_command(command, runtime, clipboard, proxy, window);
+        run_command(command, runtime, clipboard, proxy, state, window);
     }
 
+    let _span = trace::subscriptions();
+
     let subscription = application.subscription();
     runtime.track(subscription);
 }
 
 /// Runs the actions of a [`Command`].
-pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
-    command: Command<Message>,
-    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+pub fn run_command<A: Application, E: Executor>(
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
-    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    state: &mut State<A>,
     window: &winit::window::Window,
 ) {
     use iced_native::command;
+    use iced_native::event;
     use iced_native::window;
 
     for action in command.actions() {
@@ -523,6 +668,9 @@ pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
             command::Action::Clipboard(action) => match action {
                 clipboard::Action::Read(tag) => {
                     let message = tag(clipboard.read());
@@ -535,7 +683,31 @@ pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
                     clipboard.write(contents);
                 }
             },
-            command::Action::Window(action) => match action {
+            command::Action::Proxy(produce) => {
+                let sender = proxy.clone();
+                let message = produce(event::Proxy::new(move |message| {
+                    sender.send_event(message).is_ok()
+                }));
+
+                proxy
+                    .send_event(message)
+                    .expect("Send message to event loop");
+            }
+            command::Action::Window(id, action) => match action {
+                window::Action::Spawn(_) | window::Action::Close => {
+                    log::warn!(
+                        "Spawning and closing windows is only supported by \
+                        multi-window applications"
+                    );
+                }
+                _ if id != window::Id::MAIN => {
+                    log::warn!(
+                        "{:?} cannot be performed on {:?}, which is not the \
+                        window of the application",
+                        action,
+                        id
+                    );
+                }
                 window::Action::Resize { width, height } => {
                     window.set_inner_size(winit::dpi::LogicalSize {
                         width,
@@ -548,6 +720,34 @@ pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
                         y,
                     });
                 }
+                window::Action::SetScaleFactorOverride(scale_factor) => {
+                    state.set_scale_factor_override(window, scale_factor);
+                }
+                window::Action::FetchScaleFactorOverride(tag) => {
+                    let message = tag(state.scale_factor_override());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                window::Action::CaptureFrame(tag) => {
+                    use iced_futures::futures::channel::oneshot;
+                    use iced_futures::futures::{future, FutureExt, StreamExt};
+
+                    let (sender, receiver) = oneshot::channel();
+
+                    state.capture_next_frame(sender);
+                    window.request_redraw();
+
+                    // The sender is dropped without a frame if the compositor
+                    // does not support capturing frames
+                    runtime.run(Box::pin(
+                        receiver
+                            .map(move |frame| frame.ok().map(tag))
+                            .into_stream()
+                            .filter_map(future::ready),
+                    ));
+                }
             },
         }
     }
@@ -0,0 +1,107 @@
+use crate::conversion;
+use crate::gesture;
+use crate::keyboard;
+use crate::Event;
+
+use std::time::{Duration, Instant};
+
+/// The input of a window, turned into the events of its user interface.
+///
+/// It recognizes the gestures made with the mouse and the touches of the
+/// window, and completes its keyboard events.
+#[derive(Debug, Clone)]
+pub(crate) struct Input {
+    events: Vec<Event>,
+    long_press: gesture::LongPress,
+    double_click: gesture::DoubleClick,
+    pinch: gesture::Pinch,
+    keyboard: keyboard::Tracker,
+}
+
+impl Input {
+    /// Creates a new [`Input`] recognizing gestures with the given timings.
+    pub fn new(
+        long_press_duration: Duration,
+        double_click_interval: Duration,
+    ) -> Self {
+        Self {
+            events: Vec::new(),
+            long_press: gesture::LongPress::new(long_press_duration),
+            double_click: gesture::DoubleClick::new(double_click_interval),
+            pinch: gesture::Pinch::new(),
+            keyboard: keyboard::Tracker::new(),
+        }
+    }
+
+    /// Tracks an event of the window, converted with the given scale factor
+    /// and modifiers.
+    pub fn track(
+        &mut self,
+        window_event: &winit::event::WindowEvent<'_>,
+        scale_factor: f64,
+        modifiers: winit::event::ModifiersState,
+    ) {
+        if let Some(event) =
+            conversion::window_event(window_event, scale_factor, modifiers)
+        {
+            let horizontal_scroll = conversion::horizontal_scroll(&event);
+
+            self.push(event);
+
+            if let Some(event) = horizontal_scroll {
+                self.push(event);
+            }
+        }
+    }
+
+    /// Pushes an event of the window, together with the gestures it
+    /// completes.
+    pub fn push(&mut self, event: Event) {
+        let event = match event {
+            Event::Mouse(mouse_event) => Event::Mouse(
+                self.double_click.track(mouse_event, Instant::now()),
+            ),
+            event => event,
+        };
+
+        let gestures = match &event {
+            Event::Touch(touch_event) => {
+                self.long_press.track(touch_event, Instant::now());
+
+                self.pinch.track(touch_event)
+            }
+            _ => Vec::new(),
+        };
+
+        self.events.push(event);
+        self.events.extend(gestures.into_iter().map(Event::Touch));
+    }
+
+    /// Recognizes the long presses that are due at the given time.
+    pub fn recognize(&mut self, now: Instant) {
+        self.events.extend(
+            self.long_press.recognize(now).into_iter().map(Event::Touch),
+        );
+    }
+
+    /// Returns the time at which the next long press is due, if any.
+    ///
+    /// Fingers staying still do not produce any events, so the event loop
+    /// needs to wake up at this time to recognize it.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.long_press.deadline()
+    }
+
+    /// Returns whether no events have been pushed since the last
+    /// [`Input::take`].
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Takes the events pushed so far, with their keyboard events completed.
+    pub fn take(&mut self) -> Vec<Event> {
+        self.keyboard.complete(&mut self.events);
+
+        std::mem::take(&mut self.events)
+    }
+}
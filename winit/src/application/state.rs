@@ -1,21 +1,30 @@
 use crate::conversion;
-use crate::{Application, Color, Debug, Mode, Point, Size, Viewport};
+use crate::layout;
+use crate::mouse;
+use crate::{Application, Color, Debug, Mode, Size, Viewport};
 
+use iced_futures::futures::channel::oneshot;
 use std::marker::PhantomData;
 use winit::event::{Touch, WindowEvent};
 use winit::window::Window;
 
+const MIN_SCALE_FACTOR_OVERRIDE: f32 = 0.5;
+const MAX_SCALE_FACTOR_OVERRIDE: f32 = 3.0;
+
 /// The state of a windowed [`Application`].
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct State<A: Application> {
     title: String,
     mode: Mode,
     background_color: Color,
     scale_factor: f64,
+    scale_factor_override: f64,
     viewport: Viewport,
     viewport_version: usize,
-    cursor_position: winit::dpi::PhysicalPosition<f64>,
+    safe_area: layout::EdgeInsets,
+    cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
     modifiers: winit::event::ModifiersState,
+    frame_captures: Vec<oneshot::Sender<Vec<u8>>>,
     application: PhantomData<A>,
 }
 
@@ -28,7 +37,8 @@ impl<A: Application> State<A> {
         let scale_factor = application.scale_factor();
 
         let viewport = {
-            let physical_size = window.inner_size();
+            let physical_size =
+                conversion::surface_size(window, window.inner_size());
 
             Viewport::with_physical_size(
                 Size::new(physical_size.width, physical_size.height),
@@ -36,16 +46,20 @@ impl<A: Application> State<A> {
             )
         };
 
+        let safe_area = conversion::safe_area(window, viewport.scale_factor());
+
         Self {
             title,
             mode,
             background_color,
             scale_factor,
+            scale_factor_override: 1.0,
             viewport,
             viewport_version: 0,
-            // TODO: Encode cursor availability in the type-system
-            cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
+            safe_area,
+            cursor_position: None,
             modifiers: winit::event::ModifiersState::default(),
+            frame_captures: Vec::new(),
             application: PhantomData,
         }
     }
@@ -67,6 +81,14 @@ impl<A: Application> State<A> {
         self.viewport_version
     }
 
+    /// Returns the safe area of the window of the [`State`], as
+    /// [`EdgeInsets`] of its logical size.
+    ///
+    /// [`EdgeInsets`]: layout::EdgeInsets
+    pub fn safe_area(&self) -> layout::EdgeInsets {
+        self.safe_area
+    }
+
     /// Returns the physical [`Size`] of the [`Viewport`] of the [`State`].
     pub fn physical_size(&self) -> Size<u32> {
         self.viewport.physical_size()
@@ -82,12 +104,70 @@ impl<A: Application> State<A> {
         self.viewport.scale_factor()
     }
 
-    /// Returns the current cursor position of the [`State`].
-    pub fn cursor_position(&self) -> Point {
-        conversion::cursor_position(
-            self.cursor_position,
-            self.viewport.scale_factor(),
-        )
+    /// Returns the current scale factor override of the [`State`].
+    ///
+    /// The override is multiplied with the scale factor of the window and the
+    /// scale factor of the [`Application`] to obtain the scale factor of the
+    /// [`Viewport`].
+    pub fn scale_factor_override(&self) -> f32 {
+        self.scale_factor_override as f32
+    }
+
+    /// Sets the scale factor override of the [`State`], clamped to the
+    /// `[0.5, 3.0]` range, and rebuilds its [`Viewport`] accordingly.
+    pub fn set_scale_factor_override(
+        &mut self,
+        window: &Window,
+        scale_factor_override: f32,
+    ) {
+        let scale_factor_override = f64::from(
+            scale_factor_override
+                .max(MIN_SCALE_FACTOR_OVERRIDE)
+                .min(MAX_SCALE_FACTOR_OVERRIDE),
+        );
+
+        if self.scale_factor_override != scale_factor_override {
+            let size = conversion::surface_size(window, window.inner_size());
+
+            self.viewport = Viewport::with_physical_size(
+                Size::new(size.width, size.height),
+                window.scale_factor()
+                    * self.scale_factor
+                    * scale_factor_override,
+            );
+
+            self.safe_area =
+                conversion::safe_area(window, self.viewport.scale_factor());
+
+            self.scale_factor_override = scale_factor_override;
+            self.viewport_version = self.viewport_version.wrapping_add(1);
+        }
+    }
+
+    /// Requests the pixels of the next frame drawn for the [`State`] to be
+    /// sent through the given `sender`.
+    pub fn capture_next_frame(&mut self, sender: oneshot::Sender<Vec<u8>>) {
+        self.frame_captures.push(sender);
+    }
+
+    /// Takes the pending frame captures of the [`State`].
+    ///
+    /// They should be handed to the compositor right before drawing the next
+    /// frame.
+    pub fn take_frame_captures(&mut self) -> Vec<oneshot::Sender<Vec<u8>>> {
+        std::mem::take(&mut self.frame_captures)
+    }
+
+    /// Returns the current [`mouse::Cursor`] of the [`State`].
+    pub fn cursor(&self) -> mouse::Cursor {
+        self.cursor_position
+            .map(|cursor_position| {
+                mouse::Cursor::Available(conversion::cursor_position(
+                    cursor_position,
+                    self.viewport.scale_factor(),
+                ))
+            })
+            .unwrap_or(mouse::Cursor::Unavailable)
     }
 
     /// Returns the current keyboard modifiers of the [`State`].
@@ -105,39 +185,47 @@ impl<A: Application> State<A> {
     ) {
         match event {
             WindowEvent::Resized(new_size) => {
+                let new_size = conversion::surface_size(window, *new_size);
                 let size = Size::new(new_size.width, new_size.height);
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    window.scale_factor() * self.scale_factor,
+                    window.scale_factor()
+                        * self.scale_factor
+                        * self.scale_factor_override,
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
+                self.safe_area =
+                    conversion::safe_area(window, self.viewport.scale_factor());
             }
             WindowEvent::ScaleFactorChanged {
                 scale_factor: new_scale_factor,
                 new_inner_size,
             } => {
-                let size =
-                    Size::new(new_inner_size.width, new_inner_size.height);
+                let new_size =
+                    conversion::surface_size(window, **new_inner_size);
+                let size = Size::new(new_size.width, new_size.height);
 
                 self.viewport = Viewport::with_physical_size(
                     size,
-                    new_scale_factor * self.scale_factor,
+                    new_scale_factor
+                        * self.scale_factor
+                        * self.scale_factor_override,
                 );
 
                 self.viewport_version = self.viewport_version.wrapping_add(1);
+                self.safe_area =
+                    conversion::safe_area(window, self.viewport.scale_factor());
             }
             WindowEvent::CursorMoved { position, .. }
             | WindowEvent::Touch(Touch {
                 location: position, ..
             }) => {
-                self.cursor_position = *position;
+                self.cursor_position = Some(*position);
             }
             WindowEvent::CursorLeft { .. } => {
-                // TODO: Encode cursor availability in the type-system
-                self.cursor_position =
-                    winit::dpi::PhysicalPosition::new(-1.0, -1.0);
+                self.cursor_position = None;
             }
             WindowEvent::ModifiersChanged(new_modifiers) => {
                 self.modifiers = *new_modifiers;
@@ -194,13 +282,17 @@ impl<A: Application> State<A> {
         let new_scale_factor = application.scale_factor();
 
         if self.scale_factor != new_scale_factor {
-            let size = window.inner_size();
+            let size = conversion::surface_size(window, window.inner_size());
 
             self.viewport = Viewport::with_physical_size(
                 Size::new(size.width, size.height),
-                window.scale_factor() * new_scale_factor,
+                window.scale_factor()
+                    * new_scale_factor
+                    * self.scale_factor_override,
             );
 
+            self.safe_area =
+                conversion::safe_area(window, self.viewport.scale_factor());
             self.scale_factor = new_scale_factor;
         }
     }
@@ -3,11 +3,17 @@ pub use iced_native::clipboard::Action;
 
 use crate::command::{self, Command};
 
+use iced_native::image;
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
+    images: Option<RefCell<arboard::Clipboard>>,
 }
 
 enum State {
@@ -23,7 +29,17 @@ impl Clipboard {
             .map(State::Connected)
             .unwrap_or(State::Unavailable);
 
-        Clipboard { state }
+        // Images are not supported by `window_clipboard` yet
+        let images = match arboard::Clipboard::new() {
+            Ok(clipboard) => Some(RefCell::new(clipboard)),
+            Err(error) => {
+                log::warn!("images cannot be copied or pasted: {}", error);
+
+                None
+            }
+        };
+
+        Clipboard { state, images }
     }
 
     /// Reads the current content of the [`Clipboard`] as text.
@@ -46,6 +62,89 @@ impl Clipboard {
             State::Unavailable => {}
         }
     }
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    pub fn read_image(&self) -> Option<image::Handle> {
+        let image = self.images.as_ref()?.borrow_mut().get_image().ok()?;
+        let mut pixels = image.bytes.into_owned();
+
+        // The clipboard stores RGBA pixels, while handles expect BGRA
+        swap_red_and_blue(&mut pixels);
+
+        Some(image::Handle::from_pixels(
+            image.width as u32,
+            image.height as u32,
+            pixels,
+        ))
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// Only images containing decoded pixels can be written.
+    pub fn write_image(&mut self, handle: &image::Handle) {
+        let images = match &mut self.images {
+            Some(images) => images.get_mut(),
+            None => return,
+        };
+
+        let data = handle.data();
+
+        let (width, height, mut pixels) = match data {
+            image::Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => (*width, *height, pixels.clone()),
+            image::Data::Indexed { width, height, .. } => {
+                match data.expand_palette() {
+                    Some(pixels) => (*width, *height, pixels),
+                    None => {
+                        log::warn!("invalid indexed image: {:?}", data);
+
+                        return;
+                    }
+                }
+            }
+            image::Data::Path(_) | image::Data::Bytes(_) => {
+                log::warn!(
+                    "only decoded pixels can be written to the clipboard"
+                );
+
+                return;
+            }
+        };
+
+        swap_red_and_blue(&mut pixels);
+
+        let image = arboard::ImageData {
+            width: width as usize,
+            height: height as usize,
+            bytes: pixels.into(),
+        };
+
+        if let Err(error) = images.set_image(image) {
+            log::warn!("error writing image to clipboard: {}", error)
+        }
+    }
+
+    /// Reads the paths of the files in the [`Clipboard`], like the ones put
+    /// there by copying files in a file manager.
+    ///
+    /// On platforms without a native list of files in their clipboard, the
+    /// `file://` URIs in its text content are read instead.
+    pub fn read_files(&self) -> Vec<PathBuf> {
+        #[cfg(any(target_os = "windows", target_os = "macos"))]
+        {
+            clipboard_files::read().unwrap_or_default()
+        }
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        {
+            self.read()
+                .map(|text| file_paths(&text))
+                .unwrap_or_default()
+        }
+    }
 }
 
 impl iced_native::Clipboard for Clipboard {
@@ -56,6 +155,57 @@ impl iced_native::Clipboard for Clipboard {
     fn write(&mut self, contents: String) {
         self.write(contents)
     }
+
+    fn read_image(&self) -> Option<image::Handle> {
+        self.read_image()
+    }
+
+    fn write_image(&mut self, handle: &image::Handle) {
+        self.write_image(handle)
+    }
+
+    fn read_files(&self) -> Vec<PathBuf> {
+        self.read_files()
+    }
+}
+
+fn swap_red_and_blue(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel.swap(0, 2);
+    }
+}
+
+/// Parses the paths of the `file://` URIs in a text, one per line, like the
+/// `text/uri-list` format of file managers.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn file_paths(text: &str) -> Vec<PathBuf> {
+    text.lines()
+        .filter_map(|line| line.trim().strip_prefix("file://"))
+        // Skip the host, which is usually empty
+        .filter_map(|uri| uri.find('/').map(|start| &uri[start..]))
+        .filter_map(percent_decode)
+        .map(PathBuf::from)
+        .collect()
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn percent_decode(uri: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(uri.len());
+    let mut rest = uri.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = std::str::from_utf8(tail.get(..2)?).ok()?;
+
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8(bytes).ok()
 }
 
 /// Read the current contents of the clipboard.
@@ -3,6 +3,7 @@
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 use crate::keyboard;
+use crate::layout;
 use crate::mouse;
 use crate::touch;
 use crate::window;
@@ -86,24 +87,33 @@ pub fn window_event(
             input:
                 winit::event::KeyboardInput {
                     virtual_keycode: Some(virtual_keycode),
+                    scancode,
                     state,
                     ..
                 },
             ..
         } => Some(Event::Keyboard({
             let key_code = key_code(*virtual_keycode);
+            let physical_key = keyboard::ScanCode(*scancode);
+            let location = keyboard::KeyLocation::from(key_code);
             let modifiers = self::modifiers(modifiers);
 
             match state {
+                // Repeated presses are marked by a `keyboard::Tracker`
                 winit::event::ElementState::Pressed => {
                     keyboard::Event::KeyPressed {
                         key_code,
+                        physical_key,
+                        location,
                         modifiers,
+                        repeat: false,
                     }
                 }
                 winit::event::ElementState::Released => {
                     keyboard::Event::KeyReleased {
                         key_code,
+                        physical_key,
+                        location,
                         modifiers,
                     }
                 }
@@ -139,6 +149,28 @@ pub fn window_event(
     }
 }
 
+/// Returns the [`mouse::Event::HorizontalScrolled`] produced in addition to
+/// a converted [`mouse::Event::WheelScrolled`] with horizontal movement.
+pub fn horizontal_scroll(event: &Event) -> Option<Event> {
+    match event {
+        Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+            let delta_x = match *delta {
+                mouse::ScrollDelta::Lines { x, .. } => {
+                    x * mouse::ScrollDelta::PIXELS_PER_LINE
+                }
+                mouse::ScrollDelta::Pixels { x, .. } => x,
+            };
+
+            if delta_x == 0.0 {
+                None
+            } else {
+                Some(Event::Mouse(mouse::Event::HorizontalScrolled(delta_x)))
+            }
+        }
+        _ => None,
+    }
+}
+
 /// Converts a [`Position`] to a [`winit`] logical position for a given monitor.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -197,6 +229,68 @@ pub fn fullscreen(
     }
 }
 
+/// Returns the physical size of the surface of a [`winit`] window, given the
+/// inner size that [`winit`] reports for it.
+///
+/// On iOS, the inner size of a window is its [`safe_area`], while its surface
+/// covers the whole screen. Everywhere else, both sizes are the same.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn surface_size(
+    window: &winit::window::Window,
+    inner_size: winit::dpi::PhysicalSize<u32>,
+) -> winit::dpi::PhysicalSize<u32> {
+    if cfg!(target_os = "ios") {
+        window.outer_size()
+    } else {
+        inner_size
+    }
+}
+
+/// Returns the safe area of a [`winit`] window, as logical [`EdgeInsets`]
+/// of its [`surface_size`] for the given scale factor.
+///
+/// On iOS, the inner position and size of a window delimit the area that is
+/// not covered by the notch, the rounded corners or the system bars of the
+/// display. Other platforms do not report a safe area to [`winit`], and
+/// their insets are empty.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`EdgeInsets`]: layout::EdgeInsets
+pub fn safe_area(
+    window: &winit::window::Window,
+    scale_factor: f64,
+) -> layout::EdgeInsets {
+    if !cfg!(target_os = "ios") {
+        return layout::EdgeInsets::ZERO;
+    }
+
+    let (outer, inner) =
+        match (window.outer_position(), window.inner_position()) {
+            (Ok(outer), Ok(inner)) => (outer, inner),
+            _ => return layout::EdgeInsets::ZERO,
+        };
+
+    let outer_size = window.outer_size();
+    let inner_size = window.inner_size();
+
+    let top = f64::from(inner.y - outer.y);
+    let left = f64::from(inner.x - outer.x);
+    let bottom =
+        f64::from(outer_size.height) - f64::from(inner_size.height) - top;
+    let right =
+        f64::from(outer_size.width) - f64::from(inner_size.width) - left;
+
+    let logical = |inset: f64| (inset.max(0.0) / scale_factor) as f32;
+
+    layout::EdgeInsets {
+        top: logical(top),
+        bottom: logical(bottom),
+        left: logical(left),
+        right: logical(right),
+    }
+}
+
 /// Converts a [`Mode`] to a visibility flag.
 pub fn visible(mode: Mode) -> bool {
     match mode {
@@ -226,9 +320,23 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::ZoomIn => winit::window::CursorIcon::ZoomIn,
+        Interaction::ZoomOut => winit::window::CursorIcon::ZoomOut,
+        Interaction::Cell => winit::window::CursorIcon::Cell,
+        // Hidden cursors are handled by `cursor_visible`
+        Interaction::Hidden => winit::window::CursorIcon::Default,
+        // This version of `winit` cannot set custom cursor images, so we
+        // fall back to the default cursor
+        Interaction::Custom(_) => winit::window::CursorIcon::Default,
     }
 }
 
+/// Returns whether the cursor should be visible for the given
+/// [`mouse::Interaction`].
+pub fn cursor_visible(interaction: &mouse::Interaction) -> bool {
+    *interaction != mouse::Interaction::Hidden
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced_native`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -0,0 +1,509 @@
+//! Recognize gestures out of touch and mouse events.
+use crate::mouse;
+use crate::touch;
+use crate::Point;
+
+use std::collections::HashMap;
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+
+/// A recognizer of long presses.
+///
+/// A long press is produced once for every finger that stays still on the
+/// screen for longer than the configured duration.
+#[derive(Debug, Clone)]
+pub struct LongPress {
+    duration: Duration,
+    fingers: HashMap<touch::Finger, Press>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Press {
+    origin: Point,
+    position: Point,
+    started_at: Instant,
+    is_recognized: bool,
+}
+
+impl LongPress {
+    /// The default duration of a long press.
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(500);
+
+    /// The maximum distance, in logical pixels, that a finger can move while
+    /// still being considered stationary.
+    pub const TOLERANCE: f32 = 10.0;
+
+    /// Creates a new [`LongPress`] recognizer with the given duration.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            fingers: HashMap::new(),
+        }
+    }
+
+    /// Tracks a touch event that happened at the given [`Instant`].
+    pub fn track(&mut self, event: &touch::Event, now: Instant) {
+        match *event {
+            touch::Event::FingerPressed { id, position } => {
+                let _ = self.fingers.insert(
+                    id,
+                    Press {
+                        origin: position,
+                        position,
+                        started_at: now,
+                        is_recognized: false,
+                    },
+                );
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let is_stationary = match self.fingers.get_mut(&id) {
+                    Some(press) => {
+                        press.position = position;
+
+                        press.origin.distance(position) <= Self::TOLERANCE
+                    }
+                    None => true,
+                };
+
+                if !is_stationary {
+                    let _ = self.fingers.remove(&id);
+                }
+            }
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. } => {
+                let _ = self.fingers.remove(&id);
+            }
+            touch::Event::LongPress { .. }
+            | touch::Event::Pinch { .. }
+            | touch::Event::Rotate { .. } => {}
+        }
+    }
+
+    /// Returns the long presses recognized at the given [`Instant`].
+    pub fn recognize(&mut self, now: Instant) -> Vec<touch::Event> {
+        let duration = self.duration;
+
+        self.fingers
+            .iter_mut()
+            .filter(|(_, press)| {
+                !press.is_recognized
+                    && now.saturating_duration_since(press.started_at)
+                        >= duration
+            })
+            .map(|(id, press)| {
+                press.is_recognized = true;
+
+                touch::Event::LongPress {
+                    id: *id,
+                    position: press.position,
+                    duration: now - press.started_at,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the next [`Instant`] when a long press may be recognized, if
+    /// any.
+    ///
+    /// The event loop needs to wake up at this [`Instant`], since a finger
+    /// staying still does not produce any events.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.fingers
+            .values()
+            .filter(|press| !press.is_recognized)
+            .map(|press| press.started_at + self.duration)
+            .min()
+    }
+}
+
+impl Default for LongPress {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DURATION)
+    }
+}
+
+/// A recognizer of pinches and rotations.
+///
+/// Every time one of exactly two fingers on the screen moves, it produces a
+/// [`touch::Event::Pinch`] and a [`touch::Event::Rotate`] describing the
+/// change since the previous move.
+#[derive(Debug, Clone, Default)]
+pub struct Pinch {
+    fingers: Vec<(touch::Finger, Point)>,
+}
+
+impl Pinch {
+    /// Creates a new [`Pinch`] recognizer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracks a touch event, returning the gestures it produces.
+    pub fn track(&mut self, event: &touch::Event) -> Vec<touch::Event> {
+        match *event {
+            touch::Event::FingerPressed { id, position } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+                self.fingers.push((id, position));
+            }
+            touch::Event::FingerMoved { id, position } => {
+                let previous = self.span();
+
+                match self.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                {
+                    Some((_, finger)) => *finger = position,
+                    None => return Vec::new(),
+                }
+
+                if let (Some(previous), Some(current)) = (previous, self.span())
+                {
+                    return Self::recognize(previous, current);
+                }
+            }
+            touch::Event::FingerLifted { id, .. }
+            | touch::Event::FingerLost { id, .. } => {
+                self.fingers.retain(|(finger, _)| *finger != id);
+            }
+            touch::Event::LongPress { .. }
+            | touch::Event::Pinch { .. }
+            | touch::Event::Rotate { .. } => {}
+        }
+
+        Vec::new()
+    }
+
+    fn span(&self) -> Option<(Point, Point)> {
+        match self.fingers.as_slice() {
+            [(_, a), (_, b)] => Some((*a, *b)),
+            _ => None,
+        }
+    }
+
+    fn recognize(
+        (a, b): (Point, Point),
+        (c, d): (Point, Point),
+    ) -> Vec<touch::Event> {
+        let previous_spread = a.distance(b);
+        let spread = c.distance(d);
+
+        if previous_spread == 0.0 || spread == 0.0 {
+            return Vec::new();
+        }
+
+        let center = Point::new((c.x + d.x) / 2.0, (c.y + d.y) / 2.0);
+
+        let previous_angle = (b.y - a.y).atan2(b.x - a.x);
+        let angle = (d.y - c.y).atan2(d.x - c.x);
+
+        let mut angle_delta = angle - previous_angle;
+
+        if angle_delta > PI {
+            angle_delta -= 2.0 * PI;
+        } else if angle_delta <= -PI {
+            angle_delta += 2.0 * PI;
+        }
+
+        vec![
+            touch::Event::Pinch {
+                center,
+                scale_factor: spread / previous_spread,
+            },
+            touch::Event::Rotate {
+                center,
+                angle_delta,
+            },
+        ]
+    }
+}
+
+/// A recognizer of double clicks.
+///
+/// The second of two presses of the same mouse button, close in time and
+/// space, is replaced by a [`mouse::Event::DoubleClicked`].
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleClick {
+    interval: Duration,
+    cursor: Point,
+    last_press: Option<(mouse::Button, Point, Instant)>,
+}
+
+impl DoubleClick {
+    /// The default maximum time between the two clicks of a double click.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// The maximum distance, in logical pixels, that the cursor can move
+    /// between the two clicks of a double click.
+    pub const TOLERANCE: f32 = 4.0;
+
+    /// Creates a new [`DoubleClick`] recognizer with the given maximum
+    /// interval between clicks.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            cursor: Point::ORIGIN,
+            last_press: None,
+        }
+    }
+
+    /// Tracks a mouse event that happened at the given [`Instant`].
+    ///
+    /// It returns the event that should be produced in its place.
+    pub fn track(&mut self, event: mouse::Event, now: Instant) -> mouse::Event {
+        match event {
+            mouse::Event::CursorMoved { position } => {
+                self.cursor = position;
+            }
+            mouse::Event::ButtonPressed(button) => {
+                let position = self.cursor;
+
+                let is_double = match self.last_press.take() {
+                    Some((last_button, last_position, pressed_at)) => {
+                        last_button == button
+                            && now.saturating_duration_since(pressed_at)
+                                <= self.interval
+                            && last_position.distance(position)
+                                <= Self::TOLERANCE
+                    }
+                    None => false,
+                };
+
+                if is_double {
+                    return mouse::Event::DoubleClicked(button, position);
+                }
+
+                self.last_press = Some((button, position, now));
+            }
+            _ => {}
+        }
+
+        event
+    }
+}
+
+impl Default for DoubleClick {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FINGER: touch::Finger = touch::Finger(0);
+
+    fn press(long_press: &mut LongPress, now: Instant) {
+        long_press.track(
+            &touch::Event::FingerPressed {
+                id: FINGER,
+                position: Point::new(10.0, 10.0),
+            },
+            now,
+        );
+    }
+
+    #[test]
+    fn stationary_fingers_are_recognized_once() {
+        let start = Instant::now();
+        let mut long_press = LongPress::default();
+
+        press(&mut long_press, start);
+
+        assert_eq!(
+            long_press.deadline(),
+            Some(start + LongPress::DEFAULT_DURATION)
+        );
+        assert!(long_press
+            .recognize(start + Duration::from_millis(100))
+            .is_empty());
+
+        long_press.track(
+            &touch::Event::FingerMoved {
+                id: FINGER,
+                position: Point::new(13.0, 14.0),
+            },
+            start + Duration::from_millis(200),
+        );
+
+        let now = start + Duration::from_millis(600);
+
+        assert_eq!(
+            long_press.recognize(now),
+            vec![touch::Event::LongPress {
+                id: FINGER,
+                position: Point::new(13.0, 14.0),
+                duration: Duration::from_millis(600),
+            }]
+        );
+        assert!(long_press.recognize(now).is_empty());
+        assert_eq!(long_press.deadline(), None);
+    }
+
+    #[test]
+    fn moving_or_lifting_a_finger_cancels_the_long_press() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(1);
+        let mut long_press = LongPress::default();
+
+        press(&mut long_press, start);
+        long_press.track(
+            &touch::Event::FingerMoved {
+                id: FINGER,
+                position: Point::new(30.0, 10.0),
+            },
+            start,
+        );
+
+        assert!(long_press.recognize(later).is_empty());
+
+        press(&mut long_press, start);
+        long_press.track(
+            &touch::Event::FingerLifted {
+                id: FINGER,
+                position: Point::new(10.0, 10.0),
+            },
+            start,
+        );
+
+        assert!(long_press.recognize(later).is_empty());
+        assert_eq!(long_press.deadline(), None);
+    }
+
+    #[test]
+    fn two_moving_fingers_are_recognized_as_pinches_and_rotations() {
+        let mut pinch = Pinch::new();
+
+        for (id, position) in
+            [(0, Point::new(0.0, 0.0)), (1, Point::new(10.0, 0.0))]
+        {
+            assert!(pinch
+                .track(&touch::Event::FingerPressed {
+                    id: touch::Finger(id),
+                    position,
+                })
+                .is_empty());
+        }
+
+        // The second finger moves a quarter turn around the first one, twice
+        // as far away
+        let gestures = pinch.track(&touch::Event::FingerMoved {
+            id: touch::Finger(1),
+            position: Point::new(0.0, 20.0),
+        });
+
+        assert_eq!(
+            gestures,
+            vec![
+                touch::Event::Pinch {
+                    center: Point::new(0.0, 10.0),
+                    scale_factor: 2.0,
+                },
+                touch::Event::Rotate {
+                    center: Point::new(0.0, 10.0),
+                    angle_delta: PI / 2.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn single_fingers_are_not_recognized_as_pinches() {
+        let mut pinch = Pinch::new();
+
+        let _ = pinch.track(&touch::Event::FingerPressed {
+            id: touch::Finger(0),
+            position: Point::new(0.0, 0.0),
+        });
+        let _ = pinch.track(&touch::Event::FingerPressed {
+            id: touch::Finger(1),
+            position: Point::new(10.0, 0.0),
+        });
+        let _ = pinch.track(&touch::Event::FingerLifted {
+            id: touch::Finger(1),
+            position: Point::new(10.0, 0.0),
+        });
+
+        assert!(pinch
+            .track(&touch::Event::FingerMoved {
+                id: touch::Finger(0),
+                position: Point::new(5.0, 5.0),
+            })
+            .is_empty());
+    }
+
+    fn click(
+        double_click: &mut DoubleClick,
+        position: Point,
+        now: Instant,
+    ) -> mouse::Event {
+        let _ = double_click.track(mouse::Event::CursorMoved { position }, now);
+
+        double_click
+            .track(mouse::Event::ButtonPressed(mouse::Button::Left), now)
+    }
+
+    #[test]
+    fn quick_second_clicks_are_double_clicks() {
+        let start = Instant::now();
+        let position = Point::new(20.0, 30.0);
+        let mut double_click = DoubleClick::default();
+
+        assert_eq!(
+            click(&mut double_click, position, start),
+            mouse::Event::ButtonPressed(mouse::Button::Left)
+        );
+        assert_eq!(
+            click(
+                &mut double_click,
+                Point::new(21.0, 31.0),
+                start + Duration::from_millis(200)
+            ),
+            mouse::Event::DoubleClicked(
+                mouse::Button::Left,
+                Point::new(21.0, 31.0)
+            )
+        );
+
+        // A third click starts over
+        assert_eq!(
+            click(
+                &mut double_click,
+                position,
+                start + Duration::from_millis(300)
+            ),
+            mouse::Event::ButtonPressed(mouse::Button::Left)
+        );
+    }
+
+    #[test]
+    fn slow_distant_or_different_clicks_are_single_clicks() {
+        let start = Instant::now();
+        let position = Point::new(20.0, 30.0);
+        let mut double_click = DoubleClick::new(Duration::from_millis(300));
+
+        let _ = click(&mut double_click, position, start);
+
+        assert_eq!(
+            click(
+                &mut double_click,
+                position,
+                start + Duration::from_millis(400)
+            ),
+            mouse::Event::ButtonPressed(mouse::Button::Left)
+        );
+        assert_eq!(
+            click(
+                &mut double_click,
+                Point::new(40.0, 30.0),
+                start + Duration::from_millis(500)
+            ),
+            mouse::Event::ButtonPressed(mouse::Button::Left)
+        );
+        assert_eq!(
+            double_click.track(
+                mouse::Event::ButtonPressed(mouse::Button::Right),
+                start + Duration::from_millis(600)
+            ),
+            mouse::Event::ButtonPressed(mouse::Button::Right)
+        );
+    }
+}
@@ -27,6 +27,8 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod gesture;
+pub mod multi_window;
 pub mod settings;
 pub mod window;
 
@@ -39,6 +41,7 @@ pub use application::Application;
 pub use clipboard::Clipboard;
 pub use error::Error;
 pub use mode::Mode;
+pub use multi_window::MultiWindowApplication;
 pub use position::Position;
 pub use proxy::Proxy;
 pub use settings::Settings;
@@ -0,0 +1,950 @@
+//! Create interactive, native cross-platform applications with multiple
+//! windows.
+mod state;
+
+pub use state::State;
+
+use crate::application::{present, requests_exit, Input};
+use crate::clipboard::{self, Clipboard};
+use crate::event::{self, Event};
+use crate::mouse;
+use crate::settings;
+use crate::trace;
+use crate::window;
+use crate::{
+    Color, Command, Debug, Element, Error, Executor, Mode, Proxy, Runtime,
+    Settings, Subscription,
+};
+
+use iced_futures::futures;
+use iced_futures::futures::channel::mpsc;
+use iced_graphics::window::{Compositor, FramePacer};
+use iced_native::{Cache, Renderer as _, UserInterface};
+
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// An interactive, native cross-platform application with multiple windows.
+///
+/// A [`MultiWindowApplication`] starts with a single window, identified by
+/// [`window::Id::MAIN`]. More windows can be opened with [`window::spawn`]
+/// and closed with [`window::close`]. Every window displays its own
+/// [`view`](#tymethod.view), but the state, the messages and the
+/// subscriptions of the application are shared by all of them.
+///
+/// When the user requests a window to close, it is closed if
+/// [`Settings::exit_on_close_request`] is enabled. Once the last window is
+/// closed, the application exits unless
+/// [`exit_on_last_window_closed`](#method.exit_on_last_window_closed) is
+/// overridden.
+pub trait MultiWindowApplication: Sized {
+    /// The graphics backend to use to draw the windows.
+    type Renderer: iced_native::Renderer;
+
+    /// The type of __messages__ your [`MultiWindowApplication`] will produce.
+    type Message: std::fmt::Debug + Send;
+
+    /// The data needed to initialize your [`MultiWindowApplication`].
+    type Flags;
+
+    /// Initializes the [`MultiWindowApplication`] with the flags provided to
+    /// [`run`] as part of the [`Settings`].
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>);
+
+    /// Returns the current title of the window with the given
+    /// [`window::Id`].
+    fn title(&self, window: window::Id) -> String;
+
+    /// Handles a __message__ and updates the state of the
+    /// [`MultiWindowApplication`].
+    ///
+    /// The messages produced in any window are handled here.
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+
+    /// Returns the widgets to display in the window with the given
+    /// [`window::Id`].
+    fn view(
+        &mut self,
+        window: window::Id,
+    ) -> Element<'_, Self::Message, Self::Renderer>;
+
+    /// Returns the event `Subscription` for the current state of the
+    /// application.
+    ///
+    /// Subscriptions receive the events of every window.
+    ///
+    /// By default, it returns an empty subscription.
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::none()
+    }
+
+    /// Produces a message, if any, for an [`Event`] that happened in the
+    /// window with the given [`window::Id`], after its widgets have
+    /// processed it.
+    ///
+    /// By default, it returns `None`.
+    fn event(
+        &self,
+        _window: window::Id,
+        _event: Event,
+        _status: event::Status,
+    ) -> Option<Self::Message> {
+        None
+    }
+
+    /// Returns the background [`Color`] of the window with the given
+    /// [`window::Id`].
+    ///
+    /// By default, it returns [`Color::WHITE`].
+    fn background_color(&self, _window: window::Id) -> Color {
+        Color::WHITE
+    }
+
+    /// Returns the scale factor of the window with the given [`window::Id`].
+    ///
+    /// By default, it returns `1.0`.
+    fn scale_factor(&self, _window: window::Id) -> f64 {
+        1.0
+    }
+
+    /// Returns whether the [`MultiWindowApplication`] should be terminated.
+    ///
+    /// By default, it returns `false`.
+    fn should_exit(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the [`MultiWindowApplication`] should be terminated
+    /// once its last window is closed.
+    ///
+    /// By default, it returns `true`.
+    fn exit_on_last_window_closed(&self) -> bool {
+        true
+    }
+}
+
+/// A window of a running [`MultiWindowApplication`].
+struct Window<A: MultiWindowApplication, C: Compositor> {
+    surface: C::Surface,
+    raw: winit::window::Window,
+    state: State<A>,
+    cache: Cache,
+    output: <A::Renderer as iced_native::Renderer>::Output,
+    mouse_interaction: mouse::Interaction,
+    viewport_version: usize,
+    input: Input,
+    frame_pacer: FramePacer,
+    is_animating: bool,
+}
+
+/// An event of the event loop, or the outcome of a window spawned on behalf
+/// of the [`MultiWindowApplication`].
+enum LoopEvent<Message: 'static> {
+    Winit(winit::event::Event<'static, Message>),
+    WindowCreated(window::Id, winit::window::Window),
+    WindowFailed(window::Id),
+}
+
+/// A request for the event loop, which owns the window target.
+struct Spawn {
+    id: window::Id,
+    title: String,
+    settings: window::Settings,
+}
+
+/// Runs a [`MultiWindowApplication`] with an executor, compositor, and the
+/// provided settings.
+///
+/// The surfaces of all the windows are created by the same compositor.
+pub fn run<A, E, C>(
+    settings: Settings<A::Flags>,
+    compositor_settings: C::Settings,
+) -> Result<(), Error>
+where
+    A: MultiWindowApplication + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use futures::task;
+    use futures::Future;
+    use winit::event_loop::EventLoop;
+
+    let mut debug = Debug::new();
+    debug.startup_started();
+
+    let event_loop = EventLoop::with_user_event();
+    let proxy = event_loop.create_proxy();
+
+    let runtime = {
+        let proxy = Proxy::new(event_loop.create_proxy());
+        let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
+
+        Runtime::new(executor, proxy)
+    };
+
+    let (application, init_command) = {
+        let flags = settings.flags;
+
+        runtime.enter(|| A::new(flags))
+    };
+
+    let main_window = settings
+        .window
+        .into_builder(
+            &application.title(window::Id::MAIN),
+            Mode::Windowed,
+            event_loop.primary_monitor(),
+            settings.id,
+        )
+        .build(&event_loop)
+        .map_err(Error::WindowCreationFailed)?;
+
+    let clipboard = Clipboard::connect(&main_window);
+
+    let (compositor, renderer) =
+        C::new(compositor_settings, Some(&main_window))?;
+
+    let (mut sender, receiver) = mpsc::unbounded();
+    let (spawn_sender, mut spawn_receiver) = mpsc::unbounded();
+    let wake_up_at = Rc::new(Cell::new(None));
+
+    let mut instance = Box::pin(run_instance::<A, E, C>(
+        application,
+        init_command,
+        compositor,
+        renderer,
+        runtime,
+        clipboard,
+        proxy,
+        debug,
+        receiver,
+        spawn_sender,
+        main_window,
+        settings.scale_factor_override,
+        settings.exit_on_close_request,
+        settings.max_fps,
+        settings.long_press_duration,
+        settings.double_click_interval,
+        wake_up_at.clone(),
+    ));
+
+    let mut context = task::Context::from_waker(task::noop_waker_ref());
+
+    event_loop.run(move |event, window_target, control_flow| {
+        use winit::event_loop::ControlFlow;
+
+        if let ControlFlow::Exit = control_flow {
+            return;
+        }
+
+        let event = match event {
+            winit::event::Event::WindowEvent {
+                event:
+                    winit::event::WindowEvent::ScaleFactorChanged {
+                        new_inner_size,
+                        ..
+                    },
+                window_id,
+            } => Some(winit::event::Event::WindowEvent {
+                event: winit::event::WindowEvent::Resized(*new_inner_size),
+                window_id,
+            }),
+            _ => event.to_static(),
+        };
+
+        if let Some(event) = event {
+            sender
+                .start_send(LoopEvent::Winit(event))
+                .expect("Send event");
+
+            let mut poll = instance.as_mut().poll(&mut context);
+
+            // Windows can only be created with the window target, so the
+            // instance asks for them and waits for them to be created here
+            while let task::Poll::Pending = poll {
+                let spawn = match spawn_receiver.try_next() {
+                    Ok(Some(spawn)) => spawn,
+                    _ => break,
+                };
+
+                let window = settings::Window::from(spawn.settings)
+                    .into_builder(
+                        &spawn.title,
+                        Mode::Windowed,
+                        window_target.primary_monitor(),
+                        None,
+                    )
+                    .build(window_target);
+
+                let event = match window {
+                    Ok(window) => LoopEvent::WindowCreated(spawn.id, window),
+                    Err(error) => {
+                        log::error!("Failed to spawn window: {}", error);
+
+                        LoopEvent::WindowFailed(spawn.id)
+                    }
+                };
+
+                sender.start_send(event).expect("Send event");
+
+                poll = instance.as_mut().poll(&mut context);
+            }
+
+            *control_flow = match poll {
+                task::Poll::Pending => match wake_up_at.get() {
+                    Some(instant) => ControlFlow::WaitUntil(instant),
+                    None => ControlFlow::Wait,
+                },
+                task::Poll::Ready(_) => ControlFlow::Exit,
+            };
+        }
+    });
+}
+
+async fn run_instance<A, E, C>(
+    mut application: A,
+    init_command: Command<A::Message>,
+    mut compositor: C,
+    mut renderer: A::Renderer,
+    mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut clipboard: Clipboard,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut debug: Debug,
+    mut receiver: mpsc::UnboundedReceiver<LoopEvent<A::Message>>,
+    spawn_sender: mpsc::UnboundedSender<Spawn>,
+    main_window: winit::window::Window,
+    scale_factor_override: f32,
+    exit_on_close_request: bool,
+    max_fps: Option<u32>,
+    long_press_duration: Duration,
+    double_click_interval: Duration,
+    wake_up_at: Rc<Cell<Option<Instant>>>,
+) where
+    A: MultiWindowApplication + 'static,
+    E: Executor + 'static,
+    C: Compositor<Renderer = A::Renderer> + 'static,
+{
+    use iced_futures::futures::stream::StreamExt;
+    use winit::event;
+
+    let mut windows = BTreeMap::new();
+    let mut spawning = BTreeSet::new();
+    let mut focused = window::Id::MAIN;
+
+    let main_window = open(
+        &mut application,
+        window::Id::MAIN,
+        main_window,
+        &mut compositor,
+        &mut renderer,
+        &mut debug,
+        max_fps,
+        long_press_duration,
+        double_click_interval,
+    );
+
+    let _ = windows.insert(window::Id::MAIN, main_window);
+
+    let mut messages = Vec::new();
+
+    run_command(
+        &application,
+        init_command,
+        &mut runtime,
+        &mut clipboard,
+        &mut proxy,
+        &spawn_sender,
+        &mut windows,
+        &mut spawning,
+    );
+
+    if let Some(main_window) = windows.get_mut(&window::Id::MAIN) {
+        main_window
+            .state
+            .set_scale_factor_override(&main_window.raw, scale_factor_override);
+    }
+
+    runtime.track(application.subscription());
+
+    debug.startup_finished();
+
+    loop {
+        // Closing the last window ends the application, unless another one
+        // is about to be opened
+        if windows.is_empty()
+            && spawning.is_empty()
+            && application.exit_on_last_window_closed()
+        {
+            break;
+        }
+
+        let event = match receiver.next().await {
+            Some(event) => event,
+            None => break,
+        };
+
+        match event {
+            LoopEvent::WindowCreated(id, raw) => {
+                // The window may have been closed while it was spawning
+                if !spawning.remove(&id) {
+                    continue;
+                }
+
+                let window = open(
+                    &mut application,
+                    id,
+                    raw,
+                    &mut compositor,
+                    &mut renderer,
+                    &mut debug,
+                    max_fps,
+                    long_press_duration,
+                    double_click_interval,
+                );
+
+                window.raw.request_redraw();
+
+                let _ = windows.insert(id, window);
+            }
+            LoopEvent::WindowFailed(id) => {
+                let _ = spawning.remove(&id);
+            }
+            LoopEvent::Winit(event::Event::MainEventsCleared) => {
+                let now = Instant::now();
+
+                for window in windows.values_mut() {
+                    window.input.recognize(now);
+
+                    if window.frame_pacer.resume(now) {
+                        window.raw.request_redraw();
+                    }
+
+                    // Widgets that requested a redraw are animated to the
+                    // time of this frame
+                    if window.is_animating {
+                        renderer.advance_clock(now);
+                        window.input.push(Event::Window(
+                            iced_native::window::Event::RedrawRequested(now),
+                        ));
+                    }
+                }
+
+                let has_events =
+                    windows.values().any(|window| !window.input.is_empty());
+
+                if !has_events && messages.is_empty() {
+                    continue;
+                }
+
+                debug.event_processing_started();
+
+                for (id, window) in windows.iter_mut() {
+                    if window.input.is_empty() {
+                        continue;
+                    }
+
+                    let events = window.input.take();
+
+                    let statuses = rebuild(
+                        &mut application,
+                        *id,
+                        window,
+                        &events,
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                        &mut debug,
+                    );
+
+                    for (event, status) in events.into_iter().zip(statuses) {
+                        messages.extend(application.event(
+                            *id,
+                            event.clone(),
+                            status,
+                        ));
+
+                        let _span = trace::subscriptions();
+                        runtime.broadcast((event, status));
+                    }
+
+                    window.raw.request_redraw();
+                }
+
+                debug.event_processing_finished();
+
+                if !messages.is_empty() {
+                    update(
+                        &mut application,
+                        &mut runtime,
+                        &mut clipboard,
+                        &mut proxy,
+                        &spawn_sender,
+                        &mut debug,
+                        &mut messages,
+                        &mut windows,
+                        &mut spawning,
+                    );
+
+                    if application.should_exit() {
+                        break;
+                    }
+
+                    for (id, window) in windows.iter_mut() {
+                        window.state.synchronize(&application, &window.raw);
+
+                        let _ = rebuild(
+                            &mut application,
+                            *id,
+                            window,
+                            &[],
+                            &mut renderer,
+                            &mut clipboard,
+                            &mut messages,
+                            &mut debug,
+                        );
+
+                        window.raw.request_redraw();
+                    }
+                }
+            }
+            LoopEvent::Winit(event::Event::PlatformSpecific(
+                event::PlatformSpecific::MacOS(event::MacOS::ReceivedUrl(url)),
+            )) => {
+                use iced_native::event;
+
+                if let Some(window) = windows.get_mut(&focused) {
+                    window.input.push(Event::PlatformSpecific(
+                        event::PlatformSpecific::MacOS(
+                            event::MacOS::ReceivedUrl(url),
+                        ),
+                    ));
+                }
+            }
+            LoopEvent::Winit(event::Event::UserEvent(message)) => {
+                messages.push(message);
+            }
+            LoopEvent::Winit(event::Event::RedrawRequested(window_id)) => {
+                let (id, window) = match windows
+                    .iter_mut()
+                    .find(|(_, window)| window.raw.id() == window_id)
+                {
+                    Some((id, window)) => (*id, window),
+                    None => continue,
+                };
+
+                let physical_size = window.state.physical_size();
+
+                if physical_size.width == 0 || physical_size.height == 0 {
+                    continue;
+                }
+
+                // Every window is paced on its own, and frames over its
+                // maximum rate are presented once the event loop wakes up at
+                // the deadline of its pacer
+                if !window.frame_pacer.ready() {
+                    continue;
+                }
+
+                debug.render_started();
+                let current_viewport_version = window.state.viewport_version();
+
+                if window.viewport_version != current_viewport_version {
+                    let _ = rebuild(
+                        &mut application,
+                        id,
+                        window,
+                        &[],
+                        &mut renderer,
+                        &mut clipboard,
+                        &mut messages,
+                        &mut debug,
+                    );
+
+                    compositor.configure_surface(
+                        &mut window.surface,
+                        physical_size.width,
+                        physical_size.height,
+                    );
+
+                    window.viewport_version = current_viewport_version;
+                }
+
+                for sender in window.state.take_frame_captures() {
+                    compositor.capture_next_frame(&mut renderer, sender);
+                }
+
+                present(
+                    &mut compositor,
+                    &mut renderer,
+                    &mut window.surface,
+                    &window.raw,
+                    window.state.viewport(),
+                    window.state.background_color(),
+                    &window.output,
+                    &mut debug,
+                    &mut window.mouse_interaction,
+                    window.is_animating,
+                );
+            }
+            LoopEvent::Winit(event::Event::RedrawEventsCleared) => {
+                // Wake up for the next long press or deferred frame
+                wake_up_at.set(
+                    windows
+                        .values()
+                        .flat_map(|window| {
+                            window
+                                .input
+                                .deadline()
+                                .into_iter()
+                                .chain(window.frame_pacer.deadline())
+                        })
+                        .min(),
+                );
+            }
+            LoopEvent::Winit(event::Event::WindowEvent {
+                event: window_event,
+                window_id,
+            }) => {
+                let (id, window) = match windows
+                    .iter_mut()
+                    .find(|(_, window)| window.raw.id() == window_id)
+                {
+                    Some((id, window)) => (*id, window),
+                    None => continue,
+                };
+
+                if let winit::event::WindowEvent::Focused(true) = window_event {
+                    focused = id;
+                }
+
+                if requests_exit(&window_event, window.state.modifiers())
+                    && exit_on_close_request
+                {
+                    messages.extend(application.event(
+                        id,
+                        Event::Window(window::Event::CloseRequested),
+                        iced_native::event::Status::Ignored,
+                    ));
+
+                    let _ = windows.remove(&id);
+
+                    continue;
+                }
+
+                window.state.update(&window.raw, &window_event, &mut debug);
+
+                window.input.track(
+                    &window_event,
+                    window.state.scale_factor(),
+                    window.state.modifiers(),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Creates the surface and the first frame of a new window.
+fn open<A, C>(
+    application: &mut A,
+    id: window::Id,
+    raw: winit::window::Window,
+    compositor: &mut C,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+    max_fps: Option<u32>,
+    long_press_duration: Duration,
+    double_click_interval: Duration,
+) -> Window<A, C>
+where
+    A: MultiWindowApplication,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    let state = State::new(application, id, &raw);
+    let physical_size = state.physical_size();
+
+    let mut surface = compositor.create_surface(&raw);
+
+    compositor.configure_surface(
+        &mut surface,
+        physical_size.width,
+        physical_size.height,
+    );
+
+    let user_interface = build_user_interface(
+        application,
+        id,
+        &state,
+        Cache::default(),
+        renderer,
+        debug,
+    );
+
+    debug.draw_started();
+    let output = user_interface.draw(renderer, state.cursor());
+    let is_animating = renderer.take_redraw_request();
+    debug.draw_finished();
+
+    Window {
+        surface,
+        raw,
+        viewport_version: state.viewport_version(),
+        state,
+        cache: user_interface.into_cache(),
+        output,
+        mouse_interaction: mouse::Interaction::default(),
+        input: Input::new(long_press_duration, double_click_interval),
+        frame_pacer: FramePacer::new(max_fps),
+        is_animating,
+    }
+}
+
+/// Builds the [`UserInterface`] of the window with the given [`window::Id`],
+/// logging [`struct@Debug`] information accordingly.
+fn build_user_interface<'a, A: MultiWindowApplication>(
+    application: &'a mut A,
+    id: window::Id,
+    state: &State<A>,
+    cache: Cache,
+    renderer: &mut A::Renderer,
+    debug: &mut Debug,
+) -> UserInterface<'a, A::Message, A::Renderer> {
+    debug.view_started();
+    let view = application.view(id);
+    debug.view_finished();
+
+    debug.layout_started();
+    let user_interface = UserInterface::build_in_safe_area(
+        view,
+        state.logical_size(),
+        state.safe_area(),
+        cache,
+        renderer,
+    );
+    debug.layout_finished();
+
+    user_interface
+}
+
+/// Builds the user interface of a window, feeds it the given events and
+/// draws it, returning the [`event::Status`] of every event.
+///
+/// The user interface borrows the [`MultiWindowApplication`], so only its
+/// cache outlives this function.
+fn rebuild<A, C>(
+    application: &mut A,
+    id: window::Id,
+    window: &mut Window<A, C>,
+    events: &[Event],
+    renderer: &mut A::Renderer,
+    clipboard: &mut Clipboard,
+    messages: &mut Vec<A::Message>,
+    debug: &mut Debug,
+) -> Vec<event::Status>
+where
+    A: MultiWindowApplication,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    let mut user_interface = build_user_interface(
+        application,
+        id,
+        &window.state,
+        std::mem::take(&mut window.cache),
+        renderer,
+        debug,
+    );
+
+    let statuses = if events.is_empty() {
+        Vec::new()
+    } else {
+        user_interface.update(
+            events,
+            window.state.cursor(),
+            renderer,
+            clipboard,
+            messages,
+        )
+    };
+
+    debug.draw_started();
+    window.output = user_interface.draw(renderer, window.state.cursor());
+    window.is_animating = renderer.take_redraw_request();
+    debug.draw_finished();
+
+    window.cache = user_interface.into_cache();
+
+    statuses
+}
+
+/// Updates a [`MultiWindowApplication`] by feeding it the provided messages,
+/// spawning any resulting [`Command`], and tracking its [`Subscription`].
+fn update<A, E, C>(
+    application: &mut A,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    spawn_sender: &mpsc::UnboundedSender<Spawn>,
+    debug: &mut Debug,
+    messages: &mut Vec<A::Message>,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    spawning: &mut BTreeSet<window::Id>,
+) where
+    A: MultiWindowApplication,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    for message in messages.drain(..) {
+        debug.log_message(&message);
+
+        debug.update_started(&message);
+        let command = runtime.enter(|| application.update(message));
+        debug.update_finished();
+
+        run_command(
+            application,
+            command,
+            runtime,
+            clipboard,
+            proxy,
+            spawn_sender,
+            windows,
+            spawning,
+        );
+    }
+
+    let _span = trace::subscriptions();
+
+    let subscription = application.subscription();
+    runtime.track(subscription);
+}
+
+/// Runs the actions of a [`Command`].
+///
+/// The windows that are requested to open are added to the `spawning` set
+/// until the event loop creates them.
+fn run_command<A, E, C>(
+    application: &A,
+    command: Command<A::Message>,
+    runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    clipboard: &mut Clipboard,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    spawn_sender: &mpsc::UnboundedSender<Spawn>,
+    windows: &mut BTreeMap<window::Id, Window<A, C>>,
+    spawning: &mut BTreeSet<window::Id>,
+) where
+    A: MultiWindowApplication,
+    E: Executor,
+    C: Compositor<Renderer = A::Renderer>,
+{
+    use iced_native::command;
+    use iced_native::window;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
+            command::Action::Clipboard(action) => match action {
+                clipboard::Action::Read(tag) => {
+                    let message = tag(clipboard.read());
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+                clipboard::Action::Write(contents) => {
+                    clipboard.write(contents);
+                }
+            },
+            command::Action::Proxy(produce) => {
+                let sender = proxy.clone();
+                let message = produce(event::Proxy::new(move |message| {
+                    sender.send_event(message).is_ok()
+                }));
+
+                proxy
+                    .send_event(message)
+                    .expect("Send message to event loop");
+            }
+            command::Action::Window(id, window::Action::Spawn(settings)) => {
+                let _ = spawning.insert(id);
+
+                spawn_sender
+                    .unbounded_send(Spawn {
+                        id,
+                        title: application.title(id),
+                        settings,
+                    })
+                    .expect("Send window to event loop");
+            }
+            command::Action::Window(id, window::Action::Close) => {
+                let _ = windows.remove(&id);
+                let _ = spawning.remove(&id);
+            }
+            command::Action::Window(id, action) => {
+                let window = match windows.get_mut(&id) {
+                    Some(window) => window,
+                    None => {
+                        log::warn!(
+                            "{:?} cannot be performed on a window that is not open",
+                            action
+                        );
+
+                        continue;
+                    }
+                };
+
+                match action {
+                    window::Action::Resize { width, height } => {
+                        window.raw.set_inner_size(winit::dpi::LogicalSize {
+                            width,
+                            height,
+                        });
+                    }
+                    window::Action::Move { x, y } => {
+                        window.raw.set_outer_position(
+                            winit::dpi::LogicalPosition { x, y },
+                        );
+                    }
+                    window::Action::SetScaleFactorOverride(scale_factor) => {
+                        window.state.set_scale_factor_override(
+                            &window.raw,
+                            scale_factor,
+                        );
+                    }
+                    window::Action::FetchScaleFactorOverride(tag) => {
+                        let message = tag(window.state.scale_factor_override());
+
+                        proxy
+                            .send_event(message)
+                            .expect("Send message to event loop");
+                    }
+                    window::Action::CaptureFrame(tag) => {
+                        use iced_futures::futures::channel::oneshot;
+                        use iced_futures::futures::{
+                            future, FutureExt, StreamExt,
+                        };
+
+                        let (sender, receiver) = oneshot::channel();
+
+                        window.state.capture_next_frame(sender);
+                        window.raw.request_redraw();
+
+                        runtime.run(Box::pin(
+                            receiver
+                                .map(move |frame| frame.ok().map(tag))
+                                .into_stream()
+                                .filter_map(future::ready),
+                        ));
+                    }
+                    window::Action::Spawn(_) | window::Action::Close => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::conversion;
+use crate::layout;
+use crate::mouse;
+use crate::multi_window::MultiWindowApplication;
+use crate::window;
+use crate::{Color, Debug, Size, Viewport};
+
+use iced_futures::futures::channel::oneshot;
+use std::marker::PhantomData;
+use winit::event::{Touch, WindowEvent};
+use winit::window::Window;
+
+const MIN_SCALE_FACTOR_OVERRIDE: f32 = 0.5;
+const MAX_SCALE_FACTOR_OVERRIDE: f32 = 3.0;
+
+/// The state of a window of a [`MultiWindowApplication`].
+#[derive(Debug)]
+pub struct State<A: MultiWindowApplication> {
+    id: window::Id,
+    title: String,
+    background_color: Color,
+    scale_factor: f64,
+    scale_factor_override: f64,
+    viewport: Viewport,
+    viewport_version: usize,
+    safe_area: layout::EdgeInsets,
+    cursor_position: Option<winit::dpi::PhysicalPosition<f64>>,
+    modifiers: winit::event::ModifiersState,
+    frame_captures: Vec<oneshot::Sender<Vec<u8>>>,
+    application: PhantomData<A>,
+}
+
+impl<A: MultiWindowApplication> State<A> {
+    /// Creates a new [`State`] for the window of a [`MultiWindowApplication`]
+    /// with the given [`window::Id`].
+    pub fn new(application: &A, id: window::Id, window: &Window) -> Self {
+        let title = application.title(id);
+        let background_color = application.background_color(id);
+        let scale_factor = application.scale_factor(id);
+
+        let viewport = {
+            let physical_size =
+                conversion::surface_size(window, window.inner_size());
+
+            Viewport::with_physical_size(
+                Size::new(physical_size.width, physical_size.height),
+                window.scale_factor() * scale_factor,
+            )
+        };
+
+        let safe_area = conversion::safe_area(window, viewport.scale_factor());
+
+        Self {
+            id,
+            title,
+            background_color,
+            scale_factor,
+            scale_factor_override: 1.0,
+            viewport,
+            viewport_version: 0,
+            safe_area,
+            cursor_position: None,
+            modifiers: winit::event::ModifiersState::default(),
+            frame_captures: Vec::new(),
+            application: PhantomData,
+        }
+    }
+
+    /// Returns the [`window::Id`] of the window of the [`State`].
+    pub fn id(&self) -> window::Id {
+        self.id
+    }
+
+    /// Returns the current background [`Color`] of the [`State`].
+    pub fn background_color(&self) -> Color {
+        self.background_color
+    }
+
+    /// Returns the current [`Viewport`] of the [`State`].
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Returns the version of the [`Viewport`] of the [`State`].
+    ///
+    /// The version is incremented every time the [`Viewport`] changes.
+    pub fn viewport_version(&self) -> usize {
+        self.viewport_version
+    }
+
+    /// Returns the safe area of the window of the [`State`], as
+    /// [`EdgeInsets`] of its logical size.
+    ///
+    /// [`EdgeInsets`]: layout::EdgeInsets
+    pub fn safe_area(&self) -> layout::EdgeInsets {
+        self.safe_area
+    }
+
+    /// Returns the physical [`Size`] of the [`Viewport`] of the [`State`].
+    pub fn physical_size(&self) -> Size<u32> {
+        self.viewport.physical_size()
+    }
+
+    /// Returns the logical [`Size`] of the [`Viewport`] of the [`State`].
+    pub fn logical_size(&self) -> Size<f32> {
+        self.viewport.logical_size()
+    }
+
+    /// Returns the current scale factor of the [`Viewport`] of the [`State`].
+    pub fn scale_factor(&self) -> f64 {
+        self.viewport.scale_factor()
+    }
+
+    /// Returns the current scale factor override of the [`State`].
+    ///
+    /// The override is multiplied with the scale factor of the window and the
+    /// scale factor of the [`MultiWindowApplication`] to obtain the scale
+    /// factor of the [`Viewport`].
+    pub fn scale_factor_override(&self) -> f32 {
+        self.scale_factor_override as f32
+    }
+
+    /// Sets the scale factor override of the [`State`], clamped to the
+    /// `[0.5, 3.0]` range, and rebuilds its [`Viewport`] accordingly.
+    pub fn set_scale_factor_override(
+        &mut self,
+        window: &Window,
+        scale_factor_override: f32,
+    ) {
+        let scale_factor_override = f64::from(
+            scale_factor_override
+                .max(MIN_SCALE_FACTOR_OVERRIDE)
+                .min(MAX_SCALE_FACTOR_OVERRIDE),
+        );
+
+        if self.scale_factor_override != scale_factor_override {
+            let size = conversion::surface_size(window, window.inner_size());
+
+            self.viewport = Viewport::with_physical_size(
+                Size::new(size.width, size.height),
+                window.scale_factor()
+                    * self.scale_factor
+                    * scale_factor_override,
+            );
+
+            self.safe_area =
+                conversion::safe_area(window, self.viewport.scale_factor());
+
+            self.scale_factor_override = scale_factor_override;
+            self.viewport_version = self.viewport_version.wrapping_add(1);
+        }
+    }
+
+    /// Requests the pixels of the next frame drawn for the [`State`] to be
+    /// sent through the given `sender`.
+    pub fn capture_next_frame(&mut self, sender: oneshot::Sender<Vec<u8>>) {
+        self.frame_captures.push(sender);
+    }
+
+    /// Takes the pending frame captures of the [`State`].
+    ///
+    /// They should be handed to the compositor right before drawing the next
+    /// frame.
+    pub fn take_frame_captures(&mut self) -> Vec<oneshot::Sender<Vec<u8>>> {
+        std::mem::take(&mut self.frame_captures)
+    }
+
+    /// Returns the current [`mouse::Cursor`] of the [`State`].
+    pub fn cursor(&self) -> mouse::Cursor {
+        self.cursor_position
+            .map(|cursor_position| {
+                mouse::Cursor::Available(conversion::cursor_position(
+                    cursor_position,
+                    self.viewport.scale_factor(),
+                ))
+            })
+            .unwrap_or(mouse::Cursor::Unavailable)
+    }
+
+    /// Returns the current keyboard modifiers of the [`State`].
+    pub fn modifiers(&self) -> winit::event::ModifiersState {
+        self.modifiers
+    }
+
+    /// Processes the provided window event and updates the [`State`]
+    /// accordingly.
+    pub fn update(
+        &mut self,
+        window: &Window,
+        event: &WindowEvent<'_>,
+        _debug: &mut Debug,
+    ) {
+        match event {
+            WindowEvent::Resized(new_size) => {
+                let new_size = conversion::surface_size(window, *new_size);
+                let size = Size::new(new_size.width, new_size.height);
+
+                self.viewport = Viewport::with_physical_size(
+                    size,
+                    window.scale_factor()
+                        * self.scale_factor
+                        * self.scale_factor_override,
+                );
+
+                self.viewport_version = self.viewport_version.wrapping_add(1);
+                self.safe_area =
+                    conversion::safe_area(window, self.viewport.scale_factor());
+            }
+            WindowEvent::ScaleFactorChanged {
+                scale_factor: new_scale_factor,
+                new_inner_size,
+            } => {
+                let new_size =
+                    conversion::surface_size(window, **new_inner_size);
+                let size = Size::new(new_size.width, new_size.height);
+
+                self.viewport = Viewport::with_physical_size(
+                    size,
+                    new_scale_factor
+                        * self.scale_factor
+                        * self.scale_factor_override,
+                );
+
+                self.viewport_version = self.viewport_version.wrapping_add(1);
+                self.safe_area =
+                    conversion::safe_area(window, self.viewport.scale_factor());
+            }
+            WindowEvent::CursorMoved { position, .. }
+            | WindowEvent::Touch(Touch {
+                location: position, ..
+            }) => {
+                self.cursor_position = Some(*position);
+            }
+            WindowEvent::CursorLeft { .. } => {
+                self.cursor_position = None;
+            }
+            WindowEvent::ModifiersChanged(new_modifiers) => {
+                self.modifiers = *new_modifiers;
+            }
+            #[cfg(feature = "debug")]
+            WindowEvent::KeyboardInput {
+                input:
+                    winit::event::KeyboardInput {
+                        virtual_keycode: Some(winit::event::VirtualKeyCode::F12),
+                        state: winit::event::ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => _debug.toggle(),
+            _ => {}
+        }
+    }
+
+    /// Synchronizes the [`State`] with its [`MultiWindowApplication`] and its
+    /// respective window.
+    ///
+    /// Every window should be synchronized after calling
+    /// [`MultiWindowApplication::update`].
+    pub fn synchronize(&mut self, application: &A, window: &Window) {
+        // Update window title
+        let new_title = application.title(self.id);
+
+        if self.title != new_title {
+            window.set_title(&new_title);
+
+            self.title = new_title;
+        }
+
+        // Update background color
+        self.background_color = application.background_color(self.id);
+
+        // Update scale factor
+        let new_scale_factor = application.scale_factor(self.id);
+
+        if self.scale_factor != new_scale_factor {
+            let size = conversion::surface_size(window, window.inner_size());
+
+            self.viewport = Viewport::with_physical_size(
+                Size::new(size.width, size.height),
+                window.scale_factor()
+                    * new_scale_factor
+                    * self.scale_factor_override,
+            );
+
+            self.safe_area =
+                conversion::safe_area(window, self.viewport.scale_factor());
+            self.scale_factor = new_scale_factor;
+            self.viewport_version = self.viewport_version.wrapping_add(1);
+        }
+    }
+}
@@ -14,12 +14,15 @@ mod platform;
 pub use platform::PlatformSpecific;
 
 use crate::conversion;
+use crate::gesture::{DoubleClick, LongPress};
 use crate::{Mode, Position};
 use winit::monitor::MonitorHandle;
 use winit::window::WindowBuilder;
 
+use std::time::Duration;
+
 /// The settings of an application.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct Settings<Flags> {
     /// The identifier of the application.
     ///
@@ -38,6 +41,48 @@ pub struct Settings<Flags> {
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     pub exit_on_close_request: bool,
+
+    /// The initial scale factor override of the window.
+    ///
+    /// It is multiplied with the scale factor reported by the operating
+    /// system and clamped to the `[0.5, 3.0]` range.
+    pub scale_factor_override: f32,
+
+    /// The maximum amount of frames presented per second.
+    ///
+    /// Frames that would exceed it are deferred until the event loop wakes
+    /// up, without blocking it. If `None`, the frame rate is not limited.
+    pub max_fps: Option<u32>,
+
+    /// The time a finger needs to stay still on the screen to produce a
+    /// [`touch::Event::LongPress`].
+    ///
+    /// [`touch::Event::LongPress`]: crate::touch::Event::LongPress
+    pub long_press_duration: Duration,
+
+    /// The maximum time between two presses of a mouse button to produce a
+    /// [`mouse::Event::DoubleClicked`].
+    ///
+    /// [`mouse::Event::DoubleClicked`]: crate::mouse::Event::DoubleClicked
+    pub double_click_interval: Duration,
+}
+
+impl<Flags> Default for Settings<Flags>
+where
+    Flags: Default,
+{
+    fn default() -> Self {
+        Self {
+            id: None,
+            window: Window::default(),
+            flags: Flags::default(),
+            exit_on_close_request: false,
+            scale_factor_override: 1.0,
+            max_fps: None,
+            long_press_duration: LongPress::DEFAULT_DURATION,
+            double_click_interval: DoubleClick::DEFAULT_INTERVAL,
+        }
+    }
 }
 
 /// The window settings of an application.
@@ -179,3 +224,18 @@ impl Default for Window {
         }
     }
 }
+
+impl From<iced_native::window::Settings> for Window {
+    fn from(settings: iced_native::window::Settings) -> Window {
+        Window {
+            size: settings.size,
+            min_size: settings.min_size,
+            max_size: settings.max_size,
+            resizable: settings.resizable,
+            decorations: settings.decorations,
+            transparent: settings.transparent,
+            always_on_top: settings.always_on_top,
+            ..Window::default()
+        }
+    }
+}
@@ -1,18 +1,105 @@
 //! Interact with the window of your application.
+//!
+//! The actions of this module are performed on the main window. Use
+//! [`target`] to perform them on another window of a
+//! [`MultiWindowApplication`].
+//!
+//! [`MultiWindowApplication`]: crate::MultiWindowApplication
 use crate::command::{self, Command};
 use iced_native::window;
 
-pub use window::Event;
+pub use window::{Event, Id, Settings};
 
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Resize {
-        width,
-        height,
-    }))
+    Command::single(command::Action::Window(
+        Id::MAIN,
+        window::Action::Resize { width, height },
+    ))
 }
 
 /// Moves a window to the given logical coordinates.
 pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
-    Command::single(command::Action::Window(window::Action::Move { x, y }))
+    Command::single(command::Action::Window(
+        Id::MAIN,
+        window::Action::Move { x, y },
+    ))
+}
+
+/// Sets the scale factor override of the window.
+///
+/// The override is multiplied with the scale factor reported by the operating
+/// system, zooming the whole user interface in or out. Layouts are recomputed
+/// with the resulting logical size of the window.
+///
+/// The override is clamped to the `[0.5, 3.0]` range.
+pub fn set_scale_factor_override<Message>(
+    scale_factor: f32,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        Id::MAIN,
+        window::Action::SetScaleFactorOverride(scale_factor),
+    ))
+}
+
+/// Fetches the current scale factor override of the window.
+pub fn scale_factor_override<Message>(
+    f: impl Fn(f32) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        Id::MAIN,
+        window::Action::FetchScaleFactorOverride(Box::new(f)),
+    ))
+}
+
+/// Captures the pixels of the next frame drawn to the window.
+///
+/// It is a shorthand for [`Command::capture_frame`].
+pub fn capture_frame<Message>(
+    f: impl FnOnce(Vec<u8>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::capture_frame(f)
+}
+
+/// Spawns a new window with the given [`Settings`], returning its [`Id`]
+/// together with the [`Command`] that opens it.
+///
+/// Only a [`MultiWindowApplication`] can spawn windows.
+///
+/// [`MultiWindowApplication`]: crate::MultiWindowApplication
+pub fn spawn<Message>(settings: Settings) -> (Id, Command<Message>) {
+    let id = Id::unique();
+
+    (
+        id,
+        Command::single(command::Action::Window(
+            id,
+            window::Action::Spawn(settings),
+        )),
+    )
+}
+
+/// Closes the window with the given [`Id`].
+///
+/// Only a [`MultiWindowApplication`] can close windows.
+///
+/// [`MultiWindowApplication`]: crate::MultiWindowApplication
+pub fn close<Message>(id: Id) -> Command<Message> {
+    Command::single(command::Action::Window(id, window::Action::Close))
+}
+
+/// Performs the window actions of the given [`Command`] on the window with
+/// the given [`Id`], instead of the main window.
+///
+/// For instance, `window::target(palette, window::resize(300, 600))` resizes
+/// the window of the `palette`.
+pub fn target<Message>(id: Id, command: Command<Message>) -> Command<Message> {
+    Command::batch(command.actions().into_iter().map(|action| {
+        Command::single(match action {
+            command::Action::Window(_, action) => {
+                command::Action::Window(id, action)
+            }
+            action => action,
+        })
+    }))
 }